@@ -287,6 +287,7 @@ impl RustBridgeGenerator {
                             method_parameters,
                             mode,
                             schedule_at,
+                            priority: None,
                             idempotency_key: None,
                             deployment_revision: None,
                             owner_account_email: None,
@@ -125,6 +125,7 @@ fn rust_counter_agent() -> GeneratedPackage {
                 }],
             }),
             http_endpoint: vec![],
+            read_only: false,
         }],
         dependencies: vec![],
         mode: AgentMode::Durable,
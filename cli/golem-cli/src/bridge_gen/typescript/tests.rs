@@ -198,6 +198,7 @@ fn ts_counter_agent() -> GeneratedPackage {
                 }],
             }),
             http_endpoint: vec![],
+            read_only: false,
         }],
         dependencies: vec![],
         mode: AgentMode::Durable,
@@ -20,7 +20,8 @@ use golem_client::api::{
     ApiDeploymentClientLive, ApiDomainClientLive, ApiSecurityClientLive, ApplicationClientLive,
     ComponentClientLive, DeploymentClientLive, EnvironmentClientLive, GrantClientLive,
     HealthCheckClientLive, HttpApiDefinitionClientLive, LimitsClientLive, LoginClientLive,
-    McpDeploymentClientLive, PluginClientLive, TokenClientLive, WorkerClientLive,
+    McpDeploymentClientLive, PluginClientLive, ReportsClientLive, TokenClientLive,
+    WorkerClientLive,
 };
 use golem_client::{Context as ClientContext, Security};
 use golem_common::model::account::AccountId;
@@ -49,6 +50,7 @@ pub struct GolemClients {
     pub login: LoginClientLive,
     pub mcp_deployment: McpDeploymentClientLive,
     pub plugin: PluginClientLive,
+    pub reports: ReportsClientLive,
     pub token: TokenClientLive,
     pub worker: WorkerClientLive,
     pub worker_invoke: WorkerClientLive,
@@ -166,6 +168,9 @@ impl GolemClients {
             plugin: PluginClientLive {
                 context: registry_context(),
             },
+            reports: ReportsClientLive {
+                context: registry_context(),
+            },
             token: TokenClientLive {
                 context: registry_context(),
             },
@@ -23,6 +23,7 @@ use crate::command::profile::ProfileSubcommand;
 use crate::command::server::ServerSubcommand;
 use crate::command::shared_args::{
     BuildArgs, ForceBuildArg, OptionalComponentName, OptionalComponentNames, PostDeployArgs,
+    UpdateCheckArgs,
 };
 use crate::command::worker::AgentSubcommand;
 use crate::config::ProfileName;
@@ -728,6 +729,8 @@ pub enum GolemCliSubcommand {
         /// Do not wake up suspended agents, the update will be applied next time the agent wakes up
         #[arg(long, default_value_t = false)]
         disable_wakeup: bool,
+        #[command(flatten)]
+        update_check: UpdateCheckArgs,
     },
     /// Redeploy all agents of the application using the latest version
     RedeployAgents {
@@ -741,6 +744,18 @@ pub enum GolemCliSubcommand {
     },
     /// List all the deployed agent types
     ListAgentTypes {},
+    /// Show a live dashboard of the agents and worker executors
+    Top {
+        #[command(flatten)]
+        component_name: OptionalComponentName,
+        /// Filter for agent metadata in form of `property op value`, the same way as for `agent list`.
+        /// Can be used multiple times (AND condition is applied between them)
+        #[arg(long)]
+        filter: Vec<String>,
+        /// Refresh interval in seconds
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+        refresh: u64,
+    },
 
     // Other entities ------------------------------------------------------------------------------
     /// Execute custom, application manifest defined commands
@@ -800,9 +815,12 @@ pub mod shared_args {
     use crate::model::app::AppBuildStep;
     use crate::model::worker::{AgentUpdateMode, WorkerName};
     use crate::model::GuestLanguage;
+    use anyhow::anyhow;
     use clap::Args;
     use golem_common::model::account::AccountId;
-    use golem_common::model::component::ComponentName;
+    use golem_common::model::component::{ComponentName, ComponentRevision};
+    use golem_common::model::worker::UpdateCheckRequest;
+    use golem_common::model::WorkerFilter;
 
     pub type ComponentTemplateName = String;
     pub type NewWorkerArgument = String;
@@ -888,6 +906,45 @@ pub mod shared_args {
         pub reset: bool,
     }
 
+    #[derive(Debug, Args, Clone, Default)]
+    pub struct UpdateCheckArgs {
+        /// Only check whether the agents' existing histories can be replayed using the latest version, without updating them
+        #[arg(long, default_value_t = false, conflicts_with_all = ["await", "disable_wakeup"])]
+        pub dry_run: bool,
+        /// Filter for agent metadata in form of `property op value`, selects the agents to check with --dry-run.
+        ///
+        /// Filter examples: `name = my-agent(1, 2, 3)`, `revision < 7`, `status = Running`, `agent_type = shopping-cart`.
+        /// Can be used multiple times (AND condition is applied between them)
+        #[arg(long, requires = "dry_run")]
+        pub filter: Vec<String>,
+        /// Maximum number of agents to check with --dry-run, all matching agents are checked if not specified
+        #[arg(long, requires = "dry_run")]
+        pub sample_size: Option<u64>,
+        /// Maximum number of agents replayed at the same time by the server with --dry-run
+        #[arg(long, requires = "dry_run")]
+        pub concurrency: Option<u32>,
+    }
+
+    impl UpdateCheckArgs {
+        pub fn to_request(
+            &self,
+            target_revision: ComponentRevision,
+        ) -> anyhow::Result<UpdateCheckRequest> {
+            let filter = if self.filter.is_empty() {
+                None
+            } else {
+                Some(WorkerFilter::from(self.filter.clone()).map_err(|err| anyhow!(err))?)
+            };
+
+            Ok(UpdateCheckRequest {
+                target_revision,
+                filter,
+                sample_size: self.sample_size,
+                concurrency: self.concurrency,
+            })
+        }
+    }
+
     impl PostDeployArgs {
         pub fn is_any_set(&self, env_args: &PostDeployArgs) -> bool {
             env_args.update_agents.is_some()
@@ -950,7 +1007,7 @@ pub mod environment {
 
 pub mod component {
     use crate::command::shared_args::{
-        ComponentTemplateName, OptionalComponentName, OptionalComponentNames,
+        ComponentTemplateName, OptionalComponentName, OptionalComponentNames, UpdateCheckArgs,
     };
     use crate::model::worker::AgentUpdateMode;
     use clap::Subcommand;
@@ -992,6 +1049,8 @@ pub mod component {
             /// Do not wake up suspended agents, the update will be applied next time the agent wakes up
             #[arg(long, default_value_t = false)]
             disable_wakeup: bool,
+            #[command(flatten)]
+            update_check: UpdateCheckArgs,
         },
         /// Redeploy all agents of the selected component using the latest version
         RedeployAgents {
@@ -1075,12 +1134,16 @@ pub mod worker {
     use crate::command::shared_args::{
         AgentIdArgs, PostDeployArgs, StreamArgs, WorkerFunctionArgument, WorkerFunctionName,
     };
+    use crate::model::oplog_export::OplogExportFormat;
     use crate::model::worker::AgentUpdateMode;
     use chrono::{DateTime, Utc};
     use clap::Subcommand;
     use golem_client::model::ScanCursor;
     use golem_common::model::component::{ComponentName, ComponentRevision};
+    use golem_common::model::invocation_queue::InvocationPriority;
+    use golem_common::model::recurring_invocation::{MissedFirePolicy, RecurringSchedule};
     use golem_common::model::IdempotencyKey;
+    use std::path::PathBuf;
     use uuid::Uuid;
 
     #[derive(Debug, Subcommand)]
@@ -1121,6 +1184,9 @@ pub mod worker {
             /// Schedule the invocation at a specific time (ISO 8601 / RFC 3339 format, e.g. 2026-03-15T10:30:00Z)
             #[clap(long, requires = "trigger")]
             schedule_at: Option<DateTime<Utc>>,
+            /// Priority class of the invocation in the agent's invocation queue: low, normal or high
+            #[clap(long)]
+            priority: Option<InvocationPriority>,
         },
         /// Get agent metadata
         Get {
@@ -1144,7 +1210,8 @@ pub mod worker {
 
             /// Filter for agent metadata in form of `property op value`.
             ///
-            /// Filter examples: `name = my-agent(1, 2, 3)`, `version >= 0`, `status = Running`, `env.var1 = value`.
+            /// Filter examples: `name = my-agent(1, 2, 3)`, `revision >= 0`, `status = Running`, `env.var1 = value`,
+            /// `agent_type = shopping-cart`, `last_invocation_at < now-30days`, `pending_invocation_count > 100`.
             /// Can be used multiple times (AND condition is applied between them)
             #[arg(long)]
             filter: Vec<String>,
@@ -1224,8 +1291,38 @@ pub mod worker {
             #[arg(long, conflicts_with = "query")]
             from: Option<u64>,
             /// Lucene query to look for oplog entries. If missing, the whole oplog is returned
-            #[arg(long, conflicts_with = "from")]
+            #[arg(long, conflicts_with_all = ["from", "follow"])]
             query: Option<String>,
+            /// Keep streaming new oplog entries as they get committed, until interrupted
+            #[arg(long, short)]
+            follow: bool,
+            /// Only show oplog entries of the given kind, e.g. `agent-invocation-started`. Can be repeated
+            #[arg(long = "kind", value_name = "KIND")]
+            kinds: Vec<String>,
+            /// Export the oplog entries in the given format instead of showing them
+            #[arg(long, value_enum)]
+            export: Option<OplogExportFormat>,
+            /// File to write the exported entries to. If missing, JSON Lines are written to the standard output
+            #[arg(long, short, requires = "export")]
+            output: Option<PathBuf>,
+        },
+        /// Downloads an agent's full oplog, including all payloads, into a fixture file for `agent replay`
+        DownloadOplog {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// Path of the oplog fixture file to write
+            #[arg(long, short)]
+            output: PathBuf,
+        },
+        /// Replays the invocations of a downloaded oplog against the locally built component and reports the first divergence.
+        ///
+        /// The invocations are replayed in a sandboxed, in-process executor without network access, so
+        /// no environment is touched. Host calls receive the recorded responses, and their requests and
+        /// the invocation results are compared with the recorded ones.
+        #[cfg(feature = "server-commands")]
+        Replay {
+            /// Path of an oplog fixture file created by `agent download-oplog`
+            fixture: PathBuf,
         },
         /// Reverts an agent by undoing its last recorded operations
         Revert {
@@ -1245,6 +1342,24 @@ pub mod worker {
             /// Idempotency key of the invocation to be cancelled
             idempotency_key: IdempotencyKey,
         },
+        /// Manage the recurring invocations of an agent
+        Recurring {
+            #[clap(subcommand)]
+            subcommand: AgentRecurringSubcommand,
+        },
+        /// Manage the pending invocations of an agent
+        Queue {
+            #[clap(subcommand)]
+            subcommand: AgentQueueSubcommand,
+        },
+        /// Run an operation on all agents of a component matching a filter on the server side.
+        ///
+        /// Bulk operations keep running on the server when the CLI disconnects, and can be
+        /// observed and cancelled using their operation ID.
+        Bulk {
+            #[clap(subcommand)]
+            subcommand: AgentBulkSubcommand,
+        },
         /// List files in a worker's directory
         Files {
             #[command(flatten)]
@@ -1264,6 +1379,160 @@ pub mod worker {
             output: Option<String>,
         },
     }
+
+    #[derive(Debug, Subcommand)]
+    pub enum AgentRecurringSubcommand {
+        /// Schedule an agent method to be invoked repeatedly, until the recurring invocation is cancelled
+        Schedule {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// Agent function name to invoke
+            function_name: WorkerFunctionName,
+            /// Agent function arguments in WAVE format
+            arguments: Vec<WorkerFunctionArgument>,
+            /// Cron expression with five fields (minute, hour, day of month, month, day of week),
+            /// evaluated in UTC, for example `*/15 * * * *` or `@daily`
+            #[arg(long, conflicts_with = "every", required_unless_present = "every")]
+            cron: Option<String>,
+            /// Fixed interval between invocations, for example `30s`, `5m` or `1h 30m`
+            #[arg(long, value_parser = RecurringSchedule::parse_interval)]
+            every: Option<RecurringSchedule>,
+            /// What happens with occurrences missed while the schedule could not be processed:
+            /// skip, run-once or catch-up
+            #[arg(long, default_value_t = MissedFirePolicy::RunOnce)]
+            missed_fire_policy: MissedFirePolicy,
+        },
+        /// List the recurring invocations of an agent
+        List {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+        },
+        /// Cancel a recurring invocation of an agent
+        Cancel {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// ID of the recurring invocation
+            id: Uuid,
+        },
+    }
+
+    #[derive(Debug, Subcommand)]
+    pub enum AgentQueueSubcommand {
+        /// List the pending invocations of an agent in the order they are going to be processed
+        List {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+        },
+        /// Change the priority or the position of a pending invocation
+        Reorder {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// Idempotency key of the pending invocation
+            idempotency_key: IdempotencyKey,
+            /// New priority class of the invocation: low, normal or high
+            #[arg(long)]
+            priority: Option<InvocationPriority>,
+            /// Zero-based target position in the queue, if not specified the invocation is placed
+            /// by its priority
+            #[arg(long)]
+            position: Option<u64>,
+        },
+        /// Cancel every pending invocation of an agent method
+        Purge {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// Agent function name whose pending invocations are cancelled
+            #[arg(long)]
+            function_name: WorkerFunctionName,
+        },
+        /// Limit the number of pending invocations, new invocations are rejected when the queue is full
+        Limit {
+            #[command(flatten)]
+            agent_id: AgentIdArgs,
+            /// Maximum number of pending invocations
+            #[arg(conflicts_with = "unlimited", required_unless_present = "unlimited")]
+            limit: Option<u64>,
+            /// Remove the limit
+            #[arg(long)]
+            unlimited: bool,
+        },
+    }
+
+    #[derive(clap::Args, Debug, Clone)]
+    pub struct BulkTargetArgs {
+        /// Optional component name, if not specified, the component of the current application
+        /// directory is used
+        #[arg(long)]
+        pub component_name: Option<ComponentName>,
+        /// Filter for agent metadata in form of `property op value`, selects all agents if not specified.
+        ///
+        /// Filter examples: `name = my-agent(1, 2, 3)`, `revision < 7`, `status = Running`, `env.var1 = value`,
+        /// `agent_type = shopping-cart`, `last_invocation_at < now-30days`, `pending_invocation_count > 100`.
+        /// Can be used multiple times (AND condition is applied between them)
+        #[arg(long)]
+        pub filter: Vec<String>,
+        /// Maximum number of agents processed at the same time by the server
+        #[arg(long)]
+        pub concurrency: Option<u32>,
+    }
+
+    #[derive(Debug, Subcommand)]
+    pub enum AgentBulkSubcommand {
+        /// Update all matching agents
+        Update {
+            #[command(flatten)]
+            target: BulkTargetArgs,
+            /// Update mode - auto or manual (default is auto)
+            #[arg(long)]
+            mode: Option<AgentUpdateMode>,
+            /// The new revision of the updated agents (default is the latest revision)
+            #[arg(long)]
+            target_revision: Option<ComponentRevision>,
+            /// Do not wake up suspended agents, the update will be applied next time the agent wakes up
+            #[arg(long, default_value_t = false)]
+            disable_wakeup: bool,
+        },
+        /// Interrupt all matching agents
+        Interrupt {
+            #[command(flatten)]
+            target: BulkTargetArgs,
+        },
+        /// Resume all matching agents
+        Resume {
+            #[command(flatten)]
+            target: BulkTargetArgs,
+        },
+        /// Delete all matching agents
+        Delete {
+            #[command(flatten)]
+            target: BulkTargetArgs,
+        },
+        /// Get the progress of a bulk operation
+        Get {
+            /// Optional component name, if not specified, the component of the current application
+            /// directory is used
+            #[arg(long)]
+            component_name: Option<ComponentName>,
+            /// ID of the bulk operation
+            operation_id: Uuid,
+        },
+        /// List the running and recently finished bulk operations of a component
+        List {
+            /// Optional component name, if not specified, the component of the current application
+            /// directory is used
+            #[arg(long)]
+            component_name: Option<ComponentName>,
+        },
+        /// Cancel a running bulk operation
+        Cancel {
+            /// Optional component name, if not specified, the component of the current application
+            /// directory is used
+            #[arg(long)]
+            component_name: Option<ComponentName>,
+            /// ID of the bulk operation
+            operation_id: Uuid,
+        },
+    }
 }
 
 pub mod api {
@@ -1474,6 +1743,8 @@ pub mod profile {
 pub mod cloud {
     use crate::command::cloud::account::AccountSubcommand;
     use crate::command::cloud::token::TokenSubcommand;
+    use crate::command::parse_instant;
+    use chrono::{DateTime, Utc};
     use clap::Subcommand;
 
     #[derive(Debug, Subcommand)]
@@ -1488,6 +1759,15 @@ pub mod cloud {
             #[clap(subcommand)]
             subcommand: TokenSubcommand,
         },
+        /// Show the usage of the selected environment, aggregated by component and agent type
+        Usage {
+            /// Start of the time range, defaults to 24 hours before the end
+            #[arg(long, value_parser = parse_instant)]
+            from: Option<DateTime<Utc>>,
+            /// End of the time range, defaults to now
+            #[arg(long, value_parser = parse_instant)]
+            to: Option<DateTime<Utc>>,
+        },
     }
 
     pub mod token {
@@ -1679,12 +1959,16 @@ fn parse_instant(
 
 #[cfg(test)]
 mod test {
+    use crate::command::shared_args::UpdateCheckArgs;
     use crate::command::{
         builtin_exec_subcommands, help_target_to_subcommand_names, GolemCliCommand,
+        GolemCliSubcommand,
     };
     use crate::error::ShowClapHelpTarget;
     use clap::builder::StyledStr;
-    use clap::{Command, CommandFactory};
+    use clap::{Command, CommandFactory, Parser};
+    use golem_common::model::component::ComponentRevision;
+    use golem_common::model::WorkerFilter;
     use itertools::Itertools;
     use std::collections::{BTreeMap, BTreeSet};
     use strum::IntoEnumIterator;
@@ -1899,6 +2183,73 @@ mod test {
         );
     }
 
+    fn parse_update_check_args(args: &[&str]) -> Result<UpdateCheckArgs, clap::Error> {
+        let command = <GolemCliCommand as Parser>::try_parse_from(
+            ["golem", "update-agents"].iter().chain(args.iter()),
+        )?;
+        match command.subcommand {
+            GolemCliSubcommand::UpdateAgents { update_check, .. } => Ok(update_check),
+            other => panic!("Unexpected subcommand: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn update_agents_dry_run_args() {
+        let args = parse_update_check_args(&[
+            "--dry-run",
+            "--filter",
+            "name = my-agent",
+            "--filter",
+            "status = Running",
+            "--sample-size",
+            "10",
+            "--concurrency",
+            "2",
+        ])
+        .unwrap();
+
+        let revision = ComponentRevision::new(3).unwrap();
+        let request = args.to_request(revision).unwrap();
+
+        assert!(args.dry_run);
+        assert_eq!(request.target_revision, revision);
+        assert_eq!(
+            request.filter,
+            Some(
+                WorkerFilter::from(vec![
+                    "name = my-agent".to_string(),
+                    "status = Running".to_string()
+                ])
+                .unwrap()
+            )
+        );
+        assert_eq!(request.sample_size, Some(10));
+        assert_eq!(request.concurrency, Some(2));
+    }
+
+    #[test]
+    fn update_agents_dry_run_without_options_checks_all_agents() {
+        let args = parse_update_check_args(&["--dry-run"]).unwrap();
+        let request = args.to_request(ComponentRevision::INITIAL).unwrap();
+
+        assert_eq!(request.filter, None);
+        assert_eq!(request.sample_size, None);
+        assert_eq!(request.concurrency, None);
+    }
+
+    #[test]
+    fn update_agents_check_options_require_dry_run() {
+        assert!(parse_update_check_args(&["--sample-size", "10"]).is_err());
+        assert!(parse_update_check_args(&["--filter", "name = my-agent"]).is_err());
+        assert!(parse_update_check_args(&["--dry-run", "--await"]).is_err());
+    }
+
+    #[test]
+    fn update_agents_dry_run_rejects_invalid_filter() {
+        let args = parse_update_check_args(&["--dry-run", "--filter", "not a filter"]).unwrap();
+        assert!(args.to_request(ComponentRevision::INITIAL).is_err());
+    }
+
     #[test]
     fn builtin_app_subcommands_no_panic() {
         println!("{:?}", builtin_exec_subcommands())
@@ -18,6 +18,9 @@ use crate::model::http_api::{HttpApiDeploymentDeployProperties, McpDeploymentDep
 use crate::model::text::component::is_sensitive_env_var_name;
 use anyhow::bail;
 use golem_client::model::{DeploymentPlan, DeploymentSummary};
+use golem_common::model::agent::compatibility::{
+    agent_types_breaking_changes, AgentTypeBreakingChange,
+};
 use golem_common::model::agent::AgentType;
 use golem_common::model::component::{ComponentDto, ComponentName};
 use golem_common::model::deployment::{
@@ -99,6 +102,16 @@ impl DeployDiff {
         self.staged_deployment_hash == self.current_deployment_hash
     }
 
+    /// Backward incompatible agent API changes between the current deployment and the local one
+    pub fn agent_breaking_changes(&self) -> Vec<AgentTypeBreakingChange> {
+        agent_types_breaking_changes(
+            self.current_agent_types.values().flatten(),
+            self.deployable_components
+                .values()
+                .flat_map(|component| component.agent_types.iter()),
+        )
+    }
+
     pub fn unified_diffs(&self, show_sensitive: bool) -> DeployUnifiedDiffs {
         let local_for_stage = normalized_diff_deployment(
             show_sensitive,
@@ -199,6 +212,11 @@ impl DeployDiff {
                 let diff = diff::unified_diff(current_agents, local_agents_for_current);
                 (!diff.is_empty()).then_some(diff)
             },
+            agent_breaking_changes: self
+                .agent_breaking_changes()
+                .iter()
+                .map(|change| change.to_string())
+                .collect(),
         }
     }
 
@@ -461,6 +479,7 @@ pub struct DeployUnifiedDiffs {
     pub agent_diff_stage: Option<String>,
     pub deployment_diff: String,
     pub agent_diff: Option<String>,
+    pub agent_breaking_changes: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -769,6 +788,7 @@ fn normalized_diff_deployment(
                                 Some(metadata) => diff::ComponentMetadata {
                                     env: safe_env(&metadata.env),
                                     config_vars: metadata.config_vars.clone(),
+                                    agent_resource_limits: metadata.agent_resource_limits.clone(),
                                 }
                                 .into(),
                                 None => component.metadata.hash().into(),
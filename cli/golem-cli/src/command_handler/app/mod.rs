@@ -17,7 +17,7 @@ use crate::app::template::{AppTemplateCommon, AppTemplateComponent, AppTemplateN
 use crate::command::builtin_exec_subcommands;
 use crate::command::exec::ExecSubcommand;
 use crate::command::shared_args::{
-    BuildArgs, ForceBuildArg, OptionalComponentNames, PostDeployArgs,
+    BuildArgs, ForceBuildArg, OptionalComponentNames, PostDeployArgs, UpdateCheckArgs,
 };
 use crate::command_handler::app::deploy_diff::{
     DeployDetails, DeployDiff, DeployDiffKind, DeployQuickDiff, RollbackDetails, RollbackDiff,
@@ -467,15 +467,28 @@ impl AppCommandHandler {
         update_mode: AgentUpdateMode,
         await_update: bool,
         disable_wakeup: bool,
+        update_check: UpdateCheckArgs,
     ) -> anyhow::Result<()> {
         self.must_select_components(component_names, &ApplicationComponentSelectMode::All)
             .await?;
 
         let components = self.components_for_deploy_args().await?;
-        self.ctx
-            .component_handler()
-            .update_workers_by_components(&components, update_mode, await_update, disable_wakeup)
-            .await?;
+        if update_check.dry_run {
+            self.ctx
+                .component_handler()
+                .check_workers_update_by_components(&components, &update_check)
+                .await?;
+        } else {
+            self.ctx
+                .component_handler()
+                .update_workers_by_components(
+                    &components,
+                    update_mode,
+                    await_update,
+                    disable_wakeup,
+                )
+                .await?;
+        }
 
         Ok(())
     }
@@ -863,6 +876,25 @@ impl AppCommandHandler {
                     log_unified_diff(&diff);
                 }
             }
+
+            if !unified_diffs.agent_breaking_changes.is_empty() {
+                log_warn_action("Detected", "breaking agent API changes:");
+                {
+                    let _indent = LogIndent::new();
+                    for change in &unified_diffs.agent_breaking_changes {
+                        log_warn(change);
+                    }
+                }
+                if deploy_diff
+                    .environment
+                    .server_environment
+                    .compatibility_check
+                {
+                    log_warn(
+                        "The environment has compatibility checks enabled, the deployment will be rejected",
+                    );
+                }
+            }
         }
 
         {
@@ -15,6 +15,11 @@
 use crate::command::cloud::CloudSubcommand;
 use crate::command_handler::Handlers;
 use crate::context::Context;
+use crate::error::service::AnyhowMapServiceError;
+use crate::model::environment::EnvironmentResolveMode;
+use crate::model::text::usage::EnvironmentUsageView;
+use chrono::{DateTime, TimeDelta, Utc};
+use golem_client::api::ReportsClient;
 use std::sync::Arc;
 
 pub mod account;
@@ -43,6 +48,36 @@ impl CloudCommandHandler {
                     .handle_command(subcommand)
                     .await
             }
+            CloudSubcommand::Usage { from, to } => self.cmd_usage(from, to).await,
         }
     }
+
+    async fn cmd_usage(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<()> {
+        let environment = self
+            .ctx
+            .environment_handler()
+            .resolve_environment(EnvironmentResolveMode::Any)
+            .await?;
+
+        let to = to.unwrap_or_else(Utc::now);
+        let from = from.unwrap_or(to - TimeDelta::days(1));
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let report = clients
+            .reports
+            .get_environment_usage_report(&environment.environment_id.0, &from, &to)
+            .await
+            .map_service_error()?;
+
+        self.ctx
+            .log_handler()
+            .log_view(&EnvironmentUsageView(report));
+
+        Ok(())
+    }
 }
@@ -16,7 +16,9 @@ use crate::app::context::{to_anyhow, BuildContext};
 
 use crate::app::build::extract_agent_type::extract_and_store_agent_types;
 use crate::command::component::ComponentSubcommand;
-use crate::command::shared_args::{ComponentTemplateName, OptionalComponentNames, PostDeployArgs};
+use crate::command::shared_args::{
+    ComponentTemplateName, OptionalComponentNames, PostDeployArgs, UpdateCheckArgs,
+};
 use crate::command_handler::component::ifs::IfsFileManager;
 use crate::command_handler::component::staging::ComponentStager;
 use crate::command_handler::Handlers;
@@ -30,7 +32,7 @@ use crate::model::component::{
     ComponentDeployProperties, ComponentNameMatchKind, ComponentRevisionSelection, ComponentView,
     SelectedComponents,
 };
-use crate::model::deploy::{DeployConfig, TryUpdateAllWorkersResult};
+use crate::model::deploy::{DeployConfig, TryUpdateAllWorkersResult, UpdateCheckResult};
 use crate::model::environment::{
     EnvironmentReference, EnvironmentResolveMode, ResolvedEnvironmentIdentity,
 };
@@ -94,12 +96,14 @@ impl ComponentCommandHandler {
                     update_mode,
                     r#await,
                     disable_wakeup,
+                    update_check,
                 } => {
                     self.cmd_update_workers(
                         component_name.component_name,
                         update_mode,
                         r#await,
                         disable_wakeup,
+                        update_check,
                     )
                     .await
                 }
@@ -329,10 +333,21 @@ impl ComponentCommandHandler {
         update_mode: AgentUpdateMode,
         await_update: bool,
         disable_wakeup: bool,
+        update_check: UpdateCheckArgs,
     ) -> anyhow::Result<()> {
         let components = self.components_for_deploy_args(component_name).await?;
-        self.update_workers_by_components(&components, update_mode, await_update, disable_wakeup)
+        if update_check.dry_run {
+            self.check_workers_update_by_components(&components, &update_check)
+                .await?;
+        } else {
+            self.update_workers_by_components(
+                &components,
+                update_mode,
+                await_update,
+                disable_wakeup,
+            )
             .await?;
+        }
 
         Ok(())
     }
@@ -474,6 +489,44 @@ impl ComponentCommandHandler {
         }
     }
 
+    pub async fn check_workers_update_by_components(
+        &self,
+        components: &[ComponentDto],
+        update_check: &UpdateCheckArgs,
+    ) -> anyhow::Result<()> {
+        if components.is_empty() {
+            return Ok(());
+        }
+
+        log_action(
+            "Checking",
+            "whether existing agents can be updated automatically",
+        );
+        let _indent = LogIndent::new();
+
+        let mut check_results = UpdateCheckResult::default();
+        for component in components {
+            let result = self
+                .ctx
+                .worker_handler()
+                .check_component_workers_update(
+                    &component.component_name,
+                    &component.id,
+                    update_check.to_request(component.revision)?,
+                )
+                .await?;
+            check_results.extend(result);
+        }
+
+        self.ctx.log_handler().log_view(&check_results);
+
+        if check_results.has_incompatible() {
+            bail!(NonSuccessfulExit)
+        } else {
+            Ok(())
+        }
+    }
+
     pub async fn redeploy_workers_by_components(
         &self,
         components: &[ComponentDto],
@@ -883,6 +936,7 @@ impl ComponentCommandHandler {
         let plugins = component.plugins().clone();
         let env = resolve_env_vars(component_name, component.env())?;
         let config_vars = component.config_vars().clone();
+        let agent_resource_limits = component.agent_resource_limits().clone();
 
         Ok(ComponentDeployProperties {
             wasm_path,
@@ -891,6 +945,7 @@ impl ComponentCommandHandler {
             plugins,
             env,
             config_vars,
+            agent_resource_limits,
         })
     }
 
@@ -992,6 +1047,11 @@ impl ComponentCommandHandler {
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
                     .collect(),
+                agent_resource_limits: properties
+                    .agent_resource_limits
+                    .iter()
+                    .map(|limits| (limits.agent.0.clone(), limits.into()))
+                    .collect(),
             }
             .into(),
             wasm_hash: component_binary_hash.into(),
@@ -1047,6 +1107,7 @@ impl ComponentCommandHandler {
                     config_vars: component_stager.config_vars(),
                     // FIXME: agent-config
                     local_agent_config: Vec::new(),
+                    agent_resource_limits: component_stager.agent_resource_limits(),
                     agent_types,
                     plugins: component_stager.plugins(),
                 },
@@ -1143,6 +1204,7 @@ impl ComponentCommandHandler {
                     config_vars: component_stager.config_vars_if_changed(),
                     // FIXME: agent-config
                     local_agent_config: None,
+                    agent_resource_limits: component_stager.agent_resource_limits_if_changed(),
                     env: component_stager.env_if_changed(),
                     agent_types,
                     plugin_updates: component_stager.plugins_if_changed(),
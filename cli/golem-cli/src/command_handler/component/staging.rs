@@ -22,8 +22,8 @@ use anyhow::{anyhow, Context as AnyhowContext};
 use golem_client::model::EnvironmentPluginGrantWithDetails;
 use golem_common::model::agent::AgentType;
 use golem_common::model::component::{
-    ComponentFileOptions, ComponentFilePath, PluginInstallation, PluginInstallationAction,
-    PluginInstallationUpdate, PluginPriority, PluginUninstallation,
+    AgentResourceLimits, ComponentFileOptions, ComponentFilePath, PluginInstallation,
+    PluginInstallationAction, PluginInstallationUpdate, PluginPriority, PluginUninstallation,
 };
 
 use golem_common::model::diff;
@@ -269,6 +269,20 @@ impl<'a> ComponentStager<'a> {
         }
     }
 
+    pub fn agent_resource_limits(&self) -> Vec<AgentResourceLimits> {
+        self.component_deploy_properties
+            .agent_resource_limits
+            .clone()
+    }
+
+    pub fn agent_resource_limits_if_changed(&self) -> Option<Vec<AgentResourceLimits>> {
+        if self.diff.metadata_changed() {
+            Some(self.agent_resource_limits())
+        } else {
+            None
+        }
+    }
+
     pub fn plugins(&self) -> Vec<PluginInstallation> {
         self.component_deploy_properties
             .plugins
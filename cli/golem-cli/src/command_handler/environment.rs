@@ -402,6 +402,7 @@ impl EnvironmentCommandHandler {
                         compatibility_check: Some(manifest_options.compatibility_check()),
                         version_check: Some(manifest_options.version_check()),
                         security_overrides: Some(manifest_options.security_overrides()),
+                        network_egress_policy: None,
                     },
                 )
                 .await
@@ -14,6 +14,8 @@
 
 #[cfg(feature = "server-commands")]
 use crate::command::server::ServerSubcommand;
+#[cfg(feature = "server-commands")]
+use crate::command::worker::AgentSubcommand;
 use crate::command::{
     GolemCliCommand, GolemCliCommandParseResult, GolemCliFallbackCommand, GolemCliGlobalFlags,
     GolemCliSubcommand,
@@ -36,10 +38,13 @@ use crate::command_handler::plugin::PluginCommandHandler;
 use crate::command_handler::profile::config::ProfileConfigCommandHandler;
 use crate::command_handler::profile::ProfileCommandHandler;
 use crate::command_handler::repl::ReplHandler;
+use crate::command_handler::top::TopCommandHandler;
 use crate::command_handler::worker::WorkerCommandHandler;
 use crate::context::Context;
 use crate::error::{ContextInitHintError, HintError, NonSuccessfulExit, PipedExitCode};
 use crate::log::{log_anyhow_error, logln, set_log_output, Output};
+#[cfg(feature = "server-commands")]
+use crate::model::oplog_replay::{OplogFixture, ReplayedOplog};
 use crate::{command_name, init_tracing};
 use anyhow::anyhow;
 use clap::CommandFactory;
@@ -47,6 +52,8 @@ use clap_complete::Shell;
 #[cfg(feature = "server-commands")]
 use clap_verbosity_flag::Verbosity;
 use std::ffi::OsString;
+#[cfg(feature = "server-commands")]
+use std::path::Path;
 use std::process::ExitCode;
 use std::sync::Arc;
 use tracing::{debug, Level};
@@ -63,6 +70,7 @@ mod partial_match;
 mod plugin;
 mod profile;
 mod repl;
+mod top;
 mod worker;
 
 // NOTE: We are explicitly not using #[async_trait] here to be able to NOT have a Send bound
@@ -85,6 +93,14 @@ pub trait CommandHandlerHooks: Sync + Send {
 
     #[cfg(feature = "server-commands")]
     fn override_pretty_mode() -> bool;
+
+    // Used for replaying a downloaded oplog in a sandboxed, in-process executor
+    #[cfg(feature = "server-commands")]
+    fn replay_oplog(
+        &self,
+        component_wasm: &Path,
+        fixture: &OplogFixture,
+    ) -> impl std::future::Future<Output = anyhow::Result<ReplayedOplog>>;
 }
 
 // CommandHandler is responsible for matching commands and producing CLI output using Context,
@@ -335,6 +351,7 @@ impl<Hooks: CommandHandlerHooks + 'static> CommandHandler<Hooks> {
                     update_mode,
                     r#await,
                     disable_wakeup,
+                    update_check,
                 } => {
                     self.ctx
                         .app_handler()
@@ -343,6 +360,7 @@ impl<Hooks: CommandHandlerHooks + 'static> CommandHandler<Hooks> {
                             update_mode,
                             r#await,
                             disable_wakeup,
+                            update_check,
                         )
                         .await
                 }
@@ -358,6 +376,16 @@ impl<Hooks: CommandHandlerHooks + 'static> CommandHandler<Hooks> {
                 GolemCliSubcommand::ListAgentTypes {} => {
                     self.ctx.app_handler().cmd_list_agent_types().await
                 }
+                GolemCliSubcommand::Top {
+                    component_name,
+                    filter,
+                    refresh,
+                } => {
+                    self.ctx
+                        .top_handler()
+                        .cmd_top(component_name.component_name, filter, refresh)
+                        .await
+                }
                 GolemCliSubcommand::Exec { subcommand } => {
                     self.ctx.app_handler().exec_custom_command(subcommand).await
                 }
@@ -375,6 +403,15 @@ impl<Hooks: CommandHandlerHooks + 'static> CommandHandler<Hooks> {
                         .handle_command(subcommand)
                         .await
                 }
+                #[cfg(feature = "server-commands")]
+                GolemCliSubcommand::Agent {
+                    subcommand: AgentSubcommand::Replay { fixture },
+                } => {
+                    self.ctx
+                        .worker_handler()
+                        .cmd_replay(fixture, self.hooks.as_ref())
+                        .await
+                }
                 GolemCliSubcommand::Agent { subcommand } => {
                     self.ctx.worker_handler().handle_command(subcommand).await
                 }
@@ -433,6 +470,7 @@ pub trait Handlers {
     fn profile_config_handler(&self) -> ProfileConfigCommandHandler;
     fn profile_handler(&self) -> ProfileCommandHandler;
     fn repl_handler(&self) -> ReplHandler;
+    fn top_handler(&self) -> TopCommandHandler;
     fn worker_handler(&self) -> WorkerCommandHandler;
 }
 
@@ -523,6 +561,10 @@ impl Handlers for Arc<Context> {
         ReplHandler::new(self.clone())
     }
 
+    fn top_handler(&self) -> TopCommandHandler {
+        TopCommandHandler::new(self.clone())
+    }
+
     fn worker_handler(&self) -> WorkerCommandHandler {
         WorkerCommandHandler::new(self.clone())
     }
@@ -24,7 +24,9 @@ use anyhow::{anyhow, Context as AnyhowContext};
 use golem_client::api::PluginClient;
 use golem_client::model::PluginRegistrationCreation;
 use golem_common::model::base64::Base64;
-use golem_common::model::plugin_registration::{OplogProcessorPluginSpec, PluginSpecDto};
+use golem_common::model::plugin_registration::{
+    ComponentTransformerPluginSpec, LibraryPluginSpec, OplogProcessorPluginSpec, PluginSpecDto,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -104,6 +106,19 @@ impl PluginCommandHandler {
                         component_revision: spec.component_revision,
                     })
                 }
+                PluginTypeSpecificManifest::ComponentTransformer(spec) => {
+                    PluginSpecDto::ComponentTransformer(ComponentTransformerPluginSpec {
+                        transform_url: spec.transform_url.clone(),
+                        component_id: spec.component_id.map(|id| id.into()),
+                        component_revision: spec.component_revision,
+                    })
+                }
+                PluginTypeSpecificManifest::Library(spec) => {
+                    PluginSpecDto::Library(LibraryPluginSpec {
+                        component_id: spec.component_id.into(),
+                        component_revision: spec.component_revision,
+                    })
+                }
             };
 
             let clients = self.ctx.golem_clients().await?;
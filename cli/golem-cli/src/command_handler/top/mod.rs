@@ -0,0 +1,384 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod ui;
+
+use crate::command_handler::top::ui::{AgentView, TopState};
+use crate::command_handler::worker::stream::WorkerConnection;
+use crate::command_handler::Handlers;
+use crate::context::Context;
+use crate::error::service::AnyhowMapServiceError;
+use crate::log::{LogOutput, Output};
+use crate::model::format::Format;
+use crate::model::worker::{AgentLogStreamOptions, WorkerMetadata, WorkerName};
+use chrono::Local;
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures_util::StreamExt;
+use golem_client::api::WorkerClient;
+use golem_client::model::ComponentDto;
+use golem_common::model::component::ComponentName;
+use golem_common::model::executor::ExecutorsSummary;
+use ratatui::DefaultTerminal;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+pub struct TopCommandHandler {
+    ctx: Arc<Context>,
+}
+
+/// Result of one refresh of the dashboard
+struct TopSnapshot {
+    agents: anyhow::Result<Vec<WorkerMetadata>>,
+    executors: anyhow::Result<ExecutorsSummary>,
+}
+
+impl TopSnapshot {
+    fn apply(self, state: &mut TopState) {
+        match self.agents {
+            Ok(agents) => {
+                state.set_agents(agents);
+                state.agents_error = None;
+            }
+            Err(error) => state.agents_error = Some(format!("{error:#}")),
+        }
+        state.executors = Some(self.executors.map_err(|error| format!("{error:#}")));
+        state.last_refresh = Some(Local::now());
+    }
+}
+
+enum TopAction {
+    Quit,
+    Continue,
+}
+
+impl TopCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    pub async fn cmd_top(
+        &self,
+        component_name: Option<ComponentName>,
+        filters: Vec<String>,
+        refresh: u64,
+    ) -> anyhow::Result<()> {
+        let components = self
+            .ctx
+            .worker_handler()
+            .deployed_components_by_app_dir_or_name(component_name.as_ref())
+            .await?;
+
+        // Regular log output would break the terminal UI
+        let _log_output = LogOutput::new(Output::TracingDebug);
+
+        let mut terminal = ratatui::init();
+        let result = self
+            .run(&mut terminal, &components, &filters, refresh)
+            .await;
+        ratatui::restore();
+
+        result
+    }
+
+    async fn run(
+        &self,
+        terminal: &mut DefaultTerminal,
+        components: &[ComponentDto],
+        filters: &[String],
+        refresh: u64,
+    ) -> anyhow::Result<()> {
+        let refresh_period = Duration::from_secs(refresh);
+        let mut refresh_interval = interval_at(Instant::now() + refresh_period, refresh_period);
+        refresh_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut state = TopState::new(refresh);
+        let mut events = EventStream::new();
+
+        let mut pending_refresh: Option<Pin<Box<dyn Future<Output = TopSnapshot> + '_>>> =
+            Some(Box::pin(self.snapshot(components, filters)));
+
+        loop {
+            terminal.draw(|frame| ui::render(frame, &mut state))?;
+
+            tokio::select! {
+                snapshot = async { pending_refresh.as_mut().unwrap().await },
+                    if pending_refresh.is_some() =>
+                {
+                    pending_refresh = None;
+                    snapshot.apply(&mut state);
+                }
+                _ = refresh_interval.tick(), if pending_refresh.is_none() => {
+                    pending_refresh = Some(Box::pin(self.snapshot(components, filters)));
+                }
+                Some(line) = async { state.agent_view.as_mut().unwrap().next_line().await },
+                    if state.agent_view.is_some() =>
+                {
+                    if let Some(agent_view) = &mut state.agent_view {
+                        agent_view.push_line(line);
+                    }
+                }
+                event = events.next() => {
+                    let Some(event) = event else {
+                        return Ok(());
+                    };
+                    if let Event::Key(key) = event? {
+                        let action = self
+                            .handle_key(&mut state, key, components)
+                            .await;
+                        if let TopAction::Quit = action {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn handle_key(
+        &self,
+        state: &mut TopState,
+        key: KeyEvent,
+        components: &[ComponentDto],
+    ) -> TopAction {
+        if key.kind != KeyEventKind::Press {
+            return TopAction::Continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return TopAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return TopAction::Quit
+            }
+            KeyCode::Esc => {
+                if state.agent_view.take().is_none() {
+                    return TopAction::Quit;
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') if state.agent_view.is_none() => {
+                state.select_previous()
+            }
+            KeyCode::Down | KeyCode::Char('j') if state.agent_view.is_none() => state.select_next(),
+            KeyCode::Enter if state.agent_view.is_none() => {
+                if let Some(agent) = state.selected_agent().cloned() {
+                    match self.open_agent_view(&agent, components).await {
+                        Ok(agent_view) => state.agent_view = Some(agent_view),
+                        Err(error) => state.status = Some(format!("{error:#}")),
+                    }
+                }
+            }
+            KeyCode::Char('i') => {
+                if let Some(agent) = Self::target_agent(state).cloned() {
+                    state.status = Some(match self.interrupt_agent(&agent, components).await {
+                        Ok(()) => format!("Interrupted {}", agent.worker_id.worker_name),
+                        Err(error) => format!("{error:#}"),
+                    });
+                }
+            }
+            KeyCode::Char('r') => {
+                if let Some(agent) = Self::target_agent(state).cloned() {
+                    state.status = Some(match self.resume_agent(&agent, components).await {
+                        Ok(()) => format!("Resumed {}", agent.worker_id.worker_name),
+                        Err(error) => format!("{error:#}"),
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        TopAction::Continue
+    }
+
+    /// The agent that interrupt and resume are applied to
+    fn target_agent(state: &TopState) -> Option<&WorkerMetadata> {
+        if state.agent_view.is_some() {
+            state.viewed_agent()
+        } else {
+            state.selected_agent()
+        }
+    }
+
+    async fn snapshot(&self, components: &[ComponentDto], filters: &[String]) -> TopSnapshot {
+        TopSnapshot {
+            agents: self.list_agents(components, filters).await,
+            executors: self.get_executors().await,
+        }
+    }
+
+    async fn list_agents(
+        &self,
+        components: &[ComponentDto],
+        filters: &[String],
+    ) -> anyhow::Result<Vec<WorkerMetadata>> {
+        let worker_handler = self.ctx.worker_handler();
+
+        let mut agents = Vec::new();
+        for component in components {
+            let (workers, _) = worker_handler
+                .list_component_workers(
+                    &component.component_name,
+                    &component.id,
+                    Some(filters),
+                    None,
+                    None,
+                    true,
+                )
+                .await?;
+            agents.extend(workers);
+        }
+
+        Ok(agents)
+    }
+
+    async fn get_executors(&self) -> anyhow::Result<ExecutorsSummary> {
+        let clients = self.ctx.golem_clients().await?;
+
+        clients.worker.get_executors().await.map_service_error()
+    }
+
+    async fn open_agent_view(
+        &self,
+        agent: &WorkerMetadata,
+        components: &[ComponentDto],
+    ) -> anyhow::Result<AgentView> {
+        let component = Self::component_of(agent, components)?;
+        let (lines_tx, lines_rx) = mpsc::unbounded_channel();
+
+        let connection = WorkerConnection::new(
+            self.ctx.worker_service_url().clone(),
+            self.ctx.auth_token().await?,
+            &component.id,
+            agent.worker_id.worker_name.clone(),
+            AgentLogStreamOptions {
+                colors: false,
+                show_timestamp: true,
+                show_level: true,
+                logs_only: false,
+            },
+            self.ctx.allow_insecure(),
+            Format::Text,
+            None,
+        )
+        .await?
+        .with_output_channel(lines_tx);
+
+        Ok(AgentView {
+            component_name: agent.component_name.clone(),
+            worker_id: agent.worker_id.clone(),
+            lines: VecDeque::new(),
+            lines_rx,
+            stream: tokio::spawn(connection.run_forever()),
+        })
+    }
+
+    async fn interrupt_agent(
+        &self,
+        agent: &WorkerMetadata,
+        components: &[ComponentDto],
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .worker_handler()
+            .interrupt_worker(
+                Self::component_of(agent, components)?,
+                &WorkerName(agent.worker_id.worker_name.clone()),
+                false,
+            )
+            .await
+    }
+
+    async fn resume_agent(
+        &self,
+        agent: &WorkerMetadata,
+        components: &[ComponentDto],
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .worker_handler()
+            .resume_worker(
+                Self::component_of(agent, components)?,
+                &WorkerName(agent.worker_id.worker_name.clone()),
+            )
+            .await
+    }
+
+    fn component_of<'a>(
+        agent: &WorkerMetadata,
+        components: &'a [ComponentDto],
+    ) -> anyhow::Result<&'a ComponentDto> {
+        components
+            .iter()
+            .find(|component| component.id == agent.worker_id.component_id)
+            .ok_or_else(|| anyhow::anyhow!("Component not found: {}", agent.component_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::top::ui::tests::agent;
+    use crate::command_handler::top::ui::TopState;
+    use crate::command_handler::top::TopSnapshot;
+    use anyhow::anyhow;
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::executor::ExecutorsSummary;
+    use test_r::test;
+
+    #[test]
+    fn failed_agent_listing_keeps_the_previous_agents() {
+        let component_id = ComponentId::new();
+        let mut state = TopState::new(2);
+
+        TopSnapshot {
+            agents: Ok(vec![agent(component_id, "a", "a-1", 0)]),
+            executors: Ok(ExecutorsSummary {
+                number_of_shards: 4,
+                executors: vec![],
+            }),
+        }
+        .apply(&mut state);
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents_error, None);
+        assert_eq!(
+            state
+                .executors
+                .as_ref()
+                .map(|executors| executors.as_ref().map(|summary| summary.number_of_shards)),
+            Some(Ok(4))
+        );
+        let first_refresh = state.last_refresh.unwrap();
+
+        TopSnapshot {
+            agents: Err(anyhow!("listing failed")),
+            executors: Err(anyhow!("executors unavailable")),
+        }
+        .apply(&mut state);
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents_error, Some("listing failed".to_string()));
+        assert!(matches!(
+            &state.executors,
+            Some(Err(error)) if error == "executors unavailable"
+        ));
+        assert!(state.last_refresh.unwrap() >= first_refresh);
+
+        TopSnapshot {
+            agents: Ok(vec![]),
+            executors: Err(anyhow!("executors unavailable")),
+        }
+        .apply(&mut state);
+        assert!(state.agents.is_empty());
+        assert_eq!(state.agents_error, None);
+    }
+}
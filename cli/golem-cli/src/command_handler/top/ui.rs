@@ -0,0 +1,553 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command_handler::worker::stream_output::WorkerStreamLine;
+use crate::model::text::fmt::format_binary_size;
+use crate::model::worker::WorkerMetadata;
+use chrono::{DateTime, Local};
+use golem_common::model::component::ComponentName;
+use golem_common::model::executor::ExecutorsSummary;
+use golem_common::model::{LogLevel, WorkerId, WorkerStatus};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::Frame;
+use std::collections::VecDeque;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Number of log lines kept for the selected agent
+const MAX_LOG_LINES: usize = 1000;
+
+pub struct TopState {
+    pub refresh_interval_secs: u64,
+    pub agents: Vec<WorkerMetadata>,
+    pub agents_error: Option<String>,
+    pub executors: Option<Result<ExecutorsSummary, String>>,
+    pub last_refresh: Option<DateTime<Local>>,
+    pub table: TableState,
+    /// Result of the last interrupt or resume action
+    pub status: Option<String>,
+    pub agent_view: Option<AgentView>,
+}
+
+impl TopState {
+    pub fn new(refresh_interval_secs: u64) -> Self {
+        Self {
+            refresh_interval_secs,
+            agents: Vec::new(),
+            agents_error: None,
+            executors: None,
+            last_refresh: None,
+            table: TableState::default(),
+            status: None,
+            agent_view: None,
+        }
+    }
+
+    /// Replaces the listed agents, keeping the selection on the same agent if it still exists
+    pub fn set_agents(&mut self, mut agents: Vec<WorkerMetadata>) {
+        let selected_worker_id = self.selected_agent().map(|agent| agent.worker_id.clone());
+
+        agents.sort_by(|a, b| {
+            b.pending_invocation_count
+                .cmp(&a.pending_invocation_count)
+                .then_with(|| a.component_name.0.cmp(&b.component_name.0))
+                .then_with(|| a.worker_id.worker_name.cmp(&b.worker_id.worker_name))
+        });
+        self.agents = agents;
+
+        let selected = selected_worker_id
+            .and_then(|worker_id| {
+                self.agents
+                    .iter()
+                    .position(|agent| agent.worker_id == worker_id)
+            })
+            .or(if self.agents.is_empty() {
+                None
+            } else {
+                Some(0)
+            });
+        self.table.select(selected);
+    }
+
+    pub fn selected_agent(&self) -> Option<&WorkerMetadata> {
+        self.table
+            .selected()
+            .and_then(|selected| self.agents.get(selected))
+    }
+
+    /// The agent shown in the agent view, with its latest known metadata
+    pub fn viewed_agent(&self) -> Option<&WorkerMetadata> {
+        self.agent_view.as_ref().and_then(|view| {
+            self.agents
+                .iter()
+                .find(|agent| agent.worker_id == view.worker_id)
+        })
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.agents.is_empty() {
+            let next = self
+                .table
+                .selected()
+                .map_or(0, |selected| (selected + 1).min(self.agents.len() - 1));
+            self.table.select(Some(next));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.agents.is_empty() {
+            let previous = self
+                .table
+                .selected()
+                .map_or(0, |selected| selected.saturating_sub(1));
+            self.table.select(Some(previous));
+        }
+    }
+}
+
+/// Drill-down view of a single agent, streaming its output
+pub struct AgentView {
+    pub component_name: ComponentName,
+    pub worker_id: WorkerId,
+    pub lines: VecDeque<WorkerStreamLine>,
+    pub lines_rx: mpsc::UnboundedReceiver<WorkerStreamLine>,
+    pub stream: JoinHandle<()>,
+}
+
+impl AgentView {
+    pub async fn next_line(&mut self) -> Option<WorkerStreamLine> {
+        self.lines_rx.recv().await
+    }
+
+    pub fn push_line(&mut self, line: WorkerStreamLine) {
+        if self.lines.len() == MAX_LOG_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+impl Drop for AgentView {
+    fn drop(&mut self) {
+        self.stream.abort();
+    }
+}
+
+pub fn render(frame: &mut Frame, state: &mut TopState) {
+    let executors_height = match &state.executors {
+        Some(Ok(executors)) => executors.executors.len().clamp(1, 8) as u16 + 3,
+        _ => 3,
+    };
+    let [header_area, executors_area, main_area, footer_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(executors_height),
+        Constraint::Min(5),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    render_header(frame, header_area, state);
+    render_executors(frame, executors_area, state);
+    if state.agent_view.is_some() {
+        render_agent_view(frame, main_area, state);
+    } else {
+        render_agents(frame, main_area, state);
+    }
+    render_footer(frame, footer_area, state);
+}
+
+fn render_header(frame: &mut Frame, area: Rect, state: &TopState) {
+    let refreshed = match &state.last_refresh {
+        Some(last_refresh) => format!("refreshed at {}", last_refresh.format("%H:%M:%S")),
+        None => "loading...".to_string(),
+    };
+    let mut spans = vec![
+        Span::styled("golem top", Style::new().bold()),
+        Span::raw(format!(
+            " - {} agents, {}, every {}s",
+            state.agents.len(),
+            refreshed,
+            state.refresh_interval_secs
+        )),
+    ];
+    if let Some(status) = &state.status {
+        spans.push(Span::raw(" - "));
+        spans.push(Span::styled(status.clone(), Style::new().yellow()));
+    }
+    frame.render_widget(Line::from(spans), area);
+}
+
+fn render_executors(frame: &mut Frame, area: Rect, state: &TopState) {
+    let block = Block::bordered().title(" Executors ");
+    match &state.executors {
+        Some(Ok(executors)) => {
+            let rows = executors.executors.iter().map(|executor| {
+                Row::new(vec![
+                    format!("{}:{}", executor.host, executor.port),
+                    executor.shard_count.to_string(),
+                ])
+            });
+            let table = Table::new(rows, [Constraint::Min(30), Constraint::Length(10)])
+                .header(
+                    Row::new(vec!["Executor".to_string(), "Shards".to_string()])
+                        .style(Style::new().bold()),
+                )
+                .block(
+                    block.title_bottom(format!(" {} shards in total ", executors.number_of_shards)),
+                );
+            frame.render_widget(table, area);
+        }
+        Some(Err(error)) => {
+            frame.render_widget(
+                Paragraph::new(format!("Not available: {error}"))
+                    .style(Style::new().dark_gray())
+                    .block(block),
+                area,
+            );
+        }
+        None => {
+            frame.render_widget(Paragraph::new("Loading...").block(block), area);
+        }
+    }
+}
+
+fn render_agents(frame: &mut Frame, area: Rect, state: &mut TopState) {
+    let block = Block::bordered().title(" Agents ");
+
+    if let Some(error) = &state.agents_error {
+        frame.render_widget(
+            Paragraph::new(error.clone())
+                .style(Style::new().red())
+                .wrap(Wrap { trim: false })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let rows = state.agents.iter().map(|agent| {
+        Row::new(vec![
+            Line::from(agent.component_name.0.clone()),
+            Line::from(agent.worker_id.worker_name.clone()),
+            Line::styled(agent.status.to_string(), status_style(&agent.status)),
+            Line::from(agent.pending_invocation_count.to_string()),
+            Line::from(format_binary_size(&agent.total_linear_memory_size)),
+            Line::styled(
+                agent.retry_count.to_string(),
+                if agent.retry_count == 0 {
+                    Style::new()
+                } else {
+                    Style::new().yellow()
+                },
+            ),
+            Line::styled(
+                agent
+                    .last_error
+                    .as_deref()
+                    .and_then(|error| error.lines().next())
+                    .unwrap_or_default()
+                    .to_string(),
+                Style::new().red(),
+            ),
+        ])
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(15),
+            Constraint::Percentage(30),
+            Constraint::Length(11),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            "Component",
+            "Agent",
+            "Status",
+            "Pending",
+            "Memory",
+            "Retries",
+            "Last error",
+        ])
+        .style(Style::new().bold()),
+    )
+    .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+    .block(block);
+
+    frame.render_stateful_widget(table, area, &mut state.table);
+}
+
+fn render_agent_view(frame: &mut Frame, area: Rect, state: &TopState) {
+    let Some(view) = &state.agent_view else {
+        return;
+    };
+
+    let [details_area, logs_area] =
+        Layout::vertical([Constraint::Length(4), Constraint::Min(3)]).areas(area);
+
+    let details = match state.viewed_agent() {
+        Some(agent) => vec![
+            Line::from(vec![
+                Span::raw("Status: "),
+                Span::styled(agent.status.to_string(), status_style(&agent.status)),
+                Span::raw(format!(
+                    "  Pending invocations: {}  Memory: {}  Retries: {}",
+                    agent.pending_invocation_count,
+                    format_binary_size(&agent.total_linear_memory_size),
+                    agent.retry_count
+                )),
+            ]),
+            Line::styled(
+                format!(
+                    "Last error: {}",
+                    agent
+                        .last_error
+                        .as_deref()
+                        .and_then(|error| error.lines().next())
+                        .unwrap_or("-")
+                ),
+                Style::new().red(),
+            ),
+        ],
+        None => vec![Line::styled(
+            "The agent is not listed anymore",
+            Style::new().dark_gray(),
+        )],
+    };
+    frame.render_widget(
+        Paragraph::new(details).block(Block::bordered().title(format!(
+            " {}/{} ",
+            view.component_name, view.worker_id.worker_name
+        ))),
+        details_area,
+    );
+
+    // Only the last lines fitting into the view are rendered, so the latest output is always visible
+    let visible_lines = logs_area.height.saturating_sub(2) as usize;
+    let lines = view
+        .lines
+        .iter()
+        .skip(view.lines.len().saturating_sub(visible_lines))
+        .map(|line| Line::styled(line.line.clone(), log_level_style(line.level)))
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Output ")),
+        logs_area,
+    );
+}
+
+fn render_footer(frame: &mut Frame, area: Rect, state: &TopState) {
+    let keys = if state.agent_view.is_some() {
+        "esc: back  i: interrupt  r: resume  q: quit"
+    } else {
+        "↑/↓: select  enter: stream output  i: interrupt  r: resume  q: quit"
+    };
+    frame.render_widget(Line::styled(keys, Style::new().dark_gray()), area);
+}
+
+fn status_style(status: &WorkerStatus) -> Style {
+    match status {
+        WorkerStatus::Running => Style::new().green(),
+        WorkerStatus::Idle => Style::new().cyan(),
+        WorkerStatus::Suspended => Style::new().yellow(),
+        WorkerStatus::Interrupted => Style::new().red(),
+        WorkerStatus::Retrying => Style::new().yellow(),
+        WorkerStatus::Failed => Style::new().light_red(),
+        WorkerStatus::Exited => Style::new().white(),
+    }
+}
+
+fn log_level_style(level: LogLevel) -> Style {
+    match level {
+        LogLevel::Trace => Style::new().blue(),
+        LogLevel::Debug => Style::new().green(),
+        LogLevel::Info => Style::new(),
+        LogLevel::Warn => Style::new().yellow(),
+        LogLevel::Error => Style::new().red(),
+        LogLevel::Critical => Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use crate::command_handler::top::ui::{AgentView, TopState, MAX_LOG_LINES};
+    use crate::command_handler::worker::stream_output::WorkerStreamLine;
+    use crate::model::worker::WorkerMetadata;
+    use golem_common::model::account::AccountId;
+    use golem_common::model::component::{ComponentId, ComponentName, ComponentRevision};
+    use golem_common::model::environment::EnvironmentId;
+    use golem_common::model::{LogLevel, Timestamp, WorkerId, WorkerStatus};
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use test_r::test;
+    use tokio::sync::mpsc;
+
+    pub fn agent(
+        component_id: ComponentId,
+        component_name: &str,
+        worker_name: &str,
+        pending_invocation_count: u64,
+    ) -> WorkerMetadata {
+        WorkerMetadata {
+            worker_id: WorkerId {
+                component_id,
+                worker_name: worker_name.to_string(),
+            },
+            component_name: ComponentName(component_name.to_string()),
+            environment_id: EnvironmentId::new(),
+            created_by: AccountId::new(),
+            env: HashMap::new(),
+            config_vars: BTreeMap::new(),
+            status: WorkerStatus::Idle,
+            component_revision: ComponentRevision::INITIAL,
+            retry_count: 0,
+            pending_invocation_count,
+            updates: Vec::new(),
+            created_at: Timestamp::from(0),
+            last_error: None,
+            component_size: 0,
+            total_linear_memory_size: 0,
+            exported_resource_instances: HashMap::new(),
+        }
+    }
+
+    fn worker_names(state: &TopState) -> Vec<&str> {
+        state
+            .agents
+            .iter()
+            .map(|agent| agent.worker_id.worker_name.as_str())
+            .collect()
+    }
+
+    fn selected_worker_name(state: &TopState) -> Option<&str> {
+        state
+            .selected_agent()
+            .map(|agent| agent.worker_id.worker_name.as_str())
+    }
+
+    #[test]
+    fn agents_are_ordered_by_pending_invocations_then_names() {
+        let component_id = ComponentId::new();
+        let mut state = TopState::new(2);
+        state.set_agents(vec![
+            agent(component_id, "b", "b-1", 0),
+            agent(component_id, "a", "a-2", 0),
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "c", "c-1", 5),
+        ]);
+
+        assert_eq!(worker_names(&state), vec!["c-1", "a-1", "a-2", "b-1"]);
+        assert_eq!(selected_worker_name(&state), Some("c-1"));
+    }
+
+    #[test]
+    fn selection_follows_the_selected_agent_between_refreshes() {
+        let component_id = ComponentId::new();
+        let mut state = TopState::new(2);
+        state.set_agents(vec![
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "a", "a-2", 0),
+        ]);
+        state.select_next();
+        assert_eq!(selected_worker_name(&state), Some("a-2"));
+
+        state.set_agents(vec![
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "a", "a-2", 3),
+        ]);
+        assert_eq!(worker_names(&state), vec!["a-2", "a-1"]);
+        assert_eq!(selected_worker_name(&state), Some("a-2"));
+
+        // The first agent is selected when the selected one is gone
+        state.set_agents(vec![
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "a", "a-3", 0),
+        ]);
+        assert_eq!(selected_worker_name(&state), Some("a-1"));
+
+        state.set_agents(vec![]);
+        assert_eq!(state.table.selected(), None);
+        assert!(state.selected_agent().is_none());
+    }
+
+    #[test]
+    fn moving_the_selection_stays_within_the_agents() {
+        let component_id = ComponentId::new();
+        let mut state = TopState::new(2);
+        state.select_next();
+        state.select_previous();
+        assert_eq!(state.table.selected(), None);
+
+        state.set_agents(vec![
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "a", "a-2", 0),
+        ]);
+        state.select_previous();
+        assert_eq!(selected_worker_name(&state), Some("a-1"));
+        state.select_next();
+        state.select_next();
+        assert_eq!(selected_worker_name(&state), Some("a-2"));
+        state.select_previous();
+        assert_eq!(selected_worker_name(&state), Some("a-1"));
+    }
+
+    #[test]
+    async fn agent_view_shows_the_latest_metadata_and_log_lines() {
+        let component_id = ComponentId::new();
+        let viewed = agent(component_id, "a", "a-2", 0);
+        let (_lines_tx, lines_rx) = mpsc::unbounded_channel();
+
+        let mut state = TopState::new(2);
+        state.agent_view = Some(AgentView {
+            component_name: viewed.component_name.clone(),
+            worker_id: viewed.worker_id.clone(),
+            lines: VecDeque::new(),
+            lines_rx,
+            stream: tokio::spawn(async {}),
+        });
+        assert!(state.viewed_agent().is_none());
+
+        state.set_agents(vec![
+            agent(component_id, "a", "a-1", 0),
+            agent(component_id, "a", "a-2", 7),
+        ]);
+        assert_eq!(
+            state
+                .viewed_agent()
+                .map(|agent| agent.pending_invocation_count),
+            Some(7)
+        );
+
+        let agent_view = state.agent_view.as_mut().unwrap();
+        for idx in 0..MAX_LOG_LINES + 2 {
+            agent_view.push_line(WorkerStreamLine {
+                level: LogLevel::Info,
+                line: idx.to_string(),
+            });
+        }
+        assert_eq!(agent_view.lines.len(), MAX_LOG_LINES);
+        assert_eq!(agent_view.lines.front().unwrap().line, "2");
+        assert_eq!(
+            agent_view.lines.back().unwrap().line,
+            (MAX_LOG_LINES + 1).to_string()
+        );
+    }
+}
@@ -12,27 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod stream;
-mod stream_output;
+mod oplog_follow;
+pub mod stream;
+pub mod stream_output;
 
 use crate::command::shared_args::{
     AgentIdArgs, PostDeployArgs, StreamArgs, WorkerFunctionArgument, WorkerFunctionName,
 };
-use crate::command::worker::AgentSubcommand;
+use crate::command::worker::{
+    AgentBulkSubcommand, AgentQueueSubcommand, AgentRecurringSubcommand, AgentSubcommand,
+    BulkTargetArgs,
+};
+use crate::command_handler::worker::oplog_follow::OplogFollower;
 use crate::command_handler::worker::stream::WorkerConnection;
+#[cfg(feature = "server-commands")]
+use crate::command_handler::CommandHandlerHooks;
 use crate::command_handler::Handlers;
 use crate::context::Context;
 use crate::error::service::{AnyhowMapServiceError, ServiceError};
 use crate::error::NonSuccessfulExit;
+use crate::fs;
 use crate::fuzzy::{Error, FuzzySearch};
 use crate::log::{
     log_action, log_error, log_error_action, log_failed_to, log_warn, log_warn_action, logln,
     LogColorize, LogIndent,
 };
 use crate::model::app::ApplicationComponentSelectMode;
+#[cfg(feature = "server-commands")]
+use crate::model::app::BuildConfig;
 use crate::model::component::{show_exported_agent_constructors, ComponentNameMatchKind};
-use crate::model::deploy::{TryUpdateAllWorkersResult, WorkerUpdateAttempt};
+use crate::model::deploy::{
+    TryUpdateAllWorkersResult, UpdateCheckResult, WorkerUpdateAttempt, WorkerUpdateCheck,
+};
 use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::oplog_export::{OplogEntryKindFilter, OplogExportFormat, OplogExporter};
+use crate::model::oplog_replay::OplogFixture;
+#[cfg(feature = "server-commands")]
+use crate::model::oplog_replay::{find_first_divergence, replay_steps, OplogReplayReport};
 use crate::model::text::fmt::{log_fuzzy_match, log_text_view};
 use crate::model::text::help::{
     ArgumentError, AvailableAgentConstructorsHelp, AvailableComponentNamesHelp,
@@ -63,15 +79,24 @@ use golem_common::model::agent::{
     ElementValue, ElementValues, UntypedJsonDataValue,
 };
 use golem_common::model::application::ApplicationName;
+use golem_common::model::bulk_operation::{
+    BulkDelete, BulkInterrupt, BulkOperationKind, BulkOperationRequest, BulkResume, BulkUpdate,
+};
 use golem_common::model::component::ComponentName;
 use golem_common::model::component::{ComponentId, ComponentRevision};
 use golem_common::model::component_metadata::{
     ParsedFunctionName, ParsedFunctionReference, ParsedFunctionSite,
 };
 use golem_common::model::environment::EnvironmentName;
-use golem_common::model::oplog::{OplogCursor, PublicOplogEntry};
-use golem_common::model::worker::{RevertLastInvocations, RevertToOplogIndex, UpdateRecord};
-use golem_common::model::{IdempotencyKey, OplogIndex};
+use golem_common::model::invocation_queue::{
+    InvocationPriority, InvocationQueueLimit, InvocationQueuePurge, InvocationQueueReorder,
+};
+use golem_common::model::oplog::{OplogCursor, PublicOplogEntryWithIndex};
+use golem_common::model::recurring_invocation::{RecurringInvocationCreation, RecurringSchedule};
+use golem_common::model::worker::{
+    RevertLastInvocations, RevertToOplogIndex, UpdateCheckRequest, UpdateRecord, WorkerUpdateMode,
+};
+use golem_common::model::{IdempotencyKey, OplogIndex, WorkerFilter};
 use golem_wasm::analysis::AnalysedType;
 use golem_wasm::{parse_value_and_type, ValueAndType};
 use inquire::Confirm;
@@ -79,7 +104,7 @@ use itertools::{EitherOrBoth, Itertools};
 use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -117,6 +142,7 @@ impl WorkerCommandHandler {
                     stream_args,
                     post_deploy_args,
                     schedule_at,
+                    priority,
                 } => {
                     self.cmd_invoke(
                         worker_name,
@@ -128,6 +154,7 @@ impl WorkerCommandHandler {
                         stream_args,
                         post_deploy_args,
                         schedule_at,
+                        priority,
                     )
                     .await
                 }
@@ -204,7 +231,23 @@ impl WorkerCommandHandler {
                     agent_id: worker_name,
                     from,
                     query,
-                } => self.cmd_oplog(worker_name, from, query).await,
+                    follow,
+                    kinds,
+                    export,
+                    output,
+                } => {
+                    self.cmd_oplog(worker_name, from, query, follow, kinds, export, output)
+                        .await
+                }
+                AgentSubcommand::DownloadOplog {
+                    agent_id: worker_name,
+                    output,
+                } => self.cmd_download_oplog(worker_name, output).await,
+                #[cfg(feature = "server-commands")]
+                AgentSubcommand::Replay { .. } => {
+                    // Replaying needs the sandboxed executor of the command handler hooks
+                    unreachable!("agent replay is dispatched by CommandHandler")
+                }
                 AgentSubcommand::Revert {
                     agent_id: worker_name,
                     last_oplog_index,
@@ -220,6 +263,9 @@ impl WorkerCommandHandler {
                     self.cmd_cancel_invocation(worker_name, idempotency_key)
                         .await
                 }
+                AgentSubcommand::Recurring { subcommand } => self.cmd_recurring(subcommand).await,
+                AgentSubcommand::Queue { subcommand } => self.cmd_queue(subcommand).await,
+                AgentSubcommand::Bulk { subcommand } => self.cmd_bulk(subcommand).await,
                 AgentSubcommand::Files { worker_name, path } => {
                     self.cmd_files(worker_name, path).await
                 }
@@ -291,6 +337,7 @@ impl WorkerCommandHandler {
         stream_args: StreamArgs,
         post_deploy_args: Option<PostDeployArgs>,
         schedule_at: Option<DateTime<Utc>>,
+        priority: Option<InvocationPriority>,
     ) -> anyhow::Result<()> {
         self.ctx.silence_app_context_init().await;
 
@@ -347,58 +394,8 @@ impl WorkerCommandHandler {
         let (agent_id, agent_type) =
             agent_id_and_type.ok_or_else(|| anyhow!("Agent invoke requires an agent component"))?;
 
-        // If the function name is fully qualified (e.g., "rust:agent/foo-agent.{fun-string}"),
-        // extract the simple method name for fuzzy matching.
-        let method_pattern = extract_simple_method_name(function_name);
-
-        let matched_method_name = resolve_agent_method_name(&method_pattern, &agent_type);
-        let method_name = match matched_method_name {
-            Ok(match_) => {
-                log_fuzzy_match(&match_);
-                match_.option
-            }
-            Err(error) => match error {
-                Error::Ambiguous {
-                    highlighted_options,
-                    ..
-                } => {
-                    logln("");
-                    log_error(format!(
-                        "The requested method name ({}) is ambiguous.",
-                        function_name.log_color_error_highlight()
-                    ));
-                    logln("");
-                    logln("Did you mean one of");
-                    for option in highlighted_options {
-                        logln(format!(" - {}", option.bold()));
-                    }
-                    logln("?");
-                    logln("");
-                    log_text_view(&AvailableFunctionNamesHelp::new_agent(
-                        &component,
-                        &agent_id,
-                        &agent_type,
-                    ));
-
-                    bail!(NonSuccessfulExit);
-                }
-                Error::NotFound { .. } => {
-                    logln("");
-                    log_error(format!(
-                        "The requested method name ({}) was not found.",
-                        function_name.log_color_error_highlight()
-                    ));
-                    logln("");
-                    log_text_view(&AvailableFunctionNamesHelp::new_agent(
-                        &component,
-                        &agent_id,
-                        &agent_type,
-                    ));
-
-                    bail!(NonSuccessfulExit);
-                }
-            },
-        };
+        let method_name =
+            self.match_agent_method_name(&component, &agent_id, &agent_type, function_name)?;
 
         // Update worker_name with normalized agent id
         let worker_name_match = WorkerNameMatch {
@@ -466,6 +463,7 @@ impl WorkerCommandHandler {
             method_parameters,
             mode,
             schedule_at,
+            priority,
             idempotency_key: Some(idempotency_key.value.clone()),
             deployment_revision: None,
             owner_account_email: None,
@@ -625,60 +623,217 @@ impl WorkerCommandHandler {
         worker_name: AgentIdArgs,
         from: Option<u64>,
         query: Option<String>,
+        follow: bool,
+        kinds: Vec<String>,
+        export: Option<OplogExportFormat>,
+        output: Option<PathBuf>,
     ) -> anyhow::Result<()> {
         self.ctx.silence_app_context_init().await;
+
+        if follow && export == Some(OplogExportFormat::Parquet) {
+            bail!("Parquet export cannot be combined with --follow, as the file is only complete when the export finishes");
+        }
+
         let worker_name_match = self.match_worker_name(worker_name.agent_id).await?;
         let (component, worker_name) = self
             .component_by_worker_name_match(&worker_name_match)
             .await?;
 
+        let kind_filter = OplogEntryKindFilter::new(kinds);
+        let mut exporter = export
+            .map(|format| OplogExporter::new(format, output.as_deref()))
+            .transpose()?;
+        let mut entry_count = 0;
+
+        let mut show_entries = |entries: Vec<PublicOplogEntryWithIndex>| -> anyhow::Result<()> {
+            let entries = entries
+                .into_iter()
+                .filter(|entry| kind_filter.matches(&entry.entry))
+                .collect::<Vec<_>>();
+            if entries.is_empty() {
+                return Ok(());
+            }
+            entry_count += entries.len();
+
+            match &mut exporter {
+                Some(exporter) => exporter.write(&entries),
+                None => {
+                    let entries = entries
+                        .into_iter()
+                        .map(|entry| (entry.oplog_index.as_u64(), entry.entry))
+                        .collect::<Vec<_>>();
+                    self.ctx.log_handler().log_view(&entries);
+                    Ok(())
+                }
+            }
+        };
+
+        if follow {
+            let follower = OplogFollower::new(
+                self.ctx.worker_service_url().clone(),
+                self.ctx.auth_token().await?,
+                &component.id,
+                &worker_name.0,
+                from.unwrap_or(OplogIndex::INITIAL.as_u64()),
+                self.ctx.allow_insecure(),
+            )?;
+            return follower.run_forever(show_entries).await;
+        }
+
         let batch_size = self.ctx.http_batch_size();
         let mut cursor = Option::<OplogCursor>::None;
-        let mut had_entries = false;
         loop {
-            let mut entries = Vec::<(u64, PublicOplogEntry)>::new();
-            cursor = {
-                let clients = self.ctx.golem_clients().await?;
+            let clients = self.ctx.golem_clients().await?;
 
-                let result = clients
-                    .worker
-                    .get_oplog(
-                        &component.id.0,
-                        &worker_name.0,
-                        from,
-                        batch_size,
-                        cursor.as_ref(),
-                        query.as_deref(),
-                    )
-                    .await
-                    .map_service_error()?;
+            let result = clients
+                .worker
+                .get_oplog(
+                    &component.id.0,
+                    &worker_name.0,
+                    from,
+                    batch_size,
+                    cursor.as_ref(),
+                    query.as_deref(),
+                )
+                .await
+                .map_service_error()?;
 
-                entries.extend(
-                    result
-                        .entries
-                        .into_iter()
-                        .map(|entry| (entry.oplog_index.as_u64(), entry.entry)),
-                );
-                result.next
-            };
-
-            if !entries.is_empty() {
-                had_entries = true;
-                self.ctx.log_handler().log_view(&entries);
-            }
+            show_entries(result.entries)?;
 
+            cursor = result.next;
             if cursor.is_none() {
                 break;
             }
         }
 
-        if !had_entries {
+        if let Some(exporter) = exporter {
+            exporter.finish()?;
+            if let Some(output) = &output {
+                log_action(
+                    "Exported",
+                    format!(
+                        "{} oplog entries to {}",
+                        entry_count,
+                        output.display().to_string().log_color_highlight()
+                    ),
+                );
+            }
+        } else if entry_count == 0 {
             log_warn("No results.")
         }
 
         Ok(())
     }
 
+    async fn cmd_download_oplog(
+        &self,
+        worker_name: AgentIdArgs,
+        output: PathBuf,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.agent_id).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_action(
+            "Downloading",
+            format!(
+                "oplog of agent {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+
+        let fixture = OplogFixture {
+            component_name: component.component_name.clone(),
+            agent_id: worker_name.0.clone(),
+            entries: self.get_full_oplog(&component, &worker_name).await?,
+        };
+
+        fs::write_str(&output, serde_json::to_string_pretty(&fixture)?)?;
+
+        log_action(
+            "Saved",
+            format!(
+                "{} oplog entries to {}",
+                fixture.entries.len(),
+                output.log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "server-commands")]
+    pub async fn cmd_replay<Hooks: CommandHandlerHooks>(
+        &self,
+        fixture_path: PathBuf,
+        hooks: &Hooks,
+    ) -> anyhow::Result<()> {
+        let fixture: OplogFixture = serde_json::from_str(&fs::read_to_string(&fixture_path)?)
+            .with_context(|| {
+                format!(
+                    "Failed to parse oplog fixture {}",
+                    fixture_path.log_color_highlight()
+                )
+            })?;
+
+        self.ctx
+            .app_handler()
+            .build(
+                &BuildConfig::new(),
+                vec![fixture.component_name.clone()],
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let component_wasm = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            app_ctx
+                .application()
+                .component(&fixture.component_name)
+                .final_wasm()
+        };
+
+        log_action(
+            "Replaying",
+            format!(
+                "oplog of agent {} in a sandbox",
+                fixture.agent_id.log_color_highlight()
+            ),
+        );
+
+        let replayed = {
+            let _indent = LogIndent::new();
+            hooks.replay_oplog(&component_wasm, &fixture).await?
+        };
+
+        let expected = fixture.replay_steps();
+        let actual = replay_steps(&replayed.entries);
+        let divergence = find_first_divergence(&expected, &actual);
+
+        let report = OplogReplayReport {
+            recorded_agent_id: fixture.agent_id.clone(),
+            replayed_agent_id: replayed.agent_id,
+            replayed_invocations: replayed.invocations,
+            compared_steps: divergence
+                .as_ref()
+                .map(|divergence| divergence.step + 1)
+                .unwrap_or_else(|| expected.len()),
+            divergence,
+        };
+
+        logln("");
+        self.ctx.log_handler().log_view(&report);
+
+        if report.divergence.is_some() {
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
     async fn cmd_revert(
         &self,
         worker_name: AgentIdArgs,
@@ -738,6 +893,63 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    fn match_agent_method_name(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        agent_type: &AgentType,
+        function_name: &WorkerFunctionName,
+    ) -> anyhow::Result<String> {
+        // If the function name is fully qualified (e.g., "rust:agent/foo-agent.{fun-string}"),
+        // extract the simple method name for fuzzy matching.
+        let method_pattern = extract_simple_method_name(function_name);
+
+        let matched_method_name = resolve_agent_method_name(&method_pattern, agent_type);
+        match matched_method_name {
+            Ok(match_) => {
+                log_fuzzy_match(&match_);
+                Ok(match_.option)
+            }
+            Err(error) => match error {
+                Error::Ambiguous {
+                    highlighted_options,
+                    ..
+                } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested method name ({}) is ambiguous.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    logln("Did you mean one of");
+                    for option in highlighted_options {
+                        logln(format!(" - {}", option.bold()));
+                    }
+                    logln("?");
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp::new_agent(
+                        component, agent_id, agent_type,
+                    ));
+
+                    bail!(NonSuccessfulExit);
+                }
+                Error::NotFound { .. } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested method name ({}) was not found.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp::new_agent(
+                        component, agent_id, agent_type,
+                    ));
+
+                    bail!(NonSuccessfulExit);
+                }
+            },
+        }
+    }
+
     async fn cmd_cancel_invocation(
         &self,
         worker_name: AgentIdArgs,
@@ -777,6 +989,442 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_recurring(&self, subcommand: AgentRecurringSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            AgentRecurringSubcommand::Schedule {
+                agent_id,
+                function_name,
+                arguments,
+                cron,
+                every,
+                missed_fire_policy,
+            } => {
+                self.ctx.silence_app_context_init().await;
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                let (agent_id, agent_type) = self
+                    .validate_worker_and_function_names(&component, &worker_name, None)?
+                    .ok_or_else(|| anyhow!("Recurring invocations require an agent component"))?;
+
+                let method_name = self.match_agent_method_name(
+                    &component,
+                    &agent_id,
+                    &agent_type,
+                    &function_name,
+                )?;
+
+                let schedule = match (cron, every) {
+                    (Some(expression), _) => RecurringSchedule::cron(expression),
+                    (None, Some(interval)) => interval,
+                    (None, None) => bail!("Either --cron or --every must be specified"),
+                };
+
+                let method_parameters =
+                    wave_args_to_agent_method_parameters(&agent_type, &method_name, arguments)?;
+
+                log_action(
+                    "Scheduling",
+                    format!(
+                        "recurring invocation of agent {}/{} {}",
+                        format_worker_name_match(&worker_name_match),
+                        method_name.log_color_highlight(),
+                        schedule.to_string().log_color_highlight()
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+                let invocation = clients
+                    .worker
+                    .schedule_recurring_invocation(
+                        &component.id.0,
+                        &agent_id.to_string(),
+                        &RecurringInvocationCreation {
+                            method_name,
+                            method_parameters,
+                            schedule,
+                            missed_fire_policy: Some(missed_fire_policy),
+                        },
+                    )
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&invocation);
+                Ok(())
+            }
+            AgentRecurringSubcommand::List { agent_id } => {
+                self.ctx.silence_app_context_init().await;
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                let clients = self.ctx.golem_clients().await?;
+                let invocations = clients
+                    .worker
+                    .list_recurring_invocations(&component.id.0, &worker_name.0)
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&invocations);
+                Ok(())
+            }
+            AgentRecurringSubcommand::Cancel { agent_id, id } => {
+                self.ctx.silence_app_context_init().await;
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                log_warn_action(
+                    "Canceling",
+                    format!(
+                        "recurring invocation {} of agent {}",
+                        id.to_string().log_color_highlight(),
+                        format_worker_name_match(&worker_name_match)
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+                let canceled = clients
+                    .worker
+                    .cancel_recurring_invocation(&component.id.0, &worker_name.0, &id)
+                    .await
+                    .map(|result| result.canceled)
+                    .map_service_error()?;
+
+                if canceled {
+                    log_action("Canceled", "");
+                } else {
+                    log_warn_action("Failed", "to cancel, recurring invocation not found");
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    async fn cmd_queue(&self, subcommand: AgentQueueSubcommand) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+
+        match subcommand {
+            AgentQueueSubcommand::List { agent_id } => {
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                let clients = self.ctx.golem_clients().await?;
+                let queue = clients
+                    .worker
+                    .get_invocation_queue(&component.id.0, &worker_name.0)
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&queue);
+                Ok(())
+            }
+            AgentQueueSubcommand::Reorder {
+                agent_id,
+                idempotency_key,
+                priority,
+                position,
+            } => {
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                log_action(
+                    "Reordering",
+                    format!(
+                        "pending invocation {} of agent {}",
+                        idempotency_key.value.log_color_highlight(),
+                        format_worker_name_match(&worker_name_match)
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+                let queue = clients
+                    .worker
+                    .reorder_invocation(
+                        &component.id.0,
+                        &worker_name.0,
+                        &InvocationQueueReorder {
+                            idempotency_key,
+                            priority,
+                            position,
+                        },
+                    )
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&queue);
+                Ok(())
+            }
+            AgentQueueSubcommand::Purge {
+                agent_id,
+                function_name,
+            } => {
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                let (agent_id, agent_type) = self
+                    .validate_worker_and_function_names(&component, &worker_name, None)?
+                    .ok_or_else(|| anyhow!("Purging invocations requires an agent component"))?;
+
+                let method_name = self.match_agent_method_name(
+                    &component,
+                    &agent_id,
+                    &agent_type,
+                    &function_name,
+                )?;
+
+                log_warn_action(
+                    "Purging",
+                    format!(
+                        "pending invocations of agent {}/{}",
+                        format_worker_name_match(&worker_name_match),
+                        method_name.log_color_highlight()
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+                let purged = clients
+                    .worker
+                    .purge_invocations(
+                        &component.id.0,
+                        &worker_name.0,
+                        &InvocationQueuePurge { method_name },
+                    )
+                    .await
+                    .map(|result| result.purged)
+                    .map_service_error()?;
+
+                log_action(
+                    "Purged",
+                    format!(
+                        "{} pending invocation(s)",
+                        purged.to_string().log_color_highlight()
+                    ),
+                );
+                Ok(())
+            }
+            AgentQueueSubcommand::Limit {
+                agent_id,
+                limit,
+                unlimited,
+            } => {
+                let worker_name_match = self.match_worker_name(agent_id.agent_id).await?;
+                let (component, worker_name) = self
+                    .component_by_worker_name_match(&worker_name_match)
+                    .await?;
+
+                let limit = if unlimited { None } else { limit };
+
+                log_action(
+                    "Setting",
+                    format!(
+                        "invocation queue limit of agent {} to {}",
+                        format_worker_name_match(&worker_name_match),
+                        limit
+                            .map(|limit| limit.to_string())
+                            .unwrap_or_else(|| "unlimited".to_string())
+                            .log_color_highlight()
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+                let queue = clients
+                    .worker
+                    .set_invocation_queue_limit(
+                        &component.id.0,
+                        &worker_name.0,
+                        &InvocationQueueLimit { limit },
+                    )
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&queue);
+                Ok(())
+            }
+        }
+    }
+
+    async fn cmd_bulk(&self, subcommand: AgentBulkSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            AgentBulkSubcommand::Update {
+                target,
+                mode,
+                target_revision,
+                disable_wakeup,
+            } => {
+                let mode = match mode.unwrap_or(AgentUpdateMode::Automatic) {
+                    AgentUpdateMode::Automatic => WorkerUpdateMode::Automatic,
+                    AgentUpdateMode::Manual => WorkerUpdateMode::Manual,
+                };
+                self.start_bulk_operation(target, |component| {
+                    BulkOperationKind::Update(BulkUpdate {
+                        mode,
+                        target_revision: target_revision.unwrap_or(component.revision),
+                        disable_wakeup,
+                    })
+                })
+                .await
+            }
+            AgentBulkSubcommand::Interrupt { target } => {
+                self.start_bulk_operation(target, |_| {
+                    BulkOperationKind::Interrupt(BulkInterrupt {
+                        recover_immediately: false,
+                    })
+                })
+                .await
+            }
+            AgentBulkSubcommand::Resume { target } => {
+                self.start_bulk_operation(target, |_| {
+                    BulkOperationKind::Resume(BulkResume { force: false })
+                })
+                .await
+            }
+            AgentBulkSubcommand::Delete { target } => {
+                self.start_bulk_operation(target, |_| BulkOperationKind::Delete(BulkDelete {}))
+                    .await
+            }
+            AgentBulkSubcommand::Get {
+                component_name,
+                operation_id,
+            } => {
+                let component = self.bulk_operation_component(component_name).await?;
+                let clients = self.ctx.golem_clients().await?;
+
+                let operation = clients
+                    .worker
+                    .get_bulk_operation(&component.id.0, &operation_id)
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&operation);
+                Ok(())
+            }
+            AgentBulkSubcommand::List { component_name } => {
+                let component = self.bulk_operation_component(component_name).await?;
+                let clients = self.ctx.golem_clients().await?;
+
+                let operations = clients
+                    .worker
+                    .list_bulk_operations(&component.id.0)
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&operations);
+                Ok(())
+            }
+            AgentBulkSubcommand::Cancel {
+                component_name,
+                operation_id,
+            } => {
+                let component = self.bulk_operation_component(component_name).await?;
+                let clients = self.ctx.golem_clients().await?;
+
+                log_warn_action(
+                    "Canceling",
+                    format!(
+                        "bulk operation {}",
+                        operation_id.to_string().log_color_highlight()
+                    ),
+                );
+
+                let operation = clients
+                    .worker
+                    .cancel_bulk_operation(&component.id.0, &operation_id)
+                    .await
+                    .map_service_error()?;
+
+                self.ctx.log_handler().log_view(&operation);
+                Ok(())
+            }
+        }
+    }
+
+    async fn start_bulk_operation(
+        &self,
+        target: BulkTargetArgs,
+        operation: impl FnOnce(&ComponentDto) -> BulkOperationKind,
+    ) -> anyhow::Result<()> {
+        let filter = if target.filter.is_empty() {
+            None
+        } else {
+            Some(WorkerFilter::from(target.filter).map_err(|err| anyhow!(err))?)
+        };
+
+        let component = self.bulk_operation_component(target.component_name).await?;
+        let clients = self.ctx.golem_clients().await?;
+
+        let request = BulkOperationRequest {
+            filter,
+            operation: operation(&component),
+            concurrency: target.concurrency,
+        };
+
+        log_action(
+            "Starting",
+            format!(
+                "bulk operation for agents of component {}",
+                component.component_name.0.log_color_highlight()
+            ),
+        );
+
+        let operation = clients
+            .worker
+            .start_bulk_operation(&component.id.0, &request)
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&operation);
+        Ok(())
+    }
+
+    async fn bulk_operation_component(
+        &self,
+        component_name: Option<ComponentName>,
+    ) -> anyhow::Result<ComponentDto> {
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_dir_or_name(component_name.as_ref())
+            .await?;
+
+        let [component_name] = selected_components.component_names.as_slice() else {
+            log_error(
+                "Bulk operations require exactly one selected component, use --component-name to select one.",
+            );
+            bail!(NonSuccessfulExit)
+        };
+
+        match self
+            .ctx
+            .component_handler()
+            .get_current_deployed_server_component_by_name(
+                &selected_components.environment,
+                component_name,
+            )
+            .await?
+        {
+            Some(component) => Ok(component),
+            None => {
+                log_error(format!(
+                    "Component not found: {}",
+                    component_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
     async fn cmd_list(
         &self,
         agent_type_name: Option<String>,
@@ -841,49 +1489,11 @@ impl WorkerCommandHandler {
                     )
                     .await?
             }
-            None => {
-                let selected_components = self
-                    .ctx
-                    .component_handler()
-                    .must_select_components_by_app_dir_or_name(component_name.as_ref())
-                    .await?;
-
-                let environment = &selected_components.environment;
-
-                environment
-                    .with_current_deployment_revision_or_default_warn(
-                        |current_deployment_revision| async move {
-                            let mut components =
-                                Vec::with_capacity(selected_components.component_names.len());
-                            for component_name in selected_components.component_names {
-                                match clients
-                                    .component
-                                    .get_deployment_component(
-                                        &environment.environment_id.0,
-                                        current_deployment_revision.into(),
-                                        component_name.as_str(),
-                                    )
-                                    .await
-                                    .map_service_error_not_found_as_opt()?
-                                {
-                                    Some(component) => {
-                                        components.push(component);
-                                    }
-                                    None => {
-                                        log_error(format!(
-                                            "Component not found: {}",
-                                            component_name.0.log_color_error_highlight()
-                                        ));
-                                        bail!(NonSuccessfulExit)
-                                    }
-                                }
-                            }
-
-                            Ok((components, filters))
-                        },
-                    )
-                    .await?
-            }
+            None => (
+                self.deployed_components_by_app_dir_or_name(component_name.as_ref())
+                    .await?,
+                filters,
+            ),
         };
 
         if scan_cursor.is_some() && components.len() != 1 {
@@ -1369,6 +1979,46 @@ impl WorkerCommandHandler {
         Ok(update_results)
     }
 
+    pub async fn check_component_workers_update(
+        &self,
+        component_name: &ComponentName,
+        component_id: &ComponentId,
+        request: UpdateCheckRequest,
+    ) -> anyhow::Result<UpdateCheckResult> {
+        log_action(
+            "Checking",
+            format!(
+                "agents of component {} against revision {}",
+                component_name.0.blue().bold(),
+                request.target_revision.to_string().log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let response = clients
+            .worker
+            .check_workers_update(&component_id.0, &request)
+            .await
+            .map_service_error()?;
+
+        Ok(UpdateCheckResult {
+            checked: response
+                .results
+                .into_iter()
+                .map(|result| WorkerUpdateCheck {
+                    component_name: component_name.clone(),
+                    current_revision: result.current_revision,
+                    target_revision: response.target_revision,
+                    worker_name: result.worker_id.worker_name.as_str().into(),
+                    compatible: result.compatible,
+                    divergence_index: result.divergence_index,
+                    details: result.details,
+                })
+                .collect(),
+        })
+    }
+
     async fn update_worker(
         &self,
         component_name: &ComponentName,
@@ -1665,6 +2315,57 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    /// Selects components based on the current directory or the given name, and resolves them
+    /// using the current deployment of their environment
+    pub async fn deployed_components_by_app_dir_or_name(
+        &self,
+        component_name: Option<&ComponentName>,
+    ) -> anyhow::Result<Vec<ComponentDto>> {
+        let clients = self.ctx.golem_clients().await?;
+
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_dir_or_name(component_name)
+            .await?;
+
+        let environment = &selected_components.environment;
+
+        environment
+            .with_current_deployment_revision_or_default_warn(
+                |current_deployment_revision| async move {
+                    let mut components =
+                        Vec::with_capacity(selected_components.component_names.len());
+                    for component_name in selected_components.component_names {
+                        match clients
+                            .component
+                            .get_deployment_component(
+                                &environment.environment_id.0,
+                                current_deployment_revision.into(),
+                                component_name.as_str(),
+                            )
+                            .await
+                            .map_service_error_not_found_as_opt()?
+                        {
+                            Some(component) => {
+                                components.push(component);
+                            }
+                            None => {
+                                log_error(format!(
+                                    "Component not found: {}",
+                                    component_name.0.log_color_error_highlight()
+                                ));
+                                bail!(NonSuccessfulExit)
+                            }
+                        }
+                    }
+
+                    Ok(components)
+                },
+            )
+            .await
+    }
+
     pub async fn list_component_workers(
         &self,
         component_name: &ComponentName,
@@ -1751,7 +2452,42 @@ impl WorkerCommandHandler {
         Ok((component, worker_name_match.worker_name.clone()))
     }
 
-    async fn resume_worker(
+    async fn get_full_oplog(
+        &self,
+        component: &ComponentDto,
+        worker_name: &WorkerName,
+    ) -> anyhow::Result<Vec<PublicOplogEntryWithIndex>> {
+        let clients = self.ctx.golem_clients().await?;
+        let batch_size = self.ctx.http_batch_size();
+
+        let mut entries = Vec::new();
+        let mut cursor = Option::<OplogCursor>::None;
+        loop {
+            let result = clients
+                .worker
+                .get_oplog(
+                    &component.id.0,
+                    &worker_name.0,
+                    None,
+                    batch_size,
+                    cursor.as_ref(),
+                    None,
+                )
+                .await
+                .map_service_error()?;
+
+            entries.extend(result.entries);
+            cursor = result.next;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    pub async fn resume_worker(
         &self,
         component: &ComponentDto,
         worker_name: &WorkerName,
@@ -1768,7 +2504,7 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
-    async fn interrupt_worker(
+    pub async fn interrupt_worker(
         &self,
         component: &ComponentDto,
         worker_name: &WorkerName,
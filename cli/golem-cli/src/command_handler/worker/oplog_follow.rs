@@ -0,0 +1,212 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::client::{check_http_response_success, new_reqwest_client};
+use crate::config::HttpClientConfig;
+use anyhow::anyhow;
+use futures_util::StreamExt;
+use golem_common::model::auth::TokenSecret;
+use golem_common::model::component::ComponentId;
+use golem_common::model::oplog::PublicOplogEntryWithIndex;
+use std::time::Duration;
+use tracing::debug;
+use url::Url;
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Follows the oplog of an agent through the worker service's streaming oplog endpoint
+pub struct OplogFollower {
+    client: reqwest::Client,
+    url: Url,
+    auth_token: TokenSecret,
+    next_oplog_index: u64,
+}
+
+impl OplogFollower {
+    pub fn new(
+        worker_service_url: Url,
+        auth_token: TokenSecret,
+        component_id: &ComponentId,
+        worker_name: &str,
+        from_oplog_index: u64,
+        allow_insecure: bool,
+    ) -> anyhow::Result<Self> {
+        let mut url = worker_service_url;
+        url.path_segments_mut()
+            .map_err(|()| anyhow!("Failed to get url path for oplog follow url"))?
+            .push("v1")
+            .push("components")
+            .push(&component_id.0.to_string())
+            .push("workers")
+            .push(worker_name)
+            .push("oplog")
+            .push("follow");
+
+        Ok(Self {
+            // The stream may be idle for an arbitrary long time, so using the invocation client
+            // config, which has no timeouts by default
+            client: new_reqwest_client(&HttpClientConfig::new_for_invoke(allow_insecure))?,
+            url,
+            auth_token,
+            next_oplog_index: from_oplog_index,
+        })
+    }
+
+    /// Passes every batch of received oplog entries to `on_entries`, until it fails or the
+    /// server rejects the request. Dropped connections are reestablished from the entry
+    /// following the last received one.
+    pub async fn run_forever(
+        mut self,
+        mut on_entries: impl FnMut(Vec<PublicOplogEntryWithIndex>) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        loop {
+            let response = self
+                .client
+                .get(self.url.clone())
+                .query(&[("from", self.next_oplog_index)])
+                .bearer_auth(self.auth_token.secret())
+                .send()
+                .await;
+
+            match response {
+                Ok(response) => {
+                    let response = check_http_response_success(response).await?;
+                    self.read(response, &mut on_entries).await?;
+                }
+                Err(err) => {
+                    debug!(error = err.to_string(), "Oplog follow connect failed");
+                }
+            }
+
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    /// Reads the JSON Lines response body until the connection is closed
+    async fn read(
+        &mut self,
+        response: reqwest::Response,
+        on_entries: &mut impl FnMut(Vec<PublicOplogEntryWithIndex>) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let mut body = response.bytes_stream();
+        let mut buffer = Vec::new();
+
+        while let Some(chunk) = body.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    debug!(error = err.to_string(), "Oplog follow stream failed");
+                    break;
+                }
+            };
+            buffer.extend_from_slice(&chunk);
+
+            let mut entries = Vec::new();
+            while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                let line = buffer.drain(..=end).collect::<Vec<_>>();
+                let entry: PublicOplogEntryWithIndex = serde_json::from_slice(&line)?;
+                self.next_oplog_index = entry.oplog_index.as_u64() + 1;
+                entries.push(entry);
+            }
+
+            if !entries.is_empty() {
+                on_entries(entries)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command_handler::worker::oplog_follow::OplogFollower;
+    use anyhow::anyhow;
+    use axum::body::{Body, Bytes};
+    use axum::extract::{Query, State};
+    use axum::Router;
+    use golem_common::model::auth::TokenSecret;
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::oplog::public_oplog_entry::NoOpParams;
+    use golem_common::model::oplog::{PublicOplogEntry, PublicOplogEntryWithIndex};
+    use golem_common::model::{OplogIndex, Timestamp};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use test_r::test;
+    use url::Url;
+
+    fn entry_line(idx: u64) -> String {
+        let entry = PublicOplogEntryWithIndex {
+            oplog_index: OplogIndex::from_u64(idx),
+            entry: PublicOplogEntry::NoOp(NoOpParams {
+                timestamp: Timestamp::from(idx * 1000),
+            }),
+        };
+        format!("{}\n", serde_json::to_string(&entry).unwrap())
+    }
+
+    /// Serves the entries from the requested index in chunks splitting the lines, then closes
+    /// the stream after at most two entries
+    async fn follow(
+        State(requests): State<Arc<Mutex<Vec<u64>>>>,
+        Query(query): Query<HashMap<String, String>>,
+    ) -> Body {
+        let from: u64 = query["from"].parse().unwrap();
+        requests.lock().unwrap().push(from);
+
+        let body = (from..from + 2).map(entry_line).collect::<String>();
+        let (first, second) = body.split_at(body.len() / 2 + 3);
+        let chunks = vec![
+            Ok::<_, std::io::Error>(Bytes::from(first.to_string())),
+            Ok(Bytes::from(second.to_string())),
+        ];
+        Body::from_stream(futures_util::stream::iter(chunks))
+    }
+
+    #[test]
+    async fn follower_reconnects_from_the_entry_after_the_last_received_one() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let route = Router::new().fallback(follow).with_state(requests.clone());
+        let server = tokio::spawn(async move { axum::serve(listener, route).await.unwrap() });
+
+        let follower = OplogFollower::new(
+            Url::parse(&format!("http://127.0.0.1:{port}")).unwrap(),
+            TokenSecret::trusted("token".to_string()),
+            &ComponentId::new(),
+            "agent",
+            1,
+            false,
+        )
+        .unwrap();
+
+        let mut received = Vec::new();
+        let result = follower
+            .run_forever(|entries| {
+                received.extend(entries.iter().map(|entry| entry.oplog_index.as_u64()));
+                if received.len() >= 4 {
+                    Err(anyhow!("done"))
+                } else {
+                    Ok(())
+                }
+            })
+            .await;
+        server.abort();
+
+        assert_eq!(result.unwrap_err().to_string(), "done");
+        assert_eq!(received, vec![1, 2, 3, 4]);
+        assert_eq!(*requests.lock().unwrap(), vec![1, 3]);
+    }
+}
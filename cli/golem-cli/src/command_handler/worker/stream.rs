@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::command_handler::worker::parse_worker_error;
-use crate::command_handler::worker::stream_output::WorkerStreamOutput;
+use crate::command_handler::worker::stream_output::{WorkerStreamLine, WorkerStreamOutput};
 use crate::model::format::Format;
 use crate::model::worker::AgentLogStreamOptions;
 use anyhow::{anyhow, Context};
@@ -29,7 +29,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, Mutex};
 use tokio::{task, time};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::handshake::client::Request;
@@ -84,6 +84,14 @@ impl WorkerConnection {
         })
     }
 
+    /// Sends the output lines to the given channel instead of printing them
+    pub fn with_output_channel(self, channel: mpsc::UnboundedSender<WorkerStreamLine>) -> Self {
+        Self {
+            output: self.output.with_channel(channel),
+            ..self
+        }
+    }
+
     /// Creates a new worker connection and every time the connection is dropped tries to
     /// reconnect. If there was an idempotency_key goal, and it has been reached, the loop
     /// exits.
@@ -23,14 +23,23 @@ use std::fmt::Write;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, Mutex};
 use tokio_tungstenite::tungstenite;
 
+/// A formatted line of agent stream output, used when the output is sent to a channel
+/// instead of being printed
+#[derive(Clone, Debug)]
+pub struct WorkerStreamLine {
+    pub level: LogLevel,
+    pub line: String,
+}
+
 #[derive(Clone)]
 pub struct WorkerStreamOutput {
     state: Arc<Mutex<WorkerStreamOutputState>>,
     options: AgentLogStreamOptions,
     format: Format,
+    channel: Option<mpsc::UnboundedSender<WorkerStreamLine>>,
 }
 
 struct WorkerStreamOutputState {
@@ -55,6 +64,15 @@ impl WorkerStreamOutput {
             })),
             options,
             format,
+            channel: None,
+        }
+    }
+
+    /// Sends every output line to the given channel instead of printing it
+    pub fn with_channel(self, channel: mpsc::UnboundedSender<WorkerStreamLine>) -> Self {
+        Self {
+            channel: Some(channel),
+            ..self
         }
     }
 
@@ -325,13 +343,13 @@ impl WorkerStreamOutput {
 
     fn json(&self, level_or_source: &str, context: &str, message: &str) {
         let json = self.json_value(level_or_source, context, message);
-        println!("{json}");
+        self.println(LogLevel::Info, json.to_string());
     }
 
     fn pretty_json(&self, level_or_source: &str, context: &str, message: &str) {
         if self.options.colors {
             let json = self.json_value(level_or_source, context, message);
-            println!("{}", to_colored_json(&json).unwrap());
+            self.println(LogLevel::Info, to_colored_json(&json).unwrap());
         } else {
             self.json(level_or_source, context, message);
         }
@@ -339,13 +357,13 @@ impl WorkerStreamOutput {
 
     fn yaml(&self, level_or_source: &str, context: &str, message: &str) {
         let json = self.json_value(level_or_source, context, message);
-        println!("{}", serde_yaml::to_string(&json).unwrap());
+        self.println(LogLevel::Info, serde_yaml::to_string(&json).unwrap());
     }
 
     fn pretty_yaml(&self, level_or_source: &str, context: &str, message: &str) {
         if self.options.colors {
             let json = self.json_value(level_or_source, context, message);
-            println!("{}", to_colored_yaml(&json).unwrap());
+            self.println(LogLevel::Info, to_colored_yaml(&json).unwrap());
         } else {
             self.yaml(level_or_source, context, message);
         }
@@ -361,7 +379,7 @@ impl WorkerStreamOutput {
     }
 
     fn colored(&self, level: LogLevel, s: &str) {
-        if self.options.colors {
+        if self.options.colors && self.channel.is_none() {
             let colored = match level {
                 LogLevel::Trace => s.blue(),
                 LogLevel::Debug => s.green(),
@@ -372,7 +390,17 @@ impl WorkerStreamOutput {
             };
             println!("{colored}");
         } else {
-            println!("{s}");
+            self.println(level, s.to_string());
+        }
+    }
+
+    fn println(&self, level: LogLevel, line: String) {
+        match &self.channel {
+            Some(channel) => {
+                // The receiver is only dropped when the output is not needed anymore
+                let _ = channel.send(WorkerStreamLine { level, line });
+            }
+            None => println!("{line}"),
         }
     }
 
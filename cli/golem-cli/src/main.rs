@@ -25,8 +25,10 @@ mod hooks {
     use golem_cli::command::server::ServerSubcommand;
     use golem_cli::command_handler::CommandHandlerHooks;
     use golem_cli::context::Context;
+    use golem_cli::model::oplog_replay::{OplogFixture, ReplayedOplog};
 
     use clap_verbosity_flag::Verbosity;
+    use std::path::Path;
     use std::sync::Arc;
 
     pub struct NoHooks {}
@@ -55,6 +57,15 @@ mod hooks {
         fn override_pretty_mode() -> bool {
             false
         }
+
+        #[cfg(feature = "server-commands")]
+        async fn replay_oplog(
+            &self,
+            _component_wasm: &Path,
+            _fixture: &OplogFixture,
+        ) -> anyhow::Result<ReplayedOplog> {
+            unimplemented!()
+        }
     }
 }
 
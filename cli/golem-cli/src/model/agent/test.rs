@@ -65,6 +65,7 @@ pub fn single_agent_wrapper_types() -> Vec<AgentType> {
                     }],
                 }),
                 http_endpoint: Vec::new(),
+                read_only: false,
             },
             AgentMethod {
                 name: "f2".to_string(),
@@ -95,6 +96,7 @@ pub fn single_agent_wrapper_types() -> Vec<AgentType> {
                     }],
                 }),
                 http_endpoint: Vec::new(),
+                read_only: false,
             },
         ],
         dependencies: vec![],
@@ -168,6 +170,7 @@ pub fn multi_agent_wrapper_2_types() -> Vec<AgentType> {
                     }],
                 }),
                 http_endpoint: Vec::new(),
+                read_only: false,
             }],
             dependencies: vec![],
             mode: AgentMode::Durable,
@@ -229,6 +232,7 @@ pub fn multi_agent_wrapper_2_types() -> Vec<AgentType> {
                     ],
                 }),
                 http_endpoint: Vec::new(),
+                read_only: false,
             }],
             dependencies: vec![],
             mode: AgentMode::Durable,
@@ -31,7 +31,9 @@ use crate::validation::{ValidatedResult, ValidationBuilder};
 use crate::app::template::AppTemplateName;
 use golem_common::model::agent::{AgentType, AgentTypeName};
 use golem_common::model::application::ApplicationName;
-use golem_common::model::component::{ComponentFilePath, ComponentFilePermissions, ComponentName};
+use golem_common::model::component::{
+    AgentResourceLimits, ComponentFilePath, ComponentFilePermissions, ComponentName,
+};
 use golem_common::model::domain_registration::Domain;
 use golem_common::model::environment::EnvironmentName;
 use golem_common::model::validate_lower_kebab_case_identifier;
@@ -960,6 +962,17 @@ impl Layer for ComponentLayer {
                     properties.config_vars.value().clone(),
                 ),
             );
+
+            value.agent_resource_limits.apply_layer(
+                id,
+                selection,
+                (
+                    properties
+                        .agent_resource_limits_merge_mode
+                        .unwrap_or_default(),
+                    properties.agent_resource_limits.value().clone(),
+                ),
+            );
         }
 
         Ok(())
@@ -1098,6 +1111,10 @@ impl<'a> Component<'a> {
         &self.properties().config_vars
     }
 
+    pub fn agent_resource_limits(&self) -> &Vec<AgentResourceLimits> {
+        &self.properties().agent_resource_limits
+    }
+
     pub fn files(&self) -> &Vec<InitialComponentFile> {
         &self.properties().files
     }
@@ -1154,6 +1171,10 @@ pub struct ComponentLayerProperties {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub config_vars_merge_mode: Option<MapMergeMode>,
     pub config_vars: MapProperty<ComponentLayer, String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_resource_limits_merge_mode: Option<MapMergeMode>,
+    pub agent_resource_limits:
+        MapProperty<ComponentLayer, AgentTypeName, app_raw::AgentResourceLimits>,
 }
 
 impl From<app_raw::ComponentLayerProperties> for ComponentLayerProperties {
@@ -1177,6 +1198,8 @@ impl From<app_raw::ComponentLayerProperties> for ComponentLayerProperties {
             env: value.env.unwrap_or_default().into(),
             config_vars_merge_mode: value.config_vars_merge_mode,
             config_vars: value.config_vars.unwrap_or_default().into(),
+            agent_resource_limits_merge_mode: value.agent_resource_limits_merge_mode,
+            agent_resource_limits: value.agent_resource_limits.unwrap_or_default().into(),
         }
     }
 }
@@ -1195,6 +1218,7 @@ impl ComponentLayerProperties {
         self.plugins.compact_trace();
         self.env.compact_trace();
         self.config_vars.compact_trace();
+        self.agent_resource_limits.compact_trace();
     }
 
     pub fn with_compacted_traces(&self) -> Self {
@@ -1237,6 +1261,7 @@ pub struct ComponentProperties {
     pub plugins: Vec<PluginInstallation>,
     pub env: BTreeMap<String, String>,
     pub config_vars: BTreeMap<String, String>,
+    pub agent_resource_limits: Vec<AgentResourceLimits>,
 }
 
 impl ComponentProperties {
@@ -1273,6 +1298,20 @@ impl ComponentProperties {
                 .into_iter()
                 .map(|(k, v)| (k.clone(), v.clone()))
                 .collect(),
+            agent_resource_limits: merged
+                .agent_resource_limits
+                .value()
+                .iter()
+                .map(|(agent, limits)| AgentResourceLimits {
+                    agent: agent.clone(),
+                    max_memory_bytes: limits.max_memory_bytes,
+                    fuel_per_invocation: limits.fuel_per_invocation,
+                    max_invocation_time_millis: limits.max_invocation_time_millis,
+                    max_oplog_entries_per_invocation: limits.max_oplog_entries_per_invocation,
+                    max_outgoing_http_requests_per_minute: limits
+                        .max_outgoing_http_requests_per_minute,
+                })
+                .collect(),
         };
 
         for (name, value) in [
@@ -449,6 +449,25 @@ pub struct ComponentLayerProperties {
     pub config_vars_merge_mode: Option<MapMergeMode>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub config_vars: Option<IndexMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_resource_limits_merge_mode: Option<MapMergeMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_resource_limits: Option<IndexMap<AgentTypeName, AgentResourceLimits>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AgentResourceLimits {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fuel_per_invocation: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_invocation_time_millis: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_oplog_entries_per_invocation: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_outgoing_http_requests_per_minute: Option<u64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
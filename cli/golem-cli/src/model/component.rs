@@ -21,7 +21,8 @@ use golem_common::model::agent::{
     AgentType, ComponentModelElementSchema, DataSchema, ElementSchema,
 };
 use golem_common::model::component::{
-    ComponentDto, ComponentId, ComponentRevision, InstalledPlugin,
+    AgentResourceLimits, AppliedComponentTransformation, ComponentDto, ComponentId,
+    ComponentRevision, InstalledPlugin,
 };
 use golem_common::model::component::{ComponentName, InitialComponentFile};
 
@@ -130,6 +131,8 @@ pub struct ComponentView {
     pub agent_types: Vec<AgentType>,
     pub files: Vec<InitialComponentFile>,
     pub plugins: Vec<InstalledPlugin>,
+    pub transformations: Vec<AppliedComponentTransformation>,
+    pub agent_resource_limits: Vec<AgentResourceLimits>,
     pub env: BTreeMap<String, String>,
 }
 
@@ -168,6 +171,8 @@ impl ComponentView {
             agent_types: value.metadata.agent_types().to_vec(),
             files: value.files,
             plugins: value.installed_plugins,
+            transformations: value.transformations,
+            agent_resource_limits: value.agent_resource_limits,
             env: value.env,
         }
     }
@@ -181,6 +186,7 @@ pub struct ComponentDeployProperties {
     pub plugins: Vec<crate::model::app::PluginInstallation>,
     pub env: BTreeMap<String, String>,
     pub config_vars: BTreeMap<String, String>,
+    pub agent_resource_limits: Vec<AgentResourceLimits>,
 }
 
 impl TrimDateTime for ComponentView {
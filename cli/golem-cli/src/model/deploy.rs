@@ -16,6 +16,7 @@ use crate::command::shared_args::{ForceBuildArg, PostDeployArgs};
 use crate::model::worker::WorkerName;
 use crate::model::GuestLanguage;
 use golem_common::model::component::{ComponentName, ComponentRevision};
+use golem_common::model::oplog::OplogIndex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -43,6 +44,36 @@ pub struct WorkerUpdateAttempt {
     pub error: Option<String>,
 }
 
+#[derive(Clone, Default, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCheckResult {
+    pub checked: Vec<WorkerUpdateCheck>,
+}
+
+impl UpdateCheckResult {
+    pub fn extend(&mut self, other: UpdateCheckResult) {
+        self.checked.extend(other.checked);
+    }
+
+    pub fn has_incompatible(&self) -> bool {
+        self.checked.iter().any(|check| !check.compatible)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerUpdateCheck {
+    pub component_name: ComponentName,
+    pub current_revision: ComponentRevision,
+    pub target_revision: ComponentRevision,
+    pub worker_name: WorkerName,
+    pub compatible: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divergence_index: Option<OplogIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct DeployConfig {
     pub plan: bool,
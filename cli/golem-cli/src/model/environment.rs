@@ -192,6 +192,8 @@ impl ResolvedEnvironmentIdentity {
                 compatibility_check: summary.environment.compatibility_check,
                 version_check: summary.environment.version_check,
                 security_overrides: summary.environment.security_overrides,
+                // Not part of the environment summary
+                network_egress_policy: Default::default(),
                 owner_account_id: summary.account.id,
                 roles_from_active_shares: summary.environment.roles_from_active_shares,
                 current_deployment: summary.environment.current_deployment,
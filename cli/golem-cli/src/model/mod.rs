@@ -23,6 +23,8 @@ pub mod environment;
 pub mod format;
 pub mod http_api;
 pub mod invoke_result_view;
+pub mod oplog_export;
+pub mod oplog_replay;
 pub mod plugin;
 pub mod plugin_manifest;
 pub mod repl;
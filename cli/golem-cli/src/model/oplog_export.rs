@@ -0,0 +1,261 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, Context};
+use arrow_array::builder::{StringBuilder, TimestampMillisecondBuilder, UInt64Builder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use clap::ValueEnum;
+use golem_common::model::oplog::{PublicOplogEntry, PublicOplogEntryWithIndex};
+use heck::ToKebabCase;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+#[clap(rename_all = "kebab-case")]
+pub enum OplogExportFormat {
+    /// JSON Lines, one oplog entry with its index per line
+    Jsonl,
+    /// Parquet file with the index, timestamp, kind and JSON representation of each entry as columns
+    Parquet,
+}
+
+/// Selects oplog entries by their kind, given as the kebab-cased name of the entry's type,
+/// for example `agent-invocation-started`. An empty filter selects every entry.
+#[derive(Debug, Clone, Default)]
+pub struct OplogEntryKindFilter {
+    kinds: Vec<String>,
+}
+
+impl OplogEntryKindFilter {
+    pub fn new(kinds: Vec<String>) -> Self {
+        Self {
+            kinds: kinds.iter().map(|kind| kind.to_kebab_case()).collect(),
+        }
+    }
+
+    pub fn matches(&self, entry: &PublicOplogEntry) -> bool {
+        self.kinds.is_empty() || self.kinds.contains(&entry.kind().to_kebab_case())
+    }
+}
+
+/// Writes oplog entries in one of the export formats. `finish` must be called after the last
+/// batch of entries, otherwise the exported file may be incomplete.
+pub enum OplogExporter {
+    JsonLines(Box<dyn Write>),
+    Parquet(ArrowWriter<File>),
+}
+
+impl OplogExporter {
+    /// Creates an exporter writing to the given file, or to the standard output if there is none.
+    /// Parquet exports always need an output file.
+    pub fn new(format: OplogExportFormat, output: Option<&Path>) -> anyhow::Result<Self> {
+        match format {
+            OplogExportFormat::Jsonl => {
+                let writer: Box<dyn Write> = match output {
+                    Some(path) => Box::new(BufWriter::new(create_file(path)?)),
+                    None => Box::new(std::io::stdout()),
+                };
+                Ok(Self::JsonLines(writer))
+            }
+            OplogExportFormat::Parquet => {
+                let path =
+                    output.ok_or_else(|| anyhow!("Parquet export requires an output file"))?;
+                let properties = WriterProperties::builder()
+                    .set_compression(Compression::SNAPPY)
+                    .build();
+                let writer = ArrowWriter::try_new(create_file(path)?, schema(), Some(properties))?;
+                Ok(Self::Parquet(writer))
+            }
+        }
+    }
+
+    pub fn write(&mut self, entries: &[PublicOplogEntryWithIndex]) -> anyhow::Result<()> {
+        match self {
+            Self::JsonLines(writer) => {
+                for entry in entries {
+                    serde_json::to_writer(&mut *writer, entry)?;
+                    writer.write_all(b"\n")?;
+                }
+                // Flushing every batch, so followed oplogs can be consumed while being written
+                writer.flush()?;
+            }
+            Self::Parquet(writer) => {
+                let mut oplog_index = UInt64Builder::with_capacity(entries.len());
+                let mut timestamp =
+                    TimestampMillisecondBuilder::with_capacity(entries.len()).with_timezone("UTC");
+                let mut kind = StringBuilder::new();
+                let mut entry_json = StringBuilder::new();
+
+                for entry in entries {
+                    oplog_index.append_value(entry.oplog_index.as_u64());
+                    timestamp.append_value(entry.entry.timestamp().to_millis() as i64);
+                    kind.append_value(entry.entry.kind().to_kebab_case());
+                    entry_json.append_value(serde_json::to_string(&entry.entry)?);
+                }
+
+                let columns: Vec<ArrayRef> = vec![
+                    Arc::new(oplog_index.finish()),
+                    Arc::new(timestamp.finish()),
+                    Arc::new(kind.finish()),
+                    Arc::new(entry_json.finish()),
+                ];
+                writer.write(&RecordBatch::try_new(schema(), columns)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        match self {
+            Self::JsonLines(mut writer) => writer.flush()?,
+            Self::Parquet(writer) => {
+                writer.close()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("oplog_index", DataType::UInt64, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("entry", DataType::Utf8, false),
+    ]))
+}
+
+fn create_file(path: &Path) -> anyhow::Result<File> {
+    File::create(path).with_context(|| format!("Failed to create {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::oplog_export::{OplogEntryKindFilter, OplogExportFormat, OplogExporter};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{TimestampMillisecondType, UInt64Type};
+    use golem_common::model::oplog::public_oplog_entry::{ErrorParams, NoOpParams, SuspendParams};
+    use golem_common::model::oplog::{PublicOplogEntry, PublicOplogEntryWithIndex};
+    use golem_common::model::{OplogIndex, Timestamp};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use std::fs::File;
+    use test_r::test;
+
+    fn entries() -> Vec<PublicOplogEntryWithIndex> {
+        vec![
+            PublicOplogEntryWithIndex {
+                oplog_index: OplogIndex::from_u64(2),
+                entry: PublicOplogEntry::NoOp(NoOpParams {
+                    timestamp: Timestamp::from(1000),
+                }),
+            },
+            PublicOplogEntryWithIndex {
+                oplog_index: OplogIndex::from_u64(3),
+                entry: PublicOplogEntry::Error(ErrorParams {
+                    timestamp: Timestamp::from(2000),
+                    error: "test".to_string(),
+                    retry_from: OplogIndex::INITIAL,
+                }),
+            },
+        ]
+    }
+
+    #[test]
+    fn empty_kind_filter_matches_every_entry() {
+        let filter = OplogEntryKindFilter::default();
+        assert!(entries().iter().all(|entry| filter.matches(&entry.entry)));
+    }
+
+    #[test]
+    fn kind_filter_accepts_kebab_and_pascal_cased_kinds() {
+        let filter = OplogEntryKindFilter::new(vec!["NoOp".to_string(), "error".to_string()]);
+        let suspend = PublicOplogEntry::Suspend(SuspendParams {
+            timestamp: Timestamp::from(3000),
+        });
+
+        assert!(entries().iter().all(|entry| filter.matches(&entry.entry)));
+        assert!(!filter.matches(&suspend));
+        assert!(OplogEntryKindFilter::new(vec!["no-op".to_string()]).matches(&entries()[0].entry));
+    }
+
+    #[test]
+    fn jsonl_export_writes_one_entry_per_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("oplog.jsonl");
+
+        let mut exporter = OplogExporter::new(OplogExportFormat::Jsonl, Some(&path)).unwrap();
+        let entries = entries();
+        exporter.write(&entries[..1]).unwrap();
+        exporter.write(&entries[1..]).unwrap();
+        exporter.finish().unwrap();
+
+        let exported = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<PublicOplogEntryWithIndex>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(exported, entries);
+    }
+
+    #[test]
+    fn parquet_export_writes_entries_as_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("oplog.parquet");
+
+        let mut exporter = OplogExporter::new(OplogExportFormat::Parquet, Some(&path)).unwrap();
+        let entries = entries();
+        exporter.write(&entries).unwrap();
+        exporter.finish().unwrap();
+
+        let batches = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+
+        let oplog_index = batch.column(0).as_primitive::<UInt64Type>();
+        let timestamp = batch.column(1).as_primitive::<TimestampMillisecondType>();
+        let kind = batch.column(2).as_string::<i32>();
+        let entry = batch.column(3).as_string::<i32>();
+
+        assert_eq!(oplog_index.values().to_vec(), vec![2, 3]);
+        assert_eq!(timestamp.values().to_vec(), vec![1000, 2000]);
+        assert_eq!(
+            kind.iter().collect::<Vec<_>>(),
+            vec![Some("no-op"), Some("error")]
+        );
+        for (idx, expected) in entries.iter().enumerate() {
+            let actual: PublicOplogEntry = serde_json::from_str(entry.value(idx)).unwrap();
+            assert_eq!(actual, expected.entry);
+        }
+    }
+
+    #[test]
+    fn parquet_export_requires_output_file() {
+        assert!(OplogExporter::new(OplogExportFormat::Parquet, None).is_err());
+    }
+}
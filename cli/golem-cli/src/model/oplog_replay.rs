@@ -0,0 +1,311 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::agent::DataValue;
+use golem_common::model::component::ComponentName;
+use golem_common::model::oplog::{
+    PublicAgentInvocation, PublicAgentInvocationResult, PublicOplogEntry, PublicOplogEntryWithIndex,
+};
+use golem_common::model::{IdempotencyKey, OplogIndex};
+use golem_wasm::ValueAndType;
+use itertools::{EitherOrBoth, Itertools};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A downloaded oplog of an agent, including all the payloads, used as the input
+/// of a local regression replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogFixture {
+    pub component_name: ComponentName,
+    pub agent_id: String,
+    pub entries: Vec<PublicOplogEntryWithIndex>,
+}
+
+impl OplogFixture {
+    /// The agent method invocations recorded in the oplog, in the order they were started
+    pub fn recorded_invocations(&self) -> Vec<RecordedInvocation> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match &entry.entry {
+                PublicOplogEntry::AgentInvocationStarted(params) => match &params.invocation {
+                    PublicAgentInvocation::AgentMethodInvocation(invocation) => {
+                        Some(RecordedInvocation {
+                            oplog_index: entry.oplog_index,
+                            idempotency_key: invocation.idempotency_key.clone(),
+                            method_name: invocation.method_name.clone(),
+                            input: invocation.function_input.clone(),
+                        })
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn replay_steps(&self) -> Vec<ReplayStep> {
+        replay_steps(&self.entries)
+    }
+}
+
+/// The outcome of replaying the recorded invocations of an [`OplogFixture`] in a sandbox
+#[derive(Debug, Clone)]
+pub struct ReplayedOplog {
+    pub agent_id: String,
+    pub invocations: usize,
+    pub entries: Vec<PublicOplogEntryWithIndex>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedInvocation {
+    pub oplog_index: OplogIndex,
+    pub idempotency_key: IdempotencyKey,
+    pub method_name: String,
+    pub input: DataValue,
+}
+
+/// The observable behaviour of an agent which is compared between the recorded and the replayed oplog.
+///
+/// Host call responses are not part of the comparison, as the replay feeds the recorded responses
+/// back to the agent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ReplayStep {
+    #[serde(rename_all = "camelCase")]
+    HostCall {
+        oplog_index: OplogIndex,
+        function_name: String,
+        request: ValueAndType,
+    },
+    #[serde(rename_all = "camelCase")]
+    InvocationResult {
+        oplog_index: OplogIndex,
+        result: PublicAgentInvocationResult,
+    },
+}
+
+impl ReplayStep {
+    pub fn oplog_index(&self) -> OplogIndex {
+        match self {
+            ReplayStep::HostCall { oplog_index, .. } => *oplog_index,
+            ReplayStep::InvocationResult { oplog_index, .. } => *oplog_index,
+        }
+    }
+}
+
+pub fn replay_steps(entries: &[PublicOplogEntryWithIndex]) -> Vec<ReplayStep> {
+    entries
+        .iter()
+        .filter_map(|entry| match &entry.entry {
+            PublicOplogEntry::HostCall(params) => Some(ReplayStep::HostCall {
+                oplog_index: entry.oplog_index,
+                function_name: params.function_name.clone(),
+                request: params.request.clone(),
+            }),
+            PublicOplogEntry::AgentInvocationFinished(params) => {
+                Some(ReplayStep::InvocationResult {
+                    oplog_index: entry.oplog_index,
+                    result: params.result.clone(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReplayDivergenceKind {
+    /// A different host function was called, or a host call happened instead of finishing the invocation
+    DifferentHostCall,
+    /// The same host function was called with a different request
+    DifferentRequest,
+    /// The invocation finished with a different result
+    DifferentResult,
+    /// The replay stopped before reaching a recorded step
+    MissingStep,
+    /// The replay produced a step which was not recorded
+    UnexpectedStep,
+}
+
+impl Display for ReplayDivergenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ReplayDivergenceKind::DifferentHostCall => "different host call",
+            ReplayDivergenceKind::DifferentRequest => "different request",
+            ReplayDivergenceKind::DifferentResult => "different result",
+            ReplayDivergenceKind::MissingStep => "missing step",
+            ReplayDivergenceKind::UnexpectedStep => "unexpected step",
+        };
+        write!(f, "{str}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayDivergence {
+    pub kind: ReplayDivergenceKind,
+    pub step: usize,
+    pub expected: Option<ReplayStep>,
+    pub actual: Option<ReplayStep>,
+}
+
+/// Compares the recorded and replayed steps, ignoring their oplog indices, and returns the first divergence
+pub fn find_first_divergence(
+    expected: &[ReplayStep],
+    actual: &[ReplayStep],
+) -> Option<ReplayDivergence> {
+    expected
+        .iter()
+        .zip_longest(actual.iter())
+        .enumerate()
+        .find_map(|(step, pair)| {
+            let kind = match &pair {
+                EitherOrBoth::Both(expected, actual) => step_divergence(expected, actual)?,
+                EitherOrBoth::Left(_) => ReplayDivergenceKind::MissingStep,
+                EitherOrBoth::Right(_) => ReplayDivergenceKind::UnexpectedStep,
+            };
+            let (expected, actual) = pair.map_any(Clone::clone, Clone::clone).left_and_right();
+            Some(ReplayDivergence {
+                kind,
+                step,
+                expected,
+                actual,
+            })
+        })
+}
+
+fn step_divergence(expected: &ReplayStep, actual: &ReplayStep) -> Option<ReplayDivergenceKind> {
+    match (expected, actual) {
+        (
+            ReplayStep::HostCall {
+                function_name: expected_function_name,
+                request: expected_request,
+                ..
+            },
+            ReplayStep::HostCall {
+                function_name: actual_function_name,
+                request: actual_request,
+                ..
+            },
+        ) => {
+            if expected_function_name != actual_function_name {
+                Some(ReplayDivergenceKind::DifferentHostCall)
+            } else if expected_request != actual_request {
+                Some(ReplayDivergenceKind::DifferentRequest)
+            } else {
+                None
+            }
+        }
+        (
+            ReplayStep::InvocationResult {
+                result: expected_result,
+                ..
+            },
+            ReplayStep::InvocationResult {
+                result: actual_result,
+                ..
+            },
+        ) => (expected_result != actual_result).then_some(ReplayDivergenceKind::DifferentResult),
+        _ => Some(ReplayDivergenceKind::DifferentHostCall),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OplogReplayReport {
+    pub recorded_agent_id: String,
+    pub replayed_agent_id: String,
+    pub replayed_invocations: usize,
+    pub compared_steps: usize,
+    pub divergence: Option<ReplayDivergence>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::oplog_replay::{find_first_divergence, ReplayDivergenceKind, ReplayStep};
+    use golem_common::model::agent::{DataValue, ElementValues};
+    use golem_common::model::oplog::{
+        AgentInvocationOutputParameters, PublicAgentInvocationResult,
+    };
+    use golem_common::model::OplogIndex;
+    use golem_wasm::IntoValueAndType;
+    use test_r::test;
+
+    fn host_call(idx: u64, function_name: &str, request: &str) -> ReplayStep {
+        ReplayStep::HostCall {
+            oplog_index: OplogIndex::from_u64(idx),
+            function_name: function_name.to_string(),
+            request: request.to_string().into_value_and_type(),
+        }
+    }
+
+    fn invocation_result(idx: u64) -> ReplayStep {
+        ReplayStep::InvocationResult {
+            oplog_index: OplogIndex::from_u64(idx),
+            result: PublicAgentInvocationResult::AgentMethod(AgentInvocationOutputParameters {
+                output: DataValue::Tuple(ElementValues { elements: vec![] }),
+            }),
+        }
+    }
+
+    #[test]
+    fn identical_steps_with_shifted_indices_do_not_diverge() {
+        let expected = vec![host_call(2, "http::send", "a"), invocation_result(3)];
+        let actual = vec![host_call(5, "http::send", "a"), invocation_result(7)];
+        assert_eq!(find_first_divergence(&expected, &actual), None);
+    }
+
+    #[test]
+    fn reports_first_different_request() {
+        let expected = vec![
+            host_call(2, "http::send", "a"),
+            host_call(3, "http::send", "b"),
+            host_call(4, "http::send", "c"),
+        ];
+        let actual = vec![
+            host_call(2, "http::send", "a"),
+            host_call(3, "http::send", "x"),
+            host_call(4, "http::send", "y"),
+        ];
+        let divergence = find_first_divergence(&expected, &actual).unwrap();
+        assert_eq!(divergence.kind, ReplayDivergenceKind::DifferentRequest);
+        assert_eq!(divergence.step, 1);
+        assert_eq!(divergence.expected, Some(expected[1].clone()));
+        assert_eq!(divergence.actual, Some(actual[1].clone()));
+    }
+
+    #[test]
+    fn reports_different_host_call() {
+        let expected = vec![host_call(2, "http::send", "a")];
+        let actual = vec![host_call(2, "wall_clock::now", "a")];
+        let divergence = find_first_divergence(&expected, &actual).unwrap();
+        assert_eq!(divergence.kind, ReplayDivergenceKind::DifferentHostCall);
+    }
+
+    #[test]
+    fn reports_missing_and_unexpected_steps() {
+        let steps = vec![host_call(2, "http::send", "a"), invocation_result(3)];
+
+        let divergence = find_first_divergence(&steps, &steps[..1]).unwrap();
+        assert_eq!(divergence.kind, ReplayDivergenceKind::MissingStep);
+        assert_eq!(divergence.step, 1);
+        assert_eq!(divergence.actual, None);
+
+        let divergence = find_first_divergence(&steps[..1], &steps).unwrap();
+        assert_eq!(divergence.kind, ReplayDivergenceKind::UnexpectedStep);
+        assert_eq!(divergence.expected, None);
+    }
+}
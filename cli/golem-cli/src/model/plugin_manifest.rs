@@ -22,6 +22,8 @@ use uuid::Uuid;
 #[serde(tag = "type")]
 pub enum PluginTypeSpecificManifest {
     OplogProcessor(OplogProcessorManifest),
+    ComponentTransformer(ComponentTransformerManifest),
+    Library(LibraryManifest),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,6 +33,25 @@ pub struct OplogProcessorManifest {
     pub component_revision: ComponentRevision,
 }
 
+/// Either `transformUrl` or both `componentId` and `componentRevision` must be specified
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentTransformerManifest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transform_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_revision: Option<ComponentRevision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryManifest {
+    pub component_id: Uuid,
+    pub component_revision: ComponentRevision,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginManifest {
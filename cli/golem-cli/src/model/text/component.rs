@@ -52,6 +52,12 @@ impl TextView for Vec<ComponentView> {
 }
 
 fn component_view_fields(view: &ComponentView) -> Vec<(String, String)> {
+    let libraries = view
+        .plugins
+        .iter()
+        .filter(|plugin| plugin.library_component_id.is_some())
+        .collect::<Vec<_>>();
+
     let mut fields = FieldsBuilder::new();
 
     fields
@@ -77,6 +83,24 @@ fn component_view_fields(view: &ComponentView) -> Vec<(String, String)> {
             view.plugins.as_slice(),
             !view.plugins.is_empty(),
             format_plugins,
+        )
+        .fmt_field_optional(
+            "Libraries",
+            libraries.as_slice(),
+            !libraries.is_empty(),
+            format_libraries,
+        )
+        .fmt_field_optional(
+            "Transformations",
+            view.transformations.as_slice(),
+            !view.transformations.is_empty(),
+            format_component_transformations,
+        )
+        .fmt_field_optional(
+            "Resource limits",
+            view.agent_resource_limits.as_slice(),
+            !view.agent_resource_limits.is_empty(),
+            format_agent_resource_limits,
         );
 
     fields.build()
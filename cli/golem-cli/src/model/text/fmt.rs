@@ -21,7 +21,9 @@ use anyhow::anyhow;
 use cli_table::{Row, Title, WithTitle};
 use colored::control::SHOULD_COLORIZE;
 use colored::Colorize;
-use golem_common::model::component::{InitialComponentFile, InstalledPlugin};
+use golem_common::model::component::{
+    AgentResourceLimits, AppliedComponentTransformation, InitialComponentFile, InstalledPlugin,
+};
 use golem_common::model::WorkerStatus;
 use itertools::Itertools;
 use regex::Regex;
@@ -333,6 +335,81 @@ pub fn format_plugins(plugins: &[InstalledPlugin]) -> String {
         .join("\n")
 }
 
+pub fn format_libraries(plugins: &[&InstalledPlugin]) -> String {
+    plugins
+        .iter()
+        .filter_map(|plugin| {
+            let component_id = plugin.library_component_id?;
+            let component_revision = plugin.library_component_revision?;
+            Some(format!(
+                "{}/{}: {} revision {}",
+                plugin.plugin_name.log_color_highlight(),
+                plugin.plugin_version.log_color_highlight(),
+                component_id,
+                component_revision,
+            ))
+        })
+        .join("\n")
+}
+
+pub fn format_component_transformations(
+    transformations: &[AppliedComponentTransformation],
+) -> String {
+    transformations
+        .iter()
+        .map(|transformation| {
+            format!(
+                "{}/{}: {}",
+                transformation.plugin_name.log_color_highlight(),
+                transformation.plugin_version.log_color_highlight(),
+                transformation.result_hash,
+            )
+        })
+        .join("\n")
+}
+
+pub fn format_agent_resource_limits(agent_resource_limits: &[AgentResourceLimits]) -> String {
+    agent_resource_limits
+        .iter()
+        .map(|limits| {
+            let rendered = [
+                (
+                    "max memory",
+                    limits
+                        .max_memory_bytes
+                        .map(|bytes| format_binary_size(&bytes)),
+                ),
+                (
+                    "fuel per invocation",
+                    limits.fuel_per_invocation.map(|fuel| fuel.to_string()),
+                ),
+                (
+                    "max invocation time",
+                    limits
+                        .max_invocation_time_millis
+                        .map(|millis| format!("{millis}ms")),
+                ),
+                (
+                    "max oplog entries per invocation",
+                    limits
+                        .max_oplog_entries_per_invocation
+                        .map(|entries| entries.to_string()),
+                ),
+                (
+                    "max outgoing HTTP requests per minute",
+                    limits
+                        .max_outgoing_http_requests_per_minute
+                        .map(|requests| requests.to_string()),
+                ),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{name}: {value}")))
+            .join(", ");
+            format!("{}: {}", limits.agent.0.log_color_highlight(), rendered)
+        })
+        .join("\n")
+}
+
 pub fn format_env(show_sensitive: bool, env: &BTreeMap<String, String>) -> String {
     let hidden = "*****".log_color_highlight();
     env.iter()
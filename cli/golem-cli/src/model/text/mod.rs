@@ -23,9 +23,11 @@ pub mod help;
 pub mod http_api_deployment;
 pub mod http_api_domain;
 pub mod http_api_security;
+pub mod oplog_replay;
 pub mod plugin;
 pub mod profile;
 pub mod server;
 pub mod template;
 pub mod token;
+pub mod usage;
 pub mod worker;
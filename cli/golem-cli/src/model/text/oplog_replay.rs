@@ -0,0 +1,86 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::log::{logln, LogColorize};
+use crate::model::oplog_replay::{OplogReplayReport, ReplayStep};
+use crate::model::text::diff::log_unified_diff;
+use crate::model::text::fmt::*;
+use colored::Colorize;
+use golem_common::model::diff::unified_diff;
+
+impl TextView for OplogReplayReport {
+    fn log(&self) {
+        logln(format!(
+            "Replayed {} invocation(s) of agent {} as {}",
+            self.replayed_invocations,
+            format_main_id(&self.recorded_agent_id),
+            format_main_id(&self.replayed_agent_id),
+        ));
+
+        match &self.divergence {
+            None => {
+                logln(format!(
+                    "{}, compared {} step(s)",
+                    "No divergence found".green(),
+                    self.compared_steps
+                ));
+            }
+            Some(divergence) => {
+                logln(format!(
+                    "{} at step {}: {}",
+                    "First divergence".red().bold(),
+                    divergence.step,
+                    divergence.kind.to_string().log_color_error_highlight()
+                ));
+                logln(format!(
+                    "  recorded oplog index: {}",
+                    format_step_index(divergence.expected.as_ref())
+                ));
+                logln(format!(
+                    "  replayed oplog index: {}",
+                    format_step_index(divergence.actual.as_ref())
+                ));
+                logln("");
+                log_unified_diff(&unified_diff(
+                    format_step(divergence.expected.as_ref()),
+                    format_step(divergence.actual.as_ref()),
+                ));
+            }
+        }
+    }
+}
+
+fn format_step_index(step: Option<&ReplayStep>) -> String {
+    match step {
+        Some(step) => format_id(&step.oplog_index()),
+        None => format_warn("-"),
+    }
+}
+
+fn format_step(step: Option<&ReplayStep>) -> String {
+    match step {
+        Some(step) => {
+            // Oplog indices are shown separately, they are not part of the comparison
+            let mut json = serde_json::to_value(step).expect("failed to serialize replay step");
+            if let Some(fields) = json.as_object_mut() {
+                fields.remove("oplogIndex");
+            }
+            let mut str =
+                serde_json::to_string_pretty(&json).expect("failed to serialize replay step");
+            str.push('\n');
+            str
+        }
+        None => String::new(),
+    }
+}
@@ -93,6 +93,7 @@ impl MessageWithFields for PluginRegistrationGetView {
 }
 
 fn plugin_registration_fields(plugin: &PluginRegistrationDto) -> Vec<(String, String)> {
+    let transformer = plugin.component_transformer();
     let mut fields = FieldsBuilder::new();
 
     fields
@@ -101,14 +102,25 @@ fn plugin_registration_fields(plugin: &PluginRegistrationDto) -> Vec<(String, St
         .field("Description", &plugin.description)
         .field("Homepage", &plugin.homepage)
         .field("Type", &plugin.typ_as_str())
+        .fmt_field_option(
+            "Transform URL",
+            &transformer.and_then(|spec| spec.transform_url.as_ref()),
+            format_id,
+        )
         .fmt_field_option(
             "Component ID",
-            &plugin.oplog_processor_component_id(),
+            &plugin
+                .oplog_processor_component_id()
+                .or(plugin.library_component_id())
+                .or(transformer.and_then(|spec| spec.component_id)),
             format_id,
         )
         .fmt_field_option(
             "Component Version",
-            &plugin.oplog_processor_component_revision(),
+            &plugin
+                .oplog_processor_component_revision()
+                .or(plugin.library_component_revision())
+                .or(transformer.and_then(|spec| spec.component_revision)),
             format_id,
         );
 
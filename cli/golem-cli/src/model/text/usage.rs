@@ -0,0 +1,70 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::log::logln;
+use crate::model::text::fmt::*;
+use cli_table::{format::Justify, Table};
+use golem_common::model::reports::{AgentTypeUsage, EnvironmentUsageReport};
+use serde::{Deserialize, Serialize};
+
+#[derive(Table)]
+struct AgentTypeUsageTableView {
+    #[table(title = "Component ID")]
+    pub component_id: String,
+    #[table(title = "Agent type")]
+    pub agent_type: String,
+    #[table(title = "Invocations", justify = "Justify::Right")]
+    pub invocations: u64,
+    #[table(title = "Fuel", justify = "Justify::Right")]
+    pub fuel: u64,
+    #[table(title = "Invocation time (ms)", justify = "Justify::Right")]
+    pub invocation_time_millis: u64,
+    #[table(title = "Oplog bytes", justify = "Justify::Right")]
+    pub oplog_bytes: u64,
+    #[table(title = "Outgoing HTTP requests", justify = "Justify::Right")]
+    pub outgoing_http_requests: u64,
+}
+
+impl From<&AgentTypeUsage> for AgentTypeUsageTableView {
+    fn from(value: &AgentTypeUsage) -> Self {
+        Self {
+            component_id: value.component_id.to_string(),
+            agent_type: value.agent_type.to_string(),
+            invocations: value.invocations,
+            fuel: value.fuel,
+            invocation_time_millis: value.invocation_time_millis,
+            oplog_bytes: value.oplog_bytes,
+            outgoing_http_requests: value.outgoing_http_requests,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EnvironmentUsageView(pub EnvironmentUsageReport);
+
+impl TextView for EnvironmentUsageView {
+    fn log(&self) {
+        logln(format!(
+            "Usage of environment {} between {} and {}",
+            format_message_highlight(&self.0.environment_id),
+            self.0.from,
+            self.0.to
+        ));
+        if self.0.agent_types.is_empty() {
+            logln("No usage recorded in this time range");
+        } else {
+            log_table::<_, AgentTypeUsageTableView>(&self.0.agent_types);
+        }
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::log::{logln, LogColorize};
-use crate::model::deploy::TryUpdateAllWorkersResult;
+use crate::model::deploy::{TryUpdateAllWorkersResult, UpdateCheckResult, WorkerUpdateCheck};
 use crate::model::environment::EnvironmentReference;
 use crate::model::invoke_result_view::InvokeResultView;
 use crate::model::text::fmt::*;
@@ -29,12 +29,17 @@ use golem_common::model::agent::{
     BinaryReference, ComponentModelElementValue, DataValue, ElementValue, TextReference,
     UnstructuredBinaryElementValue, UnstructuredTextElementValue,
 };
+use golem_common::model::bulk_operation::{
+    BulkOperation, BulkOperationKind, BulkOperationList, BulkOperationStatus,
+};
 use golem_common::model::component::{ComponentName, ComponentRevision};
+use golem_common::model::invocation_queue::{InvocationQueue, PendingInvocation};
 use golem_common::model::oplog::{
     PluginInstallationDescription, PublicAgentInvocation, PublicAttributeValue, PublicOplogEntry,
     PublicSnapshotData, PublicUpdateDescription, StringAttributeValue,
 };
-use golem_common::model::worker::UpdateRecord;
+use golem_common::model::recurring_invocation::{RecurringInvocation, RecurringInvocationList};
+use golem_common::model::worker::{UpdateRecord, WorkerUpdateMode};
 use golem_common::model::Timestamp;
 use golem_wasm::{print_value_and_type, ValueAndType};
 use indoc::indoc;
@@ -267,6 +272,301 @@ impl TextView for TryUpdateAllWorkersResult {
     }
 }
 
+#[derive(Table)]
+struct WorkerUpdateCheckTableView {
+    #[table(title = "Component name")]
+    pub component_name: ComponentName,
+    #[table(title = "Agent name")]
+    pub worker_name: String,
+    #[table(title = "Current\nrevision", justify = "Justify::Right")]
+    pub current_revision: ComponentRevision,
+    #[table(title = "Compatible")]
+    pub compatible: String,
+    #[table(title = "Divergence\nindex", justify = "Justify::Right")]
+    pub divergence_index: String,
+    #[table(title = "Details")]
+    pub details: String,
+}
+
+impl From<&WorkerUpdateCheck> for WorkerUpdateCheckTableView {
+    fn from(value: &WorkerUpdateCheck) -> Self {
+        Self {
+            component_name: value.component_name.clone(),
+            worker_name: textwrap::wrap(&value.worker_name.0, 30).join("\n"),
+            current_revision: value.current_revision,
+            compatible: if value.compatible {
+                "yes".green().to_string()
+            } else {
+                "no".red().to_string()
+            },
+            divergence_index: value
+                .divergence_index
+                .map(|idx| idx.to_string())
+                .unwrap_or_default(),
+            details: textwrap::wrap(value.details.as_deref().unwrap_or_default(), 50).join("\n"),
+        }
+    }
+}
+
+impl TextView for UpdateCheckResult {
+    fn log(&self) {
+        if self.checked.is_empty() {
+            logln("No agents had to be checked.");
+            return;
+        }
+
+        log_table::<_, WorkerUpdateCheckTableView>(&self.checked);
+
+        let incompatible = self
+            .checked
+            .iter()
+            .filter(|check| !check.compatible)
+            .count();
+        logln("");
+        if incompatible == 0 {
+            logln(format!(
+                "All {} checked agents can be updated automatically.",
+                self.checked.len().to_string().log_color_highlight()
+            ));
+        } else {
+            logln(format!(
+                "{} of {} checked agents would fail an automatic update.",
+                incompatible.to_string().log_color_error_highlight(),
+                self.checked.len().to_string().log_color_highlight()
+            ));
+        }
+    }
+}
+
+fn format_bulk_operation_kind(kind: &BulkOperationKind) -> String {
+    match kind {
+        BulkOperationKind::Interrupt(_) => "interrupt".to_string(),
+        BulkOperationKind::Resume(_) => "resume".to_string(),
+        BulkOperationKind::Update(update) => format!(
+            "update to revision {} ({})",
+            update.target_revision,
+            match update.mode {
+                WorkerUpdateMode::Automatic => "auto",
+                WorkerUpdateMode::Manual => "manual",
+            }
+        ),
+        BulkOperationKind::Delete(_) => "delete".to_string(),
+        BulkOperationKind::ActivatePlugin(params) => {
+            format!("activate plugin {}", params.plugin_priority)
+        }
+        BulkOperationKind::DeactivatePlugin(params) => {
+            format!("deactivate plugin {}", params.plugin_priority)
+        }
+        BulkOperationKind::Revert(_) => "revert".to_string(),
+    }
+}
+
+fn format_bulk_operation_status(status: &BulkOperationStatus) -> String {
+    match status {
+        BulkOperationStatus::Running => status.to_string().yellow().to_string(),
+        BulkOperationStatus::Completed => status.to_string().green().to_string(),
+        BulkOperationStatus::Cancelled => status.to_string().bright_black().to_string(),
+        BulkOperationStatus::Failed => status.to_string().red().to_string(),
+    }
+}
+
+impl MessageWithFields for BulkOperation {
+    fn message(&self) -> String {
+        format!(
+            "Bulk operation {} is {}",
+            format_message_highlight(&self.id),
+            format_bulk_operation_status(&self.status)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("ID", &self.id, format_id)
+            .field("Operation", &format_bulk_operation_kind(&self.operation))
+            .fmt_field_option("Filter", &self.filter, |filter| filter.to_string())
+            .field("Created at", &self.created_at)
+            .field("Updated at", &self.updated_at)
+            .field("Matched agents", &self.matched)
+            .field("Succeeded", &self.succeeded)
+            .fmt_field_optional("Failed", &self.failed, self.failed > 0, |failed| {
+                failed.to_string().red().to_string()
+            })
+            .fmt_field_optional(
+                "Failures",
+                &self.failures,
+                !self.failures.is_empty(),
+                |failures| {
+                    failures
+                        .iter()
+                        .map(|failure| format!("{}: {}", failure.worker_id, failure.error))
+                        .join("\n")
+                },
+            )
+            .fmt_field_optional(
+                "Cancellation",
+                "requested",
+                self.cancel_requested && !self.status.is_finished(),
+                format_warn,
+            )
+            .fmt_field_option("Error", &self.error, |err| format_stack(err));
+
+        fields.build()
+    }
+}
+
+#[derive(Table)]
+struct BulkOperationTableView {
+    #[table(title = "ID")]
+    pub id: String,
+    #[table(title = "Operation")]
+    pub operation: String,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Matched", justify = "Justify::Right")]
+    pub matched: u64,
+    #[table(title = "Succeeded", justify = "Justify::Right")]
+    pub succeeded: u64,
+    #[table(title = "Failed", justify = "Justify::Right")]
+    pub failed: u64,
+    #[table(title = "Created at")]
+    pub created_at: Timestamp,
+}
+
+impl From<&BulkOperation> for BulkOperationTableView {
+    fn from(value: &BulkOperation) -> Self {
+        Self {
+            id: value.id.to_string(),
+            operation: format_bulk_operation_kind(&value.operation),
+            status: format_bulk_operation_status(&value.status),
+            matched: value.matched,
+            succeeded: value.succeeded,
+            failed: value.failed,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl TextView for BulkOperationList {
+    fn log(&self) {
+        if self.operations.is_empty() {
+            logln("No bulk operations found.");
+            return;
+        }
+
+        log_table::<_, BulkOperationTableView>(&self.operations);
+    }
+}
+
+impl MessageWithFields for RecurringInvocation {
+    fn message(&self) -> String {
+        format!(
+            "Recurring invocation {} of {}",
+            format_message_highlight(&self.id),
+            format_message_highlight(&self.method_name)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("ID", &self.id, format_id)
+            .fmt_field("Agent", &self.worker_id.worker_name, format_main_id)
+            .field("Method", &self.method_name)
+            .field("Schedule", &self.schedule)
+            .field("Missed fire policy", &self.missed_fire_policy)
+            .field("Next fire at", &self.next_fire_at)
+            .field("Created at", &self.created_at);
+
+        fields.build()
+    }
+}
+
+#[derive(Table)]
+struct RecurringInvocationTableView {
+    #[table(title = "ID")]
+    pub id: String,
+    #[table(title = "Method")]
+    pub method_name: String,
+    #[table(title = "Schedule")]
+    pub schedule: String,
+    #[table(title = "Missed fire policy")]
+    pub missed_fire_policy: String,
+    #[table(title = "Next fire at")]
+    pub next_fire_at: Timestamp,
+}
+
+impl From<&RecurringInvocation> for RecurringInvocationTableView {
+    fn from(value: &RecurringInvocation) -> Self {
+        Self {
+            id: value.id.to_string(),
+            method_name: value.method_name.clone(),
+            schedule: value.schedule.to_string(),
+            missed_fire_policy: value.missed_fire_policy.to_string(),
+            next_fire_at: value.next_fire_at,
+        }
+    }
+}
+
+impl TextView for RecurringInvocationList {
+    fn log(&self) {
+        if self.invocations.is_empty() {
+            logln("No recurring invocations found.");
+            return;
+        }
+
+        log_table::<_, RecurringInvocationTableView>(&self.invocations);
+    }
+}
+
+#[derive(Table)]
+struct PendingInvocationTableView {
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Method")]
+    pub method_name: String,
+    #[table(title = "Priority")]
+    pub priority: String,
+    #[table(title = "Enqueued at")]
+    pub timestamp: Timestamp,
+    #[table(title = "Parameters")]
+    pub preview: String,
+}
+
+impl From<&PendingInvocation> for PendingInvocationTableView {
+    fn from(value: &PendingInvocation) -> Self {
+        Self {
+            idempotency_key: value.idempotency_key.value.clone(),
+            method_name: value.method_name.clone(),
+            priority: value.priority.to_string(),
+            timestamp: value.timestamp,
+            preview: value.preview.clone(),
+        }
+    }
+}
+
+impl TextView for InvocationQueue {
+    fn log(&self) {
+        match self.limit {
+            Some(limit) => logln(format!(
+                "Pending invocations: {}, limit: {}",
+                format_message_highlight(&self.invocations.len()),
+                format_message_highlight(&limit)
+            )),
+            None => logln(format!(
+                "Pending invocations: {}, no limit",
+                format_message_highlight(&self.invocations.len())
+            )),
+        }
+
+        if !self.invocations.is_empty() {
+            log_table::<_, PendingInvocationTableView>(&self.invocations);
+        }
+    }
+}
+
 impl TextView for InvokeResultView {
     fn log(&self) {
         fn log_results_format(format: &str) {
@@ -894,6 +1194,96 @@ impl TextView for PublicOplogEntry {
                     }
                 }
             }
+            PublicOplogEntry::ReorderPendingInvocation(params) => {
+                logln(format_message_highlight("REORDER PENDING INVOCATION"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}idempotency key:   {}",
+                    format_id(&params.idempotency_key),
+                ));
+                logln(format!(
+                    "{pad}priority:          {}",
+                    format_id(&params.priority),
+                ));
+                if let Some(position) = &params.position {
+                    logln(format!("{pad}position:          {}", format_id(position)));
+                }
+            }
+            PublicOplogEntry::SetInvocationQueueLimit(params) => {
+                logln(format_message_highlight("SET INVOCATION QUEUE LIMIT"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}limit:             {}",
+                    params
+                        .limit
+                        .map(|limit| format_id(&limit))
+                        .unwrap_or_else(|| "none".to_string()),
+                ));
+            }
+            PublicOplogEntry::BeginSaga(params) => {
+                logln(format_message_highlight("BEGIN SAGA"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+            }
+            PublicOplogEntry::SagaStep(params) => {
+                logln(format_message_highlight("SAGA STEP"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}begin index:       {}",
+                    format_id(&params.begin_index)
+                ));
+                logln(format!(
+                    "{pad}compensate on:     {}",
+                    format_id(&params.compensation.target_worker_id)
+                ));
+                logln(format!(
+                    "{pad}compensation:      {}",
+                    format_id(&params.compensation.method_name)
+                ));
+                logln(format!("{pad}input:"));
+                log_data_value(pad, &params.compensation.input);
+            }
+            PublicOplogEntry::SagaStepCompensated(params) => {
+                logln(format_message_highlight("SAGA STEP COMPENSATED"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}begin index:       {}",
+                    format_id(&params.begin_index)
+                ));
+                logln(format!(
+                    "{pad}step index:        {}",
+                    format_id(&params.step_index)
+                ));
+            }
+            PublicOplogEntry::EndSaga(params) => {
+                logln(format_message_highlight("END SAGA"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}begin index:       {}",
+                    format_id(&params.begin_index)
+                ));
+                logln(format!(
+                    "{pad}compensated:       {}",
+                    format_id(&params.compensated)
+                ));
+            }
         }
     }
 }
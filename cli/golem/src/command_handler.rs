@@ -18,6 +18,11 @@ use clap_verbosity_flag::Verbosity;
 use golem_cli::command::server::{RunArgs, ServerSubcommand};
 use golem_cli::command_handler::CommandHandlerHooks;
 use golem_cli::context::Context;
+use golem_cli::log::{log_action, log_warn, LogColorize};
+use golem_cli::model::oplog_replay::{OplogFixture, ReplayedOplog};
+use golem_common::model::agent::AgentId;
+use golem_common::model::{OplogIndex, WorkerId};
+use golem_worker_executor_embedded::{EmbeddedExecutor, RecordedHostCall};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::debug;
@@ -99,6 +104,64 @@ impl CommandHandlerHooks for ServerCommandHandler {
     fn override_pretty_mode() -> bool {
         true
     }
+
+    async fn replay_oplog(
+        &self,
+        component_wasm: &Path,
+        fixture: &OplogFixture,
+    ) -> anyhow::Result<ReplayedOplog> {
+        let executor = EmbeddedExecutor::start_sandbox().await?;
+        let component = executor.add_component(component_wasm).await?;
+
+        let agent_id = AgentId::parse(&fixture.agent_id, &component.metadata)
+            .map_err(|err| anyhow!("Invalid agent id {}: {err}", fixture.agent_id))?;
+        let worker_id = WorkerId::from_agent_id(component.id, &agent_id)
+            .map_err(|err| anyhow!("Invalid agent id {}: {err}", fixture.agent_id))?;
+
+        executor
+            .use_recorded_host_calls(
+                &component,
+                &agent_id,
+                RecordedHostCall::from_public_oplog(&fixture.entries)?,
+            )
+            .await?;
+
+        let mut invocations = 0;
+        for invocation in fixture.recorded_invocations() {
+            log_action(
+                "Invoking",
+                format!(
+                    "{}, recorded at oplog index {}",
+                    invocation.method_name.log_color_highlight(),
+                    invocation.oplog_index.to_string().log_color_highlight()
+                ),
+            );
+
+            invocations += 1;
+            if let Err(err) = executor
+                .invoke_and_await_agent_with_key(
+                    &component,
+                    &agent_id,
+                    &invocation.idempotency_key,
+                    &invocation.method_name,
+                    invocation.input,
+                )
+                .await
+            {
+                // The failure is recorded in the replayed oplog, so it shows up as a divergence
+                log_warn(format!(
+                    "Replayed invocation failed, stopping replay: {err}"
+                ));
+                break;
+            }
+        }
+
+        Ok(ReplayedOplog {
+            agent_id: agent_id.to_string(),
+            invocations,
+            entries: executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?,
+        })
+    }
 }
 
 fn default_data_dir() -> anyhow::Result<PathBuf> {
@@ -21,6 +21,8 @@ use golem_common::model::account::{AccountEmail, AccountId};
 use golem_common::model::auth::{AccountRole, TokenSecret};
 use golem_common::model::plan::{PlanId, PlanName};
 use golem_common::model::Empty;
+use golem_debugging_service::config::DebugConfig;
+use golem_debugging_service::ServerBootstrap as DebuggingServiceBootstrap;
 use golem_registry_service::config::{
     ComponentCompilationEnabledConfig, LoginConfig, PrecreatedAccount, PrecreatedPlan,
     RegistryServiceConfig,
@@ -41,8 +43,10 @@ use golem_worker_executor::services::golem_config::{
     KeyValueStorageMultiSqliteConfig, ResourceLimitsConfig, ResourceLimitsGrpcConfig,
     ShardManagerServiceConfig, ShardManagerServiceGrpcConfig, WorkerServiceGrpcConfig,
 };
+use golem_worker_executor::Bootstrap;
 use golem_worker_service::config::{
-    RouteResolverConfig, SqliteSessionStoreConfig, WorkerServiceConfig,
+    BulkOperationStorageConfig, BulkOperationsConfig, RouteResolverConfig,
+    SqliteSessionStoreConfig, UpdateCheckConfig, WorkerServiceConfig,
 };
 use golem_worker_service::WorkerService;
 use opentelemetry::global;
@@ -122,8 +126,11 @@ async fn start_components(
 
     let shard_manager = run_shard_manager(shard_manager_config(args), join_set).await?;
 
+    let debugging_service =
+        run_debugging_service(debugging_service_config(args, &registry_service), join_set).await?;
+
     let worker_service = run_worker_service(
-        worker_service_config(args, &shard_manager, &registry_service),
+        worker_service_config(args, &shard_manager, &registry_service, &debugging_service),
         join_set,
     )
     .await?;
@@ -180,6 +187,12 @@ fn registry_service_config(
                 ..Default::default()
             }),
         ),
+        component_transformer: Default::default(),
+        // the local server delivers to receivers running on the developer's machine
+        lifecycle_webhooks: golem_registry_service::config::LifecycleWebhooksConfig {
+            allow_private_targets: true,
+            ..Default::default()
+        },
         blob_storage: blob_storage_config(args),
         initial_plans: {
             let mut plans = HashMap::new();
@@ -325,10 +338,49 @@ fn worker_executor_config(
     config
 }
 
+fn debugging_service_config(
+    args: &LaunchArgs,
+    registry_service_run_details: &golem_registry_service::SingleExecutableRunDetails,
+) -> DebugConfig {
+    DebugConfig {
+        http_address: args.router_addr.clone(),
+        http_port: 0,
+        key_value_storage: KeyValueStorageConfig::MultiSqlite(KeyValueStorageMultiSqliteConfig {
+            root_dir: args.data_dir.join("kv-store"),
+            max_connections: 4,
+            foreign_keys: false,
+        }),
+        indexed_storage: IndexedStorageConfig::KVStoreMultiSqlite(
+            IndexedStorageKVStoreMultiSqliteConfig {},
+        ),
+        blob_storage: blob_storage_config(args),
+        compiled_component_service: CompiledComponentServiceConfig::Enabled(
+            CompiledComponentServiceEnabledConfig {},
+        ),
+        registry_service: golem_service_base::clients::registry::GrpcRegistryServiceConfig {
+            host: args.router_addr.clone(),
+            port: registry_service_run_details.grpc_port,
+            ..Default::default()
+        },
+        agent_types_service: AgentTypesServiceConfig::Grpc(
+            golem_worker_executor::services::golem_config::AgentTypesServiceGrpcConfig {
+                ..Default::default()
+            },
+        ),
+        agent_deployments_service: AgentDeploymentsServiceConfig {
+            cache_capacity: 0,
+            ..Default::default()
+        },
+        cors_origin_regex: ".*".to_string(),
+        ..Default::default()
+    }
+}
+
 fn worker_service_config(
     args: &LaunchArgs,
     shard_manager_run_details: &golem_shard_manager::RunDetails,
     registry_service_run_details: &golem_registry_service::SingleExecutableRunDetails,
+    debugging_service_run_details: &golem_worker_executor::RunDetails,
 ) -> WorkerServiceConfig {
     WorkerServiceConfig {
         port: 0,
@@ -368,6 +420,23 @@ fn worker_service_config(
             router_cache_ttl: Default::default(),
             router_cache_eviction_period: Default::default(),
         },
+        bulk_operations: BulkOperationsConfig {
+            storage: BulkOperationStorageConfig::Sqlite(DbSqliteConfig {
+                database: args
+                    .data_dir
+                    .join("bulk-operations.db")
+                    .to_string_lossy()
+                    .to_string(),
+                max_connections: 4,
+                foreign_keys: false,
+            }),
+            ..Default::default()
+        },
+        update_check: UpdateCheckConfig {
+            debugging_service_host: args.router_addr.clone(),
+            debugging_service_port: debugging_service_run_details.http_port,
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
@@ -420,6 +489,25 @@ async fn run_worker_executor(
         .await
 }
 
+async fn run_debugging_service(
+    config: DebugConfig,
+    join_set: &mut JoinSet<anyhow::Result<()>>,
+) -> Result<golem_worker_executor::RunDetails, anyhow::Error> {
+    let prometheus_registry = golem_worker_executor::metrics::register_all();
+    let span = tracing::info_span!("debugging-service");
+    DebuggingServiceBootstrap {
+        debug_config: config.clone(),
+    }
+    .run(
+        config.into_golem_config(),
+        prometheus_registry,
+        Handle::current(),
+        join_set,
+    )
+    .instrument(span)
+    .await
+}
+
 async fn run_worker_service(
     config: WorkerServiceConfig,
     join_set: &mut JoinSet<anyhow::Result<()>>,
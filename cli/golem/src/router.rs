@@ -52,6 +52,22 @@ pub fn start_router(
     let app = Route::new()
         // Worker endpoints
         .at("/v1/agents/invoke-agent", worker_service_api.clone())
+        .at(
+            "/v1/components/:component_id/bulk-operations",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/bulk-operations/:operation_id",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/bulk-operations/:operation_id/cancel",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/update-check",
+            worker_service_api.clone(),
+        )
         .at(
             "/v1/components/:component_id/workers",
             worker_service_api.clone(),
@@ -96,6 +112,22 @@ pub fn start_router(
             "/v1/components/:component_id/workers/:worker_name/interrupt",
             worker_service_api.clone(),
         )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/invocation-queue",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/invocation-queue/limit",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/invocation-queue/purge",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/invocation-queue/reorder",
+            worker_service_api.clone(),
+        )
         .at(
             "/v1/components/:component_id/workers/:worker_name/invocations/:idempotency_key",
             worker_service_api.clone(),
@@ -112,6 +144,18 @@ pub fn start_router(
             "/v1/components/:component_id/workers/:worker_name/oplog",
             worker_service_api.clone(),
         )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/oplog/follow",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/recurring-invocations",
+            worker_service_api.clone(),
+        )
+        .at(
+            "/v1/components/:component_id/workers/:worker_name/recurring-invocations/:id",
+            worker_service_api.clone(),
+        )
         .at(
             "/v1/components/:component_id/workers/:worker_name/resume",
             worker_service_api.clone(),
@@ -124,6 +168,11 @@ pub fn start_router(
             "/v1/components/:component_id/workers/:worker_name/update",
             worker_service_api.clone(),
         )
+        .at(
+            "/v1/envs/:environment_id/topics/:topic/messages",
+            worker_service_api.clone(),
+        )
+        .at("/v1/executors", worker_service_api.clone())
         // Metrics
         .at("/metrics", metrics)
         // Everything else is routed to registry service
@@ -94,6 +94,27 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "TokenWithSecret",
                 "golem_common::model::auth::TokenWithSecret",
             ),
+            // bulk_operation
+            (
+                "BulkOperation",
+                "golem_common::model::bulk_operation::BulkOperation",
+            ),
+            (
+                "BulkOperationKind",
+                "golem_common::model::bulk_operation::BulkOperationKind",
+            ),
+            (
+                "BulkOperationList",
+                "golem_common::model::bulk_operation::BulkOperationList",
+            ),
+            (
+                "BulkOperationRequest",
+                "golem_common::model::bulk_operation::BulkOperationRequest",
+            ),
+            (
+                "BulkOperationStatus",
+                "golem_common::model::bulk_operation::BulkOperationStatus",
+            ),
             // component
             (
                 "ComponentCreation",
@@ -163,6 +184,14 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "EnvironmentWithDetails",
                 "golem_common::model::environment::EnvironmentWithDetails",
             ),
+            (
+                "NetworkEgressPolicy",
+                "golem_common::model::environment::NetworkEgressPolicy",
+            ),
+            (
+                "NetworkEgressRule",
+                "golem_common::model::environment::NetworkEgressRule",
+            ),
             // environment_plugin_grant
             (
                 "EnvironmentPluginGrant",
@@ -189,6 +218,73 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "EnvironmentShareUpdate",
                 "golem_common::model::environment_share::EnvironmentShareUpdate",
             ),
+            // executor
+            (
+                "ExecutorSummary",
+                "golem_common::model::executor::ExecutorSummary",
+            ),
+            (
+                "ExecutorsSummary",
+                "golem_common::model::executor::ExecutorsSummary",
+            ),
+            // invocation_queue
+            (
+                "InvocationPriority",
+                "golem_common::model::invocation_queue::InvocationPriority",
+            ),
+            (
+                "InvocationQueue",
+                "golem_common::model::invocation_queue::InvocationQueue",
+            ),
+            (
+                "InvocationQueueLimit",
+                "golem_common::model::invocation_queue::InvocationQueueLimit",
+            ),
+            (
+                "InvocationQueuePurge",
+                "golem_common::model::invocation_queue::InvocationQueuePurge",
+            ),
+            (
+                "InvocationQueuePurgeResult",
+                "golem_common::model::invocation_queue::InvocationQueuePurgeResult",
+            ),
+            (
+                "InvocationQueueReorder",
+                "golem_common::model::invocation_queue::InvocationQueueReorder",
+            ),
+            (
+                "PendingInvocation",
+                "golem_common::model::invocation_queue::PendingInvocation",
+            ),
+            // lifecycle_webhook
+            (
+                "AgentLifecycleEvent",
+                "golem_common::model::lifecycle_webhook::AgentLifecycleEvent",
+            ),
+            (
+                "AgentLifecycleEventType",
+                "golem_common::model::lifecycle_webhook::AgentLifecycleEventType",
+            ),
+            (
+                "LifecycleWebhook",
+                "golem_common::model::lifecycle_webhook::LifecycleWebhook",
+            ),
+            (
+                "LifecycleWebhookCreation",
+                "golem_common::model::lifecycle_webhook::LifecycleWebhookCreation",
+            ),
+            (
+                "LifecycleWebhookDelivery",
+                "golem_common::model::lifecycle_webhook::LifecycleWebhookDelivery",
+            ),
+            (
+                "LifecycleWebhookDeliveryStatus",
+                "golem_common::model::lifecycle_webhook::LifecycleWebhookDeliveryStatus",
+            ),
+            (
+                "LifecycleWebhookWithSecret",
+                "golem_common::model::lifecycle_webhook::LifecycleWebhookWithSecret",
+            ),
             // login
             (
                 "OAuth2DeviceflowData",
@@ -209,6 +305,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "ComponentTransformerPluginSpec",
                 "golem_common::model::plugin_registration::ComponentTransformerPluginSpec",
             ),
+            (
+                "LibraryPluginSpec",
+                "golem_common::model::plugin_registration::LibraryPluginSpec",
+            ),
             (
                 "OplogProcessorPluginSpec",
                 "golem_common::model::plugin_registration::OplogProcessorPluginSpec",
@@ -221,6 +321,44 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "PluginRegistrationDto",
                 "golem_common::model::plugin_registration::PluginRegistrationDto",
             ),
+            // pubsub
+            (
+                "TopicMessagePublication",
+                "golem_common::model::pubsub::TopicMessagePublication",
+            ),
+            (
+                "TopicMessagePublished",
+                "golem_common::model::pubsub::TopicMessagePublished",
+            ),
+            // recurring_invocation
+            (
+                "CronSchedule",
+                "golem_common::model::recurring_invocation::CronSchedule",
+            ),
+            (
+                "IntervalSchedule",
+                "golem_common::model::recurring_invocation::IntervalSchedule",
+            ),
+            (
+                "MissedFirePolicy",
+                "golem_common::model::recurring_invocation::MissedFirePolicy",
+            ),
+            (
+                "RecurringInvocation",
+                "golem_common::model::recurring_invocation::RecurringInvocation",
+            ),
+            (
+                "RecurringInvocationCreation",
+                "golem_common::model::recurring_invocation::RecurringInvocationCreation",
+            ),
+            (
+                "RecurringInvocationList",
+                "golem_common::model::recurring_invocation::RecurringInvocationList",
+            ),
+            (
+                "RecurringSchedule",
+                "golem_common::model::recurring_invocation::RecurringSchedule",
+            ),
             // reports
             (
                 "AccountCountsReport",
@@ -230,6 +368,14 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "AccountSummaryReport",
                 "golem_common::model::reports::AccountSummaryReport",
             ),
+            (
+                "AgentTypeUsage",
+                "golem_common::model::reports::AgentTypeUsage",
+            ),
+            (
+                "EnvironmentUsageReport",
+                "golem_common::model::reports::EnvironmentUsageReport",
+            ),
             // security_scheme
             (
                 "SecuritySchemeCreation",
@@ -244,6 +390,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "golem_common::model::security_scheme::SecuritySchemeUpdate",
             ),
             // worker
+            (
+                "AgentUpdateCheckResult",
+                "golem_common::model::worker::AgentUpdateCheckResult",
+            ),
             (
                 "FlatComponentFileSystemNode",
                 "golem_common::model::worker::FlatComponentFileSystemNode",
@@ -252,6 +402,14 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "RevertWorkerTarget",
                 "golem_common::model::worker::RevertWorkerTarget",
             ),
+            (
+                "UpdateCheckRequest",
+                "golem_common::model::worker::UpdateCheckRequest",
+            ),
+            (
+                "UpdateCheckResponse",
+                "golem_common::model::worker::UpdateCheckResponse",
+            ),
             (
                 "WorkerCreationRequest",
                 "golem_common::model::worker::WorkerCreationRequest",
@@ -326,7 +326,7 @@ pub struct AgentConstructor {
     feature = "full",
     derive(desert_rust::BinaryCodec, poem_openapi::Object, IntoValue, FromValue)
 )]
-#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", desert(evolution(FieldAdded("read_only", false))))]
 #[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
 #[serde(rename_all = "camelCase")]
 pub struct AgentMethod {
@@ -336,6 +336,11 @@ pub struct AgentMethod {
     pub input_schema: DataSchema,
     pub output_schema: DataSchema,
     pub http_endpoint: Vec<HttpEndpointDetails>,
+    /// Read-only methods are served against the agent's current in-memory state without being
+    /// persisted, and are not allowed to perform durable side effects
+    #[serde(default)]
+    #[cfg_attr(feature = "full", oai(default))]
+    pub read_only: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
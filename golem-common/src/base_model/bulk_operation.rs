@@ -0,0 +1,129 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_model::account::AccountId;
+use crate::base_model::component::{ComponentId, ComponentRevision, PluginPriority};
+use crate::base_model::worker::{RevertWorkerTarget, WorkerUpdateMode};
+use crate::base_model::worker_filter::WorkerFilter;
+use crate::base_model::{Timestamp, WorkerId};
+use crate::{declare_enums, declare_structs, declare_unions, newtype_uuid};
+use std::fmt::{Display, Formatter};
+
+newtype_uuid!(BulkOperationId);
+
+declare_unions! {
+    pub enum BulkOperationKind {
+        Interrupt(BulkInterrupt),
+        Resume(BulkResume),
+        Update(BulkUpdate),
+        Delete(BulkDelete),
+        ActivatePlugin(BulkActivatePlugin),
+        DeactivatePlugin(BulkDeactivatePlugin),
+        Revert(BulkRevert),
+    }
+}
+
+declare_enums! {
+    pub enum BulkOperationStatus {
+        Running,
+        Completed,
+        Cancelled,
+        Failed,
+    }
+}
+
+impl BulkOperationStatus {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, BulkOperationStatus::Running)
+    }
+}
+
+impl Display for BulkOperationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BulkOperationStatus::Running => write!(f, "running"),
+            BulkOperationStatus::Completed => write!(f, "completed"),
+            BulkOperationStatus::Cancelled => write!(f, "cancelled"),
+            BulkOperationStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+declare_structs! {
+    pub struct BulkInterrupt {
+        pub recover_immediately: bool,
+    }
+
+    pub struct BulkResume {
+        pub force: bool,
+    }
+
+    pub struct BulkUpdate {
+        pub mode: WorkerUpdateMode,
+        pub target_revision: ComponentRevision,
+        pub disable_wakeup: bool,
+    }
+
+    pub struct BulkDelete {}
+
+    pub struct BulkActivatePlugin {
+        pub plugin_priority: PluginPriority,
+    }
+
+    pub struct BulkDeactivatePlugin {
+        pub plugin_priority: PluginPriority,
+    }
+
+    pub struct BulkRevert {
+        pub target: RevertWorkerTarget,
+    }
+
+    pub struct BulkOperationRequest {
+        /// Selects the agents to apply the operation to. All agents of the component are
+        /// selected if not specified.
+        pub filter: Option<WorkerFilter>,
+        pub operation: BulkOperationKind,
+        /// Maximum number of agents processed at the same time
+        pub concurrency: Option<u32>,
+    }
+
+    pub struct BulkOperationFailure {
+        pub worker_id: WorkerId,
+        pub error: String,
+    }
+
+    pub struct BulkOperation {
+        pub id: BulkOperationId,
+        pub component_id: ComponentId,
+        pub created_by: AccountId,
+        pub filter: Option<WorkerFilter>,
+        pub operation: BulkOperationKind,
+        pub status: BulkOperationStatus,
+        pub created_at: Timestamp,
+        pub updated_at: Timestamp,
+        /// Number of agents matching the filter found so far
+        pub matched: u64,
+        pub succeeded: u64,
+        pub failed: u64,
+        /// The first failures of the operation, capped to a limited number of entries
+        pub failures: Vec<BulkOperationFailure>,
+        pub cancel_requested: bool,
+        /// The reason of the failure if the operation could not enumerate the selected agents
+        pub error: Option<String>,
+    }
+
+    pub struct BulkOperationList {
+        pub operations: Vec<BulkOperation>,
+    }
+}
@@ -119,6 +119,22 @@ declare_structs! {
         pub value: serde_json::Value
     }
 
+    /// Resource limits applied to every agent of an agent type, on top of the limits of the account
+    pub struct AgentResourceLimits {
+        pub agent: AgentTypeName,
+        /// Maximum size of the agent's linear memory, in bytes
+        pub max_memory_bytes: Option<u64>,
+        /// Maximum amount of fuel a single invocation can consume
+        pub fuel_per_invocation: Option<u64>,
+        /// Maximum wall clock time of a single invocation, in milliseconds
+        pub max_invocation_time_millis: Option<u64>,
+        /// Maximum number of oplog entries a single invocation can add. It does not bound the total
+        /// size of the agent's oplog.
+        pub max_oplog_entries_per_invocation: Option<u64>,
+        /// Maximum number of outgoing HTTP requests the agent can send per minute
+        pub max_outgoing_http_requests_per_minute: Option<u64>,
+    }
+
     pub struct ComponentDto {
         pub id: ComponentId,
         pub revision: ComponentRevision,
@@ -135,7 +151,14 @@ declare_structs! {
         pub env: BTreeMap<String, String>,
         pub config_vars: BTreeMap<String, String>,
         pub local_agent_config: Vec<LocalAgentConfigEntry>,
+        #[serde(default)]
+        #[cfg_attr(feature = "full", oai(default))]
+        pub agent_resource_limits: Vec<AgentResourceLimits>,
         pub wasm_hash: diff::Hash,
+        /// Component transformer and library plugins applied to the uploaded binary, in the order they were applied
+        #[serde(default)]
+        #[cfg_attr(feature = "full", oai(default))]
+        pub transformations: Vec<AppliedComponentTransformation>,
     }
 
     pub struct ComponentCreation {
@@ -154,6 +177,9 @@ declare_structs! {
         pub local_agent_config: Vec<LocalAgentConfigEntry>,
         #[serde(default)]
         #[cfg_attr(feature = "full", oai(default))]
+        pub agent_resource_limits: Vec<AgentResourceLimits>,
+        #[serde(default)]
+        #[cfg_attr(feature = "full", oai(default))]
         pub agent_types: Vec<AgentType>,
         #[serde(default)]
         #[cfg_attr(feature = "full", oai(default))]
@@ -171,6 +197,7 @@ declare_structs! {
         pub env: Option<BTreeMap<String, String>>,
         pub config_vars: Option<BTreeMap<String, String>>,
         pub local_agent_config: Option<Vec<LocalAgentConfigEntry>>,
+        pub agent_resource_limits: Option<Vec<AgentResourceLimits>>,
         pub agent_types: Option<Vec<AgentType>>,
         #[serde(default)]
         #[cfg_attr(feature = "full", oai(default))]
@@ -214,6 +241,20 @@ declare_structs! {
         // oplog processor only
         pub oplog_processor_component_id: Option<ComponentId>,
         pub oplog_processor_component_revision: Option<ComponentRevision>,
+
+        // library only
+        pub library_component_id: Option<ComponentId>,
+        pub library_component_revision: Option<ComponentRevision>,
+    }
+
+    /// A component transformer or library plugin that has been applied to a component revision
+    pub struct AppliedComponentTransformation {
+        pub environment_plugin_grant_id: EnvironmentPluginGrantId,
+        pub plugin_registration_id: PluginRegistrationId,
+        pub plugin_name: String,
+        pub plugin_version: String,
+        /// Hash of the binary produced by the transformation
+        pub result_hash: diff::Hash,
     }
 
     pub struct InitialComponentFile {
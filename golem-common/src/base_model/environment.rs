@@ -78,6 +78,31 @@ declare_structs! {
         pub compatibility_check: Option<bool>,
         pub version_check: Option<bool>,
         pub security_overrides: Option<bool>,
+        pub network_egress_policy: Option<NetworkEgressPolicy>,
+    }
+
+    /// Restricts the outgoing network connections (HTTP requests, TCP and UDP sockets, name lookups)
+    /// of the agents running in an environment. The default policy allows everything.
+    #[derive(Default)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub struct NetworkEgressPolicy {
+        /// Denies all outgoing network access, regardless of the rules
+        pub no_network: bool,
+        /// If not empty, only destinations matching at least one of these rules are allowed
+        pub allow: Vec<NetworkEgressRule>,
+        /// Destinations matching any of these rules are denied, even if they match an allow rule
+        pub deny: Vec<NetworkEgressRule>,
+    }
+
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub struct NetworkEgressRule {
+        /// Domain name (`*.example.com` also matches all subdomains), IP address, CIDR block
+        /// (`10.0.0.0/8`) or `*` matching every host
+        pub host: String,
+        /// Destination ports the rule applies to, all ports if empty
+        pub ports: Vec<u16>,
     }
 
     pub struct EnvironmentCurrentDeploymentView {
@@ -95,6 +120,7 @@ declare_structs! {
         pub compatibility_check: bool,
         pub version_check: bool,
         pub security_overrides: bool,
+        pub network_egress_policy: NetworkEgressPolicy,
 
         pub owner_account_id: AccountId,
         /// Roles in the environment that were given to the current user by shares. The owner always has full access.
@@ -0,0 +1,30 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::declare_structs;
+
+declare_structs! {
+    /// A worker executor and the number of shards currently assigned to it
+    pub struct ExecutorSummary {
+        pub host: String,
+        pub port: u16,
+        pub shard_count: u32,
+    }
+
+    /// Summary of the routing table of the cluster, listing every worker executor with assigned shards
+    pub struct ExecutorsSummary {
+        pub number_of_shards: u32,
+        pub executors: Vec<ExecutorSummary>,
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_model::{IdempotencyKey, Timestamp};
+use crate::{declare_enums, declare_structs};
+use golem_wasm_derive::{FromValue, IntoValue};
+use std::fmt::{Display, Formatter};
+
+declare_enums! {
+    /// Priority class of an invocation. Pending invocations with a higher priority are processed
+    /// before the ones with a lower priority, invocations within the same class are processed in
+    /// the order they arrived.
+    #[derive(Default, Hash, PartialOrd, Ord, IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[wit(name = "invocation-priority", owner = "golem:api@1.5.0/oplog")]
+    pub enum InvocationPriority {
+        Low,
+        #[default]
+        Normal,
+        High,
+    }
+}
+
+impl Display for InvocationPriority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvocationPriority::Low => write!(f, "low"),
+            InvocationPriority::Normal => write!(f, "normal"),
+            InvocationPriority::High => write!(f, "high"),
+        }
+    }
+}
+
+declare_structs! {
+    /// An invocation waiting in an agent's invocation queue
+    pub struct PendingInvocation {
+        pub idempotency_key: IdempotencyKey,
+        /// The time the invocation has been enqueued
+        pub timestamp: Timestamp,
+        pub method_name: String,
+        pub priority: InvocationPriority,
+        /// Shortened, human readable rendering of the invocation's parameters
+        pub preview: String,
+    }
+
+    /// The pending invocations of an agent, in the order they are going to be processed
+    pub struct InvocationQueue {
+        pub invocations: Vec<PendingInvocation>,
+        /// The maximum number of pending invocations. New invocations are rejected when the queue is full.
+        pub limit: Option<u64>,
+    }
+
+    pub struct InvocationQueueReorder {
+        pub idempotency_key: IdempotencyKey,
+        /// The new priority of the invocation. Defaults to its current priority.
+        pub priority: Option<InvocationPriority>,
+        /// Zero-based target position in the queue. If not specified, the invocation is placed
+        /// after the last invocation with the same or higher priority.
+        pub position: Option<u64>,
+    }
+
+    pub struct InvocationQueuePurge {
+        /// Every pending invocation of this method gets canceled
+        pub method_name: String,
+    }
+
+    pub struct InvocationQueuePurgeResult {
+        pub purged: u64,
+    }
+
+    pub struct InvocationQueueLimit {
+        /// The maximum number of pending invocations, or no limit if not specified
+        pub limit: Option<u64>,
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_model::agent::AgentTypeName;
+use crate::base_model::component::ComponentRevision;
+use crate::base_model::environment::EnvironmentId;
+use crate::base_model::WorkerId;
+use crate::{declare_enums, declare_structs, newtype_uuid};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+use strum_macros::{Display, EnumIter, EnumString};
+
+newtype_uuid!(LifecycleWebhookId);
+newtype_uuid!(LifecycleWebhookDeliveryId);
+newtype_uuid!(AgentLifecycleEventId);
+
+declare_enums! {
+    #[derive(Hash, PartialOrd, Ord, Display, EnumIter, EnumString)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum AgentLifecycleEventType {
+        Created,
+        Failed,
+        Exited,
+        Interrupted,
+        Updated,
+        UpdateFailed,
+        InvocationFailed,
+    }
+
+    #[derive(Hash, Display, EnumString)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum LifecycleWebhookDeliveryStatus {
+        Pending,
+        Delivered,
+        Failed,
+    }
+}
+
+declare_structs! {
+    /// Event posted to the lifecycle webhooks of an environment when one of its agents changes state
+    pub struct AgentLifecycleEvent {
+        pub id: AgentLifecycleEventId,
+        pub environment_id: EnvironmentId,
+        pub worker_id: WorkerId,
+        pub agent_type: Option<AgentTypeName>,
+        pub event_type: AgentLifecycleEventType,
+        pub component_revision: ComponentRevision,
+        pub timestamp: DateTime<Utc>,
+        pub error: Option<String>,
+    }
+
+    pub struct LifecycleWebhookCreation {
+        /// The URL the events are posted to
+        pub url: String,
+        /// The event types to deliver, all event types are delivered if empty
+        pub event_types: BTreeSet<AgentLifecycleEventType>,
+    }
+
+    pub struct LifecycleWebhook {
+        pub id: LifecycleWebhookId,
+        pub environment_id: EnvironmentId,
+        pub url: String,
+        pub event_types: BTreeSet<AgentLifecycleEventType>,
+        pub created_at: DateTime<Utc>,
+    }
+
+    /// A newly created lifecycle webhook, including the secret used for signing its deliveries.
+    /// The secret cannot be retrieved later.
+    pub struct LifecycleWebhookWithSecret {
+        pub id: LifecycleWebhookId,
+        pub environment_id: EnvironmentId,
+        pub url: String,
+        pub event_types: BTreeSet<AgentLifecycleEventType>,
+        pub created_at: DateTime<Utc>,
+        pub signing_secret: String,
+    }
+
+    pub struct LifecycleWebhookDelivery {
+        pub id: LifecycleWebhookDeliveryId,
+        pub webhook_id: LifecycleWebhookId,
+        pub event: AgentLifecycleEvent,
+        pub status: LifecycleWebhookDeliveryStatus,
+        pub attempts: u32,
+        pub last_status_code: Option<u16>,
+        pub last_error: Option<String>,
+        pub created_at: DateTime<Utc>,
+        pub last_attempt_at: Option<DateTime<Utc>>,
+    }
+}
+
+impl LifecycleWebhook {
+    pub fn accepts(&self, event_type: AgentLifecycleEventType) -> bool {
+        self.event_types.is_empty() || self.event_types.contains(&event_type)
+    }
+}
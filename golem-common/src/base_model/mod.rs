@@ -17,6 +17,7 @@ pub mod agent;
 pub mod application;
 pub mod auth;
 pub mod base64;
+pub mod bulk_operation;
 pub mod component;
 #[allow(unused_assignments)]
 // NOTE: from rust 1.92, a `value assigned to `cache` is never read` warning is emitted, most likely from the derived BinaryCodec. To be fixed in desert
@@ -28,13 +29,18 @@ pub mod environment;
 pub mod environment_plugin_grant;
 pub mod environment_share;
 pub mod error;
+pub mod executor;
 pub mod http_api_deployment;
 pub mod invocation_context;
+pub mod invocation_queue;
+pub mod lifecycle_webhook;
 pub mod login;
 pub mod mcp_deployment;
 pub mod oplog;
 pub mod plan;
 pub mod plugin_registration;
+pub mod pubsub;
+pub mod recurring_invocation;
 pub mod regions;
 pub mod reports;
 pub mod security_scheme;
@@ -342,6 +348,32 @@ impl Display for PromiseId {
     }
 }
 
+/// The final state of a promise
+#[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, IntoValue, FromValue)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+pub enum PromiseOutcome {
+    /// The promise was completed successfully with the given payload
+    Completed(Vec<u8>),
+    /// The promise was failed (rejected) with the given error payload
+    Failed(Vec<u8>),
+    /// The promise was not completed or failed before its expiry
+    Expired,
+}
+
+impl Display for PromiseOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PromiseOutcome::Completed(_) => write!(f, "completed"),
+            PromiseOutcome::Failed(_) => write!(f, "failed"),
+            PromiseOutcome::Expired => write!(f, "expired"),
+        }
+    }
+}
+
 #[derive(
     Debug,
     Copy,
@@ -19,6 +19,7 @@ use crate::base_model::account::AccountId;
 use crate::base_model::component::ComponentRevision;
 use crate::base_model::environment::EnvironmentId;
 use crate::base_model::invocation_context::{SpanId, TraceId};
+use crate::base_model::invocation_queue::InvocationPriority;
 use crate::base_model::regions::OplogRegion;
 use crate::base_model::{IdempotencyKey, OplogIndex, Timestamp, TransactionId, WorkerId};
 use crate::model::worker::{
@@ -544,5 +545,79 @@ oplog_entry! {
         public {
             data: PublicSnapshotData
         }
+    },
+    /// Moves a pending invocation to a new place in the invocation queue. If no position is given,
+    /// the invocation is placed after the last pending invocation with the same or higher priority.
+    ReorderPendingInvocation {
+        hint: true
+        wit_raw_type: "reorder-pending-invocation-parameters"
+        raw {
+            idempotency_key: IdempotencyKey,
+            priority: InvocationPriority,
+            position: Option<u64>,
+        }
+        public {
+            idempotency_key: IdempotencyKey,
+            priority: InvocationPriority,
+            position: Option<u64>,
+        }
+    },
+    /// Changes the maximum number of pending invocations of the agent
+    SetInvocationQueueLimit {
+        hint: true
+        wit_raw_type: "set-invocation-queue-limit-parameters"
+        raw {
+            limit: Option<u64>,
+        }
+        public {
+            limit: Option<u64>,
+        }
+    },
+    /// Marks the beginning of a saga coordinated by this agent. The index of this entry identifies the saga.
+    BeginSaga {
+        hint: false
+        wit_raw_type: "timestamp"
+        raw {}
+        public {}
+    },
+    /// A successfully performed step of an open saga, recording the agent method call compensating it
+    SagaStep {
+        hint: false
+        wit_raw_type: "raw-saga-step-parameters"
+        raw {
+            begin_index: OplogIndex,
+            compensation: SagaCompensation,
+        }
+        public {
+            begin_index: OplogIndex,
+            compensation: PublicSagaCompensation,
+        }
+    },
+    /// The compensation of the saga step recorded at `step_index` has been performed. It is a hint entry
+    /// because it can also be written by the executor after the coordinator failed permanently.
+    SagaStepCompensated {
+        hint: true
+        wit_raw_type: "saga-step-compensated-parameters"
+        raw {
+            begin_index: OplogIndex,
+            step_index: OplogIndex,
+        }
+        public {
+            begin_index: OplogIndex,
+            step_index: OplogIndex,
+        }
+    },
+    /// Closes a saga, either because it was completed or because all of its steps have been compensated
+    EndSaga {
+        hint: true
+        wit_raw_type: "end-saga-parameters"
+        raw {
+            begin_index: OplogIndex,
+            compensated: bool,
+        }
+        public {
+            begin_index: OplogIndex,
+            compensated: bool,
+        }
     }
 }
@@ -196,6 +196,21 @@ macro_rules! oplog_entry {
                 }
             )),*
         }
+
+        impl PublicOplogEntry {
+            /// Name of the entry's case, the same as the `type` discriminator of its JSON representation
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    $(Self::$case(_) => stringify!($case)),*
+                }
+            }
+
+            pub fn timestamp(&self) -> Timestamp {
+                match self {
+                    $(Self::$case(params) => params.timestamp),*
+                }
+            }
+        }
     }
 }
 
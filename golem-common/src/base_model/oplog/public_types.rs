@@ -16,7 +16,7 @@ use crate::base_model::component::{ComponentRevision, PluginPriority};
 use crate::base_model::invocation_context::{SpanId, TraceId};
 use crate::base_model::oplog::public_oplog_entry::{Deserialize, Serialize};
 use crate::base_model::oplog::PublicOplogEntry;
-use crate::base_model::{Empty, IdempotencyKey, OplogIndex, Timestamp};
+use crate::base_model::{Empty, IdempotencyKey, OplogIndex, Timestamp, WorkerId};
 use crate::declare_structs;
 use crate::model::agent::{DataSchema, DataValue, UntypedDataValue};
 use golem_wasm_derive::{FromValue, IntoValue};
@@ -267,6 +267,17 @@ pub struct AgentMethodInvocationParameters {
     pub invocation_context: Vec<Vec<PublicSpanData>>,
 }
 
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize)]
+#[cfg_attr(feature = "full", derive(poem_openapi::Object, IntoValue, FromValue))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct PublicSagaCompensation {
+    pub target_worker_id: WorkerId,
+    pub method_name: String,
+    #[cfg_attr(feature = "full", wit_field(try_convert = TypedDataValue))]
+    pub input: DataValue,
+}
+
 #[derive(Clone, Debug, Serialize, PartialEq, Deserialize)]
 #[cfg_attr(feature = "full", derive(poem_openapi::Object, IntoValue, FromValue))]
 #[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
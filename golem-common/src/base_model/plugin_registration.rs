@@ -47,10 +47,34 @@ declare_structs! {
         pub component_id: ComponentId,
         pub component_revision: ComponentRevision
     }
+
+    /// A plugin rewriting the WebAssembly binary of the components it is installed to, every time a new
+    /// component revision gets created.
+    ///
+    /// The transformer is either an HTTP endpoint (`transform_url`) or a component exporting the
+    /// `golem:api/component-transformer` interface, run in-process by the registry (`component_id` and
+    /// `component_revision`). Exactly one of them must be specified.
+    pub struct ComponentTransformerPluginSpec {
+        /// Endpoint receiving the component binary and the installation parameters as a multipart form,
+        /// responding with the transformed binary
+        pub transform_url: Option<String>,
+        pub component_id: Option<ComponentId>,
+        pub component_revision: Option<ComponentRevision>,
+    }
+
+    /// A plugin providing implementations for some of the interfaces imported by the components it is
+    /// installed to. The library component gets composed into the installing components every time a
+    /// new component revision gets created.
+    pub struct LibraryPluginSpec {
+        pub component_id: ComponentId,
+        pub component_revision: ComponentRevision,
+    }
 }
 
 declare_unions! {
     pub enum PluginSpecDto {
-        OplogProcessor(OplogProcessorPluginSpec)
+        OplogProcessor(OplogProcessorPluginSpec),
+        ComponentTransformer(ComponentTransformerPluginSpec),
+        Library(LibraryPluginSpec),
     }
 }
@@ -0,0 +1,29 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_model::agent::DataValue;
+use crate::declare_structs;
+
+declare_structs! {
+    /// A message to be published to a topic of an environment
+    pub struct TopicMessagePublication {
+        /// The message, delivered as the parameters of the subscribed handler methods
+        pub message: DataValue,
+    }
+
+    pub struct TopicMessagePublished {
+        /// The offset of the published message within the topic
+        pub offset: u64,
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_model::agent::UntypedJsonDataValue;
+use crate::base_model::{Timestamp, WorkerId};
+use crate::{declare_enums, declare_structs, declare_unions, newtype_uuid};
+use golem_wasm_derive::{FromValue, IntoValue};
+use std::fmt::{Display, Formatter};
+
+newtype_uuid!(
+    RecurringInvocationId,
+    golem_api_grpc::proto::golem::worker::RecurringInvocationId
+);
+
+declare_unions! {
+    /// Defines when a recurring invocation fires
+    #[derive(IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub enum RecurringSchedule {
+        Cron(CronSchedule),
+        Interval(IntervalSchedule),
+    }
+}
+
+declare_enums! {
+    /// Defines what happens with the occurrences of a recurring invocation which passed
+    /// while the schedule could not be processed (for example because no executor was running)
+    #[derive(Default, IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    pub enum MissedFirePolicy {
+        /// Missed occurrences are dropped, the next invocation happens at the next occurrence
+        Skip,
+        /// A single invocation is made for all the missed occurrences
+        #[default]
+        RunOnce,
+        /// An invocation is made for each missed occurrence, up to 100 occurrences at once.
+        /// Occurrences beyond this limit are dropped, and the next invocation happens at the
+        /// next occurrence
+        CatchUp,
+    }
+}
+
+impl Display for MissedFirePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissedFirePolicy::Skip => write!(f, "skip"),
+            MissedFirePolicy::RunOnce => write!(f, "run-once"),
+            MissedFirePolicy::CatchUp => write!(f, "catch-up"),
+        }
+    }
+}
+
+declare_structs! {
+    #[derive(IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub struct CronSchedule {
+        /// Cron expression with five fields (minute, hour, day of month, month, day of week),
+        /// evaluated in UTC. The `@hourly`, `@daily`, `@weekly`, `@monthly` and `@yearly` shorthands
+        /// are also accepted.
+        pub expression: String,
+    }
+
+    #[derive(IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub struct IntervalSchedule {
+        /// Time between two invocations, in milliseconds
+        pub interval_millis: u64,
+    }
+
+    /// A method invocation scheduled to repeat on an agent
+    #[derive(IntoValue, FromValue)]
+    #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec))]
+    #[cfg_attr(feature = "full", desert(evolution()))]
+    pub struct RecurringInvocation {
+        pub id: RecurringInvocationId,
+        pub worker_id: WorkerId,
+        pub method_name: String,
+        pub schedule: RecurringSchedule,
+        pub missed_fire_policy: MissedFirePolicy,
+        /// The next time the invocation is going to be enqueued
+        pub next_fire_at: Timestamp,
+        pub created_at: Timestamp,
+    }
+
+    pub struct RecurringInvocationCreation {
+        pub method_name: String,
+        pub method_parameters: UntypedJsonDataValue,
+        pub schedule: RecurringSchedule,
+        /// Defaults to `run-once`
+        pub missed_fire_policy: Option<MissedFirePolicy>,
+    }
+
+    pub struct RecurringInvocationList {
+        pub invocations: Vec<RecurringInvocation>,
+    }
+}
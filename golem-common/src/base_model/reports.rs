@@ -14,6 +14,9 @@
 
 use crate::declare_structs;
 use crate::model::account::AccountId;
+use crate::model::agent::AgentTypeName;
+use crate::model::component::ComponentId;
+use crate::model::environment::EnvironmentId;
 use chrono::DateTime;
 use chrono::Utc;
 use std::fmt::Debug;
@@ -33,4 +36,24 @@ declare_structs! {
         pub total_active_accounts: u64,
         pub total_deleted_accounts: u64
     }
+
+    /// Resource usage of the agents of an agent type, aggregated over the time range of a report
+    pub struct AgentTypeUsage {
+        pub component_id: ComponentId,
+        pub agent_type: AgentTypeName,
+        pub invocations: u64,
+        pub fuel: u64,
+        pub invocation_time_millis: u64,
+        pub oplog_bytes: u64,
+        pub outgoing_http_requests: u64,
+    }
+
+    /// Resource usage of the agents of an environment between `from` (inclusive) and `to` (exclusive).
+    /// Usage is recorded in hourly buckets, so the range is extended to whole hours.
+    pub struct EnvironmentUsageReport {
+        pub environment_id: EnvironmentId,
+        pub from: DateTime<Utc>,
+        pub to: DateTime<Utc>,
+        pub agent_types: Vec<AgentTypeUsage>,
+    }
 }
@@ -17,6 +17,7 @@ use crate::base_model::component::{ComponentFilePermissions, ComponentRevision,
 use crate::base_model::environment::EnvironmentId;
 use crate::base_model::oplog::WorkerResourceId;
 use crate::base_model::regions::OplogRegion;
+use crate::base_model::worker_filter::WorkerFilter;
 use crate::base_model::{OplogIndex, Timestamp, WorkerId, WorkerResourceDescription, WorkerStatus};
 use crate::{declare_enums, declare_structs, declare_unions};
 use golem_wasm_derive::{FromValue, IntoValue};
@@ -136,7 +137,10 @@ declare_structs! {
         /// recorded oplog entries will be skipped on retry.
         pub skipped_regions: Vec<OplogRegion>,
         /// Oplog regions permanently deleted from the workers using the revert functionality.
-        pub deleted_regions: Vec<OplogRegion>
+        pub deleted_regions: Vec<OplogRegion>,
+        /// Oplog region permanently discarded by the oplog retention policy. The worker's state
+        /// is recovered from the snapshot following this region.
+        pub discarded_region: Option<OplogRegion>
     }
 
     #[derive(IntoValue, FromValue)]
@@ -153,6 +157,37 @@ declare_structs! {
         pub number_of_invocations: u64,
     }
 
+    pub struct UpdateCheckRequest {
+        pub target_revision: ComponentRevision,
+        /// Only the agents matching this filter are checked. All agents of the component are
+        /// checked if not specified.
+        pub filter: Option<WorkerFilter>,
+        /// Maximum number of agents to check
+        pub sample_size: Option<u64>,
+        /// Maximum number of agents replayed at the same time
+        pub concurrency: Option<u32>,
+    }
+
+    pub struct AgentUpdateCheckRequest {
+        pub worker_id: WorkerId,
+        pub target_revision: ComponentRevision,
+    }
+
+    pub struct AgentUpdateCheckResult {
+        pub worker_id: WorkerId,
+        pub current_revision: ComponentRevision,
+        /// True if replaying the agent's oplog against the target revision did not diverge
+        pub compatible: bool,
+        /// The oplog index where the replay diverged from the recorded history
+        pub divergence_index: Option<OplogIndex>,
+        pub details: Option<String>,
+    }
+
+    pub struct UpdateCheckResponse {
+        pub target_revision: ComponentRevision,
+        pub results: Vec<AgentUpdateCheckResult>,
+    }
+
     // TODO: Rename to AgentFileSystemNode
     pub struct FlatComponentFileSystemNode {
         pub name: String,
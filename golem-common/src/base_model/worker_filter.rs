@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::base_model::component::ComponentRevision;
+use crate::base_model::component::{ComponentRevision, PluginPriority};
 use crate::base_model::{Timestamp, WorkerStatus};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(
@@ -182,6 +183,218 @@ impl Display for WorkerConfigVarsFilter {
     }
 }
 
+/// Filters on the agent type name, parsed from the agent ID
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerAgentTypeFilter {
+    pub comparator: StringFilterComparator,
+    pub value: String,
+}
+
+impl WorkerAgentTypeFilter {
+    pub fn new(comparator: StringFilterComparator, value: String) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerAgentTypeFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "agent_type {} {}", self.comparator, self.value)
+    }
+}
+
+/// Filters on the textual form of the agent's constructor parameters, as they appear in the agent ID
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerAgentParametersFilter {
+    pub comparator: StringFilterComparator,
+    pub value: String,
+}
+
+impl WorkerAgentParametersFilter {
+    pub fn new(comparator: StringFilterComparator, value: String) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerAgentParametersFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "agent_parameters {} {}", self.comparator, self.value)
+    }
+}
+
+/// Filters on the time the agent's last invocation started. Agents that were never invoked are
+/// matched by their creation time.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerLastInvocationAtFilter {
+    pub comparator: FilterComparator,
+    pub value: Timestamp,
+}
+
+impl WorkerLastInvocationAtFilter {
+    pub fn new(comparator: FilterComparator, value: Timestamp) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerLastInvocationAtFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "last_invocation_at {} {}", self.comparator, self.value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerPendingInvocationCountFilter {
+    pub comparator: FilterComparator,
+    pub value: u64,
+}
+
+impl WorkerPendingInvocationCountFilter {
+    pub fn new(comparator: FilterComparator, value: u64) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerPendingInvocationCountFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "pending_invocation_count {} {}",
+            self.comparator, self.value
+        )
+    }
+}
+
+/// Filters on the number of times the agent's current invocation has been retried
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerRetryCountFilter {
+    pub comparator: FilterComparator,
+    pub value: u32,
+}
+
+impl WorkerRetryCountFilter {
+    pub fn new(comparator: FilterComparator, value: u32) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerRetryCountFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "retry_count {} {}", self.comparator, self.value)
+    }
+}
+
+/// Filters on the message of the last error the agent's current invocation failed with. Agents
+/// without such an error never match.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerLastErrorFilter {
+    pub comparator: StringFilterComparator,
+    pub value: String,
+}
+
+impl WorkerLastErrorFilter {
+    pub fn new(comparator: StringFilterComparator, value: String) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerLastErrorFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "last_error {} {}", self.comparator, self.value)
+    }
+}
+
+/// Filters on the total linear memory size of the agent in bytes
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerMemorySizeFilter {
+    pub comparator: FilterComparator,
+    pub value: u64,
+}
+
+impl WorkerMemorySizeFilter {
+    pub fn new(comparator: FilterComparator, value: u64) -> Self {
+        Self { comparator, value }
+    }
+}
+
+impl Display for WorkerMemorySizeFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "memory_size {} {}", self.comparator, self.value)
+    }
+}
+
+/// Matches agents that have the plugin installed with the given priority activated
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "full",
+    derive(desert_rust::BinaryCodec, poem_openapi::Object)
+)]
+#[cfg_attr(feature = "full", desert(evolution()))]
+#[cfg_attr(feature = "full", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerActivePluginFilter {
+    pub plugin_priority: PluginPriority,
+}
+
+impl WorkerActivePluginFilter {
+    pub fn new(plugin_priority: PluginPriority) -> Self {
+        Self { plugin_priority }
+    }
+}
+
+impl Display for WorkerActivePluginFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "active_plugin == {}", self.plugin_priority)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "full",
@@ -290,6 +503,14 @@ pub enum WorkerFilter {
     Or(WorkerOrFilter),
     Not(WorkerNotFilter),
     ConfigVars(WorkerConfigVarsFilter),
+    AgentType(WorkerAgentTypeFilter),
+    AgentParameters(WorkerAgentParametersFilter),
+    LastInvocationAt(WorkerLastInvocationAtFilter),
+    PendingInvocationCount(WorkerPendingInvocationCountFilter),
+    RetryCount(WorkerRetryCountFilter),
+    LastError(WorkerLastErrorFilter),
+    MemorySize(WorkerMemorySizeFilter),
+    ActivePlugin(WorkerActivePluginFilter),
 }
 
 impl WorkerFilter {
@@ -355,6 +576,40 @@ impl WorkerFilter {
         WorkerFilter::CreatedAt(WorkerCreatedAtFilter::new(comparator, value))
     }
 
+    pub fn new_agent_type(comparator: StringFilterComparator, value: String) -> Self {
+        WorkerFilter::AgentType(WorkerAgentTypeFilter::new(comparator, value))
+    }
+
+    pub fn new_agent_parameters(comparator: StringFilterComparator, value: String) -> Self {
+        WorkerFilter::AgentParameters(WorkerAgentParametersFilter::new(comparator, value))
+    }
+
+    pub fn new_last_invocation_at(comparator: FilterComparator, value: Timestamp) -> Self {
+        WorkerFilter::LastInvocationAt(WorkerLastInvocationAtFilter::new(comparator, value))
+    }
+
+    pub fn new_pending_invocation_count(comparator: FilterComparator, value: u64) -> Self {
+        WorkerFilter::PendingInvocationCount(WorkerPendingInvocationCountFilter::new(
+            comparator, value,
+        ))
+    }
+
+    pub fn new_retry_count(comparator: FilterComparator, value: u32) -> Self {
+        WorkerFilter::RetryCount(WorkerRetryCountFilter::new(comparator, value))
+    }
+
+    pub fn new_last_error(comparator: StringFilterComparator, value: String) -> Self {
+        WorkerFilter::LastError(WorkerLastErrorFilter::new(comparator, value))
+    }
+
+    pub fn new_memory_size(comparator: FilterComparator, value: u64) -> Self {
+        WorkerFilter::MemorySize(WorkerMemorySizeFilter::new(comparator, value))
+    }
+
+    pub fn new_active_plugin(plugin_priority: PluginPriority) -> Self {
+        WorkerFilter::ActivePlugin(WorkerActivePluginFilter::new(plugin_priority))
+    }
+
     pub fn from(filters: Vec<String>) -> Result<WorkerFilter, String> {
         let mut fs = Vec::new();
         for f in filters {
@@ -385,6 +640,30 @@ impl Display for WorkerFilter {
             WorkerFilter::ConfigVars(filter) => {
                 write!(f, "{filter}")
             }
+            WorkerFilter::AgentType(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::AgentParameters(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::LastInvocationAt(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::PendingInvocationCount(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::RetryCount(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::LastError(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::MemorySize(filter) => {
+                write!(f, "{filter}")
+            }
+            WorkerFilter::ActivePlugin(filter) => {
+                write!(f, "{filter}")
+            }
             WorkerFilter::Not(filter) => {
                 write!(f, "{filter}")
             }
@@ -425,8 +704,60 @@ impl FromStr for WorkerFilter {
                 )),
                 "created_at" | "createdAt" => Ok(WorkerFilter::new_created_at(
                     comparator.parse()?,
-                    value.parse()?,
+                    parse_timestamp(value)?,
+                )),
+                "agent_type" | "agentType" => Ok(WorkerFilter::new_agent_type(
+                    comparator.parse()?,
+                    value.to_string(),
+                )),
+                "agent_parameters" | "agentParameters" => Ok(WorkerFilter::new_agent_parameters(
+                    comparator.parse()?,
+                    value.to_string(),
+                )),
+                "last_invocation_at" | "lastInvocationAt" => {
+                    Ok(WorkerFilter::new_last_invocation_at(
+                        comparator.parse()?,
+                        parse_timestamp(value)?,
+                    ))
+                }
+                "pending_invocation_count" | "pendingInvocationCount" => {
+                    Ok(WorkerFilter::new_pending_invocation_count(
+                        comparator.parse()?,
+                        value
+                            .parse()
+                            .map_err(|e| format!("Invalid filter value: {e}"))?,
+                    ))
+                }
+                "retry_count" | "retryCount" => Ok(WorkerFilter::new_retry_count(
+                    comparator.parse()?,
+                    value
+                        .parse()
+                        .map_err(|e| format!("Invalid filter value: {e}"))?,
+                )),
+                "last_error" | "lastError" => Ok(WorkerFilter::new_last_error(
+                    comparator.parse()?,
+                    value.to_string(),
+                )),
+                "memory_size" | "memorySize" => Ok(WorkerFilter::new_memory_size(
+                    comparator.parse()?,
+                    value
+                        .parse()
+                        .map_err(|e| format!("Invalid filter value: {e}"))?,
                 )),
+                "active_plugin" | "activePlugin" => {
+                    let filter = WorkerFilter::new_active_plugin(PluginPriority(
+                        value
+                            .parse()
+                            .map_err(|e| format!("Invalid filter value: {e}"))?,
+                    ));
+                    match comparator.parse()? {
+                        FilterComparator::Equal => Ok(filter),
+                        FilterComparator::NotEqual => Ok(filter.not()),
+                        _ => Err(format!(
+                            "Invalid filter: {s}, active_plugin only supports == and !="
+                        )),
+                    }
+                }
                 _ if arg.starts_with("env.") => {
                     let name = &arg[4..];
                     Ok(WorkerFilter::new_env(
@@ -443,6 +774,24 @@ impl FromStr for WorkerFilter {
     }
 }
 
+/// Parses a timestamp in a filter, either as an ISO 8601 timestamp or relative to the current time
+/// in the form of `now-<duration>`, for example `now-30days`
+fn parse_timestamp(value: &str) -> Result<Timestamp, String> {
+    match value.strip_prefix("now-") {
+        Some(duration) => {
+            let duration = humantime_serde::re::humantime::parse_duration(duration)
+                .map_err(|e| format!("Invalid filter value: {e}"))?;
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| format!("Invalid system time: {e}"))?;
+            Ok(Timestamp::from(
+                now.saturating_sub(duration).as_millis() as u64
+            ))
+        }
+        None => value.parse(),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "full", derive(desert_rust::BinaryCodec, poem_openapi::Enum))]
 #[cfg_attr(feature = "full", desert(evolution()))]
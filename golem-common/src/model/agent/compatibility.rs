@@ -0,0 +1,498 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Structural compatibility checks between two revisions of agent types.
+//!
+//! An agent type revision is considered compatible with the previous one if existing agents
+//! can still be addressed with their original constructor parameters, and existing callers
+//! can still invoke every method they could invoke before:
+//!
+//! - method and constructor inputs may only be widened (every previously valid input value must
+//!   still be accepted),
+//! - method outputs may only be narrowed (every new output value must still be understood by
+//!   the previous callers).
+
+use crate::model::agent::{
+    AgentMethod, AgentType, AgentTypeName, BinaryDescriptor, DataSchema, ElementSchema,
+    NamedElementSchema, TextDescriptor,
+};
+use golem_wasm::analysis::AnalysedType;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// A single backward incompatible change between two revisions of an agent type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentTypeBreakingChange {
+    pub agent_type: AgentTypeName,
+    /// Location of the change, for example `method checkout, input cart.items`
+    pub location: String,
+    pub description: String,
+}
+
+impl Display for AgentTypeBreakingChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.location.is_empty() {
+            write!(f, "{}: {}", self.agent_type, self.description)
+        } else {
+            write!(
+                f,
+                "{}: {}: {}",
+                self.agent_type, self.location, self.description
+            )
+        }
+    }
+}
+
+/// Collects all breaking changes between the currently deployed and the new agent types.
+///
+/// Agent types only present in `new` are not breaking.
+pub fn agent_types_breaking_changes<'a>(
+    current: impl IntoIterator<Item = &'a AgentType>,
+    new: impl IntoIterator<Item = &'a AgentType>,
+) -> Vec<AgentTypeBreakingChange> {
+    let new = new
+        .into_iter()
+        .map(|agent_type| (&agent_type.type_name, agent_type))
+        .collect::<HashMap<_, _>>();
+
+    let mut changes = Vec::new();
+    for current in current {
+        match new.get(&current.type_name) {
+            Some(new) => changes.extend(agent_type_breaking_changes(current, new)),
+            None => changes.push(AgentTypeBreakingChange {
+                agent_type: current.type_name.clone(),
+                location: String::new(),
+                description: "agent type removed".to_string(),
+            }),
+        }
+    }
+    changes
+}
+
+/// Collects all breaking changes between two revisions of the same agent type
+pub fn agent_type_breaking_changes(
+    current: &AgentType,
+    new: &AgentType,
+) -> Vec<AgentTypeBreakingChange> {
+    let mut collector = Collector {
+        agent_type: &current.type_name,
+        output: false,
+        changes: Vec::new(),
+    };
+
+    if current.mode != new.mode {
+        collector.report(
+            "",
+            format!("agent mode changed from {} to {}", current.mode, new.mode),
+        );
+    }
+
+    collector.input_schema(
+        "constructor",
+        &current.constructor.input_schema,
+        &new.constructor.input_schema,
+    );
+
+    let new_methods = new
+        .methods
+        .iter()
+        .map(|method| (method.name.as_str(), method))
+        .collect::<HashMap<_, _>>();
+
+    for method in &current.methods {
+        match new_methods.get(method.name.as_str()) {
+            Some(new_method) => collector.method(method, new_method),
+            None => collector.report(&format!("method {}", method.name), "method removed"),
+        }
+    }
+
+    collector.changes
+}
+
+struct Collector<'a> {
+    agent_type: &'a AgentTypeName,
+    /// When checking outputs the `source` side is the new revision, so the wording of the
+    /// reported changes is flipped
+    output: bool,
+    changes: Vec<AgentTypeBreakingChange>,
+}
+
+impl Collector<'_> {
+    fn report(&mut self, location: &str, description: impl Into<String>) {
+        self.changes.push(AgentTypeBreakingChange {
+            agent_type: self.agent_type.clone(),
+            location: location.to_string(),
+            description: description.into(),
+        });
+    }
+
+    fn missing_in_target(&self) -> &'static str {
+        if self.output {
+            "added"
+        } else {
+            "removed"
+        }
+    }
+
+    fn missing_in_source(&self) -> &'static str {
+        if self.output {
+            "removed"
+        } else {
+            "added"
+        }
+    }
+
+    fn changed(&self, source: &str, target: &str) -> String {
+        if self.output {
+            format!("changed from {target} to {source}")
+        } else {
+            format!("changed from {source} to {target}")
+        }
+    }
+
+    fn method(&mut self, current: &AgentMethod, new: &AgentMethod) {
+        let location = format!("method {}", current.name);
+        self.input_schema(
+            &format!("{location}, input"),
+            &current.input_schema,
+            &new.input_schema,
+        );
+        self.output = true;
+        self.output_schema(
+            &format!("{location}, output"),
+            &current.output_schema,
+            &new.output_schema,
+        );
+        self.output = false;
+    }
+
+    /// Every value matching `current` must still match `new`.
+    ///
+    /// Multimodal inputs are a list of any of their named elements, so the order of the elements
+    /// does not matter and newly added elements are never required.
+    fn input_schema(&mut self, location: &str, current: &DataSchema, new: &DataSchema) {
+        let multimodal = matches!(current, DataSchema::Multimodal(_));
+        let Some((current, new)) = self.same_schema_kind(location, current, new) else {
+            return;
+        };
+
+        let new_elements = new
+            .iter()
+            .map(|element| (element.name.as_str(), element))
+            .collect::<HashMap<_, _>>();
+
+        // Positions are only compared among the elements present in both revisions, so removing
+        // or adding an element is not also reported as moving all the following ones
+        let kept_current = current
+            .iter()
+            .filter(|element| new_elements.contains_key(element.name.as_str()))
+            .map(|element| element.name.as_str())
+            .collect::<Vec<_>>();
+        let kept_new = new
+            .iter()
+            .filter(|element| current.iter().any(|e| e.name == element.name))
+            .map(|element| element.name.as_str())
+            .collect::<Vec<_>>();
+
+        for element in current {
+            let element_location = format!("{location} {}", element.name);
+            match new_elements.get(element.name.as_str()) {
+                Some(new_element) => {
+                    let position = |names: &[&str]| {
+                        names.iter().position(|name| *name == element.name.as_str())
+                    };
+                    if !multimodal && position(&kept_current) != position(&kept_new) {
+                        self.report(&element_location, "parameter moved");
+                    }
+                    self.element(&element_location, &element.schema, &new_element.schema)
+                }
+                None => self.report(&element_location, "parameter removed"),
+            }
+        }
+
+        if multimodal {
+            return;
+        }
+
+        for element in new {
+            if !current.iter().any(|e| e.name == element.name) && !is_optional(&element.schema) {
+                self.report(
+                    &format!("{location} {}", element.name),
+                    "required parameter added",
+                );
+            }
+        }
+    }
+
+    /// Every value matching `new` must still match `current`
+    fn output_schema(&mut self, location: &str, current: &DataSchema, new: &DataSchema) {
+        let Some((current, new)) = self.same_schema_kind(location, current, new) else {
+            return;
+        };
+
+        if current.len() != new.len() {
+            self.report(
+                location,
+                format!(
+                    "number of results changed from {} to {}",
+                    current.len(),
+                    new.len()
+                ),
+            );
+            return;
+        }
+
+        for (current, new) in current.iter().zip(new) {
+            let element_location = format!("{location} {}", current.name);
+            if current.name != new.name {
+                self.report(&element_location, format!("result renamed to {}", new.name));
+            }
+            self.element(&element_location, &new.schema, &current.schema);
+        }
+    }
+
+    fn same_schema_kind<'s>(
+        &mut self,
+        location: &str,
+        current: &'s DataSchema,
+        new: &'s DataSchema,
+    ) -> Option<(&'s [NamedElementSchema], &'s [NamedElementSchema])> {
+        match (current, new) {
+            (DataSchema::Tuple(current), DataSchema::Tuple(new))
+            | (DataSchema::Multimodal(current), DataSchema::Multimodal(new)) => {
+                Some((&current.elements, &new.elements))
+            }
+            _ => {
+                self.report(
+                    location,
+                    format!(
+                        "changed from {} to {}",
+                        schema_kind(current),
+                        schema_kind(new)
+                    ),
+                );
+                None
+            }
+        }
+    }
+
+    /// Every value matching `source` must still match `target`
+    fn element(&mut self, location: &str, source: &ElementSchema, target: &ElementSchema) {
+        match (source, target) {
+            (ElementSchema::ComponentModel(source), ElementSchema::ComponentModel(target)) => {
+                self.analysed_type(location, &source.element_type, &target.element_type)
+            }
+            (
+                ElementSchema::UnstructuredText(TextDescriptor {
+                    restrictions: source,
+                }),
+                ElementSchema::UnstructuredText(TextDescriptor {
+                    restrictions: target,
+                }),
+            ) => {
+                if !restrictions_accepted(source, target) {
+                    self.report(location, "text languages restricted");
+                }
+            }
+            (
+                ElementSchema::UnstructuredBinary(BinaryDescriptor {
+                    restrictions: source,
+                }),
+                ElementSchema::UnstructuredBinary(BinaryDescriptor {
+                    restrictions: target,
+                }),
+            ) => {
+                if !restrictions_accepted(source, target) {
+                    self.report(location, "binary types restricted");
+                }
+            }
+            _ => self.report(
+                location,
+                self.changed(element_kind(source), element_kind(target)),
+            ),
+        }
+    }
+
+    /// Every value of type `source` must still be a valid value of type `target`
+    fn analysed_type(&mut self, location: &str, source: &AnalysedType, target: &AnalysedType) {
+        match (source, target) {
+            (AnalysedType::Record(source), AnalysedType::Record(target)) => {
+                for field in &source.fields {
+                    let field_location = format!("{location}.{}", field.name);
+                    match target.fields.iter().find(|f| f.name == field.name) {
+                        Some(target_field) => {
+                            self.analysed_type(&field_location, &field.typ, &target_field.typ)
+                        }
+                        None => self.report(
+                            &field_location,
+                            format!("record field {}", self.missing_in_target()),
+                        ),
+                    }
+                }
+                for field in &target.fields {
+                    if !source.fields.iter().any(|f| f.name == field.name)
+                        && !matches!(field.typ, AnalysedType::Option(_))
+                    {
+                        self.report(
+                            &format!("{location}.{}", field.name),
+                            format!("required record field {}", self.missing_in_source()),
+                        );
+                    }
+                }
+            }
+            (AnalysedType::Variant(source), AnalysedType::Variant(target)) => {
+                for case in &source.cases {
+                    let case_location = format!("{location}.{}", case.name);
+                    match target.cases.iter().find(|c| c.name == case.name) {
+                        Some(target_case) => match (&case.typ, &target_case.typ) {
+                            (Some(source), Some(target)) => {
+                                self.analysed_type(&case_location, source, target)
+                            }
+                            (None, None) => {}
+                            _ => self.report(&case_location, "variant case payload changed"),
+                        },
+                        None => self.report(
+                            &case_location,
+                            format!("variant case {}", self.missing_in_target()),
+                        ),
+                    }
+                }
+            }
+            (AnalysedType::Enum(source), AnalysedType::Enum(target)) => {
+                for case in &source.cases {
+                    if !target.cases.contains(case) {
+                        self.report(
+                            &format!("{location}.{case}"),
+                            format!("enum case {}", self.missing_in_target()),
+                        );
+                    }
+                }
+            }
+            (AnalysedType::Flags(source), AnalysedType::Flags(target)) => {
+                for flag in &source.names {
+                    if !target.names.contains(flag) {
+                        self.report(
+                            &format!("{location}.{flag}"),
+                            format!("flag {}", self.missing_in_target()),
+                        );
+                    }
+                }
+            }
+            (AnalysedType::Option(source), AnalysedType::Option(target)) => {
+                self.analysed_type(location, &source.inner, &target.inner)
+            }
+            (AnalysedType::List(source), AnalysedType::List(target)) => {
+                self.analysed_type(&format!("{location}[]"), &source.inner, &target.inner)
+            }
+            (AnalysedType::Tuple(source), AnalysedType::Tuple(target)) => {
+                if source.items.len() != target.items.len() {
+                    let description = self.changed(
+                        &source.items.len().to_string(),
+                        &target.items.len().to_string(),
+                    );
+                    self.report(location, format!("tuple size {description}"));
+                } else {
+                    for (idx, (source, target)) in
+                        source.items.iter().zip(&target.items).enumerate()
+                    {
+                        self.analysed_type(&format!("{location}.{idx}"), source, target)
+                    }
+                }
+            }
+            (AnalysedType::Result(source), AnalysedType::Result(target)) => {
+                for (name, source, target) in [
+                    ("ok", &source.ok, &target.ok),
+                    ("err", &source.err, &target.err),
+                ] {
+                    let result_location = format!("{location}.{name}");
+                    match (source, target) {
+                        (Some(source), Some(target)) => {
+                            self.analysed_type(&result_location, source, target)
+                        }
+                        (None, None) => {}
+                        _ => self.report(&result_location, "result payload changed"),
+                    }
+                }
+            }
+            (AnalysedType::Handle(source), AnalysedType::Handle(target)) => {
+                if source.resource_id != target.resource_id || source.mode != target.mode {
+                    self.report(location, "resource handle changed");
+                }
+            }
+            (source, target) => {
+                if std::mem::discriminant(source) != std::mem::discriminant(target) {
+                    let description = self.changed(type_kind(source), type_kind(target));
+                    self.report(location, format!("type {description}"));
+                }
+            }
+        }
+    }
+}
+
+fn restrictions_accepted<T: PartialEq>(source: &Option<Vec<T>>, target: &Option<Vec<T>>) -> bool {
+    match (source, target) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(source), Some(target)) => source.iter().all(|item| target.contains(item)),
+    }
+}
+
+fn is_optional(schema: &ElementSchema) -> bool {
+    matches!(
+        schema,
+        ElementSchema::ComponentModel(schema) if matches!(schema.element_type, AnalysedType::Option(_))
+    )
+}
+
+fn schema_kind(schema: &DataSchema) -> &'static str {
+    match schema {
+        DataSchema::Tuple(_) => "tuple",
+        DataSchema::Multimodal(_) => "multimodal",
+    }
+}
+
+fn element_kind(schema: &ElementSchema) -> &'static str {
+    match schema {
+        ElementSchema::ComponentModel(_) => "component model value",
+        ElementSchema::UnstructuredText(_) => "unstructured text",
+        ElementSchema::UnstructuredBinary(_) => "unstructured binary",
+    }
+}
+
+fn type_kind(typ: &AnalysedType) -> &'static str {
+    match typ {
+        AnalysedType::Variant(_) => "variant",
+        AnalysedType::Result(_) => "result",
+        AnalysedType::Option(_) => "option",
+        AnalysedType::Enum(_) => "enum",
+        AnalysedType::Flags(_) => "flags",
+        AnalysedType::Record(_) => "record",
+        AnalysedType::Tuple(_) => "tuple",
+        AnalysedType::List(_) => "list",
+        AnalysedType::Str(_) => "string",
+        AnalysedType::Chr(_) => "char",
+        AnalysedType::F64(_) => "f64",
+        AnalysedType::F32(_) => "f32",
+        AnalysedType::U64(_) => "u64",
+        AnalysedType::S64(_) => "s64",
+        AnalysedType::U32(_) => "u32",
+        AnalysedType::S32(_) => "s32",
+        AnalysedType::U16(_) => "u16",
+        AnalysedType::S16(_) => "s16",
+        AnalysedType::U8(_) => "u8",
+        AnalysedType::S8(_) => "s8",
+        AnalysedType::Bool(_) => "bool",
+        AnalysedType::Handle(_) => "handle",
+    }
+}
@@ -149,6 +149,8 @@ impl From<super::bindings::golem::agent::common::AgentMethod> for AgentMethod {
             input_schema: DataSchema::from(value.input_schema),
             output_schema: DataSchema::from(value.output_schema),
             http_endpoint: value.http_endpoint.into_iter().map(|v| v.into()).collect(),
+            // Listed separately by the optional read-only-methods export
+            read_only: false,
         }
     }
 }
@@ -28,6 +28,8 @@ use wasmtime_wasi::p2::pipe;
 use wasmtime_wasi::{IoCtx, IoData, IoView, WasiCtx, WasiCtxView, WasiView};
 const INTERFACE_NAME: &str = "golem:agent/guest@1.5.0";
 const FUNCTION_NAME: &str = "discover-agent-types";
+const READ_ONLY_METHODS_INTERFACE_NAME: &str = "golem:agent/read-only-methods@1.5.0";
+const READ_ONLY_METHODS_FUNCTION_NAME: &str = "discover-read-only-methods";
 
 /// Extracts the implemented agent types from the given WASM component, assuming it implements the `golem:agent/guest` interface.
 /// Optionally fails if the component does not implement the agent interfaces, otherwise returns an empty agent type set for such components.
@@ -137,7 +139,9 @@ pub async fn extract_agent_types_with_streams(
 
     match results.0 {
         Ok(results) => {
-            let agent_types: Vec<AgentType> = results.into_iter().map(AgentType::from).collect();
+            let mut agent_types: Vec<AgentType> =
+                results.into_iter().map(AgentType::from).collect();
+            mark_read_only_methods(&mut store, &instance, &mut agent_types).await?;
             trace!("Discovered agent types: {:#?}", agent_types);
             Ok(agent_types)
         }
@@ -165,14 +169,76 @@ pub async fn extract_agent_types(
     .await
 }
 
-fn find_discover_function(mut store: impl AsContextMut, instance: &Instance) -> Option<Func> {
-    let (_, exported_instance_id) = instance.get_export(&mut store, None, INTERFACE_NAME)?;
+fn find_discover_function(store: impl AsContextMut, instance: &Instance) -> Option<Func> {
+    find_function(store, instance, INTERFACE_NAME, FUNCTION_NAME)
+}
+
+fn find_function(
+    mut store: impl AsContextMut,
+    instance: &Instance,
+    interface_name: &str,
+    function_name: &str,
+) -> Option<Func> {
+    let (_, exported_instance_id) = instance.get_export(&mut store, None, interface_name)?;
     let (_, func_id) =
-        instance.get_export(&mut store, Some(&exported_instance_id), FUNCTION_NAME)?;
+        instance.get_export(&mut store, Some(&exported_instance_id), function_name)?;
     let func = instance.get_func(&mut store, func_id)?;
     Some(func)
 }
 
+/// Sets the `read_only` flag of the methods listed by the component's optional
+/// `golem:agent/read-only-methods` export. Components not exporting it have no read-only methods.
+async fn mark_read_only_methods(
+    mut store: &mut Store<Host>,
+    instance: &Instance,
+    agent_types: &mut [AgentType],
+) -> anyhow::Result<()> {
+    let Some(func) = find_function(
+        &mut store,
+        instance,
+        READ_ONLY_METHODS_INTERFACE_NAME,
+        READ_ONLY_METHODS_FUNCTION_NAME,
+    ) else {
+        return Ok(());
+    };
+
+    let typed_func = func
+        .typed::<(), (Vec<crate::model::agent::bindings::golem::agent::common::ReadOnlyMethod>,)>(
+            &mut store,
+        )
+        .map_err(|e| {
+            anyhow!("The component's {READ_ONLY_METHODS_INTERFACE_NAME} interface does not match the expected type signature: {e}")
+        })?;
+    let (read_only_methods,) = typed_func.call_async(&mut store, ()).await?;
+
+    apply_read_only_methods(agent_types, read_only_methods)
+}
+
+pub(crate) fn apply_read_only_methods(
+    agent_types: &mut [AgentType],
+    read_only_methods: Vec<crate::model::agent::bindings::golem::agent::common::ReadOnlyMethod>,
+) -> anyhow::Result<()> {
+    for read_only_method in read_only_methods {
+        let method = agent_types
+            .iter_mut()
+            .filter(|agent_type| agent_type.type_name.as_str() == read_only_method.agent_type)
+            .flat_map(|agent_type| agent_type.methods.iter_mut())
+            .find(|method| method.name == read_only_method.method_name);
+        match method {
+            Some(method) => method.read_only = true,
+            None => {
+                return Err(anyhow!(
+                    "Read-only method {} of agent type {} is not defined by the component",
+                    read_only_method.method_name,
+                    read_only_method.agent_type
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Clone)]
 struct Host {
     pub table: Arc<Mutex<ResourceTable>>,
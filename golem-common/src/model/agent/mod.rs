@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod compact_value_formatter;
+pub mod compatibility;
 mod conversions;
 pub mod extraction;
 mod normalisation;
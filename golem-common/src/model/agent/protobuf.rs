@@ -219,6 +219,7 @@ impl TryFrom<golem_api_grpc::proto::golem::component::AgentMethod> for AgentMeth
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            read_only: value.read_only,
         })
     }
 }
@@ -232,6 +233,7 @@ impl From<AgentMethod> for golem_api_grpc::proto::golem::component::AgentMethod
             input_schema: Some(value.input_schema.into()),
             output_schema: Some(value.output_schema.into()),
             http_endpoint: value.http_endpoint.into_iter().map(Into::into).collect(),
+            read_only: value.read_only,
         }
     }
 }
@@ -16,8 +16,13 @@ use crate::base_model::agent::{
     Snapshotting, SnapshottingConfig, SnapshottingEveryNInvocation, SnapshottingPeriodic,
 };
 use crate::base_model::Empty;
+use crate::model::agent::bindings::golem::agent::common::ReadOnlyMethod;
+use crate::model::agent::compatibility::{
+    agent_type_breaking_changes, agent_types_breaking_changes, AgentTypeBreakingChange,
+};
+use crate::model::agent::extraction::apply_read_only_methods;
 use crate::model::agent::{
-    AgentConstructor, AgentId, AgentMode, AgentType, AgentTypeName, AgentTypeResolver,
+    AgentConstructor, AgentId, AgentMethod, AgentMode, AgentType, AgentTypeName, AgentTypeResolver,
     BinaryDescriptor, BinaryReference, BinarySource, BinaryType, ComponentModelElementSchema,
     ComponentModelElementValue, DataSchema, DataValue, ElementSchema, ElementValue, ElementValues,
     JsonComponentModelValue, NamedElementSchema, NamedElementSchemas, NamedElementValue,
@@ -27,7 +32,10 @@ use crate::model::agent::{
 };
 use crate::{agent_id, data_value, phantom_agent_id};
 use async_trait::async_trait;
-use golem_wasm::analysis::analysed_type::{field, flags, list, record, str, u32, u64};
+use golem_wasm::analysis::analysed_type::{
+    case, field, flags, list, option, r#enum, record, str, u32, u64, unit_case, variant,
+};
+use golem_wasm::analysis::AnalysedType;
 use golem_wasm::json::ValueAndTypeJsonExtensions;
 use golem_wasm::{IntoValueAndType, Value, ValueAndType};
 use poem_openapi::types::ToJSON;
@@ -1315,3 +1323,273 @@ fn agent_id_vs_phantom_agent_id() {
     assert_eq!(regular_id.phantom_id, None);
     assert_eq!(phantom_id.phantom_id, Some(uuid));
 }
+
+fn compatibility_test_agent_type(
+    constructor_params: Vec<(&str, AnalysedType)>,
+    methods: Vec<(&str, Vec<(&str, AnalysedType)>, AnalysedType)>,
+) -> AgentType {
+    fn schema(params: Vec<(&str, AnalysedType)>) -> DataSchema {
+        DataSchema::Tuple(NamedElementSchemas {
+            elements: params
+                .into_iter()
+                .map(|(name, typ)| NamedElementSchema {
+                    name: name.to_string(),
+                    schema: ElementSchema::ComponentModel(ComponentModelElementSchema {
+                        element_type: typ,
+                    }),
+                })
+                .collect(),
+        })
+    }
+
+    AgentType {
+        type_name: AgentTypeName("shopping-cart".to_string()),
+        description: "".to_string(),
+        constructor: AgentConstructor {
+            name: None,
+            description: "".to_string(),
+            prompt_hint: None,
+            input_schema: schema(constructor_params),
+        },
+        methods: methods
+            .into_iter()
+            .map(|(name, params, result)| AgentMethod {
+                name: name.to_string(),
+                description: "".to_string(),
+                prompt_hint: None,
+                input_schema: schema(params),
+                output_schema: schema(vec![("return-value", result)]),
+                http_endpoint: vec![],
+                read_only: false,
+            })
+            .collect(),
+        dependencies: vec![],
+        mode: AgentMode::Durable,
+        http_mount: None,
+        snapshotting: Snapshotting::Disabled(Empty {}),
+        config: Vec::new(),
+    }
+}
+
+#[test]
+fn read_only_methods_are_marked_on_the_discovered_agent_types() {
+    let mut agent_types = vec![compatibility_test_agent_type(
+        vec![],
+        vec![
+            ("add-item", vec![("item", str())], u32()),
+            ("get-items", vec![], u32()),
+        ],
+    )];
+
+    apply_read_only_methods(
+        &mut agent_types,
+        vec![ReadOnlyMethod {
+            agent_type: "shopping-cart".to_string(),
+            method_name: "get-items".to_string(),
+        }],
+    )
+    .unwrap();
+
+    let read_only = agent_types[0]
+        .methods
+        .iter()
+        .map(|method| (method.name.as_str(), method.read_only))
+        .collect::<Vec<_>>();
+    assert_eq!(read_only, vec![("add-item", false), ("get-items", true)]);
+
+    let unknown = apply_read_only_methods(
+        &mut agent_types,
+        vec![ReadOnlyMethod {
+            agent_type: "other-agent".to_string(),
+            method_name: "get-items".to_string(),
+        }],
+    );
+    assert!(unknown.is_err());
+}
+
+#[test]
+fn agent_type_compatible_changes() {
+    let current = compatibility_test_agent_type(
+        vec![("user-id", str())],
+        vec![
+            (
+                "add-item",
+                vec![(
+                    "item",
+                    record(vec![field("sku", str()), field("quantity", u32())]),
+                )],
+                variant(vec![unit_case("added"), case("rejected", str())]),
+            ),
+            ("checkout", vec![], r#enum(&["paid", "failed"])),
+        ],
+    );
+    let new = compatibility_test_agent_type(
+        vec![("user-id", str()), ("currency", option(str()))],
+        vec![
+            (
+                "add-item",
+                vec![(
+                    "item",
+                    record(vec![
+                        field("sku", str()),
+                        field("quantity", u32()),
+                        field("note", option(str())),
+                    ]),
+                )],
+                variant(vec![unit_case("added")]),
+            ),
+            ("checkout", vec![], r#enum(&["paid"])),
+            ("clear", vec![], str()),
+        ],
+    );
+
+    assert_eq!(agent_type_breaking_changes(&current, &new), vec![]);
+}
+
+#[test]
+fn agent_type_breaking_changes_are_reported() {
+    let current = compatibility_test_agent_type(
+        vec![("user-id", str())],
+        vec![
+            (
+                "add-item",
+                vec![(
+                    "item",
+                    record(vec![field("sku", str()), field("quantity", u64())]),
+                )],
+                r#enum(&["added"]),
+            ),
+            ("set-mode", vec![("mode", r#enum(&["a", "b"]))], str()),
+            ("checkout", vec![], str()),
+        ],
+    );
+    let new = compatibility_test_agent_type(
+        vec![("user-id", u64())],
+        vec![
+            (
+                "add-item",
+                vec![("item", record(vec![field("quantity", u32())]))],
+                r#enum(&["added", "merged"]),
+            ),
+            ("set-mode", vec![("mode", r#enum(&["a"]))], str()),
+        ],
+    );
+
+    let changes = agent_type_breaking_changes(&current, &new)
+        .into_iter()
+        .map(|change| change.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        changes,
+        vec![
+            "shopping-cart: constructor user-id: type changed from string to u64",
+            "shopping-cart: method add-item, input item.sku: record field removed",
+            "shopping-cart: method add-item, input item.quantity: type changed from u64 to u32",
+            "shopping-cart: method add-item, output return-value.merged: enum case added",
+            "shopping-cart: method set-mode, input mode.b: enum case removed",
+            "shopping-cart: method checkout: method removed",
+        ]
+    );
+}
+
+#[test]
+fn removed_agent_types_are_breaking() {
+    let current = compatibility_test_agent_type(vec![], vec![]);
+    let mut renamed = current.clone();
+    renamed.type_name = AgentTypeName("cart".to_string());
+
+    let changes = agent_types_breaking_changes([&current], [&renamed]);
+
+    assert_eq!(
+        changes,
+        vec![AgentTypeBreakingChange {
+            agent_type: AgentTypeName("shopping-cart".to_string()),
+            location: "".to_string(),
+            description: "agent type removed".to_string(),
+        }]
+    );
+    assert_eq!(
+        agent_types_breaking_changes([&current], [&current, &renamed]),
+        vec![]
+    );
+}
+
+#[test]
+fn removed_parameter_does_not_move_the_following_ones() {
+    let current = compatibility_test_agent_type(
+        vec![],
+        vec![(
+            "add-item",
+            vec![("cart", str()), ("sku", str()), ("quantity", u32())],
+            str(),
+        )],
+    );
+    let new = compatibility_test_agent_type(
+        vec![],
+        vec![("add-item", vec![("sku", str()), ("quantity", u32())], str())],
+    );
+
+    let changes = agent_type_breaking_changes(&current, &new)
+        .into_iter()
+        .map(|change| change.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        changes,
+        vec!["shopping-cart: method add-item, input cart: parameter removed"]
+    );
+}
+
+#[test]
+fn multimodal_elements_are_optional_and_unordered() {
+    fn multimodal(elements: Vec<(&str, ElementSchema)>) -> DataSchema {
+        DataSchema::Multimodal(NamedElementSchemas {
+            elements: elements
+                .into_iter()
+                .map(|(name, schema)| NamedElementSchema {
+                    name: name.to_string(),
+                    schema,
+                })
+                .collect(),
+        })
+    }
+
+    let text = || ElementSchema::UnstructuredText(TextDescriptor { restrictions: None });
+    let image = || {
+        ElementSchema::UnstructuredBinary(BinaryDescriptor {
+            restrictions: Some(vec![BinaryType {
+                mime_type: "image/png".to_string(),
+            }]),
+        })
+    };
+    let count = || {
+        ElementSchema::ComponentModel(ComponentModelElementSchema {
+            element_type: u32(),
+        })
+    };
+
+    let mut current = compatibility_test_agent_type(vec![], vec![]);
+    current.constructor.input_schema = multimodal(vec![("text", text()), ("image", image())]);
+
+    let mut new = current.clone();
+    new.constructor.input_schema = multimodal(vec![
+        ("count", count()),
+        ("image", image()),
+        ("text", text()),
+    ]);
+
+    assert_eq!(agent_type_breaking_changes(&current, &new), vec![]);
+
+    new.constructor.input_schema = multimodal(vec![("count", count()), ("image", image())]);
+
+    let changes = agent_type_breaking_changes(&current, &new)
+        .into_iter()
+        .map(|change| change.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        changes,
+        vec!["shopping-cart: constructor text: parameter removed"]
+    );
+}
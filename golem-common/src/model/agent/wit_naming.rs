@@ -107,6 +107,7 @@ impl ToWitNaming for AgentMethod {
             input_schema: self.input_schema.to_wit_naming(),
             output_schema: self.output_schema.to_wit_naming(),
             http_endpoint: self.http_endpoint.clone(),
+            read_only: self.read_only,
         }
     }
 }
@@ -32,6 +32,11 @@ impl ComponentDto {
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
                     .collect(),
+                agent_resource_limits: self
+                    .agent_resource_limits
+                    .iter()
+                    .map(|limits| (limits.agent.0.clone(), limits.into()))
+                    .collect(),
             }
             .into(),
             wasm_hash: self.wasm_hash,
@@ -108,8 +113,72 @@ impl From<ComponentId> for golem_wasm::ComponentId {
 }
 
 mod protobuf {
-    use super::InstalledPlugin;
+    use super::{AgentResourceLimits, AppliedComponentTransformation, InstalledPlugin};
     use super::{ComponentRevision, PluginPriority};
+    use crate::model::agent::AgentTypeName;
+
+    impl From<AgentResourceLimits> for golem_api_grpc::proto::golem::component::AgentResourceLimits {
+        fn from(value: AgentResourceLimits) -> Self {
+            Self {
+                agent: value.agent.0,
+                max_memory_bytes: value.max_memory_bytes,
+                fuel_per_invocation: value.fuel_per_invocation,
+                max_invocation_time_millis: value.max_invocation_time_millis,
+                max_oplog_entries_per_invocation: value.max_oplog_entries_per_invocation,
+                max_outgoing_http_requests_per_minute: value.max_outgoing_http_requests_per_minute,
+            }
+        }
+    }
+
+    impl From<golem_api_grpc::proto::golem::component::AgentResourceLimits> for AgentResourceLimits {
+        fn from(value: golem_api_grpc::proto::golem::component::AgentResourceLimits) -> Self {
+            Self {
+                agent: AgentTypeName(value.agent),
+                max_memory_bytes: value.max_memory_bytes,
+                fuel_per_invocation: value.fuel_per_invocation,
+                max_invocation_time_millis: value.max_invocation_time_millis,
+                max_oplog_entries_per_invocation: value.max_oplog_entries_per_invocation,
+                max_outgoing_http_requests_per_minute: value.max_outgoing_http_requests_per_minute,
+            }
+        }
+    }
+
+    impl From<AppliedComponentTransformation>
+        for golem_api_grpc::proto::golem::component::AppliedComponentTransformation
+    {
+        fn from(value: AppliedComponentTransformation) -> Self {
+            Self {
+                environment_plugin_grant_id: Some(value.environment_plugin_grant_id.into()),
+                plugin_registration_id: Some(value.plugin_registration_id.into()),
+                plugin_name: value.plugin_name,
+                plugin_version: value.plugin_version,
+                result_hash: Some(value.result_hash.into()),
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::component::AppliedComponentTransformation>
+        for AppliedComponentTransformation
+    {
+        type Error = String;
+        fn try_from(
+            value: golem_api_grpc::proto::golem::component::AppliedComponentTransformation,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                environment_plugin_grant_id: value
+                    .environment_plugin_grant_id
+                    .ok_or("Missing environment_plugin_grant_id")?
+                    .try_into()?,
+                plugin_registration_id: value
+                    .plugin_registration_id
+                    .ok_or("Missing plugin_registration_id")?
+                    .try_into()?,
+                plugin_name: value.plugin_name,
+                plugin_version: value.plugin_version,
+                result_hash: value.result_hash.ok_or("Missing result_hash")?.try_into()?,
+            })
+        }
+    }
 
     impl From<InstalledPlugin> for golem_api_grpc::proto::golem::component::PluginInstallation {
         fn from(value: InstalledPlugin) -> Self {
@@ -126,6 +195,9 @@ mod protobuf {
                 oplog_processor_component_revision: value
                     .oplog_processor_component_revision
                     .map(|v| v.0),
+
+                library_component_id: value.library_component_id.map(|v| v.into()),
+                library_component_revision: value.library_component_revision.map(|v| v.0),
             }
         }
     }
@@ -157,6 +229,12 @@ mod protobuf {
                 oplog_processor_component_revision: value
                     .oplog_processor_component_revision
                     .map(ComponentRevision),
+
+                library_component_id: value
+                    .library_component_id
+                    .map(|v| v.try_into())
+                    .transpose()?,
+                library_component_revision: value.library_component_revision.map(ComponentRevision),
             })
         }
     }
@@ -96,6 +96,33 @@ impl Diffable for ComponentFile {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentResourceLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_memory_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel_per_invocation: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_invocation_time_millis: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_oplog_entries_per_invocation: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_outgoing_http_requests_per_minute: Option<u64>,
+}
+
+impl From<&crate::model::component::AgentResourceLimits> for AgentResourceLimits {
+    fn from(value: &crate::model::component::AgentResourceLimits) -> Self {
+        Self {
+            max_memory_bytes: value.max_memory_bytes,
+            fuel_per_invocation: value.fuel_per_invocation,
+            max_invocation_time_millis: value.max_invocation_time_millis,
+            max_oplog_entries_per_invocation: value.max_oplog_entries_per_invocation,
+            max_outgoing_http_requests_per_minute: value.max_outgoing_http_requests_per_minute,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ComponentMetadata {
@@ -103,6 +130,8 @@ pub struct ComponentMetadata {
     pub env: BTreeMap<String, String>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub config_vars: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub agent_resource_limits: BTreeMap<String, AgentResourceLimits>,
 }
 
 impl Hashable for ComponentMetadata {
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use crate::model::diff;
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
 
 pub use crate::base_model::environment::*;
 
@@ -25,3 +27,396 @@ impl Environment {
         }
     }
 }
+
+/// Destination of an outgoing network access, checked against a [`NetworkEgressPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkEgressTarget {
+    /// The host name used by the agent, if the destination was not given as an IP address
+    pub domain: Option<String>,
+    pub ip: Option<IpAddr>,
+    /// Not known for name lookups
+    pub port: Option<u16>,
+}
+
+impl NetworkEgressTarget {
+    /// Destination given as a host name or an IP address literal, such as the authority of an URL
+    pub fn host(host: &str, port: Option<u16>) -> Self {
+        let unbracketed = host.trim_start_matches('[').trim_end_matches(']');
+        match unbracketed.parse::<IpAddr>() {
+            Ok(ip) => Self {
+                domain: None,
+                ip: Some(ip),
+                port,
+            },
+            Err(_) => Self {
+                domain: Some(host.to_lowercase()),
+                ip: None,
+                port,
+            },
+        }
+    }
+
+    pub fn ip(ip: IpAddr, port: u16) -> Self {
+        Self {
+            domain: None,
+            ip: Some(ip),
+            port: Some(port),
+        }
+    }
+}
+
+impl Display for NetworkEgressTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.domain, &self.ip) {
+            (Some(domain), Some(ip)) => write!(f, "{domain} ({ip})")?,
+            (Some(domain), None) => write!(f, "{domain}")?,
+            (None, Some(ip)) => write!(f, "{ip}")?,
+            (None, None) => write!(f, "<unknown>")?,
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        Ok(())
+    }
+}
+
+impl NetworkEgressPolicy {
+    pub fn is_unrestricted(&self) -> bool {
+        !self.no_network && self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for rule in self.allow.iter().chain(&self.deny) {
+            HostPattern::parse(&rule.host)?;
+        }
+        Ok(())
+    }
+
+    /// Checks whether the policy allows accessing the given target. Returns the reason of the
+    /// denial as an error.
+    ///
+    /// Rules restricted to specific ports never deny a target with unknown port, but they allow it,
+    /// so name lookups of hosts which are only allowed on some ports still succeed.
+    pub fn check(&self, target: &NetworkEgressTarget) -> Result<(), String> {
+        self.check_rules(target, true)
+    }
+
+    /// Checks whether the policy allows resolving the given host name. If the allow list contains
+    /// IP addresses or CIDR blocks, every lookup not explicitly denied is allowed, as the resolved
+    /// addresses can only be matched against them when connecting.
+    pub fn check_name_lookup(&self, name: &str) -> Result<(), String> {
+        let has_address_rules = self.allow.iter().any(|rule| {
+            matches!(
+                HostPattern::parse(&rule.host),
+                Ok(HostPattern::Cidr(_, _) | HostPattern::Any)
+            )
+        });
+        self.check_rules(&NetworkEgressTarget::host(name, None), !has_address_rules)
+    }
+
+    fn check_rules(&self, target: &NetworkEgressTarget, check_allow: bool) -> Result<(), String> {
+        if self.no_network {
+            return Err("network access is disabled in this environment".to_string());
+        }
+
+        if let Some(rule) = self.deny.iter().find(|rule| rule.matches(target, false)) {
+            return Err(format!(
+                "access to {target} is denied by the network egress rule for {}",
+                rule.host
+            ));
+        }
+
+        if check_allow
+            && !self.allow.is_empty()
+            && !self.allow.iter().any(|rule| rule.matches(target, true))
+        {
+            return Err(format!(
+                "access to {target} is not allowed by the network egress policy"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl NetworkEgressRule {
+    fn matches(&self, target: &NetworkEgressTarget, unknown_port_matches: bool) -> bool {
+        let port_matches = match target.port {
+            Some(port) => self.ports.is_empty() || self.ports.contains(&port),
+            None => self.ports.is_empty() || unknown_port_matches,
+        };
+        port_matches
+            && HostPattern::parse(&self.host)
+                .map(|pattern| pattern.matches(target))
+                .unwrap_or(false)
+    }
+}
+
+enum HostPattern<'a> {
+    Any,
+    Domain(&'a str),
+    Subdomains(&'a str),
+    Cidr(IpAddr, u8),
+}
+
+impl<'a> HostPattern<'a> {
+    fn parse(host: &'a str) -> Result<Self, String> {
+        if host == "*" {
+            Ok(Self::Any)
+        } else if let Some((ip, prefix)) = host.split_once('/') {
+            let ip: IpAddr = ip
+                .parse()
+                .map_err(|_| format!("Invalid CIDR block in network egress rule: {host}"))?;
+            let max_prefix = if ip.is_ipv4() { 32 } else { 128 };
+            let prefix = prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max_prefix)
+                .ok_or_else(|| format!("Invalid CIDR block in network egress rule: {host}"))?;
+            Ok(Self::Cidr(ip, prefix))
+        } else if let Ok(ip) = host.parse::<IpAddr>() {
+            Ok(Self::Cidr(ip, if ip.is_ipv4() { 32 } else { 128 }))
+        } else if let Some(domain) = host.strip_prefix("*.") {
+            Ok(Self::Subdomains(domain))
+        } else if host.is_empty() || host.contains('*') {
+            Err(format!("Invalid host in network egress rule: {host}"))
+        } else {
+            Ok(Self::Domain(host))
+        }
+    }
+
+    fn matches(&self, target: &NetworkEgressTarget) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Domain(pattern) => target
+                .domain
+                .as_ref()
+                .is_some_and(|domain| domain.eq_ignore_ascii_case(pattern)),
+            Self::Subdomains(pattern) => target.domain.as_ref().is_some_and(|domain| {
+                domain.eq_ignore_ascii_case(pattern)
+                    || domain
+                        .to_lowercase()
+                        .ends_with(&format!(".{}", pattern.to_lowercase()))
+            }),
+            Self::Cidr(network, prefix) => target
+                .ip
+                .is_some_and(|ip| cidr_contains(*network, *prefix, ip)),
+        }
+    }
+}
+
+fn cidr_contains(network: IpAddr, prefix: u8, ip: IpAddr) -> bool {
+    let (network, ip, bits) = match (network, ip) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            (u32::from(network) as u128, u32::from(ip) as u128, 32u32)
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => (u128::from(network), u128::from(ip), 128u32),
+        (IpAddr::V6(network), IpAddr::V4(ip)) => {
+            (u128::from(network), u128::from(ip.to_ipv6_mapped()), 128u32)
+        }
+        (IpAddr::V4(_), IpAddr::V6(ip)) => match ip.to_ipv4_mapped() {
+            Some(ip) => return cidr_contains(network, prefix, IpAddr::V4(ip)),
+            None => return false,
+        },
+    };
+    let host_bits = bits - prefix as u32;
+    if host_bits >= bits {
+        true
+    } else {
+        (network >> host_bits) == (ip >> host_bits)
+    }
+}
+
+mod protobuf {
+    use super::{NetworkEgressPolicy, NetworkEgressRule};
+
+    impl From<golem_api_grpc::proto::golem::common::NetworkEgressPolicy> for NetworkEgressPolicy {
+        fn from(value: golem_api_grpc::proto::golem::common::NetworkEgressPolicy) -> Self {
+            Self {
+                no_network: value.no_network,
+                allow: value
+                    .allow
+                    .into_iter()
+                    .map(NetworkEgressRule::from)
+                    .collect(),
+                deny: value
+                    .deny
+                    .into_iter()
+                    .map(NetworkEgressRule::from)
+                    .collect(),
+            }
+        }
+    }
+
+    impl From<NetworkEgressPolicy> for golem_api_grpc::proto::golem::common::NetworkEgressPolicy {
+        fn from(value: NetworkEgressPolicy) -> Self {
+            Self {
+                no_network: value.no_network,
+                allow: value.allow.into_iter().map(|rule| rule.into()).collect(),
+                deny: value.deny.into_iter().map(|rule| rule.into()).collect(),
+            }
+        }
+    }
+
+    impl From<golem_api_grpc::proto::golem::common::NetworkEgressRule> for NetworkEgressRule {
+        fn from(value: golem_api_grpc::proto::golem::common::NetworkEgressRule) -> Self {
+            Self {
+                host: value.host,
+                // Ports are always serialized from u16 values
+                ports: value.ports.into_iter().map(|port| port as u16).collect(),
+            }
+        }
+    }
+
+    impl From<NetworkEgressRule> for golem_api_grpc::proto::golem::common::NetworkEgressRule {
+        fn from(value: NetworkEgressRule) -> Self {
+            Self {
+                host: value.host,
+                ports: value.ports.into_iter().map(|port| port as u32).collect(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NetworkEgressPolicy, NetworkEgressRule, NetworkEgressTarget};
+    use test_r::test;
+
+    fn rule(host: &str, ports: &[u16]) -> NetworkEgressRule {
+        NetworkEgressRule {
+            host: host.to_string(),
+            ports: ports.to_vec(),
+        }
+    }
+
+    #[test]
+    fn default_policy_allows_everything() {
+        let policy = NetworkEgressPolicy::default();
+        assert!(policy.is_unrestricted());
+        assert!(policy
+            .check(&NetworkEgressTarget::host("example.com", Some(443)))
+            .is_ok());
+    }
+
+    #[test]
+    fn no_network_denies_everything() {
+        let policy = NetworkEgressPolicy {
+            no_network: true,
+            allow: vec![rule("*", &[])],
+            deny: vec![],
+        };
+        assert!(policy
+            .check(&NetworkEgressTarget::host("example.com", None))
+            .is_err());
+    }
+
+    #[test]
+    fn allow_list_matches_domains_and_ports() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("*.example.com", &[443]), rule("api.test.org", &[])],
+            deny: vec![],
+        };
+        let check = |host: &str, port: Option<u16>| {
+            policy.check(&NetworkEgressTarget::host(host, port)).is_ok()
+        };
+
+        assert!(check("example.com", Some(443)));
+        assert!(check("www.Example.com", Some(443)));
+        assert!(!check("www.example.com", Some(80)));
+        assert!(check("www.example.com", None));
+        assert!(check("api.test.org", Some(8080)));
+        assert!(!check("test.org", Some(8080)));
+        assert!(!check("notexample.com", Some(443)));
+    }
+
+    #[test]
+    fn deny_list_takes_precedence_and_matches_cidr_blocks() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("*", &[])],
+            deny: vec![rule("10.0.0.0/8", &[]), rule("169.254.169.254", &[80])],
+        };
+        let check = |host: &str, port: Option<u16>| {
+            policy.check(&NetworkEgressTarget::host(host, port)).is_ok()
+        };
+
+        assert!(!check("10.1.2.3", Some(5432)));
+        assert!(check("11.1.2.3", Some(5432)));
+        assert!(!check("169.254.169.254", Some(80)));
+        assert!(check("169.254.169.254", None));
+        assert!(!check("[::ffff:10.0.0.1]", Some(80)));
+        assert!(check("example.com", Some(80)));
+    }
+
+    #[test]
+    fn resolved_host_names_are_matched_by_domain_and_cidr_rules() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("*", &[])],
+            deny: vec![rule("10.0.0.0/8", &[]), rule("blocked.example.com", &[])],
+        };
+        let resolved = |host: &str, ip: &str| NetworkEgressTarget {
+            ip: Some(ip.parse().unwrap()),
+            ..NetworkEgressTarget::host(host, Some(443))
+        };
+
+        assert!(policy
+            .check(&resolved("internal.example.com", "10.1.2.3"))
+            .is_err());
+        assert!(policy
+            .check(&resolved("blocked.example.com", "1.2.3.4"))
+            .is_err());
+        assert!(policy.check(&resolved("example.com", "1.2.3.4")).is_ok());
+    }
+
+    #[test]
+    fn cidr_allow_list_permits_name_lookups_but_not_other_addresses() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("93.184.0.0/16", &[])],
+            deny: vec![rule("blocked.example.com", &[])],
+        };
+        let resolved = |host: &str, ip: &str| NetworkEgressTarget {
+            ip: Some(ip.parse().unwrap()),
+            ..NetworkEgressTarget::host(host, Some(443))
+        };
+
+        assert!(policy.check_name_lookup("example.com").is_ok());
+        assert!(policy.check_name_lookup("blocked.example.com").is_err());
+        assert!(policy
+            .check(&resolved("example.com", "93.184.216.34"))
+            .is_ok());
+        assert!(policy.check(&resolved("example.com", "1.2.3.4")).is_err());
+    }
+
+    #[test]
+    fn domain_allow_list_restricts_name_lookups() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("*.example.com", &[])],
+            deny: vec![],
+        };
+
+        assert!(policy.check_name_lookup("api.example.com").is_ok());
+        assert!(policy.check_name_lookup("example.org").is_err());
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![rule("10.0.0.0/33", &[])],
+            deny: vec![],
+        };
+        assert!(policy.validate().is_err());
+
+        let policy = NetworkEgressPolicy {
+            no_network: false,
+            allow: vec![],
+            deny: vec![rule("a*.example.com", &[])],
+        };
+        assert!(policy.validate().is_err());
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::base_model::executor::*;
+
+use crate::model::RoutingTable;
+
+impl From<&RoutingTable> for ExecutorsSummary {
+    fn from(routing_table: &RoutingTable) -> Self {
+        let mut executors = routing_table
+            .shard_counts()
+            .into_iter()
+            .map(|(pod, shard_count)| ExecutorSummary {
+                host: pod.host().to_string(),
+                port: pod.port(),
+                shard_count: shard_count as u32,
+            })
+            .collect::<Vec<_>>();
+        executors.sort_by(|a, b| (&a.host, a.port).cmp(&(&b.host, b.port)));
+
+        Self {
+            number_of_shards: routing_table.number_of_shards.value as u32,
+            executors,
+        }
+    }
+}
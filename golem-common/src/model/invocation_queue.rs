@@ -0,0 +1,274 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::base_model::invocation_queue::*;
+
+use crate::model::{IdempotencyKey, TimestampedAgentInvocation};
+use std::str::FromStr;
+
+/// The maximum length of the parameter previews of pending invocations
+pub const INVOCATION_PREVIEW_MAX_LENGTH: usize = 200;
+
+impl FromStr for InvocationPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(InvocationPriority::Low),
+            "normal" => Ok(InvocationPriority::Normal),
+            "high" => Ok(InvocationPriority::High),
+            _ => Err(format!(
+                "Invalid invocation priority: {s}, must be one of low, normal or high"
+            )),
+        }
+    }
+}
+
+/// Inserts an invocation into a pending invocation queue, after the last invocation
+/// with the same or higher priority.
+pub fn insert_by_priority(
+    queue: &mut Vec<TimestampedAgentInvocation>,
+    invocation: TimestampedAgentInvocation,
+) {
+    let position = queue
+        .iter()
+        .rposition(|queued| queued.priority >= invocation.priority)
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+    queue.insert(position, invocation);
+}
+
+/// Moves a pending invocation to a new place in the queue, changing its priority.
+///
+/// If `position` is not specified, the invocation is placed by its priority, otherwise it is
+/// moved to the given position (or to the end of the queue if the position is out of bounds).
+/// Returns false if there was no pending invocation with the given idempotency key.
+pub fn reorder_pending_invocation(
+    queue: &mut Vec<TimestampedAgentInvocation>,
+    idempotency_key: &IdempotencyKey,
+    priority: InvocationPriority,
+    position: Option<u64>,
+) -> bool {
+    match queue
+        .iter()
+        .position(|queued| queued.invocation.has_idempotency_key(idempotency_key))
+    {
+        Some(idx) => {
+            let mut invocation = queue.remove(idx);
+            invocation.priority = priority;
+            match position {
+                Some(position) => {
+                    let position = (position as usize).min(queue.len());
+                    queue.insert(position, invocation);
+                }
+                None => insert_by_priority(queue, invocation),
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Shortens an invocation parameter preview to at most `INVOCATION_PREVIEW_MAX_LENGTH` characters
+pub fn truncate_preview(preview: String) -> String {
+    if preview.chars().count() > INVOCATION_PREVIEW_MAX_LENGTH {
+        let mut truncated: String = preview
+            .chars()
+            .take(INVOCATION_PREVIEW_MAX_LENGTH - 3)
+            .collect();
+        truncated.push_str("...");
+        truncated
+    } else {
+        preview
+    }
+}
+
+mod protobuf {
+    use super::{InvocationPriority, InvocationQueue, PendingInvocation};
+
+    impl From<InvocationPriority> for golem_api_grpc::proto::golem::worker::InvocationPriority {
+        fn from(value: InvocationPriority) -> Self {
+            match value {
+                InvocationPriority::Low => Self::Low,
+                InvocationPriority::Normal => Self::Normal,
+                InvocationPriority::High => Self::High,
+            }
+        }
+    }
+
+    impl From<golem_api_grpc::proto::golem::worker::InvocationPriority> for InvocationPriority {
+        fn from(value: golem_api_grpc::proto::golem::worker::InvocationPriority) -> Self {
+            match value {
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Low => Self::Low,
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Normal => Self::Normal,
+                golem_api_grpc::proto::golem::worker::InvocationPriority::High => Self::High,
+            }
+        }
+    }
+
+    impl From<PendingInvocation> for golem_api_grpc::proto::golem::worker::PendingInvocation {
+        fn from(value: PendingInvocation) -> Self {
+            Self {
+                idempotency_key: Some(value.idempotency_key.into()),
+                timestamp: Some(value.timestamp.into()),
+                method_name: value.method_name,
+                priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(
+                    value.priority,
+                ) as i32,
+                preview: value.preview,
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::worker::PendingInvocation> for PendingInvocation {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::worker::PendingInvocation,
+        ) -> Result<Self, Self::Error> {
+            let priority = value.priority().into();
+            Ok(Self {
+                idempotency_key: value
+                    .idempotency_key
+                    .ok_or("Missing idempotency_key")?
+                    .into(),
+                timestamp: value.timestamp.ok_or("Missing timestamp")?.into(),
+                method_name: value.method_name,
+                priority,
+                preview: value.preview,
+            })
+        }
+    }
+
+    impl From<InvocationQueue> for golem_api_grpc::proto::golem::worker::InvocationQueue {
+        fn from(value: InvocationQueue) -> Self {
+            Self {
+                invocations: value.invocations.into_iter().map(|i| i.into()).collect(),
+                limit: value.limit,
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::worker::InvocationQueue> for InvocationQueue {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::worker::InvocationQueue,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                invocations: value
+                    .invocations
+                    .into_iter()
+                    .map(|i| i.try_into())
+                    .collect::<Result<Vec<_>, _>>()?,
+                limit: value.limit,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        insert_by_priority, reorder_pending_invocation, truncate_preview, InvocationPriority,
+        INVOCATION_PREVIEW_MAX_LENGTH,
+    };
+    use crate::model::agent::{Principal, UntypedDataValue};
+    use crate::model::invocation_context::InvocationContextStack;
+    use crate::model::{AgentInvocation, IdempotencyKey, Timestamp, TimestampedAgentInvocation};
+    use test_r::test;
+
+    fn invocation(key: &str, priority: InvocationPriority) -> TimestampedAgentInvocation {
+        TimestampedAgentInvocation {
+            timestamp: Timestamp::now_utc(),
+            invocation: AgentInvocation::AgentMethod {
+                idempotency_key: IdempotencyKey::new(key.to_string()),
+                method_name: "method".to_string(),
+                input: UntypedDataValue::Tuple(vec![]),
+                invocation_context: InvocationContextStack::fresh(),
+                principal: Principal::anonymous(),
+            },
+            priority,
+        }
+    }
+
+    fn keys(queue: &[TimestampedAgentInvocation]) -> Vec<String> {
+        queue
+            .iter()
+            .map(|i| i.invocation.idempotency_key().unwrap().value.clone())
+            .collect()
+    }
+
+    #[test]
+    fn insert_keeps_priority_classes_in_arrival_order() {
+        let mut queue = Vec::new();
+        insert_by_priority(&mut queue, invocation("n1", InvocationPriority::Normal));
+        insert_by_priority(&mut queue, invocation("l1", InvocationPriority::Low));
+        insert_by_priority(&mut queue, invocation("h1", InvocationPriority::High));
+        insert_by_priority(&mut queue, invocation("n2", InvocationPriority::Normal));
+        insert_by_priority(&mut queue, invocation("h2", InvocationPriority::High));
+
+        assert_eq!(keys(&queue), vec!["h1", "h2", "n1", "n2", "l1"]);
+    }
+
+    #[test]
+    fn reorder_by_priority_and_position() {
+        let mut queue = Vec::new();
+        insert_by_priority(&mut queue, invocation("a", InvocationPriority::Normal));
+        insert_by_priority(&mut queue, invocation("b", InvocationPriority::Normal));
+        insert_by_priority(&mut queue, invocation("c", InvocationPriority::Normal));
+
+        assert!(reorder_pending_invocation(
+            &mut queue,
+            &IdempotencyKey::new("c".to_string()),
+            InvocationPriority::High,
+            None
+        ));
+        assert_eq!(keys(&queue), vec!["c", "a", "b"]);
+
+        assert!(reorder_pending_invocation(
+            &mut queue,
+            &IdempotencyKey::new("c".to_string()),
+            InvocationPriority::High,
+            Some(100)
+        ));
+        assert_eq!(keys(&queue), vec!["a", "b", "c"]);
+
+        assert!(reorder_pending_invocation(
+            &mut queue,
+            &IdempotencyKey::new("b".to_string()),
+            InvocationPriority::Low,
+            Some(0)
+        ));
+        assert_eq!(keys(&queue), vec!["b", "a", "c"]);
+        assert_eq!(queue[0].priority, InvocationPriority::Low);
+
+        assert!(!reorder_pending_invocation(
+            &mut queue,
+            &IdempotencyKey::new("missing".to_string()),
+            InvocationPriority::High,
+            None
+        ));
+    }
+
+    #[test]
+    fn long_previews_are_truncated() {
+        let short = "x".repeat(INVOCATION_PREVIEW_MAX_LENGTH);
+        assert_eq!(truncate_preview(short.clone()), short);
+
+        let truncated = truncate_preview("x".repeat(INVOCATION_PREVIEW_MAX_LENGTH + 1));
+        assert_eq!(truncated.chars().count(), INVOCATION_PREVIEW_MAX_LENGTH);
+        assert!(truncated.ends_with("..."));
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::base_model::lifecycle_webhook::*;
+
+mod protobuf {
+    use super::{AgentLifecycleEvent, AgentLifecycleEventType};
+    use crate::model::agent::AgentTypeName;
+    use crate::model::component::ComponentRevision;
+    use chrono::DateTime;
+
+    impl From<AgentLifecycleEventType>
+        for golem_api_grpc::proto::golem::registry::AgentLifecycleEventType
+    {
+        fn from(value: AgentLifecycleEventType) -> Self {
+            match value {
+                AgentLifecycleEventType::Created => Self::Created,
+                AgentLifecycleEventType::Failed => Self::Failed,
+                AgentLifecycleEventType::Exited => Self::Exited,
+                AgentLifecycleEventType::Interrupted => Self::Interrupted,
+                AgentLifecycleEventType::Updated => Self::Updated,
+                AgentLifecycleEventType::UpdateFailed => Self::UpdateFailed,
+                AgentLifecycleEventType::InvocationFailed => Self::InvocationFailed,
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::registry::AgentLifecycleEventType>
+        for AgentLifecycleEventType
+    {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::registry::AgentLifecycleEventType,
+        ) -> Result<Self, Self::Error> {
+            use golem_api_grpc::proto::golem::registry::AgentLifecycleEventType as GrpcEventType;
+            match value {
+                GrpcEventType::Created => Ok(Self::Created),
+                GrpcEventType::Failed => Ok(Self::Failed),
+                GrpcEventType::Exited => Ok(Self::Exited),
+                GrpcEventType::Interrupted => Ok(Self::Interrupted),
+                GrpcEventType::Updated => Ok(Self::Updated),
+                GrpcEventType::UpdateFailed => Ok(Self::UpdateFailed),
+                GrpcEventType::InvocationFailed => Ok(Self::InvocationFailed),
+                GrpcEventType::Unspecified => Err("Unknown agent lifecycle event type".to_string()),
+            }
+        }
+    }
+
+    impl From<AgentLifecycleEvent> for golem_api_grpc::proto::golem::registry::AgentLifecycleEvent {
+        fn from(value: AgentLifecycleEvent) -> Self {
+            let event_type: golem_api_grpc::proto::golem::registry::AgentLifecycleEventType =
+                value.event_type.into();
+            Self {
+                event_id: Some(uuid::Uuid::from(value.id).into()),
+                environment_id: Some(value.environment_id.into()),
+                worker_id: Some(value.worker_id.into()),
+                agent_type: value.agent_type.map(|agent_type| agent_type.0),
+                event_type: event_type as i32,
+                component_revision: value.component_revision.get(),
+                timestamp: Some(prost_types::Timestamp {
+                    seconds: value.timestamp.timestamp(),
+                    nanos: value.timestamp.timestamp_subsec_nanos() as i32,
+                }),
+                error: value.error,
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::registry::AgentLifecycleEvent> for AgentLifecycleEvent {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::registry::AgentLifecycleEvent,
+        ) -> Result<Self, Self::Error> {
+            let event_type = value.event_type().try_into()?;
+            let timestamp = value.timestamp.ok_or("Missing timestamp")?;
+            Ok(Self {
+                id: uuid::Uuid::from(value.event_id.ok_or("Missing event_id")?).into(),
+                environment_id: value
+                    .environment_id
+                    .ok_or("Missing environment_id")?
+                    .try_into()?,
+                worker_id: value.worker_id.ok_or("Missing worker_id")?.try_into()?,
+                agent_type: value.agent_type.map(AgentTypeName),
+                event_type,
+                component_revision: ComponentRevision::new(value.component_revision)
+                    .map_err(|err| err.to_string())?,
+                timestamp: DateTime::from_timestamp(timestamp.seconds, timestamp.nanos as u32)
+                    .ok_or("Invalid timestamp")?,
+                error: value.error,
+            })
+        }
+    }
+}
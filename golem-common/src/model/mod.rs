@@ -17,6 +17,7 @@ pub mod agent;
 pub mod application;
 pub mod auth;
 pub mod base64;
+pub mod bulk_operation;
 pub mod certificate;
 pub mod component;
 pub mod component_metadata;
@@ -27,9 +28,12 @@ pub mod environment;
 pub mod environment_plugin_grant;
 pub mod environment_share;
 pub mod error;
+pub mod executor;
 pub mod exports;
 pub mod http_api_deployment;
 pub mod invocation_context;
+pub mod invocation_queue;
+pub mod lifecycle_webhook;
 pub mod login;
 pub mod lucene;
 pub mod mcp_deployment;
@@ -41,6 +45,8 @@ pub mod plan;
 pub mod plugin_registration;
 pub mod poem;
 pub mod protobuf;
+pub mod pubsub;
+pub mod recurring_invocation;
 pub mod regions;
 pub mod reports;
 pub mod security_scheme;
@@ -54,6 +60,8 @@ pub use crate::base_model::*;
 use self::component::ComponentId;
 use self::component::{ComponentFilePermissions, ComponentRevision, PluginPriority};
 use self::environment::EnvironmentId;
+use self::invocation_queue::InvocationPriority;
+use self::recurring_invocation::RecurringInvocationId;
 use self::worker::ParsedWorkerCreationLocalAgentConfigEntry;
 use crate::base_model::agent::AgentId;
 use crate::base_model::agent::Principal;
@@ -62,9 +70,9 @@ use crate::model::agent::{AgentTypeResolver, UntypedDataValue, UntypedElementVal
 use crate::model::invocation_context::InvocationContextStack;
 use crate::model::oplog::types::AgentMetadataForGuests;
 use crate::model::oplog::{
-    OplogEntry, RawSnapshotData, TimestampedUpdateDescription, WorkerResourceId,
+    OplogEntry, RawSnapshotData, SagaCompensation, TimestampedUpdateDescription, WorkerResourceId,
 };
-use crate::model::regions::DeletedRegions;
+use crate::model::regions::{DeletedRegions, OplogRegion};
 use crate::{grpc_uri, SafeDisplay};
 use desert_rust::{
     BinaryCodec, BinaryDeserializer, BinaryOutput, BinarySerializer, DeserializationContext,
@@ -324,6 +332,28 @@ pub enum ScheduledAction {
         owned_worker_id: OwnedWorkerId,
         invocation: Box<AgentInvocation>,
     },
+    /// Fires the occurrence of a recurring invocation that was due at `scheduled_for`, and
+    /// schedules its next occurrence. The recurring invocation itself is stored separately,
+    /// the action is dropped if it no longer exists.
+    InvokeRecurring {
+        owned_worker_id: OwnedWorkerId,
+        id: RecurringInvocationId,
+        scheduled_for: Timestamp,
+    },
+    /// Completes a given promise with the expired outcome, if it has not been completed
+    /// or failed before
+    ExpirePromise {
+        account_id: AccountId,
+        environment_id: EnvironmentId,
+        promise_id: PromiseId,
+    },
+    /// Performs the compensations of the sagas left open by a permanently failed agent. If
+    /// some of them could not be performed, schedules a next attempt.
+    CompensateSagas {
+        account_id: AccountId,
+        owned_worker_id: OwnedWorkerId,
+        attempt: u32,
+    },
 }
 
 impl ScheduledAction {
@@ -340,6 +370,17 @@ impl ScheduledAction {
             ScheduledAction::Invoke {
                 owned_worker_id, ..
             } => owned_worker_id.clone(),
+            ScheduledAction::InvokeRecurring {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
+            ScheduledAction::ExpirePromise {
+                environment_id,
+                promise_id,
+                ..
+            } => OwnedWorkerId::new(*environment_id, &promise_id.worker_id),
+            ScheduledAction::CompensateSagas {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
         }
     }
 }
@@ -358,6 +399,17 @@ impl Display for ScheduledAction {
             ScheduledAction::Invoke {
                 owned_worker_id, ..
             } => write!(f, "invoke[{owned_worker_id}]"),
+            ScheduledAction::InvokeRecurring {
+                owned_worker_id,
+                id,
+                ..
+            } => write!(f, "invoke-recurring[{owned_worker_id}/{id}]"),
+            ScheduledAction::ExpirePromise { promise_id, .. } => {
+                write!(f, "expire[{promise_id}]")
+            }
+            ScheduledAction::CompensateSagas {
+                owned_worker_id, ..
+            } => write!(f, "compensate-sagas[{owned_worker_id}]"),
         }
     }
 }
@@ -387,6 +439,14 @@ pub struct Pod {
 }
 
 impl Pod {
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
     pub fn uri(&self, use_tls: bool) -> Uri {
         grpc_uri(&self.host, self.port, use_tls)
     }
@@ -423,6 +483,15 @@ impl RoutingTable {
     pub fn all(&self) -> HashSet<&Pod> {
         self.shard_assignments.values().collect()
     }
+
+    /// Number of shards assigned to each pod
+    pub fn shard_counts(&self) -> HashMap<&Pod, usize> {
+        let mut result = HashMap::new();
+        for pod in self.shard_assignments.values() {
+            *result.entry(pod).or_insert(0) += 1;
+        }
+        result
+    }
 }
 
 impl Display for RoutingTable {
@@ -571,6 +640,53 @@ impl WorkerFilter {
             WorkerFilter::Status(WorkerStatusFilter { comparator, value }) => {
                 comparator.matches(&metadata.last_known_status.status, &value)
             }
+            WorkerFilter::AgentType(WorkerAgentTypeFilter { comparator, value }) => {
+                agent::parse_agent_id_parts(&metadata.worker_id.worker_name)
+                    .map(|(agent_type, _, _)| comparator.matches(&agent_type, &value.as_str()))
+                    .unwrap_or(false)
+            }
+            WorkerFilter::AgentParameters(WorkerAgentParametersFilter { comparator, value }) => {
+                agent::parse_agent_id_parts(&metadata.worker_id.worker_name)
+                    .map(|(_, parameters, _)| comparator.matches(&parameters, &value.as_str()))
+                    .unwrap_or(false)
+            }
+            WorkerFilter::LastInvocationAt(WorkerLastInvocationAtFilter { comparator, value }) => {
+                let last_invocation_at = metadata
+                    .last_known_status
+                    .last_invocation_at
+                    .unwrap_or(metadata.created_at);
+                comparator.matches(&last_invocation_at, &value)
+            }
+            WorkerFilter::PendingInvocationCount(WorkerPendingInvocationCountFilter {
+                comparator,
+                value,
+            }) => {
+                let count = metadata.last_known_status.pending_invocations.len() as u64;
+                comparator.matches(&count, &value)
+            }
+            WorkerFilter::RetryCount(WorkerRetryCountFilter { comparator, value }) => {
+                let retry_count = metadata
+                    .last_known_status
+                    .current_retry_count
+                    .values()
+                    .max()
+                    .copied()
+                    .unwrap_or_default();
+                comparator.matches(&retry_count, &value)
+            }
+            WorkerFilter::LastError(WorkerLastErrorFilter { comparator, value }) => metadata
+                .last_known_status
+                .last_error
+                .as_ref()
+                .map(|last_error| comparator.matches(last_error, &value))
+                .unwrap_or(false),
+            WorkerFilter::MemorySize(WorkerMemorySizeFilter { comparator, value }) => {
+                comparator.matches(&metadata.last_known_status.total_linear_memory_size, &value)
+            }
+            WorkerFilter::ActivePlugin(WorkerActivePluginFilter { plugin_priority }) => metadata
+                .last_known_status
+                .active_plugins
+                .contains(&plugin_priority),
             WorkerFilter::Not(WorkerNotFilter { filter }) => !filter.matches(metadata),
             WorkerFilter::And(WorkerAndFilter { filters }) => {
                 let mut result = true;
@@ -683,7 +799,13 @@ impl SafeDisplay for RetryConfig {
 /// By having an associated oplog_idx, the cached information can be used together with the
 /// tail of the oplog to determine the actual status of the worker.
 #[derive(Clone, Debug, PartialEq, BinaryCodec)]
-#[desert(evolution())]
+#[desert(evolution(
+    FieldAdded("discarded_region", None),
+    FieldAdded("last_invocation_at", None),
+    FieldAdded("last_error", None),
+    FieldAdded("pending_invocation_limit", None),
+    FieldAdded("open_sagas", BTreeMap::new())
+))]
 pub struct WorkerStatusRecord {
     pub status: WorkerStatus,
     pub skipped_regions: DeletedRegions,
@@ -708,6 +830,20 @@ pub struct WorkerStatusRecord {
     /// the last invocation boundary.
     pub current_retry_count: HashMap<OplogIndex, u32>,
     pub last_snapshot_index: Option<OplogIndex>,
+    /// The prefix of the oplog (excluding the initial Create entry) that has been permanently
+    /// discarded by the oplog retention policy. This is the only field that cannot be recomputed
+    /// from the oplog, so it is preserved when the status is recalculated from scratch.
+    pub discarded_region: Option<OplogRegion>,
+    /// The start time of the last invocation
+    pub last_invocation_at: Option<Timestamp>,
+    /// The message of the last error encountered since the last invocation boundary
+    pub last_error: Option<String>,
+    /// The maximum number of pending invocations, new invocations are rejected above this limit
+    pub pending_invocation_limit: Option<u64>,
+    /// Sagas coordinated by this agent which were neither completed nor compensated yet, keyed by the
+    /// index of their BeginSaga entry. Each saga maps the indices of its not yet compensated steps to
+    /// their compensations.
+    pub open_sagas: BTreeMap<OplogIndex, BTreeMap<OplogIndex, SagaCompensation>>,
 }
 
 impl Default for WorkerStatusRecord {
@@ -732,6 +868,11 @@ impl Default for WorkerStatusRecord {
             component_revision_for_replay: ComponentRevision::INITIAL,
             current_retry_count: HashMap::new(),
             last_snapshot_index: None,
+            discarded_region: None,
+            last_invocation_at: None,
+            last_error: None,
+            pending_invocation_limit: None,
+            open_sagas: BTreeMap::new(),
         }
     }
 }
@@ -1131,10 +1272,11 @@ impl AgentInvocation {
 }
 
 #[derive(Clone, Debug, PartialEq, BinaryCodec)]
-#[desert(evolution())]
+#[desert(evolution(FieldAdded("priority", InvocationPriority::Normal)))]
 pub struct TimestampedAgentInvocation {
     pub timestamp: Timestamp,
     pub invocation: AgentInvocation,
+    pub priority: InvocationPriority,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, BinaryCodec, Serialize, Deserialize)]
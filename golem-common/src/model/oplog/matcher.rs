@@ -389,6 +389,39 @@ impl PublicOplogEntry {
             PublicOplogEntry::Snapshot(_params) => {
                 Self::string_match("snapshot", &[], query_path, query)
             }
+            PublicOplogEntry::ReorderPendingInvocation(params) => {
+                Self::string_match("reorderpendinginvocation", &[], query_path, query)
+                    || Self::string_match("reorder-pending-invocation", &[], query_path, query)
+                    || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+                    || Self::string_match(&params.priority.to_string(), &[], query_path, query)
+            }
+            PublicOplogEntry::SetInvocationQueueLimit(_params) => {
+                Self::string_match("setinvocationqueuelimit", &[], query_path, query)
+                    || Self::string_match("set-invocation-queue-limit", &[], query_path, query)
+            }
+            PublicOplogEntry::BeginSaga(_params) => {
+                Self::string_match("beginsaga", &[], query_path, query)
+                    || Self::string_match("begin-saga", &[], query_path, query)
+            }
+            PublicOplogEntry::SagaStep(params) => {
+                Self::string_match("sagastep", &[], query_path, query)
+                    || Self::string_match("saga-step", &[], query_path, query)
+                    || Self::string_match(
+                        &params.compensation.target_worker_id.to_string(),
+                        &[],
+                        query_path,
+                        query,
+                    )
+                    || Self::string_match(&params.compensation.method_name, &[], query_path, query)
+            }
+            PublicOplogEntry::SagaStepCompensated(_params) => {
+                Self::string_match("sagastepcompensated", &[], query_path, query)
+                    || Self::string_match("saga-step-compensated", &[], query_path, query)
+            }
+            PublicOplogEntry::EndSaga(_params) => {
+                Self::string_match("endsaga", &[], query_path, query)
+                    || Self::string_match("end-saga", &[], query_path, query)
+            }
         }
     }
 
@@ -24,15 +24,20 @@ use crate::model::oplog::payload::types::{
     SerializableSocketError,
 };
 use crate::model::oplog::types::{
-    AgentMetadataForGuests, SerializableDbColumn, SerializableDbResult, SerializableDbValue,
-    SerializableHttpErrorCode, SerializableHttpMethod, SerializableHttpResponse,
-    SerializableInvokeResult, SerializableIpAddresses, SerializableRdbmsError,
-    SerializableRdbmsRequest, SerializableRpcError, SerializableScheduledInvocation,
-    SerializableStreamError,
+    AgentMetadataForGuests, SerializableDatagram, SerializableDbColumn, SerializableDbResult,
+    SerializableDbValue, SerializableHttpErrorCode, SerializableHttpMethod,
+    SerializableHttpResponse, SerializableInvokeResult, SerializableIpAddresses,
+    SerializableIpSocketAddress, SerializableRdbmsError, SerializableRdbmsRequest,
+    SerializableRpcError, SerializableScheduledInvocation, SerializableStreamError,
 };
 use crate::model::oplog::PayloadId;
+use crate::model::recurring_invocation::{
+    MissedFirePolicy, RecurringInvocation, RecurringInvocationId, RecurringSchedule,
+};
 use crate::model::worker::RevertWorkerTarget;
-use crate::model::{ComponentId, ForkResult, IdempotencyKey, OplogIndex, PromiseId, WorkerId};
+use crate::model::{
+    ComponentId, ForkResult, IdempotencyKey, OplogIndex, PromiseId, PromiseOutcome, WorkerId,
+};
 use crate::oplog_payload;
 use crate::serialization::serialize;
 use desert_rust::{
@@ -111,6 +116,17 @@ oplog_payload! {
             target_revision: ComponentRevision,
             mode: UpdateMode
         },
+        GolemApiPubSubPublish {
+            topic: String,
+            message: UntypedDataValue
+        },
+        GolemApiPubSubSubscription {
+            topic: String,
+            handler_method: String
+        },
+        GolemApiPubSubTopic {
+            topic: String
+        },
         GolemAgentGetAgentType {
             agent_type_name: AgentTypeName
         },
@@ -145,6 +161,23 @@ oplog_payload! {
         GolemRpcScheduledInvocationCancellation {
             invocation: SerializableScheduledInvocation
         },
+        GolemRpcRecurringInvocation {
+            remote_worker_id: WorkerId,
+            method_name: String,
+            input: UntypedDataValue,
+            schedule: RecurringSchedule,
+            missed_fire_policy: MissedFirePolicy,
+        },
+        GolemRpcRecurringInvocationList {
+            remote_worker_id: WorkerId,
+        },
+        GolemRpcRecurringInvocationCancellation {
+            remote_worker_id: WorkerId,
+            id: RecurringInvocationId,
+        },
+        GolemRpcSagaAbort {
+            begin_index: OplogIndex,
+        },
         HttpRequest {
              uri: String,
              method: SerializableHttpMethod,
@@ -179,6 +212,15 @@ oplog_payload! {
         SocketsResolveName {
             name: String
         },
+        SocketsTcpConnection {
+            remote_address: SerializableIpSocketAddress
+        },
+        SocketsUdpReceive {
+            max_results: u64
+        },
+        SocketsUdpSend {
+            datagram_count: u64
+        },
     }
 }
 
@@ -245,9 +287,18 @@ oplog_payload! {
         GolemApiPromiseResult {
             result: Option<Vec<u8>>
         },
+        GolemApiPromiseOutcome {
+            outcome: Option<PromiseOutcome>
+        },
         GolemApiUnit {
             result: Result<(), String>,
         },
+        GolemApiPubSubOffset {
+            result: Result<u64, String>
+        },
+        GolemApiPubSubUnsubscribed {
+            result: Result<bool, String>
+        },
         GolemAgentAgentTypes {
             result: Result<Vec<RegisteredAgentType>, String>
         },
@@ -278,6 +329,15 @@ oplog_payload! {
         GolemRpcScheduledInvocation {
             invocation: SerializableScheduledInvocation
         },
+        GolemRpcRecurringInvocation {
+            result: Result<RecurringInvocation, String>
+        },
+        GolemRpcRecurringInvocations {
+            result: Result<Vec<RecurringInvocation>, String>
+        },
+        GolemRpcRecurringInvocationCanceled {
+            result: Result<bool, String>
+        },
         GolemRpcUnitOrFailure { result: Result<(), SerializableRpcError> },
         GolemRpcUnit {},
         HttpFutureTrailersGet {
@@ -325,6 +385,18 @@ oplog_payload! {
         },
         StreamSkip {
             result: Result<u64, SerializableStreamError>
+        },
+        SocketsUnit {
+            result: Result<(), SerializableSocketError>
+        },
+        SocketsUdpDatagrams {
+            result: Result<Vec<SerializableDatagram>, SerializableSocketError>
+        },
+        SocketsUdpSendCount {
+            result: Result<u64, SerializableSocketError>
+        },
+        StreamWrite {
+            result: Result<(), SerializableStreamError>
         }
     }
 }
@@ -377,7 +449,11 @@ pub mod host_functions {
         (GolemRpcWasmRpcInvokeAndAwaitResult => "golem::rpc::wasm-rpc", "invoke_and_await", GolemRpcInvoke, GolemRpcInvokeAndAwait),
         (GolemRpcWasmRpcInvoke => "golem::rpc::wasm-rpc", "invoke", GolemRpcInvoke, GolemRpcUnitOrFailure),
         (GolemRpcWasmRpcScheduleInvocation => "golem::rpc::wasm-rpc", "schedule_invocation", GolemRpcScheduledInvocation, GolemRpcScheduledInvocation),
+        (GolemRpcWasmRpcScheduleRecurringInvocation => "golem::rpc::wasm-rpc", "schedule_recurring_invocation", GolemRpcRecurringInvocation, GolemRpcRecurringInvocation),
+        (GolemRpcWasmRpcListRecurringInvocations => "golem::rpc::wasm-rpc", "list_recurring_invocations", GolemRpcRecurringInvocationList, GolemRpcRecurringInvocations),
+        (GolemRpcWasmRpcCancelRecurringInvocation => "golem::rpc::wasm-rpc", "cancel_recurring_invocation", GolemRpcRecurringInvocationCancellation, GolemRpcRecurringInvocationCanceled),
         (GolemRpcCancellationTokenCancel => "golem::rpc::cancellation-token", "cancel", GolemRpcScheduledInvocationCancellation, GolemRpcUnit),
+        (GolemRpcSagaAbort => "golem::rpc::saga", "abort", GolemRpcSagaAbort, GolemRpcUnitOrFailure),
         (IoPollReady => "io::poll", "ready", NoInput, PollReady),
         (IoPollPoll => "io::poll", "poll", PollCount, PollResult),
         (HttpTypesFutureTrailersGet => "http::types::future_trailers", "get", HttpRequest, HttpFutureTrailersGet),
@@ -408,21 +484,36 @@ pub mod host_functions {
         (FilesystemTypesDescriptorStat => "filesystem::types::descriptor", "stat", FileSystemPath, FileSystemStat),
         (FilesystemTypesDescriptorStatAt => "filesystem::types::descriptor", "stat_at", FileSystemPath, FileSystemStat),
         (SocketsIpNameLookupResolveAddresses => "sockets::ip_name_lookup", "resolve_addresses", SocketsResolveName, SocketsResolveName),
+        (SocketsTcpStartConnect => "sockets::tcp", "start_connect", SocketsTcpConnection, SocketsUnit),
+        (SocketsTcpFinishConnect => "sockets::tcp", "finish_connect", SocketsTcpConnection, SocketsUnit),
+        (SocketsTcpInputStreamRead => "sockets::tcp::input_stream", "read", SocketsTcpConnection, StreamChunk),
+        (SocketsTcpInputStreamBlockingRead => "sockets::tcp::input_stream", "blocking_read", SocketsTcpConnection, StreamChunk),
+        (SocketsTcpInputStreamSkip => "sockets::tcp::input_stream", "skip", SocketsTcpConnection, StreamSkip),
+        (SocketsTcpInputStreamBlockingSkip => "sockets::tcp::input_stream", "blocking_skip", SocketsTcpConnection, StreamSkip),
+        (SocketsTcpOutputStreamWrite => "sockets::tcp::output_stream", "write", SocketsTcpConnection, StreamWrite),
+        (SocketsUdpIncomingDatagramStreamReceive => "sockets::udp::incoming_datagram_stream", "receive", SocketsUdpReceive, SocketsUdpDatagrams),
+        (SocketsUdpOutgoingDatagramStreamSend => "sockets::udp::outgoing_datagram_stream", "send", SocketsUdpSend, SocketsUdpSendCount),
         (GolemAgentGetAllAgentTypes => "golem::agent", "get_all_agent_types", NoInput, GolemAgentAgentTypes),
         (GolemAgentGetAgentType => "golem::agent", "get_agent_type", GolemAgentGetAgentType, GolemAgentAgentType),
         (GolemAgentCreateWebhook => "golem::agent", "create_webhook", GolemApiPromiseId, GolemAgentWebhookUrl),
         (GolemApiCreatePromise => "golem::api", "create_promise", NoInput, GolemApiPromiseId),
         (GolemApiCompletePromise => "golem::api", "complete_promise", GolemApiPromiseId, GolemApiPromiseCompletion),
+        (GolemApiCreatePromiseWithTimeout => "golem::api", "create_promise_with_timeout", MonotonicClockDuration, GolemApiPromiseId),
+        (GolemApiFailPromise => "golem::api", "fail_promise", GolemApiPromiseId, GolemApiPromiseCompletion),
         (GolemApiGenerateIdempotencyKey => "golem::api", "generate_idempotency-key", NoInput, GolemApiIdempotencyKey),
         (GolemApiUpdateWorker => "golem::api", "update_worker", GolemApiUpdateAgent, GolemApiUnit),
         (GolemApiGetSelfMetadata => "golem::api", "get_self_metadata", NoInput, GolemApiSelfAgentMetadata),
         (GolemApiGetAgentMetadata => "golem::api", "get_agent_metadata", GolemApiAgentId, GolemApiAgentMetadata),
         (GolemApiGetPromiseResult => "golem::api", "get_promise_result", NoInput, GolemApiPromiseResult),
+        (GolemApiGetPromiseOutcome => "golem::api", "get_promise_outcome", NoInput, GolemApiPromiseOutcome),
         (GolemApiForkWorker => "golem::api", "fork_worker", GolemApiForkAgent, GolemApiUnit),
         (GolemApiRevertWorker => "golem::api", "revert_worker", GolemApiRevertAgent, GolemApiUnit),
         (GolemApiResolveComponentId => "golem::api", "resolve_component_id", GolemApiComponentSlug, GolemApiComponentId),
         (GolemApiResolveWorkerIdStrict => "golem::api", "resolve_worker_id_strict", GolemApiComponentSlugAndAgentName, GolemApiAgentId),
-        (GolemApiFork => "golem::api", "fork", NoInput, GolemApiFork)
+        (GolemApiFork => "golem::api", "fork", NoInput, GolemApiFork),
+        (GolemApiPubSubPublish => "golem::api::pubsub", "publish", GolemApiPubSubPublish, GolemApiPubSubOffset),
+        (GolemApiPubSubSubscribe => "golem::api::pubsub", "subscribe", GolemApiPubSubSubscription, GolemApiUnit),
+        (GolemApiPubSubUnsubscribe => "golem::api::pubsub", "unsubscribe", GolemApiPubSubTopic, GolemApiPubSubUnsubscribed)
     }
 }
 
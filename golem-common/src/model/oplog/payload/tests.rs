@@ -17,8 +17,8 @@ use test_r::test;
 use crate::model::invocation_context::{AttributeValue, SpanId};
 use crate::model::oplog::raw_types::SpanData;
 use crate::model::oplog::types::{
-    SerializableDateTime, SerializableHttpErrorCode, SerializableHttpVersion,
-    SerializableIpAddress, SerializableIpAddresses,
+    SerializableDatagram, SerializableDateTime, SerializableHttpErrorCode, SerializableHttpVersion,
+    SerializableIpAddress, SerializableIpAddresses, SerializableIpSocketAddress,
 };
 use crate::model::Timestamp;
 use http::Version;
@@ -30,7 +30,10 @@ use proptest::strategy::LazyJust;
 use std::num::NonZeroU64;
 use std::ops::Add;
 use std::time::{Duration, SystemTime};
-use wasmtime_wasi::p2::bindings::sockets::network::IpAddress;
+use wasmtime_wasi::p2::bindings::sockets::network::{
+    IpAddress, IpSocketAddress, Ipv4SocketAddress, Ipv6SocketAddress,
+};
+use wasmtime_wasi::p2::bindings::sockets::udp::IncomingDatagram;
 use wasmtime_wasi_http::bindings::http::types::{
     DnsErrorPayload, ErrorCode, FieldSizePayload, TlsAlertReceivedPayload,
 };
@@ -58,6 +61,35 @@ fn ipaddress_strat() -> impl Strategy<Value = IpAddress> {
     }
 }
 
+fn ip_socket_address_strat() -> impl Strategy<Value = IpSocketAddress> {
+    prop_oneof! {
+        (ipaddress_strat(), any::<u16>(), any::<u32>(), any::<u32>()).prop_map(|(address, port, flow_info, scope_id)| match address {
+            IpAddress::Ipv4(address) => IpSocketAddress::Ipv4(Ipv4SocketAddress { port, address }),
+            IpAddress::Ipv6(address) => IpSocketAddress::Ipv6(Ipv6SocketAddress { port, flow_info, address, scope_id }),
+        }),
+    }
+}
+
+fn assert_same_ip_socket_address(
+    value: &IpSocketAddress,
+    result: &IpSocketAddress,
+) -> Result<(), TestCaseError> {
+    match (value, result) {
+        (IpSocketAddress::Ipv4(value), IpSocketAddress::Ipv4(result)) => {
+            prop_assert_eq!(value.address, result.address);
+            prop_assert_eq!(value.port, result.port);
+        }
+        (IpSocketAddress::Ipv6(value), IpSocketAddress::Ipv6(result)) => {
+            prop_assert_eq!(value.address, result.address);
+            prop_assert_eq!(value.port, result.port);
+            prop_assert_eq!(value.flow_info, result.flow_info);
+            prop_assert_eq!(value.scope_id, result.scope_id);
+        }
+        _ => prop_assert!(false),
+    }
+    Ok(())
+}
+
 fn span_data_strat() -> impl Strategy<Value = SpanData> {
     prop_oneof![
         (
@@ -119,6 +151,22 @@ proptest! {
         }
     }
 
+    #[test]
+    fn roundtrip_ip_socket_address(value in ip_socket_address_strat()) {
+        let serialized: SerializableIpSocketAddress = value.into();
+        let result: IpSocketAddress = serialized.into();
+        assert_same_ip_socket_address(&value, &result)?;
+    }
+
+    #[test]
+    fn roundtrip_datagram(data in vec(any::<u8>(), 0..100), remote_address in ip_socket_address_strat()) {
+        let value = IncomingDatagram { data, remote_address };
+        let serialized: SerializableDatagram = value.clone().into();
+        let result: IncomingDatagram = serialized.into();
+        prop_assert_eq!(&value.data, &result.data);
+        assert_same_ip_socket_address(&value.remote_address, &result.remote_address)?;
+    }
+
     #[test]
     fn roundtrip_ipaddresses(value in vec(ipaddress_strat(), 0..100)) {
         let serialized: SerializableIpAddresses = value.clone().into();
@@ -52,6 +52,10 @@ use uuid::Uuid;
 use wasmtime_wasi::p2::bindings::filesystem;
 use wasmtime_wasi::p2::bindings::sockets::ip_name_lookup::IpAddress;
 use wasmtime_wasi::p2::bindings::sockets::network::ErrorCode as SocketErrorCode;
+use wasmtime_wasi::p2::bindings::sockets::network::{
+    IpSocketAddress, Ipv4SocketAddress, Ipv6SocketAddress,
+};
+use wasmtime_wasi::p2::bindings::sockets::udp::IncomingDatagram;
 use wasmtime_wasi::p2::{FsError, SocketError};
 use wasmtime_wasi::StreamError;
 use wasmtime_wasi_http::bindings::http::types::{
@@ -1259,6 +1263,78 @@ impl From<SerializableIpAddresses> for Vec<IpAddress> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BinaryCodec, IntoValue, FromValue)]
+#[desert(evolution())]
+pub struct SerializableIpSocketAddress {
+    pub address: SerializableIpAddress,
+    pub port: u16,
+    /// Only used for IPv6 addresses
+    pub flow_info: u32,
+    /// Only used for IPv6 addresses
+    pub scope_id: u32,
+}
+
+impl From<IpSocketAddress> for SerializableIpSocketAddress {
+    fn from(value: IpSocketAddress) -> Self {
+        match value {
+            IpSocketAddress::Ipv4(address) => SerializableIpSocketAddress {
+                address: IpAddress::Ipv4(address.address).into(),
+                port: address.port,
+                flow_info: 0,
+                scope_id: 0,
+            },
+            IpSocketAddress::Ipv6(address) => SerializableIpSocketAddress {
+                address: IpAddress::Ipv6(address.address).into(),
+                port: address.port,
+                flow_info: address.flow_info,
+                scope_id: address.scope_id,
+            },
+        }
+    }
+}
+
+impl From<SerializableIpSocketAddress> for IpSocketAddress {
+    fn from(value: SerializableIpSocketAddress) -> Self {
+        match IpAddress::from(value.address) {
+            IpAddress::Ipv4(address) => IpSocketAddress::Ipv4(Ipv4SocketAddress {
+                port: value.port,
+                address,
+            }),
+            IpAddress::Ipv6(address) => IpSocketAddress::Ipv6(Ipv6SocketAddress {
+                port: value.port,
+                flow_info: value.flow_info,
+                address,
+                scope_id: value.scope_id,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BinaryCodec, IntoValue, FromValue)]
+#[desert(evolution())]
+pub struct SerializableDatagram {
+    pub data: Vec<u8>,
+    pub remote_address: SerializableIpSocketAddress,
+}
+
+impl From<IncomingDatagram> for SerializableDatagram {
+    fn from(value: IncomingDatagram) -> Self {
+        SerializableDatagram {
+            data: value.data,
+            remote_address: value.remote_address.into(),
+        }
+    }
+}
+
+impl From<SerializableDatagram> for IncomingDatagram {
+    fn from(value: SerializableDatagram) -> Self {
+        IncomingDatagram {
+            data: value.data,
+            remote_address: value.remote_address.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, BinaryCodec, IntoValue, FromValue)]
 #[desert(evolution())]
 pub enum SerializableInvokeResult {
@@ -19,10 +19,10 @@ use super::{
     PluginInstallationDescription, ProcessOplogEntriesParameters, PublicAgentInvocation,
     PublicAgentInvocationResult, PublicAttribute, PublicAttributeValue, PublicDurableFunctionType,
     PublicExternalSpanData, PublicLocalSpanData, PublicOplogEntry, PublicOplogEntryWithIndex,
-    PublicRetryConfig, PublicSnapshotData, PublicSpanData, PublicUpdateDescription,
-    RawSnapshotData, SaveSnapshotResultParameters, SnapshotBasedUpdateParameters,
-    StringAttributeValue, WorkerError, WorkerResourceId, WriteRemoteBatchedParameters,
-    WriteRemoteTransactionParameters,
+    PublicRetryConfig, PublicSagaCompensation, PublicSnapshotData, PublicSpanData,
+    PublicUpdateDescription, RawSnapshotData, SaveSnapshotResultParameters,
+    SnapshotBasedUpdateParameters, StringAttributeValue, WorkerError, WorkerResourceId,
+    WriteRemoteBatchedParameters, WriteRemoteTransactionParameters,
 };
 use crate::base_model::OplogIndex;
 use crate::model::agent::DataValue;
@@ -30,15 +30,16 @@ use crate::model::component::PluginPriority;
 use crate::model::invocation_context::{SpanId, TraceId};
 use crate::model::oplog::public_oplog_entry::{
     ActivatePluginParams, AgentInvocationFinishedParams, AgentInvocationStartedParams,
-    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams,
+    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams, BeginSagaParams,
     CancelPendingInvocationParams, ChangePersistenceLevelParams, ChangeRetryPolicyParams,
     CommittedRemoteTransactionParams, CreateParams, CreateResourceParams, DeactivatePluginParams,
-    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, ErrorParams, ExitedParams,
-    FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams, InterruptedParams,
-    JumpParams, LogParams, NoOpParams, PendingAgentInvocationParams, PendingUpdateParams,
-    PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams, RestartParams,
-    RevertParams, RolledBackRemoteTransactionParams, SetSpanAttributeParams, SnapshotParams,
-    StartSpanParams, SuccessfulUpdateParams, SuspendParams,
+    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, EndSagaParams, ErrorParams,
+    ExitedParams, FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams,
+    InterruptedParams, JumpParams, LogParams, NoOpParams, PendingAgentInvocationParams,
+    PendingUpdateParams, PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams,
+    ReorderPendingInvocationParams, RestartParams, RevertParams, RolledBackRemoteTransactionParams,
+    SagaStepCompensatedParams, SagaStepParams, SetInvocationQueueLimitParams,
+    SetSpanAttributeParams, SnapshotParams, StartSpanParams, SuccessfulUpdateParams, SuspendParams,
 };
 use crate::model::oplog::PersistenceLevel;
 use crate::model::regions::OplogRegion;
@@ -98,6 +99,11 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerError> for WorkerError
             Error::InvalidRequest(inner) => Ok(Self::InvalidRequest(inner.details)),
             Error::UnknownError(inner) => Ok(Self::Unknown(inner.details)),
             Error::ExceededMemoryLimit(_) => Ok(Self::ExceededMemoryLimit),
+            Error::ExceededAgentMemoryLimit(_) => Ok(Self::ExceededAgentMemoryLimit),
+            Error::ExceededInvocationFuelLimit(_) => Ok(Self::ExceededInvocationFuelLimit),
+            Error::ExceededInvocationTimeLimit(_) => Ok(Self::ExceededInvocationTimeLimit),
+            Error::ExceededOplogSizeLimit(_) => Ok(Self::ExceededOplogSizeLimit),
+            Error::ExceededHttpRequestRateLimit(_) => Ok(Self::ExceededHttpRequestRateLimit),
         }
     }
 }
@@ -121,6 +127,21 @@ impl From<WorkerError> for golem_api_grpc::proto::golem::worker::WorkerError {
             WorkerError::AgentError(details) => {
                 Error::UnknownError(grpc_worker::UnknownError { details })
             }
+            WorkerError::ExceededAgentMemoryLimit => {
+                Error::ExceededAgentMemoryLimit(grpc_worker::ExceededAgentMemoryLimit {})
+            }
+            WorkerError::ExceededInvocationFuelLimit => {
+                Error::ExceededInvocationFuelLimit(grpc_worker::ExceededInvocationFuelLimit {})
+            }
+            WorkerError::ExceededInvocationTimeLimit => {
+                Error::ExceededInvocationTimeLimit(grpc_worker::ExceededInvocationTimeLimit {})
+            }
+            WorkerError::ExceededOplogSizeLimit => {
+                Error::ExceededOplogSizeLimit(grpc_worker::ExceededOplogSizeLimit {})
+            }
+            WorkerError::ExceededHttpRequestRateLimit => {
+                Error::ExceededHttpRequestRateLimit(grpc_worker::ExceededHttpRequestRateLimit {})
+            }
         };
         Self { error: Some(error) }
     }
@@ -596,6 +617,54 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                     data,
                 }))
             }
+            oplog_entry::Entry::ReorderPendingInvocation(reorder) => {
+                let priority = reorder.priority().into();
+                Ok(PublicOplogEntry::ReorderPendingInvocation(
+                    ReorderPendingInvocationParams {
+                        timestamp: reorder.timestamp.ok_or("Missing timestamp field")?.into(),
+                        idempotency_key: reorder
+                            .idempotency_key
+                            .ok_or("Missing idempotency_key field")?
+                            .into(),
+                        priority,
+                        position: reorder.position,
+                    },
+                ))
+            }
+            oplog_entry::Entry::SetInvocationQueueLimit(limit) => Ok(
+                PublicOplogEntry::SetInvocationQueueLimit(SetInvocationQueueLimitParams {
+                    timestamp: limit.timestamp.ok_or("Missing timestamp field")?.into(),
+                    limit: limit.limit,
+                }),
+            ),
+            oplog_entry::Entry::BeginSaga(begin) => {
+                Ok(PublicOplogEntry::BeginSaga(BeginSagaParams {
+                    timestamp: begin.timestamp.ok_or("Missing timestamp field")?.into(),
+                }))
+            }
+            oplog_entry::Entry::SagaStep(step) => Ok(PublicOplogEntry::SagaStep(SagaStepParams {
+                timestamp: step.timestamp.ok_or("Missing timestamp field")?.into(),
+                begin_index: OplogIndex::from_u64(step.begin_index),
+                compensation: step
+                    .compensation
+                    .ok_or("Missing compensation field")?
+                    .try_into()?,
+            })),
+            oplog_entry::Entry::SagaStepCompensated(compensated) => Ok(
+                PublicOplogEntry::SagaStepCompensated(SagaStepCompensatedParams {
+                    timestamp: compensated
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    begin_index: OplogIndex::from_u64(compensated.begin_index),
+                    step_index: OplogIndex::from_u64(compensated.step_index),
+                }),
+            ),
+            oplog_entry::Entry::EndSaga(end) => Ok(PublicOplogEntry::EndSaga(EndSagaParams {
+                timestamp: end.timestamp.ok_or("Missing timestamp field")?.into(),
+                begin_index: OplogIndex::from_u64(end.begin_index),
+                compensated: end.compensated,
+            })),
         }
     }
 }
@@ -1038,10 +1107,106 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::ReorderPendingInvocation(reorder) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::ReorderPendingInvocation(
+                        golem_api_grpc::proto::golem::worker::ReorderPendingInvocationParameters {
+                            timestamp: Some(reorder.timestamp.into()),
+                            idempotency_key: Some(reorder.idempotency_key.into()),
+                            priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(
+                                reorder.priority,
+                            ) as i32,
+                            position: reorder.position,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::SetInvocationQueueLimit(limit) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::SetInvocationQueueLimit(
+                        golem_api_grpc::proto::golem::worker::SetInvocationQueueLimitParameters {
+                            timestamp: Some(limit.timestamp.into()),
+                            limit: limit.limit,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::BeginSaga(begin) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::BeginSaga(
+                        golem_api_grpc::proto::golem::worker::TimestampParameter {
+                            timestamp: Some(begin.timestamp.into()),
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::SagaStep(step) => golem_api_grpc::proto::golem::worker::OplogEntry {
+                entry: Some(oplog_entry::Entry::SagaStep(
+                    golem_api_grpc::proto::golem::worker::SagaStepParameters {
+                        timestamp: Some(step.timestamp.into()),
+                        begin_index: step.begin_index.into(),
+                        compensation: Some(step.compensation.into()),
+                    },
+                )),
+            },
+            PublicOplogEntry::SagaStepCompensated(compensated) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::SagaStepCompensated(
+                        golem_api_grpc::proto::golem::worker::SagaStepCompensatedParameters {
+                            timestamp: Some(compensated.timestamp.into()),
+                            begin_index: compensated.begin_index.into(),
+                            step_index: compensated.step_index.into(),
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::EndSaga(end) => golem_api_grpc::proto::golem::worker::OplogEntry {
+                entry: Some(oplog_entry::Entry::EndSaga(
+                    golem_api_grpc::proto::golem::worker::EndSagaParameters {
+                        timestamp: Some(end.timestamp.into()),
+                        begin_index: end.begin_index.into(),
+                        compensated: end.compensated,
+                    },
+                )),
+            },
+        })
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::SagaCompensation> for PublicSagaCompensation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::SagaCompensation,
+    ) -> Result<Self, Self::Error> {
+        let typed = value.input.ok_or("Missing input field")?;
+        let schema = typed.schema.ok_or("Missing schema field")?.try_into()?;
+        let untyped = typed.value.ok_or("Missing value field")?.try_into()?;
+        Ok(Self {
+            target_worker_id: value
+                .target_worker_id
+                .ok_or("Missing target_worker_id field")?
+                .try_into()?,
+            method_name: value.method_name,
+            input: DataValue::try_from_untyped(untyped, schema)?,
         })
     }
 }
 
+impl From<PublicSagaCompensation> for golem_api_grpc::proto::golem::worker::SagaCompensation {
+    fn from(value: PublicSagaCompensation) -> Self {
+        let typed_data_value: super::TypedDataValue = value.input.into();
+        Self {
+            target_worker_id: Some(value.target_worker_id.into()),
+            method_name: value.method_name,
+            input: Some(golem_api_grpc::proto::golem::component::TypedDataValue {
+                value: Some(typed_data_value.value.into()),
+                schema: Some(typed_data_value.schema.into()),
+            }),
+        }
+    }
+}
+
 impl TryFrom<golem_api_grpc::proto::golem::worker::WrappedFunctionType>
     for PublicDurableFunctionType
 {
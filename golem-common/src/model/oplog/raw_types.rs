@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::base_model::OplogIndex;
+use crate::base_model::{IdempotencyKey, OplogIndex, WorkerId};
+use crate::model::agent::UntypedDataValue;
 use crate::model::component::ComponentRevision;
 use crate::model::invocation_context::{AttributeValue, InvocationContextSpan, SpanId};
 use crate::model::oplog::OplogPayload;
@@ -354,6 +355,12 @@ pub enum WorkerError {
     // The worker tried to grow its memory beyond the limits of the plan
     ExceededMemoryLimit,
     AgentError(String),
+    // The limits below are the resource limits declared for the worker's agent type
+    ExceededAgentMemoryLimit,
+    ExceededInvocationFuelLimit,
+    ExceededInvocationTimeLimit,
+    ExceededOplogSizeLimit,
+    ExceededHttpRequestRateLimit,
 }
 
 impl WorkerError {
@@ -365,6 +372,15 @@ impl WorkerError {
             Self::OutOfMemory => "Out of memory",
             Self::ExceededMemoryLimit => "Exceeded plan memory limit",
             Self::AgentError(message) => message,
+            Self::ExceededAgentMemoryLimit => "Exceeded agent type memory limit",
+            Self::ExceededInvocationFuelLimit => "Exceeded agent type fuel limit per invocation",
+            Self::ExceededInvocationTimeLimit => "Exceeded agent type invocation time limit",
+            Self::ExceededOplogSizeLimit => {
+                "Exceeded agent type oplog entries per invocation limit"
+            }
+            Self::ExceededHttpRequestRateLimit => {
+                "Exceeded agent type outgoing HTTP request rate limit"
+            }
         }
     }
 
@@ -379,6 +395,30 @@ impl WorkerError {
     }
 }
 
+/// An agent method call compensating a step of a saga, performed when the saga is aborted or
+/// its coordinator agent fails permanently
+#[derive(Clone, Debug, PartialEq, BinaryCodec, IntoValue, FromValue)]
+#[desert(evolution())]
+#[wit(name = "raw-saga-compensation", owner = "golem:api@1.5.0/oplog")]
+pub struct SagaCompensation {
+    pub target_worker_id: WorkerId,
+    pub method_name: String,
+    pub input: OplogPayload<UntypedDataValue>,
+}
+
+impl SagaCompensation {
+    /// The idempotency key of the call compensating the saga step recorded at `step_index`.
+    ///
+    /// It only depends on the coordinator agent and the step, so the compensation can be retried
+    /// both by the coordinator and by the executor without performing it more than once.
+    pub fn idempotency_key(coordinator: &WorkerId, step_index: OplogIndex) -> IdempotencyKey {
+        IdempotencyKey::derived(
+            &IdempotencyKey::new(format!("saga-{coordinator}")),
+            step_index,
+        )
+    }
+}
+
 impl golem_wasm::IntoValue for UpdateDescription {
     fn into_value(self) -> golem_wasm::Value {
         match self {
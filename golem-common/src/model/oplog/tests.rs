@@ -14,6 +14,7 @@
 
 use crate::model::agent::{ComponentModelElementValue, DataValue, ElementValue, ElementValues};
 use crate::model::invocation_context::{SpanId, TraceId};
+use crate::model::invocation_queue::InvocationPriority;
 use crate::model::oplog::public_oplog_entry::{
     ActivatePluginParams, AgentInvocationFinishedParams, AgentInvocationStartedParams,
     BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams,
@@ -22,8 +23,9 @@ use crate::model::oplog::public_oplog_entry::{
     DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, ErrorParams, ExitedParams,
     FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams, InterruptedParams,
     JumpParams, LogParams, NoOpParams, PendingAgentInvocationParams, PendingUpdateParams,
-    PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams, RestartParams,
-    RevertParams, RolledBackRemoteTransactionParams, SetSpanAttributeParams, SnapshotParams,
+    PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams,
+    ReorderPendingInvocationParams, RestartParams, RevertParams, RolledBackRemoteTransactionParams,
+    SagaStepParams, SetInvocationQueueLimitParams, SetSpanAttributeParams, SnapshotParams,
     StartSpanParams, SuccessfulUpdateParams, SuspendParams,
 };
 use crate::model::oplog::{
@@ -31,9 +33,9 @@ use crate::model::oplog::{
     AgentMethodInvocationParameters, JsonSnapshotData, LogLevel, PersistenceLevel,
     PluginInstallationDescription, PublicAgentInvocation, PublicAgentInvocationResult,
     PublicAttribute, PublicAttributeValue, PublicDurableFunctionType, PublicLocalSpanData,
-    PublicOplogEntry, PublicRetryConfig, PublicSnapshotData, PublicSpanData,
-    PublicUpdateDescription, RawSnapshotData, SnapshotBasedUpdateParameters, StringAttributeValue,
-    WorkerResourceId,
+    PublicOplogEntry, PublicRetryConfig, PublicSagaCompensation, PublicSnapshotData,
+    PublicSpanData, PublicUpdateDescription, RawSnapshotData, SnapshotBasedUpdateParameters,
+    StringAttributeValue, WorkerResourceId,
 };
 use crate::model::regions::OplogRegion;
 use crate::model::worker::ParsedWorkerCreationLocalAgentConfigEntry;
@@ -695,6 +697,58 @@ fn cancel_pending_invocation_serialization_poem_serde_equivalence() {
     assert_eq!(entry, deserialized);
 }
 
+#[test]
+fn reorder_pending_invocation_serialization_poem_serde_equivalence() {
+    let entry = PublicOplogEntry::ReorderPendingInvocation(ReorderPendingInvocationParams {
+        timestamp: Timestamp::now_utc().rounded(),
+        idempotency_key: IdempotencyKey::new("reorder-key".to_string()),
+        priority: InvocationPriority::High,
+        position: Some(2),
+    });
+    let serialized = entry.to_json_string();
+    let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(entry, deserialized);
+}
+
+#[test]
+fn set_invocation_queue_limit_serialization_poem_serde_equivalence() {
+    let entry = PublicOplogEntry::SetInvocationQueueLimit(SetInvocationQueueLimitParams {
+        timestamp: Timestamp::now_utc().rounded(),
+        limit: Some(100),
+    });
+    let serialized = entry.to_json_string();
+    let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(entry, deserialized);
+}
+
+#[test]
+fn saga_step_serialization_poem_serde_equivalence() {
+    let entry = PublicOplogEntry::SagaStep(SagaStepParams {
+        timestamp: Timestamp::now_utc().rounded(),
+        begin_index: OplogIndex::from_u64(10),
+        compensation: PublicSagaCompensation {
+            target_worker_id: WorkerId {
+                component_id: ComponentId(
+                    Uuid::parse_str("13A5C8D4-F05E-4E23-B982-F4D413E181CB").unwrap(),
+                ),
+                worker_name: "inventory(\"warehouse-1\")".to_string(),
+            },
+            method_name: "release".to_string(),
+            input: DataValue::Tuple(ElementValues {
+                elements: vec![ElementValue::ComponentModel(ComponentModelElementValue {
+                    value: ValueAndType {
+                        value: Value::U64(3),
+                        typ: u64(),
+                    },
+                })],
+            }),
+        },
+    });
+    let serialized = entry.to_json_string();
+    let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(entry, deserialized);
+}
+
 #[test]
 fn start_span_serialization_poem_serde_equivalence() {
     let entry = PublicOplogEntry::StartSpan(StartSpanParams {
@@ -27,6 +27,21 @@ impl PluginRegistrationDto {
     fn oplog_processor(&self) -> Option<&OplogProcessorPluginSpec> {
         match &self.spec {
             PluginSpecDto::OplogProcessor(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn component_transformer(&self) -> Option<&ComponentTransformerPluginSpec> {
+        match &self.spec {
+            PluginSpecDto::ComponentTransformer(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn library(&self) -> Option<&LibraryPluginSpec> {
+        match &self.spec {
+            PluginSpecDto::Library(inner) => Some(inner),
+            _ => None,
         }
     }
 
@@ -38,9 +53,19 @@ impl PluginRegistrationDto {
         self.oplog_processor().map(|inner| inner.component_revision)
     }
 
+    pub fn library_component_id(&self) -> Option<ComponentId> {
+        self.library().map(|inner| inner.component_id)
+    }
+
+    pub fn library_component_revision(&self) -> Option<ComponentRevision> {
+        self.library().map(|inner| inner.component_revision)
+    }
+
     pub fn typ_as_str(&self) -> &'static str {
         match &self.spec {
             PluginSpecDto::OplogProcessor(_) => "oplog processor",
+            PluginSpecDto::ComponentTransformer(_) => "component transformer",
+            PluginSpecDto::Library(_) => "library",
         }
     }
 }
@@ -13,13 +13,18 @@
 // limitations under the License.
 
 use super::{diff, WorkerConfigVarsFilter, WorkerResourceDescription};
-use crate::model::component::{ComponentFileContentHash, ComponentFilePath, InitialComponentFile};
+use crate::model::component::{
+    ComponentFileContentHash, ComponentFilePath, InitialComponentFile, PluginPriority,
+};
 use crate::model::oplog::{OplogIndex, WorkerResourceId};
 use crate::model::{
     ComponentFilePermissions, FilterComparator, IdempotencyKey, LogLevel, NumberOfShards, Pod,
     PromiseId, RoutingTable, RoutingTableEntry, ScanCursor, ShardId, StringFilterComparator,
-    Timestamp, WorkerCreatedAtFilter, WorkerEnvFilter, WorkerEvent, WorkerFilter, WorkerId,
-    WorkerNameFilter, WorkerNotFilter, WorkerRevisionFilter, WorkerStatus, WorkerStatusFilter,
+    Timestamp, WorkerActivePluginFilter, WorkerAgentParametersFilter, WorkerAgentTypeFilter,
+    WorkerCreatedAtFilter, WorkerEnvFilter, WorkerEvent, WorkerFilter, WorkerId,
+    WorkerLastErrorFilter, WorkerLastInvocationAtFilter, WorkerMemorySizeFilter, WorkerNameFilter,
+    WorkerNotFilter, WorkerPendingInvocationCountFilter, WorkerRetryCountFilter,
+    WorkerRevisionFilter, WorkerStatus, WorkerStatusFilter,
 };
 use applying::Apply;
 use golem_api_grpc::proto::golem;
@@ -236,6 +241,40 @@ impl TryFrom<golem::worker::WorkerFilter> for WorkerFilter {
                         filter.value,
                     ))
                 }
+                golem::worker::worker_filter::Filter::AgentType(filter) => Ok(
+                    WorkerFilter::new_agent_type(filter.comparator.try_into()?, filter.value),
+                ),
+                golem::worker::worker_filter::Filter::AgentParameters(filter) => Ok(
+                    WorkerFilter::new_agent_parameters(filter.comparator.try_into()?, filter.value),
+                ),
+                golem::worker::worker_filter::Filter::LastInvocationAt(filter) => {
+                    let value = filter
+                        .value
+                        .map(|t| t.into())
+                        .ok_or_else(|| "Missing value".to_string())?;
+                    Ok(WorkerFilter::new_last_invocation_at(
+                        filter.comparator.try_into()?,
+                        value,
+                    ))
+                }
+                golem::worker::worker_filter::Filter::PendingInvocationCount(filter) => {
+                    Ok(WorkerFilter::new_pending_invocation_count(
+                        filter.comparator.try_into()?,
+                        filter.value,
+                    ))
+                }
+                golem::worker::worker_filter::Filter::RetryCount(filter) => Ok(
+                    WorkerFilter::new_retry_count(filter.comparator.try_into()?, filter.value),
+                ),
+                golem::worker::worker_filter::Filter::LastError(filter) => Ok(
+                    WorkerFilter::new_last_error(filter.comparator.try_into()?, filter.value),
+                ),
+                golem::worker::worker_filter::Filter::MemorySize(filter) => Ok(
+                    WorkerFilter::new_memory_size(filter.comparator.try_into()?, filter.value),
+                ),
+                golem::worker::worker_filter::Filter::ActivePlugin(filter) => Ok(
+                    WorkerFilter::new_active_plugin(PluginPriority(filter.plugin_priority)),
+                ),
                 golem::worker::worker_filter::Filter::Not(filter) => {
                     let filter = *filter.filter.ok_or_else(|| "Missing filter".to_string())?;
                     Ok(WorkerFilter::new_not(filter.try_into()?))
@@ -319,6 +358,70 @@ impl From<WorkerFilter> for golem::worker::WorkerFilter {
                     },
                 )
             }
+            WorkerFilter::AgentType(WorkerAgentTypeFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::AgentType(
+                    golem::worker::WorkerAgentTypeFilter {
+                        comparator: comparator.into(),
+                        value,
+                    },
+                )
+            }
+            WorkerFilter::AgentParameters(WorkerAgentParametersFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::AgentParameters(
+                    golem::worker::WorkerAgentParametersFilter {
+                        comparator: comparator.into(),
+                        value,
+                    },
+                )
+            }
+            WorkerFilter::LastInvocationAt(WorkerLastInvocationAtFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::LastInvocationAt(
+                    golem::worker::WorkerLastInvocationAtFilter {
+                        value: Some(value.into()),
+                        comparator: comparator.into(),
+                    },
+                )
+            }
+            WorkerFilter::PendingInvocationCount(WorkerPendingInvocationCountFilter {
+                comparator,
+                value,
+            }) => golem::worker::worker_filter::Filter::PendingInvocationCount(
+                golem::worker::WorkerPendingInvocationCountFilter {
+                    comparator: comparator.into(),
+                    value,
+                },
+            ),
+            WorkerFilter::RetryCount(WorkerRetryCountFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::RetryCount(
+                    golem::worker::WorkerRetryCountFilter {
+                        comparator: comparator.into(),
+                        value,
+                    },
+                )
+            }
+            WorkerFilter::LastError(WorkerLastErrorFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::LastError(
+                    golem::worker::WorkerLastErrorFilter {
+                        comparator: comparator.into(),
+                        value,
+                    },
+                )
+            }
+            WorkerFilter::MemorySize(WorkerMemorySizeFilter { comparator, value }) => {
+                golem::worker::worker_filter::Filter::MemorySize(
+                    golem::worker::WorkerMemorySizeFilter {
+                        comparator: comparator.into(),
+                        value,
+                    },
+                )
+            }
+            WorkerFilter::ActivePlugin(WorkerActivePluginFilter { plugin_priority }) => {
+                golem::worker::worker_filter::Filter::ActivePlugin(
+                    golem::worker::WorkerActivePluginFilter {
+                        plugin_priority: plugin_priority.0,
+                    },
+                )
+            }
             WorkerFilter::Not(WorkerNotFilter { filter }) => {
                 let f: golem::worker::WorkerFilter = (*filter).into();
                 golem::worker::worker_filter::Filter::Not(Box::new(
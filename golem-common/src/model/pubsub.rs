@@ -0,0 +1,59 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::base_model::pubsub::*;
+
+/// The maximum length of a topic name
+pub const MAX_TOPIC_NAME_LENGTH: usize = 256;
+
+/// Checks if a topic name is valid. Topic names are non-empty and consist of ASCII letters,
+/// digits, and the `-`, `_`, `.` and `:` characters, so they can be used as URL path segments.
+pub fn validate_topic_name(topic: &str) -> Result<(), String> {
+    if topic.is_empty() {
+        Err("Topic name must not be empty".to_string())
+    } else if topic.len() > MAX_TOPIC_NAME_LENGTH {
+        Err(format!(
+            "Topic name must not be longer than {MAX_TOPIC_NAME_LENGTH} characters"
+        ))
+    } else if let Some(c) = topic
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '_' | '.' | ':'))
+    {
+        Err(format!("Invalid character in topic name: '{c}'"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_topic_name;
+    use test_r::test;
+
+    #[test]
+    fn valid_topic_names() {
+        assert!(validate_topic_name("orders").is_ok());
+        assert!(validate_topic_name("orders.created").is_ok());
+        assert!(validate_topic_name("shop.orders:v1_created-events").is_ok());
+    }
+
+    #[test]
+    fn invalid_topic_names() {
+        assert!(validate_topic_name("").is_err());
+        assert!(validate_topic_name("orders created").is_err());
+        assert!(validate_topic_name("orders*").is_err());
+        assert!(validate_topic_name("shop/orders").is_err());
+        assert!(validate_topic_name(&"a".repeat(257)).is_err());
+    }
+}
@@ -0,0 +1,500 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::base_model::recurring_invocation::*;
+
+use chrono::{DateTime, Datelike, TimeDelta, TimeZone, Timelike, Utc};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The shortest allowed interval of interval based recurring schedules
+pub const MIN_RECURRING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Cron expressions which do not match any point in time within this many years are treated
+/// as never firing again
+const CRON_SEARCH_YEARS: i32 = 5;
+
+impl RecurringSchedule {
+    pub fn cron(expression: impl Into<String>) -> Self {
+        Self::Cron(CronSchedule {
+            expression: expression.into(),
+        })
+    }
+
+    pub fn interval(interval: Duration) -> Self {
+        Self::Interval(IntervalSchedule {
+            interval_millis: interval.as_millis() as u64,
+        })
+    }
+
+    /// Parses a human readable interval, such as `30s`, `5m` or `1h 30m`
+    pub fn parse_interval(interval: &str) -> Result<Self, String> {
+        humantime_serde::re::humantime::parse_duration(interval)
+            .map(Self::interval)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            RecurringSchedule::Cron(cron) => CronExpression::from_str(&cron.expression).map(|_| ()),
+            RecurringSchedule::Interval(interval) => {
+                if Duration::from_millis(interval.interval_millis) < MIN_RECURRING_INTERVAL {
+                    Err(format!(
+                        "Interval must be at least {}",
+                        humantime_serde::re::humantime::format_duration(MIN_RECURRING_INTERVAL)
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Gets the first occurrence of the schedule strictly after `after`.
+    ///
+    /// Interval schedules repeat relative to `anchor`, which must be an earlier occurrence of the
+    /// schedule (or the time the schedule was created at). Returns `None` if the schedule never fires again.
+    pub fn next_occurrence(
+        &self,
+        anchor: DateTime<Utc>,
+        after: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, String> {
+        match self {
+            RecurringSchedule::Cron(cron) => {
+                Ok(CronExpression::from_str(&cron.expression)?.next_after(after))
+            }
+            RecurringSchedule::Interval(interval) => {
+                let interval_millis = interval.interval_millis as i64;
+                if interval_millis <= 0 {
+                    return Err("Interval must be positive".to_string());
+                }
+                if anchor > after {
+                    return Ok(Some(anchor));
+                }
+                let elapsed = (after - anchor).num_milliseconds();
+                let periods = elapsed / interval_millis + 1;
+                Ok(periods
+                    .checked_mul(interval_millis)
+                    .and_then(TimeDelta::try_milliseconds)
+                    .and_then(|delta| anchor.checked_add_signed(delta)))
+            }
+        }
+    }
+}
+
+impl Display for RecurringSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecurringSchedule::Cron(cron) => write!(f, "cron({})", cron.expression),
+            RecurringSchedule::Interval(interval) => write!(
+                f,
+                "every {}",
+                humantime_serde::re::humantime::format_duration(Duration::from_millis(
+                    interval.interval_millis
+                ))
+            ),
+        }
+    }
+}
+
+impl FromStr for MissedFirePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(MissedFirePolicy::Skip),
+            "run-once" => Ok(MissedFirePolicy::RunOnce),
+            "catch-up" => Ok(MissedFirePolicy::CatchUp),
+            other => Err(format!(
+                "Invalid missed fire policy: {other}, expected one of skip, run-once, catch-up"
+            )),
+        }
+    }
+}
+
+/// A parsed five-field cron expression, evaluated in UTC.
+///
+/// Every field accepts `*`, single values, ranges (`a-b`), steps (`*/n`, `a-b/n`, `a/n`) and comma
+/// separated lists of these. Months and days of the week can also be given by their three letter
+/// English names. Both 0 and 7 mean Sunday. As in standard cron, if both the day of month and the
+/// day of week fields are restricted, a day matches if any of them matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpression {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+}
+
+impl CronExpression {
+    const MONTH_NAMES: [&'static str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    const DAY_NAMES: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+    /// Gets the first matching minute strictly after the given time
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let last_year = time.year() + CRON_SEARCH_YEARS;
+        let mut candidate = Utc
+            .with_ymd_and_hms(
+                time.year(),
+                time.month(),
+                time.day(),
+                time.hour(),
+                time.minute(),
+                0,
+            )
+            .single()?
+            .checked_add_signed(TimeDelta::minutes(1))?;
+
+        while candidate.year() <= last_year {
+            if !Self::contains(self.months, candidate.month()) {
+                let (year, month) = if candidate.month() == 12 {
+                    (candidate.year() + 1, 1)
+                } else {
+                    (candidate.year(), candidate.month() + 1)
+                };
+                candidate = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+            } else if !self.matches_day(&candidate) {
+                let next_day = candidate.date_naive().succ_opt()?;
+                candidate = Utc.from_utc_datetime(&next_day.and_hms_opt(0, 0, 0)?);
+            } else if !Self::contains(self.hours, candidate.hour()) {
+                candidate = candidate.with_minute(0)? + TimeDelta::hours(1);
+            } else if !Self::contains(self.minutes, candidate.minute()) {
+                candidate += TimeDelta::minutes(1);
+            } else {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    fn matches_day(&self, time: &DateTime<Utc>) -> bool {
+        let day_of_month = Self::contains(self.days_of_month, time.day());
+        let day_of_week = Self::contains(self.days_of_week, time.weekday().num_days_from_sunday());
+        match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month || day_of_week,
+            _ => day_of_month && day_of_week,
+        }
+    }
+
+    fn contains(set: u64, value: u32) -> bool {
+        set & (1 << value) != 0
+    }
+
+    fn parse_field(
+        field: &str,
+        name: &str,
+        min: u32,
+        max: u32,
+        names: &[&str],
+    ) -> Result<u64, String> {
+        let parse_value = |value: &str| -> Result<u32, String> {
+            let lowercase = value.to_lowercase();
+            let parsed = match names.iter().position(|n| *n == lowercase) {
+                Some(index) => index as u32 + min,
+                None => value
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid {name} value in cron expression: {value}"))?,
+            };
+            if parsed < min || parsed > max {
+                Err(format!(
+                    "The {name} value {parsed} is out of range, must be between {min} and {max}"
+                ))
+            } else {
+                Ok(parsed)
+            }
+        };
+
+        let mut result = 0u64;
+        for item in field.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => {
+                    let step = step
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| format!("Invalid {name} step in cron expression: {step}"))?;
+                    (range, step)
+                }
+                None => (item, 1),
+            };
+            let (from, to) = if range == "*" {
+                (min, max)
+            } else if let Some((from, to)) = range.split_once('-') {
+                (parse_value(from)?, parse_value(to)?)
+            } else {
+                let value = parse_value(range)?;
+                if item.contains('/') {
+                    (value, max)
+                } else {
+                    (value, value)
+                }
+            };
+            if from > to {
+                return Err(format!("Invalid {name} range in cron expression: {range}"));
+            }
+            for value in (from..=to).step_by(step as usize) {
+                result |= 1 << value;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expression = match s.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return Err(format!(
+                "Cron expression must have five fields (minute, hour, day of month, month, day of week), got: {s}"
+            ));
+        }
+
+        let mut days_of_week = Self::parse_field(fields[4], "day of week", 0, 7, &Self::DAY_NAMES)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(Self {
+            minutes: Self::parse_field(fields[0], "minute", 0, 59, &[])?,
+            hours: Self::parse_field(fields[1], "hour", 0, 23, &[])?,
+            days_of_month: Self::parse_field(fields[2], "day of month", 1, 31, &[])?,
+            months: Self::parse_field(fields[3], "month", 1, 12, &Self::MONTH_NAMES)?,
+            days_of_week,
+            days_of_month_restricted: !fields[2].starts_with('*'),
+            days_of_week_restricted: !fields[4].starts_with('*'),
+        })
+    }
+}
+
+mod protobuf {
+    use super::{
+        CronSchedule, IntervalSchedule, MissedFirePolicy, RecurringInvocation, RecurringSchedule,
+    };
+    use golem_api_grpc::proto::golem::worker::recurring_schedule::Schedule;
+
+    impl From<RecurringSchedule> for golem_api_grpc::proto::golem::worker::RecurringSchedule {
+        fn from(value: RecurringSchedule) -> Self {
+            Self {
+                schedule: Some(match value {
+                    RecurringSchedule::Cron(cron) => Schedule::Cron(cron.expression),
+                    RecurringSchedule::Interval(interval) => {
+                        Schedule::IntervalMillis(interval.interval_millis)
+                    }
+                }),
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::worker::RecurringSchedule> for RecurringSchedule {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::worker::RecurringSchedule,
+        ) -> Result<Self, Self::Error> {
+            match value.schedule.ok_or("Missing schedule")? {
+                Schedule::Cron(expression) => Ok(Self::Cron(CronSchedule { expression })),
+                Schedule::IntervalMillis(interval_millis) => {
+                    Ok(Self::Interval(IntervalSchedule { interval_millis }))
+                }
+            }
+        }
+    }
+
+    impl From<MissedFirePolicy> for golem_api_grpc::proto::golem::worker::MissedFirePolicy {
+        fn from(value: MissedFirePolicy) -> Self {
+            match value {
+                MissedFirePolicy::Skip => Self::Skip,
+                MissedFirePolicy::RunOnce => Self::RunOnce,
+                MissedFirePolicy::CatchUp => Self::CatchUp,
+            }
+        }
+    }
+
+    impl From<golem_api_grpc::proto::golem::worker::MissedFirePolicy> for MissedFirePolicy {
+        fn from(value: golem_api_grpc::proto::golem::worker::MissedFirePolicy) -> Self {
+            match value {
+                golem_api_grpc::proto::golem::worker::MissedFirePolicy::Skip => Self::Skip,
+                golem_api_grpc::proto::golem::worker::MissedFirePolicy::RunOnce => Self::RunOnce,
+                golem_api_grpc::proto::golem::worker::MissedFirePolicy::CatchUp => Self::CatchUp,
+            }
+        }
+    }
+
+    impl From<RecurringInvocation> for golem_api_grpc::proto::golem::worker::RecurringInvocation {
+        fn from(value: RecurringInvocation) -> Self {
+            Self {
+                id: Some(value.id.into()),
+                worker_id: Some(value.worker_id.into()),
+                method_name: value.method_name,
+                schedule: Some(value.schedule.into()),
+                missed_fire_policy: golem_api_grpc::proto::golem::worker::MissedFirePolicy::from(
+                    value.missed_fire_policy,
+                ) as i32,
+                next_fire_at: Some(value.next_fire_at.into()),
+                created_at: Some(value.created_at.into()),
+            }
+        }
+    }
+
+    impl TryFrom<golem_api_grpc::proto::golem::worker::RecurringInvocation> for RecurringInvocation {
+        type Error = String;
+
+        fn try_from(
+            value: golem_api_grpc::proto::golem::worker::RecurringInvocation,
+        ) -> Result<Self, Self::Error> {
+            let missed_fire_policy = value.missed_fire_policy().into();
+            Ok(Self {
+                id: value.id.ok_or("Missing id")?.try_into()?,
+                worker_id: value.worker_id.ok_or("Missing worker_id")?.try_into()?,
+                method_name: value.method_name,
+                schedule: value.schedule.ok_or("Missing schedule")?.try_into()?,
+                missed_fire_policy,
+                next_fire_at: value.next_fire_at.ok_or("Missing next_fire_at")?.into(),
+                created_at: value.created_at.ok_or("Missing created_at")?.into(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CronExpression, RecurringSchedule};
+    use chrono::{DateTime, Utc};
+    use std::str::FromStr;
+    use std::time::Duration;
+    use test_r::test;
+
+    fn time(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    fn next(expression: &str, after: &str) -> Option<DateTime<Utc>> {
+        CronExpression::from_str(expression)
+            .unwrap()
+            .next_after(time(after))
+    }
+
+    #[test]
+    fn cron_every_minute() {
+        assert_eq!(
+            next("* * * * *", "2026-01-01T10:15:30Z"),
+            Some(time("2026-01-01T10:16:00Z"))
+        );
+        assert_eq!(
+            next("* * * * *", "2026-01-01T10:15:00Z"),
+            Some(time("2026-01-01T10:16:00Z"))
+        );
+    }
+
+    #[test]
+    fn cron_steps_ranges_and_lists() {
+        assert_eq!(
+            next("*/15 9-17 * * mon-fri", "2026-01-02T17:50:00Z"),
+            Some(time("2026-01-05T09:00:00Z"))
+        );
+        assert_eq!(
+            next("0,30 */6 * * *", "2026-01-01T06:10:00Z"),
+            Some(time("2026-01-01T06:30:00Z"))
+        );
+        assert_eq!(
+            next("5/20 * * * *", "2026-01-01T06:46:00Z"),
+            Some(time("2026-01-01T07:05:00Z"))
+        );
+    }
+
+    #[test]
+    fn cron_day_of_month_or_day_of_week() {
+        // The 13th of the month or any Friday
+        assert_eq!(
+            next("0 0 13 * 5", "2026-02-01T00:00:00Z"),
+            Some(time("2026-02-06T00:00:00Z"))
+        );
+        assert_eq!(
+            next("0 0 13 * 5", "2026-02-11T00:00:00Z"),
+            Some(time("2026-02-13T00:00:00Z"))
+        );
+        // Sunday can be given as 7
+        assert_eq!(
+            next("0 12 * * 7", "2026-01-01T00:00:00Z"),
+            Some(time("2026-01-04T12:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn cron_shorthands_and_month_names() {
+        assert_eq!(
+            next("@monthly", "2026-01-31T23:59:00Z"),
+            Some(time("2026-02-01T00:00:00Z"))
+        );
+        assert_eq!(
+            next("0 0 29 FEB *", "2026-03-01T00:00:00Z"),
+            Some(time("2028-02-29T00:00:00Z"))
+        );
+        assert_eq!(next("0 0 30 2 *", "2026-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn cron_invalid_expressions() {
+        assert!(CronExpression::from_str("* * * *").is_err());
+        assert!(CronExpression::from_str("60 * * * *").is_err());
+        assert!(CronExpression::from_str("* * 0 * *").is_err());
+        assert!(CronExpression::from_str("*/0 * * * *").is_err());
+        assert!(CronExpression::from_str("10-5 * * * *").is_err());
+        assert!(CronExpression::from_str("* * * foo *").is_err());
+    }
+
+    #[test]
+    fn interval_occurrences_are_relative_to_the_anchor() {
+        let schedule = RecurringSchedule::interval(Duration::from_secs(60));
+        let anchor = time("2026-01-01T10:00:30Z");
+
+        assert_eq!(
+            schedule
+                .next_occurrence(anchor, time("2026-01-01T09:00:00Z"))
+                .unwrap(),
+            Some(anchor)
+        );
+        assert_eq!(
+            schedule.next_occurrence(anchor, anchor).unwrap(),
+            Some(time("2026-01-01T10:01:30Z"))
+        );
+        assert_eq!(
+            schedule
+                .next_occurrence(anchor, time("2026-01-01T10:05:45Z"))
+                .unwrap(),
+            Some(time("2026-01-01T10:06:30Z"))
+        );
+        assert!(RecurringSchedule::interval(Duration::from_millis(10))
+            .validate()
+            .is_err());
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::model::component::{ComponentFilePath, ComponentRevision};
+use crate::model::component::{ComponentFilePath, ComponentRevision, PluginPriority};
 use crate::model::environment::EnvironmentId;
 use crate::model::oplog::OplogIndex;
 use crate::model::{
@@ -21,7 +21,7 @@ use crate::model::{
 };
 use desert_rust::BinaryCodec;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::str::FromStr;
 use std::vec;
 use test_r::test;
@@ -95,6 +95,111 @@ fn worker_filter_parse() {
             "abc".to_string(),
         )
     );
+
+    assert_eq!(
+        WorkerFilter::from_str("agent_type = shopping-cart").unwrap(),
+        WorkerFilter::new_agent_type(StringFilterComparator::Equal, "shopping-cart".to_string())
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("pendingInvocationCount > 100").unwrap(),
+        WorkerFilter::new_pending_invocation_count(FilterComparator::Greater, 100)
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("last_error like timeout").unwrap(),
+        WorkerFilter::new_last_error(StringFilterComparator::Like, "timeout".to_string())
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("active_plugin != 1").unwrap(),
+        WorkerFilter::new_active_plugin(PluginPriority(1)).not()
+    );
+
+    assert!(WorkerFilter::from_str("active_plugin > 1").is_err());
+
+    let before = Timestamp::now_utc();
+    let filter = WorkerFilter::from_str("last_invocation_at < now-30days").unwrap();
+    let WorkerFilter::LastInvocationAt(filter) = filter else {
+        panic!("Unexpected filter: {filter:?}");
+    };
+    assert_eq!(filter.comparator, FilterComparator::Less);
+    assert!(filter.value.to_millis() <= before.to_millis() - 30 * 24 * 60 * 60 * 1000 + 1000);
+    assert!(filter.value.to_millis() >= before.to_millis() - 30 * 24 * 60 * 60 * 1000 - 1000);
+}
+
+#[test]
+fn worker_filter_parse_agent_properties() {
+    assert_eq!(
+        WorkerFilter::from_str("status != Failed").unwrap(),
+        WorkerFilter::new_status(FilterComparator::NotEqual, WorkerStatus::Failed)
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("revision != 3").unwrap(),
+        WorkerFilter::new_revision(
+            FilterComparator::NotEqual,
+            ComponentRevision::new(3).unwrap()
+        )
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("agentType != counter").unwrap(),
+        WorkerFilter::new_agent_type(StringFilterComparator::NotEqual, "counter".to_string())
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("agent_parameters startswith user-").unwrap(),
+        WorkerFilter::new_agent_parameters(StringFilterComparator::StartsWith, "user-".to_string())
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("pending_invocation_count != 0").unwrap(),
+        WorkerFilter::new_pending_invocation_count(FilterComparator::NotEqual, 0)
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("retry_count >= 3").unwrap(),
+        WorkerFilter::new_retry_count(FilterComparator::GreaterEqual, 3)
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("lastError != timeout").unwrap(),
+        WorkerFilter::new_last_error(StringFilterComparator::NotEqual, "timeout".to_string())
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("memory_size > 104857600").unwrap(),
+        WorkerFilter::new_memory_size(FilterComparator::Greater, 104857600)
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("active_plugin == 2").unwrap(),
+        WorkerFilter::new_active_plugin(PluginPriority(2))
+    );
+
+    assert_eq!(
+        WorkerFilter::from_str("last_invocation_at >= 2024-04-01T12:10:00Z").unwrap(),
+        WorkerFilter::new_last_invocation_at(
+            FilterComparator::GreaterEqual,
+            "2024-04-01T12:10:00Z".parse().unwrap()
+        )
+    );
+
+    let before = Timestamp::now_utc();
+    let filter = WorkerFilter::from_str("created_at > now-1h").unwrap();
+    let WorkerFilter::CreatedAt(filter) = filter else {
+        panic!("Unexpected filter: {filter:?}");
+    };
+    assert_eq!(filter.comparator, FilterComparator::Greater);
+    assert!(filter.value.to_millis() <= before.to_millis() - 60 * 60 * 1000 + 1000);
+    assert!(filter.value.to_millis() >= before.to_millis() - 60 * 60 * 1000 - 1000);
+
+    assert!(WorkerFilter::from_str("last_invocation_at < now-forever").is_err());
+    assert!(WorkerFilter::from_str("retry_count > many").is_err());
+    assert!(WorkerFilter::from_str("retry_count like 3").is_err());
+    assert!(WorkerFilter::from_str("version < 7").is_err());
+    assert!(WorkerFilter::from_str("config_vars.var1 = value").is_err());
 }
 
 #[test]
@@ -305,6 +410,91 @@ fn worker_filter_matches() {
     .matches(&worker_metadata));
 }
 
+#[test]
+fn worker_filter_matches_agent_properties() {
+    let created_at = Timestamp::from(1_700_000_000_000);
+    let last_invocation_at = Timestamp::from(1_700_000_100_000);
+    let worker_metadata = WorkerMetadata {
+        worker_id: WorkerId {
+            worker_name: "shopping-cart(\"user-1\")".to_string(),
+            component_id: ComponentId::new(),
+        },
+        env: Vec::new(),
+        environment_id: EnvironmentId::new(),
+        created_by: AccountId(uuid!("f935056f-e2f0-4183-a40f-d8ef3011f0bc")),
+        config_vars: BTreeMap::new(),
+        local_agent_config: Vec::new(),
+        created_at,
+        parent: None,
+        last_known_status: WorkerStatusRecord {
+            component_revision: ComponentRevision::new(1).unwrap(),
+            total_linear_memory_size: 2048,
+            active_plugins: HashSet::from([PluginPriority(1)]),
+            current_retry_count: HashMap::from([(OplogIndex::from_u64(5), 3)]),
+            last_invocation_at: Some(last_invocation_at),
+            last_error: Some("connection timeout".to_string()),
+            ..WorkerStatusRecord::default()
+        },
+        original_phantom_id: None,
+    };
+
+    assert!(WorkerFilter::new_agent_type(
+        StringFilterComparator::Equal,
+        "shopping-cart".to_string()
+    )
+    .matches(&worker_metadata));
+    assert!(
+        !WorkerFilter::new_agent_type(StringFilterComparator::Equal, "counter".to_string())
+            .matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_agent_parameters(StringFilterComparator::Like, "user-1".to_string())
+            .matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_last_invocation_at(FilterComparator::Greater, created_at)
+            .matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_pending_invocation_count(FilterComparator::Equal, 0)
+            .matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_retry_count(FilterComparator::GreaterEqual, 3).matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_last_error(StringFilterComparator::Like, "timeout".to_string())
+            .matches(&worker_metadata)
+    );
+    assert!(
+        WorkerFilter::new_memory_size(FilterComparator::Greater, 1024).matches(&worker_metadata)
+    );
+    assert!(WorkerFilter::new_active_plugin(PluginPriority(1)).matches(&worker_metadata));
+    assert!(!WorkerFilter::new_active_plugin(PluginPriority(2)).matches(&worker_metadata));
+
+    let never_invoked = WorkerMetadata {
+        worker_id: WorkerId {
+            worker_name: "not an agent id".to_string(),
+            component_id: ComponentId::new(),
+        },
+        last_known_status: WorkerStatusRecord::default(),
+        ..worker_metadata
+    };
+
+    assert!(
+        WorkerFilter::new_last_invocation_at(FilterComparator::Equal, created_at)
+            .matches(&never_invoked)
+    );
+    assert!(
+        !WorkerFilter::new_last_error(StringFilterComparator::NotEqual, "timeout".to_string())
+            .matches(&never_invoked)
+    );
+    assert!(
+        !WorkerFilter::new_agent_type(StringFilterComparator::NotEqual, "counter".to_string())
+            .matches(&never_invoked)
+    );
+}
+
 #[test]
 fn derived_idempotency_key() {
     let base1 = IdempotencyKey::fresh();
@@ -123,6 +123,7 @@ mod protobuf {
                     .into_iter()
                     .map(OplogRegion::from)
                     .collect::<Vec<_>>(),
+                discarded_region: value.discarded_region.map(OplogRegion::from),
             })
         }
     }
@@ -166,6 +167,7 @@ mod protobuf {
                     .into_iter()
                     .map(|region| region.into())
                     .collect(),
+                discarded_region: value.discarded_region.map(|region| region.into()),
             }
         }
     }
@@ -21,7 +21,7 @@ use desert_rust::{BinaryDeserializer, BinarySerializer};
 use fred::clients::Transaction;
 use fred::cmd;
 use fred::prelude::{Pool as FredRedisPool, *};
-use fred::types::{InfoKind, Limit, Map, MultipleKeys, MultipleValues};
+use fred::types::{InfoKind, Limit, Map, MultipleKeys, MultipleStrings, MultipleValues};
 use tracing::{debug, Level};
 
 use crate::metrics::redis::{record_redis_failure, record_redis_success};
@@ -531,6 +531,21 @@ impl RedisLabelledApi<'_> {
         Ok(())
     }
 
+    pub async fn xdel<R, K, S>(&self, key: K, ids: S) -> RedisResult<R>
+    where
+        R: FromValue,
+        K: AsRef<str>,
+        S: Into<MultipleStrings> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "XDEL",
+            self.pool.xdel(self.prefixed_key(key), ids).await,
+        )
+    }
+
     pub async fn xlen<R, K>(&self, key: K) -> RedisResult<R>
     where
         R: FromValue,
@@ -797,6 +812,25 @@ impl RedisLabelledApi<'_> {
         )
     }
 
+    pub async fn eval<R>(&self, script: &str, keys: Vec<String>, args: Vec<Value>) -> RedisResult<R>
+    where
+        R: FromValue,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+
+        //https://redis.io/commands/eval/
+        let mut all_args: Vec<Value> = vec![script.into(), (keys.len() as i64).into()];
+        all_args.extend(keys.into_iter().map(|key| self.prefixed_key(key).into()));
+        all_args.extend(args);
+
+        self.record(
+            start,
+            "EVAL",
+            self.pool.next().custom(cmd!("EVAL"), all_args).await,
+        )
+    }
+
     pub async fn keys<K>(&self, pattern: K) -> RedisResult<Vec<String>>
     where
         K: AsRef<str>,
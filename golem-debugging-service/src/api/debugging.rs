@@ -16,9 +16,11 @@ use super::errors::DebuggingApiError;
 use crate::jrpc::run_jrpc_debug_websocket_session;
 use crate::services::auth::AuthService;
 use crate::services::debug_service::DebugService;
+use golem_common::model::worker::{AgentUpdateCheckRequest, AgentUpdateCheckResult};
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::WrappedGolemSecuritySchema;
 use poem::web::websocket::{BoxWebSocketUpgraded, WebSocket};
+use poem_openapi::payload::Json;
 use poem_openapi::*;
 use std::sync::Arc;
 
@@ -58,4 +60,32 @@ impl DebuggingApi {
 
         Ok(upgraded)
     }
+
+    /// Check whether an agent can be automatically updated to a component revision
+    ///
+    /// Replays the oplog of the agent against the target revision without affecting the agent,
+    /// and reports whether the replay diverges together with the oplog index of the divergence.
+    /// Used by the worker service to serve update checks of multiple agents.
+    #[oai(
+        path = "/update-check",
+        method = "post",
+        operation_id = "debugger_check_agent_update"
+    )]
+    pub async fn check_agent_update(
+        &self,
+        request: Json<AgentUpdateCheckRequest>,
+        token: WrappedGolemSecuritySchema,
+    ) -> Result<Json<AgentUpdateCheckResult>, DebuggingApiError> {
+        let auth_ctx = self
+            .auth_service
+            .authenticate_token(token.0.secret())
+            .await?;
+
+        let response = self
+            .debug_service
+            .check_agent_update(&auth_ctx, request.0)
+            .await?;
+
+        Ok(Json(response))
+    }
 }
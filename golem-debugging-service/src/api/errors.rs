@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::services::auth::AuthServiceError;
+use crate::services::debug_service::DebugServiceError;
 use golem_common::model::error::{ErrorBody, ErrorsBody};
 use golem_common::SafeDisplay;
 use poem_openapi::payload::Json;
@@ -53,3 +54,26 @@ impl From<AuthServiceError> for DebuggingApiError {
         }
     }
 }
+
+impl From<DebugServiceError> for DebuggingApiError {
+    fn from(value: DebugServiceError) -> Self {
+        let error = value.to_safe_string();
+        match value {
+            DebugServiceError::Internal { .. } => {
+                Self::InternalError(Json(ErrorBody { error, cause: None }))
+            }
+            DebugServiceError::Unauthorized { .. } => {
+                Self::Forbidden(Json(ErrorBody { error, cause: None }))
+            }
+            DebugServiceError::Conflict { .. } => {
+                Self::AlreadyExists(Json(ErrorBody { error, cause: None }))
+            }
+            DebugServiceError::ValidationFailed { errors, .. } => {
+                Self::BadRequest(Json(ErrorsBody {
+                    errors,
+                    cause: None,
+                }))
+            }
+        }
+    }
+}
@@ -18,13 +18,15 @@ use golem_common::tracing::TracingConfig;
 use golem_common::SafeDisplay;
 use golem_service_base::clients::registry::GrpcRegistryServiceConfig;
 use golem_service_base::config::BlobStorageConfig;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use golem_service_base::service::compiled_component::CompiledComponentServiceConfig;
 use golem_worker_executor::services::golem_config::{
-    ActiveWorkersConfig, AgentDeploymentsServiceConfig, AgentTypesServiceConfig,
-    AgentWebhooksServiceConfig, ComponentCacheConfig, EngineConfig, GolemConfig, GrpcApiConfig,
-    IndexedStorageConfig, KeyValueStorageConfig, Limits, MemoryConfig, OplogConfig, RdbmsConfig,
-    ResourceLimitsConfig, SchedulerConfig, ShardManagerServiceConfig,
-    ShardManagerServiceSingleShardConfig, SuspendConfig, WorkerServiceGrpcConfig,
+    ActiveWorkersConfig, AgentDeploymentsServiceConfig, AgentLifecycleEventsConfig,
+    AgentTypesServiceConfig, AgentWebhooksServiceConfig, ComponentCacheConfig, EngineConfig,
+    GolemConfig, GrpcApiConfig, IndexedStorageConfig, KeyValueStorageConfig, Limits, MemoryConfig,
+    NetworkEgressPolicyServiceConfig, OplogConfig, PubSubConfig, RdbmsConfig, ResourceLimitsConfig,
+    SchedulerConfig, ShardManagerServiceConfig, ShardManagerServiceSingleShardConfig,
+    SocketsConfig, SuspendConfig, UsageMeteringConfig, WorkerServiceGrpcConfig,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
@@ -43,8 +45,10 @@ pub struct DebugConfig {
     pub compiled_component_service: CompiledComponentServiceConfig,
     pub oplog: OplogConfig,
     pub suspend: SuspendConfig,
+    pub sockets: SocketsConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
+    pub pubsub: PubSubConfig,
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub memory: MemoryConfig,
     pub rdbms: RdbmsConfig,
@@ -54,6 +58,7 @@ pub struct DebugConfig {
     pub agent_types_service: AgentTypesServiceConfig,
     pub agent_deployments_service: AgentDeploymentsServiceConfig,
     pub agent_webhooks_service: AgentWebhooksServiceConfig,
+    pub network_egress_policy_service: NetworkEgressPolicyServiceConfig,
     pub registry_service: GrpcRegistryServiceConfig,
     pub engine: EngineConfig,
     pub resource_limits: ResourceLimitsConfig,
@@ -73,8 +78,10 @@ impl DebugConfig {
             compiled_component_service: self.compiled_component_service,
             oplog: self.oplog,
             suspend: self.suspend,
+            sockets: self.sockets,
             active_workers: self.active_workers,
             scheduler: self.scheduler,
+            pubsub: self.pubsub,
             public_worker_api: self.public_worker_api,
             memory: self.memory,
             rdbms: self.rdbms,
@@ -83,8 +90,16 @@ impl DebugConfig {
             agent_types_service: self.agent_types_service,
             agent_deployments_service: self.agent_deployments_service,
             agent_webhooks_service: self.agent_webhooks_service,
+            network_egress_policy_service: self.network_egress_policy_service,
             engine: self.engine,
+            // replayed agents must not publish their lifecycle events again
+            agent_lifecycle_events: AgentLifecycleEventsConfig {
+                enabled: false,
+                ..Default::default()
+            },
             // unused
+            usage_metering: UsageMeteringConfig::default(),
+            fault_injection: FaultInjectionConfig::default(),
             grpc: GrpcApiConfig::default(),
             http_address: self.http_address,
             http_port: self.http_port,
@@ -123,8 +138,10 @@ impl Default for DebugConfig {
             compiled_component_service: default_golem_config.compiled_component_service,
             oplog: default_golem_config.oplog,
             suspend: default_golem_config.suspend,
+            sockets: default_golem_config.sockets,
             active_workers: default_golem_config.active_workers,
             scheduler: default_golem_config.scheduler,
+            pubsub: default_golem_config.pubsub,
             public_worker_api: default_golem_config.public_worker_api,
             memory: default_golem_config.memory,
             rdbms: default_golem_config.rdbms,
@@ -135,6 +152,7 @@ impl Default for DebugConfig {
             agent_types_service: AgentTypesServiceConfig::default(),
             agent_deployments_service: AgentDeploymentsServiceConfig::default(),
             agent_webhooks_service: AgentWebhooksServiceConfig::default(),
+            network_egress_policy_service: NetworkEgressPolicyServiceConfig::default(),
             engine: EngineConfig::default(),
             resource_limits: ResourceLimitsConfig::default(),
             cors_origin_regex: "https://*.golem.cloud".to_string(),
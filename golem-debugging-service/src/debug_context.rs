@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::additional_deps::AdditionalDeps;
+use crate::debug_session::{DebugSessionId, DebugSessions, UpdateCheckOutcome};
 use anyhow::Error;
 use async_trait::async_trait;
 use golem_common::base_model::OplogIndex;
@@ -28,7 +29,9 @@ use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, IdempotencyKey, OwnedWorkerId, Timestamp, WorkerId,
     WorkerStatusRecord,
 };
-use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
+use golem_service_base::error::worker_executor::{
+    GolemSpecificWasmTrap, InterruptKind, WorkerExecutorError,
+};
 use golem_service_base::model::component::Component;
 use golem_service_base::model::GetFileSystemNodeResult;
 use golem_wasm::wasmtime::{ResourceStore, ResourceTypeId};
@@ -40,8 +43,8 @@ use golem_worker_executor::model::{
     ExecutionStatus, LastError, ReadFileResult, TrapType, WorkerConfig,
 };
 use golem_worker_executor::preview2::golem::agent::host::{
-    CancellationToken, FutureInvokeResult, HostCancellationToken, HostFutureInvokeResult,
-    HostWasmRpc, RpcError, WasmRpc,
+    CancellationToken, FutureInvokeResult, HostCancellationToken, HostFutureInvokeResult, HostSaga,
+    HostWasmRpc, MissedFirePolicy, RecurringInvocation, RecurringSchedule, RpcError, Saga, WasmRpc,
 };
 use golem_worker_executor::services::active_workers::ActiveWorkers;
 use golem_worker_executor::services::agent_types::AgentTypesService;
@@ -51,8 +54,10 @@ use golem_worker_executor::services::component::ComponentService;
 use golem_worker_executor::services::file_loader::FileLoader;
 use golem_worker_executor::services::golem_config::GolemConfig;
 use golem_worker_executor::services::key_value::KeyValueService;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
 use golem_worker_executor::services::oplog::{Oplog, OplogService};
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms::RdbmsService;
 use golem_worker_executor::services::resource_limits::ResourceLimits;
 use golem_worker_executor::services::rpc::Rpc;
@@ -62,7 +67,7 @@ use golem_worker_executor::services::worker::WorkerService;
 use golem_worker_executor::services::worker_event::WorkerEventService;
 use golem_worker_executor::services::worker_fork::WorkerForkService;
 use golem_worker_executor::services::worker_proxy::WorkerProxy;
-use golem_worker_executor::services::{worker_enumeration, HasAll};
+use golem_worker_executor::services::{worker_enumeration, HasAll, HasWorker};
 use golem_worker_executor::worker::{RetryDecision, Worker};
 use golem_worker_executor::workerctx::{
     ExternalOperations, FileSystemReading, FuelManagement, InvocationContextManagement,
@@ -80,6 +85,33 @@ use wasmtime_wasi_http::WasiHttpView;
 
 pub struct DebugContext {
     pub durable_ctx: DurableWorkerCtx<Self>,
+    debug_session: Arc<dyn DebugSessions>,
+}
+
+impl DebugContext {
+    /// Records the outcome of an update check session. Returns false if the current session
+    /// is a regular debugging session and the update should be handled as usual.
+    async fn record_update_check_outcome(&self, outcome: UpdateCheckOutcome) -> bool {
+        let debug_session_id = DebugSessionId::new(self.owned_worker_id().clone());
+        let is_update_check = self
+            .debug_session
+            .get(&debug_session_id)
+            .await
+            .is_some_and(|session| session.update_check.is_some());
+
+        if is_update_check {
+            self.debug_session
+                .set_update_check_outcome(&debug_session_id, outcome)
+                .await;
+            // The simulated update was never recorded in the oplog, so it has to be removed
+            // manually to avoid retrying it on the next recovery
+            self.get_public_state()
+                .worker()
+                .drop_simulated_pending_update()
+                .await;
+        }
+        is_update_check
+    }
 }
 
 impl DurableWorkerCtxView<DebugContext> for DebugContext {
@@ -101,6 +133,10 @@ impl FuelManagement for DebugContext {
     fn return_fuel(&mut self, _current_level: u64) -> u64 {
         0
     }
+
+    fn check_invocation_limits(&self, _current_level: u64) -> Option<GolemSpecificWasmTrap> {
+        None
+    }
 }
 
 #[async_trait]
@@ -229,6 +265,14 @@ impl InvocationHooks for DebugContext {
     async fn get_current_retry_point(&self) -> OplogIndex {
         self.durable_ctx.get_current_retry_point().await
     }
+
+    fn begin_read_only_query(&mut self) {
+        self.durable_ctx.begin_read_only_query()
+    }
+
+    fn end_read_only_query(&mut self) {
+        self.durable_ctx.end_read_only_query()
+    }
 }
 
 #[async_trait]
@@ -246,9 +290,23 @@ impl UpdateManagement for DebugContext {
         target_revision: ComponentRevision,
         details: Option<String>,
     ) {
-        self.durable_ctx
-            .on_worker_update_failed(target_revision, details)
+        let debug_session_id = DebugSessionId::new(self.owned_worker_id().clone());
+        let oplog_index = self
+            .debug_session
+            .get(&debug_session_id)
             .await
+            .map(|session| session.current_oplog_index)
+            .unwrap_or(OplogIndex::NONE);
+
+        let outcome = UpdateCheckOutcome::Diverged {
+            oplog_index,
+            details: details.clone(),
+        };
+        if !self.record_update_check_outcome(outcome).await {
+            self.durable_ctx
+                .on_worker_update_failed(target_revision, details)
+                .await
+        }
     }
 
     async fn on_worker_update_succeeded(
@@ -257,9 +315,14 @@ impl UpdateManagement for DebugContext {
         new_component_size: u64,
         new_active_plugins: HashSet<PluginPriority>,
     ) {
-        self.durable_ctx
-            .on_worker_update_succeeded(target_revision, new_component_size, new_active_plugins)
+        if !self
+            .record_update_check_outcome(UpdateCheckOutcome::Compatible)
             .await
+        {
+            self.durable_ctx
+                .on_worker_update_succeeded(target_revision, new_component_size, new_active_plugins)
+                .await
+        }
     }
 }
 
@@ -399,6 +462,36 @@ impl HostWasmRpc for DebugContext {
             .await
     }
 
+    async fn schedule_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<Result<RecurringInvocation, String>> {
+        self.durable_ctx
+            .schedule_recurring_invocation(self_, schedule, missed_fire_policy, method_name, input)
+            .await
+    }
+
+    async fn list_recurring_invocations(
+        &mut self,
+        self_: Resource<WasmRpc>,
+    ) -> anyhow::Result<Vec<RecurringInvocation>> {
+        self.durable_ctx.list_recurring_invocations(self_).await
+    }
+
+    async fn cancel_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        id: golem_wasm::Uuid,
+    ) -> anyhow::Result<bool> {
+        self.durable_ctx
+            .cancel_recurring_invocation(self_, id)
+            .await
+    }
+
     async fn drop(&mut self, rep: Resource<WasmRpc>) -> anyhow::Result<()> {
         HostWasmRpc::drop(&mut self.durable_ctx, rep).await
     }
@@ -438,6 +531,34 @@ impl HostCancellationToken for DebugContext {
     }
 }
 
+impl HostSaga for DebugContext {
+    async fn new(&mut self) -> anyhow::Result<Resource<Saga>> {
+        HostSaga::new(&mut self.durable_ctx).await
+    }
+
+    async fn record_step(
+        &mut self,
+        self_: Resource<Saga>,
+        target: Resource<WasmRpc>,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<()> {
+        HostSaga::record_step(&mut self.durable_ctx, self_, target, method_name, input).await
+    }
+
+    async fn complete(&mut self, self_: Resource<Saga>) -> anyhow::Result<()> {
+        HostSaga::complete(&mut self.durable_ctx, self_).await
+    }
+
+    async fn abort(&mut self, self_: Resource<Saga>) -> anyhow::Result<Result<(), RpcError>> {
+        HostSaga::abort(&mut self.durable_ctx, self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<Saga>) -> anyhow::Result<()> {
+        HostSaga::drop(&mut self.durable_ctx, rep).await
+    }
+}
+
 impl wasmtime_wasi::p2::bindings::cli::environment::Host for DebugContext {
     fn get_environment(
         &mut self,
@@ -528,10 +649,11 @@ impl WorkerCtx for DebugContext {
         oplog: Arc<dyn Oplog>,
         invocation_queue: Weak<Worker<Self>>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
-        _extra_deps: Self::ExtraDeps,
+        extra_deps: Self::ExtraDeps,
         config: Arc<GolemConfig>,
         worker_config: WorkerConfig,
         execution_status: Arc<RwLock<ExecutionStatus>>,
@@ -540,6 +662,7 @@ impl WorkerCtx for DebugContext {
         _resource_limits: Arc<dyn ResourceLimits>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shard_service: Arc<dyn ShardService>,
         pending_update: Option<TimestampedUpdateDescription>,
         original_phantom_id: Option<uuid::Uuid>,
@@ -558,6 +681,7 @@ impl WorkerCtx for DebugContext {
             oplog,
             invocation_queue,
             scheduler_service,
+            pubsub_service,
             rpc,
             worker_proxy,
             component_service,
@@ -568,6 +692,7 @@ impl WorkerCtx for DebugContext {
             worker_fork,
             agent_types_service,
             agent_webhooks_service,
+            network_egress_policy_service,
             shard_service,
             pending_update,
             original_phantom_id,
@@ -575,6 +700,7 @@ impl WorkerCtx for DebugContext {
         .await?;
         Ok(Self {
             durable_ctx: golem_ctx,
+            debug_session: extra_deps.debug_session(),
         })
     }
 
@@ -25,7 +25,7 @@ use golem_common::model::oplog::public_oplog_entry::{
     LogParams, PublicAgentInvocationResult, RawSnapshotData,
 };
 use golem_common::model::oplog::{
-    DurableFunctionType, OplogEntry, OplogIndex, OplogPayload, WorkerError,
+    DurableFunctionType, OplogEntry, OplogIndex, OplogPayload, SagaCompensation, WorkerError,
 };
 use golem_common::model::oplog::{PublicDurableFunctionType, PublicOplogEntry, PublicSnapshotData};
 use golem_common::model::{
@@ -62,6 +62,12 @@ pub trait DebugSessions: Send + Sync {
         debug_session_id: &DebugSessionId,
         oplog_index: OplogIndex,
     ) -> Option<DebugSessionData>;
+
+    async fn set_update_check_outcome(
+        &self,
+        debug_session_id: &DebugSessionId,
+        outcome: UpdateCheckOutcome,
+    ) -> Option<DebugSessionData>;
 }
 pub struct DebugSessionsDefault {
     pub session: Arc<Mutex<HashMap<DebugSessionId, DebugSessionData>>>,
@@ -130,6 +136,21 @@ impl DebugSessions for DebugSessionsDefault {
             None
         }
     }
+
+    async fn set_update_check_outcome(
+        &self,
+        debug_session_id: &DebugSessionId,
+        outcome: UpdateCheckOutcome,
+    ) -> Option<DebugSessionData> {
+        let mut session = self.session.lock().unwrap();
+        let session_data = session.get_mut(debug_session_id);
+        if let Some(session_data) = session_data {
+            session_data.update_check = Some(outcome);
+            Some(session_data.clone())
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -140,6 +161,19 @@ pub struct DebugSessionData {
     // The current status of the oplog index being replayed and possibly
     // index of newly added oplog entries as part of going live in between host functions
     pub current_oplog_index: OplogIndex,
+    // Set only for sessions checking whether a component update is compatible with the
+    // worker's existing oplog
+    pub update_check: Option<UpdateCheckOutcome>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateCheckOutcome {
+    Pending,
+    Compatible,
+    Diverged {
+        oplog_index: OplogIndex,
+        details: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -498,6 +532,46 @@ fn get_oplog_entry_from_public_oplog_entry(
                 mime_type: bytes.1,
             })
         }
+        PublicOplogEntry::ReorderPendingInvocation(reorder_params) => {
+            Ok(OplogEntry::ReorderPendingInvocation {
+                timestamp: reorder_params.timestamp,
+                idempotency_key: reorder_params.idempotency_key,
+                priority: reorder_params.priority,
+                position: reorder_params.position,
+            })
+        }
+        PublicOplogEntry::SetInvocationQueueLimit(limit_params) => {
+            Ok(OplogEntry::SetInvocationQueueLimit {
+                timestamp: limit_params.timestamp,
+                limit: limit_params.limit,
+            })
+        }
+        PublicOplogEntry::BeginSaga(timestamp_params) => Ok(OplogEntry::BeginSaga {
+            timestamp: timestamp_params.timestamp,
+        }),
+        PublicOplogEntry::SagaStep(step_params) => Ok(OplogEntry::SagaStep {
+            timestamp: step_params.timestamp,
+            begin_index: step_params.begin_index,
+            compensation: SagaCompensation {
+                target_worker_id: step_params.compensation.target_worker_id,
+                method_name: step_params.compensation.method_name,
+                input: OplogPayload::Inline(Box::new(UntypedDataValue::from(
+                    step_params.compensation.input,
+                ))),
+            },
+        }),
+        PublicOplogEntry::SagaStepCompensated(compensated_params) => {
+            Ok(OplogEntry::SagaStepCompensated {
+                timestamp: compensated_params.timestamp,
+                begin_index: compensated_params.begin_index,
+                step_index: compensated_params.step_index,
+            })
+        }
+        PublicOplogEntry::EndSaga(end_params) => Ok(OplogEntry::EndSaga {
+            timestamp: end_params.timestamp,
+            begin_index: end_params.begin_index,
+            compensated: end_params.compensated,
+        }),
     }
 }
 
@@ -35,6 +35,7 @@ use golem_service_base::storage::blob::BlobStorage;
 use golem_worker_executor::durable_host::DurableWorkerCtx;
 use golem_worker_executor::preview2::{golem_api_1_x, golem_durability};
 use golem_worker_executor::services::active_workers::ActiveWorkers;
+use golem_worker_executor::services::agent_lifecycle_events::AgentLifecycleEventService;
 use golem_worker_executor::services::agent_types::AgentTypesService;
 use golem_worker_executor::services::agent_webhooks::AgentWebhooksService;
 use golem_worker_executor::services::blob_store::BlobStoreService;
@@ -43,9 +44,11 @@ use golem_worker_executor::services::events::Events;
 use golem_worker_executor::services::file_loader::FileLoader;
 use golem_worker_executor::services::golem_config::GolemConfig;
 use golem_worker_executor::services::key_value::KeyValueService;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
 use golem_worker_executor::services::oplog::plugin::OplogProcessorPlugin;
 use golem_worker_executor::services::oplog::OplogService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::shard::ShardService;
@@ -133,12 +136,15 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator<DebugContext>>,
         oplog_service: Arc<dyn OplogService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
         worker_proxy: Arc<dyn WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         registry_service: Arc<dyn RegistryService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         leak_sentinel: Arc<()>,
@@ -157,6 +163,7 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
 
         let resource_limits = resource_limits::configured(
             &golem_config.resource_limits,
+            &golem_config.usage_metering,
             registry_service,
             shutdown_token.clone(),
         );
@@ -187,11 +194,14 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             // (the worker instance's oplog) but writes using the live oplog service)
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
             shutdown_token.clone(),
@@ -222,11 +232,14 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             rdbms_service.clone(),
             debug_oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             shutdown_token.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
@@ -256,12 +269,15 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             debug_oplog_service,
             rpc,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             additional_deps,
             leak_sentinel,
@@ -362,6 +378,10 @@ pub fn create_debug_wasmtime_linker(engine: &Engine) -> anyhow::Result<Linker<De
         &mut linker,
         get_durable_ctx,
     )?;
+    golem_api_1_x::pubsub::add_to_linker::<_, HasSelf<DurableWorkerCtx<DebugContext>>>(
+        &mut linker,
+        get_durable_ctx,
+    )?;
     golem_durability::durability::add_to_linker::<_, HasSelf<DurableWorkerCtx<DebugContext>>>(
         &mut linker,
         get_durable_ctx,
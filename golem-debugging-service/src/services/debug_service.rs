@@ -15,15 +15,17 @@
 use super::auth::{AuthService, AuthServiceError};
 use crate::debug_context::DebugContext;
 use crate::debug_session::PlaybackOverridesInternal;
-use crate::debug_session::{DebugSessionData, DebugSessionId, DebugSessions};
+use crate::debug_session::{DebugSessionData, DebugSessionId, DebugSessions, UpdateCheckOutcome};
 use crate::model::params::*;
 use async_trait::async_trait;
 use gethostname::gethostname;
 use golem_common::model::account::AccountId;
 use golem_common::model::agent::Principal;
+use golem_common::model::component::ComponentRevision;
 use golem_common::model::environment::EnvironmentId;
 use golem_common::model::invocation_context::InvocationContextStack;
-use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
+use golem_common::model::worker::{AgentUpdateCheckRequest, AgentUpdateCheckResult};
 use golem_common::model::{OwnedWorkerId, WorkerId, WorkerMetadata};
 use golem_common::SafeDisplay;
 use golem_service_base::error::worker_executor::InterruptKind;
@@ -32,8 +34,8 @@ use golem_worker_executor::services::component::ComponentService;
 use golem_worker_executor::services::oplog::Oplog;
 use golem_worker_executor::services::worker_event::WorkerEventReceiver;
 use golem_worker_executor::services::{
-    All, HasComponentService, HasConfig, HasExtraDeps, HasOplog, HasShardManagerService,
-    HasShardService, HasWorkerForkService, HasWorkerService,
+    All, HasActiveWorkers, HasComponentService, HasConfig, HasExtraDeps, HasOplog,
+    HasShardManagerService, HasShardService, HasWorkerForkService, HasWorkerService,
 };
 use golem_worker_executor::worker::Worker;
 use log::debug;
@@ -80,6 +82,14 @@ pub trait DebugService: Send + Sync {
     ) -> Result<OplogIndex, DebugServiceError>;
 
     async fn terminate_session(&self, worker_id: &OwnedWorkerId) -> Result<(), DebugServiceError>;
+
+    /// Replays the oplog of an agent against the target component revision, without affecting
+    /// the agent, and reports whether it would fail an automatic update.
+    async fn check_agent_update(
+        &self,
+        auth_ctx: &AuthCtx,
+        request: AgentUpdateCheckRequest,
+    ) -> Result<AgentUpdateCheckResult, DebugServiceError>;
 }
 
 #[derive(Clone, Debug)]
@@ -198,27 +208,98 @@ impl DebugServiceDefault {
                 )
             })?;
 
+        info!("Registering worker {worker_id}");
+        self.register_executor()
+            .await
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        let worker = Worker::get_or_create_suspended(
+            &self.all,
+            account_id,
+            &owned_worker_id,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+            &InvocationContextStack::fresh(),
+            Principal::anonymous(),
+        )
+        .await
+        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+
+        let metadata = worker.get_latest_worker_metadata().await;
+
+        let receiver = worker.event_service().receiver();
+
+        Ok((metadata, receiver))
+    }
+
+    async fn register_executor(&self) -> Result<(), String> {
         let host = gethostname().to_string_lossy().to_string();
 
         let port = self.all.config().grpc.port;
 
-        info!(
-            "Registering worker {} with host {} and port {}",
-            worker_id, host, port
-        );
+        debug!("Registering debugging executor with host {host} and port {port}");
 
         let shard_assignment = self
             .all
             .shard_manager_service()
             .register(host, port)
             .await
-            .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+            .map_err(|e| e.to_string())?;
 
         self.all.shard_service().register(
             shard_assignment.number_of_shards,
             &shard_assignment.shard_ids,
         );
 
+        Ok(())
+    }
+
+    // Replays a single worker's oplog against the target revision in a dedicated debug session
+    async fn check_worker_update(
+        &self,
+        account_id: AccountId,
+        worker_metadata: WorkerMetadata,
+        target_revision: ComponentRevision,
+    ) -> AgentUpdateCheckResult {
+        let owned_worker_id = worker_metadata.owned_worker_id();
+        let worker_id = owned_worker_id.worker_id.clone();
+        let current_revision = worker_metadata.last_known_status.component_revision;
+        let debug_session_id = DebugSessionId::new(owned_worker_id.clone());
+
+        let result = |compatible: bool,
+                      divergence_index: Option<OplogIndex>,
+                      details: Option<String>| AgentUpdateCheckResult {
+            worker_id: worker_id.clone(),
+            current_revision,
+            compatible,
+            divergence_index,
+            details,
+        };
+
+        if self.debug_session.get(&debug_session_id).await.is_some() {
+            return result(
+                false,
+                None,
+                Some("Agent is currently being debugged".to_string()),
+            );
+        }
+
+        self.debug_session
+            .insert(
+                debug_session_id.clone(),
+                DebugSessionData {
+                    worker_metadata: worker_metadata.clone(),
+                    target_oplog_index: None,
+                    playback_overrides: PlaybackOverridesInternal::empty(),
+                    current_oplog_index: OplogIndex::NONE,
+                    update_check: Some(UpdateCheckOutcome::Pending),
+                },
+            )
+            .await;
+
         let worker = Worker::get_or_create_suspended(
             &self.all,
             account_id,
@@ -231,14 +312,54 @@ impl DebugServiceDefault {
             &InvocationContextStack::fresh(),
             Principal::anonymous(),
         )
-        .await
-        .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+        .await;
 
-        let metadata = worker.get_latest_worker_metadata().await;
+        let replay_result = match worker {
+            Ok(worker) => {
+                worker
+                    .simulate_pending_update(UpdateDescription::Automatic { target_revision })
+                    .await;
 
-        let receiver = worker.event_service().receiver();
+                let started = Worker::start_if_needed(worker.clone()).await;
+                if started.is_ok() {
+                    // Fails if the replay diverged, the outcome is recorded in the session
+                    let _ = worker.await_ready_to_process_commands().await;
+                }
 
-        Ok((metadata, receiver))
+                worker.stop_if_idle().await;
+                self.all.active_workers().remove(&worker_id).await;
+
+                started.map(|_| ()).map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        let outcome = self
+            .debug_session
+            .remove(debug_session_id)
+            .await
+            .and_then(|session| session.update_check);
+
+        match (replay_result, outcome) {
+            (_, Some(UpdateCheckOutcome::Compatible)) => result(true, None, None),
+            (
+                _,
+                Some(UpdateCheckOutcome::Diverged {
+                    oplog_index,
+                    details,
+                }),
+            ) => result(false, Some(oplog_index), details),
+            (Err(error), _) => result(
+                false,
+                None,
+                Some(format!("Failed to replay the agent: {error}")),
+            ),
+            (Ok(()), _) => result(
+                false,
+                None,
+                Some("Replaying the agent did not complete".to_string()),
+            ),
+        }
     }
 
     pub async fn validate_playback_overrides(
@@ -356,6 +477,7 @@ impl DebugService for DebugServiceDefault {
                     target_oplog_index: None,
                     playback_overrides: PlaybackOverridesInternal::empty(),
                     current_oplog_index: OplogIndex::NONE,
+                    update_check: None,
                 },
             )
             .await;
@@ -677,6 +799,73 @@ impl DebugService for DebugServiceDefault {
 
         Ok(())
     }
+
+    async fn check_agent_update(
+        &self,
+        auth_ctx: &AuthCtx,
+        request: AgentUpdateCheckRequest,
+    ) -> Result<AgentUpdateCheckResult, DebugServiceError> {
+        let worker_id = request.worker_id;
+        let component = self
+            .component_service
+            .get_metadata(worker_id.component_id, None)
+            .await
+            .map_err(|e| DebugServiceError::internal(e.to_string(), Some(worker_id.clone())))?;
+
+        self.auth_service
+            .check_user_allowed_to_debug_in_environment(component.environment_id, auth_ctx)
+            .await
+            .map_err(|e| match e {
+                AuthServiceError::DebuggingNotAllowed => DebugServiceError::Unauthorized {
+                    message: e.to_safe_string(),
+                },
+                e => DebugServiceError::internal(e.to_string(), Some(worker_id.clone())),
+            })?;
+
+        // The target revision must exist, otherwise the replay would not be attempted at all
+        self.component_service
+            .get_metadata(worker_id.component_id, Some(request.target_revision))
+            .await
+            .map_err(|e| {
+                DebugServiceError::validation_failed(
+                    vec![format!(
+                        "Target revision {} is not available: {e}",
+                        request.target_revision
+                    )],
+                    Some(worker_id.clone()),
+                )
+            })?;
+
+        let owned_worker_id = OwnedWorkerId::new(component.environment_id, &worker_id);
+        let worker_metadata = self
+            .all
+            .worker_service()
+            .get(&owned_worker_id)
+            .await
+            .ok_or_else(|| {
+                DebugServiceError::conflict(
+                    worker_id.clone(),
+                    "Worker doesn't exist in live/real worker executor".to_string(),
+                )
+            })?;
+
+        self.register_executor()
+            .await
+            .map_err(|e| DebugServiceError::internal(e, Some(worker_id.clone())))?;
+
+        debug!(
+            "Checking update of worker {worker_id} to revision {}",
+            request.target_revision
+        );
+
+        Ok(self
+            .check_worker_update(
+                component.account_id,
+                worker_metadata,
+                request.target_revision,
+            )
+            .await)
+    }
 }
 
 #[cfg(test)]
@@ -13,6 +13,7 @@ use golem_service_base::service::compiled_component::DefaultCompiledComponentSer
 use golem_service_base::storage::blob::BlobStorage;
 use golem_worker_executor::services::active_workers::ActiveWorkers;
 use golem_worker_executor::services::agent_deployments::AgentDeploymentsService;
+use golem_worker_executor::services::agent_lifecycle_events::AgentLifecycleEventService;
 use golem_worker_executor::services::agent_types::AgentTypesService;
 use golem_worker_executor::services::agent_webhooks::AgentWebhooksService;
 use golem_worker_executor::services::blob_store::BlobStoreService;
@@ -20,12 +21,15 @@ use golem_worker_executor::services::component::ComponentService;
 use golem_worker_executor::services::events::Events;
 use golem_worker_executor::services::file_loader::FileLoader;
 use golem_worker_executor::services::golem_config::{
-    AgentDeploymentsServiceConfig, GolemConfig, ResourceLimitsConfig, ResourceLimitsDisabledConfig,
+    AgentDeploymentsServiceConfig, GolemConfig, NetworkEgressPolicyServiceConfig,
+    ResourceLimitsConfig, ResourceLimitsDisabledConfig, UsageMeteringConfig,
 };
 use golem_worker_executor::services::key_value::KeyValueService;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
 use golem_worker_executor::services::oplog::plugin::OplogProcessorPlugin;
 use golem_worker_executor::services::oplog::OplogService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms;
 use golem_worker_executor::services::resource_limits;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
@@ -43,6 +47,7 @@ use golem_worker_executor::services::All;
 use golem_worker_executor::{Bootstrap, RunDetails};
 use golem_worker_executor_test_utils::agent_deployments_service::DisabledAgentDeploymentsService;
 use golem_worker_executor_test_utils::component_service::ComponentServiceLocalFileSystem;
+use golem_worker_executor_test_utils::network_egress_policy_service::UnrestrictedNetworkEgressPolicyService;
 use golem_worker_executor_test_utils::TestWorkerExecutor;
 use prometheus::Registry;
 use std::sync::Arc;
@@ -83,6 +88,14 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
         Arc::new(DisabledAgentDeploymentsService)
     }
 
+    fn create_network_egress_policy_service(
+        &self,
+        _config: &NetworkEgressPolicyServiceConfig,
+        _registry_service: Arc<dyn RegistryService>,
+    ) -> Arc<dyn NetworkEgressPolicyService> {
+        Arc::new(UnrestrictedNetworkEgressPolicyService)
+    }
+
     fn create_component_service(
         &self,
         _golem_config: &GolemConfig,
@@ -130,12 +143,15 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
         worker_activator: Arc<dyn WorkerActivator<DebugContext>>,
         oplog_service: Arc<dyn OplogService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
         _worker_proxy: Arc<dyn WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         registry_service: Arc<dyn RegistryService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         leak_sentinel: Arc<()>,
@@ -165,6 +181,7 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
         let additional_deps = AdditionalDeps::new(auth_service, debug_sessions);
         let resource_limits = resource_limits::configured(
             &ResourceLimitsConfig::Disabled(ResourceLimitsDisabledConfig {}),
+            &UsageMeteringConfig::default(),
             registry_service.clone(),
             shutdown_token.clone(),
         );
@@ -192,11 +209,14 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             rdbms_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
             shutdown_token.clone(),
@@ -227,11 +247,14 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             rdbms_service.clone(),
             debug_oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             shutdown_token.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
@@ -261,12 +284,15 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             debug_oplog_service,
             rpc,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             additional_deps,
             leak_sentinel,
@@ -4,6 +4,7 @@ use axum_jrpc::{Id, JsonRpcAnswer, JsonRpcRequest, JsonRpcResponse};
 use futures::stream::{SplitSink, SplitStream};
 use futures::{SinkExt, StreamExt};
 use golem_common::model::auth::TokenSecret;
+use golem_common::model::worker::{AgentUpdateCheckRequest, AgentUpdateCheckResult};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -25,6 +26,8 @@ pub struct DebugWorkerExecutorClient {
     read_msg: DebugRead,
     read_messages: Vec<UntypedJrpcMessage>,
     join_set: Option<JoinSet<anyhow::Result<()>>>,
+    http_port: u16,
+    token: TokenSecret,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -146,9 +149,34 @@ impl DebugWorkerExecutorClient {
             read_msg: read,
             read_messages: Vec::new(),
             join_set: None,
+            http_port: port,
+            token,
         })
     }
 
+    pub async fn check_agent_update(
+        &self,
+        request: &AgentUpdateCheckRequest,
+    ) -> anyhow::Result<AgentUpdateCheckResult> {
+        let response = reqwest::Client::new()
+            .post(format!(
+                "http://127.0.0.1:{}/v1/debugger/update-check",
+                self.http_port
+            ))
+            .bearer_auth(self.token.secret())
+            .json(request)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("Update check failed with {status}: {body}"));
+        }
+
+        Ok(response.json().await?)
+    }
+
     pub async fn close(&mut self) -> anyhow::Result<()> {
         self.write_msg.send(Message::Close(None)).await?;
 
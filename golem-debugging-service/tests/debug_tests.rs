@@ -4,6 +4,7 @@ use golem_common::model::agent::AgentId;
 use golem_common::model::component::ComponentDto;
 use golem_common::model::oplog::public_oplog_entry::AgentInvocationFinishedParams;
 use golem_common::model::oplog::{OplogIndex, PublicOplogEntry, PublicOplogEntryWithIndex};
+use golem_common::model::worker::AgentUpdateCheckRequest;
 use golem_common::model::{Timestamp, WorkerId};
 use golem_common::{agent_id, data_value, phantom_agent_id};
 use golem_debugging_service::model::params::PlaybackOverride;
@@ -544,6 +545,109 @@ async fn test_playback_with_overrides(
     Ok(())
 }
 
+#[test]
+#[tracing::instrument]
+async fn test_update_check_of_non_diverging_history(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let regular_worker_executor = golem_worker_executor_test_utils::start(deps, &context).await?;
+    let debug_executor = start_debug_worker_executor(&regular_worker_executor).await?;
+
+    let component = regular_worker_executor
+        .component(
+            &context.default_environment_id,
+            "it_agent_update_v1_release",
+        )
+        .name("it:agent-update")
+        .unique()
+        .store()
+        .await?;
+
+    let agent_id = agent_id!("update-test");
+    let worker_id = regular_worker_executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    // f3 behaves the same way in both versions, so replaying it against v2 does not diverge
+    regular_worker_executor
+        .invoke_and_await_agent(&component, &agent_id, "f3", data_value!())
+        .await?;
+    regular_worker_executor
+        .invoke_and_await_agent(&component, &agent_id, "f3", data_value!())
+        .await?;
+
+    let updated_component = regular_worker_executor
+        .update_component(&component.id, "it_agent_update_v2_release")
+        .await?;
+
+    let oplog_before = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await?;
+
+    let result = debug_executor
+        .check_agent_update(&AgentUpdateCheckRequest {
+            worker_id: worker_id.clone(),
+            target_revision: updated_component.revision,
+        })
+        .await?;
+
+    let oplog_after = regular_worker_executor
+        .get_oplog(&worker_id, OplogIndex::INITIAL)
+        .await?;
+    let metadata = regular_worker_executor
+        .get_worker_metadata(&worker_id)
+        .await?;
+
+    assert_eq!(result.worker_id, worker_id);
+    assert_eq!(result.current_revision, ComponentRevision::INITIAL);
+    assert!(result.compatible, "{result:?}");
+    assert_eq!(result.divergence_index, None);
+    // The check must not touch the checked agent
+    assert_eq!(oplog_after.len(), oplog_before.len());
+    assert_eq!(metadata.component_revision, ComponentRevision::INITIAL);
+
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+async fn test_update_check_of_unknown_revision(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let regular_worker_executor = golem_worker_executor_test_utils::start(deps, &context).await?;
+    let debug_executor = start_debug_worker_executor(&regular_worker_executor).await?;
+
+    let component = regular_worker_executor
+        .component(&context.default_environment_id, "it_agent_counters_release")
+        .name("it:agent-counters")
+        .store()
+        .await?;
+
+    let repo_id = agent_id!("repository", "update-check-unknown-revision");
+    let worker_id = regular_worker_executor
+        .start_agent(&component.id, repo_id.clone())
+        .await?;
+
+    run_repo_add_two(&regular_worker_executor, &component, &repo_id).await?;
+
+    let result = debug_executor
+        .check_agent_update(&AgentUpdateCheckRequest {
+            worker_id,
+            target_revision: ComponentRevision::new(100)?,
+        })
+        .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 fn nth_invocation_boundary(oplogs: &[PublicOplogEntryWithIndex], n: usize) -> OplogIndex {
     let index = oplogs
         .iter()
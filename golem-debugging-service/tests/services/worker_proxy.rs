@@ -271,6 +271,7 @@ impl WorkerProxy for TestWorkerProxy {
         &self,
         _promise_id: PromiseId,
         _data: Vec<u8>,
+        _failed: bool,
         _caller_account_id: AccountId,
     ) -> Result<bool, WorkerProxyError> {
         unimplemented!()
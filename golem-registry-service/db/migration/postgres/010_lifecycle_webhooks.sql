@@ -0,0 +1,47 @@
+CREATE TABLE lifecycle_webhooks
+(
+    webhook_id     UUID      NOT NULL,
+    environment_id UUID      NOT NULL,
+    url            TEXT      NOT NULL,
+    event_types    JSONB     NOT NULL,
+    signing_secret TEXT      NOT NULL,
+
+    created_at     TIMESTAMP NOT NULL,
+    created_by     UUID      NOT NULL,
+    deleted_at     TIMESTAMP,
+    deleted_by     UUID,
+
+    CONSTRAINT lifecycle_webhooks_pk
+        PRIMARY KEY (webhook_id),
+    CONSTRAINT lifecycle_webhooks_environments_fk
+        FOREIGN KEY (environment_id) REFERENCES environments
+);
+
+CREATE INDEX lifecycle_webhooks_environment_id_idx
+    ON lifecycle_webhooks (environment_id)
+    WHERE deleted_at IS NULL;
+
+CREATE TABLE lifecycle_webhook_deliveries
+(
+    delivery_id      UUID      NOT NULL,
+    webhook_id       UUID      NOT NULL,
+    event            JSONB     NOT NULL,
+    status           INTEGER   NOT NULL,
+    attempts         INTEGER   NOT NULL,
+    next_attempt_at  TIMESTAMP NOT NULL,
+    last_status_code INTEGER,
+    last_error       TEXT,
+    created_at       TIMESTAMP NOT NULL,
+    last_attempt_at  TIMESTAMP,
+
+    CONSTRAINT lifecycle_webhook_deliveries_pk
+        PRIMARY KEY (delivery_id),
+    CONSTRAINT lifecycle_webhook_deliveries_lifecycle_webhooks_fk
+        FOREIGN KEY (webhook_id) REFERENCES lifecycle_webhooks
+);
+
+CREATE INDEX lifecycle_webhook_deliveries_pending_idx
+    ON lifecycle_webhook_deliveries (status, next_attempt_at);
+
+CREATE INDEX lifecycle_webhook_deliveries_webhook_id_idx
+    ON lifecycle_webhook_deliveries (webhook_id, created_at);
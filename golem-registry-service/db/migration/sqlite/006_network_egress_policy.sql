@@ -0,0 +1,2 @@
+ALTER TABLE environment_revisions
+    ADD network_egress_policy BYTEA;
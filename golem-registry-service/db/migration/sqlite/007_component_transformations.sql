@@ -0,0 +1,5 @@
+ALTER TABLE component_revisions
+    ADD original_object_store_key TEXT;
+
+ALTER TABLE component_revisions
+    ADD transformations JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1,2 @@
+ALTER TABLE component_revisions
+    ADD agent_resource_limits JSONB NOT NULL DEFAULT '[]';
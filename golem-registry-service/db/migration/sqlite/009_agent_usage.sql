@@ -0,0 +1,17 @@
+CREATE TABLE agent_usage
+(
+    environment_id         UUID      NOT NULL,
+    component_id           UUID      NOT NULL,
+    agent_type             TEXT      NOT NULL,
+    period_start           TIMESTAMP NOT NULL,
+    invocations            NUMERIC   NOT NULL,
+    fuel                   NUMERIC   NOT NULL,
+    invocation_time_millis NUMERIC   NOT NULL,
+    oplog_bytes            NUMERIC   NOT NULL,
+    outgoing_http_requests NUMERIC   NOT NULL,
+    updated_at             TIMESTAMP NOT NULL,
+    CONSTRAINT agent_usage_pk
+        PRIMARY KEY (environment_id, component_id, agent_type, period_start)
+);
+
+CREATE INDEX agent_usage_period_start_idx ON agent_usage (environment_id, period_start);
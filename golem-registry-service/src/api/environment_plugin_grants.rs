@@ -14,6 +14,7 @@
 
 use super::ApiResult;
 use crate::services::auth::AuthService;
+use crate::services::component::ComponentWriteService;
 use crate::services::environment_plugin_grant::EnvironmentPluginGrantService;
 use golem_common::model::Page;
 use golem_common::model::environment::EnvironmentId;
@@ -22,18 +23,20 @@ use golem_common::model::environment_plugin_grant::{
     EnvironmentPluginGrantWithDetails,
 };
 use golem_common::model::poem::NoContentResponse;
-use golem_common::recorded_http_api_request;
+use golem_common::{SafeDisplay, recorded_http_api_request};
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::AuthCtx;
 use golem_service_base::model::auth::GolemSecurityScheme;
 use poem_openapi::OpenApi;
 use poem_openapi::param::{Path, Query};
 use poem_openapi::payload::Json;
+use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::Instrument;
+use tracing::{Instrument, warn};
 
 pub struct EnvironmentPluginGrantsApi {
     environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+    component_write_service: Arc<ComponentWriteService>,
     auth_service: Arc<AuthService>,
 }
 
@@ -45,10 +48,12 @@ pub struct EnvironmentPluginGrantsApi {
 impl EnvironmentPluginGrantsApi {
     pub fn new(
         environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+        component_write_service: Arc<ComponentWriteService>,
         auth_service: Arc<AuthService>,
     ) -> Self {
         Self {
             environment_plugin_grant_service,
+            component_write_service,
             auth_service,
         }
     }
@@ -91,6 +96,22 @@ impl EnvironmentPluginGrantsApi {
             .environment_plugin_grant_service
             .create(environment_id, data, &auth)
             .await?;
+
+        // Installations refer to grants by id, so the new grant does not transform any component
+        // yet, but components left behind by an interrupted grant deletion or plugin
+        // unregistration get their transformations brought up to date.
+        if let Err(err) = self
+            .component_write_service
+            .reapply_transformations(environment_id, &HashSet::new(), &auth)
+            .await
+        {
+            warn!(
+                environment_id = %environment_id,
+                "Failed to re-apply component transformations: {}",
+                err.to_safe_string()
+            );
+        }
+
         Ok(Json(grant))
     }
 
@@ -213,6 +234,22 @@ impl EnvironmentPluginGrantsApi {
         environment_plugin_grant_id: EnvironmentPluginGrantId,
         auth: AuthCtx,
     ) -> ApiResult<NoContentResponse> {
+        let grant = self
+            .environment_plugin_grant_service
+            .get_by_id(environment_plugin_grant_id, false, &auth)
+            .await?;
+
+        // Components transformed by the plugin get a new revision without its transformation.
+        // This happens before deleting the grant, so after a failure, deleting the grant again
+        // completes the work.
+        self.component_write_service
+            .reapply_transformations(
+                grant.environment_id,
+                &HashSet::from([environment_plugin_grant_id]),
+                &auth,
+            )
+            .await?;
+
         self.environment_plugin_grant_service
             .delete(environment_plugin_grant_id, &auth)
             .await?;
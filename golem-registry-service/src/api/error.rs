@@ -23,6 +23,7 @@ use crate::services::environment::EnvironmentError;
 use crate::services::environment_plugin_grant::EnvironmentPluginGrantError;
 use crate::services::environment_share::EnvironmentShareError;
 use crate::services::http_api_deployment::HttpApiDeploymentError;
+use crate::services::lifecycle_webhook::LifecycleWebhookError;
 use crate::services::mcp_deployment::McpDeploymentError;
 use crate::services::oauth2::OAuth2Error;
 use crate::services::plan::PlanError;
@@ -225,6 +226,11 @@ impl From<EnvironmentError> for ApiError {
                 Self::Conflict(Json(ErrorBody { error, cause: None }))
             }
 
+            EnvironmentError::InvalidNetworkEgressPolicy(_) => Self::BadRequest(Json(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            })),
+
             EnvironmentError::Unauthorized(inner) => inner.into(),
 
             EnvironmentError::LimitExceeded(inner) => inner.into(),
@@ -263,10 +269,14 @@ impl From<ComponentError> for ApiError {
             | ComponentError::InvalidComponentName { .. }
             | ComponentError::InvalidOplogProcessorPlugin
             | ComponentError::InvalidPluginScope { .. }
+            | ComponentError::ComponentTransformationFailed { .. }
+            | ComponentError::LibraryCompositionFailed { .. }
             | ComponentError::MalformedComponentArchive { .. }
             | ComponentError::PluginInstallationNotFound { .. }
             | ComponentError::AgentConfigDuplicateValue { .. }
             | ComponentError::AgentConfigTypeMismatch { .. }
+            | ComponentError::AgentResourceLimitsForUnknownAgent { .. }
+            | ComponentError::AgentResourceLimitsDuplicated { .. }
             | ComponentError::EnvironmentPluginNotFound(_) => Self::BadRequest(Json(ErrorsBody {
                 errors: vec![error],
                 cause: None,
@@ -369,6 +379,13 @@ impl From<ReportsError> for ApiError {
     fn from(value: ReportsError) -> Self {
         let error: String = value.to_safe_string();
         match value {
+            ReportsError::ParentEnvironmentNotFound(_) => {
+                Self::NotFound(Json(ErrorBody { error, cause: None }))
+            }
+            ReportsError::InvalidRange(_) => Self::BadRequest(Json(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            })),
             ReportsError::Unauthorized(inner) => inner.into(),
             ReportsError::InternalError(_) => Self::InternalError(Json(ErrorBody {
                 error,
@@ -387,7 +404,10 @@ impl From<PluginRegistrationError> for ApiError {
                 Self::NotFound(Json(ErrorBody { error, cause: None }))
             }
 
-            PluginRegistrationError::OplogProcessorComponentDoesNotExist => {
+            PluginRegistrationError::OplogProcessorComponentDoesNotExist
+            | PluginRegistrationError::ComponentTransformerComponentDoesNotExist
+            | PluginRegistrationError::InvalidComponentTransformer(_)
+            | PluginRegistrationError::LibraryComponentDoesNotExist => {
                 Self::BadRequest(Json(ErrorsBody {
                     errors: vec![error],
                     cause: None,
@@ -541,6 +561,29 @@ impl From<DomainRegistrationError> for ApiError {
     }
 }
 
+impl From<LifecycleWebhookError> for ApiError {
+    fn from(value: LifecycleWebhookError) -> Self {
+        let error: String = value.to_safe_string();
+        match value {
+            LifecycleWebhookError::ParentEnvironmentNotFound(_)
+            | LifecycleWebhookError::LifecycleWebhookNotFound(_) => {
+                Self::NotFound(Json(ErrorBody { error, cause: None }))
+            }
+
+            LifecycleWebhookError::InvalidUrl(_) => Self::BadRequest(Json(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            })),
+
+            LifecycleWebhookError::Unauthorized(inner) => inner.into(),
+            LifecycleWebhookError::InternalError(_) => Self::InternalError(Json(ErrorBody {
+                error,
+                cause: Some(value.into_anyhow()),
+            })),
+        }
+    }
+}
+
 impl From<SecuritySchemeError> for ApiError {
     fn from(value: SecuritySchemeError) -> Self {
         let error: String = value.to_safe_string();
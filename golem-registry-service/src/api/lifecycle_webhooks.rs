@@ -0,0 +1,256 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ApiResult;
+use crate::services::auth::AuthService;
+use crate::services::lifecycle_webhook::LifecycleWebhookService;
+use golem_common::model::Page;
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::lifecycle_webhook::{
+    LifecycleWebhook, LifecycleWebhookCreation, LifecycleWebhookDelivery, LifecycleWebhookId,
+    LifecycleWebhookWithSecret,
+};
+use golem_common::model::poem::NoContentResponse;
+use golem_common::recorded_http_api_request;
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::model::auth::AuthCtx;
+use golem_service_base::model::auth::GolemSecurityScheme;
+use poem_openapi::OpenApi;
+use poem_openapi::param::Path;
+use poem_openapi::payload::Json;
+use std::sync::Arc;
+use tracing::Instrument;
+
+pub struct LifecycleWebhooksApi {
+    lifecycle_webhook_service: Arc<LifecycleWebhookService>,
+    auth_service: Arc<AuthService>,
+}
+
+#[OpenApi(
+    prefix_path = "/v1",
+    tag = ApiTags::RegistryService,
+    tag = ApiTags::LifecycleWebhook
+)]
+impl LifecycleWebhooksApi {
+    pub fn new(
+        lifecycle_webhook_service: Arc<LifecycleWebhookService>,
+        auth_service: Arc<AuthService>,
+    ) -> Self {
+        Self {
+            lifecycle_webhook_service,
+            auth_service,
+        }
+    }
+
+    /// Create a new lifecycle webhook in the environment
+    ///
+    /// The returned signing secret is used to sign the deliveries of the webhook
+    /// and cannot be retrieved later.
+    #[oai(
+        path = "/envs/:environment_id/lifecycle-webhooks",
+        method = "post",
+        operation_id = "create_lifecycle_webhook",
+        tag = ApiTags::Environment,
+    )]
+    pub async fn create_lifecycle_webhook(
+        &self,
+        environment_id: Path<EnvironmentId>,
+        payload: Json<LifecycleWebhookCreation>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<Json<LifecycleWebhookWithSecret>> {
+        let record = recorded_http_api_request!(
+            "create_lifecycle_webhook",
+            environment_id = environment_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .create_lifecycle_webhook_internal(environment_id.0, payload.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn create_lifecycle_webhook_internal(
+        &self,
+        environment_id: EnvironmentId,
+        payload: LifecycleWebhookCreation,
+        auth: AuthCtx,
+    ) -> ApiResult<Json<LifecycleWebhookWithSecret>> {
+        let webhook = self
+            .lifecycle_webhook_service
+            .create(environment_id, payload, &auth)
+            .await?;
+
+        Ok(Json(webhook))
+    }
+
+    /// List all lifecycle webhooks in the environment
+    #[oai(
+        path = "/envs/:environment_id/lifecycle-webhooks",
+        method = "get",
+        operation_id = "list_environment_lifecycle_webhooks",
+        tag = ApiTags::Environment,
+    )]
+    pub async fn list_environment_lifecycle_webhooks(
+        &self,
+        environment_id: Path<EnvironmentId>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<Json<Page<LifecycleWebhook>>> {
+        let record = recorded_http_api_request!(
+            "list_environment_lifecycle_webhooks",
+            environment_id = environment_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .list_environment_lifecycle_webhooks_internal(environment_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn list_environment_lifecycle_webhooks_internal(
+        &self,
+        environment_id: EnvironmentId,
+        auth: AuthCtx,
+    ) -> ApiResult<Json<Page<LifecycleWebhook>>> {
+        let webhooks = self
+            .lifecycle_webhook_service
+            .list_in_environment(environment_id, &auth)
+            .await?;
+
+        Ok(Json(Page { values: webhooks }))
+    }
+
+    /// Get lifecycle webhook by id
+    #[oai(
+        path = "/lifecycle-webhooks/:lifecycle_webhook_id",
+        method = "get",
+        operation_id = "get_lifecycle_webhook"
+    )]
+    pub async fn get_lifecycle_webhook(
+        &self,
+        lifecycle_webhook_id: Path<LifecycleWebhookId>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<Json<LifecycleWebhook>> {
+        let record = recorded_http_api_request!(
+            "get_lifecycle_webhook",
+            lifecycle_webhook_id = lifecycle_webhook_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .get_lifecycle_webhook_internal(lifecycle_webhook_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_lifecycle_webhook_internal(
+        &self,
+        lifecycle_webhook_id: LifecycleWebhookId,
+        auth: AuthCtx,
+    ) -> ApiResult<Json<LifecycleWebhook>> {
+        let webhook = self
+            .lifecycle_webhook_service
+            .get_by_id(lifecycle_webhook_id, &auth)
+            .await?;
+
+        Ok(Json(webhook))
+    }
+
+    /// Delete lifecycle webhook
+    #[oai(
+        path = "/lifecycle-webhooks/:lifecycle_webhook_id",
+        method = "delete",
+        operation_id = "delete_lifecycle_webhook"
+    )]
+    pub async fn delete_lifecycle_webhook(
+        &self,
+        lifecycle_webhook_id: Path<LifecycleWebhookId>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<NoContentResponse> {
+        let record = recorded_http_api_request!(
+            "delete_lifecycle_webhook",
+            lifecycle_webhook_id = lifecycle_webhook_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .delete_lifecycle_webhook_internal(lifecycle_webhook_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn delete_lifecycle_webhook_internal(
+        &self,
+        lifecycle_webhook_id: LifecycleWebhookId,
+        auth: AuthCtx,
+    ) -> ApiResult<NoContentResponse> {
+        self.lifecycle_webhook_service
+            .delete(lifecycle_webhook_id, &auth)
+            .await?;
+
+        Ok(NoContentResponse::NoContent)
+    }
+
+    /// Get the latest deliveries of a lifecycle webhook, newest first
+    #[oai(
+        path = "/lifecycle-webhooks/:lifecycle_webhook_id/deliveries",
+        method = "get",
+        operation_id = "list_lifecycle_webhook_deliveries"
+    )]
+    pub async fn list_lifecycle_webhook_deliveries(
+        &self,
+        lifecycle_webhook_id: Path<LifecycleWebhookId>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<Json<Page<LifecycleWebhookDelivery>>> {
+        let record = recorded_http_api_request!(
+            "list_lifecycle_webhook_deliveries",
+            lifecycle_webhook_id = lifecycle_webhook_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .list_lifecycle_webhook_deliveries_internal(lifecycle_webhook_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn list_lifecycle_webhook_deliveries_internal(
+        &self,
+        lifecycle_webhook_id: LifecycleWebhookId,
+        auth: AuthCtx,
+    ) -> ApiResult<Json<Page<LifecycleWebhookDelivery>>> {
+        let deliveries = self
+            .lifecycle_webhook_service
+            .list_deliveries(lifecycle_webhook_id, &auth)
+            .await?;
+
+        Ok(Json(Page { values: deliveries }))
+    }
+}
@@ -22,6 +22,7 @@ pub mod environment_shares;
 pub mod environments;
 pub mod error;
 pub mod http_api_deployments;
+pub mod lifecycle_webhooks;
 pub mod login;
 pub mod mcp_deployments;
 pub mod plugin_registrations;
@@ -39,6 +40,7 @@ use self::environment_shares::EnvironmentSharesApi;
 use self::environments::EnvironmentsApi;
 use self::error::ApiError;
 use self::http_api_deployments::HttpApiDeploymentsApi;
+use self::lifecycle_webhooks::LifecycleWebhooksApi;
 use self::login::LoginApi;
 use self::mcp_deployments::McpDeploymentsApi;
 use self::plugin_registrations::PluginRegistrationsApi;
@@ -61,6 +63,7 @@ pub type Apis = (
         EnvironmentSharesApi,
     ),
     HttpApiDeploymentsApi,
+    LifecycleWebhooksApi,
     McpDeploymentsApi,
     LoginApi,
     PluginRegistrationsApi,
@@ -101,6 +104,7 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
             (
                 EnvironmentPluginGrantsApi::new(
                     services.environment_plugin_grant_service.clone(),
+                    services.component_write_service.clone(),
                     services.auth_service.clone(),
                 ),
                 EnvironmentsApi::new(
@@ -118,6 +122,10 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
                 services.http_api_deployment_service.clone(),
                 services.auth_service.clone(),
             ),
+            LifecycleWebhooksApi::new(
+                services.lifecycle_webhook_service.clone(),
+                services.auth_service.clone(),
+            ),
             McpDeploymentsApi::new(
                 services.mcp_deployment_service.clone(),
                 services.auth_service.clone(),
@@ -128,6 +136,8 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
             ),
             PluginRegistrationsApi::new(
                 services.plugin_registration_service.clone(),
+                services.environment_plugin_grant_service.clone(),
+                services.component_write_service.clone(),
                 services.auth_service.clone(),
             ),
             ReportsApi::new(
@@ -14,20 +14,26 @@
 
 use super::ApiResult;
 use crate::services::auth::AuthService;
+use crate::services::component::ComponentWriteService;
+use crate::services::environment_plugin_grant::EnvironmentPluginGrantService;
 use crate::services::plugin_registration::PluginRegistrationService;
 use golem_common::model::plugin_registration::{PluginRegistrationDto, PluginRegistrationId};
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::AuthCtx;
 use golem_service_base::model::auth::GolemSecurityScheme;
+use itertools::Itertools;
 use poem_openapi::OpenApi;
 use poem_openapi::param::Path;
 use poem_openapi::payload::Json;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tracing::Instrument;
 
 pub struct PluginRegistrationsApi {
     plugin_registration_service: Arc<PluginRegistrationService>,
+    environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+    component_write_service: Arc<ComponentWriteService>,
     auth_service: Arc<AuthService>,
 }
 
@@ -39,10 +45,14 @@ pub struct PluginRegistrationsApi {
 impl PluginRegistrationsApi {
     pub fn new(
         plugin_registration_service: Arc<PluginRegistrationService>,
+        environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+        component_write_service: Arc<ComponentWriteService>,
         auth_service: Arc<AuthService>,
     ) -> Self {
         Self {
             plugin_registration_service,
+            environment_plugin_grant_service,
+            component_write_service,
             auth_service,
         }
     }
@@ -111,6 +121,31 @@ impl PluginRegistrationsApi {
         plugin_id: PluginRegistrationId,
         auth: AuthCtx,
     ) -> ApiResult<Json<PluginRegistrationDto>> {
+        self.plugin_registration_service
+            .get_plugin_for_unregistration(plugin_id, &auth)
+            .await?;
+
+        // The grants of the plugin become inactive, so the components transformed by the plugin get
+        // a new revision without its transformation. This happens before unregistering the plugin,
+        // so after a failure, deleting the plugin again completes the work.
+        let grants_by_environment = self
+            .environment_plugin_grant_service
+            .list_for_plugin(plugin_id)
+            .await?
+            .into_iter()
+            .into_group_map_by(|grant| grant.environment_id);
+
+        for (environment_id, grants) in grants_by_environment {
+            let excluded_grants = grants
+                .into_iter()
+                .map(|grant| grant.id)
+                .collect::<HashSet<_>>();
+            // the environments the plugin was granted to are not necessarily visible to the plugin owner
+            self.component_write_service
+                .reapply_transformations(environment_id, &excluded_grants, &AuthCtx::system())
+                .await?;
+        }
+
         let plugin_registration = self
             .plugin_registration_service
             .unregister_plugin(plugin_id, &auth)
@@ -15,12 +15,17 @@
 use crate::api::ApiResult;
 use crate::services::auth::AuthService;
 use crate::services::reports::ReportsService;
+use chrono::{DateTime, Utc};
 use golem_common::model::Page;
-use golem_common::model::reports::{AccountCountsReport, AccountSummaryReport};
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::reports::{
+    AccountCountsReport, AccountSummaryReport, EnvironmentUsageReport,
+};
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::AuthCtx;
 use golem_service_base::model::auth::GolemSecurityScheme;
+use poem_openapi::param::{Path, Query};
 use poem_openapi::payload::Json;
 use poem_openapi::*;
 use std::sync::Arc;
@@ -103,4 +108,49 @@ impl ReportsApi {
         let report = self.reports_service.get_account_counts(&auth).await?;
         Ok(Json(report))
     }
+
+    /// Get the usage of an environment aggregated by component and agent type
+    ///
+    /// Usage is recorded in hourly buckets, so the requested time range is extended to whole hours.
+    #[oai(
+        path = "/envs/:environment_id/usage",
+        method = "get",
+        operation_id = "get_environment_usage_report",
+        tag = ApiTags::Environment
+    )]
+    async fn get_environment_usage_report(
+        &self,
+        environment_id: Path<EnvironmentId>,
+        from: Query<DateTime<Utc>>,
+        to: Query<DateTime<Utc>>,
+        token: GolemSecurityScheme,
+    ) -> ApiResult<Json<EnvironmentUsageReport>> {
+        let record = recorded_http_api_request!(
+            "get_environment_usage_report",
+            environment_id = environment_id.0.to_string(),
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .get_environment_usage_report_internal(environment_id.0, from.0, to.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_environment_usage_report_internal(
+        &self,
+        environment_id: EnvironmentId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        auth: AuthCtx,
+    ) -> ApiResult<Json<EnvironmentUsageReport>> {
+        let report = self
+            .reports_service
+            .get_environment_usage(environment_id, from, to, &auth)
+            .await?;
+        Ok(Json(report))
+    }
 }
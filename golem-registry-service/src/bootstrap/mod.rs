@@ -28,6 +28,7 @@ use crate::repo::environment_plugin_grant::{
 };
 use crate::repo::environment_share::{DbEnvironmentShareRepo, EnvironmentShareRepo};
 use crate::repo::http_api_deployment::{DbHttpApiDeploymentRepo, HttpApiDeploymentRepo};
+use crate::repo::lifecycle_webhook::{DbLifecycleWebhookRepo, LifecycleWebhookRepo};
 use crate::repo::mcp_deployment::{DbMcpDeploymentRepo, McpDeploymentRepo};
 use crate::repo::oauth2_token::{DbOAuth2TokenRepo, OAuth2TokenRepo};
 use crate::repo::oauth2_webflow_state::{DbOAuth2WebflowStateRepo, OAuth2WebflowStateRepo};
@@ -44,6 +45,7 @@ use crate::services::component::{ComponentService, ComponentWriteService};
 use crate::services::component_compilation::ComponentCompilationService;
 use crate::services::component_object_store::ComponentObjectStore;
 use crate::services::component_resolver::ComponentResolverService;
+use crate::services::component_transformer::ComponentTransformerService;
 use crate::services::deployment::{
     DeployedMcpService, DeployedRoutesService, DeploymentService, DeploymentWriteService,
 };
@@ -52,6 +54,7 @@ use crate::services::environment::EnvironmentService;
 use crate::services::environment_plugin_grant::EnvironmentPluginGrantService;
 use crate::services::environment_share::EnvironmentShareService;
 use crate::services::http_api_deployment::HttpApiDeploymentService;
+use crate::services::lifecycle_webhook::LifecycleWebhookService;
 use crate::services::mcp_deployment::McpDeploymentService;
 use crate::services::plan::PlanService;
 use crate::services::plugin_registration::PluginRegistrationService;
@@ -93,6 +96,7 @@ pub struct Services {
     pub environment_service: Arc<EnvironmentService>,
     pub environment_share_service: Arc<EnvironmentShareService>,
     pub http_api_deployment_service: Arc<HttpApiDeploymentService>,
+    pub lifecycle_webhook_service: Arc<LifecycleWebhookService>,
     pub mcp_deployment_service: Arc<McpDeploymentService>,
     pub login_system: LoginSystem,
     pub plan_service: Arc<PlanService>,
@@ -113,6 +117,7 @@ struct Repos {
     environment_repo: Arc<dyn EnvironmentRepo>,
     environment_share_repo: Arc<dyn EnvironmentShareRepo>,
     http_api_deployment_repo: Arc<dyn HttpApiDeploymentRepo>,
+    lifecycle_webhook_repo: Arc<dyn LifecycleWebhookRepo>,
     mcp_deployment_repo: Arc<dyn McpDeploymentRepo>,
     oauth2_token_repo: Arc<dyn OAuth2TokenRepo>,
     oauth2_webflow_state_repo: Arc<dyn OAuth2WebflowStateRepo>,
@@ -217,6 +222,12 @@ impl Services {
             plugin_registration_service.clone(),
         ));
 
+        let component_transformer_service = Arc::new(ComponentTransformerService::new(
+            repos.component_repo.clone(),
+            component_object_store.clone(),
+            &config.component_transformer,
+        )?);
+
         let component_write_service = Arc::new(ComponentWriteService::new(
             repos.component_repo,
             component_object_store,
@@ -225,6 +236,7 @@ impl Services {
             account_usage_service.clone(),
             environment_service.clone(),
             environment_plugin_grant_service.clone(),
+            component_transformer_service,
         ));
 
         let login_system = LoginSystem::new(
@@ -235,7 +247,16 @@ impl Services {
             repos.oauth2_webflow_state_repo.clone(),
         )?;
 
-        let reports_service = Arc::new(ReportsService::new(repos.reports_repo.clone()));
+        let reports_service = Arc::new(ReportsService::new(
+            repos.reports_repo.clone(),
+            environment_service.clone(),
+        ));
+
+        let lifecycle_webhook_service = Arc::new(LifecycleWebhookService::new(
+            repos.lifecycle_webhook_repo.clone(),
+            environment_service.clone(),
+            &config.lifecycle_webhooks,
+        )?);
 
         let component_resolver_service = Arc::new(ComponentResolverService::new(
             account_service.clone(),
@@ -307,6 +328,7 @@ impl Services {
             environment_service,
             environment_share_service,
             http_api_deployment_service,
+            lifecycle_webhook_service,
             mcp_deployment_service,
             login_system,
             plan_service,
@@ -351,6 +373,7 @@ async fn make_repos(db_config: &DbConfig) -> anyhow::Result<Repos> {
             let http_api_deployment_repo =
                 Arc::new(DbHttpApiDeploymentRepo::logged(db_pool.clone()));
             let mcp_deployment_repo = Arc::new(DbMcpDeploymentRepo::logged(db_pool.clone()));
+            let lifecycle_webhook_repo = Arc::new(DbLifecycleWebhookRepo::logged(db_pool.clone()));
 
             Ok(Repos {
                 account_repo,
@@ -363,6 +386,7 @@ async fn make_repos(db_config: &DbConfig) -> anyhow::Result<Repos> {
                 environment_repo,
                 environment_share_repo,
                 http_api_deployment_repo,
+                lifecycle_webhook_repo,
                 mcp_deployment_repo,
                 oauth2_token_repo,
                 oauth2_webflow_state_repo,
@@ -402,6 +426,7 @@ async fn make_repos(db_config: &DbConfig) -> anyhow::Result<Repos> {
             let http_api_deployment_repo =
                 Arc::new(DbHttpApiDeploymentRepo::logged(db_pool.clone()));
             let mcp_deployment_repo = Arc::new(DbMcpDeploymentRepo::logged(db_pool.clone()));
+            let lifecycle_webhook_repo = Arc::new(DbLifecycleWebhookRepo::logged(db_pool.clone()));
 
             Ok(Repos {
                 account_repo,
@@ -414,6 +439,7 @@ async fn make_repos(db_config: &DbConfig) -> anyhow::Result<Repos> {
                 environment_repo,
                 environment_share_repo,
                 http_api_deployment_repo,
+                lifecycle_webhook_repo,
                 mcp_deployment_repo,
                 oauth2_token_repo,
                 oauth2_webflow_state_repo,
@@ -44,6 +44,8 @@ pub struct RegistryServiceConfig {
     pub cors_origin_regex: String,
     pub domain_provisioner: DomainProvisionerConfig,
     pub component_compilation: ComponentCompilationConfig,
+    pub component_transformer: ComponentTransformerConfig,
+    pub lifecycle_webhooks: LifecycleWebhooksConfig,
     pub initial_accounts: HashMap<String, PrecreatedAccount>,
     pub initial_plans: HashMap<String, PrecreatedPlan>,
 }
@@ -89,6 +91,20 @@ impl SafeDisplay for RegistryServiceConfig {
             self.component_compilation.to_safe_string_indented()
         );
 
+        let _ = writeln!(&mut result, "component transformer:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.component_transformer.to_safe_string_indented()
+        );
+
+        let _ = writeln!(&mut result, "lifecycle webhooks:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.lifecycle_webhooks.to_safe_string_indented()
+        );
+
         result
     }
 }
@@ -151,6 +167,8 @@ impl Default for RegistryServiceConfig {
             login: LoginConfig::default(),
             cors_origin_regex: "https://*.golem.cloud".to_string(),
             component_compilation: ComponentCompilationConfig::default(),
+            component_transformer: ComponentTransformerConfig::default(),
+            lifecycle_webhooks: LifecycleWebhooksConfig::default(),
             blob_storage: BlobStorageConfig::default(),
             domain_provisioner: DomainProvisionerConfig::default(),
             initial_accounts,
@@ -357,6 +375,90 @@ impl ComponentCompilationEnabledConfig {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ComponentTransformerConfig {
+    /// Maximum time a single component transformer invocation may take
+    #[serde(with = "humantime_serde")]
+    pub timeout: std::time::Duration,
+}
+
+impl SafeDisplay for ComponentTransformerConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "timeout: {:?}", self.timeout);
+        result
+    }
+}
+
+impl Default for ComponentTransformerConfig {
+    fn default() -> Self {
+        Self {
+            timeout: std::time::Duration::from_mins(2),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LifecycleWebhooksConfig {
+    /// How often pending webhook deliveries are attempted
+    #[serde(with = "humantime_serde")]
+    pub delivery_interval: std::time::Duration,
+    /// Maximum number of deliveries attempted in one round
+    pub delivery_batch_size: u32,
+    /// Timeout of a single webhook request
+    #[serde(with = "humantime_serde")]
+    pub request_timeout: std::time::Duration,
+    /// Number of attempts after which a delivery is marked as failed
+    pub max_attempts: u32,
+    #[serde(with = "humantime_serde")]
+    pub min_retry_delay: std::time::Duration,
+    #[serde(with = "humantime_serde")]
+    pub max_retry_delay: std::time::Duration,
+    /// Allows delivering to loopback, private and link-local addresses. Only meant for local
+    /// deployments, otherwise webhooks could be used to reach the internal network.
+    pub allow_private_targets: bool,
+}
+
+impl SafeDisplay for LifecycleWebhooksConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(
+            &mut result,
+            "delivery interval: {:?}",
+            self.delivery_interval
+        );
+        let _ = writeln!(
+            &mut result,
+            "delivery batch size: {}",
+            self.delivery_batch_size
+        );
+        let _ = writeln!(&mut result, "request timeout: {:?}", self.request_timeout);
+        let _ = writeln!(&mut result, "max attempts: {}", self.max_attempts);
+        let _ = writeln!(&mut result, "min retry delay: {:?}", self.min_retry_delay);
+        let _ = writeln!(&mut result, "max retry delay: {:?}", self.max_retry_delay);
+        let _ = writeln!(
+            &mut result,
+            "allow private targets: {}",
+            self.allow_private_targets
+        );
+        result
+    }
+}
+
+impl Default for LifecycleWebhooksConfig {
+    fn default() -> Self {
+        Self {
+            delivery_interval: std::time::Duration::from_secs(5),
+            delivery_batch_size: 100,
+            request_timeout: std::time::Duration::from_secs(10),
+            max_attempts: 10,
+            min_retry_delay: std::time::Duration::from_secs(10),
+            max_retry_delay: std::time::Duration::from_hours(1),
+            allow_private_targets: false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PrecreatedAccount {
     pub id: AccountId,
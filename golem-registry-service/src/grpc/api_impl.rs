@@ -19,12 +19,15 @@ use crate::services::component::ComponentService;
 use crate::services::component_resolver::ComponentResolverService;
 use crate::services::deployment::{DeployedMcpService, DeployedRoutesService, DeploymentService};
 use crate::services::environment::EnvironmentService;
+use crate::services::lifecycle_webhook::LifecycleWebhookService;
+use crate::services::reports::ReportsService;
 use applying::Apply;
 use async_trait::async_trait;
 use futures::StreamExt;
 use futures::stream::BoxStream;
 use golem_api_grpc::proto::golem::common::Empty as EmptySuccessResponse;
 use golem_api_grpc::proto::golem::registry::v1::get_agent_deployments_response::GetAgentDeploymentsSuccessResponse;
+use golem_api_grpc::proto::golem::registry::v1::get_network_egress_policy_response::GetNetworkEgressPolicySuccessResponse;
 use golem_api_grpc::proto::golem::registry::v1::{
     AuthenticateTokenRequest, AuthenticateTokenResponse, AuthenticateTokenSuccessResponse,
     BatchUpdateFuelUsageRequest, BatchUpdateFuelUsageResponse, BatchUpdateFuelUsageSuccessResponse,
@@ -40,21 +43,24 @@ use golem_api_grpc::proto::golem::registry::v1::{
     GetAuthDetailsForEnvironmentSuccessResponse, GetComponentMetadataRequest,
     GetComponentMetadataResponse, GetComponentMetadataSuccessResponse,
     GetDeployedComponentMetadataRequest, GetDeployedComponentMetadataResponse,
-    GetDeployedComponentMetadataSuccessResponse, GetResourceLimitsRequest,
-    GetResourceLimitsResponse, GetResourceLimitsSuccessResponse, RegistryServiceError,
-    ResolveAgentTypeAtDeploymentRequest, ResolveAgentTypeAtDeploymentResponse,
-    ResolveAgentTypeAtDeploymentSuccessResponse, ResolveAgentTypeByNamesRequest,
-    ResolveAgentTypeByNamesResponse, ResolveAgentTypeByNamesSuccessResponse,
-    ResolveComponentRequest, ResolveComponentResponse, ResolveComponentSuccessResponse,
-    ResolveLatestAgentTypeByNamesRequest, ResolveLatestAgentTypeByNamesResponse,
-    ResolveLatestAgentTypeByNamesSuccessResponse, UpdateWorkerConnectionLimitRequest,
-    UpdateWorkerConnectionLimitResponse, UpdateWorkerLimitRequest, UpdateWorkerLimitResponse,
-    authenticate_token_response, batch_update_fuel_usage_response, download_component_response,
+    GetDeployedComponentMetadataSuccessResponse, GetNetworkEgressPolicyRequest,
+    GetNetworkEgressPolicyResponse, GetResourceLimitsRequest, GetResourceLimitsResponse,
+    GetResourceLimitsSuccessResponse, PublishAgentLifecycleEventsRequest,
+    PublishAgentLifecycleEventsResponse, RegistryServiceError, ResolveAgentTypeAtDeploymentRequest,
+    ResolveAgentTypeAtDeploymentResponse, ResolveAgentTypeAtDeploymentSuccessResponse,
+    ResolveAgentTypeByNamesRequest, ResolveAgentTypeByNamesResponse,
+    ResolveAgentTypeByNamesSuccessResponse, ResolveComponentRequest, ResolveComponentResponse,
+    ResolveComponentSuccessResponse, ResolveLatestAgentTypeByNamesRequest,
+    ResolveLatestAgentTypeByNamesResponse, ResolveLatestAgentTypeByNamesSuccessResponse,
+    UpdateWorkerConnectionLimitRequest, UpdateWorkerConnectionLimitResponse,
+    UpdateWorkerLimitRequest, UpdateWorkerLimitResponse, authenticate_token_response,
+    batch_update_fuel_usage_response, download_component_response,
     get_active_mcp_for_domain_response, get_active_routes_for_domain_response,
     get_agent_deployments_response, get_agent_type_response, get_all_agent_types_response,
     get_all_deployed_component_revisions_response, get_auth_details_for_environment_response,
     get_component_metadata_response, get_deployed_component_metadata_response,
-    get_resource_limits_response, registry_service_error,
+    get_network_egress_policy_response, get_resource_limits_response,
+    publish_agent_lifecycle_events_response, registry_service_error,
     resolve_agent_type_at_deployment_response, resolve_agent_type_by_names_response,
     resolve_component_response, resolve_latest_agent_type_by_names_response,
     update_worker_connection_limit_response, update_worker_limit_response,
@@ -67,16 +73,18 @@ use golem_common::model::component::{ComponentId, ComponentRevision};
 use golem_common::model::deployment::DeploymentRevision;
 use golem_common::model::domain_registration::Domain;
 use golem_common::model::environment::{EnvironmentId, EnvironmentName};
+use golem_common::model::lifecycle_webhook::AgentLifecycleEvent;
 use golem_common::recorded_grpc_api_request;
 use golem_service_base::grpc::{
     proto_account_id_string, proto_application_id_string, proto_component_id_string,
     proto_environment_id_string,
 };
-use golem_service_base::model::AgentDeploymentDetails;
 use golem_service_base::model::auth::{AuthCtx, AuthDetailsForEnvironment};
+use golem_service_base::model::{AgentDeploymentDetails, AgentUsageUpdate};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
+use tracing::warn;
 use tracing_futures::Instrument;
 
 pub struct RegistryServiceGrpcApi {
@@ -88,6 +96,8 @@ pub struct RegistryServiceGrpcApi {
     deployment_service: Arc<DeploymentService>,
     deployed_routes_service: Arc<DeployedRoutesService>,
     deployed_mcp_service: Arc<DeployedMcpService>,
+    reports_service: Arc<ReportsService>,
+    lifecycle_webhook_service: Arc<LifecycleWebhookService>,
 }
 
 impl RegistryServiceGrpcApi {
@@ -100,6 +110,8 @@ impl RegistryServiceGrpcApi {
         deployment_service: Arc<DeploymentService>,
         deployed_routes_service: Arc<DeployedRoutesService>,
         deployed_mcp_service: Arc<DeployedMcpService>,
+        reports_service: Arc<ReportsService>,
+        lifecycle_webhook_service: Arc<LifecycleWebhookService>,
     ) -> Self {
         Self {
             auth_service,
@@ -110,6 +122,8 @@ impl RegistryServiceGrpcApi {
             deployment_service,
             deployed_routes_service,
             deployed_mcp_service,
+            reports_service,
+            lifecycle_webhook_service,
         }
     }
 
@@ -225,11 +239,27 @@ impl RegistryServiceGrpcApi {
             })
             .collect::<Result<_, _>>()?;
 
+        let agent_usage_updates: Vec<AgentUsageUpdate> = request
+            .agent_usage_updates
+            .into_iter()
+            .map(AgentUsageUpdate::try_from)
+            .collect::<Result<_, _>>()?;
+
         let account_resource_limits = self
             .account_usage_service
             .record_fuel_consumption(updates, &AuthCtx::System)
             .await?;
 
+        // Usage reports are best-effort; failing to store them must not prevent the executor
+        // from receiving the updated account limits
+        if let Err(err) = self
+            .reports_service
+            .record_agent_usage(agent_usage_updates, &AuthCtx::System)
+            .await
+        {
+            warn!("Failed to record agent usage: {err}");
+        }
+
         Ok(BatchUpdateFuelUsageSuccessResponse {
             account_resource_limits: Some(account_resource_limits.into()),
         })
@@ -448,6 +478,42 @@ impl RegistryServiceGrpcApi {
         })
     }
 
+    async fn get_network_egress_policy_internal(
+        &self,
+        request: GetNetworkEgressPolicyRequest,
+    ) -> Result<GetNetworkEgressPolicySuccessResponse, GrpcApiError> {
+        let environment_id: EnvironmentId = request
+            .environment_id
+            .ok_or("missing environment_id field")?
+            .try_into()?;
+
+        let environment = self
+            .environment_service
+            .get(environment_id, false, &AuthCtx::System)
+            .await?;
+
+        Ok(GetNetworkEgressPolicySuccessResponse {
+            network_egress_policy: Some(environment.network_egress_policy.into()),
+        })
+    }
+
+    async fn publish_agent_lifecycle_events_internal(
+        &self,
+        request: PublishAgentLifecycleEventsRequest,
+    ) -> Result<EmptySuccessResponse, GrpcApiError> {
+        let events: Vec<AgentLifecycleEvent> = request
+            .events
+            .into_iter()
+            .map(AgentLifecycleEvent::try_from)
+            .collect::<Result<_, _>>()?;
+
+        self.lifecycle_webhook_service
+            .publish_events(events, &AuthCtx::System)
+            .await?;
+
+        Ok(EmptySuccessResponse {})
+    }
+
     async fn resolve_latest_agent_type_by_names_internal(
         &self,
         request: ResolveLatestAgentTypeByNamesRequest,
@@ -1047,6 +1113,53 @@ impl golem_api_grpc::proto::golem::registry::v1::registry_service_server::Regist
             result: Some(response),
         }))
     }
+
+    async fn get_network_egress_policy(
+        &self,
+        request: Request<GetNetworkEgressPolicyRequest>,
+    ) -> Result<Response<GetNetworkEgressPolicyResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_network_egress_policy",
+            environment_id = proto_environment_id_string(&request.environment_id),
+        );
+
+        let response = match self
+            .get_network_egress_policy_internal(request)
+            .instrument(record.span.clone())
+            .await
+            .apply(|r| record.result(r))
+        {
+            Ok(result) => get_network_egress_policy_response::Result::Success(result),
+            Err(error) => get_network_egress_policy_response::Result::Error(error.into()),
+        };
+
+        Ok(Response::new(GetNetworkEgressPolicyResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn publish_agent_lifecycle_events(
+        &self,
+        request: Request<PublishAgentLifecycleEventsRequest>,
+    ) -> Result<Response<PublishAgentLifecycleEventsResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("publish_agent_lifecycle_events",);
+
+        let response = match self
+            .publish_agent_lifecycle_events_internal(request)
+            .instrument(record.span.clone())
+            .await
+            .apply(|r| record.result(r))
+        {
+            Ok(result) => publish_agent_lifecycle_events_response::Result::Success(result),
+            Err(error) => publish_agent_lifecycle_events_response::Result::Error(error.into()),
+        };
+
+        Ok(Response::new(PublishAgentLifecycleEventsResponse {
+            result: Some(response),
+        }))
+    }
 }
 
 fn internal_error(error: &str) -> RegistryServiceError {
@@ -18,6 +18,8 @@ use crate::services::component::ComponentError;
 use crate::services::component_resolver::ComponentResolverError;
 use crate::services::deployment::{DeployedMcpError, DeployedRoutesError, DeploymentError};
 use crate::services::environment::EnvironmentError;
+use crate::services::lifecycle_webhook::LifecycleWebhookError;
+use crate::services::reports::ReportsError;
 use golem_common::IntoAnyhow;
 use golem_common::metrics::api::ApiErrorDetails;
 use golem_common::model::error::{ErrorBody, ErrorsBody};
@@ -249,6 +251,11 @@ impl From<EnvironmentError> for GrpcApiError {
 
             EnvironmentError::LimitExceeded(inner) => inner.into(),
 
+            EnvironmentError::InvalidNetworkEgressPolicy(_) => Self::BadRequest(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            }),
+
             EnvironmentError::InternalError(_)
             | EnvironmentError::EnvironmentWithNameAlreadyExists
             | EnvironmentError::ConcurrentModification => Self::InternalError(ErrorBody {
@@ -259,6 +266,53 @@ impl From<EnvironmentError> for GrpcApiError {
     }
 }
 
+impl From<ReportsError> for GrpcApiError {
+    fn from(value: ReportsError) -> Self {
+        let error: String = value.to_string();
+        match value {
+            ReportsError::ParentEnvironmentNotFound(_) => {
+                Self::NotFound(ErrorBody { error, cause: None })
+            }
+
+            ReportsError::InvalidRange(_) => Self::BadRequest(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            }),
+
+            ReportsError::Unauthorized(inner) => inner.into(),
+
+            ReportsError::InternalError(_) => Self::InternalError(ErrorBody {
+                error,
+                cause: Some(value.into_anyhow()),
+            }),
+        }
+    }
+}
+
+impl From<LifecycleWebhookError> for GrpcApiError {
+    fn from(value: LifecycleWebhookError) -> Self {
+        let error: String = value.to_string();
+        match value {
+            LifecycleWebhookError::ParentEnvironmentNotFound(_)
+            | LifecycleWebhookError::LifecycleWebhookNotFound(_) => {
+                Self::NotFound(ErrorBody { error, cause: None })
+            }
+
+            LifecycleWebhookError::InvalidUrl(_) => Self::BadRequest(ErrorsBody {
+                errors: vec![error],
+                cause: None,
+            }),
+
+            LifecycleWebhookError::Unauthorized(inner) => inner.into(),
+
+            LifecycleWebhookError::InternalError(_) => Self::InternalError(ErrorBody {
+                error,
+                cause: Some(value.into_anyhow()),
+            }),
+        }
+    }
+}
+
 impl From<GrpcApiError>
     for golem_api_grpc::proto::golem::registry::v1::registry_service_error::Error
 {
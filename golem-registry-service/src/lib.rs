@@ -79,6 +79,7 @@ impl RegistryService {
     ) -> Result<RunDetails, anyhow::Error> {
         let http_port = self.start_http_server(join_set, tracer).await?;
         let grpc_port = self.start_grpc_server(join_set).await?;
+        self.start_lifecycle_webhook_delivery(join_set);
 
         Ok(RunDetails {
             http_port,
@@ -92,6 +93,7 @@ impl RegistryService {
         join_set: &mut JoinSet<Result<(), anyhow::Error>>,
     ) -> Result<SingleExecutableRunDetails, anyhow::Error> {
         let grpc_port = self.start_grpc_server(join_set).await?;
+        self.start_lifecycle_webhook_delivery(join_set);
         let endpoint = api::make_open_api_service(&self.services).boxed();
 
         Ok(SingleExecutableRunDetails {
@@ -116,6 +118,15 @@ impl RegistryService {
         Ok(port)
     }
 
+    fn start_lifecycle_webhook_delivery(&self, join_set: &mut JoinSet<Result<(), anyhow::Error>>) {
+        let lifecycle_webhook_service = self.services.lifecycle_webhook_service.clone();
+        join_set.spawn(
+            lifecycle_webhook_service
+                .run_delivery_loop()
+                .in_current_span(),
+        );
+    }
+
     async fn start_http_server(
         &self,
         join_set: &mut JoinSet<Result<(), anyhow::Error>>,
@@ -481,7 +481,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id AND c.current_revision_id = cr.revision_id
                     WHERE c.component_id = $1 AND c.deleted_at IS NULL
@@ -509,7 +511,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id AND c.current_revision_id = cr.revision_id
                     WHERE c.environment_id = $1 AND c.name = $2 AND c.deleted_at IS NULL
@@ -537,7 +541,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                         cr.created_at, cr.created_by, cr.deleted,
                         cr.size, cr.metadata, cr.env,
                         cr.config_vars, cr.local_agent_config,
-                        cr.object_store_key, cr.binary_hash
+                        cr.object_store_key, cr.binary_hash,
+                        cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM current_deployments cd
                     JOIN current_deployment_revisions cdr
                         ON cdr.environment_id = cd.environment_id AND cdr.revision_id = cd.current_revision_id
@@ -572,7 +578,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM current_deployments cd
                     JOIN current_deployment_revisions cdr
                         ON cdr.environment_id = cd.environment_id AND cdr.revision_id = cd.current_revision_id
@@ -619,7 +627,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM distinct_revs dr
                     JOIN component_revisions cr
                         ON cr.revision_id = dr.revision_id
@@ -650,7 +660,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id
                     WHERE c.component_id = $1 AND cr.revision_id = $2 AND ($3 OR cr.deleted = FALSE)
@@ -679,7 +691,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id AND c.current_revision_id = cr.revision_id
                     WHERE c.environment_id = $1 AND c.deleted_at IS NULL
@@ -707,7 +721,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.original_config_vars, cr.config_vars, cr.local_agent_config,
-                           cr.binary_hash
+                           cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM current_deployments cd
                     JOIN current_deployment_revisions cdr
                         ON cdr.environment_id = cd.environment_id AND cdr.revision_id = cd.current_revision_id
@@ -744,7 +760,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id
                     JOIN deployment_component_revisions dcr
@@ -778,7 +796,9 @@ impl ComponentRepo for DbComponentRepo<PostgresPool> {
                            cr.created_at, cr.created_by, cr.deleted,
                            cr.size, cr.metadata, cr.env,
                            cr.config_vars, cr.local_agent_config,
-                           cr.object_store_key, cr.binary_hash
+                           cr.object_store_key, cr.binary_hash,
+                           cr.original_object_store_key, cr.transformations,
+                        cr.agent_resource_limits
                     FROM components c
                     JOIN component_revisions cr ON c.component_id = cr.component_id
                     JOIN deployment_component_revisions dcr ON dcr.component_id = c.component_id AND dcr.component_revision_id = cr.revision_id
@@ -880,8 +900,10 @@ impl ComponentRepoInternal for DbComponentRepo<PostgresPool> {
                         p.plugin_id AS plugin_registration_id,
                         p.name AS plugin_name,
                         p.version AS plugin_version,
-                        p.component_id AS oplog_processor_component_id,
-                        p.component_revision_id AS oplog_processor_component_revision_id,
+                        CASE WHEN p.plugin_type = 3 THEN p.component_id END AS oplog_processor_component_id,
+                        CASE WHEN p.plugin_type = 3 THEN p.component_revision_id END AS oplog_processor_component_revision_id,
+                        CASE WHEN p.plugin_type = 2 THEN p.component_id END AS library_component_id,
+                        CASE WHEN p.plugin_type = 2 THEN p.component_revision_id END AS library_component_revision_id,
                         cpi.created_at,
                         cpi.created_by
                     FROM component_plugin_installations cpi
@@ -914,8 +936,10 @@ impl ComponentRepoInternal for DbComponentRepo<PostgresPool> {
                        p.plugin_id AS plugin_registration_id,
                        p.name AS plugin_name,
                        p.version AS plugin_version,
-                       p.component_id AS oplog_processor_component_id,
-                       p.component_revision_id AS oplog_processor_component_revision_id,
+                       CASE WHEN p.plugin_type = 3 THEN p.component_id END AS oplog_processor_component_id,
+                       CASE WHEN p.plugin_type = 3 THEN p.component_revision_id END AS oplog_processor_component_revision_id,
+                       CASE WHEN p.plugin_type = 2 THEN p.component_id END AS library_component_id,
+                       CASE WHEN p.plugin_type = 2 THEN p.component_revision_id END AS library_component_revision_id,
                        cpi.created_at,
                        cpi.created_by
                 FROM component_plugin_installations cpi
@@ -968,13 +992,17 @@ impl ComponentRepoInternal for DbComponentRepo<PostgresPool> {
                         created_at, created_by, deleted,
                         size, metadata, env,
                         config_vars, local_agent_config,
-                        object_store_key, binary_hash)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                        object_store_key, binary_hash,
+                        original_object_store_key, transformations,
+                        agent_resource_limits)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
                     RETURNING component_id, revision_id, hash,
                         created_at, created_by, deleted,
                         size, metadata, env,
                         config_vars, local_agent_config,
-                        object_store_key, binary_hash
+                        object_store_key, binary_hash,
+                        original_object_store_key, transformations,
+                        agent_resource_limits
                 "# })
                 .bind(revision.component_id)
                 .bind(revision.revision_id)
@@ -986,7 +1014,10 @@ impl ComponentRepoInternal for DbComponentRepo<PostgresPool> {
                 .bind(revision.config_vars)
                 .bind(revision.local_agent_config)
                 .bind(revision.object_store_key)
-                .bind(revision.binary_hash),
+                .bind(revision.binary_hash)
+                .bind(revision.original_object_store_key)
+                .bind(revision.transformations)
+                .bind(revision.agent_resource_limits),
             )
             .await
             .to_error_on_unique_violation(ComponentRepoError::ConcurrentModification)?
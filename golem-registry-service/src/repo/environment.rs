@@ -250,7 +250,7 @@ impl EnvironmentRepo for DbEnvironmentRepo<PostgresPool> {
                         e.name, e.application_id,
                         r.environment_id, r.revision_id, r.hash,
                         r.created_at, r.created_by, r.deleted,
-                        r.compatibility_check, r.version_check, r.security_overrides,
+                        r.compatibility_check, r.version_check, r.security_overrides, r.network_egress_policy,
 
                         a.account_id as owner_account_id,
                         COALESCE(esr.roles, 0) AS environment_roles_from_shares,
@@ -322,7 +322,7 @@ impl EnvironmentRepo for DbEnvironmentRepo<PostgresPool> {
                         e.name, e.application_id,
                         r.environment_id, r.revision_id, r.hash,
                         r.created_at, r.created_by, r.deleted,
-                        r.compatibility_check, r.version_check, r.security_overrides,
+                        r.compatibility_check, r.version_check, r.security_overrides, r.network_egress_policy,
 
                         a.account_id as owner_account_id,
                         COALESCE(esr.roles, 0) AS environment_roles_from_shares,
@@ -403,7 +403,7 @@ impl EnvironmentRepo for DbEnvironmentRepo<PostgresPool> {
                         e.name, e.application_id,
                         r.environment_id, r.revision_id, r.hash,
                         r.created_at, r.created_by, r.deleted,
-                        r.compatibility_check, r.version_check, r.security_overrides,
+                        r.compatibility_check, r.version_check, r.security_overrides, r.network_egress_policy,
 
                         a.account_id as owner_account_id,
                         COALESCE(esr.roles, 0) AS environment_roles_from_shares,
@@ -904,9 +904,9 @@ impl EnvironmentRepoInternal for DbEnvironmentRepo<PostgresPool> {
 
         let revision = tx.fetch_one_as(sqlx::query_as(indoc! { r#"
             INSERT INTO environment_revisions
-            (environment_id, revision_id, name, hash, created_at, created_by, deleted, compatibility_check, version_check, security_overrides)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING environment_id, revision_id, name, hash, created_at, created_by, deleted, compatibility_check, version_check, security_overrides
+            (environment_id, revision_id, name, hash, created_at, created_by, deleted, compatibility_check, version_check, security_overrides, network_egress_policy)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING environment_id, revision_id, name, hash, created_at, created_by, deleted, compatibility_check, version_check, security_overrides, network_egress_policy
         "# })
             .bind(revision.environment_id)
             .bind(revision.revision_id)
@@ -915,7 +915,8 @@ impl EnvironmentRepoInternal for DbEnvironmentRepo<PostgresPool> {
             .bind_deletable_revision_audit(revision.audit)
             .bind(revision.compatibility_check)
             .bind(revision.version_check)
-            .bind(revision.security_overrides))
+            .bind(revision.security_overrides)
+            .bind(revision.network_egress_policy))
             .await
             .to_error_on_unique_violation(EnvironmentRepoError::ConcurrentModification)?;
 
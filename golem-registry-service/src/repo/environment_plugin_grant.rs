@@ -51,6 +51,12 @@ pub trait EnvironmentPluginGrantRepo: Send + Sync {
         &self,
         environment_id: Uuid,
     ) -> Result<Vec<EnvironmentPluginGrantWithDetailsRecord>, EnvironmentPluginGrantRepoError>;
+
+    /// Lists the grants of the plugin which are not deleted, across all environments
+    async fn list_by_plugin(
+        &self,
+        plugin_id: Uuid,
+    ) -> Result<Vec<EnvironmentPluginGrantRecord>, EnvironmentPluginGrantRepoError>;
 }
 
 pub struct LoggedEnvironmentPluginGrantRepo<Repo: EnvironmentPluginGrantRepo> {
@@ -71,6 +77,10 @@ impl<Repo: EnvironmentPluginGrantRepo> LoggedEnvironmentPluginGrantRepo<Repo> {
     fn span_environment(environment_id: Uuid) -> Span {
         info_span!(SPAN_NAME, environment_id=%environment_id)
     }
+
+    fn span_plugin(plugin_id: Uuid) -> Span {
+        info_span!(SPAN_NAME, plugin_id=%plugin_id)
+    }
 }
 
 #[async_trait]
@@ -120,6 +130,14 @@ impl<Repo: EnvironmentPluginGrantRepo> EnvironmentPluginGrantRepo
             .instrument(span)
             .await
     }
+
+    async fn list_by_plugin(
+        &self,
+        plugin_id: Uuid,
+    ) -> Result<Vec<EnvironmentPluginGrantRecord>, EnvironmentPluginGrantRepoError> {
+        let span = Self::span_plugin(plugin_id);
+        self.repo.list_by_plugin(plugin_id).instrument(span).await
+    }
 }
 
 pub struct DbEnvironmentPluginGrantRepo<DBP: Pool> {
@@ -336,4 +354,27 @@ impl EnvironmentPluginGrantRepo for DbEnvironmentPluginGrantRepo<PostgresPool> {
 
         Ok(result)
     }
+
+    async fn list_by_plugin(
+        &self,
+        plugin_id: Uuid,
+    ) -> Result<Vec<EnvironmentPluginGrantRecord>, EnvironmentPluginGrantRepoError> {
+        let result = self
+            .with_ro("list_by_plugin")
+            .fetch_all_as(
+                sqlx::query_as(indoc! {r#"
+                    SELECT
+                        environment_plugin_grant_id, environment_id, plugin_id,
+                        created_at, created_by, deleted_at, deleted_by
+                    FROM environment_plugin_grants
+                    WHERE
+                        plugin_id = $1
+                        AND deleted_at IS NULL
+                "#})
+                .bind(plugin_id),
+            )
+            .await?;
+
+        Ok(result)
+    }
 }
@@ -0,0 +1,467 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::model::datetime::SqlDateTime;
+use super::model::lifecycle_webhook::{
+    LifecycleWebhookDeliveryRecord, LifecycleWebhookDeliveryStatusRecord, LifecycleWebhookRecord,
+    LifecycleWebhookRepoError,
+};
+use crate::repo::model::BindFields;
+use async_trait::async_trait;
+use conditional_trait_gen::trait_gen;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use golem_service_base::db::postgres::PostgresPool;
+use golem_service_base::db::sqlite::SqlitePool;
+use golem_service_base::db::{LabelledPoolApi, LabelledPoolTransaction, Pool, PoolApi};
+use golem_service_base::repo::RepoResult;
+use indoc::indoc;
+use tracing::{Instrument, Span, info_span};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait LifecycleWebhookRepo: Send + Sync {
+    async fn create(
+        &self,
+        record: LifecycleWebhookRecord,
+    ) -> Result<LifecycleWebhookRecord, LifecycleWebhookRepoError>;
+
+    async fn delete(
+        &self,
+        webhook_id: Uuid,
+        actor: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError>;
+
+    async fn get_by_id(
+        &self,
+        webhook_id: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError>;
+
+    async fn list_by_environment(
+        &self,
+        environment_id: Uuid,
+    ) -> Result<Vec<LifecycleWebhookRecord>, LifecycleWebhookRepoError>;
+
+    /// Creates the deliveries, ignoring the ones which already exist
+    async fn create_deliveries(
+        &self,
+        records: Vec<LifecycleWebhookDeliveryRecord>,
+    ) -> Result<(), LifecycleWebhookRepoError>;
+
+    /// Returns up to `limit` pending deliveries that are due, postponing their next attempt to
+    /// `leased_until` so they are not picked up again while being delivered.
+    async fn claim_pending_deliveries(
+        &self,
+        limit: u32,
+        leased_until: SqlDateTime,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError>;
+
+    async fn update_delivery(
+        &self,
+        record: LifecycleWebhookDeliveryRecord,
+    ) -> Result<(), LifecycleWebhookRepoError>;
+
+    /// Returns the latest deliveries of a webhook, newest first
+    async fn list_deliveries(
+        &self,
+        webhook_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError>;
+}
+
+pub struct LoggedLifecycleWebhookRepo<Repo: LifecycleWebhookRepo> {
+    repo: Repo,
+}
+
+static SPAN_NAME: &str = "lifecycle webhook repository";
+
+impl<Repo: LifecycleWebhookRepo> LoggedLifecycleWebhookRepo<Repo> {
+    pub fn new(repo: Repo) -> Self {
+        Self { repo }
+    }
+
+    fn span_id(webhook_id: Uuid) -> Span {
+        info_span!(SPAN_NAME, webhook_id=%webhook_id)
+    }
+
+    fn span_environment(environment_id: Uuid) -> Span {
+        info_span!(SPAN_NAME, environment_id=%environment_id)
+    }
+
+    fn span_delivery(delivery_id: Uuid) -> Span {
+        info_span!(SPAN_NAME, delivery_id=%delivery_id)
+    }
+}
+
+#[async_trait]
+impl<Repo: LifecycleWebhookRepo> LifecycleWebhookRepo for LoggedLifecycleWebhookRepo<Repo> {
+    async fn create(
+        &self,
+        record: LifecycleWebhookRecord,
+    ) -> Result<LifecycleWebhookRecord, LifecycleWebhookRepoError> {
+        let span = Self::span_id(record.webhook_id);
+        self.repo.create(record).instrument(span).await
+    }
+
+    async fn delete(
+        &self,
+        webhook_id: Uuid,
+        actor: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let span = Self::span_id(webhook_id);
+        self.repo.delete(webhook_id, actor).instrument(span).await
+    }
+
+    async fn get_by_id(
+        &self,
+        webhook_id: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let span = Self::span_id(webhook_id);
+        self.repo.get_by_id(webhook_id).instrument(span).await
+    }
+
+    async fn list_by_environment(
+        &self,
+        environment_id: Uuid,
+    ) -> Result<Vec<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let span = Self::span_environment(environment_id);
+        self.repo
+            .list_by_environment(environment_id)
+            .instrument(span)
+            .await
+    }
+
+    /// Creates the deliveries, ignoring the ones which already exist
+    async fn create_deliveries(
+        &self,
+        records: Vec<LifecycleWebhookDeliveryRecord>,
+    ) -> Result<(), LifecycleWebhookRepoError> {
+        self.repo
+            .create_deliveries(records)
+            .instrument(info_span!(SPAN_NAME))
+            .await
+    }
+
+    async fn claim_pending_deliveries(
+        &self,
+        limit: u32,
+        leased_until: SqlDateTime,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError> {
+        self.repo
+            .claim_pending_deliveries(limit, leased_until)
+            .instrument(info_span!(SPAN_NAME))
+            .await
+    }
+
+    async fn update_delivery(
+        &self,
+        record: LifecycleWebhookDeliveryRecord,
+    ) -> Result<(), LifecycleWebhookRepoError> {
+        let span = Self::span_delivery(record.delivery_id);
+        self.repo.update_delivery(record).instrument(span).await
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError> {
+        let span = Self::span_id(webhook_id);
+        self.repo
+            .list_deliveries(webhook_id, limit)
+            .instrument(span)
+            .await
+    }
+}
+
+pub struct DbLifecycleWebhookRepo<DBP: Pool> {
+    db_pool: DBP,
+}
+
+static METRICS_SVC_NAME: &str = "lifecycle_webhooks";
+
+impl<DBP: Pool> DbLifecycleWebhookRepo<DBP> {
+    pub fn new(db_pool: DBP) -> Self {
+        Self { db_pool }
+    }
+
+    pub fn logged(db_pool: DBP) -> LoggedLifecycleWebhookRepo<Self>
+    where
+        Self: LifecycleWebhookRepo,
+    {
+        LoggedLifecycleWebhookRepo::new(Self::new(db_pool))
+    }
+
+    fn with_ro(&self, api_name: &'static str) -> DBP::LabelledApi {
+        self.db_pool.with_ro(METRICS_SVC_NAME, api_name)
+    }
+
+    fn with_rw(&self, api_name: &'static str) -> DBP::LabelledApi {
+        self.db_pool.with_rw(METRICS_SVC_NAME, api_name)
+    }
+
+    async fn with_tx<R, F>(&self, api_name: &'static str, f: F) -> RepoResult<R>
+    where
+        R: Send,
+        F: for<'f> FnOnce(
+                &'f mut <DBP::LabelledApi as LabelledPoolApi>::LabelledTransaction,
+            ) -> BoxFuture<'f, RepoResult<R>>
+            + Send,
+    {
+        self.db_pool.with_tx(METRICS_SVC_NAME, api_name, f).await
+    }
+}
+
+#[trait_gen(PostgresPool -> PostgresPool, SqlitePool)]
+#[async_trait]
+impl LifecycleWebhookRepo for DbLifecycleWebhookRepo<PostgresPool> {
+    async fn create(
+        &self,
+        record: LifecycleWebhookRecord,
+    ) -> Result<LifecycleWebhookRecord, LifecycleWebhookRepoError> {
+        let result = self
+            .with_rw("create")
+            .fetch_one_as(
+                sqlx::query_as(indoc! {r#"
+                INSERT INTO lifecycle_webhooks (
+                    webhook_id, environment_id, url, event_types, signing_secret,
+                    created_at, created_by, deleted_at, deleted_by
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING
+                    webhook_id, environment_id, url, event_types, signing_secret,
+                    created_at, created_by, deleted_at, deleted_by
+            "#})
+                .bind(record.webhook_id)
+                .bind(record.environment_id)
+                .bind(record.url)
+                .bind(record.event_types)
+                .bind(record.signing_secret)
+                .bind_immutable_audit(record.audit),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn delete(
+        &self,
+        webhook_id: Uuid,
+        actor: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let deleted_at = SqlDateTime::now();
+
+        let result = self
+            .with_rw("delete")
+            .fetch_optional_as(
+                sqlx::query_as(indoc! {r#"
+                    UPDATE lifecycle_webhooks
+                    SET
+                        deleted_at = $2, deleted_by = $3
+                    WHERE
+                        webhook_id = $1
+                        AND deleted_at IS NULL
+                    RETURNING
+                        webhook_id, environment_id, url, event_types, signing_secret,
+                        created_at, created_by, deleted_at, deleted_by
+                "#})
+                .bind(webhook_id)
+                .bind(deleted_at)
+                .bind(actor),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn get_by_id(
+        &self,
+        webhook_id: Uuid,
+    ) -> Result<Option<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let result = self
+            .with_ro("get_by_id")
+            .fetch_optional_as(
+                sqlx::query_as(indoc! {r#"
+                    SELECT
+                        webhook_id, environment_id, url, event_types, signing_secret,
+                        created_at, created_by, deleted_at, deleted_by
+                    FROM lifecycle_webhooks
+                    WHERE
+                        webhook_id = $1
+                        AND deleted_at IS NULL
+                "#})
+                .bind(webhook_id),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn list_by_environment(
+        &self,
+        environment_id: Uuid,
+    ) -> Result<Vec<LifecycleWebhookRecord>, LifecycleWebhookRepoError> {
+        let result = self
+            .with_ro("list_by_environment")
+            .fetch_all_as(
+                sqlx::query_as(indoc! {r#"
+                    SELECT
+                        webhook_id, environment_id, url, event_types, signing_secret,
+                        created_at, created_by, deleted_at, deleted_by
+                    FROM lifecycle_webhooks
+                    WHERE
+                        environment_id = $1
+                        AND deleted_at IS NULL
+                    ORDER BY created_at
+                "#})
+                .bind(environment_id),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Creates the deliveries, ignoring the ones which already exist
+    async fn create_deliveries(
+        &self,
+        records: Vec<LifecycleWebhookDeliveryRecord>,
+    ) -> Result<(), LifecycleWebhookRepoError> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        self.with_tx("create_deliveries", |tx| {
+            async move {
+                for record in records {
+                    tx.execute(
+                        sqlx::query(indoc! { r#"
+                            INSERT INTO lifecycle_webhook_deliveries (
+                                delivery_id, webhook_id, event, status, attempts, next_attempt_at,
+                                last_status_code, last_error, created_at, last_attempt_at
+                            )
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                            ON CONFLICT (delivery_id) DO NOTHING
+                        "#})
+                        .bind(record.delivery_id)
+                        .bind(record.webhook_id)
+                        .bind(record.event)
+                        .bind(record.status)
+                        .bind(record.attempts)
+                        .bind(record.next_attempt_at)
+                        .bind(record.last_status_code)
+                        .bind(record.last_error)
+                        .bind(record.created_at)
+                        .bind(record.last_attempt_at),
+                    )
+                    .await?;
+                }
+
+                Ok(())
+            }
+            .boxed()
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn claim_pending_deliveries(
+        &self,
+        limit: u32,
+        leased_until: SqlDateTime,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError> {
+        let result = self
+            .with_rw("claim_pending_deliveries")
+            .fetch_all_as(
+                sqlx::query_as(indoc! {r#"
+                    UPDATE lifecycle_webhook_deliveries
+                    SET next_attempt_at = $1
+                    WHERE delivery_id IN (
+                        SELECT delivery_id
+                        FROM lifecycle_webhook_deliveries
+                        WHERE
+                            status = $2
+                            AND next_attempt_at <= $3
+                        ORDER BY next_attempt_at
+                        LIMIT $4
+                    )
+                    RETURNING
+                        delivery_id, webhook_id, event, status, attempts, next_attempt_at,
+                        last_status_code, last_error, created_at, last_attempt_at
+                "#})
+                .bind(leased_until)
+                .bind(LifecycleWebhookDeliveryStatusRecord::Pending)
+                .bind(SqlDateTime::now())
+                .bind(limit as i64),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn update_delivery(
+        &self,
+        record: LifecycleWebhookDeliveryRecord,
+    ) -> Result<(), LifecycleWebhookRepoError> {
+        self.with_rw("update_delivery")
+            .execute(
+                sqlx::query(indoc! {r#"
+                    UPDATE lifecycle_webhook_deliveries
+                    SET
+                        status = $2,
+                        attempts = $3,
+                        next_attempt_at = $4,
+                        last_status_code = $5,
+                        last_error = $6,
+                        last_attempt_at = $7
+                    WHERE delivery_id = $1
+                "#})
+                .bind(record.delivery_id)
+                .bind(record.status)
+                .bind(record.attempts)
+                .bind(record.next_attempt_at)
+                .bind(record.last_status_code)
+                .bind(record.last_error)
+                .bind(record.last_attempt_at),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        webhook_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<LifecycleWebhookDeliveryRecord>, LifecycleWebhookRepoError> {
+        let result = self
+            .with_ro("list_deliveries")
+            .fetch_all_as(
+                sqlx::query_as(indoc! {r#"
+                    SELECT
+                        delivery_id, webhook_id, event, status, attempts, next_attempt_at,
+                        last_status_code, last_error, created_at, last_attempt_at
+                    FROM lifecycle_webhook_deliveries
+                    WHERE webhook_id = $1
+                    ORDER BY created_at DESC
+                    LIMIT $2
+                "#})
+                .bind(webhook_id)
+                .bind(limit as i64),
+            )
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -23,6 +23,7 @@ pub mod environment;
 pub mod environment_plugin_grant;
 pub mod environment_share;
 pub mod http_api_deployment;
+pub mod lifecycle_webhook;
 pub mod mcp_deployment;
 pub mod oauth2_token;
 pub mod oauth2_webflow_state;
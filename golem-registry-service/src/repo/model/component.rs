@@ -22,8 +22,9 @@ use golem_common::model::application::ApplicationId;
 use golem_common::model::component::ComponentId;
 use golem_common::model::component::PluginPriority;
 use golem_common::model::component::{
-    ComponentFileContentHash, ComponentFilePath, ComponentFilePermissions, ComponentName,
-    ComponentRevision, InitialComponentFile, InstalledPlugin,
+    AgentResourceLimits, AppliedComponentTransformation, ComponentFileContentHash,
+    ComponentFilePath, ComponentFilePermissions, ComponentName, ComponentRevision,
+    InitialComponentFile, InstalledPlugin,
 };
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::deployment::DeploymentPlanComponentEntry;
@@ -165,6 +166,9 @@ pub struct ComponentRevisionRecord {
     pub local_agent_config: Blob<Vec<LocalAgentConfigEntry>>,
     pub object_store_key: String,
     pub binary_hash: SqlBlake3Hash, // NOTE: expected to be provided by service-layer
+    pub original_object_store_key: Option<String>,
+    pub transformations: Json<Vec<AppliedComponentTransformation>>,
+    pub agent_resource_limits: Json<Vec<AgentResourceLimits>>,
 
     #[sqlx(skip)]
     pub files: Vec<ComponentFileRecord>,
@@ -206,8 +210,11 @@ impl ComponentRevisionRecord {
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
         local_agent_config: Vec<LocalAgentConfigEntry>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         wasm_hash: diff::Hash,
         object_store_key: String,
+        original_object_store_key: Option<String>,
+        transformations: Vec<AppliedComponentTransformation>,
         actor: AccountId,
     ) -> Self {
         let component_id = component_id.0;
@@ -240,6 +247,9 @@ impl ComponentRevisionRecord {
             local_agent_config: Blob::new(local_agent_config),
             object_store_key,
             binary_hash: wasm_hash.into(),
+            original_object_store_key,
+            transformations: Json(transformations),
+            agent_resource_limits: Json(agent_resource_limits),
         }
     }
 
@@ -276,6 +286,9 @@ impl ComponentRevisionRecord {
             audit: DeletableRevisionAuditFields::new(actor.0),
             object_store_key: value.object_store_key,
             binary_hash: value.wasm_hash.into(),
+            original_object_store_key: value.original_object_store_key,
+            transformations: Json(value.transformations),
+            agent_resource_limits: Json(value.agent_resource_limits),
         }
     }
 
@@ -292,6 +305,9 @@ impl ComponentRevisionRecord {
             local_agent_config: Blob::new(Vec::new()),
             object_store_key: "".to_string(),
             binary_hash: SqlBlake3Hash::empty(),
+            original_object_store_key: None,
+            transformations: Json(Vec::new()),
+            agent_resource_limits: Json(Vec::new()),
             plugins: vec![],
             files: vec![],
         };
@@ -312,6 +328,11 @@ impl ComponentRevisionRecord {
                     .iter()
                     .map(|(k, v)| (k.clone(), v.clone()))
                     .collect(),
+                agent_resource_limits: self
+                    .agent_resource_limits
+                    .iter()
+                    .map(|limits| (limits.agent.0.clone(), limits.into()))
+                    .collect(),
             }
             .into(),
             wasm_hash: self.binary_hash.into(),
@@ -416,6 +437,9 @@ impl ComponentExtRevisionRecord {
             config_vars: self.revision.config_vars.0,
             local_agent_config: self.revision.local_agent_config.into_value(),
             object_store_key: self.revision.object_store_key,
+            original_object_store_key: self.revision.original_object_store_key,
+            transformations: self.revision.transformations.0,
+            agent_resource_limits: self.revision.agent_resource_limits.0,
             wasm_hash: self.revision.binary_hash.into(),
             hash: self.revision.hash.into(),
         })
@@ -476,6 +500,9 @@ pub struct ComponentPluginInstallationRecord {
     pub oplog_processor_component_id: Option<Uuid>,
     pub oplog_processor_component_revision_id: Option<i64>,
 
+    pub library_component_id: Option<Uuid>,
+    pub library_component_revision_id: Option<i64>,
+
     // NOTE: the properties below are used for hash calculation when inserting and must be returned by repo
     pub plugin_registration_id: Uuid,
     pub plugin_name: String,
@@ -510,6 +537,10 @@ impl ComponentPluginInstallationRecord {
             oplog_processor_component_revision_id: plugin_installation
                 .oplog_processor_component_revision
                 .map(|id| id.into()),
+            library_component_id: plugin_installation.library_component_id.map(|id| id.0),
+            library_component_revision_id: plugin_installation
+                .library_component_revision
+                .map(|id| id.into()),
         }
     }
 }
@@ -532,6 +563,11 @@ impl TryFrom<ComponentPluginInstallationRecord> for InstalledPlugin {
                 .oplog_processor_component_revision_id
                 .map(ComponentRevision::try_from)
                 .transpose()?,
+            library_component_id: value.library_component_id.map(ComponentId),
+            library_component_revision: value
+                .library_component_revision_id
+                .map(ComponentRevision::try_from)
+                .transpose()?,
         })
     }
 }
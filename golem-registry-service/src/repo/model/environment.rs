@@ -27,8 +27,10 @@ use golem_common::model::diff::{self};
 use golem_common::model::environment::{
     Environment, EnvironmentCreation, EnvironmentCurrentDeploymentView, EnvironmentId,
     EnvironmentName, EnvironmentRevision, EnvironmentSummary, EnvironmentWithDetails,
+    NetworkEgressPolicy,
 };
 use golem_service_base::repo::RepoError;
+use golem_service_base::repo::blob::Blob;
 use sqlx::FromRow;
 use std::collections::BTreeSet;
 use uuid::Uuid;
@@ -74,6 +76,8 @@ pub struct EnvironmentRevisionRecord {
     pub compatibility_check: bool,
     pub version_check: bool,
     pub security_overrides: bool,
+    /// Not set for revisions created before network egress policies were introduced
+    pub network_egress_policy: Option<Blob<NetworkEgressPolicy>>,
 }
 
 impl EnvironmentRevisionRecord {
@@ -86,6 +90,7 @@ impl EnvironmentRevisionRecord {
             compatibility_check: environment.compatibility_check,
             version_check: environment.version_check,
             security_overrides: environment.security_overrides,
+            network_egress_policy: Some(Blob::new(NetworkEgressPolicy::default())),
             audit: DeletableRevisionAuditFields::new(actor.0),
         }
     }
@@ -99,6 +104,7 @@ impl EnvironmentRevisionRecord {
             compatibility_check: environment.compatibility_check,
             version_check: environment.version_check,
             security_overrides: environment.security_overrides,
+            network_egress_policy: Some(Blob::new(environment.network_egress_policy)),
             audit,
         }
     }
@@ -148,6 +154,11 @@ impl TryFrom<EnvironmentExtRevisionRecord> for Environment {
             compatibility_check: value.revision.compatibility_check,
             version_check: value.revision.version_check,
             security_overrides: value.revision.security_overrides,
+            network_egress_policy: value
+                .revision
+                .network_egress_policy
+                .map(Blob::into_value)
+                .unwrap_or_default(),
 
             owner_account_id: AccountId(value.owner_account_id),
             roles_from_active_shares: environment_roles_from_bit_vector(
@@ -192,6 +203,7 @@ pub struct OptionalEnvironmentExtRevisionRecord {
     pub compatibility_check: Option<bool>,
     pub version_check: Option<bool>,
     pub security_overrides: Option<bool>,
+    pub network_egress_policy: Option<Blob<NetworkEgressPolicy>>,
 
     pub owner_account_id: Uuid,
     pub environment_roles_from_shares: i32,
@@ -237,6 +249,7 @@ impl OptionalEnvironmentExtRevisionRecord {
                 compatibility_check,
                 version_check,
                 security_overrides,
+                network_egress_policy: self.network_egress_policy,
             },
 
             owner_account_id: self.owner_account_id,
@@ -0,0 +1,155 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::audit::ImmutableAuditFields;
+use super::datetime::SqlDateTime;
+use golem_common::error_forwarding;
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::lifecycle_webhook::{
+    AgentLifecycleEvent, AgentLifecycleEventType, LifecycleWebhook, LifecycleWebhookDelivery,
+    LifecycleWebhookDeliveryId, LifecycleWebhookDeliveryStatus, LifecycleWebhookId,
+    LifecycleWebhookWithSecret,
+};
+use golem_service_base::repo::RepoError;
+use sqlx::FromRow;
+use sqlx::types::Json;
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LifecycleWebhookRepoError {
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+error_forwarding!(LifecycleWebhookRepoError, RepoError);
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct LifecycleWebhookRecord {
+    pub webhook_id: Uuid,
+    pub environment_id: Uuid,
+    pub url: String,
+    pub event_types: Json<BTreeSet<AgentLifecycleEventType>>,
+    pub signing_secret: String,
+
+    #[sqlx(flatten)]
+    pub audit: ImmutableAuditFields,
+}
+
+impl LifecycleWebhookRecord {
+    pub fn from_model(model: LifecycleWebhookWithSecret, audit: ImmutableAuditFields) -> Self {
+        Self {
+            webhook_id: model.id.0,
+            environment_id: model.environment_id.0,
+            url: model.url,
+            event_types: Json(model.event_types),
+            signing_secret: model.signing_secret,
+            audit,
+        }
+    }
+}
+
+impl From<LifecycleWebhookRecord> for LifecycleWebhook {
+    fn from(value: LifecycleWebhookRecord) -> Self {
+        Self {
+            id: LifecycleWebhookId(value.webhook_id),
+            environment_id: EnvironmentId(value.environment_id),
+            url: value.url,
+            event_types: value.event_types.0,
+            created_at: value.audit.created_at.into(),
+        }
+    }
+}
+
+impl From<LifecycleWebhookRecord> for LifecycleWebhookWithSecret {
+    fn from(value: LifecycleWebhookRecord) -> Self {
+        Self {
+            id: LifecycleWebhookId(value.webhook_id),
+            environment_id: EnvironmentId(value.environment_id),
+            url: value.url,
+            event_types: value.event_types.0,
+            created_at: value.audit.created_at.into(),
+            signing_secret: value.signing_secret,
+        }
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "integer")]
+pub enum LifecycleWebhookDeliveryStatusRecord {
+    Pending = 0,
+    Delivered = 1,
+    Failed = 2,
+}
+
+impl From<LifecycleWebhookDeliveryStatusRecord> for LifecycleWebhookDeliveryStatus {
+    fn from(value: LifecycleWebhookDeliveryStatusRecord) -> Self {
+        match value {
+            LifecycleWebhookDeliveryStatusRecord::Pending => Self::Pending,
+            LifecycleWebhookDeliveryStatusRecord::Delivered => Self::Delivered,
+            LifecycleWebhookDeliveryStatusRecord::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct LifecycleWebhookDeliveryRecord {
+    pub delivery_id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: Json<AgentLifecycleEvent>,
+    pub status: LifecycleWebhookDeliveryStatusRecord,
+    pub attempts: i32,
+    pub next_attempt_at: SqlDateTime,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: SqlDateTime,
+    pub last_attempt_at: Option<SqlDateTime>,
+}
+
+impl LifecycleWebhookDeliveryRecord {
+    /// The id of the delivery is derived from the webhook and the event, so publishing the same
+    /// event again does not create a second delivery
+    pub fn new(webhook_id: LifecycleWebhookId, event: AgentLifecycleEvent) -> Self {
+        let now = SqlDateTime::now();
+        Self {
+            delivery_id: Uuid::new_v5(&webhook_id.0, event.id.0.as_bytes()),
+            webhook_id: webhook_id.0,
+            event: Json(event),
+            status: LifecycleWebhookDeliveryStatusRecord::Pending,
+            attempts: 0,
+            next_attempt_at: now.clone(),
+            last_status_code: None,
+            last_error: None,
+            created_at: now,
+            last_attempt_at: None,
+        }
+    }
+}
+
+impl From<LifecycleWebhookDeliveryRecord> for LifecycleWebhookDelivery {
+    fn from(value: LifecycleWebhookDeliveryRecord) -> Self {
+        Self {
+            id: LifecycleWebhookDeliveryId(value.delivery_id),
+            webhook_id: LifecycleWebhookId(value.webhook_id),
+            event: value.event.0,
+            status: value.status.into(),
+            attempts: value.attempts.max(0) as u32,
+            last_status_code: value.last_status_code.map(|code| code as u16),
+            last_error: value.last_error,
+            created_at: value.created_at.into(),
+            last_attempt_at: value.last_attempt_at.map(|t| t.into()),
+        }
+    }
+}
@@ -16,13 +16,18 @@ use super::audit::ImmutableAuditFields;
 use super::hash::SqlBlake3Hash;
 use anyhow::anyhow;
 use golem_common::model::account::AccountId;
-use golem_common::model::component::ComponentId;
-use golem_common::model::plugin_registration::{OplogProcessorPluginSpec, PluginRegistrationId};
+use golem_common::model::component::{ComponentId, ComponentRevision};
+use golem_common::model::plugin_registration::{
+    ComponentTransformerPluginSpec, LibraryPluginSpec, OplogProcessorPluginSpec,
+    PluginRegistrationId,
+};
 use golem_service_base::model::plugin_registration::{PluginRegistration, PluginSpec};
 use sqlx::FromRow;
 use sqlx::types::Json;
 use uuid::Uuid;
 
+const COMPONENT_TRANSFORMER_PLUGIN_TYPE: i16 = 0;
+const LIBRARY_PLUGIN_TYPE: i16 = 2;
 const OPLOG_PROCESSOR_PLUGIN_TYPE: i16 = 3;
 
 #[derive(Debug, Clone, PartialEq, FromRow)]
@@ -47,7 +52,7 @@ pub struct PluginRecord {
     pub validate_url: Option<String>,
     pub transform_url: Option<String>,
 
-    // for OplogProcessor plugin type
+    // for OplogProcessor and Library plugin type
     pub component_id: Option<Uuid>,
     pub component_revision_id: Option<i64>,
 
@@ -76,6 +81,42 @@ impl PluginRecord {
                 component_revision_id: Some(inner.component_revision.into()),
                 wasm_content_hash: None,
             },
+            PluginSpec::ComponentTransformer(inner) => Self {
+                plugin_id: model.id.0,
+                account_id: model.account_id.0,
+                name: model.name,
+                version: model.version,
+                audit,
+                description: model.description,
+                icon: model.icon,
+                homepage: model.homepage,
+                plugin_type: COMPONENT_TRANSFORMER_PLUGIN_TYPE,
+                provided_wit_package: None,
+                json_schema: None,
+                validate_url: None,
+                transform_url: inner.transform_url,
+                component_id: inner.component_id.map(|id| id.0),
+                component_revision_id: inner.component_revision.map(|rev| rev.into()),
+                wasm_content_hash: None,
+            },
+            PluginSpec::Library(inner) => Self {
+                plugin_id: model.id.0,
+                account_id: model.account_id.0,
+                name: model.name,
+                version: model.version,
+                audit,
+                description: model.description,
+                icon: model.icon,
+                homepage: model.homepage,
+                plugin_type: LIBRARY_PLUGIN_TYPE,
+                provided_wit_package: None,
+                json_schema: None,
+                validate_url: None,
+                transform_url: None,
+                component_id: Some(inner.component_id.0),
+                component_revision_id: Some(inner.component_revision.into()),
+                wasm_content_hash: None,
+            },
         }
     }
 }
@@ -103,6 +144,41 @@ impl TryFrom<PluginRecord> for PluginRegistration {
                         .try_into()?,
                 }),
             }),
+            COMPONENT_TRANSFORMER_PLUGIN_TYPE => Ok(Self {
+                id: PluginRegistrationId(value.plugin_id),
+                account_id: AccountId(value.account_id),
+                name: value.name,
+                version: value.version,
+                description: value.description,
+                icon: value.icon,
+                homepage: value.homepage,
+                spec: PluginSpec::ComponentTransformer(ComponentTransformerPluginSpec {
+                    transform_url: value.transform_url,
+                    component_id: value.component_id.map(ComponentId),
+                    component_revision: value
+                        .component_revision_id
+                        .map(ComponentRevision::try_from)
+                        .transpose()?,
+                }),
+            }),
+            LIBRARY_PLUGIN_TYPE => Ok(Self {
+                id: PluginRegistrationId(value.plugin_id),
+                account_id: AccountId(value.account_id),
+                name: value.name,
+                version: value.version,
+                description: value.description,
+                icon: value.icon,
+                homepage: value.homepage,
+                spec: PluginSpec::Library(LibraryPluginSpec {
+                    component_id: ComponentId(
+                        value.component_id.ok_or(anyhow!("no component_id field"))?,
+                    ),
+                    component_revision: value
+                        .component_revision_id
+                        .ok_or(anyhow!("no component_revision field"))?
+                        .try_into()?,
+                }),
+            }),
             other => Err(anyhow!("Unknown plugin type {other}"))?,
         }
     }
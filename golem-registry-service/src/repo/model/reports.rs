@@ -14,8 +14,12 @@
 
 use super::datetime::SqlDateTime;
 use crate::repo::model::audit::AuditFields;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
 use golem_common::model::account::AccountId;
-use golem_common::model::reports::{AccountCountsReport, AccountSummaryReport};
+use golem_common::model::agent::AgentTypeName;
+use golem_common::model::component::ComponentId;
+use golem_common::model::reports::{AccountCountsReport, AccountSummaryReport, AgentTypeUsage};
+use golem_service_base::model::AgentUsageUpdate;
 use golem_service_base::repo::numeric::NumericU64;
 use sqlx::FromRow;
 use std::fmt::Debug;
@@ -72,3 +76,64 @@ impl From<AccountCountsRecord> for AccountCountsReport {
         }
     }
 }
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct AgentUsageRecord {
+    pub environment_id: Uuid,
+    pub component_id: Uuid,
+    pub agent_type: String,
+    pub period_start: SqlDateTime,
+    pub invocations: NumericU64,
+    pub fuel: NumericU64,
+    pub invocation_time_millis: NumericU64,
+    pub oplog_bytes: NumericU64,
+    pub outgoing_http_requests: NumericU64,
+}
+
+impl AgentUsageRecord {
+    pub fn from_update(update: AgentUsageUpdate, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            environment_id: update.key.environment_id.0,
+            component_id: update.key.component_id.0,
+            agent_type: update.key.agent_type.0,
+            period_start: hour_start(timestamp).into(),
+            invocations: update.usage.invocations.into(),
+            fuel: update.usage.fuel.into(),
+            invocation_time_millis: update.usage.invocation_time_millis.into(),
+            oplog_bytes: update.usage.oplog_bytes.into(),
+            outgoing_http_requests: update.usage.outgoing_http_requests.into(),
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct AgentTypeUsageRecord {
+    pub component_id: Uuid,
+    pub agent_type: String,
+    pub invocations: NumericU64,
+    pub fuel: NumericU64,
+    pub invocation_time_millis: NumericU64,
+    pub oplog_bytes: NumericU64,
+    pub outgoing_http_requests: NumericU64,
+}
+
+impl From<AgentTypeUsageRecord> for AgentTypeUsage {
+    fn from(value: AgentTypeUsageRecord) -> Self {
+        Self {
+            component_id: ComponentId(value.component_id),
+            agent_type: AgentTypeName(value.agent_type),
+            invocations: value.invocations.get(),
+            fuel: value.fuel.get(),
+            invocation_time_millis: value.invocation_time_millis.get(),
+            oplog_bytes: value.oplog_bytes.get(),
+            outgoing_http_requests: value.outgoing_http_requests.get(),
+        }
+    }
+}
+
+/// Start of the hourly usage bucket containing the given timestamp
+pub fn hour_start(timestamp: DateTime<Utc>) -> DateTime<Utc> {
+    timestamp
+        .duration_trunc(TimeDelta::hours(1))
+        .unwrap_or(timestamp)
+}
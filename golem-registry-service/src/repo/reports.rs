@@ -12,21 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::model::reports::{AccountCountsRecord, AccountSummaryRecord};
+use super::model::datetime::SqlDateTime;
+use super::model::reports::{
+    AccountCountsRecord, AccountSummaryRecord, AgentTypeUsageRecord, AgentUsageRecord,
+};
 use async_trait::async_trait;
 use conditional_trait_gen::trait_gen;
+use futures::FutureExt;
+use futures::future::BoxFuture;
 use golem_service_base::db::postgres::PostgresPool;
 use golem_service_base::db::sqlite::SqlitePool;
-use golem_service_base::db::{Pool, PoolApi};
+use golem_service_base::db::{LabelledPoolApi, LabelledPoolTransaction, Pool, PoolApi};
 use golem_service_base::repo::RepoResult;
 use indoc::indoc;
 use tracing::{Instrument, info_span};
+use uuid::Uuid;
 
 #[async_trait]
 pub trait ReportsRepo: Send + Sync {
     async fn list_account_summaries(&self) -> RepoResult<Vec<AccountSummaryRecord>>;
 
     async fn get_account_counts(&self) -> RepoResult<AccountCountsRecord>;
+
+    /// Adds the given usage to the already recorded usage of the same hourly bucket
+    async fn add_agent_usage(&self, records: Vec<AgentUsageRecord>) -> RepoResult<()>;
+
+    /// Usage of the environment aggregated by component and agent type, for the buckets
+    /// starting in the `[from, to)` range
+    async fn get_agent_usage(
+        &self,
+        environment_id: Uuid,
+        from: SqlDateTime,
+        to: SqlDateTime,
+    ) -> RepoResult<Vec<AgentTypeUsageRecord>>;
 }
 
 pub struct LoggedReportsRepo<Repo: ReportsRepo> {
@@ -56,6 +74,25 @@ impl<Repo: ReportsRepo> ReportsRepo for LoggedReportsRepo<Repo> {
             .instrument(info_span!(SPAN_NAME))
             .await
     }
+
+    async fn add_agent_usage(&self, records: Vec<AgentUsageRecord>) -> RepoResult<()> {
+        self.repo
+            .add_agent_usage(records)
+            .instrument(info_span!(SPAN_NAME))
+            .await
+    }
+
+    async fn get_agent_usage(
+        &self,
+        environment_id: Uuid,
+        from: SqlDateTime,
+        to: SqlDateTime,
+    ) -> RepoResult<Vec<AgentTypeUsageRecord>> {
+        self.repo
+            .get_agent_usage(environment_id, from, to)
+            .instrument(info_span!(SPAN_NAME, environment_id = %environment_id))
+            .await
+    }
 }
 
 pub struct DbReportsRepo<DBP: Pool> {
@@ -81,6 +118,17 @@ impl<DBP: Pool> DbReportsRepo<DBP> {
     fn with_ro(&self, api_name: &'static str) -> DBP::LabelledApi {
         self.db_pool.with_ro(METRICS_SVC_NAME, api_name)
     }
+
+    async fn with_tx<R, F>(&self, api_name: &'static str, f: F) -> RepoResult<R>
+    where
+        R: Send,
+        F: for<'f> FnOnce(
+                &'f mut <DBP::LabelledApi as LabelledPoolApi>::LabelledTransaction,
+            ) -> BoxFuture<'f, RepoResult<R>>
+            + Send,
+    {
+        self.db_pool.with_tx(METRICS_SVC_NAME, api_name, f).await
+    }
 }
 
 #[trait_gen(PostgresPool -> PostgresPool, SqlitePool)]
@@ -141,4 +189,93 @@ impl ReportsRepo for DbReportsRepo<PostgresPool> {
 
         Ok(result)
     }
+
+    async fn add_agent_usage(&self, records: Vec<AgentUsageRecord>) -> RepoResult<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        self.with_tx("add_agent_usage", |tx| {
+            async move {
+                let updated_at = SqlDateTime::now();
+                for record in records {
+                    tx.execute(
+                        sqlx::query(indoc! { r#"
+                            INSERT INTO agent_usage (
+                                environment_id,
+                                component_id,
+                                agent_type,
+                                period_start,
+                                invocations,
+                                fuel,
+                                invocation_time_millis,
+                                oplog_bytes,
+                                outgoing_http_requests,
+                                updated_at
+                            )
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                            ON CONFLICT (environment_id, component_id, agent_type, period_start) DO UPDATE
+                            SET
+                                invocations = agent_usage.invocations + excluded.invocations,
+                                fuel = agent_usage.fuel + excluded.fuel,
+                                invocation_time_millis = agent_usage.invocation_time_millis + excluded.invocation_time_millis,
+                                oplog_bytes = agent_usage.oplog_bytes + excluded.oplog_bytes,
+                                outgoing_http_requests = agent_usage.outgoing_http_requests + excluded.outgoing_http_requests,
+                                updated_at = excluded.updated_at
+                        "#})
+                        .bind(record.environment_id)
+                        .bind(record.component_id)
+                        .bind(record.agent_type)
+                        .bind(record.period_start)
+                        .bind(record.invocations)
+                        .bind(record.fuel)
+                        .bind(record.invocation_time_millis)
+                        .bind(record.oplog_bytes)
+                        .bind(record.outgoing_http_requests)
+                        .bind(updated_at.clone()),
+                    )
+                    .await?;
+                }
+
+                Ok(())
+            }
+            .boxed()
+        })
+        .await
+    }
+
+    async fn get_agent_usage(
+        &self,
+        environment_id: Uuid,
+        from: SqlDateTime,
+        to: SqlDateTime,
+    ) -> RepoResult<Vec<AgentTypeUsageRecord>> {
+        let result = self
+            .with_ro("get_agent_usage")
+            .fetch_all_as(
+                sqlx::query_as(indoc! { r#"
+                    SELECT
+                        component_id,
+                        agent_type,
+                        CAST(SUM(invocations) AS NUMERIC) as invocations,
+                        CAST(SUM(fuel) AS NUMERIC) as fuel,
+                        CAST(SUM(invocation_time_millis) AS NUMERIC) as invocation_time_millis,
+                        CAST(SUM(oplog_bytes) AS NUMERIC) as oplog_bytes,
+                        CAST(SUM(outgoing_http_requests) AS NUMERIC) as outgoing_http_requests
+                    FROM agent_usage
+                    WHERE
+                        environment_id = $1
+                        AND period_start >= $2
+                        AND period_start < $3
+                    GROUP BY component_id, agent_type
+                    ORDER BY component_id, agent_type
+                "# })
+                .bind(environment_id)
+                .bind(from)
+                .bind(to),
+            )
+            .await?;
+
+        Ok(result)
+    }
 }
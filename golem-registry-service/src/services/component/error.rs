@@ -54,6 +54,18 @@ pub enum ComponentError {
     InvalidComponentName { expected: String, actual: String },
     #[error("Plugin does not implement golem:api/oplog-processor")]
     InvalidOplogProcessorPlugin,
+    #[error("Component transformer {plugin_name}@{plugin_version} failed: {reason}")]
+    ComponentTransformationFailed {
+        plugin_name: String,
+        plugin_version: String,
+        reason: String,
+    },
+    #[error("Composing library plugin {plugin_name}@{plugin_version} failed: {reason}")]
+    LibraryCompositionFailed {
+        plugin_name: String,
+        plugin_version: String,
+        reason: String,
+    },
     #[error("Invalid plugin scope for {plugin_name}@{plugin_version} {details}")]
     InvalidPluginScope {
         plugin_name: String,
@@ -110,6 +122,10 @@ pub enum ComponentError {
         agent: AgentTypeName,
         key: Vec<String>,
     },
+    #[error("Resource limits declared for unknown agent type {agent}")]
+    AgentResourceLimitsForUnknownAgent { agent: AgentTypeName },
+    #[error("Multiple resource limits declared for agent type {agent}")]
+    AgentResourceLimitsDuplicated { agent: AgentTypeName },
     #[error(transparent)]
     Unauthorized(#[from] AuthorizationError),
     #[error(transparent)]
@@ -132,6 +148,8 @@ impl SafeDisplay for ComponentError {
             Self::InvalidOplogProcessorPlugin => self.to_string(),
             Self::EnvironmentPluginNotFound(_) => self.to_string(),
             Self::InvalidPluginScope { .. } => self.to_string(),
+            Self::ComponentTransformationFailed { .. } => self.to_string(),
+            Self::LibraryCompositionFailed { .. } => self.to_string(),
             Self::ConcurrentUpdate => self.to_string(),
             Self::PluginInstallationNotFound(_) => self.to_string(),
             Self::ParentEnvironmentNotFound(_) => self.to_string(),
@@ -146,6 +164,8 @@ impl SafeDisplay for ComponentError {
             Self::AgentConfigProvidedSharedWhereOnlyLocalAllowed { .. } => self.to_string(),
             Self::AgentConfigDuplicateValue { .. } => self.to_string(),
             Self::AgentConfigOldConfigNotValid { .. } => self.to_string(),
+            Self::AgentResourceLimitsForUnknownAgent { .. } => self.to_string(),
+            Self::AgentResourceLimitsDuplicated { .. } => self.to_string(),
             Self::Unauthorized(_) => self.to_string(),
             Self::InternalError(_) => "Internal error".to_string(),
         }
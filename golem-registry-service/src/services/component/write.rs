@@ -18,6 +18,9 @@ use crate::repo::model::component::{ComponentRepoError, ComponentRevisionRecord}
 use crate::services::account_usage::AccountUsageService;
 use crate::services::component_compilation::ComponentCompilationService;
 use crate::services::component_object_store::ComponentObjectStore;
+use crate::services::component_transformer::{
+    ComponentTransformationMetadata, ComponentTransformerError, ComponentTransformerService,
+};
 use crate::services::environment::EnvironmentError;
 use crate::services::environment::EnvironmentService;
 use crate::services::environment_plugin_grant::{
@@ -28,15 +31,18 @@ use anyhow::Context;
 use golem_common::base_model::component::LocalAgentConfigEntry as CommonLocalAgentConfigEntry;
 use golem_common::model::agent::{AgentType, ConfigValueType};
 use golem_common::model::component::{
-    ComponentCreation, ComponentFileContentHash, ComponentFileOptions, ComponentFilePath,
-    ComponentFilePermissions, ComponentUpdate, InitialComponentFile, InstalledPlugin,
-    PluginInstallationAction,
+    AgentResourceLimits, AppliedComponentTransformation, ComponentCreation,
+    ComponentFileContentHash, ComponentFileOptions, ComponentFilePath, ComponentFilePermissions,
+    ComponentUpdate, InitialComponentFile, InstalledPlugin, PluginInstallationAction,
 };
 use golem_common::model::component::{ComponentId, PluginInstallation};
 use golem_common::model::component::{ComponentName, ComponentRevision};
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::diff::Hash;
 use golem_common::model::environment::{Environment, EnvironmentId};
+use golem_common::model::environment_plugin_grant::{
+    EnvironmentPluginGrantId, EnvironmentPluginGrantWithDetails,
+};
 use golem_service_base::model::auth::AuthCtx;
 use golem_service_base::model::auth::EnvironmentAction;
 use golem_service_base::model::component::{Component, LocalAgentConfigEntry};
@@ -59,6 +65,7 @@ pub struct ComponentWriteService {
     account_usage_service: Arc<AccountUsageService>,
     environment_service: Arc<EnvironmentService>,
     environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+    component_transformer_service: Arc<ComponentTransformerService>,
 }
 
 impl ComponentWriteService {
@@ -70,6 +77,7 @@ impl ComponentWriteService {
         account_usage_service: Arc<AccountUsageService>,
         environment_service: Arc<EnvironmentService>,
         environment_plugin_grant_service: Arc<EnvironmentPluginGrantService>,
+        component_transformer_service: Arc<ComponentTransformerService>,
     ) -> Self {
         Self {
             component_repo,
@@ -79,6 +87,7 @@ impl ComponentWriteService {
             account_usage_service,
             environment_service,
             environment_plugin_grant_service,
+            component_transformer_service,
         }
     }
 
@@ -145,16 +154,34 @@ impl ComponentWriteService {
             .upload_and_hash_component_wasm(environment_id, wasm.clone())
             .await?;
 
+        let transformed = self
+            .apply_component_transformations(
+                &environment,
+                component_id,
+                &component_creation.component_name,
+                &plugin_installations,
+                &HashSet::new(),
+                wasm,
+                wasm_object_store_key,
+                auth,
+            )
+            .await?;
+
         let local_agent_config = validate_and_transform_local_agent_config_entries(
             &component_creation.agent_types,
             component_creation.local_agent_config,
         )?;
 
-        let component_size = wasm.len() as u64;
+        validate_agent_resource_limits(
+            &component_creation.agent_types,
+            &component_creation.agent_resource_limits,
+        )?;
+
+        let component_size = transformed.wasm.len() as u64;
         let component_metadata = analyze_and_validate_component_wasm(
             &component_creation.component_name,
             component_creation.agent_types,
-            wasm.clone(),
+            transformed.wasm,
         )
         .await?;
 
@@ -167,8 +194,11 @@ impl ComponentWriteService {
             component_creation.env,
             component_creation.config_vars,
             local_agent_config,
+            component_creation.agent_resource_limits,
             wasm_hash,
-            wasm_object_store_key,
+            transformed.object_store_key,
+            transformed.original_object_store_key,
+            transformed.transformations,
             auth.account_id(),
         );
 
@@ -262,26 +292,75 @@ impl ComponentWriteService {
             .agent_types
             .unwrap_or(component.metadata.agent_types().to_vec());
 
-        if let Some(new_wasm) = new_wasm {
-            self.account_usage_service
-                .ensure_updated_component_within_limits(
-                    environment.owner_account_id,
-                    u64::try_from(new_wasm.len()).unwrap(),
+        // changing the plugin installations may change the applied component transformations
+        let plugins_changed = !component_update.plugin_updates.is_empty();
+
+        component.installed_plugins = self
+            .update_plugin_installations(
+                &environment,
+                component.installed_plugins,
+                component_update.plugin_updates,
+                auth,
+            )
+            .await?;
+
+        let original_wasm = match new_wasm {
+            Some(new_wasm) => {
+                self.account_usage_service
+                    .ensure_updated_component_within_limits(
+                        environment.owner_account_id,
+                        u64::try_from(new_wasm.len()).unwrap(),
+                    )
+                    .await?;
+
+                let (wasm_hash, wasm_object_store_key) = self
+                    .upload_and_hash_component_wasm(environment_id, new_wasm.clone())
+                    .await?;
+
+                component.wasm_hash = wasm_hash;
+                Some((new_wasm, wasm_object_store_key))
+            }
+            None if plugins_changed => {
+                let original_object_store_key = component
+                    .original_object_store_key
+                    .clone()
+                    .unwrap_or(component.object_store_key.clone());
+                let original_wasm = self
+                    .object_store
+                    .get(environment_id, &original_object_store_key)
+                    .await?;
+                Some((Arc::from(original_wasm), original_object_store_key))
+            }
+            None => None,
+        };
+
+        if let Some((original_wasm, original_object_store_key)) = original_wasm {
+            let transformed = self
+                .apply_component_transformations(
+                    &environment,
+                    component_id,
+                    &component.component_name,
+                    &component.installed_plugins,
+                    &HashSet::new(),
+                    original_wasm,
+                    original_object_store_key,
+                    auth,
                 )
                 .await?;
 
-            let (wasm_hash, wasm_object_store_key) = self
-                .upload_and_hash_component_wasm(environment_id, new_wasm.clone())
+            if transformed.object_store_key != component.object_store_key || agent_types_changed {
+                component.component_size = transformed.wasm.len() as u64;
+                component.metadata = analyze_and_validate_component_wasm(
+                    &component.component_name,
+                    agent_types,
+                    transformed.wasm,
+                )
                 .await?;
+            }
 
-            component.wasm_hash = wasm_hash;
-            component.object_store_key = wasm_object_store_key;
-            component.metadata = analyze_and_validate_component_wasm(
-                &component.component_name,
-                agent_types,
-                new_wasm.clone(),
-            )
-            .await?;
+            component.object_store_key = transformed.object_store_key;
+            component.original_object_store_key = transformed.original_object_store_key;
+            component.transformations = transformed.transformations;
         } else if agent_types_changed {
             // TODO: skip the download here
             let old_data = self
@@ -317,6 +396,14 @@ impl ComponentWriteService {
             )?;
         };
 
+        if let Some(agent_resource_limits) = component_update.agent_resource_limits {
+            component.agent_resource_limits = agent_resource_limits;
+        }
+        validate_agent_resource_limits(
+            component.metadata.agent_types(),
+            &component.agent_resource_limits,
+        )?;
+
         component.files = self
             .update_initial_component_files(
                 environment_id,
@@ -327,15 +414,6 @@ impl ComponentWriteService {
             )
             .await?;
 
-        component.installed_plugins = self
-            .update_plugin_installations(
-                &environment,
-                component.installed_plugins,
-                component_update.plugin_updates,
-                auth,
-            )
-            .await?;
-
         let record = ComponentRevisionRecord::from_model(component, auth.account_id());
 
         let stored_component: Component = self
@@ -416,6 +494,268 @@ impl ComponentWriteService {
         Ok(())
     }
 
+    /// Re-runs the component transformations of the staged components in the environment whose
+    /// applied transformations no longer match the active plugin grants of their plugin
+    /// installations, creating a new revision for each affected component.
+    ///
+    /// The grants in `excluded_grants` are treated as inactive, so their transformations can be
+    /// dropped before the grants themselves get deleted. Components that are already up to date
+    /// are left untouched, so a failed reapplication can be completed by calling this again.
+    pub async fn reapply_transformations(
+        &self,
+        environment_id: EnvironmentId,
+        excluded_grants: &HashSet<EnvironmentPluginGrantId>,
+        auth: &AuthCtx,
+    ) -> Result<(), ComponentError> {
+        let environment = self
+            .environment_service
+            .get(environment_id, false, auth)
+            .await
+            .map_err(|err| match err {
+                EnvironmentError::EnvironmentNotFound(environment_id) => {
+                    ComponentError::ParentEnvironmentNotFound(environment_id)
+                }
+                other => other.into(),
+            })?;
+
+        let mut affected_components = Vec::new();
+        for record in self.component_repo.list_staged(environment_id.0).await? {
+            let component =
+                record.try_into_model(environment.application_id, environment.owner_account_id)?;
+
+            let mut expected_grants = Vec::new();
+            for installed_plugin in component
+                .installed_plugins
+                .iter()
+                .sorted_by_key(|p| p.priority.0)
+            {
+                if self
+                    .active_transforming_grant(
+                        installed_plugin,
+                        &environment,
+                        excluded_grants,
+                        auth,
+                    )
+                    .await?
+                    .is_some()
+                {
+                    expected_grants.push(installed_plugin.environment_plugin_grant_id);
+                }
+            }
+
+            let applied_grants = component
+                .transformations
+                .iter()
+                .map(|t| t.environment_plugin_grant_id)
+                .collect::<Vec<_>>();
+
+            if expected_grants != applied_grants {
+                affected_components.push(component);
+            }
+        }
+
+        if affected_components.is_empty() {
+            return Ok(());
+        }
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::UpdateComponent,
+        )?;
+
+        for mut component in affected_components {
+            info!(
+                environment_id = %environment_id,
+                component_id = %component.id,
+                "Re-applying component transformations"
+            );
+
+            let original_object_store_key = component
+                .original_object_store_key
+                .clone()
+                .unwrap_or(component.object_store_key.clone());
+            let original_wasm = self
+                .object_store
+                .get(environment_id, &original_object_store_key)
+                .await?;
+
+            let transformed = self
+                .apply_component_transformations(
+                    &environment,
+                    component.id,
+                    &component.component_name,
+                    &component.installed_plugins,
+                    excluded_grants,
+                    Arc::from(original_wasm),
+                    original_object_store_key,
+                    auth,
+                )
+                .await?;
+
+            component.revision = component.revision.next()?;
+            component.component_size = transformed.wasm.len() as u64;
+            component.metadata = analyze_and_validate_component_wasm(
+                &component.component_name,
+                component.metadata.agent_types().to_vec(),
+                transformed.wasm,
+            )
+            .await?;
+            component.object_store_key = transformed.object_store_key;
+            component.original_object_store_key = transformed.original_object_store_key;
+            component.transformations = transformed.transformations;
+
+            let component_id = component.id;
+            let record = ComponentRevisionRecord::from_model(component, auth.account_id());
+
+            let stored_component: Component = self
+                .component_repo
+                .update(record)
+                .await
+                .map_err(|err| match err {
+                    ComponentRepoError::ConcurrentModification => ComponentError::ConcurrentUpdate,
+                    other => other.into(),
+                })?
+                .try_into_model(environment.application_id, environment.owner_account_id)?;
+
+            self.component_compilation
+                .enqueue_compilation(environment_id, component_id, stored_component.revision)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the plugin grant of an installation if it is active in the environment, not excluded,
+    /// and its plugin transforms the installing components
+    async fn active_transforming_grant(
+        &self,
+        installed_plugin: &InstalledPlugin,
+        environment: &Environment,
+        excluded_grants: &HashSet<EnvironmentPluginGrantId>,
+        auth: &AuthCtx,
+    ) -> Result<Option<EnvironmentPluginGrantWithDetails>, ComponentError> {
+        if excluded_grants.contains(&installed_plugin.environment_plugin_grant_id) {
+            return Ok(None);
+        }
+
+        let environment_plugin_grant = match self
+            .environment_plugin_grant_service
+            .get_active_by_id_for_environment(
+                installed_plugin.environment_plugin_grant_id,
+                environment,
+                auth,
+            )
+            .await
+        {
+            Ok(grant) => grant,
+            Err(EnvironmentPluginGrantError::EnvironmentPluginGrantNotFound(_)) => return Ok(None),
+            Err(other) => Err(other)?,
+        };
+
+        let plugin = &environment_plugin_grant.plugin;
+        if plugin.component_transformer().is_some() || plugin.library().is_some() {
+            Ok(Some(environment_plugin_grant))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Runs the component transformer plugins and composes the library plugins installed to the
+    /// component in the order of their priority, starting from the originally uploaded wasm.
+    /// Installations whose plugin grant is no longer active in the environment, or is excluded,
+    /// are skipped.
+    #[allow(clippy::too_many_arguments)]
+    async fn apply_component_transformations(
+        &self,
+        environment: &Environment,
+        component_id: ComponentId,
+        component_name: &ComponentName,
+        installed_plugins: &[InstalledPlugin],
+        excluded_grants: &HashSet<EnvironmentPluginGrantId>,
+        original_wasm: Arc<[u8]>,
+        original_object_store_key: String,
+        auth: &AuthCtx,
+    ) -> Result<TransformedComponentWasm, ComponentError> {
+        let mut wasm = original_wasm;
+        let mut object_store_key = original_object_store_key.clone();
+        let mut transformations = Vec::new();
+
+        for installed_plugin in installed_plugins.iter().sorted_by_key(|p| p.priority.0) {
+            let Some(environment_plugin_grant) = self
+                .active_transforming_grant(installed_plugin, environment, excluded_grants, auth)
+                .await?
+            else {
+                continue;
+            };
+
+            let plugin = &environment_plugin_grant.plugin;
+            let transformed = if let Some(spec) = plugin.component_transformer() {
+                let metadata = ComponentTransformationMetadata {
+                    environment_id: environment.id,
+                    component_id,
+                    component_name: component_name.clone(),
+                    plugin_name: installed_plugin.plugin_name.clone(),
+                    plugin_version: installed_plugin.plugin_version.clone(),
+                };
+
+                self.component_transformer_service
+                    .transform(spec, &metadata, &installed_plugin.parameters, wasm)
+                    .await
+            } else if let Some(spec) = plugin.library() {
+                self.component_transformer_service
+                    .compose_library(spec, wasm)
+                    .await
+            } else {
+                continue;
+            };
+
+            let transformed = transformed.map_err(|err| match err {
+                ComponentTransformerError::TransformationFailed(reason) => {
+                    ComponentError::ComponentTransformationFailed {
+                        plugin_name: installed_plugin.plugin_name.clone(),
+                        plugin_version: installed_plugin.plugin_version.clone(),
+                        reason,
+                    }
+                }
+                ComponentTransformerError::LibraryCompositionFailed(reason) => {
+                    ComponentError::LibraryCompositionFailed {
+                        plugin_name: installed_plugin.plugin_name.clone(),
+                        plugin_version: installed_plugin.plugin_version.clone(),
+                        reason,
+                    }
+                }
+                ComponentTransformerError::InternalError(inner) => {
+                    ComponentError::InternalError(inner)
+                }
+            })?;
+
+            wasm = Arc::from(transformed);
+            let (result_hash, result_object_store_key) = self
+                .upload_and_hash_component_wasm(environment.id, wasm.clone())
+                .await?;
+            object_store_key = result_object_store_key;
+
+            transformations.push(AppliedComponentTransformation {
+                environment_plugin_grant_id: installed_plugin.environment_plugin_grant_id,
+                plugin_registration_id: installed_plugin.plugin_registration_id,
+                plugin_name: installed_plugin.plugin_name.clone(),
+                plugin_version: installed_plugin.plugin_version.clone(),
+                result_hash,
+            });
+        }
+
+        let original_object_store_key =
+            (object_store_key != original_object_store_key).then_some(original_object_store_key);
+
+        Ok(TransformedComponentWasm {
+            wasm,
+            object_store_key,
+            original_object_store_key,
+            transformations,
+        })
+    }
+
     async fn upload_and_hash_component_wasm(
         &self,
         environment_id: EnvironmentId,
@@ -572,6 +912,10 @@ impl ComponentWriteService {
                 oplog_processor_component_revision: environment_plugin_grant
                     .plugin
                     .oplog_processor_component_revision(),
+                library_component_id: environment_plugin_grant.plugin.library_component_id(),
+                library_component_revision: environment_plugin_grant
+                    .plugin
+                    .library_component_revision(),
                 plugin_name: environment_plugin_grant.plugin.name,
                 plugin_version: environment_plugin_grant.plugin.version,
             });
@@ -675,6 +1019,12 @@ impl ComponentWriteService {
                         oplog_processor_component_revision: environment_plugin_grant
                             .plugin
                             .oplog_processor_component_revision(),
+                        library_component_id: environment_plugin_grant
+                            .plugin
+                            .library_component_id(),
+                        library_component_revision: environment_plugin_grant
+                            .plugin
+                            .library_component_revision(),
                         plugin_name: environment_plugin_grant.plugin.name,
                         plugin_version: environment_plugin_grant.plugin.version,
                     });
@@ -696,6 +1046,13 @@ impl ComponentWriteService {
     }
 }
 
+struct TransformedComponentWasm {
+    wasm: Arc<[u8]>,
+    object_store_key: String,
+    original_object_store_key: Option<String>,
+    transformations: Vec<AppliedComponentTransformation>,
+}
+
 fn validate_and_transform_local_agent_config_entries(
     agent_types: &[AgentType],
     mut agent_local_config: Vec<CommonLocalAgentConfigEntry>,
@@ -797,6 +1154,32 @@ fn check_transformed_local_agent_config_entries_match(
     Ok(())
 }
 
+fn validate_agent_resource_limits(
+    agent_types: &[AgentType],
+    agent_resource_limits: &[AgentResourceLimits],
+) -> Result<(), ComponentError> {
+    let mut seen_agent_types = HashSet::new();
+
+    for limits in agent_resource_limits {
+        if !agent_types
+            .iter()
+            .any(|agent_type| agent_type.type_name == limits.agent)
+        {
+            return Err(ComponentError::AgentResourceLimitsForUnknownAgent {
+                agent: limits.agent.clone(),
+            });
+        }
+
+        if !seen_agent_types.insert(&limits.agent) {
+            return Err(ComponentError::AgentResourceLimitsDuplicated {
+                agent: limits.agent.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 async fn analyze_and_validate_component_wasm(
     component_name: &ComponentName,
     agent_types: Vec<AgentType>,
@@ -0,0 +1,521 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::ComponentTransformerConfig;
+use crate::repo::component::ComponentRepo;
+use crate::services::component_object_store::ComponentObjectStore;
+use crate::services::run_cpu_bound_work;
+use anyhow::anyhow;
+use base64::Engine as _;
+use golem_common::model::component::{ComponentId, ComponentName, ComponentRevision};
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::plugin_registration::{ComponentTransformerPluginSpec, LibraryPluginSpec};
+use golem_common::{SafeDisplay, error_forwarding};
+use golem_wasm::analysis::wit_parser::WitAnalysisContext;
+use golem_wasm::analysis::{AnalysedExport, AnalysedFunction, AnalysedInstance};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info};
+use wac_graph::types::{Package, SubtypeChecker};
+use wac_graph::{CompositionGraph, EncodeOptions};
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Engine, Store};
+use wasmtime_wasi::{IoCtx, IoData, IoView, WasiCtx, WasiCtxView, WasiView};
+
+const INTERFACE_NAME: &str = "golem:api/component-transformer@1.5.0";
+const FUNCTION_NAME: &str = "transform";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ComponentTransformerError {
+    #[error("{0}")]
+    TransformationFailed(String),
+    #[error("{0}")]
+    LibraryCompositionFailed(String),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+error_forwarding!(ComponentTransformerError);
+
+impl SafeDisplay for ComponentTransformerError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            Self::TransformationFailed(_) => self.to_string(),
+            Self::LibraryCompositionFailed(_) => self.to_string(),
+            Self::InternalError(_) => "Internal error".to_string(),
+        }
+    }
+}
+
+/// Details of the transformed component, sent to HTTP component transformers
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentTransformationMetadata {
+    pub environment_id: EnvironmentId,
+    pub component_id: ComponentId,
+    pub component_name: ComponentName,
+    pub plugin_name: String,
+    pub plugin_version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TransformResponse {
+    /// Base64 encoded binary of the transformed component
+    data: String,
+}
+
+/// Runs component transformer plugins, either by calling their HTTP endpoint or by
+/// executing their Wasm component in-process, and composes library plugins into components.
+pub struct ComponentTransformerService {
+    component_repo: Arc<dyn ComponentRepo>,
+    object_store: Arc<ComponentObjectStore>,
+    http_client: reqwest::Client,
+    engine: Engine,
+    timeout: Duration,
+}
+
+impl ComponentTransformerService {
+    pub fn new(
+        component_repo: Arc<dyn ComponentRepo>,
+        object_store: Arc<ComponentObjectStore>,
+        config: &ComponentTransformerConfig,
+    ) -> anyhow::Result<Self> {
+        let mut wasmtime_config = wasmtime::Config::default();
+        wasmtime_config.wasm_component_model(true);
+        // fuel is only used to regularly yield, so the configured timeout can interrupt the transformer
+        wasmtime_config.consume_fuel(true);
+
+        Ok(Self {
+            component_repo,
+            object_store,
+            http_client: reqwest::Client::builder().timeout(config.timeout).build()?,
+            engine: Engine::new(&wasmtime_config)?,
+            timeout: config.timeout,
+        })
+    }
+
+    pub async fn transform(
+        &self,
+        spec: &ComponentTransformerPluginSpec,
+        metadata: &ComponentTransformationMetadata,
+        parameters: &BTreeMap<String, String>,
+        wasm: Arc<[u8]>,
+    ) -> Result<Vec<u8>, ComponentTransformerError> {
+        info!(
+            component_id = %metadata.component_id,
+            plugin_name = metadata.plugin_name,
+            plugin_version = metadata.plugin_version,
+            "Running component transformer"
+        );
+
+        match (
+            &spec.transform_url,
+            spec.component_id,
+            spec.component_revision,
+        ) {
+            (Some(transform_url), _, _) => {
+                self.transform_with_http(transform_url, metadata, parameters, wasm)
+                    .await
+            }
+            (None, Some(component_id), Some(component_revision)) => {
+                self.transform_with_component(component_id, component_revision, parameters, wasm)
+                    .await
+            }
+            _ => Err(anyhow!(
+                "Invalid component transformer plugin specification"
+            ))?,
+        }
+    }
+
+    async fn transform_with_http(
+        &self,
+        transform_url: &str,
+        metadata: &ComponentTransformationMetadata,
+        parameters: &BTreeMap<String, String>,
+        wasm: Arc<[u8]>,
+    ) -> Result<Vec<u8>, ComponentTransformerError> {
+        let mut form = reqwest::multipart::Form::new()
+            .part(
+                "component",
+                reqwest::multipart::Part::bytes(wasm.to_vec()).file_name("component.wasm"),
+            )
+            .text(
+                "metadata",
+                serde_json::to_string(metadata).map_err(anyhow::Error::from)?,
+            );
+        for (key, value) in parameters {
+            form = form.text(key.clone(), value.clone());
+        }
+
+        let response = self
+            .http_client
+            .post(transform_url)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|err| {
+                ComponentTransformerError::TransformationFailed(format!(
+                    "Failed to call {transform_url}: {err}"
+                ))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ComponentTransformerError::TransformationFailed(format!(
+                "{transform_url} responded with {status}: {body}"
+            )));
+        }
+
+        let response: TransformResponse = response.json().await.map_err(|err| {
+            ComponentTransformerError::TransformationFailed(format!(
+                "Invalid response from {transform_url}: {err}"
+            ))
+        })?;
+
+        base64::engine::general_purpose::STANDARD
+            .decode(response.data)
+            .map_err(|err| {
+                ComponentTransformerError::TransformationFailed(format!(
+                    "Invalid component data in the response of {transform_url}: {err}"
+                ))
+            })
+    }
+
+    async fn transform_with_component(
+        &self,
+        component_id: ComponentId,
+        component_revision: ComponentRevision,
+        parameters: &BTreeMap<String, String>,
+        wasm: Arc<[u8]>,
+    ) -> Result<Vec<u8>, ComponentTransformerError> {
+        let transformer_wasm = self
+            .get_component_wasm(component_id, component_revision)
+            .await?
+            .ok_or_else(|| {
+                ComponentTransformerError::TransformationFailed(format!(
+                    "Transformer component {component_id} revision {component_revision} does not exist"
+                ))
+            })?;
+
+        let config: Vec<(String, String)> = parameters
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        match tokio::time::timeout(
+            self.timeout,
+            self.run_transformer_component(&transformer_wasm, wasm.to_vec(), config),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(ComponentTransformerError::TransformationFailed(format!(
+                "Transformer component did not finish in {:?}",
+                self.timeout
+            ))),
+        }
+    }
+
+    /// Composes the library component into the given component, plugging all the interfaces
+    /// imported by the component which are exported by the library.
+    pub async fn compose_library(
+        &self,
+        spec: &LibraryPluginSpec,
+        wasm: Arc<[u8]>,
+    ) -> Result<Vec<u8>, ComponentTransformerError> {
+        info!(
+            library_component_id = %spec.component_id,
+            library_component_revision = %spec.component_revision,
+            "Composing library component"
+        );
+
+        let library_wasm = self
+            .get_component_wasm(spec.component_id, spec.component_revision)
+            .await?
+            .ok_or_else(|| {
+                ComponentTransformerError::LibraryCompositionFailed(format!(
+                    "Library component {} revision {} does not exist",
+                    spec.component_id, spec.component_revision
+                ))
+            })?;
+
+        run_cpu_bound_work(move || {
+            check_library_compatibility(&library_wasm, &wasm)?;
+            compose_library_into(library_wasm, wasm.to_vec())
+        })
+        .await
+        .map_err(ComponentTransformerError::LibraryCompositionFailed)
+    }
+
+    async fn get_component_wasm(
+        &self,
+        component_id: ComponentId,
+        component_revision: ComponentRevision,
+    ) -> Result<Option<Vec<u8>>, ComponentTransformerError> {
+        let Some(component) = self
+            .component_repo
+            .get_by_id_and_revision(component_id.0, component_revision.into(), false)
+            .await
+            .map_err(anyhow::Error::from)?
+        else {
+            return Ok(None);
+        };
+
+        let wasm = self
+            .object_store
+            .get(
+                EnvironmentId(component.environment_id),
+                &component.revision.object_store_key,
+            )
+            .await?;
+
+        Ok(Some(wasm))
+    }
+
+    async fn run_transformer_component(
+        &self,
+        transformer_wasm: &[u8],
+        wasm: Vec<u8>,
+        config: Vec<(String, String)>,
+    ) -> Result<Vec<u8>, ComponentTransformerError> {
+        let failed = |err: wasmtime::Error| {
+            ComponentTransformerError::TransformationFailed(format!(
+                "Failed to run transformer component: {err}"
+            ))
+        };
+
+        let component = Component::new(&self.engine, transformer_wasm).map_err(failed)?;
+
+        let mut linker: Linker<Host> = Linker::new(&self.engine);
+        wasmtime_wasi::p2::add_to_linker_with_options_async(
+            &mut linker,
+            &wasmtime_wasi::p2::bindings::LinkOptions::default(),
+        )
+        .map_err(failed)?;
+
+        // The transformer only gets access to the WASI interfaces, without any preopened directories,
+        // environment variables or network access
+        let (wasi, io) = WasiCtx::builder().build();
+        let mut store = Store::new(
+            &self.engine,
+            Host {
+                table: ResourceTable::new(),
+                wasi,
+                io,
+            },
+        );
+        store.set_fuel(u64::MAX).map_err(failed)?;
+        store
+            .fuel_async_yield_interval(Some(10000))
+            .map_err(failed)?;
+
+        debug!("Instantiating transformer component");
+        let instance = linker
+            .instantiate_async(&mut store, &component)
+            .await
+            .map_err(failed)?;
+
+        let func = instance
+            .get_export(&mut store, None, INTERFACE_NAME)
+            .and_then(|(_, interface)| {
+                instance.get_export(&mut store, Some(&interface), FUNCTION_NAME)
+            })
+            .and_then(|(_, func)| instance.get_func(&mut store, func))
+            .ok_or_else(|| {
+                ComponentTransformerError::TransformationFailed(format!(
+                    "Transformer component does not export {INTERFACE_NAME}.{{{FUNCTION_NAME}}}"
+                ))
+            })?;
+
+        let typed_func = func
+            .typed::<(Vec<u8>, Vec<(String, String)>), (Result<Vec<u8>, String>,)>(&mut store)
+            .map_err(failed)?;
+
+        let (result,) = typed_func
+            .call_async(&mut store, (wasm, config))
+            .await
+            .map_err(failed)?;
+
+        result.map_err(ComponentTransformerError::TransformationFailed)
+    }
+}
+
+/// Checks that the library exports at least one of the interfaces imported by the component, and
+/// that every such interface provides all the imported functions with matching signatures.
+fn check_library_compatibility(library_wasm: &[u8], wasm: &[u8]) -> Result<(), String> {
+    let imports = WitAnalysisContext::new(wasm)
+        .and_then(|ctx| ctx.get_top_level_imports())
+        .map_err(|err| format!("Failed to analyse the component's imports: {}", err.reason))?;
+    let exports: Vec<AnalysedInstance> = WitAnalysisContext::new(library_wasm)
+        .and_then(|ctx| ctx.get_top_level_exports())
+        .map_err(|err| format!("Failed to analyse the library's exports: {}", err.reason))?
+        .into_iter()
+        .filter_map(|export| match export {
+            AnalysedExport::Instance(instance) => Some(instance),
+            AnalysedExport::Function(_) => None,
+        })
+        .collect();
+
+    let mut provides_any_import = false;
+    for import in &imports {
+        let Some(export) = exports.iter().find(|export| export.name == import.name) else {
+            continue;
+        };
+        provides_any_import = true;
+
+        for imported_function in &import.functions {
+            let exported_function = export
+                .functions
+                .iter()
+                .find(|f| f.name == imported_function.name)
+                .ok_or_else(|| {
+                    format!(
+                        "The library does not export {}.{{{}}}, imported by the component",
+                        import.name, imported_function.name
+                    )
+                })?;
+
+            if !signatures_match(imported_function, exported_function) {
+                return Err(format!(
+                    "The signature of {}.{{{}}} exported by the library does not match the one imported by the component",
+                    import.name, imported_function.name
+                ));
+            }
+        }
+    }
+
+    if provides_any_import {
+        Ok(())
+    } else {
+        Err(
+            "The library does not export any of the interfaces imported by the component"
+                .to_string(),
+        )
+    }
+}
+
+fn signatures_match(imported: &AnalysedFunction, exported: &AnalysedFunction) -> bool {
+    let results_match = match (&imported.result, &exported.result) {
+        (Some(imported), Some(exported)) => imported.typ.structurally_equals(&exported.typ),
+        (None, None) => true,
+        _ => false,
+    };
+
+    results_match
+        && imported.parameters.len() == exported.parameters.len()
+        && imported
+            .parameters
+            .iter()
+            .zip(&exported.parameters)
+            .all(|(imported, exported)| imported.typ.structurally_equals(&exported.typ))
+}
+
+/// Instantiates the component with the matching exports of the library plugged into its imports.
+/// Imports of the library and the remaining imports of the component become the imports of the
+/// composed component.
+fn compose_library_into(library_wasm: Vec<u8>, wasm: Vec<u8>) -> Result<Vec<u8>, String> {
+    let failed = |err: anyhow::Error| format!("{err:#}");
+
+    let mut graph = CompositionGraph::new();
+
+    let socket = Package::from_bytes("component", None, wasm, graph.types_mut()).map_err(failed)?;
+    let socket = graph
+        .register_package(socket)
+        .map_err(|err| failed(err.into()))?;
+    let library =
+        Package::from_bytes("library", None, library_wasm, graph.types_mut()).map_err(failed)?;
+    let library = graph
+        .register_package(library)
+        .map_err(|err| failed(err.into()))?;
+
+    let socket_instantiation = graph.instantiate(socket);
+    let library_instantiation = graph.instantiate(library);
+
+    let mut cache = Default::default();
+    let mut checker = SubtypeChecker::new(&mut cache);
+    let plugged: Vec<String> = graph.types()[graph[library].ty()]
+        .exports
+        .iter()
+        .filter_map(|(name, library_ty)| {
+            let socket_ty = graph.types()[graph[socket].ty()].imports.get(name)?;
+            checker
+                .is_subtype(*library_ty, graph.types(), *socket_ty, graph.types())
+                .is_ok()
+                .then(|| name.clone())
+        })
+        .collect();
+
+    for name in plugged {
+        let export = graph
+            .alias_instance_export(library_instantiation, &name)
+            .map_err(|err| failed(err.into()))?;
+        graph
+            .set_instantiation_argument(socket_instantiation, &name, export)
+            .map_err(|err| failed(err.into()))?;
+    }
+
+    let socket_exports: Vec<String> = graph.types()[graph[socket].ty()]
+        .exports
+        .keys()
+        .cloned()
+        .collect();
+    for name in socket_exports {
+        let export = graph
+            .alias_instance_export(socket_instantiation, &name)
+            .map_err(|err| failed(err.into()))?;
+        graph
+            .export(export, &name)
+            .map_err(|err| failed(err.into()))?;
+    }
+
+    graph
+        .encode(EncodeOptions::default())
+        .map_err(|err| failed(err.into()))
+}
+
+struct Host {
+    table: ResourceTable,
+    wasi: WasiCtx,
+    io: IoCtx,
+}
+
+impl IoView for Host {
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn io_ctx(&mut self) -> &mut IoCtx {
+        &mut self.io
+    }
+
+    fn io_data(&mut self) -> IoData<'_> {
+        IoData {
+            table: &mut self.table,
+            io_ctx: &mut self.io,
+        }
+    }
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> WasiCtxView<'_> {
+        WasiCtxView {
+            ctx: &mut self.wasi,
+            table: &mut self.table,
+            io_ctx: &mut self.io,
+        }
+    }
+}
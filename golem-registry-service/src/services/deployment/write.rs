@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::deployment_context::DeploymentContext;
+use super::deployment_context::{DeploymentContext, InProgressDeployedRegisteredAgentType};
 use crate::repo::deployment::DeploymentRepo;
 use crate::repo::model::deployment::{DeployRepoError, DeploymentRevisionCreationRecord};
 use crate::services::component::{ComponentError, ComponentService};
@@ -21,6 +21,9 @@ use crate::services::environment::{EnvironmentError, EnvironmentService};
 use crate::services::http_api_deployment::{HttpApiDeploymentError, HttpApiDeploymentService};
 use crate::services::mcp_deployment::{McpDeploymentError, McpDeploymentService};
 use futures::TryFutureExt;
+use golem_common::model::agent::compatibility::{
+    AgentTypeBreakingChange, agent_types_breaking_changes,
+};
 use golem_common::model::agent::{AgentTypeName, DeployedRegisteredAgentType, HttpMethod};
 use golem_common::model::component::ComponentName;
 use golem_common::model::deployment::{CurrentDeployment, DeploymentRevision, DeploymentRollback};
@@ -36,6 +39,7 @@ use golem_service_base::custom_api::PathSegment;
 use golem_service_base::model::auth::EnvironmentAction;
 use golem_service_base::model::auth::{AuthCtx, AuthorizationError};
 use golem_service_base::repo::RepoError;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Debug, thiserror::Error)]
@@ -167,6 +171,8 @@ pub enum DeployValidationError {
     },
     #[error("Invalid http method: {method:?}")]
     InvalidHttpMethod { method: HttpMethod },
+    #[error("Incompatible change in agent type {0}")]
+    IncompatibleAgentTypeChange(AgentTypeBreakingChange),
 }
 
 impl SafeDisplay for DeployValidationError {
@@ -299,6 +305,14 @@ impl DeploymentWriteService {
         }
 
         let registered_agent_types = deployment_context.extract_registered_agent_types()?;
+
+        if deployment_context.environment.compatibility_check
+            && deployment_context.environment.current_deployment.is_some()
+        {
+            self.check_agent_type_compatibility(environment_id, &registered_agent_types)
+                .await?;
+        }
+
         let compiled_routes =
             deployment_context.compile_http_api_routes(&registered_agent_types)?;
 
@@ -413,6 +427,40 @@ impl DeploymentWriteService {
         Ok(current_deployment)
     }
 
+    async fn check_agent_type_compatibility(
+        &self,
+        environment_id: EnvironmentId,
+        registered_agent_types: &HashMap<AgentTypeName, InProgressDeployedRegisteredAgentType>,
+    ) -> Result<(), DeploymentWriteError> {
+        let deployed_agent_types = self
+            .deployment_repo
+            .list_deployed_agent_types(environment_id.0)
+            .await?
+            .into_iter()
+            .map(DeployedRegisteredAgentType::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let breaking_changes = agent_types_breaking_changes(
+            deployed_agent_types
+                .iter()
+                .map(|deployed| &deployed.agent_type),
+            registered_agent_types
+                .values()
+                .map(|registered| &registered.agent_type),
+        );
+
+        if breaking_changes.is_empty() {
+            Ok(())
+        } else {
+            Err(DeploymentWriteError::DeploymentValidationFailed(
+                breaking_changes
+                    .into_iter()
+                    .map(DeployValidationError::IncompatibleAgentTypeChange)
+                    .collect(),
+            ))
+        }
+    }
+
     async fn get_latest_deployment_for_environment(
         &self,
         environment: &Environment,
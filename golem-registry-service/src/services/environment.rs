@@ -45,6 +45,8 @@ pub enum EnvironmentError {
     ParentApplicationNotFound(ApplicationId),
     #[error("Concurrent update attempt")]
     ConcurrentModification,
+    #[error("Invalid network egress policy: {0}")]
+    InvalidNetworkEgressPolicy(String),
     #[error(transparent)]
     LimitExceeded(LimitExceededError),
     #[error(transparent)]
@@ -61,6 +63,7 @@ impl SafeDisplay for EnvironmentError {
             Self::EnvironmentByNameNotFound(_) => self.to_string(),
             Self::ParentApplicationNotFound(_) => self.to_string(),
             Self::ConcurrentModification => self.to_string(),
+            Self::InvalidNetworkEgressPolicy(_) => self.to_string(),
             Self::LimitExceeded(inner) => inner.to_safe_string(),
             Self::Unauthorized(inner) => inner.to_safe_string(),
             Self::InternalError(_) => "Internal error".to_string(),
@@ -174,6 +177,12 @@ impl EnvironmentService {
         if let Some(security_overrides) = update.security_overrides {
             environment.security_overrides = security_overrides;
         }
+        if let Some(network_egress_policy) = update.network_egress_policy {
+            network_egress_policy
+                .validate()
+                .map_err(EnvironmentError::InvalidNetworkEgressPolicy)?;
+            environment.network_egress_policy = network_egress_policy;
+        }
 
         let audit = DeletableRevisionAuditFields::new(auth.account_id().0);
         let record = EnvironmentRevisionRecord::from_model(environment, audit);
@@ -160,6 +160,21 @@ impl EnvironmentPluginGrantService {
         Ok(())
     }
 
+    /// Lists the grants of the plugin across all environments. This is used for system
+    /// initiated work on the environments and does not check authorization.
+    pub async fn list_for_plugin(
+        &self,
+        plugin_registration_id: PluginRegistrationId,
+    ) -> Result<Vec<EnvironmentPluginGrant>, EnvironmentPluginGrantError> {
+        Ok(self
+            .environment_plugin_grant_repo
+            .list_by_plugin(plugin_registration_id.0)
+            .await?
+            .into_iter()
+            .map(EnvironmentPluginGrant::from)
+            .collect())
+    }
+
     pub async fn list_in_environment(
         &self,
         environment_id: EnvironmentId,
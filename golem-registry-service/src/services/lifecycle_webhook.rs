@@ -0,0 +1,619 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::environment::{EnvironmentError, EnvironmentService};
+use crate::config::LifecycleWebhooksConfig;
+use crate::repo::lifecycle_webhook::LifecycleWebhookRepo;
+use crate::repo::model::audit::ImmutableAuditFields;
+use crate::repo::model::datetime::SqlDateTime;
+use crate::repo::model::lifecycle_webhook::{
+    LifecycleWebhookDeliveryRecord, LifecycleWebhookDeliveryStatusRecord, LifecycleWebhookRecord,
+    LifecycleWebhookRepoError,
+};
+use chrono::{DateTime, Utc};
+use golem_common::model::auth::TokenSecret;
+use golem_common::model::environment::{Environment, EnvironmentId};
+use golem_common::model::lifecycle_webhook::{
+    AgentLifecycleEvent, LifecycleWebhook, LifecycleWebhookCreation, LifecycleWebhookDelivery,
+    LifecycleWebhookId, LifecycleWebhookWithSecret,
+};
+use golem_common::{SafeDisplay, error_forwarding};
+use golem_service_base::model::auth::{AuthCtx, AuthorizationError};
+use golem_service_base::model::auth::{EnvironmentAction, GlobalAction};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{Instrument, debug, error, info_span, warn};
+
+/// Maximum number of deliveries returned from the delivery log of a webhook
+const DELIVERY_LOG_LIMIT: u32 = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LifecycleWebhookError {
+    #[error("Lifecycle webhook {0} not found")]
+    LifecycleWebhookNotFound(LifecycleWebhookId),
+    #[error("Parent environment {0} not found")]
+    ParentEnvironmentNotFound(EnvironmentId),
+    #[error("Invalid webhook url: {0}")]
+    InvalidUrl(String),
+    #[error(transparent)]
+    Unauthorized(#[from] AuthorizationError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+impl SafeDisplay for LifecycleWebhookError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            Self::LifecycleWebhookNotFound(_) => self.to_string(),
+            Self::ParentEnvironmentNotFound(_) => self.to_string(),
+            Self::InvalidUrl(_) => self.to_string(),
+            Self::Unauthorized(_) => self.to_string(),
+            Self::InternalError(_) => "Internal error".to_string(),
+        }
+    }
+}
+
+error_forwarding!(
+    LifecycleWebhookError,
+    EnvironmentError,
+    LifecycleWebhookRepoError
+);
+
+/// Manages the lifecycle webhooks of environments and delivers the agent lifecycle events
+/// published by the executors to them. Deliveries are recorded and retried with an exponential
+/// backoff until they succeed or run out of attempts.
+pub struct LifecycleWebhookService {
+    lifecycle_webhook_repo: Arc<dyn LifecycleWebhookRepo>,
+    environment_service: Arc<EnvironmentService>,
+    http_client: reqwest::Client,
+    config: LifecycleWebhooksConfig,
+}
+
+impl LifecycleWebhookService {
+    pub fn new(
+        lifecycle_webhook_repo: Arc<dyn LifecycleWebhookRepo>,
+        environment_service: Arc<EnvironmentService>,
+        config: &LifecycleWebhooksConfig,
+    ) -> anyhow::Result<Self> {
+        let mut http_client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            // a redirect could point to an address which was not checked
+            .redirect(reqwest::redirect::Policy::none());
+        if !config.allow_private_targets {
+            http_client = http_client.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+
+        Ok(Self {
+            lifecycle_webhook_repo,
+            environment_service,
+            http_client: http_client.build()?,
+            config: config.clone(),
+        })
+    }
+
+    pub async fn create(
+        &self,
+        environment_id: EnvironmentId,
+        data: LifecycleWebhookCreation,
+        auth: &AuthCtx,
+    ) -> Result<LifecycleWebhookWithSecret, LifecycleWebhookError> {
+        let environment = self.get_parent_environment(environment_id, auth).await?;
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::CreateLifecycleWebhook,
+        )?;
+
+        validate_url(&data.url, self.config.allow_private_targets)?;
+
+        let webhook = LifecycleWebhookWithSecret {
+            id: LifecycleWebhookId::new(),
+            environment_id,
+            url: data.url,
+            event_types: data.event_types,
+            created_at: Utc::now(),
+            signing_secret: TokenSecret::new().into_secret(),
+        };
+
+        let record = LifecycleWebhookRecord::from_model(
+            webhook,
+            ImmutableAuditFields::new(auth.account_id().0),
+        );
+
+        Ok(self.lifecycle_webhook_repo.create(record).await?.into())
+    }
+
+    pub async fn delete(
+        &self,
+        webhook_id: LifecycleWebhookId,
+        auth: &AuthCtx,
+    ) -> Result<LifecycleWebhook, LifecycleWebhookError> {
+        let (_, environment) = self.get_by_id_with_environment(webhook_id, auth).await?;
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::DeleteLifecycleWebhook,
+        )?;
+
+        let deleted: LifecycleWebhook = self
+            .lifecycle_webhook_repo
+            .delete(webhook_id.0, auth.account_id().0)
+            .await?
+            .ok_or(LifecycleWebhookError::LifecycleWebhookNotFound(webhook_id))?
+            .into();
+
+        Ok(deleted)
+    }
+
+    pub async fn get_by_id(
+        &self,
+        webhook_id: LifecycleWebhookId,
+        auth: &AuthCtx,
+    ) -> Result<LifecycleWebhook, LifecycleWebhookError> {
+        Ok(self.get_by_id_with_environment(webhook_id, auth).await?.0)
+    }
+
+    pub async fn list_in_environment(
+        &self,
+        environment_id: EnvironmentId,
+        auth: &AuthCtx,
+    ) -> Result<Vec<LifecycleWebhook>, LifecycleWebhookError> {
+        let environment = self.get_parent_environment(environment_id, auth).await?;
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::ViewLifecycleWebhook,
+        )?;
+
+        let webhooks = self
+            .lifecycle_webhook_repo
+            .list_by_environment(environment_id.0)
+            .await?
+            .into_iter()
+            .map(|r| r.into())
+            .collect();
+
+        Ok(webhooks)
+    }
+
+    /// Returns the latest deliveries of a webhook, newest first
+    pub async fn list_deliveries(
+        &self,
+        webhook_id: LifecycleWebhookId,
+        auth: &AuthCtx,
+    ) -> Result<Vec<LifecycleWebhookDelivery>, LifecycleWebhookError> {
+        self.get_by_id_with_environment(webhook_id, auth).await?;
+
+        let deliveries = self
+            .lifecycle_webhook_repo
+            .list_deliveries(webhook_id.0, DELIVERY_LOG_LIMIT)
+            .await?
+            .into_iter()
+            .map(|r| r.into())
+            .collect();
+
+        Ok(deliveries)
+    }
+
+    /// Records a delivery for every webhook subscribed to the published events.
+    /// The deliveries are sent asynchronously by the delivery loop.
+    pub async fn publish_events(
+        &self,
+        events: Vec<AgentLifecycleEvent>,
+        auth: &AuthCtx,
+    ) -> Result<(), LifecycleWebhookError> {
+        auth.authorize_global_action(GlobalAction::PublishAgentLifecycleEvents)?;
+
+        let mut events_by_environment: HashMap<EnvironmentId, Vec<AgentLifecycleEvent>> =
+            HashMap::new();
+        for event in events {
+            events_by_environment
+                .entry(event.environment_id)
+                .or_default()
+                .push(event);
+        }
+
+        let mut deliveries = Vec::new();
+        for (environment_id, events) in events_by_environment {
+            let webhooks: Vec<LifecycleWebhook> = self
+                .lifecycle_webhook_repo
+                .list_by_environment(environment_id.0)
+                .await?
+                .into_iter()
+                .map(|r| r.into())
+                .collect();
+
+            for webhook in &webhooks {
+                for event in &events {
+                    if webhook.accepts(event.event_type) {
+                        deliveries.push(LifecycleWebhookDeliveryRecord::new(
+                            webhook.id,
+                            event.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        self.lifecycle_webhook_repo
+            .create_deliveries(deliveries)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Periodically attempts all pending deliveries, never returns
+    pub async fn run_delivery_loop(self: Arc<Self>) -> anyhow::Result<()> {
+        let mut interval = tokio::time::interval(self.config.delivery_interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self
+                .deliver_pending()
+                .instrument(info_span!("lifecycle webhook delivery"))
+                .await
+            {
+                error!("Failed to deliver lifecycle webhook events: {err}");
+            }
+        }
+    }
+
+    /// Attempts one batch of due deliveries, returning the number of attempted deliveries
+    pub async fn deliver_pending(&self) -> Result<usize, LifecycleWebhookError> {
+        // deliveries are leased for longer than a request may take, so a crashed registry
+        // does not lose them but they are not attempted twice concurrently
+        let leased_until =
+            Utc::now() + self.config.request_timeout * 2 + self.config.delivery_interval;
+        let deliveries = self
+            .lifecycle_webhook_repo
+            .claim_pending_deliveries(self.config.delivery_batch_size, leased_until.into())
+            .await?;
+
+        let count = deliveries.len();
+        let mut webhooks: HashMap<uuid::Uuid, Option<LifecycleWebhookRecord>> = HashMap::new();
+        for delivery in deliveries {
+            let webhook = match webhooks.get(&delivery.webhook_id) {
+                Some(webhook) => webhook.clone(),
+                None => {
+                    let webhook = self
+                        .lifecycle_webhook_repo
+                        .get_by_id(delivery.webhook_id)
+                        .await?;
+                    webhooks.insert(delivery.webhook_id, webhook.clone());
+                    webhook
+                }
+            };
+            self.deliver(delivery, webhook.as_ref()).await?;
+        }
+
+        Ok(count)
+    }
+
+    async fn deliver(
+        &self,
+        mut delivery: LifecycleWebhookDeliveryRecord,
+        webhook: Option<&LifecycleWebhookRecord>,
+    ) -> Result<(), LifecycleWebhookError> {
+        let now = Utc::now();
+        delivery.attempts += 1;
+        delivery.last_attempt_at = Some(now.into());
+
+        let Some(webhook) = webhook else {
+            // the webhook was deleted since the event was published
+            delivery.status = LifecycleWebhookDeliveryStatusRecord::Failed;
+            delivery.last_error = Some("Lifecycle webhook was deleted".to_string());
+            self.lifecycle_webhook_repo
+                .update_delivery(delivery)
+                .await?;
+            return Ok(());
+        };
+
+        let result = self.send(webhook, &delivery.event.0, now).await;
+
+        match result {
+            Ok(status) if status.is_success() => {
+                debug!(
+                    delivery_id = %delivery.delivery_id,
+                    "Delivered lifecycle event"
+                );
+                delivery.status = LifecycleWebhookDeliveryStatusRecord::Delivered;
+                delivery.last_status_code = Some(status.as_u16() as i32);
+                delivery.last_error = None;
+            }
+            Ok(status) => {
+                delivery.last_status_code = Some(status.as_u16() as i32);
+                delivery.last_error = Some(format!("Webhook responded with status {status}"));
+                self.schedule_retry(&mut delivery, now);
+            }
+            Err(err) => {
+                delivery.last_status_code = None;
+                delivery.last_error = Some(err.to_string());
+                self.schedule_retry(&mut delivery, now);
+            }
+        }
+
+        self.lifecycle_webhook_repo
+            .update_delivery(delivery)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        webhook: &LifecycleWebhookRecord,
+        event: &AgentLifecycleEvent,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<reqwest::StatusCode> {
+        // host names are checked by the DNS resolver of the client, IP addresses are not resolved
+        if !self.config.allow_private_targets {
+            validate_url(&webhook.url, false)?;
+        }
+
+        let payload = serde_json::to_string(event)?;
+        let timestamp = now.timestamp();
+        let signature = sign_payload(&webhook.signing_secret, timestamp, &payload);
+
+        let response = self
+            .http_client
+            .post(&webhook.url)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header("X-Golem-Event-Id", event.id.to_string())
+            .header("X-Golem-Event-Type", event.event_type.to_string())
+            .header("X-Golem-Timestamp", timestamp.to_string())
+            .header("X-Golem-Signature", format!("v1={signature}"))
+            .body(payload)
+            .send()
+            .await?;
+
+        Ok(response.status())
+    }
+
+    fn schedule_retry(&self, delivery: &mut LifecycleWebhookDeliveryRecord, now: DateTime<Utc>) {
+        let attempts = delivery.attempts.max(0) as u32;
+        if attempts >= self.config.max_attempts {
+            warn!(
+                delivery_id = %delivery.delivery_id,
+                webhook_id = %delivery.webhook_id,
+                "Giving up lifecycle event delivery after {attempts} attempts"
+            );
+            delivery.status = LifecycleWebhookDeliveryStatusRecord::Failed;
+        } else {
+            let delay = retry_delay(
+                attempts,
+                self.config.min_retry_delay,
+                self.config.max_retry_delay,
+            );
+            delivery.next_attempt_at = SqlDateTime::new(now + delay);
+        }
+    }
+
+    async fn get_parent_environment(
+        &self,
+        environment_id: EnvironmentId,
+        auth: &AuthCtx,
+    ) -> Result<Environment, LifecycleWebhookError> {
+        self.environment_service
+            .get(environment_id, false, auth)
+            .await
+            .map_err(|err| match err {
+                EnvironmentError::EnvironmentNotFound(environment_id) => {
+                    LifecycleWebhookError::ParentEnvironmentNotFound(environment_id)
+                }
+                other => other.into(),
+            })
+    }
+
+    async fn get_by_id_with_environment(
+        &self,
+        webhook_id: LifecycleWebhookId,
+        auth: &AuthCtx,
+    ) -> Result<(LifecycleWebhook, Environment), LifecycleWebhookError> {
+        let webhook: LifecycleWebhook = self
+            .lifecycle_webhook_repo
+            .get_by_id(webhook_id.0)
+            .await?
+            .ok_or(LifecycleWebhookError::LifecycleWebhookNotFound(webhook_id))?
+            .into();
+
+        let environment = self
+            .environment_service
+            .get(webhook.environment_id, false, auth)
+            .await
+            .map_err(|err| match err {
+                EnvironmentError::EnvironmentNotFound(_) => {
+                    LifecycleWebhookError::LifecycleWebhookNotFound(webhook_id)
+                }
+                other => other.into(),
+            })?;
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::ViewLifecycleWebhook,
+        )
+        .map_err(|_| LifecycleWebhookError::LifecycleWebhookNotFound(webhook_id))?;
+
+        Ok((webhook, environment))
+    }
+}
+
+fn validate_url(url: &str, allow_private_targets: bool) -> Result<(), LifecycleWebhookError> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|err| LifecycleWebhookError::InvalidUrl(err.to_string()))?;
+    match parsed.scheme() {
+        "http" | "https" => {}
+        other => {
+            return Err(LifecycleWebhookError::InvalidUrl(format!(
+                "unsupported scheme {other}"
+            )));
+        }
+    }
+
+    let ip = match parsed.host() {
+        Some(url::Host::Ipv4(ip)) => Some(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => Some(IpAddr::V6(ip)),
+        _ => None,
+    };
+    if let Some(ip) = ip
+        && !allow_private_targets
+        && !is_public_address(ip)
+    {
+        return Err(LifecycleWebhookError::InvalidUrl(format!(
+            "{ip} is not a public address"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whether the address can be reached from the public internet. Loopback, private, link-local
+/// and other special purpose addresses are not public.
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            let shared = first == 100 && (second & 0b1100_0000) == 64;
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_address(IpAddr::V4(ip)),
+            None => {
+                let first_segment = ip.segments()[0];
+                let unique_local = (first_segment & 0xfe00) == 0xfc00;
+                let link_local = (first_segment & 0xffc0) == 0xfe80;
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || unique_local
+                    || link_local)
+            }
+        },
+    }
+}
+
+/// Resolves the host names of webhook urls to their public addresses only, so the delivery
+/// requests cannot reach the internal network of the registry
+struct PublicAddressResolver;
+
+impl reqwest::dns::Resolve for PublicAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_address(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(
+                    format!("{} does not resolve to a public address", name.as_str()).into(),
+                );
+            }
+            let addrs: reqwest::dns::Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Hex encoded HMAC-SHA256 of `{timestamp}.{payload}`, sent in the `X-Golem-Signature` header
+/// so receivers can verify the origin and freshness of the events.
+pub fn sign_payload(signing_secret: &str, timestamp: i64, payload: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Exponential backoff delay before the next attempt, after the given number of failed attempts
+fn retry_delay(attempts: u32, min_delay: Duration, max_delay: Duration) -> Duration {
+    let multiplier = 2u32.saturating_pow(attempts.saturating_sub(1));
+    min_delay.saturating_mul(multiplier).min(max_delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_public_address, retry_delay, sign_payload, validate_url};
+    use std::time::Duration;
+    use test_r::test;
+
+    #[test]
+    fn special_purpose_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_address(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in ["93.184.216.34", "8.8.8.8", "2606:4700::1111"] {
+            assert!(is_public_address(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn urls_with_private_addresses_are_rejected() {
+        assert!(validate_url("https://example.com/hook", false).is_ok());
+        assert!(validate_url("https://93.184.216.34/hook", false).is_ok());
+        assert!(validate_url("http://127.0.0.1:8080/hook", false).is_err());
+        assert!(validate_url("http://169.254.169.254/latest/meta-data", false).is_err());
+        assert!(validate_url("http://[::1]/hook", false).is_err());
+        assert!(validate_url("http://127.0.0.1:8080/hook", true).is_ok());
+        assert!(validate_url("ftp://example.com/hook", true).is_err());
+    }
+
+    #[test]
+    fn signature_covers_timestamp_and_payload() {
+        let signature = sign_payload("secret", 1700000000, r#"{"a":1}"#);
+        assert_eq!(signature.len(), 64);
+        assert_eq!(signature, sign_payload("secret", 1700000000, r#"{"a":1}"#));
+        assert_ne!(signature, sign_payload("secret", 1700000001, r#"{"a":1}"#));
+        assert_ne!(signature, sign_payload("secret", 1700000000, r#"{"a":2}"#));
+        assert_ne!(signature, sign_payload("other", 1700000000, r#"{"a":1}"#));
+    }
+
+    #[test]
+    fn retry_delay_grows_exponentially_up_to_max() {
+        let min = Duration::from_secs(10);
+        let max = Duration::from_secs(100);
+        assert_eq!(retry_delay(1, min, max), Duration::from_secs(10));
+        assert_eq!(retry_delay(2, min, max), Duration::from_secs(20));
+        assert_eq!(retry_delay(3, min, max), Duration::from_secs(40));
+        assert_eq!(retry_delay(5, min, max), max);
+        assert_eq!(retry_delay(100, min, max), max);
+    }
+}
@@ -20,12 +20,14 @@ pub mod component;
 pub mod component_compilation;
 pub mod component_object_store;
 pub mod component_resolver;
+pub mod component_transformer;
 pub mod deployment;
 pub mod domain_registration;
 pub mod environment;
 pub mod environment_plugin_grant;
 pub mod environment_share;
 pub mod http_api_deployment;
+pub mod lifecycle_webhook;
 pub mod mcp_deployment;
 pub mod oauth2;
 pub mod oauth2_github_client;
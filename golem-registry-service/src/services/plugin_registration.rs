@@ -19,7 +19,8 @@ use crate::repo::model::plugin::PluginRecord;
 use crate::repo::plugin::PluginRepo;
 use golem_common::model::account::AccountId;
 use golem_common::model::plugin_registration::{
-    OplogProcessorPluginSpec, PluginRegistrationCreation, PluginRegistrationId, PluginSpecDto,
+    ComponentTransformerPluginSpec, LibraryPluginSpec, OplogProcessorPluginSpec,
+    PluginRegistrationCreation, PluginRegistrationId, PluginSpecDto,
 };
 use golem_common::{SafeDisplay, error_forwarding};
 use golem_service_base::model::auth::AccountAction;
@@ -34,6 +35,12 @@ pub enum PluginRegistrationError {
     PluginRegistrationNotFound(PluginRegistrationId),
     #[error("Target component for oplog processor does not exist")]
     OplogProcessorComponentDoesNotExist,
+    #[error("Target component for component transformer does not exist")]
+    ComponentTransformerComponentDoesNotExist,
+    #[error("Invalid component transformer: {0}")]
+    InvalidComponentTransformer(String),
+    #[error("Library component does not exist")]
+    LibraryComponentDoesNotExist,
     #[error("Plugin with this name and version already exists")]
     PluginNameAndVersionAlreadyExists,
     #[error("Parent account {0} not found")]
@@ -49,6 +56,9 @@ impl SafeDisplay for PluginRegistrationError {
         match self {
             Self::PluginRegistrationNotFound(_) => self.to_string(),
             Self::OplogProcessorComponentDoesNotExist => self.to_string(),
+            Self::ComponentTransformerComponentDoesNotExist => self.to_string(),
+            Self::InvalidComponentTransformer(_) => self.to_string(),
+            Self::LibraryComponentDoesNotExist => self.to_string(),
             Self::PluginNameAndVersionAlreadyExists => self.to_string(),
             Self::ParentAccountNotFound(_) => self.to_string(),
             Self::Unauthorized(inner) => inner.to_safe_string(),
@@ -106,6 +116,15 @@ impl PluginRegistrationService {
                 self.validate_oplog_processor_plugin(&inner, auth).await?;
                 PluginSpec::OplogProcessor(inner)
             }
+            PluginSpecDto::ComponentTransformer(inner) => {
+                self.validate_component_transformer_plugin(&inner, auth)
+                    .await?;
+                PluginSpec::ComponentTransformer(inner)
+            }
+            PluginSpecDto::Library(inner) => {
+                self.validate_library_plugin(&inner, auth).await?;
+                PluginSpec::Library(inner)
+            }
         };
 
         let id = PluginRegistrationId::new();
@@ -140,9 +159,7 @@ impl PluginRegistrationService {
         plugin_id: PluginRegistrationId,
         auth: &AuthCtx,
     ) -> Result<PluginRegistration, PluginRegistrationError> {
-        let plugin = self.get_plugin(plugin_id, false, auth).await?;
-
-        auth.authorize_account_action(plugin.account_id, AccountAction::DeletePlugin)?;
+        self.get_plugin_for_unregistration(plugin_id, auth).await?;
 
         let plugin = self
             .plugin_repo
@@ -156,6 +173,19 @@ impl PluginRegistrationService {
         Ok(plugin)
     }
 
+    /// Gets the plugin, ensuring that it can be unregistered
+    pub async fn get_plugin_for_unregistration(
+        &self,
+        plugin_id: PluginRegistrationId,
+        auth: &AuthCtx,
+    ) -> Result<PluginRegistration, PluginRegistrationError> {
+        let plugin = self.get_plugin(plugin_id, false, auth).await?;
+
+        auth.authorize_account_action(plugin.account_id, AccountAction::DeletePlugin)?;
+
+        Ok(plugin)
+    }
+
     pub async fn get_plugin(
         &self,
         plugin_id: PluginRegistrationId,
@@ -243,4 +273,68 @@ impl PluginRegistrationService {
 
         Ok(())
     }
+
+    async fn validate_component_transformer_plugin(
+        &self,
+        definition: &ComponentTransformerPluginSpec,
+        auth: &AuthCtx,
+    ) -> Result<(), PluginRegistrationError> {
+        match (
+            &definition.transform_url,
+            definition.component_id,
+            definition.component_revision,
+        ) {
+            (Some(transform_url), None, None) => {
+                url::Url::parse(transform_url).map_err(|err| {
+                    PluginRegistrationError::InvalidComponentTransformer(format!(
+                        "invalid transform URL: {err}"
+                    ))
+                })?;
+                Ok(())
+            }
+            (None, Some(component_id), Some(component_revision)) => {
+                // Same as for oplog processors, any component the user _currently_ has access to
+                // can be used as an in-process transformer.
+                self.component_service
+                    .get_component_revision(component_id, component_revision, false, auth)
+                    .await
+                    .map_err(|err| match err {
+                        ComponentError::ComponentNotFound(_) => {
+                            PluginRegistrationError::ComponentTransformerComponentDoesNotExist
+                        }
+                        other => other.into(),
+                    })?;
+                Ok(())
+            }
+            _ => Err(PluginRegistrationError::InvalidComponentTransformer(
+                "either a transform URL or a component id and revision must be specified"
+                    .to_string(),
+            )),
+        }
+    }
+
+    async fn validate_library_plugin(
+        &self,
+        definition: &LibraryPluginSpec,
+        auth: &AuthCtx,
+    ) -> Result<(), PluginRegistrationError> {
+        // Whether the library can actually be composed into a component is only known when it
+        // gets installed, as it depends on the imports of the installing component.
+        self.component_service
+            .get_component_revision(
+                definition.component_id,
+                definition.component_revision,
+                false,
+                auth,
+            )
+            .await
+            .map_err(|err| match err {
+                ComponentError::ComponentNotFound(_) => {
+                    PluginRegistrationError::LibraryComponentDoesNotExist
+                }
+                other => other.into(),
+            })?;
+
+        Ok(())
+    }
 }
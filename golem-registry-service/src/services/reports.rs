@@ -12,17 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::environment::{EnvironmentError, EnvironmentService};
+use crate::repo::model::reports::{AgentUsageRecord, hour_start};
 use crate::repo::reports::ReportsRepo;
-use golem_common::model::reports::{AccountCountsReport, AccountSummaryReport};
+use chrono::{DateTime, TimeDelta, Utc};
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::reports::{
+    AccountCountsReport, AccountSummaryReport, EnvironmentUsageReport,
+};
 use golem_common::{SafeDisplay, error_forwarding};
-use golem_service_base::model::auth::GlobalAction;
+use golem_service_base::model::AgentUsageUpdate;
 use golem_service_base::model::auth::{AuthCtx, AuthorizationError};
+use golem_service_base::model::auth::{EnvironmentAction, GlobalAction};
 use golem_service_base::repo::RepoError;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ReportsError {
+    #[error("Parent environment {0} not found")]
+    ParentEnvironmentNotFound(EnvironmentId),
+    #[error("Invalid usage report range: {0}")]
+    InvalidRange(String),
     #[error(transparent)]
     Unauthorized(#[from] AuthorizationError),
     #[error(transparent)]
@@ -32,21 +43,30 @@ pub enum ReportsError {
 impl SafeDisplay for ReportsError {
     fn to_safe_string(&self) -> String {
         match self {
+            Self::ParentEnvironmentNotFound(_) => self.to_string(),
+            Self::InvalidRange(_) => self.to_string(),
             Self::Unauthorized(_) => self.to_string(),
             Self::InternalError(_) => "Internal error".to_string(),
         }
     }
 }
 
-error_forwarding!(ReportsError, RepoError);
+error_forwarding!(ReportsError, RepoError, EnvironmentError);
 
 pub struct ReportsService {
     reports_repo: Arc<dyn ReportsRepo>,
+    environment_service: Arc<EnvironmentService>,
 }
 
 impl ReportsService {
-    pub fn new(reports_repo: Arc<dyn ReportsRepo>) -> Self {
-        Self { reports_repo }
+    pub fn new(
+        reports_repo: Arc<dyn ReportsRepo>,
+        environment_service: Arc<EnvironmentService>,
+    ) -> Self {
+        Self {
+            reports_repo,
+            environment_service,
+        }
     }
 }
 
@@ -78,4 +98,81 @@ impl ReportsService {
 
         Ok(account_counts)
     }
+
+    /// Stores usage flushed by the executors in the bucket of the current hour
+    pub async fn record_agent_usage(
+        &self,
+        updates: Vec<AgentUsageUpdate>,
+        auth: &AuthCtx,
+    ) -> Result<(), ReportsError> {
+        auth.authorize_global_action(GlobalAction::RecordAgentUsage)?;
+
+        let now = Utc::now();
+        let records = updates
+            .into_iter()
+            .filter(|update| !update.usage.is_empty())
+            .map(|update| AgentUsageRecord::from_update(update, now))
+            .collect();
+
+        self.reports_repo.add_agent_usage(records).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_environment_usage(
+        &self,
+        environment_id: EnvironmentId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        auth: &AuthCtx,
+    ) -> Result<EnvironmentUsageReport, ReportsError> {
+        if from >= to {
+            return Err(ReportsError::InvalidRange(
+                "from must be earlier than to".to_string(),
+            ));
+        }
+
+        let environment = self
+            .environment_service
+            .get(environment_id, false, auth)
+            .await
+            .map_err(|err| match err {
+                EnvironmentError::EnvironmentNotFound(environment_id) => {
+                    ReportsError::ParentEnvironmentNotFound(environment_id)
+                }
+                other => other.into(),
+            })?;
+
+        auth.authorize_environment_action(
+            environment.owner_account_id,
+            &environment.roles_from_active_shares,
+            EnvironmentAction::ViewUsage,
+        )?;
+
+        // usage is stored in hourly buckets, the range is extended to whole hours
+        let from = hour_start(from);
+        let to = {
+            let start = hour_start(to);
+            if start == to {
+                to
+            } else {
+                start + TimeDelta::hours(1)
+            }
+        };
+
+        let agent_types = self
+            .reports_repo
+            .get_agent_usage(environment_id.0, from.into(), to.into())
+            .await?
+            .into_iter()
+            .map(|r| r.into())
+            .collect();
+
+        Ok(EnvironmentUsageReport {
+            environment_id,
+            from,
+            to,
+            agent_types,
+        })
+    }
 }
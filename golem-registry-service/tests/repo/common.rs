@@ -14,18 +14,24 @@
 
 use crate::repo::Deps;
 use assert2::{assert, check, let_assert};
-use chrono::Utc;
+use chrono::{TimeDelta, Utc};
 use futures::future::join_all;
 use golem_common::base_model::Empty;
+use golem_common::model::WorkerId;
 use golem_common::model::agent::{
     AgentConstructor, AgentMode, AgentType, AgentTypeName, DataSchema, NamedElementSchemas,
     Snapshotting,
 };
 use golem_common::model::auth::EnvironmentRole;
-use golem_common::model::component::ComponentFilePermissions;
+use golem_common::model::component::{ComponentFilePermissions, ComponentId, ComponentRevision};
 use golem_common::model::component_metadata::ComponentMetadata;
+use golem_common::model::environment::EnvironmentId;
 use golem_common::model::environment_share::EnvironmentShareId;
 use golem_common::model::http_api_deployment::HttpApiDeploymentAgentOptions;
+use golem_common::model::lifecycle_webhook::{
+    AgentLifecycleEvent, AgentLifecycleEventId, AgentLifecycleEventType, LifecycleWebhookId,
+    LifecycleWebhookWithSecret,
+};
 use golem_registry_service::repo::environment::EnvironmentRevisionRecord;
 use golem_registry_service::repo::model::account::{
     AccountExtRevisionRecord, AccountRepoError, AccountRevisionRecord,
@@ -50,11 +56,15 @@ use golem_registry_service::repo::model::hash::SqlBlake3Hash;
 use golem_registry_service::repo::model::http_api_deployment::{
     HttpApiDeploymentData, HttpApiDeploymentRepoError, HttpApiDeploymentRevisionRecord,
 };
+use golem_registry_service::repo::model::lifecycle_webhook::{
+    LifecycleWebhookDeliveryRecord, LifecycleWebhookDeliveryStatusRecord, LifecycleWebhookRecord,
+};
 use golem_registry_service::repo::model::mcp_deployment::{
     McpDeploymentData, McpDeploymentRepoError, McpDeploymentRevisionRecord,
 };
 use golem_registry_service::repo::model::new_repo_uuid;
 use golem_registry_service::repo::model::plugin::PluginRecord;
+use golem_registry_service::repo::model::reports::{AgentUsageRecord, hour_start};
 use golem_service_base::repo::blob::Blob;
 use std::collections::{BTreeMap, BTreeSet};
 use std::default::Default;
@@ -298,6 +308,7 @@ pub async fn test_environment_create(deps: &Deps) {
         compatibility_check: false,
         version_check: false,
         security_overrides: false,
+        network_egress_policy: None,
         hash: SqlBlake3Hash::empty(),
     }
     .with_updated_hash();
@@ -357,6 +368,7 @@ pub async fn test_environment_create_concurrently(deps: &Deps) {
                             compatibility_check: false,
                             version_check: false,
                             security_overrides: false,
+                            network_egress_policy: None,
                             hash: SqlBlake3Hash::empty(),
                         },
                     )
@@ -394,6 +406,7 @@ pub async fn test_environment_update(deps: &Deps) {
         compatibility_check: true,
         version_check: true,
         security_overrides: false,
+        network_egress_policy: None,
         hash: SqlBlake3Hash::empty(),
     }
     .with_updated_hash();
@@ -450,6 +463,7 @@ pub async fn test_environment_update(deps: &Deps) {
         compatibility_check: true,
         version_check: true,
         security_overrides: false,
+        network_egress_policy: None,
         hash: SqlBlake3Hash::empty(),
     }
     .with_updated_hash();
@@ -519,6 +533,7 @@ pub async fn test_environment_update_concurrently(deps: &Deps) {
                         compatibility_check: false,
                         version_check: false,
                         security_overrides: false,
+                        network_egress_policy: None,
                         hash: SqlBlake3Hash::empty(),
                     })
                     .await
@@ -614,6 +629,9 @@ pub async fn test_component_stage(deps: &Deps) {
         local_agent_config: Blob::new(Vec::new()),
         object_store_key: "xys".to_string(),
         binary_hash: blake3::hash("test".as_bytes()).into(),
+        original_object_store_key: None,
+        transformations: Default::default(),
+        agent_resource_limits: Default::default(),
         plugins: vec![],
         files: vec![ComponentFileRecord {
             component_id,
@@ -1105,6 +1123,9 @@ pub async fn test_account_usage(deps: &Deps) {
                     local_agent_config: Blob::new(Vec::new()),
                     object_store_key: "".to_string(),
                     binary_hash: SqlBlake3Hash::empty(),
+                    original_object_store_key: None,
+                    transformations: Default::default(),
+                    agent_resource_limits: Default::default(),
                     plugins: vec![],
                     files: vec![],
                 },
@@ -1200,6 +1221,7 @@ async fn setup_resolve_env(deps: &Deps) -> ResolveTestEnv {
                 compatibility_check: false,
                 version_check: false,
                 security_overrides: false,
+                network_egress_policy: None,
                 hash: SqlBlake3Hash::empty(),
             },
         )
@@ -1232,6 +1254,9 @@ async fn setup_resolve_env(deps: &Deps) -> ResolveTestEnv {
                 config_vars: Default::default(),
                 object_store_key: "".to_string(),
                 binary_hash: SqlBlake3Hash::empty(),
+                original_object_store_key: None,
+                transformations: Default::default(),
+                agent_resource_limits: Default::default(),
                 plugins: vec![],
                 files: vec![],
                 local_agent_config: Blob::new(vec![]),
@@ -1422,6 +1447,7 @@ pub async fn test_resolve_agent_type_no_deployment_returns_none(deps: &Deps) {
                 compatibility_check: false,
                 version_check: false,
                 security_overrides: false,
+                network_egress_policy: None,
                 hash: SqlBlake3Hash::empty(),
             },
         )
@@ -1666,3 +1692,217 @@ pub async fn test_mcp_deployment_list_and_delete(deps: &Deps) {
 
     assert!(deployments.len() == 1);
 }
+
+pub async fn test_agent_usage(deps: &Deps) {
+    let environment_id = new_repo_uuid();
+    let component_id = new_repo_uuid();
+    let current_hour = hour_start(Utc::now());
+    let previous_hour = current_hour - TimeDelta::hours(1);
+
+    let record =
+        |agent_type: &str, period_start: chrono::DateTime<Utc>, value: u64| AgentUsageRecord {
+            environment_id,
+            component_id,
+            agent_type: agent_type.to_string(),
+            period_start: period_start.into(),
+            invocations: value.into(),
+            fuel: (value * 100).into(),
+            invocation_time_millis: (value * 10).into(),
+            oplog_bytes: (value * 1000).into(),
+            outgoing_http_requests: value.into(),
+        };
+
+    deps.reports_repo
+        .add_agent_usage(vec![
+            record("agent-a", current_hour, 1),
+            record("agent-b", current_hour, 5),
+            record("agent-a", previous_hour, 7),
+        ])
+        .await
+        .unwrap();
+    // usage of the same bucket is added to the already stored values
+    deps.reports_repo
+        .add_agent_usage(vec![record("agent-a", current_hour, 2)])
+        .await
+        .unwrap();
+
+    let current_hour_usage = deps
+        .reports_repo
+        .get_agent_usage(
+            environment_id,
+            current_hour.into(),
+            (current_hour + TimeDelta::hours(1)).into(),
+        )
+        .await
+        .unwrap();
+
+    let_assert!([agent_a, agent_b] = current_hour_usage.as_slice());
+    check!(agent_a.agent_type == "agent-a");
+    check!(agent_a.invocations.get() == 3);
+    check!(agent_a.fuel.get() == 300);
+    check!(agent_a.invocation_time_millis.get() == 30);
+    check!(agent_a.oplog_bytes.get() == 3000);
+    check!(agent_a.outgoing_http_requests.get() == 3);
+    check!(agent_b.agent_type == "agent-b");
+    check!(agent_b.invocations.get() == 5);
+
+    let all_usage = deps
+        .reports_repo
+        .get_agent_usage(
+            environment_id,
+            previous_hour.into(),
+            (current_hour + TimeDelta::hours(1)).into(),
+        )
+        .await
+        .unwrap();
+
+    let_assert!([agent_a, agent_b] = all_usage.as_slice());
+    check!(agent_a.invocations.get() == 10);
+    check!(agent_b.invocations.get() == 5);
+
+    let other_environment_usage = deps
+        .reports_repo
+        .get_agent_usage(
+            new_repo_uuid(),
+            previous_hour.into(),
+            (current_hour + TimeDelta::hours(1)).into(),
+        )
+        .await
+        .unwrap();
+    check!(other_environment_usage.is_empty());
+}
+
+pub async fn test_lifecycle_webhooks(deps: &Deps) {
+    let user = deps.create_account().await;
+    let app = deps.create_application(user.revision.account_id).await;
+    let env = deps.create_env(app.revision.application_id).await;
+    let environment_id = EnvironmentId(env.revision.environment_id);
+
+    let webhook = LifecycleWebhookRecord::from_model(
+        LifecycleWebhookWithSecret {
+            id: LifecycleWebhookId::new(),
+            environment_id,
+            url: "https://example.com/hooks".to_string(),
+            event_types: BTreeSet::from([
+                AgentLifecycleEventType::Failed,
+                AgentLifecycleEventType::Exited,
+            ]),
+            created_at: Utc::now(),
+            signing_secret: "secret".to_string(),
+        },
+        ImmutableAuditFields::new(user.revision.account_id),
+    );
+
+    let created = deps
+        .lifecycle_webhook_repo
+        .create(webhook.clone())
+        .await
+        .unwrap();
+    check!(created.webhook_id == webhook.webhook_id);
+    check!(created.event_types == webhook.event_types);
+
+    let webhooks = deps
+        .lifecycle_webhook_repo
+        .list_by_environment(environment_id.0)
+        .await
+        .unwrap();
+    let_assert!([listed] = webhooks.as_slice());
+    check!(listed.url == webhook.url);
+
+    let event = |event_type: AgentLifecycleEventType| AgentLifecycleEvent {
+        id: AgentLifecycleEventId::new(),
+        environment_id,
+        worker_id: WorkerId {
+            component_id: ComponentId::new(),
+            worker_name: "agent-1".to_string(),
+        },
+        agent_type: None,
+        event_type,
+        component_revision: ComponentRevision::INITIAL,
+        timestamp: Utc::now(),
+        error: None,
+    };
+    let webhook_id = LifecycleWebhookId(webhook.webhook_id);
+
+    let failed = event(AgentLifecycleEventType::Failed);
+    deps.lifecycle_webhook_repo
+        .create_deliveries(vec![
+            LifecycleWebhookDeliveryRecord::new(webhook_id, failed.clone()),
+            LifecycleWebhookDeliveryRecord::new(webhook_id, event(AgentLifecycleEventType::Exited)),
+        ])
+        .await
+        .unwrap();
+
+    // events published again by the executors do not create new deliveries
+    deps.lifecycle_webhook_repo
+        .create_deliveries(vec![LifecycleWebhookDeliveryRecord::new(
+            webhook_id, failed,
+        )])
+        .await
+        .unwrap();
+
+    let leased_until = SqlDateTime::new(Utc::now() + TimeDelta::minutes(1));
+    let claimed = deps
+        .lifecycle_webhook_repo
+        .claim_pending_deliveries(10, leased_until.clone())
+        .await
+        .unwrap();
+    let_assert!([first, second] = claimed.as_slice());
+    check!(first.next_attempt_at == leased_until);
+    check!(second.next_attempt_at == leased_until);
+
+    // leased deliveries are not claimed again until the lease expires
+    let claimed_again = deps
+        .lifecycle_webhook_repo
+        .claim_pending_deliveries(10, leased_until.clone())
+        .await
+        .unwrap();
+    check!(claimed_again.is_empty());
+
+    deps.lifecycle_webhook_repo
+        .update_delivery(LifecycleWebhookDeliveryRecord {
+            status: LifecycleWebhookDeliveryStatusRecord::Delivered,
+            attempts: 1,
+            last_status_code: Some(200),
+            last_attempt_at: Some(SqlDateTime::now()),
+            ..first.clone()
+        })
+        .await
+        .unwrap();
+
+    let deliveries = deps
+        .lifecycle_webhook_repo
+        .list_deliveries(webhook.webhook_id, 10)
+        .await
+        .unwrap();
+    check!(deliveries.len() == 2);
+    let_assert!(
+        Some(delivered) = deliveries
+            .iter()
+            .find(|delivery| delivery.delivery_id == first.delivery_id)
+    );
+    check!(delivered.status == LifecycleWebhookDeliveryStatusRecord::Delivered);
+    check!(delivered.attempts == 1);
+    check!(delivered.last_status_code == Some(200));
+
+    let deleted = deps
+        .lifecycle_webhook_repo
+        .delete(webhook.webhook_id, user.revision.account_id)
+        .await
+        .unwrap();
+    check!(deleted.is_some());
+
+    let after_delete = deps
+        .lifecycle_webhook_repo
+        .get_by_id(webhook.webhook_id)
+        .await
+        .unwrap();
+    check!(after_delete.is_none());
+
+    let webhooks = deps
+        .lifecycle_webhook_repo
+        .list_by_environment(environment_id.0)
+        .await
+        .unwrap();
+    check!(webhooks.is_empty());
+}
@@ -21,6 +21,7 @@ use golem_registry_service::repo::deployment::DeploymentRepo;
 use golem_registry_service::repo::environment::EnvironmentRepo;
 use golem_registry_service::repo::environment_share::EnvironmentShareRepo;
 use golem_registry_service::repo::http_api_deployment::HttpApiDeploymentRepo;
+use golem_registry_service::repo::lifecycle_webhook::LifecycleWebhookRepo;
 use golem_registry_service::repo::mcp_deployment::McpDeploymentRepo;
 use golem_registry_service::repo::model::account::{
     AccountExtRevisionRecord, AccountRevisionRecord,
@@ -36,6 +37,7 @@ use golem_registry_service::repo::model::new_repo_uuid;
 use golem_registry_service::repo::model::plan::PlanRecord;
 use golem_registry_service::repo::plan::PlanRepo;
 use golem_registry_service::repo::plugin::PluginRepo;
+use golem_registry_service::repo::reports::ReportsRepo;
 use std::str::FromStr;
 use test_r::{inherit_test_dep, sequential_suite};
 use uuid::Uuid;
@@ -62,6 +64,8 @@ pub struct Deps {
     pub full_deployment_repo: Box<dyn DeploymentRepo>,
     pub environment_share_repo: Box<dyn EnvironmentShareRepo>,
     pub plugin_repo: Box<dyn PluginRepo>,
+    pub reports_repo: Box<dyn ReportsRepo>,
+    pub lifecycle_webhook_repo: Box<dyn LifecycleWebhookRepo>,
 }
 
 impl Deps {
@@ -140,6 +144,7 @@ impl Deps {
                     compatibility_check: true,
                     version_check: true,
                     security_overrides: true,
+                    network_egress_policy: None,
                     hash: blake3::hash("test".as_bytes()).into(),
                 },
             )
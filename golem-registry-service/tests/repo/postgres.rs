@@ -23,9 +23,11 @@ use golem_registry_service::repo::deployment::DbDeploymentRepo;
 use golem_registry_service::repo::environment::DbEnvironmentRepo;
 use golem_registry_service::repo::environment_share::DbEnvironmentShareRepo;
 use golem_registry_service::repo::http_api_deployment::DbHttpApiDeploymentRepo;
+use golem_registry_service::repo::lifecycle_webhook::DbLifecycleWebhookRepo;
 use golem_registry_service::repo::mcp_deployment::DbMcpDeploymentRepo;
 use golem_registry_service::repo::plan::DbPlanRepo;
 use golem_registry_service::repo::plugin::DbPluginRepo;
+use golem_registry_service::repo::reports::DbReportsRepo;
 use golem_service_base::db;
 use golem_service_base::db::postgres::PostgresPool;
 use golem_service_base::migration::{Migrations, MigrationsDir};
@@ -164,6 +166,8 @@ async fn deps(db: &PostgresDb) -> Deps {
         full_deployment_repo: Box::new(DbDeploymentRepo::logged(db.pool.clone())),
         environment_share_repo: Box::new(DbEnvironmentShareRepo::logged(db.pool.clone())),
         plugin_repo: Box::new(DbPluginRepo::logged(db.pool.clone())),
+        reports_repo: Box::new(DbReportsRepo::logged(db.pool.clone())),
+        lifecycle_webhook_repo: Box::new(DbLifecycleWebhookRepo::logged(db.pool.clone())),
     };
     deps.setup().await;
     deps
@@ -265,3 +269,13 @@ async fn test_mcp_deployment_create_and_update(deps: &Deps) {
 async fn test_mcp_deployment_list_and_delete(deps: &Deps) {
     crate::repo::common::test_mcp_deployment_list_and_delete(deps).await;
 }
+
+#[test]
+async fn test_agent_usage(deps: &Deps) {
+    crate::repo::common::test_agent_usage(deps).await;
+}
+
+#[test]
+async fn test_lifecycle_webhooks(deps: &Deps) {
+    crate::repo::common::test_lifecycle_webhooks(deps).await;
+}
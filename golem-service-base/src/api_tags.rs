@@ -36,6 +36,8 @@ pub enum ApiTags {
     Grant,
     HealthCheck,
     HttpApiDefinition,
+    /// Lifecycle webhooks deliver signed events about agent status changes of an environment.
+    LifecycleWebhook,
     /// The limits API allows users to query their current resource limits.
     Limits,
     /// The login endpoints are implementing an OAuth2 flow.
@@ -17,7 +17,9 @@ use crate::grpc::client::{GrpcClient, GrpcClientConfig};
 use crate::mcp::CompiledMcp;
 use crate::model::auth::{AuthCtx, AuthDetailsForEnvironment, UserAuthCtx};
 use crate::model::component::Component;
-use crate::model::{AccountResourceLimits, AgentDeploymentDetails, ResourceLimits};
+use crate::model::{
+    AccountResourceLimits, AgentDeploymentDetails, AgentUsageUpdate, ResourceLimits,
+};
 use async_trait::async_trait;
 use golem_api_grpc::proto::golem::registry::FuelUsageUpdate;
 use golem_api_grpc::proto::golem::registry::v1::registry_service_client::RegistryServiceClient;
@@ -26,18 +28,19 @@ use golem_api_grpc::proto::golem::registry::v1::{
     GetActiveMcpForDomainRequest, GetActiveRoutesForDomainRequest, GetAgentDeploymentsRequest,
     GetAgentTypeRequest, GetAllAgentTypesRequest, GetAllDeployedComponentRevisionsRequest,
     GetAuthDetailsForEnvironmentRequest, GetComponentMetadataRequest,
-    GetDeployedComponentMetadataRequest, GetResourceLimitsRequest,
-    ResolveAgentTypeAtDeploymentRequest, ResolveAgentTypeByNamesRequest, ResolveComponentRequest,
-    UpdateWorkerConnectionLimitRequest, UpdateWorkerLimitRequest, authenticate_token_response,
-    batch_update_fuel_usage_response, download_component_response,
-    get_active_mcp_for_domain_response, get_active_routes_for_domain_response,
-    get_agent_deployments_response, get_agent_type_response, get_all_agent_types_response,
-    get_all_deployed_component_revisions_response, get_auth_details_for_environment_response,
-    get_component_metadata_response, get_deployed_component_metadata_response,
-    get_resource_limits_response, resolve_agent_type_at_deployment_response,
-    resolve_agent_type_by_names_response, resolve_component_response,
-    resolve_latest_agent_type_by_names_response, update_worker_connection_limit_response,
-    update_worker_limit_response,
+    GetDeployedComponentMetadataRequest, GetNetworkEgressPolicyRequest, GetResourceLimitsRequest,
+    PublishAgentLifecycleEventsRequest, ResolveAgentTypeAtDeploymentRequest,
+    ResolveAgentTypeByNamesRequest, ResolveComponentRequest, UpdateWorkerConnectionLimitRequest,
+    UpdateWorkerLimitRequest, authenticate_token_response, batch_update_fuel_usage_response,
+    download_component_response, get_active_mcp_for_domain_response,
+    get_active_routes_for_domain_response, get_agent_deployments_response, get_agent_type_response,
+    get_all_agent_types_response, get_all_deployed_component_revisions_response,
+    get_auth_details_for_environment_response, get_component_metadata_response,
+    get_deployed_component_metadata_response, get_network_egress_policy_response,
+    get_resource_limits_response, publish_agent_lifecycle_events_response,
+    resolve_agent_type_at_deployment_response, resolve_agent_type_by_names_response,
+    resolve_component_response, resolve_latest_agent_type_by_names_response,
+    update_worker_connection_limit_response, update_worker_limit_response,
 };
 use golem_common::config::{ConfigExample, HasConfigExamples};
 use golem_common::model::WorkerId;
@@ -48,7 +51,8 @@ use golem_common::model::auth::TokenSecret;
 use golem_common::model::component::{ComponentId, ComponentRevision};
 use golem_common::model::deployment::DeploymentRevision;
 use golem_common::model::domain_registration::Domain;
-use golem_common::model::environment::{EnvironmentId, EnvironmentName};
+use golem_common::model::environment::{EnvironmentId, EnvironmentName, NetworkEgressPolicy};
+use golem_common::model::lifecycle_webhook::AgentLifecycleEvent;
 use golem_common::{IntoAnyhow, SafeDisplay, grpc_uri};
 use http::Uri;
 use serde::{Deserialize, Serialize};
@@ -96,9 +100,11 @@ pub trait RegistryService: Send + Sync {
 
     // will be a noop if the account no longer exists
     // will return all current limits of updated accounts
+    // agent usage updates are recorded for usage metering and do not affect the returned limits
     async fn batch_update_fuel_usage(
         &self,
         updates: HashMap<AccountId, i64>,
+        agent_usage_updates: Vec<AgentUsageUpdate>,
     ) -> Result<AccountResourceLimits, RegistryServiceError>;
 
     // components api
@@ -194,6 +200,19 @@ pub trait RegistryService: Send + Sync {
         &self,
         environment_id: EnvironmentId,
     ) -> Result<HashMap<AgentTypeName, AgentDeploymentDetails>, RegistryServiceError>;
+
+    // environment api
+    async fn get_network_egress_policy(
+        &self,
+        environment_id: EnvironmentId,
+    ) -> Result<NetworkEgressPolicy, RegistryServiceError>;
+
+    // lifecycle webhooks api
+    // events of environments without lifecycle webhooks are dropped
+    async fn publish_agent_lifecycle_events(
+        &self,
+        events: Vec<AgentLifecycleEvent>,
+    ) -> Result<(), RegistryServiceError>;
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -409,6 +428,7 @@ impl RegistryService for GrpcRegistryService {
     async fn batch_update_fuel_usage(
         &self,
         updates: HashMap<AccountId, i64>,
+        agent_usage_updates: Vec<AgentUsageUpdate>,
     ) -> Result<AccountResourceLimits, RegistryServiceError> {
         let updates: Vec<FuelUsageUpdate> = updates
             .into_iter()
@@ -417,12 +437,15 @@ impl RegistryService for GrpcRegistryService {
                 value: v,
             })
             .collect();
+        let agent_usage_updates: Vec<golem_api_grpc::proto::golem::registry::AgentUsageUpdate> =
+            agent_usage_updates.into_iter().map(|u| u.into()).collect();
 
         let response = self
             .client
             .call("batch_update_fuel_usage", move |client| {
                 let request = BatchUpdateFuelUsageRequest {
                     updates: updates.clone(),
+                    agent_usage_updates: agent_usage_updates.clone(),
                 };
 
                 Box::pin(client.batch_update_fuel_usage(request))
@@ -864,6 +887,58 @@ impl RegistryService for GrpcRegistryService {
             Some(get_agent_deployments_response::Result::Error(error)) => Err(error.into()),
         }
     }
+
+    async fn get_network_egress_policy(
+        &self,
+        environment_id: EnvironmentId,
+    ) -> Result<NetworkEgressPolicy, RegistryServiceError> {
+        let response = self
+            .client
+            .call("get_network_egress_policy", move |client| {
+                let request = GetNetworkEgressPolicyRequest {
+                    environment_id: Some(environment_id.into()),
+                };
+                Box::pin(client.get_network_egress_policy(request))
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            None => Err(RegistryServiceError::empty_response()),
+            Some(get_network_egress_policy_response::Result::Success(payload)) => Ok(payload
+                .network_egress_policy
+                .ok_or("missing network_egress_policy field")?
+                .into()),
+            Some(get_network_egress_policy_response::Result::Error(error)) => Err(error.into()),
+        }
+    }
+
+    async fn publish_agent_lifecycle_events(
+        &self,
+        events: Vec<AgentLifecycleEvent>,
+    ) -> Result<(), RegistryServiceError> {
+        let events: Vec<golem_api_grpc::proto::golem::registry::AgentLifecycleEvent> =
+            events.into_iter().map(|e| e.into()).collect();
+
+        let response = self
+            .client
+            .call("publish_agent_lifecycle_events", move |client| {
+                let request = PublishAgentLifecycleEventsRequest {
+                    events: events.clone(),
+                };
+                Box::pin(client.publish_agent_lifecycle_events(request))
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            None => Err(RegistryServiceError::empty_response()),
+            Some(publish_agent_lifecycle_events_response::Result::Success(_)) => Ok(()),
+            Some(publish_agent_lifecycle_events_response::Result::Error(error)) => {
+                Err(error.into())
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -116,6 +116,11 @@ pub enum WorkerExecutorError {
         error: WorkerError,
         stderr: String,
     },
+    /// The worker's invocation queue reached its configured limit
+    InvocationQueueFull {
+        worker_id: WorkerId,
+        limit: u64,
+    },
 }
 
 impl WorkerExecutorError {
@@ -300,6 +305,12 @@ impl Display for WorkerExecutorError {
             Self::InvocationFailed { error, stderr } => {
                 write!(f, "Component trapped: {}", error.to_string(stderr))
             }
+            Self::InvocationQueueFull { worker_id, limit } => {
+                write!(
+                    f,
+                    "Invocation queue of {worker_id} is full (limit: {limit})"
+                )
+            }
         }
     }
 }
@@ -341,6 +352,7 @@ impl Error for WorkerExecutorError {
             Self::Unknown { .. } => "Unknown error",
             Self::ShardingNotReady => "Sharding not ready",
             Self::FileSystemError { .. } => "File system error",
+            Self::InvocationQueueFull { .. } => "Invocation queue full",
         }
     }
 }
@@ -374,6 +386,7 @@ impl ApiErrorDetails for WorkerExecutorError {
             Self::Unknown { .. } => "Unknown",
             Self::ShardingNotReady => "ShardingNotReady",
             Self::FileSystemError { .. } => "FileSystemError",
+            Self::InvocationQueueFull { .. } => "InvocationQueueFull",
         }
     }
 
@@ -385,7 +398,8 @@ impl ApiErrorDetails for WorkerExecutorError {
             | Self::PromiseDropped { .. }
             | Self::PromiseAlreadyCompleted { .. }
             | Self::Interrupted { .. }
-            | Self::InvalidShardId { .. } => true,
+            | Self::InvalidShardId { .. }
+            | Self::InvocationQueueFull { .. } => true,
             Self::InvalidRequest { .. }
             | Self::WorkerCreationFailed { .. }
             | Self::FailedToResumeWorker { .. }
@@ -459,6 +473,9 @@ impl From<WorkerExecutorError> for Status {
             WorkerExecutorError::PreviousInvocationFailed { .. } => {
                 Self::failed_precondition(format!("{value}"))
             }
+            WorkerExecutorError::InvocationQueueFull { .. } => {
+                Self::resource_exhausted(format!("{value}"))
+            }
             _ => Self::internal(format!("{value}")),
         }
     }
@@ -693,6 +710,16 @@ impl From<WorkerExecutorError> for golem::worker::v1::WorkerExecutionError {
                     }
                 ))
             },
+            WorkerExecutorError::InvocationQueueFull { worker_id, limit } => Self {
+                error: Some(
+                    golem::worker::v1::worker_execution_error::Error::InvocationQueueFull(
+                        golem::worker::v1::InvocationQueueFull {
+                            worker_id: Some(worker_id.into()),
+                            limit,
+                        },
+                    ),
+                ),
+            },
         }
     }
 }
@@ -880,6 +907,15 @@ impl TryFrom<golem::worker::v1::WorkerExecutionError> for WorkerExecutorError {
                 error: inner.error.ok_or("no trap_cause field")?.try_into()?,
                 stderr: inner.stderr
              }),
+            Some(golem::worker::v1::worker_execution_error::Error::InvocationQueueFull(
+                invocation_queue_full,
+            )) => Ok(Self::InvocationQueueFull {
+                worker_id: invocation_queue_full
+                    .worker_id
+                    .ok_or("Missing worker_id")?
+                    .try_into()?,
+                limit: invocation_queue_full.limit,
+            }),
         }
     }
 }
@@ -898,6 +934,11 @@ impl From<EncodingError> for WorkerExecutorError {
 pub enum GolemSpecificWasmTrap {
     WorkerOutOfMemory,
     WorkerExceededMemoryLimit,
+    WorkerExceededAgentMemoryLimit,
+    WorkerExceededInvocationFuelLimit,
+    WorkerExceededInvocationTimeLimit,
+    WorkerExceededOplogSizeLimit,
+    WorkerExceededHttpRequestRateLimit,
 }
 
 impl Display for GolemSpecificWasmTrap {
@@ -905,6 +946,31 @@ impl Display for GolemSpecificWasmTrap {
         match self {
             Self::WorkerOutOfMemory => write!(f, "Worker cannot acquire more memory"),
             Self::WorkerExceededMemoryLimit => write!(f, "Worker exceeded plan memory limits"),
+            Self::WorkerExceededAgentMemoryLimit => {
+                write!(f, "Worker exceeded the memory limit of its agent type")
+            }
+            Self::WorkerExceededInvocationFuelLimit => {
+                write!(
+                    f,
+                    "Worker exceeded the fuel limit per invocation of its agent type"
+                )
+            }
+            Self::WorkerExceededInvocationTimeLimit => {
+                write!(
+                    f,
+                    "Worker exceeded the invocation time limit of its agent type"
+                )
+            }
+            Self::WorkerExceededOplogSizeLimit => {
+                write!(
+                    f,
+                    "Worker exceeded the oplog entries per invocation limit of its agent type"
+                )
+            }
+            Self::WorkerExceededHttpRequestRateLimit => write!(
+                f,
+                "Worker exceeded the outgoing HTTP request rate limit of its agent type"
+            ),
         }
     }
 }
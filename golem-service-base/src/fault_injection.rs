@@ -0,0 +1,290 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fault injection for durability tests.
+//!
+//! The storage and client wrappers built on [`FaultInjector`] delay operations, fail them, or
+//! only partially perform writes according to a seeded random schedule. Using the same seed
+//! produces the same sequence of faults for the same sequence of operations.
+
+use golem_common::SafeDisplay;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::warn;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FaultInjectionConfig {
+    pub enabled: bool,
+    /// Seed of the random fault schedule
+    pub seed: u64,
+    pub key_value_storage: bool,
+    pub indexed_storage: bool,
+    pub blob_storage: bool,
+    pub shard_manager: bool,
+    /// Probability of an operation failing without being performed
+    pub error_probability: f64,
+    /// Probability of a write operation being performed only partially before failing. Batch
+    /// writes store a prefix of their items, single writes are stored but reported as failed.
+    pub partial_write_probability: f64,
+    /// Probability of an operation being delayed by a random duration up to `max_delay`
+    pub delay_probability: f64,
+    #[serde(with = "humantime_serde")]
+    pub max_delay: Duration,
+}
+
+impl Default for FaultInjectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            seed: 0,
+            key_value_storage: true,
+            indexed_storage: true,
+            blob_storage: true,
+            shard_manager: true,
+            error_probability: 0.01,
+            partial_write_probability: 0.01,
+            delay_probability: 0.05,
+            max_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl SafeDisplay for FaultInjectionConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "enabled: {}", self.enabled);
+        let _ = writeln!(&mut result, "seed: {}", self.seed);
+        let _ = writeln!(&mut result, "key-value storage: {}", self.key_value_storage);
+        let _ = writeln!(&mut result, "indexed storage: {}", self.indexed_storage);
+        let _ = writeln!(&mut result, "blob storage: {}", self.blob_storage);
+        let _ = writeln!(&mut result, "shard manager: {}", self.shard_manager);
+        let _ = writeln!(&mut result, "error probability: {}", self.error_probability);
+        let _ = writeln!(
+            &mut result,
+            "partial write probability: {}",
+            self.partial_write_probability
+        );
+        let _ = writeln!(&mut result, "delay probability: {}", self.delay_probability);
+        let _ = writeln!(&mut result, "max delay: {:?}", self.max_delay);
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultInjectionTarget {
+    KeyValueStorage,
+    IndexedStorage,
+    BlobStorage,
+    ShardManager,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    None,
+    Error,
+    PartialWrite,
+}
+
+#[derive(Debug)]
+pub struct FaultInjector {
+    config: FaultInjectionConfig,
+    rng: Mutex<StdRng>,
+}
+
+impl FaultInjector {
+    /// Creates a fault injector, failing if any of the configured probabilities is outside of
+    /// the `[0, 1]` range or if the error and partial write probabilities add up to more than 1
+    pub fn new(config: FaultInjectionConfig) -> Result<Self, String> {
+        for (name, probability) in [
+            ("error_probability", config.error_probability),
+            (
+                "partial_write_probability",
+                config.partial_write_probability,
+            ),
+            ("delay_probability", config.delay_probability),
+        ] {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(format!(
+                    "Fault injection {name} must be between 0 and 1, got {probability}"
+                ));
+            }
+        }
+        if config.error_probability + config.partial_write_probability > 1.0 {
+            return Err(format!(
+                "Fault injection error_probability and partial_write_probability must add up to at most 1, got {} and {}",
+                config.error_probability, config.partial_write_probability
+            ));
+        }
+
+        let rng = Mutex::new(StdRng::seed_from_u64(config.seed));
+        Ok(Self { config, rng })
+    }
+
+    /// Returns a fault injector if fault injection is enabled in the configuration
+    pub fn configured(config: &FaultInjectionConfig) -> Result<Option<Arc<Self>>, String> {
+        if config.enabled {
+            let injector = Self::new(config.clone())?;
+            warn!(
+                "Fault injection is enabled, storage and shard manager operations will randomly fail"
+            );
+            Ok(Some(Arc::new(injector)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn applies_to(&self, target: FaultInjectionTarget) -> bool {
+        match target {
+            FaultInjectionTarget::KeyValueStorage => self.config.key_value_storage,
+            FaultInjectionTarget::IndexedStorage => self.config.indexed_storage,
+            FaultInjectionTarget::BlobStorage => self.config.blob_storage,
+            FaultInjectionTarget::ShardManager => self.config.shard_manager,
+        }
+    }
+
+    /// Decides the fault of the next operation, sleeping first if the operation gets delayed.
+    /// Partial writes are only injected into write operations.
+    pub async fn next_fault(&self, is_write: bool) -> Fault {
+        let (delay, fault) = self.roll(is_write);
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+        fault
+    }
+
+    /// Decides the fault of the next operation without delaying it, for operations that cannot
+    /// wait asynchronously. The delay is still drawn to keep the schedule reproducible.
+    pub fn next_fault_without_delay(&self, is_write: bool) -> Fault {
+        self.roll(is_write).1
+    }
+
+    // The random values are always drawn in the same order to keep the schedule reproducible
+    fn roll(&self, is_write: bool) -> (Option<Duration>, Fault) {
+        let mut rng = self.rng.lock().unwrap();
+        let delayed = rng.random_bool(self.config.delay_probability);
+        let delay = self.config.max_delay.mul_f64(rng.random::<f64>());
+        let fault_roll = rng.random::<f64>();
+
+        let fault = if fault_roll < self.config.error_probability {
+            Fault::Error
+        } else if is_write
+            && fault_roll < self.config.error_probability + self.config.partial_write_probability
+        {
+            Fault::PartialWrite
+        } else {
+            Fault::None
+        };
+
+        (delayed.then_some(delay), fault)
+    }
+
+    pub fn error_message(target: FaultInjectionTarget, op_label: &str) -> String {
+        format!("Injected fault in {target:?} operation {op_label}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fault, FaultInjectionConfig, FaultInjector};
+    use test_r::test;
+
+    #[test]
+    fn same_seed_produces_same_schedule() {
+        let config = FaultInjectionConfig {
+            enabled: true,
+            seed: 42,
+            error_probability: 0.2,
+            partial_write_probability: 0.2,
+            delay_probability: 0.5,
+            ..Default::default()
+        };
+
+        let schedule = |injector: &FaultInjector| {
+            (0..100)
+                .map(|i| injector.roll(i % 2 == 0))
+                .collect::<Vec<_>>()
+        };
+
+        let first = schedule(&FaultInjector::new(config.clone()).unwrap());
+        let second = schedule(&FaultInjector::new(config).unwrap());
+        assert_eq!(first, second);
+        assert!(first.iter().any(|(_, fault)| *fault == Fault::Error));
+        assert!(first.iter().any(|(_, fault)| *fault == Fault::PartialWrite));
+        assert!(first.iter().any(|(_, fault)| *fault == Fault::None));
+    }
+
+    #[test]
+    fn partial_writes_are_only_injected_into_writes() {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            error_probability: 0.0,
+            partial_write_probability: 1.0,
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(injector.roll(false).1, Fault::None);
+        assert_eq!(injector.roll(true).1, Fault::PartialWrite);
+    }
+
+    #[test]
+    fn probabilities_outside_of_the_unit_range_are_rejected() {
+        for config in [
+            FaultInjectionConfig {
+                error_probability: -0.1,
+                ..Default::default()
+            },
+            FaultInjectionConfig {
+                partial_write_probability: 1.5,
+                ..Default::default()
+            },
+            FaultInjectionConfig {
+                delay_probability: 2.0,
+                ..Default::default()
+            },
+            FaultInjectionConfig {
+                delay_probability: f64::NAN,
+                ..Default::default()
+            },
+        ] {
+            assert!(FaultInjector::new(config).is_err());
+        }
+    }
+
+    #[test]
+    fn error_and_partial_write_probabilities_must_not_exceed_one_together() {
+        let config = FaultInjectionConfig {
+            error_probability: 0.6,
+            partial_write_probability: 0.6,
+            ..Default::default()
+        };
+
+        assert!(FaultInjector::new(config).is_err());
+    }
+
+    #[test]
+    fn disabled_fault_injection_creates_no_injector() {
+        let config = FaultInjectionConfig {
+            enabled: false,
+            error_probability: 5.0,
+            ..Default::default()
+        };
+
+        assert!(FaultInjector::configured(&config).unwrap().is_none());
+    }
+}
@@ -19,6 +19,7 @@ pub mod config;
 pub mod custom_api;
 pub mod db;
 pub mod error;
+pub mod fault_injection;
 pub mod grpc;
 pub mod headers;
 pub mod mcp;
@@ -155,6 +155,9 @@ pub enum GlobalAction {
     CreateAccount,
     GetDefaultPlan,
     GetReports,
+    PublishAgentLifecycleEvents,
+    RecordAgentUsage,
+    ViewExecutors,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, strum_macros::Display)]
@@ -194,6 +197,7 @@ pub enum EnvironmentAction {
     CreateEnvironmentPluginGrant,
     CreateHttpApiDefinition,
     CreateHttpApiDeployment,
+    CreateLifecycleWebhook,
     CreateMcpDeployment,
     CreateSecurityScheme,
     CreateShare,
@@ -204,6 +208,7 @@ pub enum EnvironmentAction {
     DeleteEnvironmentPluginGrant,
     DeleteHttpApiDefinition,
     DeleteHttpApiDeployment,
+    DeleteLifecycleWebhook,
     DeleteMcpDeployment,
     DeleteSecurityScheme,
     DeleteShare,
@@ -226,9 +231,11 @@ pub enum EnvironmentAction {
     ViewEnvironmentPluginGrant,
     ViewHttpApiDefinition,
     ViewHttpApiDeployment,
+    ViewLifecycleWebhook,
     ViewMcpDeployment,
     ViewSecurityScheme,
     ViewShares,
+    ViewUsage,
     ViewWorker,
 }
 
@@ -356,6 +363,11 @@ impl AuthCtx {
             GlobalAction::GetReports => {
                 self.has_any_account_role(&[AccountRole::Admin, AccountRole::MarketingAdmin])
             }
+            GlobalAction::PublishAgentLifecycleEvents => {
+                self.has_any_account_role(&[AccountRole::Admin])
+            }
+            GlobalAction::RecordAgentUsage => self.has_any_account_role(&[AccountRole::Admin]),
+            GlobalAction::ViewExecutors => self.has_any_account_role(&[AccountRole::Admin]),
         };
 
         if !is_allowed {
@@ -529,6 +541,21 @@ impl AuthCtx {
                     EnvironmentRole::Viewer,
                 ],
             ),
+            // Lifecycle webhooks
+            EnvironmentAction::CreateLifecycleWebhook => {
+                has_any_role(roles_from_shares, &[EnvironmentRole::Admin])
+            }
+            EnvironmentAction::DeleteLifecycleWebhook => {
+                has_any_role(roles_from_shares, &[EnvironmentRole::Admin])
+            }
+            EnvironmentAction::ViewLifecycleWebhook => has_any_role(
+                roles_from_shares,
+                &[
+                    EnvironmentRole::Admin,
+                    EnvironmentRole::Deployer,
+                    EnvironmentRole::Viewer,
+                ],
+            ),
             // Workers
             EnvironmentAction::CreateWorker => has_any_role(
                 roles_from_shares,
@@ -652,6 +679,15 @@ impl AuthCtx {
                     EnvironmentRole::Viewer,
                 ],
             ),
+            // Usage
+            EnvironmentAction::ViewUsage => has_any_role(
+                roles_from_shares,
+                &[
+                    EnvironmentRole::Admin,
+                    EnvironmentRole::Deployer,
+                    EnvironmentRole::Viewer,
+                ],
+            ),
         };
 
         if !is_allowed {
@@ -18,7 +18,8 @@ use golem_common::model::account::AccountId;
 use golem_common::model::agent::AgentTypeName;
 use golem_common::model::application::ApplicationId;
 use golem_common::model::component::{
-    ComponentId, ComponentName, ComponentRevision, InitialComponentFile, InstalledPlugin,
+    AgentResourceLimits, AppliedComponentTransformation, ComponentId, ComponentName,
+    ComponentRevision, InitialComponentFile, InstalledPlugin,
 };
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::diff;
@@ -96,9 +97,26 @@ pub struct Component {
     pub env: BTreeMap<String, String>,
     pub config_vars: BTreeMap<String, String>,
     pub local_agent_config: Vec<LocalAgentConfigEntry>,
+    pub agent_resource_limits: Vec<AgentResourceLimits>,
     /// Hash of the wasm before any transformations
     pub wasm_hash: diff::Hash,
+    /// Object store key of the wasm after all the transformations
     pub object_store_key: String,
+    /// Object store key of the uploaded wasm, if it differs from `object_store_key`
+    pub original_object_store_key: Option<String>,
+    pub transformations: Vec<AppliedComponentTransformation>,
+}
+
+impl Component {
+    /// Resource limits declared for the given agent type, if any
+    pub fn agent_resource_limits_of(
+        &self,
+        agent_type: &AgentTypeName,
+    ) -> Option<&AgentResourceLimits> {
+        self.agent_resource_limits
+            .iter()
+            .find(|limits| &limits.agent == agent_type)
+    }
 }
 
 impl From<Component> for golem_common::model::component::ComponentDto {
@@ -122,8 +140,10 @@ impl From<Component> for golem_common::model::component::ComponentDto {
                 .into_iter()
                 .map(golem_common::model::component::LocalAgentConfigEntry::from)
                 .collect(),
+            agent_resource_limits: value.agent_resource_limits,
             wasm_hash: value.wasm_hash,
             hash: value.hash,
+            transformations: value.transformations,
         }
     }
 }
@@ -196,8 +216,20 @@ impl TryFrom<golem_api_grpc::proto::golem::component::Component> for Component {
             .map(LocalAgentConfigEntry::try_from)
             .collect::<Result<Vec<_>, _>>()?;
 
+        let agent_resource_limits = value
+            .agent_resource_limits
+            .into_iter()
+            .map(AgentResourceLimits::from)
+            .collect();
+
         let hash = value.hash.ok_or("Missing hash field")?.try_into()?;
 
+        let transformations = value
+            .transformations
+            .into_iter()
+            .map(|t| t.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
         let wasm_hash = value
             .wasm_hash
             .ok_or("Missing wasm hash field")?
@@ -218,9 +250,12 @@ impl TryFrom<golem_api_grpc::proto::golem::component::Component> for Component {
             env,
             config_vars,
             local_agent_config,
+            agent_resource_limits,
             wasm_hash,
             hash,
             object_store_key: value.object_store_key,
+            original_object_store_key: None,
+            transformations,
         })
     }
 }
@@ -252,9 +287,19 @@ impl From<Component> for golem_api_grpc::proto::golem::component::Component {
                 .into_iter()
                 .map(golem_api_grpc::proto::golem::component::LocalAgentConfigEntry::from)
                 .collect(),
+            agent_resource_limits: value
+                .agent_resource_limits
+                .into_iter()
+                .map(golem_api_grpc::proto::golem::component::AgentResourceLimits::from)
+                .collect(),
             wasm_hash: Some(value.wasm_hash.into()),
             hash: Some(value.hash.into()),
             object_store_key: value.object_store_key,
+            transformations: value
+                .transformations
+                .into_iter()
+                .map(|transformation| transformation.into())
+                .collect(),
         }
     }
 }
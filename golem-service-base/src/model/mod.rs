@@ -21,8 +21,9 @@ use desert_rust::BinaryCodec;
 use golem_common::model::account::AccountId;
 use golem_common::model::agent::{AgentTypeName, DeployedRegisteredAgentType};
 use golem_common::model::component::{
-    ComponentFilePermissions, ComponentRevision, PluginInstallationAction,
+    ComponentFilePermissions, ComponentId, ComponentRevision, PluginInstallationAction,
 };
+use golem_common::model::environment::EnvironmentId;
 use golem_common::model::oplog::{OplogCursor, PublicOplogEntryWithIndex};
 use golem_common::model::worker::{
     FlatComponentFileSystemNode, FlatComponentFileSystemNodeKind, WorkerUpdateMode,
@@ -47,6 +48,9 @@ pub struct WorkerCreationResponse {
 pub struct CompleteParameters {
     pub oplog_idx: u64,
     pub data: Vec<u8>,
+    /// If true, the promise is failed (rejected) and `data` is passed to the worker as the error payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed: Option<bool>,
 }
 
 impl From<CompleteParameters> for golem_api_grpc::proto::golem::worker::CompleteParameters {
@@ -54,6 +58,7 @@ impl From<CompleteParameters> for golem_api_grpc::proto::golem::worker::Complete
         Self {
             oplog_idx: value.oplog_idx,
             data: value.data,
+            failed: value.failed.unwrap_or(false),
         }
     }
 }
@@ -90,6 +95,11 @@ pub struct CancelInvocationResponse {
     pub canceled: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct CancelRecurringInvocationResponse {
+    pub canceled: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -205,6 +215,92 @@ impl From<AccountResourceLimits> for golem_api_grpc::proto::golem::common::Accou
     }
 }
 
+/// Identifies the agents of an agent type in a component for usage metering
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AgentUsageKey {
+    pub environment_id: EnvironmentId,
+    pub component_id: ComponentId,
+    pub agent_type: AgentTypeName,
+}
+
+/// Resource usage of agents, accumulated by a worker executor between two usage reports
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AgentUsage {
+    pub invocations: u64,
+    pub fuel: u64,
+    pub invocation_time_millis: u64,
+    pub oplog_bytes: u64,
+    pub outgoing_http_requests: u64,
+}
+
+impl AgentUsage {
+    pub fn add(&mut self, other: &AgentUsage) {
+        self.invocations = self.invocations.saturating_add(other.invocations);
+        self.fuel = self.fuel.saturating_add(other.fuel);
+        self.invocation_time_millis = self
+            .invocation_time_millis
+            .saturating_add(other.invocation_time_millis);
+        self.oplog_bytes = self.oplog_bytes.saturating_add(other.oplog_bytes);
+        self.outgoing_http_requests = self
+            .outgoing_http_requests
+            .saturating_add(other.outgoing_http_requests);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentUsageUpdate {
+    pub key: AgentUsageKey,
+    pub usage: AgentUsage,
+}
+
+impl From<AgentUsageUpdate> for golem_api_grpc::proto::golem::registry::AgentUsageUpdate {
+    fn from(value: AgentUsageUpdate) -> Self {
+        Self {
+            environment_id: Some(value.key.environment_id.into()),
+            component_id: Some(value.key.component_id.into()),
+            agent_type: value.key.agent_type.0,
+            invocations: value.usage.invocations,
+            fuel: value.usage.fuel,
+            invocation_time_millis: value.usage.invocation_time_millis,
+            oplog_bytes: value.usage.oplog_bytes,
+            outgoing_http_requests: value.usage.outgoing_http_requests,
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::registry::AgentUsageUpdate> for AgentUsageUpdate {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::registry::AgentUsageUpdate,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key: AgentUsageKey {
+                environment_id: value
+                    .environment_id
+                    .ok_or("missing environment_id field")?
+                    .try_into()?,
+                component_id: value
+                    .component_id
+                    .ok_or("missing component_id field")?
+                    .try_into()?,
+                agent_type: AgentTypeName(value.agent_type),
+            },
+            usage: AgentUsage {
+                invocations: value.invocations,
+                fuel: value.fuel,
+                invocation_time_millis: value.invocation_time_millis,
+                oplog_bytes: value.oplog_bytes,
+                outgoing_http_requests: value.outgoing_http_requests,
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
 pub struct BatchPluginInstallationUpdates {
     pub actions: Vec<PluginInstallationAction>,
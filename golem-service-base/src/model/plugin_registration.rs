@@ -14,7 +14,9 @@
 
 use golem_common::model::account::AccountId;
 use golem_common::model::base64::Base64;
-use golem_common::model::plugin_registration::OplogProcessorPluginSpec;
+use golem_common::model::plugin_registration::{
+    ComponentTransformerPluginSpec, LibraryPluginSpec, OplogProcessorPluginSpec,
+};
 use golem_common::model::plugin_registration::{
     PluginRegistrationDto, PluginRegistrationId, PluginSpecDto,
 };
@@ -49,12 +51,16 @@ impl From<PluginRegistration> for PluginRegistrationDto {
 #[derive(Debug, Clone)]
 pub enum PluginSpec {
     OplogProcessor(OplogProcessorPluginSpec),
+    ComponentTransformer(ComponentTransformerPluginSpec),
+    Library(LibraryPluginSpec),
 }
 
 impl From<PluginSpec> for PluginSpecDto {
     fn from(value: PluginSpec) -> Self {
         match value {
             PluginSpec::OplogProcessor(inner) => Self::OplogProcessor(inner),
+            PluginSpec::ComponentTransformer(inner) => Self::ComponentTransformer(inner),
+            PluginSpec::Library(inner) => Self::Library(inner),
         }
     }
 }
@@ -0,0 +1,408 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fault_injection::{Fault, FaultInjectionTarget, FaultInjector};
+use crate::replayable_stream::ErasedReplayableStream;
+use crate::storage::blob::{BlobMetadata, BlobStorage, BlobStorageNamespace, ExistsResult};
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Blob storage injecting the faults of a [`FaultInjector`] into the operations of an
+/// underlying storage
+#[derive(Debug)]
+pub struct FaultInjectingBlobStorage {
+    inner: Arc<dyn BlobStorage + Send + Sync>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultInjectingBlobStorage {
+    pub fn new(inner: Arc<dyn BlobStorage + Send + Sync>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    /// Wraps the storage if the fault injector is enabled for blob storage
+    pub fn wrap(
+        inner: Arc<dyn BlobStorage + Send + Sync>,
+        injector: &Option<Arc<FaultInjector>>,
+    ) -> Arc<dyn BlobStorage + Send + Sync> {
+        match injector {
+            Some(injector) if injector.applies_to(FaultInjectionTarget::BlobStorage) => {
+                Arc::new(Self::new(inner, injector.clone()))
+            }
+            _ => inner,
+        }
+    }
+
+    async fn with_read_fault<R>(
+        &self,
+        op_label: &'static str,
+        read: impl Future<Output = Result<R, Error>>,
+    ) -> Result<R, Error> {
+        match self.injector.next_fault(false).await {
+            Fault::None => read.await,
+            Fault::Error | Fault::PartialWrite => Err(Self::error(op_label)),
+        }
+    }
+
+    /// Performs a write, on partial write faults the given `partial` write is performed instead
+    /// of the full one before reporting the failure
+    async fn with_write_fault<R>(
+        &self,
+        op_label: &'static str,
+        write: impl Future<Output = Result<R, Error>>,
+        partial: impl Future<Output = Result<R, Error>>,
+    ) -> Result<R, Error> {
+        match self.injector.next_fault(true).await {
+            Fault::None => write.await,
+            Fault::Error => Err(Self::error(op_label)),
+            Fault::PartialWrite => {
+                partial.await?;
+                Err(Self::error(op_label))
+            }
+        }
+    }
+
+    fn error(op_label: &'static str) -> Error {
+        anyhow!(FaultInjector::error_message(
+            FaultInjectionTarget::BlobStorage,
+            op_label
+        ))
+    }
+}
+
+#[async_trait]
+impl BlobStorage for FaultInjectingBlobStorage {
+    async fn get_raw(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner.get_raw(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn get_stream(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<Option<BoxStream<'static, Result<Bytes, Error>>>, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner
+                .get_stream(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn get_raw_slice(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner
+                .get_raw_slice(target_label, op_label, namespace, path, start, end),
+        )
+        .await
+    }
+
+    async fn get_metadata(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<Option<BlobMetadata>, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner
+                .get_metadata(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn put_raw(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+        data: &[u8],
+    ) -> Result<(), Error> {
+        // partial writes leave a truncated blob behind, as if the write was interrupted
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .put_raw(target_label, op_label, namespace.clone(), path, data),
+            self.inner.put_raw(
+                target_label,
+                op_label,
+                namespace,
+                path,
+                &data[..data.len() / 2],
+            ),
+        )
+        .await
+    }
+
+    async fn put_stream(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+        stream: &dyn ErasedReplayableStream<Item = Result<Vec<u8>, Error>, Error = Error>,
+    ) -> Result<(), Error> {
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .put_stream(target_label, op_label, namespace.clone(), path, stream),
+            self.inner
+                .put_stream(target_label, op_label, namespace, path, stream),
+        )
+        .await
+    }
+
+    async fn delete(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<(), Error> {
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .delete(target_label, op_label, namespace.clone(), path),
+            self.inner.delete(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn delete_many(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        paths: &[PathBuf],
+    ) -> Result<(), Error> {
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .delete_many(target_label, op_label, namespace.clone(), paths),
+            self.inner
+                .delete_many(target_label, op_label, namespace, &paths[..paths.len() / 2]),
+        )
+        .await
+    }
+
+    async fn create_dir(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<(), Error> {
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .create_dir(target_label, op_label, namespace.clone(), path),
+            self.inner
+                .create_dir(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn list_dir(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<Vec<PathBuf>, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner.list_dir(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn delete_dir(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<bool, Error> {
+        self.with_write_fault(
+            op_label,
+            self.inner
+                .delete_dir(target_label, op_label, namespace.clone(), path),
+            self.inner
+                .delete_dir(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+
+    async fn exists(
+        &self,
+        target_label: &'static str,
+        op_label: &'static str,
+        namespace: BlobStorageNamespace,
+        path: &Path,
+    ) -> Result<ExistsResult, Error> {
+        self.with_read_fault(
+            op_label,
+            self.inner.exists(target_label, op_label, namespace, path),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::FaultInjectingBlobStorage;
+    use crate::fault_injection::{FaultInjectionConfig, FaultInjector};
+    use crate::storage::blob::memory::InMemoryBlobStorage;
+    use crate::storage::blob::{BlobStorage, BlobStorageNamespace};
+    use golem_common::model::environment::EnvironmentId;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    fn storages(
+        error_probability: f64,
+        partial_write_probability: f64,
+    ) -> (
+        Arc<dyn BlobStorage + Send + Sync>,
+        Arc<dyn BlobStorage + Send + Sync>,
+    ) {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            error_probability,
+            partial_write_probability,
+            delay_probability: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let inner: Arc<dyn BlobStorage + Send + Sync> = Arc::new(InMemoryBlobStorage::new());
+        let wrapped = FaultInjectingBlobStorage::wrap(inner.clone(), &Some(Arc::new(injector)));
+        (inner, wrapped)
+    }
+
+    fn namespace() -> BlobStorageNamespace {
+        BlobStorageNamespace::CustomStorage {
+            environment_id: EnvironmentId::new(),
+        }
+    }
+
+    #[test]
+    async fn failed_writes_store_nothing() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+        let namespace = namespace();
+        let path = Path::new("blob");
+
+        let result = wrapped
+            .put_raw("test", "test", namespace.clone(), path, b"12345678")
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            inner
+                .get_raw("test", "test", namespace, path)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    async fn partial_writes_leave_a_truncated_blob_and_fail() {
+        let (inner, wrapped) = storages(0.0, 1.0);
+        let namespace = namespace();
+        let path = Path::new("blob");
+
+        let result = wrapped
+            .put_raw("test", "test", namespace.clone(), path, b"12345678")
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            inner
+                .get_raw("test", "test", namespace, path)
+                .await
+                .unwrap(),
+            Some(b"1234".to_vec())
+        );
+    }
+
+    #[test]
+    async fn reads_fail_on_injected_errors() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+        let namespace = namespace();
+        let path = Path::new("blob");
+        inner
+            .put_raw("test", "test", namespace.clone(), path, b"12345678")
+            .await
+            .unwrap();
+
+        let result = wrapped.get_raw("test", "test", namespace, path).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn operations_pass_through_without_faults() {
+        let (inner, wrapped) = storages(0.0, 0.0);
+        let namespace = namespace();
+        let path = Path::new("blob");
+
+        wrapped
+            .put_raw("test", "test", namespace.clone(), path, b"12345678")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            inner
+                .get_raw("test", "test", namespace.clone(), path)
+                .await
+                .unwrap(),
+            Some(b"12345678".to_vec())
+        );
+        assert_eq!(
+            wrapped
+                .get_raw("test", "test", namespace, path)
+                .await
+                .unwrap(),
+            Some(b"12345678".to_vec())
+        );
+    }
+}
@@ -25,6 +25,7 @@ use golem_common::serialization::{deserialize, serialize};
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 
+pub mod fault_injection;
 pub mod fs;
 pub mod memory;
 pub mod s3;
@@ -40,6 +40,10 @@ pub enum ShardManagerError {
     RedisError(#[from] golem_common::redis::RedisError),
     #[error("IO error {0}")]
     IoError(#[from] std::io::Error),
+    #[error("Pod not found: {0}")]
+    PodNotFound(String),
+    #[error("No other pod to move shards of the draining pod to")]
+    NoPodToDrainTo,
 }
 
 impl IsRetriableError for ShardManagerError {
@@ -54,6 +58,8 @@ impl IsRetriableError for ShardManagerError {
             ShardManagerError::SerializationError(_) => false,
             ShardManagerError::RedisError(_) => false,
             ShardManagerError::IoError(_) => false,
+            ShardManagerError::PodNotFound(_) => false,
+            ShardManagerError::NoPodToDrainTo => false,
         }
     }
 
@@ -101,6 +107,14 @@ impl From<ShardManagerError> for golem::shardmanager::v1::ShardManagerError {
             ShardManagerError::IoError(err) => {
                 error(shard_manager_error::Error::Unknown, err.to_string())
             }
+            ShardManagerError::PodNotFound(pod) => error(
+                shard_manager_error::Error::InvalidRequest,
+                format!("PodNotFound: {pod}"),
+            ),
+            ShardManagerError::NoPodToDrainTo => error(
+                shard_manager_error::Error::InvalidRequest,
+                "NoPodToDrainTo".to_string(),
+            ),
         }
     }
 }
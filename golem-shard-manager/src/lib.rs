@@ -213,6 +213,115 @@ impl ShardManagerService for ShardManagerServiceImpl {
             result: Some(result),
         }))
     }
+
+    async fn drain_pod(
+        &self,
+        request: tonic::Request<golem::shardmanager::v1::DrainPodRequest>,
+    ) -> Result<Response<golem::shardmanager::v1::DrainPodResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "drain_pod",
+            host = &request.host,
+            port = &request.port.to_string(),
+        );
+
+        let response = self
+            .shard_management
+            .drain_pod(&request.host, request.port as u16)
+            .instrument(record.span.clone())
+            .await;
+
+        let result = match response {
+            Ok(status) => record.succeed(
+                golem::shardmanager::v1::drain_pod_response::Result::Success(status.into()),
+            ),
+            Err(error) => {
+                let error: golem::shardmanager::v1::ShardManagerError = error.into();
+                record.fail(
+                    golem::shardmanager::v1::drain_pod_response::Result::Failure(error.clone()),
+                    &mut ShardManagerTraceErrorKind(&error),
+                )
+            }
+        };
+
+        Ok(Response::new(golem::shardmanager::v1::DrainPodResponse {
+            result: Some(result),
+        }))
+    }
+
+    async fn undrain_pod(
+        &self,
+        request: tonic::Request<golem::shardmanager::v1::UndrainPodRequest>,
+    ) -> Result<Response<golem::shardmanager::v1::UndrainPodResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "undrain_pod",
+            host = &request.host,
+            port = &request.port.to_string(),
+        );
+
+        let response = self
+            .shard_management
+            .undrain_pod(&request.host, request.port as u16)
+            .instrument(record.span.clone())
+            .await;
+
+        let result = match response {
+            Ok(status) => record.succeed(
+                golem::shardmanager::v1::undrain_pod_response::Result::Success(status.into()),
+            ),
+            Err(error) => {
+                let error: golem::shardmanager::v1::ShardManagerError = error.into();
+                record.fail(
+                    golem::shardmanager::v1::undrain_pod_response::Result::Failure(error.clone()),
+                    &mut ShardManagerTraceErrorKind(&error),
+                )
+            }
+        };
+
+        Ok(Response::new(golem::shardmanager::v1::UndrainPodResponse {
+            result: Some(result),
+        }))
+    }
+
+    async fn get_drain_status(
+        &self,
+        request: tonic::Request<golem::shardmanager::v1::GetDrainStatusRequest>,
+    ) -> Result<Response<golem::shardmanager::v1::GetDrainStatusResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_drain_status",
+            host = &request.host,
+            port = &request.port.to_string(),
+        );
+
+        let response = self
+            .shard_management
+            .drain_status(&request.host, request.port as u16)
+            .instrument(record.span.clone())
+            .await;
+
+        let result = match response {
+            Ok(status) => record.succeed(
+                golem::shardmanager::v1::get_drain_status_response::Result::Success(status.into()),
+            ),
+            Err(error) => {
+                let error: golem::shardmanager::v1::ShardManagerError = error.into();
+                record.fail(
+                    golem::shardmanager::v1::get_drain_status_response::Result::Failure(
+                        error.clone(),
+                    ),
+                    &mut ShardManagerTraceErrorKind(&error),
+                )
+            }
+        };
+
+        Ok(Response::new(
+            golem::shardmanager::v1::GetDrainStatusResponse {
+                result: Some(result),
+            },
+        ))
+    }
 }
 
 pub async fn run(
@@ -65,6 +65,15 @@ impl Pod {
         format!("{}:{}", self.ip, self.port).to_socket_addrs()
     }
 
+    /// Checks if the pod is identified by the given host and port, where host can be either
+    /// the registered host name, the pod's IP address or its pod name
+    pub fn is_identified_by(&self, host: &str, port: u16) -> bool {
+        self.port == port
+            && (self.host == host
+                || self.ip.to_string() == host
+                || self.pod_name.as_deref() == Some(host))
+    }
+
     pub fn from_register_request(
         source_ip: IpAddr,
         request: golem::shardmanager::v1::RegisterRequest,
@@ -126,6 +135,8 @@ impl Display for Pod {
 pub struct RoutingTable {
     pub number_of_shards: usize,
     pub shard_assignments: BTreeMap<Pod, BTreeSet<ShardId>>,
+    /// Pods which are not getting new shards, and their existing shards are moved to other pods
+    pub draining_pods: BTreeSet<Pod>,
 }
 
 impl RoutingTable {
@@ -133,6 +144,7 @@ impl RoutingTable {
         Self {
             number_of_shards,
             shard_assignments: BTreeMap::new(),
+            draining_pods: BTreeSet::new(),
         }
     }
 
@@ -185,21 +197,53 @@ impl RoutingTable {
         self.shard_assignments.get(pod).cloned()
     }
 
-    pub fn get_pod_count(&self) -> usize {
-        self.shard_assignments.len()
-    }
-
     pub fn add_pod(&mut self, pod: &Pod) {
         self.shard_assignments.insert(pod.clone(), BTreeSet::new());
     }
 
     pub fn remove_pod(&mut self, pod: &Pod) {
         self.shard_assignments.remove(pod);
+        self.draining_pods.remove(pod);
     }
 
     pub fn has_pod(&self, pod: &Pod) -> bool {
         self.shard_assignments.contains_key(pod)
     }
+
+    pub fn find_pod(&self, host: &str, port: u16) -> Option<Pod> {
+        self.shard_assignments
+            .keys()
+            .find(|pod| pod.is_identified_by(host, port))
+            .cloned()
+    }
+
+    pub fn is_draining(&self, pod: &Pod) -> bool {
+        self.draining_pods.contains(pod)
+    }
+
+    pub fn set_draining(&mut self, pod: &Pod, draining: bool) {
+        if draining {
+            self.draining_pods.insert(pod.clone());
+        } else {
+            self.draining_pods.remove(pod);
+        }
+    }
+
+    /// Gets the routing table entries of the pods which can get new shards assigned
+    pub fn get_active_entries_vec(&self) -> Vec<RoutingTableEntry> {
+        self.get_entries_vec()
+            .into_iter()
+            .filter(|entry| !self.is_draining(&entry.pod))
+            .collect()
+    }
+
+    pub fn get_drain_status(&self, pod: &Pod) -> DrainStatus {
+        DrainStatus {
+            pod: pod.clone(),
+            draining: self.is_draining(pod),
+            remaining_shards: self.get_shards(pod).map(|shards| shards.len()).unwrap_or(0),
+        }
+    }
 }
 
 impl From<RoutingTable> for golem::shardmanager::RoutingTable {
@@ -227,13 +271,31 @@ impl Display for RoutingTable {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "{{ number_of_shards: {}, shard_assignments: [{}] }}",
+            "{{ number_of_shards: {}, shard_assignments: [{}], draining_pods: [{}] }}",
             self.number_of_shards,
-            shard_assignments_map_to_string(&self.shard_assignments)
+            shard_assignments_map_to_string(&self.shard_assignments),
+            self.draining_pods.iter().join(", ")
         )
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DrainStatus {
+    pub pod: Pod,
+    pub draining: bool,
+    pub remaining_shards: usize,
+}
+
+impl From<DrainStatus> for golem::shardmanager::v1::DrainStatus {
+    fn from(value: DrainStatus) -> Self {
+        golem::shardmanager::v1::DrainStatus {
+            pod: Some(value.pod.into()),
+            draining: value.draining,
+            remaining_shards: value.remaining_shards as u32,
+        }
+    }
+}
+
 pub struct RoutingTableEntry {
     pub pod: Pod,
     pub shard_ids: BTreeSet<ShardId>,
@@ -362,10 +424,11 @@ impl Display for Unassignments {
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, BinaryCodec)]
-#[desert(evolution())]
+#[desert(evolution(FieldAdded("draining_pods", Vec::new())))]
 pub struct ShardManagerState {
     pub number_of_shards: usize,
     pub shard_assignments: Vec<(Pod, Vec<ShardId>)>,
+    pub draining_pods: Vec<Pod>,
 }
 
 impl ShardManagerState {
@@ -380,6 +443,7 @@ impl ShardManagerState {
         ShardManagerState {
             number_of_shards: routing_table.number_of_shards,
             shard_assignments,
+            draining_pods: routing_table.draining_pods.iter().cloned().collect(),
         }
     }
 
@@ -391,6 +455,7 @@ impl ShardManagerState {
         RoutingTable {
             number_of_shards: self.number_of_shards,
             shard_assignments,
+            draining_pods: self.draining_pods.iter().cloned().collect(),
         }
     }
 }
@@ -399,9 +464,10 @@ impl Display for ShardManagerState {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "{{ number_of_shards: {}, shard_assignments: [{}]}}",
+            "{{ number_of_shards: {}, shard_assignments: [{}], draining_pods: [{}]}}",
             self.number_of_shards,
             shard_assignments_to_string(&self.shard_assignments),
+            self.draining_pods.iter().join(", "),
         )
     }
 }
@@ -43,10 +43,14 @@ impl Rebalance {
     /// Threshold is a percentage of the optimal count, so for 10 pods with 1000 shards,
     /// and a threshold of 10%, pods with shard count between 90 and 110 will be considered
     /// balanced.
+    ///
+    /// Draining pods are not part of the plan: they never get shards assigned, and their
+    /// shards are moved away one by one by [`Rebalance::drain_step`].
     pub fn from_routing_table(routing_table: &RoutingTable, threshold: f64) -> Self {
         let mut assignments = Assignments::new();
         let mut unassignments = Unassignments::new();
-        let pod_count = routing_table.get_pod_count();
+        let mut routing_table_entries = routing_table.get_active_entries_vec();
+        let pod_count = routing_table_entries.len();
         if pod_count == 0 {
             return Rebalance {
                 assignments,
@@ -54,14 +58,19 @@ impl Rebalance {
             };
         }
 
-        let mut routing_table_entries = routing_table.get_entries_vec();
         let initial_target_pods: Vec<usize> = routing_table_entries
             .iter()
             .enumerate()
             .filter(|&(_idx, entry)| entry.shard_ids.is_empty())
             .map(|(idx, _entry)| idx)
             .collect();
-        let optimal_count = routing_table.number_of_shards / pod_count;
+        let draining_shard_count: usize = routing_table
+            .draining_pods
+            .iter()
+            .filter_map(|pod| routing_table.shard_assignments.get(pod))
+            .map(|shard_ids| shard_ids.len())
+            .sum();
+        let optimal_count = (routing_table.number_of_shards - draining_shard_count) / pod_count;
         let upper_threshold = (optimal_count as f64 * (1.0 + threshold)).ceil() as usize;
         let lower_threshold = (optimal_count as f64 * (1.0 - threshold)).floor() as usize;
 
@@ -187,6 +196,31 @@ impl Rebalance {
         }
     }
 
+    /// Constructs a plan moving a single shard from a draining pod to the non-draining pod
+    /// having the fewest shards.
+    ///
+    /// Returns `None` if no draining pod has shards left, or if there is no pod to move to.
+    pub fn drain_step(routing_table: &RoutingTable) -> Option<Self> {
+        let (source_pod, shard_id) = routing_table.draining_pods.iter().find_map(|pod| {
+            routing_table
+                .shard_assignments
+                .get(pod)
+                .and_then(|shard_ids| shard_ids.first())
+                .map(|shard_id| (pod.clone(), *shard_id))
+        })?;
+        let target = routing_table.get_active_entries_vec().into_iter().min()?;
+
+        let mut assignments = Assignments::new();
+        let mut unassignments = Unassignments::new();
+        unassignments.unassign(source_pod, shard_id);
+        assignments.assign(target.pod, shard_id);
+
+        Some(Rebalance {
+            assignments,
+            unassignments,
+        })
+    }
+
     pub fn get_assignments(&self) -> &Assignments {
         &self.assignments
     }
@@ -730,4 +764,95 @@ mod tests {
 
         assert_eq!(rebalance.unassignments.unassignments.len(), 0);
     }
+
+    #[test]
+    #[traced_test]
+    fn rebalance_does_not_assign_to_draining_pod() {
+        let mut routing_table = new_routing_table(TestConfig {
+            number_of_shards: 9,
+            number_of_pods: 3,
+            initial_assignments: vec![
+                //
+                (0, vec![0, 1, 2]),
+                (1, vec![3, 4, 5]),
+            ],
+        });
+        routing_table.set_draining(&pod(0), true);
+
+        let rebalance = Rebalance::from_routing_table(&routing_table, 0.0);
+
+        assert_assignments(
+            &rebalance,
+            vec![
+                //
+                (0, vec![]),
+                (1, vec![]),
+                (2, vec![6, 7, 8]),
+            ],
+        );
+        assert!(rebalance.get_unassignments().is_empty());
+    }
+
+    #[test]
+    #[traced_test]
+    fn drain_step_moves_one_shard_to_least_loaded_pod() {
+        let mut routing_table = new_routing_table(TestConfig {
+            number_of_shards: 6,
+            number_of_pods: 3,
+            initial_assignments: vec![
+                //
+                (0, vec![0, 1]),
+                (1, vec![2, 3, 4]),
+                (2, vec![5]),
+            ],
+        });
+        routing_table.set_draining(&pod(0), true);
+
+        let rebalance = Rebalance::drain_step(&routing_table).unwrap();
+
+        assert_assignments(
+            &rebalance,
+            vec![
+                //
+                (0, vec![]),
+                (1, vec![]),
+                (2, vec![0]),
+            ],
+        );
+        assert_unassignments(
+            &rebalance,
+            vec![
+                //
+                (0, vec![0]),
+                (1, vec![]),
+                (2, vec![]),
+            ],
+        );
+    }
+
+    #[test]
+    #[traced_test]
+    fn drain_step_without_target_pod() {
+        let mut routing_table = new_routing_table(TestConfig {
+            number_of_shards: 4,
+            number_of_pods: 1,
+            initial_assignments: vec![(0, vec![0, 1, 2, 3])],
+        });
+        routing_table.set_draining(&pod(0), true);
+
+        assert!(Rebalance::drain_step(&routing_table).is_none());
+    }
+
+    #[test]
+    #[traced_test]
+    fn drain_step_of_drained_pod() {
+        let mut routing_table = new_routing_table(TestConfig {
+            number_of_shards: 4,
+            number_of_pods: 2,
+            initial_assignments: vec![(1, vec![0, 1, 2, 3])],
+        });
+        routing_table.set_draining(&pod(0), true);
+
+        assert!(Rebalance::drain_step(&routing_table).is_none());
+    }
 }
@@ -14,7 +14,7 @@
 
 use crate::error::ShardManagerError;
 use crate::healthcheck::{get_unhealthy_pods, HealthCheck};
-use crate::model::{Pod, RoutingTable};
+use crate::model::{DrainStatus, Pod, RoutingTable};
 use crate::persistence::RoutingTablePersistence;
 use crate::rebalancing::Rebalance;
 use crate::worker_executor::{assign_shards, revoke_shards, WorkerExecutorService};
@@ -22,10 +22,14 @@ use async_rwlock::RwLock;
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{Mutex, Notify};
 use tokio::task::JoinHandle;
 use tracing::{debug, info, warn, Instrument};
 
+/// Delay before retrying a drain step which failed to move its shard
+const DRAIN_RETRY_DELAY: Duration = Duration::from_secs(5);
+
 #[derive(Clone)]
 pub struct ShardManagement {
     routing_table: Arc<RwLock<RoutingTable>>,
@@ -111,6 +115,67 @@ impl ShardManagement {
         self.routing_table.read().await.clone()
     }
 
+    /// Marks a pod as draining. A draining pod does not get new shards, and its existing shards
+    /// are moved to the other pods one by one.
+    pub async fn drain_pod(&self, host: &str, port: u16) -> Result<DrainStatus, ShardManagerError> {
+        let status = {
+            let mut routing_table = self.routing_table.write().await;
+            let pod = Self::find_pod(&routing_table, host, port)?;
+            if !routing_table
+                .get_active_entries_vec()
+                .iter()
+                .any(|entry| entry.pod != pod)
+            {
+                return Err(ShardManagerError::NoPodToDrainTo);
+            }
+            routing_table.set_draining(&pod, true);
+            routing_table.get_drain_status(&pod)
+        };
+
+        info!(pod=%status.pod, remaining_shards=status.remaining_shards, "Draining pod");
+        self.change.notify_one();
+        Ok(status)
+    }
+
+    /// Stops draining a pod, making it eligible for shard assignments again
+    pub async fn undrain_pod(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<DrainStatus, ShardManagerError> {
+        let status = {
+            let mut routing_table = self.routing_table.write().await;
+            let pod = Self::find_pod(&routing_table, host, port)?;
+            routing_table.set_draining(&pod, false);
+            routing_table.get_drain_status(&pod)
+        };
+
+        info!(pod=%status.pod, "Undraining pod");
+        self.change.notify_one();
+        Ok(status)
+    }
+
+    /// Gets the drain status of a pod
+    pub async fn drain_status(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<DrainStatus, ShardManagerError> {
+        let routing_table = self.routing_table.read().await;
+        let pod = Self::find_pod(&routing_table, host, port)?;
+        Ok(routing_table.get_drain_status(&pod))
+    }
+
+    fn find_pod(
+        routing_table: &RoutingTable,
+        host: &str,
+        port: u16,
+    ) -> Result<Pod, ShardManagerError> {
+        routing_table
+            .find_pod(host, port)
+            .ok_or_else(|| ShardManagerError::PodNotFound(format!("{host}:{port}")))
+    }
+
     async fn worker(
         routing_table: Arc<RwLock<RoutingTable>>,
         change: Arc<Notify>,
@@ -178,6 +243,98 @@ impl ShardManagement {
                 .write(&routing_table.read().await.clone())
                 .await
                 .expect("Failed to persist routing table after rebalance");
+
+            Self::drain_step(
+                &routing_table,
+                &change,
+                &persistence_service,
+                worker_executors.clone(),
+            )
+            .await;
+        }
+    }
+
+    /// Moves a single shard off a draining pod, and wakes up the shard management loop again
+    /// to continue with the next one.
+    ///
+    /// Revoking the shard makes the worker executor suspend the shard's active workers, so
+    /// the shard is only assigned to its new pod once all of them are stopped.
+    async fn drain_step(
+        routing_table: &Arc<RwLock<RoutingTable>>,
+        change: &Arc<Notify>,
+        persistence_service: &Arc<dyn RoutingTablePersistence + Send + Sync>,
+        worker_executors: Arc<dyn WorkerExecutorService + Send + Sync>,
+    ) {
+        let drain_step = {
+            let current_routing_table = routing_table.read().await;
+            let drain_step = Rebalance::drain_step(&current_routing_table);
+            if drain_step.is_none()
+                && current_routing_table
+                    .draining_pods
+                    .iter()
+                    .any(|pod| current_routing_table.get_drain_status(pod).remaining_shards > 0)
+            {
+                warn!("Draining pods have remaining shards, but there is no pod to move them to");
+            }
+            drain_step
+        };
+
+        let Some(mut drain_step) = drain_step else {
+            return;
+        };
+
+        let draining_pods = drain_step
+            .get_unassignments()
+            .unassignments
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        debug!(rebalance=%drain_step, "Applying drain step");
+        Self::execute_rebalance(worker_executors, &mut drain_step).await;
+
+        let moved = drain_step
+            .get_assignments()
+            .assignments
+            .values()
+            .any(|shard_ids| !shard_ids.is_empty());
+
+        if moved {
+            let drain_statuses = {
+                let mut current_routing_table = routing_table.write().await;
+                current_routing_table.rebalance(drain_step);
+                persistence_service
+                    .write(&current_routing_table)
+                    .await
+                    .expect("Failed to persist routing table after drain step");
+                draining_pods
+                    .iter()
+                    .map(|pod| current_routing_table.get_drain_status(pod))
+                    .collect::<Vec<_>>()
+            };
+
+            for status in drain_statuses {
+                if status.remaining_shards == 0 {
+                    info!(pod=%status.pod, "Pod drained");
+                } else {
+                    info!(pod=%status.pod, remaining_shards=status.remaining_shards, "Draining pod");
+                }
+            }
+
+            change.notify_one();
+        } else {
+            warn!(
+                retry_delay = ?DRAIN_RETRY_DELAY,
+                "Failed to move shard off draining pod, retrying later"
+            );
+            let change = change.clone();
+            tokio::spawn(
+                async move {
+                    tokio::time::sleep(DRAIN_RETRY_DELAY).await;
+                    change.notify_one();
+                }
+                .in_current_span(),
+            );
         }
     }
 
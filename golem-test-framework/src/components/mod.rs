@@ -16,6 +16,7 @@ use golem_api_grpc::proto::grpc::health::v1::health_check_response::ServingStatu
 use golem_api_grpc::proto::grpc::health::v1::HealthCheckRequest;
 use golem_client::api::HealthCheckClient;
 use golem_client::Security;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use std::collections::HashMap;
 use std::io::{BufRead, BufReader};
 use std::process::Child;
@@ -424,6 +425,50 @@ impl EnvVarBuilder {
         self
     }
 
+    fn with_optional_fault_injection(self, config: &Option<FaultInjectionConfig>) -> Self {
+        match config {
+            Some(config) => self
+                .with(
+                    "GOLEM__FAULT_INJECTION__ENABLED",
+                    config.enabled.to_string(),
+                )
+                .with("GOLEM__FAULT_INJECTION__SEED", config.seed.to_string())
+                .with(
+                    "GOLEM__FAULT_INJECTION__KEY_VALUE_STORAGE",
+                    config.key_value_storage.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__INDEXED_STORAGE",
+                    config.indexed_storage.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__BLOB_STORAGE",
+                    config.blob_storage.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__SHARD_MANAGER",
+                    config.shard_manager.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__ERROR_PROBABILITY",
+                    config.error_probability.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__PARTIAL_WRITE_PROBABILITY",
+                    config.partial_write_probability.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__DELAY_PROBABILITY",
+                    config.delay_probability.to_string(),
+                )
+                .with(
+                    "GOLEM__FAULT_INJECTION__MAX_DELAY",
+                    format!("{}ms", config.max_delay.as_millis()),
+                ),
+            None => self,
+        }
+    }
+
     fn build(self) -> HashMap<String, String> {
         self.env_vars
     }
@@ -18,6 +18,7 @@ use super::shard_manager::ShardManager;
 use super::worker_service::WorkerService;
 use super::{wait_for_startup_grpc, EnvVarBuilder};
 use async_trait::async_trait;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -52,6 +53,7 @@ async fn env_vars(
     registry_service: &Arc<dyn RegistryService>,
     verbosity: Level,
     otlp: bool,
+    fault_injection: &Option<FaultInjectionConfig>,
 ) -> HashMap<String, String> {
     EnvVarBuilder::golem_service(verbosity)
         .with_str("ENVIRONMENT", "local")
@@ -123,5 +125,6 @@ async fn env_vars(
         .with("GOLEM__GRPC__PORT", grpc_port.to_string())
         .with("GOLEM__HTTP_PORT", http_port.to_string())
         .with_optional_otlp("worker_executor", otlp)
+        .with_optional_fault_injection(fault_injection)
         .build()
 }
@@ -19,6 +19,7 @@ use crate::components::worker_executor::{wait_for_startup, WorkerExecutor};
 use crate::components::worker_service::WorkerService;
 use crate::components::ChildProcessLogger;
 use async_trait::async_trait;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::{Arc, Mutex};
@@ -41,6 +42,7 @@ pub struct SpawnedWorkerExecutor {
     err_level: Level,
     registry_service: Arc<dyn RegistryService>,
     otlp: bool,
+    fault_injection: Option<FaultInjectionConfig>,
 }
 
 impl SpawnedWorkerExecutor {
@@ -57,6 +59,7 @@ impl SpawnedWorkerExecutor {
         err_level: Level,
         registry_service: Arc<dyn RegistryService>,
         otlp: bool,
+        fault_injection: Option<FaultInjectionConfig>,
     ) -> Self {
         info!("Starting golem-worker-executor process");
 
@@ -77,6 +80,7 @@ impl SpawnedWorkerExecutor {
             err_level,
             &registry_service,
             otlp,
+            &fault_injection,
         )
         .await;
 
@@ -95,6 +99,7 @@ impl SpawnedWorkerExecutor {
             err_level,
             registry_service,
             otlp,
+            fault_injection,
         }
     }
 
@@ -111,6 +116,7 @@ impl SpawnedWorkerExecutor {
         err_level: Level,
         registry_service: &Arc<dyn RegistryService>,
         otlp: bool,
+        fault_injection: &Option<FaultInjectionConfig>,
     ) -> (Child, ChildProcessLogger) {
         let mut child = Command::new(executable)
             .current_dir(working_directory)
@@ -124,6 +130,7 @@ impl SpawnedWorkerExecutor {
                     registry_service,
                     verbosity,
                     otlp,
+                    fault_injection,
                 )
                 .await,
             )
@@ -183,6 +190,7 @@ impl WorkerExecutor for SpawnedWorkerExecutor {
             self.err_level,
             &self.registry_service,
             self.otlp,
+            &self.fault_injection,
         )
         .await;
 
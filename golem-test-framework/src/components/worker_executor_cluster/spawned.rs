@@ -20,6 +20,7 @@ use crate::components::worker_executor::WorkerExecutor;
 use crate::components::worker_executor_cluster::WorkerExecutorCluster;
 use crate::components::worker_service::WorkerService;
 use async_trait::async_trait;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -47,6 +48,7 @@ impl SpawnedWorkerExecutorCluster {
         err_level: Level,
         registry_service: Arc<dyn RegistryService>,
         otlp: bool,
+        fault_injection: Option<FaultInjectionConfig>,
     ) -> Arc<dyn WorkerExecutor> {
         Arc::new(
             SpawnedWorkerExecutor::new(
@@ -62,6 +64,7 @@ impl SpawnedWorkerExecutorCluster {
                 err_level,
                 registry_service,
                 otlp,
+                fault_injection,
             )
             .await,
         )
@@ -81,6 +84,7 @@ impl SpawnedWorkerExecutorCluster {
         err_level: Level,
         registry_service: Arc<dyn RegistryService>,
         otlp: bool,
+        fault_injection: Option<FaultInjectionConfig>,
     ) -> Self {
         info!("Starting a cluster of golem-worker-executors of size {size}");
         let mut worker_executors_joins = Vec::new();
@@ -88,6 +92,11 @@ impl SpawnedWorkerExecutorCluster {
         for i in 0..size {
             let http_port = base_http_port + i as u16;
             let grpc_port = base_grpc_port + i as u16;
+            // each executor gets its own fault schedule
+            let fault_injection = fault_injection.clone().map(|config| FaultInjectionConfig {
+                seed: config.seed.wrapping_add(i as u64),
+                ..config
+            });
 
             let worker_executor_join = tokio::spawn(
                 Self::make_worker_executor(
@@ -103,6 +112,7 @@ impl SpawnedWorkerExecutorCluster {
                     err_level,
                     registry_service.clone(),
                     otlp,
+                    fault_injection,
                 )
                 .in_current_span(),
             );
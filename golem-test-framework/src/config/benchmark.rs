@@ -377,6 +377,7 @@ impl BenchmarkTestDependencies {
                 Level::ERROR,
                 registry_service.clone(),
                 otlp,
+                None,
             )
             .await,
         );
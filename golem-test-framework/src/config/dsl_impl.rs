@@ -40,7 +40,9 @@ use golem_common::model::application::{
     Application, ApplicationCreation, ApplicationId, ApplicationName,
 };
 use golem_common::model::auth::TokenSecret;
-use golem_common::model::component::{ComponentCreation, ComponentUpdate, LocalAgentConfigEntry};
+use golem_common::model::component::{
+    AgentResourceLimits, ComponentCreation, ComponentUpdate, LocalAgentConfigEntry,
+};
 use golem_common::model::component::{
     ComponentDto, ComponentFileOptions, ComponentFilePath, ComponentId, ComponentName,
     ComponentRevision, PluginInstallation,
@@ -189,6 +191,7 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
         local_agent_config: Vec<LocalAgentConfigEntry>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         plugins: Vec<PluginInstallation>,
     ) -> anyhow::Result<ComponentDto> {
         let component_directory = self.deps.component_directory();
@@ -245,6 +248,7 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
                     env,
                     config_vars,
                     local_agent_config,
+                    agent_resource_limits,
                     plugins,
                     agent_types,
                 },
@@ -343,6 +347,7 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
                     env,
                     config_vars,
                     local_agent_config,
+                    agent_resource_limits: None,
                     agent_types: updated_wasm
                         .as_ref()
                         .map(|(_wasm, agent_types)| agent_types.clone()),
@@ -425,6 +430,7 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
                     method_parameters: params.into(),
                     mode: golem_client::model::AgentInvocationMode::Schedule,
                     schedule_at: None,
+                    priority: None,
                     idempotency_key: None,
                     deployment_revision: None,
                     owner_account_email: None,
@@ -476,6 +482,7 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
                     method_parameters: params.into(),
                     mode: golem_client::model::AgentInvocationMode::Await,
                     schedule_at: None,
+                    priority: None,
                     idempotency_key: None,
                     deployment_revision: deployment_revision.map(i64::from),
                     owner_account_email: None,
@@ -649,6 +656,27 @@ impl<Deps: TestDependencies> TestDsl for TestUserContext<Deps> {
                 &CompleteParameters {
                     oplog_idx: promise_id.oplog_idx.as_u64(),
                     data,
+                    failed: None,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn fail_promise(&self, promise_id: &PromiseId, error: Vec<u8>) -> anyhow::Result<()> {
+        let client = self
+            .deps
+            .worker_service()
+            .worker_http_client(&self.token)
+            .await;
+        client
+            .complete_promise(
+                &promise_id.worker_id.component_id.0,
+                &promise_id.worker_id.worker_name,
+                &CompleteParameters {
+                    oplog_idx: promise_id.oplog_idx.as_u64(),
+                    data: error,
+                    failed: Some(true),
                 },
             )
             .await?;
@@ -991,6 +1019,27 @@ impl<Deps: TestDependencies> TestDslExtended for TestUserContext<Deps> {
                 anyhow!("No deployment revision recorded for environment {environment_id}")
             })
     }
+
+    async fn executor_index_of(&self, worker_id: &WorkerId) -> anyhow::Result<usize> {
+        let routing_table = self.deps.shard_manager().get_routing_table().await?;
+        let pod = routing_table
+            .lookup(worker_id)
+            .ok_or_else(|| anyhow!("No executor is assigned to {worker_id}"))?;
+        self.deps
+            .worker_executor_cluster()
+            .to_vec()
+            .iter()
+            .position(|executor| executor.grpc_port() == pod.port())
+            .ok_or_else(|| anyhow!("Executor {pod} of {worker_id} is not part of the cluster"))
+    }
+
+    async fn kill_executor(&self, index: usize) {
+        self.deps.worker_executor_cluster().stop(index).await
+    }
+
+    async fn restart_executor(&self, index: usize) {
+        self.deps.worker_executor_cluster().start(index).await
+    }
 }
 
 struct HttpWorkerLogEventStream {
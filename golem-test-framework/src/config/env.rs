@@ -32,6 +32,7 @@ use crate::components::worker_service::spawned::SpawnedWorkerService;
 use crate::components::worker_service::WorkerService;
 use crate::config::{DbType, TestDependencies};
 use async_trait::async_trait;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
 use golem_service_base::storage::blob::fs::FileSystemBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
@@ -54,6 +55,8 @@ pub struct EnvBasedTestDependenciesConfig {
     pub redis_key_prefix: String,
     pub golem_repo_root: PathBuf,
     pub unique_network_id: String,
+    /// Fault injection configuration passed to the spawned worker executors
+    pub fault_injection: Option<FaultInjectionConfig>,
 }
 
 impl EnvBasedTestDependenciesConfig {
@@ -152,6 +155,7 @@ impl Default for EnvBasedTestDependenciesConfig {
             redis_key_prefix: "".to_string(),
             golem_repo_root: PathBuf::from(".."),
             unique_network_id: Uuid::new_v4().to_string(),
+            fault_injection: None,
         }
     }
 }
@@ -333,6 +337,7 @@ impl EnvBasedTestDependencies {
                 config.default_stderr_level(),
                 registry_service,
                 false,
+                config.fault_injection.clone(),
             )
             .await,
         )
@@ -423,6 +423,64 @@ pub fn debug_render_oplog_entry(entry: &PublicOplogEntry) -> String {
                 }
             }
         }
+        PublicOplogEntry::ReorderPendingInvocation(params) => {
+            let _ = writeln!(result, "REORDER PENDING INVOCATION");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(
+                result,
+                "{pad}idempotency key:   {}",
+                &params.idempotency_key,
+            );
+            let _ = writeln!(result, "{pad}priority:          {}", &params.priority);
+            if let Some(position) = &params.position {
+                let _ = writeln!(result, "{pad}position:          {position}");
+            }
+        }
+        PublicOplogEntry::SetInvocationQueueLimit(params) => {
+            let _ = writeln!(result, "SET INVOCATION QUEUE LIMIT");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            match &params.limit {
+                Some(limit) => {
+                    let _ = writeln!(result, "{pad}limit:             {limit}");
+                }
+                None => {
+                    let _ = writeln!(result, "{pad}limit:             none");
+                }
+            }
+        }
+        PublicOplogEntry::BeginSaga(params) => {
+            let _ = writeln!(result, "BEGIN SAGA");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+        }
+        PublicOplogEntry::SagaStep(params) => {
+            let _ = writeln!(result, "SAGA STEP");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(result, "{pad}begin index:       {}", &params.begin_index);
+            let _ = writeln!(
+                result,
+                "{pad}compensate on:     {}",
+                &params.compensation.target_worker_id
+            );
+            let _ = writeln!(
+                result,
+                "{pad}compensation:      {}",
+                &params.compensation.method_name
+            );
+            let _ = writeln!(result, "{pad}input:");
+            log_data_value(&mut result, pad, &params.compensation.input);
+        }
+        PublicOplogEntry::SagaStepCompensated(params) => {
+            let _ = writeln!(result, "SAGA STEP COMPENSATED");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(result, "{pad}begin index:       {}", &params.begin_index);
+            let _ = writeln!(result, "{pad}step index:        {}", &params.step_index);
+        }
+        PublicOplogEntry::EndSaga(params) => {
+            let _ = writeln!(result, "END SAGA");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(result, "{pad}begin index:       {}", &params.begin_index);
+            let _ = writeln!(result, "{pad}compensated:       {}", &params.compensated);
+        }
     }
 
     result
@@ -445,7 +503,6 @@ fn value_to_string(value: &ValueAndType) -> String {
     print_value_and_type(value).unwrap_or_else(|_| format!("{value:?}"))
 }
 
-#[allow(dead_code)]
 fn log_data_value(output: &mut String, pad: &str, value: &DataValue) {
     match value {
         DataValue::Tuple(values) => {
@@ -463,7 +520,6 @@ fn log_data_value(output: &mut String, pad: &str, value: &DataValue) {
     }
 }
 
-#[allow(dead_code)]
 fn log_element_value(output: &mut String, pad: &str, value: &ElementValue) {
     match value {
         ElementValue::ComponentModel(ComponentModelElementValue { value }) => {
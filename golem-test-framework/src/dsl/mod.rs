@@ -32,8 +32,8 @@ use golem_common::model::agent::{AgentId, DataValue};
 use golem_common::model::application::{Application, ApplicationId};
 use golem_common::model::auth::EnvironmentRole;
 use golem_common::model::component::{
-    ComponentDto, ComponentFilePath, ComponentFilePermissions, ComponentId, ComponentRevision,
-    PluginInstallation,
+    AgentResourceLimits, ComponentDto, ComponentFilePath, ComponentFilePermissions, ComponentId,
+    ComponentRevision, PluginInstallation,
 };
 use golem_common::model::component::{LocalAgentConfigEntry, PluginPriority};
 use golem_common::model::component_metadata::RawComponentMetadata;
@@ -125,6 +125,7 @@ pub trait TestDsl {
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
         local_agent_config: Vec<LocalAgentConfigEntry>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         plugins: Vec<PluginInstallation>,
     ) -> anyhow::Result<ComponentDto>;
 
@@ -383,6 +384,8 @@ pub trait TestDsl {
 
     async fn complete_promise(&self, promise_id: &PromiseId, data: Vec<u8>) -> anyhow::Result<()>;
 
+    async fn fail_promise(&self, promise_id: &PromiseId, error: Vec<u8>) -> anyhow::Result<()>;
+
     async fn make_worker_log_event_stream(
         &self,
         worker_id: &WorkerId,
@@ -666,6 +669,81 @@ pub trait TestDslExtended: TestDsl {
         &self,
         environment_id: &EnvironmentId,
     ) -> anyhow::Result<DeploymentRevision>;
+
+    /// Index of the executor in the worker executor cluster currently owning the worker
+    async fn executor_index_of(&self, worker_id: &WorkerId) -> anyhow::Result<usize>;
+
+    /// Kills the executor currently owning the worker, returning its index in the cluster
+    async fn kill_executor_of(&self, worker_id: &WorkerId) -> anyhow::Result<usize> {
+        let index = self.executor_index_of(worker_id).await?;
+        self.kill_executor(index).await;
+        Ok(index)
+    }
+
+    async fn kill_executor(&self, index: usize);
+
+    async fn restart_executor(&self, index: usize);
+
+    /// Invokes an agent method and awaits its result while the executor running the agent gets
+    /// killed after `crash_after` and restarted. The invocation is retried with the same
+    /// idempotency key until it succeeds, so tests can assert the method's side effects happened
+    /// exactly once.
+    async fn invoke_and_await_agent_with_executor_crash(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        method_name: &str,
+        params: DataValue,
+        crash_after: Duration,
+    ) -> anyhow::Result<DataValue> {
+        let worker_id = WorkerId::from_agent_id(component.id, agent_id)
+            .map_err(|err| anyhow!("Invalid agent id: {err}"))?;
+        let idempotency_key = IdempotencyKey::fresh();
+
+        let invocation = self.invoke_and_await_agent_with_key(
+            component,
+            agent_id,
+            &idempotency_key,
+            method_name,
+            params.clone(),
+        );
+        let crash = async {
+            tokio::time::sleep(crash_after).await;
+            let index = self.kill_executor_of(&worker_id).await?;
+            info!("Killed executor {index} running {worker_id}");
+            self.restart_executor(index).await;
+            anyhow::Ok(())
+        };
+        let (result, crash_result) = tokio::join!(invocation, crash);
+        crash_result?;
+
+        match result {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                debug!("Invocation interrupted by executor crash, retrying: {err}");
+                let deadline = Instant::now() + Duration::from_secs(60);
+                loop {
+                    match self
+                        .invoke_and_await_agent_with_key(
+                            component,
+                            agent_id,
+                            &idempotency_key,
+                            method_name,
+                            params.clone(),
+                        )
+                        .await
+                    {
+                        Ok(result) => break Ok(result),
+                        Err(err) if Instant::now() < deadline => {
+                            debug!("Retrying invocation after executor crash: {err}");
+                            tokio::time::sleep(Duration::from_millis(500)).await;
+                        }
+                        Err(err) => break Err(err),
+                    }
+                }
+            }
+        }
+    }
 }
 
 pub struct StoreComponentBuilder<'a, Dsl: TestDsl + ?Sized> {
@@ -679,6 +757,7 @@ pub struct StoreComponentBuilder<'a, Dsl: TestDsl + ?Sized> {
     env: BTreeMap<String, String>,
     config_vars: BTreeMap<String, String>,
     local_agent_config: Vec<LocalAgentConfigEntry>,
+    agent_resource_limits: Vec<AgentResourceLimits>,
     plugins: Vec<PluginInstallation>,
 }
 
@@ -695,6 +774,7 @@ impl<'a, Dsl: TestDsl + ?Sized> StoreComponentBuilder<'a, Dsl> {
             env: BTreeMap::new(),
             config_vars: BTreeMap::new(),
             local_agent_config: Vec::new(),
+            agent_resource_limits: Vec::new(),
             plugins: Vec::new(),
         }
     }
@@ -788,6 +868,14 @@ impl<'a, Dsl: TestDsl + ?Sized> StoreComponentBuilder<'a, Dsl> {
         self
     }
 
+    pub fn with_agent_resource_limits(
+        mut self,
+        agent_resource_limits: Vec<AgentResourceLimits>,
+    ) -> Self {
+        self.agent_resource_limits = agent_resource_limits;
+        self
+    }
+
     pub fn with_plugin(
         self,
         environment_plugin_id: &EnvironmentPluginGrantId,
@@ -824,6 +912,7 @@ impl<'a, Dsl: TestDsl + ?Sized> StoreComponentBuilder<'a, Dsl> {
                 self.env,
                 self.config_vars,
                 self.local_agent_config,
+                self.agent_resource_limits,
                 self.plugins,
             )
             .await
@@ -1027,6 +1116,9 @@ pub fn worker_error_message(error: &WorkerExecutorError) -> String {
             format!("File system error: {}", reason)
         }
         WorkerExecutorError::InvocationFailed { .. } => "Invocation failed".to_string(),
+        WorkerExecutorError::InvocationQueueFull { limit, .. } => {
+            format!("Invocation queue full (limit: {})", limit)
+        }
     }
 }
 
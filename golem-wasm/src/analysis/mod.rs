@@ -32,7 +32,7 @@ pub type AnalysisResult<A> = Result<A, AnalysisFailure>;
 
 #[cfg(test)]
 mod tests {
-    use crate::analysis::analysed_type::{f32, field, handle, record, result, str, u32, u64};
+    use crate::analysis::analysed_type::{f32, field, handle, list, record, result, str, u32, u64};
     use crate::analysis::{
         AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult, AnalysedResourceId,
         AnalysedResourceMode,
@@ -107,4 +107,37 @@ mod tests {
         assert!(!fun.is_method());
         assert!(!fun.is_static_method());
     }
+
+    #[test]
+    fn structural_type_equality() {
+        let a = record(vec![
+            field("id", str()),
+            field(
+                "items",
+                list(handle(AnalysedResourceId(0), AnalysedResourceMode::Borrowed).named("cart")),
+            ),
+        ])
+        .named("order")
+        .owned("shop:api/orders");
+        let b = record(vec![
+            field("id", str()),
+            field(
+                "items",
+                list(handle(AnalysedResourceId(7), AnalysedResourceMode::Borrowed).named("cart")),
+            ),
+        ]);
+        let different_mode = record(vec![
+            field("id", str()),
+            field(
+                "items",
+                list(handle(AnalysedResourceId(0), AnalysedResourceMode::Owned).named("cart")),
+            ),
+        ]);
+        let different_field = record(vec![field("id", u64())]);
+
+        assert!(a.structurally_equals(&b));
+        assert!(!a.structurally_equals(&different_mode));
+        assert!(!a.structurally_equals(&different_field));
+        assert!(!str().structurally_equals(&u32()));
+    }
 }
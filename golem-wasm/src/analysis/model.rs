@@ -537,6 +537,61 @@ impl AnalysedType {
             _ => false,
         }
     }
+
+    /// Checks whether two types have the same structure, ignoring type names, owners and
+    /// resource ids. Resource ids are only meaningful within a single analysed component, so
+    /// handles are compared by their resource name and mode instead.
+    pub fn structurally_equals(&self, other: &AnalysedType) -> bool {
+        fn optional_equals(a: &Option<Box<AnalysedType>>, b: &Option<Box<AnalysedType>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => a.structurally_equals(b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        match (self, other) {
+            (AnalysedType::Variant(a), AnalysedType::Variant(b)) => {
+                a.cases.len() == b.cases.len()
+                    && a.cases.iter().zip(&b.cases).all(|(a, b)| {
+                        a.name == b.name
+                            && match (&a.typ, &b.typ) {
+                                (Some(a), Some(b)) => a.structurally_equals(b),
+                                (None, None) => true,
+                                _ => false,
+                            }
+                    })
+            }
+            (AnalysedType::Result(a), AnalysedType::Result(b)) => {
+                optional_equals(&a.ok, &b.ok) && optional_equals(&a.err, &b.err)
+            }
+            (AnalysedType::Option(a), AnalysedType::Option(b)) => {
+                a.inner.structurally_equals(&b.inner)
+            }
+            (AnalysedType::Enum(a), AnalysedType::Enum(b)) => a.cases == b.cases,
+            (AnalysedType::Flags(a), AnalysedType::Flags(b)) => a.names == b.names,
+            (AnalysedType::Record(a), AnalysedType::Record(b)) => {
+                a.fields.len() == b.fields.len()
+                    && a.fields
+                        .iter()
+                        .zip(&b.fields)
+                        .all(|(a, b)| a.name == b.name && a.typ.structurally_equals(&b.typ))
+            }
+            (AnalysedType::Tuple(a), AnalysedType::Tuple(b)) => {
+                a.items.len() == b.items.len()
+                    && a.items
+                        .iter()
+                        .zip(&b.items)
+                        .all(|(a, b)| a.structurally_equals(b))
+            }
+            (AnalysedType::List(a), AnalysedType::List(b)) => a.inner.structurally_equals(&b.inner),
+            (AnalysedType::Handle(a), AnalysedType::Handle(b)) => {
+                a.name == b.name && a.mode == b.mode
+            }
+            // the remaining cases are primitive types without any structure
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
 }
 
 pub mod analysed_type {
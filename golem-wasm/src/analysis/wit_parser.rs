@@ -17,7 +17,7 @@ use wasm_metadata::{Payload, Version};
 use wasmparser::KnownCustom;
 use wit_parser::decoding::DecodedWasm;
 use wit_parser::{
-    Function, Handle, Interface, PackageName, Resolve, Type, TypeDef, TypeDefKind, WorldItem,
+    Function, Handle, Interface, PackageName, Resolve, Type, TypeDef, TypeDefKind, World, WorldItem,
 };
 use wit_parser::{TypeId, TypeOwner as WitParserTypeOwner};
 
@@ -57,6 +57,65 @@ impl WitAnalysisContext {
     /// Get all top-level exports from the component with all the type information gathered from
     /// the component AST.
     pub fn get_top_level_exports(&self) -> AnalysisResult<Vec<AnalysedExport>> {
+        let resolve = self.wasm.resolve();
+        let world = self.root_world()?;
+
+        let mut result = Vec::new();
+        for (world_key, world_item) in &world.exports {
+            let world_name = resolve.name_world_key(world_key);
+            match world_item {
+                WorldItem::Interface { id, .. } => {
+                    let interface =
+                        AnalysisFailure::fail_on_missing(resolve.interfaces.get(*id), "interface")?;
+
+                    match self.analyse_interface(interface) {
+                        Ok(instance) => result.push(AnalysedExport::Instance(instance)),
+                        Err(failure) => self.warning(AnalysisWarning::InterfaceCouldNotBeAnalyzed(
+                            InterfaceCouldNotBeAnalyzedWarning {
+                                name: world_name,
+                                failure,
+                            },
+                        )),
+                    }
+                }
+                WorldItem::Function(function) => {
+                    result.push(AnalysedExport::Function(self.analyse_function(function)?));
+                }
+                WorldItem::Type(_) => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Get all the interfaces imported by the component with all the type information gathered
+    /// from the component AST. Interfaces that cannot be analysed are reported as warnings.
+    pub fn get_top_level_imports(&self) -> AnalysisResult<Vec<AnalysedInstance>> {
+        let resolve = self.wasm.resolve();
+        let world = self.root_world()?;
+
+        let mut result = Vec::new();
+        for (world_key, world_item) in &world.imports {
+            if let WorldItem::Interface { id, .. } = world_item {
+                let interface =
+                    AnalysisFailure::fail_on_missing(resolve.interfaces.get(*id), "interface")?;
+
+                match self.analyse_interface(interface) {
+                    Ok(instance) => result.push(instance),
+                    Err(failure) => self.warning(AnalysisWarning::InterfaceCouldNotBeAnalyzed(
+                        InterfaceCouldNotBeAnalyzedWarning {
+                            name: resolve.name_world_key(world_key),
+                            failure,
+                        },
+                    )),
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn root_world(&self) -> AnalysisResult<&World> {
         let package_id = self.wasm.package();
         let resolve = self.wasm.resolve();
 
@@ -73,38 +132,7 @@ impl WitAnalysisContext {
             ))
         } else {
             let (_world_name, world_id) = root_package.worlds.iter().next().unwrap();
-            let world = AnalysisFailure::fail_on_missing(resolve.worlds.get(*world_id), "world")?;
-
-            let mut result = Vec::new();
-            for (world_key, world_item) in &world.exports {
-                let world_name = resolve.name_world_key(world_key);
-                match world_item {
-                    WorldItem::Interface { id, .. } => {
-                        let interface = AnalysisFailure::fail_on_missing(
-                            resolve.interfaces.get(*id),
-                            "interface",
-                        )?;
-
-                        match self.analyse_interface(interface) {
-                            Ok(instance) => result.push(AnalysedExport::Instance(instance)),
-                            Err(failure) => {
-                                self.warning(AnalysisWarning::InterfaceCouldNotBeAnalyzed(
-                                    InterfaceCouldNotBeAnalyzedWarning {
-                                        name: world_name,
-                                        failure,
-                                    },
-                                ))
-                            }
-                        }
-                    }
-                    WorldItem::Function(function) => {
-                        result.push(AnalysedExport::Function(self.analyse_function(function)?));
-                    }
-                    WorldItem::Type(_) => {}
-                }
-            }
-
-            Ok(result)
+            AnalysisFailure::fail_on_missing(resolve.worlds.get(*world_id), "world")
         }
     }
 
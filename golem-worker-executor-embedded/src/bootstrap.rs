@@ -0,0 +1,183 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::agent_deployments_service::DisabledAgentDeploymentsService;
+use crate::component_service::ComponentServiceLocalFileSystem;
+use crate::network_egress_policy_service::{
+    NoNetworkEgressPolicyService, UnrestrictedNetworkEgressPolicyService,
+};
+use async_trait::async_trait;
+use golem_service_base::clients::registry::RegistryService;
+use golem_service_base::service::compiled_component::DefaultCompiledComponentService;
+use golem_service_base::storage::blob::BlobStorage;
+use golem_worker_executor::bootstrap::ServerBootstrap;
+use golem_worker_executor::services::active_workers::ActiveWorkers;
+use golem_worker_executor::services::agent_deployments::AgentDeploymentsService;
+use golem_worker_executor::services::agent_lifecycle_events::AgentLifecycleEventService;
+use golem_worker_executor::services::agent_types::AgentTypesService;
+use golem_worker_executor::services::agent_webhooks::AgentWebhooksService;
+use golem_worker_executor::services::blob_store::BlobStoreService;
+use golem_worker_executor::services::component::ComponentService;
+use golem_worker_executor::services::events::Events;
+use golem_worker_executor::services::file_loader::FileLoader;
+use golem_worker_executor::services::golem_config::{
+    AgentDeploymentsServiceConfig, GolemConfig, NetworkEgressPolicyServiceConfig,
+};
+use golem_worker_executor::services::key_value::KeyValueService;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
+use golem_worker_executor::services::oplog::plugin::OplogProcessorPlugin;
+use golem_worker_executor::services::oplog::OplogService;
+use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
+use golem_worker_executor::services::rdbms;
+use golem_worker_executor::services::scheduler::SchedulerService;
+use golem_worker_executor::services::shard::ShardService;
+use golem_worker_executor::services::shard_manager::ShardManagerService;
+use golem_worker_executor::services::worker::WorkerService;
+use golem_worker_executor::services::worker_activator::WorkerActivator;
+use golem_worker_executor::services::worker_enumeration::{
+    RunningWorkerEnumerationService, WorkerEnumerationService,
+};
+use golem_worker_executor::services::worker_proxy::WorkerProxy;
+use golem_worker_executor::services::All;
+use golem_worker_executor::workerctx::default::Context;
+use golem_worker_executor::Bootstrap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Handle;
+use wasmtime::component::Linker;
+use wasmtime::Engine;
+
+/// Bootstrap of the production executor, loading components from a local directory instead of
+/// the registry service
+pub(crate) struct EmbeddedBootstrap {
+    pub component_service_directory: PathBuf,
+    /// Denies all outgoing network access of the agents
+    pub no_network: bool,
+}
+
+#[async_trait]
+impl Bootstrap<Context> for EmbeddedBootstrap {
+    fn create_active_workers(&self, golem_config: &GolemConfig) -> Arc<ActiveWorkers<Context>> {
+        ServerBootstrap {}.create_active_workers(golem_config)
+    }
+
+    fn create_agent_deployments_service(
+        &self,
+        _config: &AgentDeploymentsServiceConfig,
+        _registry_service: Arc<dyn RegistryService>,
+    ) -> Arc<dyn AgentDeploymentsService> {
+        Arc::new(DisabledAgentDeploymentsService)
+    }
+
+    fn create_network_egress_policy_service(
+        &self,
+        _config: &NetworkEgressPolicyServiceConfig,
+        _registry_service: Arc<dyn RegistryService>,
+    ) -> Arc<dyn NetworkEgressPolicyService> {
+        if self.no_network {
+            Arc::new(NoNetworkEgressPolicyService)
+        } else {
+            Arc::new(UnrestrictedNetworkEgressPolicyService)
+        }
+    }
+
+    fn create_component_service(
+        &self,
+        _golem_config: &GolemConfig,
+        _registry_service: Arc<dyn RegistryService>,
+        blob_storage: Arc<dyn BlobStorage>,
+    ) -> Arc<dyn ComponentService> {
+        Arc::new(ComponentServiceLocalFileSystem::new(
+            &self.component_service_directory,
+            10000,
+            Duration::from_secs(3600),
+            Arc::new(DefaultCompiledComponentService::new(blob_storage)),
+        ))
+    }
+
+    async fn create_services(
+        &self,
+        active_workers: Arc<ActiveWorkers<Context>>,
+        engine: Arc<Engine>,
+        linker: Arc<Linker<Context>>,
+        runtime: Handle,
+        component_service: Arc<dyn ComponentService>,
+        shard_manager_service: Arc<dyn ShardManagerService>,
+        worker_service: Arc<dyn WorkerService>,
+        worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
+        running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
+        promise_service: Arc<dyn PromiseService>,
+        golem_config: Arc<GolemConfig>,
+        shard_service: Arc<dyn ShardService>,
+        key_value_service: Arc<dyn KeyValueService>,
+        blob_store_service: Arc<dyn BlobStoreService>,
+        rdbms_service: Arc<dyn rdbms::RdbmsService>,
+        worker_activator: Arc<dyn WorkerActivator<Context>>,
+        oplog_service: Arc<dyn OplogService>,
+        scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
+        worker_proxy: Arc<dyn WorkerProxy>,
+        events: Arc<Events>,
+        file_loader: Arc<FileLoader>,
+        oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
+        agent_type_service: Arc<dyn AgentTypesService>,
+        agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
+        registry_service: Arc<dyn RegistryService>,
+        shutdown_token: tokio_util::sync::CancellationToken,
+        leak_sentinel: Arc<()>,
+    ) -> anyhow::Result<All<Context>> {
+        ServerBootstrap {}
+            .create_services(
+                active_workers,
+                engine,
+                linker,
+                runtime,
+                component_service,
+                shard_manager_service,
+                worker_service,
+                worker_enumeration_service,
+                running_worker_enumeration_service,
+                promise_service,
+                golem_config,
+                shard_service,
+                key_value_service,
+                blob_store_service,
+                rdbms_service,
+                worker_activator,
+                oplog_service,
+                scheduler_service,
+                pubsub_service,
+                agent_lifecycle_event_service,
+                worker_proxy,
+                events,
+                file_loader,
+                oplog_processor_plugin,
+                agent_type_service,
+                agent_webhooks_service,
+                network_egress_policy_service,
+                registry_service,
+                shutdown_token,
+                leak_sentinel,
+            )
+            .await
+    }
+
+    fn create_wasmtime_linker(&self, engine: &Engine) -> anyhow::Result<Linker<Context>> {
+        ServerBootstrap {}.create_wasmtime_linker(engine)
+    }
+}
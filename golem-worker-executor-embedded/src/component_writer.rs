@@ -20,8 +20,8 @@ use golem_common::model::agent::AgentType;
 use golem_common::model::application::ApplicationId;
 use golem_common::model::auth::EnvironmentRole;
 use golem_common::model::component::{
-    ComponentDto, ComponentFilePath, ComponentId, ComponentName, ComponentRevision,
-    InitialComponentFile,
+    AgentResourceLimits, ComponentDto, ComponentFilePath, ComponentId, ComponentName,
+    ComponentRevision, InitialComponentFile,
 };
 use golem_common::model::component_metadata::{
     ComponentMetadata, LinearMemory, RawComponentMetadata,
@@ -92,6 +92,7 @@ impl FileSystemComponentWriter {
         skip_analysis: bool,
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         environment_id: EnvironmentId,
         application_id: ApplicationId,
         account_id: AccountId,
@@ -186,6 +187,7 @@ impl FileSystemComponentWriter {
             wasm_filename,
             env,
             config_vars,
+            agent_resource_limits,
             agent_types,
             target_path,
             root_package_name,
@@ -250,6 +252,7 @@ impl FileSystemComponentWriter {
         unverified: bool,
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         environment_id: EnvironmentId,
         application_id: ApplicationId,
         account_id: AccountId,
@@ -263,6 +266,7 @@ impl FileSystemComponentWriter {
             unverified,
             env,
             config_vars,
+            agent_resource_limits,
             environment_id,
             application_id,
             account_id,
@@ -281,6 +285,7 @@ impl FileSystemComponentWriter {
         unverified: bool,
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         environment_id: EnvironmentId,
         application_id: ApplicationId,
         account_id: AccountId,
@@ -296,6 +301,7 @@ impl FileSystemComponentWriter {
             unverified,
             env,
             config_vars,
+            agent_resource_limits,
             environment_id,
             application_id,
             account_id,
@@ -324,6 +330,7 @@ impl FileSystemComponentWriter {
             false,
             BTreeMap::new(),
             BTreeMap::new(),
+            Vec::new(),
             environment_id,
             application_id,
             account_id,
@@ -380,6 +387,7 @@ impl FileSystemComponentWriter {
                 false,
                 env.unwrap_or(old_metadata.env),
                 config_vars.unwrap_or(old_metadata.config_vars),
+                old_metadata.agent_resource_limits,
                 old_metadata.environment_id,
                 old_metadata.application_id,
                 old_metadata.account_id,
@@ -549,6 +557,8 @@ pub(super) struct LocalFileSystemComponentMetadata {
     pub wasm_filename: String,
     pub env: BTreeMap<String, String>,
     pub config_vars: BTreeMap<String, String>,
+    #[serde(default)]
+    pub agent_resource_limits: Vec<AgentResourceLimits>,
     pub wasm_hash: golem_common::model::diff::Hash,
     pub agent_types: Vec<AgentType>,
     pub environment_roles_from_shares: HashSet<EnvironmentRole>,
@@ -592,10 +602,13 @@ impl From<LocalFileSystemComponentMetadata> for Component {
             installed_plugins: vec![],
             env: value.env,
             local_agent_config: Vec::new(),
+            agent_resource_limits: value.agent_resource_limits,
             config_vars: value.config_vars,
             wasm_hash: value.wasm_hash,
             hash: value.final_hash,
             object_store_key: "".to_string(),
+            original_object_store_key: None,
+            transformations: vec![],
         }
     }
 }
@@ -0,0 +1,527 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An in-process worker executor for unit testing agents.
+//!
+//! [`EmbeddedExecutor`] runs the executor services inside the current process with in-memory
+//! key-value, indexed and blob storage, loads components directly from files and calls the
+//! executor API without going through gRPC, so neither Redis nor any other Golem service is
+//! needed.
+//!
+//! [`EmbeddedExecutor::start_sandbox`] starts one for replaying recorded oplogs, where the agents
+//! receive the recorded host call responses instead of reaching the outside world.
+
+pub mod agent_deployments_service;
+mod bootstrap;
+pub mod component_service;
+pub mod component_writer;
+pub mod network_egress_policy_service;
+
+#[cfg(test)]
+test_r::enable!();
+
+pub use golem_worker_executor::services::recorded_host_calls::RecordedHostCall;
+
+use crate::bootstrap::EmbeddedBootstrap;
+use crate::component_writer::FileSystemComponentWriter;
+use anyhow::anyhow;
+use chrono::{DateTime, TimeDelta, Utc};
+use golem_api_grpc::proto::golem::worker::InvocationPriority;
+use golem_api_grpc::proto::golem::workerexecutor;
+use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
+use golem_api_grpc::proto::golem::workerexecutor::v1::{
+    complete_promise_response, get_oplog_response, interrupt_worker_response,
+    CompletePromiseRequest, InterruptWorkerRequest,
+};
+use golem_common::base_model::agent::Principal;
+use golem_common::base_model::agent::{AgentId, DataValue, ElementValues, UntypedDataValue};
+use golem_common::model::account::AccountId;
+use golem_common::model::application::ApplicationId;
+use golem_common::model::auth::AccountRole;
+use golem_common::model::component::ComponentDto;
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::oplog::{OplogEntry, PublicOplogEntry, PublicOplogEntryWithIndex};
+use golem_common::model::plan::PlanId;
+use golem_common::model::{
+    IdempotencyKey, OplogIndex, OwnedWorkerId, PromiseId, RetryConfig, WorkerId,
+};
+use golem_service_base::config::{BlobStorageConfig, InMemoryBlobStorageConfig};
+use golem_service_base::model::auth::{AuthCtx, UserAuthCtx};
+use golem_service_base::service::compiled_component::{
+    CompiledComponentServiceConfig, CompiledComponentServiceEnabledConfig,
+};
+use golem_worker_executor::grpc::WorkerExecutorImpl;
+use golem_worker_executor::services::golem_config::{
+    AgentLifecycleEventsConfig, AgentTypesServiceConfig, AgentTypesServiceLocalConfig,
+    EngineConfig, GolemConfig, IndexedStorageConfig, IndexedStorageInMemoryConfig,
+    KeyValueStorageConfig, KeyValueStorageInMemoryConfig, ResourceLimitsConfig,
+    ResourceLimitsDisabledConfig, ShardManagerServiceConfig, ShardManagerServiceSingleShardConfig,
+};
+use golem_worker_executor::services::shutdown::Shutdown;
+use golem_worker_executor::services::worker_activator::LazyWorkerActivator;
+use golem_worker_executor::services::{All, HasExtraDeps, HasSchedulerService};
+use golem_worker_executor::worker::Worker;
+use golem_worker_executor::workerctx::default::Context;
+use golem_worker_executor::{create_worker_executor_impl, RunDetails};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::runtime::Handle;
+use tokio::sync::Mutex;
+use tonic::Request;
+
+/// The executor configuration used by [`EmbeddedExecutor::start`]
+pub fn embedded_golem_config() -> GolemConfig {
+    GolemConfig {
+        key_value_storage: KeyValueStorageConfig::InMemory(KeyValueStorageInMemoryConfig {}),
+        indexed_storage: IndexedStorageConfig::InMemory(IndexedStorageInMemoryConfig {}),
+        blob_storage: BlobStorageConfig::InMemory(InMemoryBlobStorageConfig {}),
+        compiled_component_service: CompiledComponentServiceConfig::Enabled(
+            CompiledComponentServiceEnabledConfig {},
+        ),
+        shard_manager_service: ShardManagerServiceConfig::SingleShard(
+            ShardManagerServiceSingleShardConfig {},
+        ),
+        agent_types_service: AgentTypesServiceConfig::Local(AgentTypesServiceLocalConfig {}),
+        resource_limits: ResourceLimitsConfig::Disabled(ResourceLimitsDisabledConfig {}),
+        agent_lifecycle_events: AgentLifecycleEventsConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        engine: EngineConfig {
+            enable_fs_cache: true,
+        },
+        ..Default::default()
+    }
+}
+
+/// A worker executor running inside the current process, see the [crate documentation](crate)
+pub struct EmbeddedExecutor {
+    executor: WorkerExecutorImpl<Context, All<Context>>,
+    services: All<Context>,
+    component_writer: FileSystemComponentWriter,
+    /// The account, application and environment owning every component of the executor
+    account_id: AccountId,
+    account_plan_id: PlanId,
+    account_roles: BTreeSet<AccountRole>,
+    application_id: ApplicationId,
+    environment_id: EnvironmentId,
+    /// How far the virtual clock used for scheduled invocations is ahead of the real one
+    clock_offset: Mutex<TimeDelta>,
+    /// Stops the executor's background tasks when the embedded executor is dropped
+    _run_details: RunDetails,
+    _data_dir: TempDir,
+}
+
+impl EmbeddedExecutor {
+    pub async fn start() -> anyhow::Result<Self> {
+        Self::start_with_config(embedded_golem_config()).await
+    }
+
+    pub async fn start_with_config(golem_config: GolemConfig) -> anyhow::Result<Self> {
+        Self::start_with(golem_config, false).await
+    }
+
+    /// Starts an embedded executor for replaying recorded oplogs. Its agents have no network
+    /// access and failed host calls are not retried, so they can only continue with the responses
+    /// set by [`Self::use_recorded_host_calls`].
+    pub async fn start_sandbox() -> anyhow::Result<Self> {
+        Self::start_with(
+            GolemConfig {
+                retry: RetryConfig::no_retries(),
+                ..embedded_golem_config()
+            },
+            true,
+        )
+        .await
+    }
+
+    async fn start_with(golem_config: GolemConfig, no_network: bool) -> anyhow::Result<Self> {
+        let data_dir = TempDir::new()?;
+        let component_service_directory = data_dir.path().join("components");
+
+        let bootstrap = EmbeddedBootstrap {
+            component_service_directory: component_service_directory.clone(),
+            no_network,
+        };
+        let lazy_worker_activator = Arc::new(LazyWorkerActivator::new());
+        let shutdown = Shutdown::new();
+
+        let (services, epoch_thread, epoch_stop) = create_worker_executor_impl(
+            golem_config,
+            &bootstrap,
+            Handle::current(),
+            &lazy_worker_activator,
+            shutdown.token(),
+        )
+        .await?;
+        let leak_detector = services.leak_detector();
+
+        let executor = WorkerExecutorImpl::new(services.clone(), lazy_worker_activator, 0).await?;
+
+        Ok(Self {
+            executor,
+            services,
+            component_writer: FileSystemComponentWriter::new(&component_service_directory).await,
+            account_id: AccountId::new(),
+            account_plan_id: PlanId::new(),
+            account_roles: BTreeSet::new(),
+            application_id: ApplicationId::new(),
+            environment_id: EnvironmentId::new(),
+            clock_offset: Mutex::new(TimeDelta::zero()),
+            _run_details: RunDetails {
+                http_port: 0,
+                grpc_port: 0,
+                epoch_thread: std::sync::Mutex::new(Some(epoch_thread)),
+                epoch_stop,
+                shutdown,
+                leak_detector,
+            },
+            _data_dir: data_dir,
+        })
+    }
+
+    /// Loads a component from a WASM file, naming it after the file
+    pub async fn add_component(&self, wasm_path: &Path) -> anyhow::Result<ComponentDto> {
+        let name = wasm_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("Invalid component path: {wasm_path:?}"))?;
+
+        let component = self
+            .component_writer
+            .add_component(
+                wasm_path,
+                name,
+                Vec::new(),
+                false,
+                BTreeMap::new(),
+                BTreeMap::new(),
+                Vec::new(),
+                self.environment_id,
+                self.application_id,
+                self.account_id,
+                HashSet::new(),
+                None,
+            )
+            .await?;
+
+        Ok(component.into())
+    }
+
+    pub async fn invoke_and_await_agent(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        method_name: &str,
+        params: DataValue,
+    ) -> anyhow::Result<DataValue> {
+        self.invoke_and_await_agent_with_key(
+            component,
+            agent_id,
+            &IdempotencyKey::fresh(),
+            method_name,
+            params,
+        )
+        .await
+    }
+
+    pub async fn invoke_and_await_agent_with_key(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        idempotency_key: &IdempotencyKey,
+        method_name: &str,
+        params: DataValue,
+    ) -> anyhow::Result<DataValue> {
+        let worker_id = WorkerId::from_agent_id(component.id, agent_id)
+            .map_err(|err| anyhow!("Invalid agent id: {err}"))?;
+
+        let response = self
+            .executor
+            .invoke_agent(Request::new(workerexecutor::v1::InvokeAgentRequest {
+                worker_id: Some(worker_id.into()),
+                method_name: method_name.to_string(),
+                method_parameters: Some(UntypedDataValue::from(params).into()),
+                mode: workerexecutor::v1::AgentInvocationMode::Await as i32,
+                schedule_at: None,
+                idempotency_key: Some(idempotency_key.clone().into()),
+                component_owner_account_id: Some(component.account_id.into()),
+                environment_id: Some(component.environment_id.into()),
+                auth_ctx: Some(self.auth_ctx().into()),
+                context: None,
+                principal: None,
+                priority: InvocationPriority::Normal as i32,
+            }))
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(workerexecutor::v1::invoke_agent_response::Result::Success(success)) => {
+                match success.result {
+                    Some(proto_val) => {
+                        let untyped_data_value = UntypedDataValue::try_from(proto_val)
+                            .map_err(|err| anyhow!("UntypedDataValue conversion error: {err}"))?;
+                        let agent_type = component
+                            .metadata
+                            .find_agent_type_by_wrapper_name(&agent_id.agent_type)
+                            .map_err(|err| anyhow!("Agent type not found: {err}"))?
+                            .ok_or_else(|| {
+                                anyhow!("Agent type not found: {}", agent_id.agent_type)
+                            })?;
+                        let agent_method = agent_type
+                            .methods
+                            .iter()
+                            .find(|method| method.name == method_name)
+                            .ok_or_else(|| anyhow!("Agent method not found: {method_name}"))?;
+
+                        DataValue::try_from_untyped(
+                            untyped_data_value,
+                            agent_method.output_schema.clone(),
+                        )
+                        .map_err(|err| anyhow!("DataValue conversion error: {err}"))
+                    }
+                    None => Ok(DataValue::Tuple(ElementValues { elements: vec![] })),
+                }
+            }
+            Some(workerexecutor::v1::invoke_agent_response::Result::Failure(error)) => {
+                Err(anyhow!("Agent invocation failed: {error:?}"))
+            }
+            None => Err(anyhow!("No response from invoke_agent")),
+        }
+    }
+
+    /// Schedules an invocation of the agent to be performed once the virtual clock reaches `at`
+    pub async fn schedule_agent_invocation(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        method_name: &str,
+        params: DataValue,
+        at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        let worker_id = WorkerId::from_agent_id(component.id, agent_id)
+            .map_err(|err| anyhow!("Invalid agent id: {err}"))?;
+
+        let response = self
+            .executor
+            .invoke_agent(Request::new(workerexecutor::v1::InvokeAgentRequest {
+                worker_id: Some(worker_id.into()),
+                method_name: method_name.to_string(),
+                method_parameters: Some(UntypedDataValue::from(params).into()),
+                mode: workerexecutor::v1::AgentInvocationMode::Schedule as i32,
+                schedule_at: Some(::prost_types::Timestamp {
+                    seconds: at.timestamp(),
+                    nanos: at.timestamp_subsec_nanos() as i32,
+                }),
+                idempotency_key: Some(IdempotencyKey::fresh().into()),
+                component_owner_account_id: Some(component.account_id.into()),
+                environment_id: Some(component.environment_id.into()),
+                auth_ctx: Some(self.auth_ctx().into()),
+                context: None,
+                principal: None,
+                priority: InvocationPriority::Normal as i32,
+            }))
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(workerexecutor::v1::invoke_agent_response::Result::Success(_)) => Ok(()),
+            Some(workerexecutor::v1::invoke_agent_response::Result::Failure(error)) => {
+                Err(anyhow!("Failed to schedule agent invocation: {error:?}"))
+            }
+            None => Err(anyhow!("No response from invoke_agent")),
+        }
+    }
+
+    /// Makes the agent receive the responses of the given host calls, in order, in place of the
+    /// live responses of its host calls. The live requests are still written to the agent's oplog,
+    /// together with the recorded responses. Once the agent calls a different host function than
+    /// the next recorded one, it continues with live responses.
+    pub async fn use_recorded_host_calls(
+        &self,
+        component: &ComponentDto,
+        agent_id: &AgentId,
+        host_calls: Vec<RecordedHostCall>,
+    ) -> anyhow::Result<()> {
+        let worker_id = WorkerId::from_agent_id(component.id, agent_id)
+            .map_err(|err| anyhow!("Invalid agent id: {err}"))?;
+        self.services.extra_deps().set(worker_id, host_calls).await;
+        Ok(())
+    }
+
+    /// The current time of the virtual clock driving scheduled invocations
+    pub async fn now(&self) -> DateTime<Utc> {
+        Utc::now() + *self.clock_offset.lock().await
+    }
+
+    /// Advances the virtual clock, performing every scheduled invocation and promise timeout
+    /// that becomes due in the skipped period. The clock of the agents themselves is not changed.
+    pub async fn advance_clock(&self, duration: Duration) -> anyhow::Result<()> {
+        let mut clock_offset = self.clock_offset.lock().await;
+        let delta = TimeDelta::from_std(duration)?;
+        let target = Utc::now() + *clock_offset + delta;
+
+        // the scheduler only looks at the current and previous hour, so longer jumps are
+        // processed in one hour steps
+        let mut now = Utc::now() + *clock_offset;
+        loop {
+            now = (now + TimeDelta::hours(1)).min(target);
+            self.services
+                .scheduler_service()
+                .process_due(now)
+                .await
+                .map_err(|err| anyhow!("Failed to process scheduled actions: {err}"))?;
+            if now == target {
+                break;
+            }
+        }
+
+        *clock_offset += delta;
+        Ok(())
+    }
+
+    pub async fn complete_promise(
+        &self,
+        promise_id: &PromiseId,
+        data: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let response = self
+            .executor
+            .complete_promise(Request::new(CompletePromiseRequest {
+                promise_id: Some(promise_id.clone().into()),
+                environment_id: Some(self.environment_id.into()),
+                data,
+                auth_ctx: Some(self.auth_ctx().into()),
+                failed: false,
+            }))
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(complete_promise_response::Result::Success(_)) => Ok(()),
+            Some(complete_promise_response::Result::Failure(error)) => {
+                Err(anyhow!("Failed to complete promise: {error:?}"))
+            }
+            None => Err(anyhow!("No response from complete_promise")),
+        }
+    }
+
+    /// Interrupts the agent as if its executor crashed, and immediately recovers it by replaying
+    /// its oplog
+    pub async fn simulated_crash(&self, worker_id: &WorkerId) -> anyhow::Result<()> {
+        let response = self
+            .executor
+            .interrupt_worker(Request::new(InterruptWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                environment_id: Some(self.environment_id.into()),
+                recover_immediately: true,
+                auth_ctx: Some(self.auth_ctx().into()),
+                principal: None,
+            }))
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(interrupt_worker_response::Result::Success(_)) => Ok(()),
+            Some(interrupt_worker_response::Result::Failure(error)) => {
+                Err(anyhow!("Failed to interrupt worker: {error:?}"))
+            }
+            None => Err(anyhow!("No response from interrupt_worker")),
+        }
+    }
+
+    /// Appends an entry to the oplog of an existing agent as if the agent had written it, for
+    /// reaching states which are hard to trigger through invocations. Returns the entry's index.
+    pub async fn append_to_oplog(
+        &self,
+        worker_id: &WorkerId,
+        entry: OplogEntry,
+    ) -> anyhow::Result<OplogIndex> {
+        let worker = Worker::get_or_create_suspended(
+            &self.services,
+            self.account_id,
+            &OwnedWorkerId::new(self.environment_id, worker_id),
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+            &InvocationContextStack::fresh(),
+            Principal::anonymous(),
+        )
+        .await?;
+        Ok(worker.add_and_commit_oplog(entry).await)
+    }
+
+    pub async fn get_oplog(
+        &self,
+        worker_id: &WorkerId,
+        from: OplogIndex,
+    ) -> anyhow::Result<Vec<PublicOplogEntryWithIndex>> {
+        let mut result = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let response = self
+                .executor
+                .get_oplog(Request::new(workerexecutor::v1::GetOplogRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    environment_id: Some(self.environment_id.into()),
+                    from_oplog_index: from.into(),
+                    cursor,
+                    count: 100,
+                    auth_ctx: Some(self.auth_ctx().into()),
+                }))
+                .await?
+                .into_inner();
+
+            match response.result {
+                Some(get_oplog_response::Result::Success(chunk)) => {
+                    if chunk.entries.is_empty() {
+                        break;
+                    }
+                    for (chunk_idx, entry) in chunk.entries.into_iter().enumerate() {
+                        let entry = PublicOplogEntry::try_from(entry)
+                            .map_err(|err| anyhow!("Failed to convert oplog entry: {err}"))?;
+                        result.push(PublicOplogEntryWithIndex {
+                            entry,
+                            oplog_index: OplogIndex::from_u64(
+                                chunk.first_index_in_chunk + chunk_idx as u64,
+                            ),
+                        });
+                    }
+                    cursor = chunk.next;
+                }
+                Some(get_oplog_response::Result::Failure(error)) => {
+                    return Err(anyhow!("Failed to get oplog: {error:?}"));
+                }
+                None => break,
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn auth_ctx(&self) -> AuthCtx {
+        AuthCtx::User(UserAuthCtx {
+            account_id: self.account_id,
+            account_plan_id: self.account_plan_id,
+            account_roles: self.account_roles.clone(),
+        })
+    }
+}
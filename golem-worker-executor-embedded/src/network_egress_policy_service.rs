@@ -0,0 +1,47 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::model::environment::{EnvironmentId, NetworkEgressPolicy};
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
+use std::sync::Arc;
+
+pub struct UnrestrictedNetworkEgressPolicyService;
+
+#[async_trait]
+impl NetworkEgressPolicyService for UnrestrictedNetworkEgressPolicyService {
+    async fn get_network_egress_policy(
+        &self,
+        _environment: EnvironmentId,
+    ) -> Result<Arc<NetworkEgressPolicy>, WorkerExecutorError> {
+        Ok(Arc::new(NetworkEgressPolicy::default()))
+    }
+}
+
+/// Denies all outgoing network access, used by sandboxed executors
+pub struct NoNetworkEgressPolicyService;
+
+#[async_trait]
+impl NetworkEgressPolicyService for NoNetworkEgressPolicyService {
+    async fn get_network_egress_policy(
+        &self,
+        _environment: EnvironmentId,
+    ) -> Result<Arc<NetworkEgressPolicy>, WorkerExecutorError> {
+        Ok(Arc::new(NetworkEgressPolicy {
+            no_network: true,
+            ..NetworkEgressPolicy::default()
+        }))
+    }
+}
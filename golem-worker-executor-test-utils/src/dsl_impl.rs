@@ -16,7 +16,7 @@ use super::TestWorkerExecutor;
 use anyhow::anyhow;
 use applying::Apply;
 use bytes::Bytes;
-use golem_api_grpc::proto::golem::worker::{LogEvent, UpdateMode};
+use golem_api_grpc::proto::golem::worker::{InvocationPriority, LogEvent, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     cancel_invocation_response, complete_promise_response, create_worker_response,
@@ -30,8 +30,8 @@ use golem_api_grpc::proto::golem::workerexecutor::v1::{
 };
 use golem_common::base_model::agent::{AgentId, DataValue, UntypedDataValue};
 use golem_common::model::component::{
-    ComponentDto, ComponentFilePath, ComponentId, ComponentName, ComponentRevision,
-    InitialComponentFile, LocalAgentConfigEntry, PluginInstallation,
+    AgentResourceLimits, ComponentDto, ComponentFilePath, ComponentId, ComponentName,
+    ComponentRevision, InitialComponentFile, LocalAgentConfigEntry, PluginInstallation,
 };
 use golem_common::model::deployment::DeploymentRevision;
 use golem_common::model::environment::EnvironmentId;
@@ -74,6 +74,7 @@ impl TestDsl for TestWorkerExecutor {
         env: BTreeMap<String, String>,
         config_vars: BTreeMap<String, String>,
         local_agent_config: Vec<LocalAgentConfigEntry>,
+        agent_resource_limits: Vec<AgentResourceLimits>,
         plugins: Vec<PluginInstallation>,
     ) -> anyhow::Result<ComponentDto> {
         if !local_agent_config.is_empty() {
@@ -141,6 +142,7 @@ impl TestDsl for TestWorkerExecutor {
                         unverified,
                         env,
                         config_vars,
+                        agent_resource_limits,
                         environment_id,
                         self.context.application_id,
                         self.context.account_id,
@@ -159,6 +161,7 @@ impl TestDsl for TestWorkerExecutor {
                         unverified,
                         env,
                         config_vars,
+                        agent_resource_limits,
                         environment_id,
                         self.context.application_id,
                         self.context.account_id,
@@ -356,6 +359,7 @@ impl TestDsl for TestWorkerExecutor {
                 auth_ctx: Some(self.auth_ctx().into()),
                 context: None,
                 principal: None,
+                priority: InvocationPriority::Normal as i32,
             })
             .await;
 
@@ -403,6 +407,7 @@ impl TestDsl for TestWorkerExecutor {
                 auth_ctx: Some(self.auth_ctx().into()),
                 context: None,
                 principal: None,
+                priority: InvocationPriority::Normal as i32,
             })
             .await;
 
@@ -695,6 +700,7 @@ impl TestDsl for TestWorkerExecutor {
                 environment_id: Some(latest_version.environment_id.into()),
                 data,
                 auth_ctx: Some(self.auth_ctx().into()),
+                failed: false,
             })
             .await?
             .into_inner();
@@ -708,6 +714,34 @@ impl TestDsl for TestWorkerExecutor {
         }
     }
 
+    #[tracing::instrument(level = "info", skip_all, fields(%promise_id))]
+    async fn fail_promise(&self, promise_id: &PromiseId, error: Vec<u8>) -> anyhow::Result<()> {
+        let latest_version = self
+            .get_latest_component_revision(&promise_id.worker_id.component_id)
+            .await?;
+
+        let response = self
+            .client
+            .clone()
+            .complete_promise(CompletePromiseRequest {
+                promise_id: Some(promise_id.clone().into()),
+                environment_id: Some(latest_version.environment_id.into()),
+                data: error,
+                auth_ctx: Some(self.auth_ctx().into()),
+                failed: true,
+            })
+            .await?
+            .into_inner();
+
+        match response.result {
+            Some(complete_promise_response::Result::Success(_)) => Ok(()),
+            Some(complete_promise_response::Result::Failure(error)) => {
+                Err(anyhow!("Failed to fail promise: {error:?}"))
+            }
+            None => Err(anyhow!("No response from fail promise")),
+        }
+    }
+
     #[tracing::instrument(level = "info", skip_all, fields(%worker_id))]
     async fn make_worker_log_event_stream(
         &self,
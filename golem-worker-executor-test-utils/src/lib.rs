@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub mod agent_deployments_service;
-pub mod component_service;
-pub mod component_writer;
 pub mod dsl_impl;
 
+pub use golem_worker_executor_embedded::{
+    agent_deployments_service, component_service, component_writer, network_egress_policy_service,
+};
+
 use self::agent_deployments_service::DisabledAgentDeploymentsService;
 use self::component_writer::FileSystemComponentWriter;
+use self::network_egress_policy_service::UnrestrictedNetworkEgressPolicyService;
 use crate::component_service::ComponentServiceLocalFileSystem;
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
@@ -48,7 +50,9 @@ use golem_common::model::{
 };
 use golem_service_base::clients::registry::RegistryService;
 use golem_service_base::config::{BlobStorageConfig, LocalFileSystemBlobStorageConfig};
-use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
+use golem_service_base::error::worker_executor::{
+    GolemSpecificWasmTrap, InterruptKind, WorkerExecutorError,
+};
 use golem_service_base::grpc::server::GrpcServerTlsConfig;
 use golem_service_base::model::auth::{AuthCtx, UserAuthCtx};
 use golem_service_base::model::component::Component;
@@ -73,12 +77,14 @@ use golem_worker_executor::model::{
     ExecutionStatus, LastError, ReadFileResult, TrapType, WorkerConfig,
 };
 use golem_worker_executor::preview2::golem::agent::host::{
-    CancellationToken, FutureInvokeResult, HostFutureInvokeResult, HostWasmRpc, RpcError, WasmRpc,
+    CancellationToken, FutureInvokeResult, HostFutureInvokeResult, HostWasmRpc, MissedFirePolicy,
+    RecurringInvocation, RecurringSchedule, RpcError, WasmRpc,
 };
 use golem_worker_executor::preview2::golem::durability;
 use golem_worker_executor::preview2::golem_api_1_x;
 use golem_worker_executor::services::active_workers::ActiveWorkers;
 use golem_worker_executor::services::agent_deployments::AgentDeploymentsService;
+use golem_worker_executor::services::agent_lifecycle_events::AgentLifecycleEventService;
 use golem_worker_executor::services::agent_types::AgentTypesService;
 use golem_worker_executor::services::agent_webhooks::AgentWebhooksService;
 use golem_worker_executor::services::blob_store::BlobStoreService;
@@ -89,12 +95,15 @@ use golem_worker_executor::services::golem_config::{
     AgentDeploymentsServiceConfig, AgentTypesServiceConfig, AgentTypesServiceLocalConfig,
     EngineConfig, GolemConfig, GrpcApiConfig, IndexedStorageConfig,
     IndexedStorageKVStoreRedisConfig, KeyValueStorageConfig, MemoryConfig,
-    ShardManagerServiceConfig, ShardManagerServiceSingleShardConfig, SnapshotPolicy,
+    NetworkEgressPolicyServiceConfig, OplogRetentionConfig, ShardManagerServiceConfig,
+    ShardManagerServiceSingleShardConfig, SnapshotPolicy,
 };
 use golem_worker_executor::services::key_value::KeyValueService;
+use golem_worker_executor::services::network_egress_policy::NetworkEgressPolicyService;
 use golem_worker_executor::services::oplog::plugin::OplogProcessorPlugin;
 use golem_worker_executor::services::oplog::{CommitLevel, Oplog, OplogService};
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms::mysql::MysqlType;
 use golem_worker_executor::services::rdbms::postgres::PostgresType;
 use golem_worker_executor::services::rdbms::{
@@ -401,12 +410,42 @@ pub async fn start_with_snapshot_policy(
     start_customized(deps, context, None, None, Some(snapshot_policy)).await
 }
 
+pub async fn start_with_oplog_retention(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    snapshot_policy: SnapshotPolicy,
+    oplog_retention: OplogRetentionConfig,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_with_config(deps, context, None, move |config| {
+        config.oplog.default_snapshotting = snapshot_policy;
+        config.oplog.retention = oplog_retention;
+    })
+    .await
+}
+
 pub async fn start_customized(
     deps: &WorkerExecutorTestDependencies,
     context: &TestContext,
     system_memory_override: Option<u64>,
     retry_override: Option<RetryConfig>,
     snapshot_policy_override: Option<SnapshotPolicy>,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_with_config(deps, context, system_memory_override, move |config| {
+        if let Some(retry) = retry_override {
+            config.retry = retry;
+        }
+        if let Some(snapshot_policy) = snapshot_policy_override {
+            config.oplog.default_snapshotting = snapshot_policy;
+        }
+    })
+    .await
+}
+
+async fn start_with_config(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    system_memory_override: Option<u64>,
+    customize: impl FnOnce(&mut GolemConfig),
 ) -> anyhow::Result<TestWorkerExecutor> {
     let redis = deps.redis.clone();
     let redis_monitor = deps.redis_monitor.clone();
@@ -447,12 +486,7 @@ pub async fn start_customized(
         },
         ..Default::default()
     };
-    if let Some(retry) = retry_override {
-        config.retry = retry;
-    }
-    if let Some(snapshot_policy) = snapshot_policy_override {
-        config.oplog.default_snapshotting = snapshot_policy;
-    }
+    customize(&mut config);
 
     let handle = Handle::current();
 
@@ -515,6 +549,7 @@ async fn run(
 
 struct TestWorkerCtx {
     durable_ctx: DurableWorkerCtx<TestWorkerCtx>,
+    last_fuel_level: u64,
 }
 
 impl DurableWorkerCtxView<TestWorkerCtx> for TestWorkerCtx {
@@ -549,9 +584,15 @@ impl FuelManagement for TestWorkerCtx {
         true
     }
 
-    fn return_fuel(&mut self, _current_level: u64) -> u64 {
+    fn return_fuel(&mut self, current_level: u64) -> u64 {
+        self.last_fuel_level = current_level;
         0
     }
+
+    fn check_invocation_limits(&self, current_level: u64) -> Option<GolemSpecificWasmTrap> {
+        let consumed = self.last_fuel_level.saturating_sub(current_level);
+        self.durable_ctx.check_invocation_limits(consumed)
+    }
 }
 
 #[async_trait]
@@ -677,6 +718,14 @@ impl InvocationHooks for TestWorkerCtx {
     async fn get_current_retry_point(&self) -> OplogIndex {
         self.durable_ctx.get_current_retry_point().await
     }
+
+    fn begin_read_only_query(&mut self) {
+        self.durable_ctx.begin_read_only_query()
+    }
+
+    fn end_read_only_query(&mut self) {
+        self.durable_ctx.end_read_only_query()
+    }
 }
 
 #[async_trait]
@@ -756,6 +805,7 @@ impl WorkerCtx for TestWorkerCtx {
         oplog: Arc<dyn Oplog>,
         invocation_queue: Weak<Worker<TestWorkerCtx>>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
@@ -768,6 +818,7 @@ impl WorkerCtx for TestWorkerCtx {
         _resource_limits: Arc<dyn ResourceLimits>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shard_service: Arc<dyn ShardService>,
         pending_update: Option<TimestampedUpdateDescription>,
         original_phantom_id: Option<Uuid>,
@@ -792,6 +843,7 @@ impl WorkerCtx for TestWorkerCtx {
             oplog,
             invocation_queue,
             scheduler_service,
+            pubsub_service,
             rpc,
             worker_proxy,
             component_service,
@@ -802,12 +854,16 @@ impl WorkerCtx for TestWorkerCtx {
             worker_fork,
             agent_types_service,
             agent_webhooks_service,
+            network_egress_policy_service,
             shard_service,
             pending_update,
             original_phantom_id,
         )
         .await?;
-        Ok(Self { durable_ctx })
+        Ok(Self {
+            durable_ctx,
+            last_fuel_level: u64::MAX,
+        })
     }
 
     fn as_wasi_view(&mut self) -> impl WasiView {
@@ -885,6 +941,14 @@ impl ResourceLimiterAsync for TestWorkerCtx {
             current,
             desired
         );
+        if self
+            .durable_ctx
+            .agent_max_memory()
+            .is_some_and(|agent_limit| desired > agent_limit)
+        {
+            Err(self.durable_ctx.agent_memory_limit_exceeded())?;
+        }
+
         let current_known = self.durable_ctx.total_linear_memory_size();
         let delta = (desired as u64).saturating_sub(current_known);
         if delta > 0 {
@@ -1000,6 +1064,36 @@ impl HostWasmRpc for TestWorkerCtx {
             .await
     }
 
+    async fn schedule_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<Result<RecurringInvocation, String>> {
+        self.durable_ctx
+            .schedule_recurring_invocation(self_, schedule, missed_fire_policy, method_name, input)
+            .await
+    }
+
+    async fn list_recurring_invocations(
+        &mut self,
+        self_: Resource<WasmRpc>,
+    ) -> anyhow::Result<Vec<RecurringInvocation>> {
+        self.durable_ctx.list_recurring_invocations(self_).await
+    }
+
+    async fn cancel_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        id: golem_wasm::Uuid,
+    ) -> anyhow::Result<bool> {
+        self.durable_ctx
+            .cancel_recurring_invocation(self_, id)
+            .await
+    }
+
     async fn drop(&mut self, rep: Resource<WasmRpc>) -> anyhow::Result<()> {
         HostWasmRpc::drop(&mut self.durable_ctx, rep).await
     }
@@ -1092,6 +1186,14 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
         Arc::new(DisabledAgentDeploymentsService)
     }
 
+    fn create_network_egress_policy_service(
+        &self,
+        _config: &NetworkEgressPolicyServiceConfig,
+        _registry_service: Arc<dyn RegistryService>,
+    ) -> Arc<dyn NetworkEgressPolicyService> {
+        Arc::new(UnrestrictedNetworkEgressPolicyService)
+    }
+
     fn create_component_service(
         &self,
         _golem_config: &GolemConfig,
@@ -1126,18 +1228,22 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator<TestWorkerCtx>>,
         oplog_service: Arc<dyn OplogService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
         worker_proxy: Arc<dyn WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         registry_service: Arc<dyn RegistryService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         leak_sentinel: Arc<()>,
     ) -> anyhow::Result<All<TestWorkerCtx>> {
         let resource_limits = resource_limits::configured(
             &golem_config.resource_limits,
+            &golem_config.usage_metering,
             registry_service,
             shutdown_token.clone(),
         );
@@ -1169,11 +1275,14 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
             rdbms_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
             shutdown_token.clone(),
@@ -1204,11 +1313,14 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
             rdbms_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             shutdown_token.clone(),
             agent_types_service.clone(),
             agent_webhooks_service.clone(),
@@ -1237,12 +1349,15 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator,
             worker_proxy,
             events,
             file_loader,
             oplog_processor_plugin,
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             extra_deps.clone(),
             leak_sentinel,
@@ -1263,6 +1378,10 @@ impl Bootstrap<TestWorkerCtx> for TestServerBootstrap {
             &mut linker,
             get_durable_ctx,
         )?;
+        golem_api_1_x::pubsub::add_to_linker::<_, HasSelf<DurableWorkerCtx<TestWorkerCtx>>>(
+            &mut linker,
+            get_durable_ctx,
+        )?;
         durability::durability::add_to_linker::<_, HasSelf<DurableWorkerCtx<TestWorkerCtx>>>(
             &mut linker,
             get_durable_ctx,
@@ -1372,10 +1491,20 @@ impl Oplog for TestOplog {
         self.oplog.drop_prefix(last_dropped_id).await
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        self.oplog
+            .drop_range(first_dropped_id, last_dropped_id)
+            .await
+    }
+
     async fn commit(&self, level: CommitLevel) -> BTreeMap<OplogIndex, OplogEntry> {
         self.oplog.commit(level).await
     }
 
+    async fn take_committed_bytes(&self) -> u64 {
+        self.oplog.take_committed_bytes().await
+    }
+
     async fn current_oplog_index(&self) -> OplogIndex {
         self.oplog.current_oplog_index().await
     }
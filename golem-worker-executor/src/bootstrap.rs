@@ -15,6 +15,7 @@
 use crate::durable_host::DurableWorkerCtx;
 use crate::preview2::{golem_api_1_x, golem_durability};
 use crate::services::active_workers::ActiveWorkers;
+use crate::services::agent_lifecycle_events::AgentLifecycleEventService;
 use crate::services::agent_types::AgentTypesService;
 use crate::services::agent_webhooks::AgentWebhooksService;
 use crate::services::blob_store::BlobStoreService;
@@ -23,9 +24,12 @@ use crate::services::events::Events;
 use crate::services::file_loader::FileLoader;
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::plugin::OplogProcessorPlugin;
 use crate::services::oplog::OplogService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
+use crate::services::recorded_host_calls::RecordedHostCalls;
 use crate::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
 use crate::services::scheduler::SchedulerService;
 use crate::services::shard::ShardService;
@@ -37,7 +41,7 @@ use crate::services::worker_enumeration::{
 };
 use crate::services::worker_fork::DefaultWorkerFork;
 use crate::services::worker_proxy::WorkerProxy;
-use crate::services::{rdbms, resource_limits, All, NoAdditionalDeps};
+use crate::services::{rdbms, resource_limits, All};
 use crate::wasi_host::create_linker;
 use crate::workerctx::default::Context;
 use crate::{Bootstrap, RunDetails};
@@ -55,7 +59,8 @@ use wasmtime::Engine;
 #[cfg(test)]
 test_r::enable!();
 
-struct ServerBootstrap {}
+/// Bootstrap of the worker executor server, also usable as a base for customized executors
+pub struct ServerBootstrap {}
 
 #[async_trait]
 impl Bootstrap<Context> for ServerBootstrap {
@@ -97,23 +102,27 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator<Context>>,
         oplog_service: Arc<dyn OplogService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
         worker_proxy: Arc<dyn WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         agent_type_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         registry_service: Arc<dyn RegistryService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         leak_sentinel: Arc<()>,
     ) -> anyhow::Result<All<Context>> {
         let resource_limits = resource_limits::configured(
             &golem_config.resource_limits,
+            &golem_config.usage_metering,
             registry_service.clone(),
             shutdown_token.clone(),
         );
 
-        let additional_deps = NoAdditionalDeps {};
+        let additional_deps = RecordedHostCalls::new();
 
         let worker_fork = Arc::new(DefaultWorkerFork::new(
             Arc::new(RemoteInvocationRpc::new(
@@ -138,11 +147,14 @@ impl Bootstrap<Context> for ServerBootstrap {
             rdbms_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             agent_type_service.clone(),
             agent_webhooks_service.clone(),
             shutdown_token.clone(),
@@ -173,11 +185,14 @@ impl Bootstrap<Context> for ServerBootstrap {
             rdbms_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            pubsub_service.clone(),
+            agent_lifecycle_event_service.clone(),
             worker_activator.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits.clone(),
+            network_egress_policy_service.clone(),
             shutdown_token.clone(),
             agent_type_service.clone(),
             agent_webhooks_service.clone(),
@@ -207,12 +222,15 @@ impl Bootstrap<Context> for ServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
             file_loader.clone(),
             oplog_processor_plugin.clone(),
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             additional_deps,
             leak_sentinel,
@@ -233,6 +251,10 @@ impl Bootstrap<Context> for ServerBootstrap {
             &mut linker,
             get_durable_ctx,
         )?;
+        golem_api_1_x::pubsub::add_to_linker::<_, HasSelf<DurableWorkerCtx<Context>>>(
+            &mut linker,
+            get_durable_ctx,
+        )?;
         golem_durability::durability::add_to_linker::<_, HasSelf<DurableWorkerCtx<Context>>>(
             &mut linker,
             get_durable_ctx,
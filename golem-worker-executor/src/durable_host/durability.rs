@@ -17,7 +17,7 @@ use crate::metrics::wasm::record_host_function_call;
 use crate::model::TrapType;
 use crate::preview2::golem::durability::durability;
 use crate::services::oplog::{CommitLevel, OplogOps};
-use crate::services::{HasOplog, HasWorker};
+use crate::services::{HasExtraDeps, HasOplog, HasWorker};
 use crate::worker::RetryDecision;
 use crate::workerctx::WorkerCtx;
 use anyhow::Error;
@@ -103,6 +103,13 @@ pub trait DurabilityHost {
     /// If retrying is not possible, the function returns Ok(()) and the host function
     /// can continue persisting the failed result permanently.
     async fn try_trigger_retry(&mut self, failure: Error) -> anyhow::Result<()>;
+
+    /// Gets the recorded response to use in place of the live response of the next host function
+    /// invocation, if the worker is replaying a recorded oplog in a sandbox
+    async fn recorded_host_call_response(
+        &self,
+        function_name: &HostFunctionName,
+    ) -> Option<HostResponse>;
 }
 
 impl From<durability::DurableFunctionType> for DurableFunctionType {
@@ -297,6 +304,11 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
         &mut self,
         function_type: &DurableFunctionType,
     ) -> Result<OplogIndex, WorkerExecutorError> {
+        if self.state.read_only_query && is_side_effect(function_type) {
+            return Err(WorkerExecutorError::runtime(
+                "Read-only agent methods cannot perform durable side effects",
+            ));
+        }
         self.process_pending_replay_events().await?;
         let oplog_index = self.begin_function(function_type).await?;
         Ok(oplog_index)
@@ -424,6 +436,29 @@ impl<Ctx: WorkerCtx> DurabilityHost for DurableWorkerCtx<Ctx> {
             RetryDecision::None | RetryDecision::TryStop(_) => Ok(()),
         }
     }
+
+    async fn recorded_host_call_response(
+        &self,
+        function_name: &HostFunctionName,
+    ) -> Option<HostResponse> {
+        Ctx::recorded_host_call_response(
+            &self.public_state.worker().extra_deps(),
+            &self.owned_worker_id.worker_id,
+            function_name,
+        )
+        .await
+    }
+}
+
+/// Whether a durable function of the given type modifies the local or the remote state
+fn is_side_effect(function_type: &DurableFunctionType) -> bool {
+    matches!(
+        function_type,
+        DurableFunctionType::WriteLocal
+            | DurableFunctionType::WriteRemote
+            | DurableFunctionType::WriteRemoteBatched(_)
+            | DurableFunctionType::WriteRemoteTransaction(_)
+    )
 }
 
 #[derive(Debug)]
@@ -488,26 +523,39 @@ impl<Pair: HostPayloadPair> Durability<Pair> {
         let response = self
             .persist_raw(ctx, request.into(), response.into())
             .await?;
-        Ok(response.try_into().unwrap()) // Assuming converting to HostResponse and back always succeeds
+        // Converting to HostResponse and back always succeeds, unless the response was replaced
+        // by a recorded one of a different type
+        response
+            .try_into()
+            .map_err(|err| WorkerExecutorError::unexpected_oplog_entry("HostResponse", err))
     }
 
+    /// Persists the invocation and returns the response the host function has to continue with.
+    /// This is the given live response, unless the worker replays a recorded oplog in a sandbox,
+    /// where the recorded response of the same host function is persisted and returned instead.
     pub async fn persist_raw(
         &self,
         ctx: &mut impl DurabilityHost,
         request: HostRequest,
         response: HostResponse,
     ) -> Result<HostResponse, WorkerExecutorError> {
-        if self.durable_execution_state.snapshotting_mode.is_none() {
-            ctx.persist_durable_function_invocation(
-                Pair::HOST_FUNCTION_NAME,
-                &request,
-                &response,
-                self.function_type.clone(),
-            )
-            .await;
-            ctx.end_durable_function(&self.function_type, self.begin_index, false)
-                .await?;
+        if self.durable_execution_state.snapshotting_mode.is_some() {
+            return Ok(response);
         }
+
+        let response = ctx
+            .recorded_host_call_response(&Pair::HOST_FUNCTION_NAME)
+            .await
+            .unwrap_or(response);
+        ctx.persist_durable_function_invocation(
+            Pair::HOST_FUNCTION_NAME,
+            &request,
+            &response,
+            self.function_type.clone(),
+        )
+        .await;
+        ctx.end_durable_function(&self.function_type, self.begin_index, false)
+            .await?;
         Ok(response)
     }
 
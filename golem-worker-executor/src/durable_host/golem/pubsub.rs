@@ -0,0 +1,116 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::preview2::golem_api_1_x::pubsub::{DataValue, Host};
+use crate::workerctx::WorkerCtx;
+use anyhow::anyhow;
+use golem_common::model::agent::UntypedDataValue;
+use golem_common::model::oplog::host_functions::{
+    GolemApiPubSubPublish, GolemApiPubSubSubscribe, GolemApiPubSubUnsubscribe,
+};
+use golem_common::model::oplog::{
+    DurableFunctionType, HostRequestGolemApiPubSubPublish, HostRequestGolemApiPubSubSubscription,
+    HostRequestGolemApiPubSubTopic, HostResponseGolemApiPubSubOffset,
+    HostResponseGolemApiPubSubUnsubscribed, HostResponseGolemApiUnit,
+};
+
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn publish(&mut self, topic: String, message: DataValue) -> anyhow::Result<u64> {
+        let durability =
+            Durability::<GolemApiPubSubPublish>::new(self, DurableFunctionType::WriteRemote)
+                .await?;
+
+        let message: UntypedDataValue = message.into();
+        let result = if durability.is_live() {
+            let result = self
+                .state
+                .pubsub_service
+                .publish(self.owned_worker_id.environment_id, &topic, message.clone())
+                .await
+                .map_err(|err| err.to_string());
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemApiPubSubPublish { topic, message },
+                    HostResponseGolemApiPubSubOffset { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        result.result.map_err(|err| anyhow!(err))
+    }
+
+    async fn subscribe(&mut self, topic: String, handler_method: String) -> anyhow::Result<()> {
+        let durability =
+            Durability::<GolemApiPubSubSubscribe>::new(self, DurableFunctionType::WriteRemote)
+                .await?;
+
+        let result = if durability.is_live() {
+            let result = self
+                .state
+                .pubsub_service
+                .subscribe(
+                    self.created_by(),
+                    &self.owned_worker_id,
+                    &topic,
+                    handler_method.clone(),
+                )
+                .await
+                .map_err(|err| err.to_string());
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemApiPubSubSubscription {
+                        topic,
+                        handler_method,
+                    },
+                    HostResponseGolemApiUnit { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        result.result.map_err(|err| anyhow!(err))
+    }
+
+    async fn unsubscribe(&mut self, topic: String) -> anyhow::Result<bool> {
+        let durability =
+            Durability::<GolemApiPubSubUnsubscribe>::new(self, DurableFunctionType::WriteRemote)
+                .await?;
+
+        let result = if durability.is_live() {
+            let result = self
+                .state
+                .pubsub_service
+                .unsubscribe(&self.owned_worker_id, &topic)
+                .await
+                .map_err(|err| err.to_string());
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemApiPubSubTopic { topic },
+                    HostResponseGolemApiPubSubUnsubscribed { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        result.result.map_err(|err| anyhow!(err))
+    }
+}
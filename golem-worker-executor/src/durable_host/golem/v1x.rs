@@ -31,11 +31,13 @@ use crate::worker::status::calculate_last_known_status;
 use crate::workerctx::{InvocationManagement, StatusManagement, WorkerCtx};
 use anyhow::anyhow;
 use async_trait::async_trait;
+use chrono::Utc;
 use golem_common::model::agent::AgentId;
-use golem_common::model::component::{ComponentId, ComponentRevision};
+use golem_common::model::component::{ComponentId, ComponentRevision, PluginPriority};
 use golem_common::model::oplog::host_functions::{
-    GolemApiCompletePromise, GolemApiCreatePromise, GolemApiFork, GolemApiForkWorker,
-    GolemApiGenerateIdempotencyKey, GolemApiGetAgentMetadata, GolemApiGetPromiseResult,
+    GolemApiCompletePromise, GolemApiCreatePromise, GolemApiCreatePromiseWithTimeout,
+    GolemApiFailPromise, GolemApiFork, GolemApiForkWorker, GolemApiGenerateIdempotencyKey,
+    GolemApiGetAgentMetadata, GolemApiGetPromiseOutcome, GolemApiGetPromiseResult,
     GolemApiGetSelfMetadata, GolemApiResolveComponentId, GolemApiResolveWorkerIdStrict,
     GolemApiRevertWorker, GolemApiUpdateWorker,
 };
@@ -44,14 +46,15 @@ use golem_common::model::oplog::{
     DurableFunctionType, HostRequestGolemApiAgentId, HostRequestGolemApiComponentSlug,
     HostRequestGolemApiComponentSlugAndAgentName, HostRequestGolemApiForkAgent,
     HostRequestGolemApiPromiseId, HostRequestGolemApiRevertAgent, HostRequestGolemApiUpdateAgent,
-    HostRequestNoInput, HostResponseGolemApiAgentId, HostResponseGolemApiAgentMetadata,
-    HostResponseGolemApiComponentId, HostResponseGolemApiFork, HostResponseGolemApiIdempotencyKey,
-    HostResponseGolemApiPromiseCompletion, HostResponseGolemApiPromiseId,
+    HostRequestMonotonicClockDuration, HostRequestNoInput, HostResponseGolemApiAgentId,
+    HostResponseGolemApiAgentMetadata, HostResponseGolemApiComponentId, HostResponseGolemApiFork,
+    HostResponseGolemApiIdempotencyKey, HostResponseGolemApiPromiseCompletion,
+    HostResponseGolemApiPromiseId, HostResponseGolemApiPromiseOutcome,
     HostResponseGolemApiPromiseResult, HostResponseGolemApiSelfAgentMetadata,
     HostResponseGolemApiUnit, OplogEntry, PublicOplogEntry,
 };
 use golem_common::model::regions::OplogRegion;
-use golem_common::model::{IdempotencyKey, OplogIndex, PromiseId, RetryConfig};
+use golem_common::model::{IdempotencyKey, OplogIndex, PromiseId, PromiseOutcome, RetryConfig};
 use golem_common::model::{OwnedWorkerId, ScanCursor, WorkerId};
 use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
 use std::sync::Arc;
@@ -82,6 +85,25 @@ impl<Ctx: WorkerCtx> HostGetAgents for DurableWorkerCtx<Ctx> {
         Ok(resource)
     }
 
+    async fn with_extended_filter(
+        &mut self,
+        component_id: golem_api_1_x::host::ComponentId,
+        filter: Option<golem_api_1_x::host::ExtendedAgentAnyFilter>,
+        precise: bool,
+    ) -> anyhow::Result<Resource<GetAgents>> {
+        self.observe_function_call("golem::api::get-workers", "with-extended-filter");
+        let entry = GetAgentsEntry::new(
+            component_id.into(),
+            filter
+                .map(|f| f.try_into())
+                .transpose()
+                .map_err(|e: String| anyhow!(e))?,
+            precise,
+        );
+        let resource = self.as_wasi_view().table().push(entry)?;
+        Ok(resource)
+    }
+
     async fn get_next(
         &mut self,
         self_: Resource<GetAgents>,
@@ -159,6 +181,45 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         Ok(result.promise_id.into())
     }
 
+    async fn create_promise_with_timeout(
+        &mut self,
+        timeout: golem_api_1_x::host::Duration,
+    ) -> anyhow::Result<golem_api_1_x::host::PromiseId> {
+        let durability = Durability::<GolemApiCreatePromiseWithTimeout>::new(
+            self,
+            DurableFunctionType::WriteLocal,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let oplog_idx = self.state.current_oplog_index().await.next();
+            let promise_id = self
+                .public_state
+                .promise_service
+                .create(&self.owned_worker_id.worker_id, oplog_idx)
+                .await;
+            self.state
+                .expire_promise_at(
+                    promise_id.clone(),
+                    Utc::now() + Duration::from_nanos(timeout),
+                )
+                .await;
+            durability
+                .persist(
+                    self,
+                    HostRequestMonotonicClockDuration {
+                        duration_in_nanos: timeout,
+                    },
+                    HostResponseGolemApiPromiseId { promise_id },
+                )
+                .await?
+        } else {
+            durability.replay(self).await?
+        };
+
+        Ok(result.promise_id.into())
+    }
+
     async fn get_promise(
         &mut self,
         promise_id: golem_api_1_x::host::PromiseId,
@@ -179,27 +240,39 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
 
         let promise_id: PromiseId = promise_id.into();
         let result = if durability.is_live() {
-            // A promise must be completed on the instance that is owning the agent that originally created here.
-            let worker_id = &promise_id.worker_id;
+            let promise_completion_result = self
+                .complete_promise_on_owner(promise_id.clone(), data, false)
+                .await?;
 
-            let is_local_worker = match self.state.shard_service.check_worker(worker_id) {
-                Ok(()) => true,
-                Err(WorkerExecutorError::InvalidShardId { .. }) => false,
-                Err(other) => Err(other)?,
-            };
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemApiPromiseId { promise_id },
+                    HostResponseGolemApiPromiseCompletion {
+                        completed: promise_completion_result,
+                    },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
 
-            let promise_completion_result = if is_local_worker {
-                self.public_state
-                    .promise_service
-                    .complete(promise_id.clone(), data, self.created_by())
-                    .await?
-            } else {
-                // talk to the executor that actually owns the promise
-                self.state
-                    .worker_proxy
-                    .complete_promise(promise_id.clone(), data, self.created_by())
-                    .await?
-            };
+        Ok(result.completed)
+    }
+
+    async fn fail_promise(
+        &mut self,
+        promise_id: golem_api_1_x::host::PromiseId,
+        error: Vec<u8>,
+    ) -> anyhow::Result<bool> {
+        let durability =
+            Durability::<GolemApiFailPromise>::new(self, DurableFunctionType::WriteLocal).await?;
+
+        let promise_id: PromiseId = promise_id.into();
+        let result = if durability.is_live() {
+            let promise_completion_result = self
+                .complete_promise_on_owner(promise_id.clone(), error, true)
+                .await?;
 
             durability
                 .persist(
@@ -991,7 +1064,18 @@ impl<Ctx: WorkerCtx> HostGetPromiseResult for DurableWorkerCtx<Ctx> {
                 ));
             }
 
-            let result = entry.get_handle().await.get().await;
+            let promise_id = entry.promise_id.clone();
+            let result = match entry.get_handle().await.get().await {
+                None => None,
+                Some(PromiseOutcome::Completed(data)) => Some(data),
+                // the legacy result can't represent failures and expiries, so they trap
+                // instead of being mistaken for a successful completion
+                Some(outcome) => {
+                    return Err(anyhow!(
+                        "Promise {promise_id} was {outcome}, use get-outcome to observe failed and expired promises"
+                    ));
+                }
+            };
             durability
                 .persist(
                     self,
@@ -1006,6 +1090,40 @@ impl<Ctx: WorkerCtx> HostGetPromiseResult for DurableWorkerCtx<Ctx> {
         Ok(result.result)
     }
 
+    async fn get_outcome(
+        &mut self,
+        resource: Resource<GetPromiseResultEntry>,
+    ) -> anyhow::Result<Option<golem_api_1_x::host::PromiseOutcome>> {
+        let durability =
+            Durability::<GolemApiGetPromiseOutcome>::new(self, DurableFunctionType::ReadRemote)
+                .await?;
+
+        let result = if durability.is_live() {
+            let self_worker_id = self.worker_id().clone();
+            let entry = self.table().get(&resource)?;
+
+            // only the agent that originally created the promise is woken up when it is completed.
+            if entry.promise_id.worker_id != self_worker_id {
+                return Err(anyhow!(
+                    "Tried awaiting a promise not created by the current agent"
+                ));
+            }
+
+            let outcome = entry.get_handle().await.get().await;
+            durability
+                .persist(
+                    self,
+                    HostRequestNoInput {},
+                    HostResponseGolemApiPromiseOutcome { outcome },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(result.outcome.map(|outcome| outcome.into()))
+    }
+
     async fn drop(&mut self, resource: Resource<GetPromiseResultEntry>) -> anyhow::Result<()> {
         self.observe_function_call("golem::api::promise-result", "drop");
         let resource_rep = resource.rep();
@@ -1190,6 +1308,40 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         };
         Ok(worker_id)
     }
+
+    /// Completes or fails a promise on the executor owning the agent that originally created it
+    async fn complete_promise_on_owner(
+        &self,
+        promise_id: PromiseId,
+        data: Vec<u8>,
+        failed: bool,
+    ) -> anyhow::Result<bool> {
+        let is_local_worker = match self.state.shard_service.check_worker(&promise_id.worker_id) {
+            Ok(()) => true,
+            Err(WorkerExecutorError::InvalidShardId { .. }) => false,
+            Err(other) => Err(other)?,
+        };
+
+        if is_local_worker {
+            let outcome = if failed {
+                PromiseOutcome::Failed(data)
+            } else {
+                PromiseOutcome::Completed(data)
+            };
+            Ok(self
+                .public_state
+                .promise_service
+                .complete(promise_id, outcome, self.created_by())
+                .await?)
+        } else {
+            // talk to the executor that actually owns the promise
+            Ok(self
+                .state
+                .worker_proxy
+                .complete_promise(promise_id, data, failed, self.created_by())
+                .await?)
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1314,6 +1466,16 @@ impl From<golem_api_1_x::host::RevertAgentTarget>
     }
 }
 
+impl From<PromiseOutcome> for golem_api_1_x::host::PromiseOutcome {
+    fn from(value: PromiseOutcome) -> Self {
+        match value {
+            PromiseOutcome::Completed(data) => Self::Completed(data),
+            PromiseOutcome::Failed(error) => Self::Failed(error),
+            PromiseOutcome::Expired => Self::Expired,
+        }
+    }
+}
+
 impl From<&RetryConfig> for golem_api_1_x::host::RetryPolicy {
     fn from(value: &RetryConfig) -> Self {
         Self {
@@ -1496,6 +1658,92 @@ impl TryFrom<AgentAnyFilter> for golem_common::model::WorkerFilter {
     }
 }
 
+impl TryFrom<golem_api_1_x::host::ExtendedAgentPropertyFilter>
+    for golem_common::model::WorkerFilter
+{
+    type Error = String;
+
+    fn try_from(
+        filter: golem_api_1_x::host::ExtendedAgentPropertyFilter,
+    ) -> Result<Self, Self::Error> {
+        let converted = match filter {
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::Base(filter) => filter.try_into()?,
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::AgentType(filter) => {
+                golem_common::model::WorkerFilter::new_agent_type(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::AgentParameters(filter) => {
+                golem_common::model::WorkerFilter::new_agent_parameters(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::LastInvocationAt(filter) => {
+                golem_common::model::WorkerFilter::new_last_invocation_at(
+                    filter.comparator.into(),
+                    filter.value.into(),
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::PendingInvocationCount(filter) => {
+                golem_common::model::WorkerFilter::new_pending_invocation_count(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::RetryCount(filter) => {
+                golem_common::model::WorkerFilter::new_retry_count(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::LastError(filter) => {
+                golem_common::model::WorkerFilter::new_last_error(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::MemorySize(filter) => {
+                golem_common::model::WorkerFilter::new_memory_size(
+                    filter.comparator.into(),
+                    filter.value,
+                )
+            }
+            golem_api_1_x::host::ExtendedAgentPropertyFilter::ActivePlugin(filter) => {
+                golem_common::model::WorkerFilter::new_active_plugin(PluginPriority(
+                    filter.plugin_priority,
+                ))
+            }
+        };
+        Ok(converted)
+    }
+}
+
+impl TryFrom<golem_api_1_x::host::ExtendedAgentAllFilter> for golem_common::model::WorkerFilter {
+    type Error = String;
+    fn try_from(filter: golem_api_1_x::host::ExtendedAgentAllFilter) -> Result<Self, Self::Error> {
+        let filters = filter
+            .filters
+            .into_iter()
+            .map(|f| f.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(golem_common::model::WorkerFilter::new_and(filters))
+    }
+}
+
+impl TryFrom<golem_api_1_x::host::ExtendedAgentAnyFilter> for golem_common::model::WorkerFilter {
+    type Error = String;
+    fn try_from(filter: golem_api_1_x::host::ExtendedAgentAnyFilter) -> Result<Self, Self::Error> {
+        let filters = filter
+            .filters
+            .into_iter()
+            .map(|f| f.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(golem_common::model::WorkerFilter::new_or(filters))
+    }
+}
+
 impl From<AgentMetadataForGuests> for golem_api_1_x::host::AgentMetadata {
     fn from(value: AgentMetadataForGuests) -> Self {
         Self {
@@ -15,20 +15,34 @@
 use crate::durable_host::{
     DurabilityHost, DurableWorkerCtx, HttpRequestCloseOwner, HttpRequestState,
 };
+use crate::services::HasWorker;
 use crate::workerctx::{InvocationContextManagement, InvocationManagement, WorkerCtx};
 use golem_common::model::invocation_context::AttributeValue;
 use golem_common::model::oplog::types::SerializableHttpMethod;
 use golem_common::model::oplog::{DurableFunctionType, HostRequestHttpRequest};
 use golem_common::model::IdempotencyKey;
 use golem_service_base::headers::TraceContextHeaders;
+use golem_service_base::model::AgentUsage;
+use http::uri::Authority;
 use http::{HeaderName, HeaderValue};
+use http_body_util::BodyExt;
+use rustls::pki_types::ServerName;
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio::time::timeout;
 use wasmtime::component::Resource;
 use wasmtime_wasi_http::bindings::http::outgoing_handler::Host;
 use wasmtime_wasi_http::bindings::http::types;
-use wasmtime_wasi_http::types::{HostFutureIncomingResponse, HostOutgoingRequest};
-use wasmtime_wasi_http::{HttpError, HttpResult};
+use wasmtime_wasi_http::bindings::http::types::{ErrorCode, Scheme};
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::io::TokioIo;
+use wasmtime_wasi_http::types::{
+    HostFutureIncomingResponse, HostOutgoingRequest, IncomingResponse, OutgoingRequestConfig,
+};
+use wasmtime_wasi_http::{dns_error, hyper_request_error, HttpError, HttpResult};
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     async fn handle(
@@ -38,6 +52,15 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     ) -> HttpResult<Resource<HostFutureIncomingResponse>> {
         self.observe_function_call("http::outgoing_handler", "handle");
 
+        if self.state.is_live() {
+            self.register_outgoing_http_request()
+                .map_err(|trap| HttpError::trap(wasmtime::Error::from(trap)))?;
+            self.public_state.worker().record_usage(AgentUsage {
+                outgoing_http_requests: 1,
+                ..Default::default()
+            });
+        }
+
         // Durability is handled by the WasiHttpView send_request method and the follow-up calls to await/poll the response future
         let begin_index = self
             .begin_durable_function(&DurableFunctionType::WriteRemoteBatched(None))
@@ -45,6 +68,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             .map_err(|err| HttpError::trap(wasmtime::Error::msg(err.to_string())))?;
 
         let host_request = self.table().get(&request)?;
+        let egress_host = outgoing_http_request_host(host_request);
         let uri = format!(
             "{}{}",
             host_request.authority.as_ref().unwrap_or(&String::new()),
@@ -114,7 +138,20 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             }
         }
 
+        if self.state.is_live() {
+            if let Some((host, port)) = egress_host {
+                // A denied request still goes through the regular, durable flow with a future
+                // response failing with HttpRequestDenied, and an allowed one connects to the
+                // checked address (see send_request)
+                self.state.outgoing_http_egress = self
+                    .outgoing_http_egress(&host, port)
+                    .await
+                    .map_err(|err| HttpError::trap(wasmtime::Error::msg(err.to_string())))?;
+            }
+        }
+
         let result = Host::handle(&mut self.as_wasi_http_view(), request, options).await;
+        self.state.outgoing_http_egress = None;
 
         match &result {
             Ok(future_incoming_response) => {
@@ -174,3 +211,117 @@ fn outgoing_http_request_span_attributes(
         ),
     ]
 }
+
+/// Host and port an outgoing http request connects to, if it can be sent at all
+fn outgoing_http_request_host(request: &HostOutgoingRequest) -> Option<(String, u16)> {
+    let authority = Authority::from_str(request.authority.as_ref()?).ok()?;
+    let port = authority.port_u16().or(match &request.scheme {
+        Some(Scheme::Http) => Some(80),
+        Some(Scheme::Https) | None => Some(443),
+        Some(Scheme::Other(_)) => None,
+    })?;
+    Some((authority.host().to_string(), port))
+}
+
+/// Sends an outgoing http request like `default_send_request`, but connects to the given address
+/// instead of resolving the request's host again, so the connection goes to the exact address
+/// that was checked against the network egress policy
+pub(crate) fn send_request_to_address(
+    request: hyper::Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    address: SocketAddr,
+) -> HostFutureIncomingResponse {
+    let handle = wasmtime_wasi::runtime::spawn(async move {
+        Ok(send_request_to_address_handler(request, config, address).await)
+    });
+    HostFutureIncomingResponse::pending(handle)
+}
+
+async fn send_request_to_address_handler(
+    mut request: hyper::Request<HyperOutgoingBody>,
+    OutgoingRequestConfig {
+        use_tls,
+        connect_timeout,
+        first_byte_timeout,
+        between_bytes_timeout,
+    }: OutgoingRequestConfig,
+    address: SocketAddr,
+) -> Result<IncomingResponse, ErrorCode> {
+    let host = request
+        .uri()
+        .host()
+        .ok_or(ErrorCode::HttpRequestUriInvalid)?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+
+    let tcp_stream = timeout(connect_timeout, TcpStream::connect(address))
+        .await
+        .map_err(|_| ErrorCode::ConnectionTimeout)?
+        .map_err(|_| ErrorCode::ConnectionRefused)?;
+
+    let (mut sender, worker) = if use_tls {
+        let root_cert_store = rustls::RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.into(),
+        };
+        let tls_config = rustls::ClientConfig::builder()
+            .with_root_certificates(root_cert_store)
+            .with_no_client_auth();
+        let connector = tokio_rustls::TlsConnector::from(Arc::new(tls_config));
+        let server_name =
+            ServerName::try_from(host).map_err(|_| dns_error("invalid dns name".to_string(), 0))?;
+        let stream = connector
+            .connect(server_name, tcp_stream)
+            .await
+            .map_err(|_| ErrorCode::TlsProtocolError)?;
+
+        let (sender, connection) = timeout(
+            connect_timeout,
+            hyper::client::conn::http1::handshake(TokioIo::new(stream)),
+        )
+        .await
+        .map_err(|_| ErrorCode::ConnectionTimeout)?
+        .map_err(hyper_request_error)?;
+        let worker = wasmtime_wasi::runtime::spawn(async move {
+            let _ = connection.await;
+        });
+        (sender, worker)
+    } else {
+        let (sender, connection) = timeout(
+            connect_timeout,
+            hyper::client::conn::http1::handshake(TokioIo::new(tcp_stream)),
+        )
+        .await
+        .map_err(|_| ErrorCode::ConnectionTimeout)?
+        .map_err(hyper_request_error)?;
+        let worker = wasmtime_wasi::runtime::spawn(async move {
+            let _ = connection.await;
+        });
+        (sender, worker)
+    };
+
+    // The request contains the scheme and the authority, but they are only sent when addressing
+    // a proxy, so they are removed like in `default_send_request`
+    *request.uri_mut() = http::Uri::builder()
+        .path_and_query(
+            request
+                .uri()
+                .path_and_query()
+                .map(|path_and_query| path_and_query.as_str())
+                .unwrap_or("/"),
+        )
+        .build()
+        .map_err(|_| ErrorCode::HttpRequestUriInvalid)?;
+
+    let resp = timeout(first_byte_timeout, sender.send_request(request))
+        .await
+        .map_err(|_| ErrorCode::ConnectionReadTimeout)?
+        .map_err(hyper_request_error)?
+        .map(|body| body.map_err(hyper_request_error).boxed());
+
+    Ok(IncomingResponse {
+        resp,
+        worker: Some(worker),
+        between_bytes_timeout,
+    })
+}
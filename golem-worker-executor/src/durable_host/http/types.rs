@@ -515,17 +515,28 @@ impl<Ctx: WorkerCtx> HostFutureTrailers for DurableWorkerCtx<Ctx> {
                     .try_trigger_retry(self, &for_retry)
                     .await
                     .map_err(wasmtime::Error::from_anyhow)?;
-                let _ = durability
+                let persisted = durability
                     .persist(
                         self,
                         request,
                         HostResponseHttpFutureTrailersGet {
-                            result: to_serialize,
+                            result: to_serialize.clone(),
                         },
                     )
                     .await
                     .map_err(wasmtime::Error::from)?;
 
+                // When replaying a recorded oplog in a sandbox, the recorded trailers are persisted
+                // and the guest continues with them instead of the live ones
+                let result = if persisted.result != to_serialize {
+                    if let Ok(Some(Ok(Ok(Some(trailers))))) = result {
+                        self.table().delete(trailers)?;
+                    }
+                    future_trailers_from_serialized(self, persisted)
+                } else {
+                    result
+                };
+
                 // End the HTTP request when trailers have resolved (not pending)
                 let is_resolved = !matches!(&result, Ok(None));
                 if is_resolved {
@@ -539,30 +550,7 @@ impl<Ctx: WorkerCtx> HostFutureTrailers for DurableWorkerCtx<Ctx> {
                         .replay(self)
                         .await
                         .map_err(wasmtime::Error::from)?;
-                let result = match serialized.result {
-                    Ok(Some(Ok(Ok(None)))) => Ok(Some(Ok(Ok(None)))),
-                    Ok(Some(Ok(Ok(Some(serialized_trailers))))) => {
-                        let mut header_map = http::HeaderMap::new();
-                        for (key, value) in serialized_trailers {
-                            header_map
-                                .insert(HeaderName::from_str(&key)?, HeaderValue::try_from(value)?);
-                        }
-                        let field_size_limit = {
-                            let mut view = self.as_wasi_http_view();
-                            use wasmtime_wasi_http::types::WasiHttpView;
-                            view.ctx().field_size_limit
-                        };
-                        let fields = FieldMap::new(header_map, field_size_limit);
-                        let hdrs = self
-                            .table()
-                            .push(wasmtime_wasi_http::types::HostFields::Owned { fields })?;
-                        Ok(Some(Ok(Ok(Some(hdrs)))))
-                    }
-                    Ok(Some(Ok(Err(error_code)))) => Ok(Some(Ok(Err(error_code.into())))),
-                    Ok(Some(Err(_))) => Ok(Some(Err(()))),
-                    Ok(None) => Ok(None),
-                    Err(error) => Err(wasmtime::Error::msg(error)),
-                };
+                let result = future_trailers_from_serialized(self, serialized);
 
                 // End the HTTP request when trailers have resolved (not pending)
                 let is_resolved = !matches!(&result, Ok(None));
@@ -593,6 +581,36 @@ impl<Ctx: WorkerCtx> HostFutureTrailers for DurableWorkerCtx<Ctx> {
     }
 }
 
+/// Creates the result of `future-trailers::get` from persisted trailers
+fn future_trailers_from_serialized<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    serialized: HostResponseHttpFutureTrailersGet,
+) -> wasmtime::Result<Option<Result<Result<Option<Resource<Trailers>>, ErrorCode>, ()>>> {
+    match serialized.result {
+        Ok(Some(Ok(Ok(None)))) => Ok(Some(Ok(Ok(None)))),
+        Ok(Some(Ok(Ok(Some(serialized_trailers))))) => {
+            let mut header_map = http::HeaderMap::new();
+            for (key, value) in serialized_trailers {
+                header_map.insert(HeaderName::from_str(&key)?, HeaderValue::try_from(value)?);
+            }
+            let field_size_limit = {
+                let mut view = ctx.as_wasi_http_view();
+                use wasmtime_wasi_http::types::WasiHttpView;
+                view.ctx().field_size_limit
+            };
+            let fields = FieldMap::new(header_map, field_size_limit);
+            let hdrs = ctx
+                .table()
+                .push(wasmtime_wasi_http::types::HostFields::Owned { fields })?;
+            Ok(Some(Ok(Ok(Some(hdrs)))))
+        }
+        Ok(Some(Ok(Err(error_code)))) => Ok(Some(Ok(Err(error_code.into())))),
+        Ok(Some(Err(_))) => Ok(Some(Err(()))),
+        Ok(None) => Ok(None),
+        Err(error) => Err(wasmtime::Error::msg(error)),
+    }
+}
+
 impl<Ctx: WorkerCtx> HostOutgoingResponse for DurableWorkerCtx<Ctx> {
     fn new(&mut self, headers: Resource<Headers>) -> wasmtime::Result<Resource<OutgoingResponse>> {
         self.observe_function_call("http::types::outgoing_response", "new");
@@ -714,6 +732,11 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                     SerializableHttpResponse::InternalError(None),
                     Err("Unknown error".to_string()),
                 ),
+                // Requests denied by the network egress policy are not retried
+                Ok(Some(Ok(Err(error_code @ ErrorCode::HttpRequestDenied)))) => (
+                    SerializableHttpResponse::HttpError(error_code.clone().into()),
+                    Ok(()),
+                ),
                 Ok(Some(Ok(Err(error_code)))) => (
                     SerializableHttpResponse::HttpError(error_code.clone().into()),
                     Err(error_code.to_string()),
@@ -724,14 +747,29 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                 ),
             };
 
-            if let Err(err) = for_retry {
+            // When replaying a recorded oplog in a sandbox, the guest continues with the recorded
+            // response instead of the live one
+            let recorded_response = if self.state.snapshotting_mode.is_none() {
+                match self
+                    .recorded_host_call_response(
+                        &HttpTypesFutureIncomingResponseGet::HOST_FUNCTION_NAME,
+                    )
+                    .await
+                {
+                    Some(HostResponse::HttpResponse(recorded)) => Some(recorded.response),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+            if let (Err(err), None) = (for_retry, &recorded_response) {
                 self.state.current_retry_point = begin_index;
                 self.try_trigger_retry(anyhow!(err))
                     .await
                     .map_err(wasmtime::Error::from_anyhow)?;
             }
 
-            let is_pending = matches!(serializable_response, SerializableHttpResponse::Pending);
             if self.state.snapshotting_mode.is_none() {
                 self.state
                     .oplog
@@ -739,7 +777,9 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                         HttpTypesFutureIncomingResponseGet::HOST_FUNCTION_NAME,
                         &HostRequest::HttpRequest(request),
                         &HostResponse::HttpResponse(HostResponseHttpResponse {
-                            response: serializable_response,
+                            response: recorded_response
+                                .clone()
+                                .unwrap_or_else(|| serializable_response.clone()),
                         }),
                         DurableFunctionType::WriteRemoteBatched(Some(begin_index)),
                     )
@@ -751,6 +791,14 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                     .await;
             }
 
+            if let Some(recorded_response) = recorded_response {
+                if let Ok(Some(Ok(Ok(resource)))) = response {
+                    self.table().delete(resource)?;
+                }
+                return incoming_response_from_serialized(self, handle, recorded_response);
+            }
+
+            let is_pending = matches!(serializable_response, SerializableHttpResponse::Pending);
             if !is_pending {
                 if let Ok(Some(Ok(Ok(resource)))) = &response {
                     let incoming_response_handle = resource.rep();
@@ -790,34 +838,7 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
                 other => panic!("unexpected oplog entry: {other:?}"),
             };
 
-            match serialized_response {
-                SerializableHttpResponse::Pending => Ok(None),
-                SerializableHttpResponse::HeadersReceived(serializable_response_headers) => {
-                    let incoming_response: wasmtime_wasi_http::types::HostIncomingResponse =
-                        serializable_response_headers
-                            .try_into()
-                            .map_err(wasmtime::Error::from_anyhow)?;
-
-                    let rep = self.table().push(incoming_response)?;
-                    let incoming_response_handle = rep.rep();
-
-                    continue_http_request(
-                        self,
-                        handle,
-                        incoming_response_handle,
-                        HttpRequestCloseOwner::IncomingResponseDrop,
-                    );
-
-                    Ok(Some(Ok(Ok(rep))))
-                }
-                SerializableHttpResponse::InternalError(None) => Ok(Some(Err(()))),
-                SerializableHttpResponse::InternalError(Some(serializable_error)) => {
-                    Err(wasmtime::Error::msg(serializable_error))
-                }
-                SerializableHttpResponse::HttpError(error_code) => {
-                    Ok(Some(Ok(Err(error_code.into()))))
-                }
-            }
+            incoming_response_from_serialized(self, handle, serialized_response)
         }
     }
 
@@ -835,6 +856,41 @@ impl<Ctx: WorkerCtx> HostFutureIncomingResponse for DurableWorkerCtx<Ctx> {
     }
 }
 
+/// Creates the result of `future-incoming-response::get` from a persisted response, which has
+/// the body of the response read from the oplog too
+fn incoming_response_from_serialized<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    handle: u32,
+    serialized_response: SerializableHttpResponse,
+) -> wasmtime::Result<Option<Result<Result<Resource<IncomingResponse>, ErrorCode>, ()>>> {
+    match serialized_response {
+        SerializableHttpResponse::Pending => Ok(None),
+        SerializableHttpResponse::HeadersReceived(serializable_response_headers) => {
+            let incoming_response: wasmtime_wasi_http::types::HostIncomingResponse =
+                serializable_response_headers
+                    .try_into()
+                    .map_err(wasmtime::Error::from_anyhow)?;
+
+            let rep = ctx.table().push(incoming_response)?;
+            let incoming_response_handle = rep.rep();
+
+            continue_http_request(
+                ctx,
+                handle,
+                incoming_response_handle,
+                HttpRequestCloseOwner::IncomingResponseDrop,
+            );
+
+            Ok(Some(Ok(Ok(rep))))
+        }
+        SerializableHttpResponse::InternalError(None) => Ok(Some(Err(()))),
+        SerializableHttpResponse::InternalError(Some(serializable_error)) => {
+            Err(wasmtime::Error::msg(serializable_error))
+        }
+        SerializableHttpResponse::HttpError(error_code) => Ok(Some(Ok(Err(error_code.into())))),
+    }
+}
+
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     fn http_error_code(&mut self, err: Resource<IoError>) -> wasmtime::Result<Option<ErrorCode>> {
         self.observe_function_call("http::types", "http_error_code");
@@ -17,17 +17,22 @@ use wasmtime_wasi::StreamError;
 
 use crate::durable_host::http::{continue_http_request, end_http_request};
 use crate::durable_host::io::{ManagedStdErr, ManagedStdOut};
-use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx, HttpRequestCloseOwner};
+use crate::durable_host::sockets::tcp::TCP_STREAM_WRITE_BUDGET;
+use crate::durable_host::{
+    Durability, DurabilityHost, DurableWorkerCtx, HttpRequestCloseOwner, TcpConnectionState,
+};
 use crate::model::event::InternalWorkerEvent;
 use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::host_functions::{
     HttpTypesIncomingBodyStreamBlockingRead, HttpTypesIncomingBodyStreamBlockingSkip,
     HttpTypesIncomingBodyStreamRead, HttpTypesIncomingBodyStreamSkip,
+    SocketsTcpInputStreamBlockingRead, SocketsTcpInputStreamBlockingSkip,
+    SocketsTcpInputStreamRead, SocketsTcpInputStreamSkip, SocketsTcpOutputStreamWrite,
 };
 use golem_common::model::oplog::types::SerializableStreamError;
 use golem_common::model::oplog::{
-    DurableFunctionType, HostRequestHttpRequest, HostResponseStreamChunk, HostResponseStreamSkip,
-    OplogIndex,
+    DurableFunctionType, HostPayloadPair, HostRequestHttpRequest, HostResponseStreamChunk,
+    HostResponseStreamSkip, HostResponseStreamWrite, OplogIndex,
 };
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use wasmtime_wasi::p2::bindings::io::streams::{
@@ -74,6 +79,28 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
             }?;
 
             end_http_request_if_closed(self, handle, &result.result).await?;
+            result.result.map_err(StreamError::from)
+        } else if let Some(connection) = get_tcp_stream_connection(self, handle) {
+            let durability =
+                Durability::<SocketsTcpInputStreamRead>::new(self, DurableFunctionType::ReadRemote)
+                    .await?;
+
+            let result = if durability.is_live() {
+                let result = HostInputStream::read(self.table(), self_, len).await;
+                try_trigger_tcp_retry(self, &durability, &connection, &result).await?;
+                durability
+                    .persist(
+                        self,
+                        connection.request,
+                        HostResponseStreamChunk {
+                            result: result.map_err(SerializableStreamError::from),
+                        },
+                    )
+                    .await
+            } else {
+                durability.replay(self).await
+            }?;
+
             result.result.map_err(StreamError::from)
         } else {
             self.observe_function_call("io::streams::input_stream", "read");
@@ -86,8 +113,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
         self_: Resource<InputStream>,
         len: u64,
     ) -> Result<Vec<u8>, StreamError> {
+        let handle = self_.rep();
         if is_incoming_http_body_stream(self, &self_) {
-            let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
             let durability = Durability::<HttpTypesIncomingBodyStreamBlockingRead>::new(
@@ -117,6 +144,30 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
             }?;
 
             end_http_request_if_closed(self, handle, &result.result).await?;
+            result.result.map_err(StreamError::from)
+        } else if let Some(connection) = get_tcp_stream_connection(self, handle) {
+            let durability = Durability::<SocketsTcpInputStreamBlockingRead>::new(
+                self,
+                DurableFunctionType::ReadRemote,
+            )
+            .await?;
+
+            let result = if durability.is_live() {
+                let result = HostInputStream::blocking_read(self.table(), self_, len).await;
+                try_trigger_tcp_retry(self, &durability, &connection, &result).await?;
+                durability
+                    .persist(
+                        self,
+                        connection.request,
+                        HostResponseStreamChunk {
+                            result: result.map_err(SerializableStreamError::from),
+                        },
+                    )
+                    .await
+            } else {
+                durability.replay(self).await
+            }?;
+
             result.result.map_err(StreamError::from)
         } else {
             self.observe_function_call("io::streams::input_stream", "blocking_read");
@@ -125,8 +176,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     }
 
     async fn skip(&mut self, self_: Resource<InputStream>, len: u64) -> Result<u64, StreamError> {
+        let handle = self_.rep();
         if is_incoming_http_body_stream(self, &self_) {
-            let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
             let durability = Durability::<HttpTypesIncomingBodyStreamSkip>::new(
@@ -155,6 +206,28 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
             }?;
 
             end_http_request_if_closed(self, handle, &result.result).await?;
+            result.result.map_err(StreamError::from)
+        } else if let Some(connection) = get_tcp_stream_connection(self, handle) {
+            let durability =
+                Durability::<SocketsTcpInputStreamSkip>::new(self, DurableFunctionType::ReadRemote)
+                    .await?;
+
+            let result = if durability.is_live() {
+                let result = HostInputStream::skip(self.table(), self_, len).await;
+                try_trigger_tcp_retry(self, &durability, &connection, &result).await?;
+                durability
+                    .persist(
+                        self,
+                        connection.request,
+                        HostResponseStreamSkip {
+                            result: result.map_err(SerializableStreamError::from),
+                        },
+                    )
+                    .await
+            } else {
+                durability.replay(self).await
+            }?;
+
             result.result.map_err(StreamError::from)
         } else {
             self.observe_function_call("io::streams::input_stream", "skip");
@@ -167,8 +240,8 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
         self_: Resource<InputStream>,
         len: u64,
     ) -> Result<u64, StreamError> {
+        let handle = self_.rep();
         if is_incoming_http_body_stream(self, &self_) {
-            let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
             let durability = Durability::<HttpTypesIncomingBodyStreamBlockingSkip>::new(
@@ -198,6 +271,30 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
             }?;
             end_http_request_if_closed(self, handle, &result.result).await?;
 
+            result.result.map_err(StreamError::from)
+        } else if let Some(connection) = get_tcp_stream_connection(self, handle) {
+            let durability = Durability::<SocketsTcpInputStreamBlockingSkip>::new(
+                self,
+                DurableFunctionType::ReadRemote,
+            )
+            .await?;
+
+            let result = if durability.is_live() {
+                let result = HostInputStream::blocking_skip(self.table(), self_, len).await;
+                try_trigger_tcp_retry(self, &durability, &connection, &result).await?;
+                durability
+                    .persist(
+                        self,
+                        connection.request,
+                        HostResponseStreamSkip {
+                            result: result.map_err(SerializableStreamError::from),
+                        },
+                    )
+                    .await
+            } else {
+                durability.replay(self).await
+            }?;
+
             result.result.map_err(StreamError::from)
         } else {
             self.observe_function_call("io::streams::input_stream", "blocking_skip");
@@ -221,6 +318,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 }
             }
         }
+        self.state.tcp_streams.remove(&rep.rep());
 
         HostInputStream::drop(self.table(), rep).await
     }
@@ -229,7 +327,14 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     fn check_write(&mut self, self_: Resource<OutputStream>) -> Result<u64, StreamError> {
         self.observe_function_call("io::streams::output_stream", "check_write");
-        HostOutputStream::check_write(self.table(), self_)
+        if self.state.tcp_streams.contains_key(&self_.rep()) {
+            // The write budget of tcp streams is not recorded, so it is capped to a fixed value
+            // to make the writes performed by the guest the same during replay
+            let budget = HostOutputStream::check_write(self.table(), self_)?;
+            Ok(budget.min(TCP_STREAM_WRITE_BUDGET as u64))
+        } else {
+            HostOutputStream::check_write(self.table(), self_)
+        }
     }
 
     async fn write(
@@ -237,6 +342,32 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         self_: Resource<OutputStream>,
         contents: Vec<u8>,
     ) -> Result<(), StreamError> {
+        if let Some(connection) = get_tcp_stream_connection(self, self_.rep()) {
+            let durability = Durability::<SocketsTcpOutputStreamWrite>::new(
+                self,
+                DurableFunctionType::WriteRemote,
+            )
+            .await?;
+
+            let result = if durability.is_live() {
+                let result = HostOutputStream::write(self.table(), self_, contents).await;
+                try_trigger_tcp_retry(self, &durability, &connection, &result).await?;
+                durability
+                    .persist(
+                        self,
+                        connection.request,
+                        HostResponseStreamWrite {
+                            result: result.map_err(SerializableStreamError::from),
+                        },
+                    )
+                    .await
+            } else {
+                durability.replay(self).await
+            }?;
+
+            return result.result.map_err(StreamError::from);
+        }
+
         self.observe_function_call("io::streams::output_stream", "write");
 
         let output = self.table().get(&self_)?;
@@ -326,6 +457,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
 
     async fn drop(&mut self, rep: Resource<OutputStream>) -> wasmtime::Result<()> {
         self.observe_function_call("io::streams::output_stream", "drop");
+        self.state.tcp_streams.remove(&rep.rep());
         HostOutputStream::drop(self.table(), rep).await
     }
 }
@@ -410,6 +542,30 @@ fn get_http_stream_request<Ctx: WorkerCtx>(
     Ok(request_state.request.clone())
 }
 
+fn get_tcp_stream_connection<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    handle: u32,
+) -> Option<TcpConnectionState> {
+    ctx.state.tcp_streams.get(&handle).cloned()
+}
+
+/// Triggers a retry on tcp stream failures, unless the stream belongs to a connection restored
+/// from the oplog. Those can never succeed, so the failure is returned to the agent instead.
+async fn try_trigger_tcp_retry<Ctx: WorkerCtx, Pair: HostPayloadPair, T>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    durability: &Durability<Pair>,
+    connection: &TcpConnectionState,
+    result: &Result<T, StreamError>,
+) -> Result<(), StreamError> {
+    if !connection.replayed {
+        durability
+            .try_trigger_retry(ctx, &ignore_closed_error(result))
+            .await
+            .map_err(|e| StreamError::Trap(wasmtime::Error::from_anyhow(e)))?;
+    }
+    Ok(())
+}
+
 fn ignore_closed_error<T>(result: &Result<T, StreamError>) -> Result<(), &StreamError> {
     if let Err(StreamError::Closed) = result {
         Ok(())
@@ -26,14 +26,18 @@ pub mod http;
 pub mod io;
 pub mod keyvalue;
 mod logging;
+mod network_egress;
 mod random;
 pub mod rdbms;
 mod replay_state;
-mod sockets;
+mod resource_limits;
+pub mod sockets;
 pub mod wasm_rpc;
 
 use self::golem::v1x::GetPromiseResultEntry;
+use crate::durable_host::http::outgoing_http::send_request_to_address;
 use crate::durable_host::io::{ManagedStdErr, ManagedStdIn, ManagedStdOut};
+use crate::durable_host::network_egress::OutgoingHttpEgress;
 use crate::durable_host::replay_state::{OplogEntryLookupResult, ReplayState};
 use crate::metrics::wasm::{record_number_of_replayed_functions, record_resume_worker};
 use crate::model::event::InternalWorkerEvent;
@@ -47,8 +51,10 @@ use crate::services::component::ComponentService;
 use crate::services::file_loader::{FileLoader, FileUseToken};
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps, OplogService};
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
@@ -89,10 +95,11 @@ use golem_common::model::environment::EnvironmentId;
 use golem_common::model::invocation_context::{
     AttributeValue, InvocationContextSpan, InvocationContextStack, SpanId,
 };
+use golem_common::model::oplog::types::SerializableIpSocketAddress;
 use golem_common::model::oplog::{
-    DurableFunctionType, HostRequestHttpRequest, LogLevel, OplogEntry, OplogIndex,
-    PersistenceLevel, RawSnapshotData, TimestampedUpdateDescription, UpdateDescription,
-    WorkerError, WorkerResourceId,
+    DurableFunctionType, HostRequestHttpRequest, HostRequestSocketsTcpConnection, LogLevel,
+    OplogEntry, OplogIndex, PersistenceLevel, RawSnapshotData, TimestampedUpdateDescription,
+    UpdateDescription, WorkerError, WorkerResourceId,
 };
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
 use golem_common::model::worker::ParsedWorkerCreationLocalAgentConfigEntry;
@@ -100,8 +107,8 @@ use golem_common::model::RetryConfig;
 use golem_common::model::TransactionId;
 use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, AgentInvocationResult, IdempotencyKey, OwnedWorkerId,
-    ScanCursor, ScheduledAction, Timestamp, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
-    WorkerStatusRecord,
+    PromiseId, ScanCursor, ScheduledAction, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
+    WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::retries::get_delay;
 use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
@@ -112,9 +119,11 @@ use golem_service_base::model::{
 use golem_wasm::wasmtime::{ResourceStore, ResourceTypeId};
 use golem_wasm::Uri;
 use replay_state::ReplayEvent;
+use resource_limits::HttpRequestRateWindow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant, SystemTime};
@@ -168,6 +177,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         oplog: Arc<dyn Oplog>,
         invocation_queue: Weak<Worker<Ctx>>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
@@ -178,6 +188,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         worker_fork: Arc<dyn WorkerForkService>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shard_service: Arc<dyn ShardService>,
         pending_update: Option<TimestampedUpdateDescription>,
         original_phantom_id: Option<Uuid>,
@@ -263,6 +274,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 oplog,
                 promise_service,
                 scheduler_service,
+                pubsub_service,
                 worker_service,
                 worker_enumeration_service,
                 key_value_service,
@@ -271,6 +283,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 component_service,
                 agent_types_service,
                 agent_webhooks_service,
+                network_egress_policy_service,
                 config.clone(),
                 owned_worker_id.clone(),
                 rpc,
@@ -458,7 +471,15 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 ..
             } => RetryDecision::None,
             TrapType::Error {
-                error: WorkerError::Unknown(_),
+                error:
+                    WorkerError::ExceededAgentMemoryLimit
+                    | WorkerError::ExceededInvocationFuelLimit
+                    | WorkerError::ExceededInvocationTimeLimit
+                    | WorkerError::ExceededOplogSizeLimit,
+                ..
+            } => RetryDecision::None,
+            TrapType::Error {
+                error: WorkerError::Unknown(_) | WorkerError::ExceededHttpRequestRateLimit,
                 retry_from,
             } => {
                 let previous_tries = previous_tries.get(retry_from).copied().unwrap_or_default();
@@ -542,6 +563,10 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         &mut self,
         function_type: &DurableFunctionType,
     ) -> Result<OplogIndex, WorkerExecutorError> {
+        if self.is_live() {
+            self.update_oplog_size_limit_state().await;
+        }
+
         if (*function_type == DurableFunctionType::WriteRemote && !self.state.assume_idempotence)
             || matches!(
                 *function_type,
@@ -1600,6 +1625,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                 .commit_oplog_and_update_state(CommitLevel::Always)
                 .await;
         }
+        self.begin_invocation_limits().await;
         Ok(())
     }
 
@@ -1608,6 +1634,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         full_function_name: &str,
         trap_type: &TrapType,
     ) -> RetryDecision {
+        self.end_invocation_limits();
         let current_idempotency_key = self.get_current_idempotency_key().await;
 
         let oplog_entry = match trap_type {
@@ -1695,6 +1722,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         consumed_fuel: u64,
         output: &AgentInvocationOutput,
     ) -> Result<(), WorkerExecutorError> {
+        self.end_invocation_limits();
         let is_live = self.state.is_live();
 
         if is_live {
@@ -1760,6 +1788,18 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
             self.state.current_retry_point
         }
     }
+
+    fn begin_read_only_query(&mut self) {
+        // Read-only queries run like snapshotting functions: no invocation markers and no
+        // persisted host calls. On top of that, side effects are rejected in `begin_durable_function`.
+        self.begin_call_snapshotting_function();
+        self.state.read_only_query = true;
+    }
+
+    fn end_read_only_query(&mut self) {
+        self.state.read_only_query = false;
+        self.end_call_snapshotting_function();
+    }
 }
 
 #[async_trait]
@@ -2379,6 +2419,21 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                         record_resume_worker(start.elapsed());
                         result
                     }
+                    SnapshotRecoveryResult::NotAttempted | SnapshotRecoveryResult::Failed
+                        if store
+                            .as_context()
+                            .data()
+                            .get_public_state()
+                            .worker()
+                            .has_discarded_oplog_history()
+                            .await =>
+                    {
+                        // A full replay would silently skip the history discarded by the
+                        // oplog retention policy, so recovering without the snapshot is not possible
+                        Err(WorkerExecutorError::runtime(
+                            "Failed to recover from the latest snapshot, and the oplog history before it has been discarded by the retention policy",
+                        ))
+                    }
                     SnapshotRecoveryResult::NotAttempted => {
                         let result = Self::resume_replay(store, instance, false).await;
                         record_resume_worker(start.elapsed());
@@ -2828,12 +2883,23 @@ struct HttpRequestState {
     pub body_handle: Option<u32>,
 }
 
+/// State associated with tcp connections, on top of the underlying wasi-sockets implementation
+#[derive(Debug, Clone)]
+pub(crate) struct TcpConnectionState {
+    /// Information about the connection to be included in the oplog
+    pub request: HostRequestSocketsTcpConnection,
+    /// True if the connection was restored from the oplog and there is no real network
+    /// connection behind it. Such connections fail once the worker is live again.
+    pub replayed: bool,
+}
+
 struct PrivateDurableWorkerState {
     // IMPORTANT: commits to the oplog must go via self.public_state.worker().commit_oplog_and_update_state
     oplog_service: Arc<dyn OplogService>,
     oplog: Arc<dyn Oplog>,
     promise_service: Arc<dyn PromiseService>,
     scheduler_service: Arc<dyn SchedulerService>,
+    pubsub_service: Arc<dyn PubSubService>,
     worker_service: Arc<dyn WorkerService>,
     worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
     key_value_service: Arc<dyn KeyValueService>,
@@ -2842,6 +2908,7 @@ struct PrivateDurableWorkerState {
     component_service: Arc<dyn ComponentService>,
     agent_types_service: Arc<dyn AgentTypesService>,
     agent_webhooks_service: Arc<AgentWebhooksService>,
+    network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
     config: Arc<GolemConfig>,
     owned_worker_id: OwnedWorkerId,
     created_by: AccountId,
@@ -2859,7 +2926,31 @@ struct PrivateDurableWorkerState {
     /// State of ongoing http requests, key is the resource id it is most recently associated with (one state object can belong to multiple resources, but just one at once)
     open_http_requests: HashMap<u32, HttpRequestState>,
 
+    /// State of tcp sockets with a successfully started connection, key is the socket's resource id
+    tcp_connections: HashMap<u32, TcpConnectionState>,
+    /// State of the streams of established tcp connections, key is the input or output stream's resource id
+    tcp_streams: HashMap<u32, TcpConnectionState>,
+    /// Remote addresses of connected udp streams, key is the outgoing datagram stream's resource id
+    udp_stream_remote_addresses: HashMap<u32, SerializableIpSocketAddress>,
+    /// Host names resolved by the worker, used to match raw socket addresses against the domain
+    /// rules of the network egress policy
+    resolved_host_names: HashMap<IpAddr, String>,
+    /// Set for the duration of sending an outgoing http request restricted by the network egress policy
+    outgoing_http_egress: Option<OutgoingHttpEgress>,
+    /// Outgoing http requests of the last minute, for the request rate limit of the agent type
+    outgoing_http_request_rate: HttpRequestRateWindow,
+    /// Start of the invocation running in live mode, for the invocation limits of the agent type
+    current_invocation_started_at: Option<Instant>,
+    /// Oplog index at the start of the invocation running in live mode, for the oplog entries per
+    /// invocation limit of the agent type
+    current_invocation_oplog_start: OplogIndex,
+    /// Set when the running invocation added more oplog entries than the oplog entries per
+    /// invocation limit of the agent type allows
+    oplog_size_limit_exceeded: bool,
+
     snapshotting_mode: Option<PersistenceLevel>,
+    /// Set while a read-only agent method is running against the current in-memory state
+    read_only_query: bool,
 
     component_metadata: Component,
 
@@ -2922,6 +3013,7 @@ impl PrivateDurableWorkerState {
         oplog: Arc<dyn Oplog>,
         promise_service: Arc<dyn PromiseService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -2930,6 +3022,7 @@ impl PrivateDurableWorkerState {
         component_service: Arc<dyn ComponentService>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         config: Arc<GolemConfig>,
         owned_worker_id: OwnedWorkerId,
         rpc: Arc<dyn Rpc>,
@@ -2974,6 +3067,7 @@ impl PrivateDurableWorkerState {
             agent_id,
             promise_service,
             scheduler_service,
+            pubsub_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -2982,6 +3076,7 @@ impl PrivateDurableWorkerState {
             component_service,
             agent_types_service,
             agent_webhooks_service,
+            network_egress_policy_service,
             config,
             owned_worker_id,
             current_idempotency_key: None,
@@ -2993,7 +3088,17 @@ impl PrivateDurableWorkerState {
             persistence_level: PersistenceLevel::Smart,
             assume_idempotence: true,
             open_http_requests: HashMap::new(),
+            tcp_connections: HashMap::new(),
+            tcp_streams: HashMap::new(),
+            udp_stream_remote_addresses: HashMap::new(),
+            resolved_host_names: HashMap::new(),
+            outgoing_http_egress: None,
+            outgoing_http_request_rate: HttpRequestRateWindow::default(),
+            current_invocation_started_at: None,
+            current_invocation_oplog_start: OplogIndex::NONE,
+            oplog_size_limit_exceeded: false,
             snapshotting_mode: None,
+            read_only_query: false,
             component_metadata,
             total_linear_memory_size,
             replay_state,
@@ -3070,6 +3175,27 @@ impl PrivateDurableWorkerState {
         Ok(())
     }
 
+    /// Schedules the expiry of a promise created by this worker. If the promise is still
+    /// pending at `when`, it gets completed with the expired outcome.
+    pub async fn expire_promise_at(&self, promise_id: PromiseId, when: DateTime<Utc>) {
+        let schedule_id = self
+            .scheduler_service
+            .schedule(
+                when,
+                ScheduledAction::ExpirePromise {
+                    account_id: self.created_by,
+                    environment_id: self.owned_worker_id.environment_id(),
+                    promise_id,
+                },
+            )
+            .await;
+        debug!(
+            "Schedule added to expire promise at {} with id {}",
+            when.to_rfc3339(),
+            schedule_id
+        );
+    }
+
     pub fn get_current_idempotency_key(&self) -> Option<IdempotencyKey> {
         self.current_idempotency_key.clone()
     }
@@ -3299,7 +3425,17 @@ impl<Ctx: WorkerCtx> WasiHttpView for DurableWorkerCtxWasiHttpView<'_, Ctx> {
             // or poll the response future.
             Ok(HostFutureIncomingResponse::deferred(request, config))
         } else {
-            Ok(default_send_request(request, config))
+            match self.0.state.outgoing_http_egress {
+                // The denial is returned through the response future, so it gets recorded in the
+                // oplog like any other response
+                Some(OutgoingHttpEgress::Denied) => Ok(HostFutureIncomingResponse::ready(Ok(Err(
+                    wasmtime_wasi_http::bindings::http::types::ErrorCode::HttpRequestDenied,
+                )))),
+                Some(OutgoingHttpEgress::Connect(address)) => {
+                    Ok(send_request_to_address(request, config, address))
+                }
+                None => Ok(default_send_request(request, config)),
+            }
         }
     }
 }
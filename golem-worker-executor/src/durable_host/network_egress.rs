@@ -0,0 +1,276 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::DurableWorkerCtx;
+use crate::model::event::InternalWorkerEvent;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::environment::{NetworkEgressPolicy, NetworkEgressTarget};
+use golem_common::model::LogLevel;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::net::{IpAddr, SocketAddr};
+
+const NETWORK_EGRESS_LOG_CONTEXT: &str = "network-egress";
+
+/// How the outgoing http request being sent has to connect, according to the network egress policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutgoingHttpEgress {
+    /// Connect to this address, which was checked against the policy, without resolving the
+    /// host again
+    Connect(SocketAddr),
+    Denied,
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Checks an outgoing network access against the network egress policy of the worker's
+    /// environment. Denied accesses are written to the worker's log.
+    ///
+    /// Must only be called in live mode, and the outcome has to be part of the persisted result of
+    /// the network operation, so replaying does not depend on the current policy.
+    pub(crate) async fn is_network_egress_allowed(
+        &self,
+        mut target: NetworkEgressTarget,
+    ) -> Result<bool, WorkerExecutorError> {
+        if target.domain.is_none() {
+            if let Some(ip) = &target.ip {
+                target.domain = self.state.resolved_host_names.get(ip).cloned();
+            }
+        }
+
+        let policy = self
+            .state
+            .network_egress_policy_service
+            .get_network_egress_policy(self.owned_worker_id.environment_id)
+            .await?;
+
+        match policy.check(&target) {
+            Ok(()) => Ok(true),
+            Err(reason) => {
+                self.log_network_egress_denial(&reason).await;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Checks resolving a host name against the network egress policy, see
+    /// [`NetworkEgressPolicy::check_name_lookup`]. Connections to the resolved addresses are
+    /// checked separately.
+    pub(crate) async fn is_name_lookup_allowed(
+        &self,
+        name: &str,
+    ) -> Result<bool, WorkerExecutorError> {
+        let policy = self
+            .state
+            .network_egress_policy_service
+            .get_network_egress_policy(self.owned_worker_id.environment_id)
+            .await?;
+
+        match policy.check_name_lookup(name) {
+            Ok(()) => Ok(true),
+            Err(reason) => {
+                self.log_network_egress_denial(&reason).await;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Decides how an outgoing http request to the given host connects. The host is resolved and
+    /// each address is checked against the network egress policy together with the host name, so
+    /// both domain and CIDR rules apply. Returns `None` if the policy does not restrict anything,
+    /// or the host cannot be resolved and the request fails on its own.
+    ///
+    /// Must only be called in live mode, like [`Self::is_network_egress_allowed`].
+    pub(crate) async fn outgoing_http_egress(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> Result<Option<OutgoingHttpEgress>, WorkerExecutorError> {
+        let policy = self
+            .state
+            .network_egress_policy_service
+            .get_network_egress_policy(self.owned_worker_id.environment_id)
+            .await?;
+
+        if policy.is_unrestricted() {
+            return Ok(None);
+        }
+
+        let unbracketed = host.trim_start_matches('[').trim_end_matches(']');
+        let addresses = tokio::net::lookup_host((unbracketed, port))
+            .await
+            .map(|addresses| addresses.collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        match select_egress_address(&policy, host, port, addresses) {
+            Ok(Some(address)) => Ok(Some(OutgoingHttpEgress::Connect(address))),
+            Ok(None) => Ok(None),
+            Err(reason) => {
+                self.log_network_egress_denial(&reason).await;
+                Ok(Some(OutgoingHttpEgress::Denied))
+            }
+        }
+    }
+
+    async fn log_network_egress_denial(&self, reason: &str) {
+        self.emit_log_event(InternalWorkerEvent::log(
+            LogLevel::Warn,
+            NETWORK_EGRESS_LOG_CONTEXT,
+            &format!("Outgoing network access denied: {reason}"),
+        ))
+        .await;
+    }
+
+    /// Remembers the host name the given addresses were resolved from, so connections to them
+    /// can be matched against the domain rules of the network egress policy
+    pub(crate) fn record_resolved_host_name(
+        &mut self,
+        name: &str,
+        addresses: impl IntoIterator<Item = IpAddr>,
+    ) {
+        let name = name.to_lowercase();
+        for address in addresses {
+            self.state.resolved_host_names.insert(address, name.clone());
+        }
+    }
+}
+
+/// Selects the first of the resolved addresses of a host which the policy allows to connect to.
+/// If the host could not be resolved, only looking up its name is checked and `None` is returned.
+fn select_egress_address(
+    policy: &NetworkEgressPolicy,
+    host: &str,
+    port: u16,
+    addresses: Vec<SocketAddr>,
+) -> Result<Option<SocketAddr>, String> {
+    if addresses.is_empty() {
+        return policy.check_name_lookup(host).map(|()| None);
+    }
+
+    let host_target = NetworkEgressTarget::host(host, Some(port));
+    let mut denial = String::new();
+    for address in addresses {
+        let target = NetworkEgressTarget {
+            ip: Some(address.ip()),
+            ..host_target.clone()
+        };
+        match policy.check(&target) {
+            Ok(()) => return Ok(Some(address)),
+            Err(reason) => denial = reason,
+        }
+    }
+    Err(denial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::select_egress_address;
+    use golem_common::model::environment::{NetworkEgressPolicy, NetworkEgressRule};
+    use std::net::SocketAddr;
+    use test_r::test;
+
+    fn policy(allow: &[&str], deny: &[&str]) -> NetworkEgressPolicy {
+        let rules = |hosts: &[&str]| {
+            hosts
+                .iter()
+                .map(|host| NetworkEgressRule {
+                    host: host.to_string(),
+                    ports: vec![],
+                })
+                .collect()
+        };
+        NetworkEgressPolicy {
+            no_network: false,
+            allow: rules(allow),
+            deny: rules(deny),
+        }
+    }
+
+    fn address(address: &str) -> SocketAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn host_name_resolving_into_denied_cidr_is_denied() {
+        let policy = policy(&[], &["10.0.0.0/8"]);
+
+        let result = select_egress_address(
+            &policy,
+            "internal.example.com",
+            443,
+            vec![address("10.1.2.3:443")],
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn denied_addresses_of_a_host_name_are_skipped() {
+        let policy = policy(&[], &["10.0.0.0/8"]);
+
+        let result = select_egress_address(
+            &policy,
+            "example.com",
+            443,
+            vec![address("10.1.2.3:443"), address("93.184.216.34:443")],
+        );
+
+        assert_eq!(result, Ok(Some(address("93.184.216.34:443"))));
+    }
+
+    #[test]
+    fn host_name_resolving_into_allowed_cidr_is_allowed() {
+        let policy = policy(&["93.184.0.0/16"], &[]);
+
+        let allowed = select_egress_address(
+            &policy,
+            "example.com",
+            443,
+            vec![address("93.184.216.34:443")],
+        );
+        let not_allowed =
+            select_egress_address(&policy, "example.org", 443, vec![address("1.2.3.4:443")]);
+
+        assert_eq!(allowed, Ok(Some(address("93.184.216.34:443"))));
+        assert!(not_allowed.is_err());
+    }
+
+    #[test]
+    fn domain_rules_apply_to_resolved_host_names() {
+        let policy = policy(&["*.example.com"], &[]);
+
+        let allowed = select_egress_address(
+            &policy,
+            "api.example.com",
+            443,
+            vec![address("1.2.3.4:443")],
+        );
+        let denied =
+            select_egress_address(&policy, "example.org", 443, vec![address("1.2.3.4:443")]);
+
+        assert_eq!(allowed, Ok(Some(address("1.2.3.4:443"))));
+        assert!(denied.is_err());
+    }
+
+    #[test]
+    async fn localhost_is_denied_by_loopback_cidr() {
+        let policy = policy(&[], &["127.0.0.0/8", "::1/128"]);
+        let addresses = tokio::net::lookup_host(("localhost", 80))
+            .await
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        let result = select_egress_address(&policy, "localhost", 80, addresses);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::DurableWorkerCtx;
+use crate::metrics::resources::record_agent_resource_limit_exceeded;
+use crate::services::HasWorker;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::component::AgentResourceLimits;
+use golem_service_base::error::worker_executor::GolemSpecificWasmTrap;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+const HTTP_REQUEST_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Sliding one minute window of the outgoing HTTP requests of a worker
+#[derive(Debug, Default)]
+pub(crate) struct HttpRequestRateWindow {
+    requests: VecDeque<Instant>,
+}
+
+impl HttpRequestRateWindow {
+    /// Registers a new request at `now`, unless there were already `max_requests` requests in the
+    /// last minute. Returns whether the request is allowed.
+    pub fn try_register(&mut self, now: Instant, max_requests: u64) -> bool {
+        while let Some(oldest) = self.requests.front() {
+            if now.saturating_duration_since(*oldest) >= HTTP_REQUEST_RATE_WINDOW {
+                self.requests.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.requests.len() as u64 >= max_requests {
+            false
+        } else {
+            self.requests.push_back(now);
+            true
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Resource limits declared for the agent type of the worker, if any
+    pub(crate) fn agent_resource_limits(&self) -> Option<&AgentResourceLimits> {
+        let agent_id = self.state.agent_id.as_ref()?;
+        self.state
+            .component_metadata
+            .agent_resource_limits_of(&agent_id.agent_type)
+    }
+
+    /// Maximum size of the worker's linear memory as declared for its agent type
+    pub fn agent_max_memory(&self) -> Option<usize> {
+        self.agent_resource_limits()?
+            .max_memory_bytes
+            .map(|max| usize::try_from(max).unwrap_or(usize::MAX))
+    }
+
+    /// Checks the limits of the invocation currently running in live mode.
+    ///
+    /// Called periodically while the worker is executing, with the amount of fuel consumed since
+    /// the invocation started.
+    pub fn check_invocation_limits(&self, consumed_fuel: u64) -> Option<GolemSpecificWasmTrap> {
+        let started_at = self.state.current_invocation_started_at?;
+        let limits = self.agent_resource_limits()?;
+
+        let trap = if self.state.oplog_size_limit_exceeded {
+            Some(GolemSpecificWasmTrap::WorkerExceededOplogSizeLimit)
+        } else if limits
+            .fuel_per_invocation
+            .is_some_and(|max| consumed_fuel > max)
+        {
+            Some(GolemSpecificWasmTrap::WorkerExceededInvocationFuelLimit)
+        } else if limits
+            .max_invocation_time_millis
+            .is_some_and(|max| started_at.elapsed() > Duration::from_millis(max))
+        {
+            Some(GolemSpecificWasmTrap::WorkerExceededInvocationTimeLimit)
+        } else {
+            None
+        };
+
+        if let Some(trap) = &trap {
+            self.record_resource_limit_exceeded(trap);
+        }
+        trap
+    }
+
+    /// Marks the start of a live invocation. The oplog entries per invocation limit of the
+    /// worker's agent type is applied to the entries added from this point on.
+    pub(crate) async fn begin_invocation_limits(&mut self) {
+        self.state.current_invocation_started_at = Some(Instant::now());
+        self.state.current_invocation_oplog_start = self
+            .public_state
+            .worker()
+            .oplog()
+            .current_oplog_index()
+            .await;
+        self.state.oplog_size_limit_exceeded = false;
+    }
+
+    pub(crate) fn end_invocation_limits(&mut self) {
+        self.state.current_invocation_started_at = None;
+        self.state.oplog_size_limit_exceeded = false;
+    }
+
+    /// Re-checks the number of oplog entries added by the running invocation against the limit of
+    /// the worker's agent type. Exceeding it fails the running invocation at the next check of the
+    /// invocation limits; the following invocations start counting from zero again.
+    pub(crate) async fn update_oplog_size_limit_state(&mut self) {
+        if self.state.current_invocation_started_at.is_none() {
+            return;
+        }
+        if let Some(max_entries) = self
+            .agent_resource_limits()
+            .and_then(|limits| limits.max_oplog_entries_per_invocation)
+        {
+            let current_index = self
+                .public_state
+                .worker()
+                .oplog()
+                .current_oplog_index()
+                .await;
+            let added_entries = u64::from(current_index)
+                .saturating_sub(u64::from(self.state.current_invocation_oplog_start));
+            self.state.oplog_size_limit_exceeded = added_entries > max_entries;
+        }
+    }
+
+    /// Registers an outgoing HTTP request in live mode, failing with a trap if the worker
+    /// exceeded the request rate limit of its agent type
+    pub(crate) fn register_outgoing_http_request(&mut self) -> Result<(), GolemSpecificWasmTrap> {
+        let Some(max_requests) = self
+            .agent_resource_limits()
+            .and_then(|limits| limits.max_outgoing_http_requests_per_minute)
+        else {
+            return Ok(());
+        };
+
+        if self
+            .state
+            .outgoing_http_request_rate
+            .try_register(Instant::now(), max_requests)
+        {
+            Ok(())
+        } else {
+            let trap = GolemSpecificWasmTrap::WorkerExceededHttpRequestRateLimit;
+            self.record_resource_limit_exceeded(&trap);
+            Err(trap)
+        }
+    }
+
+    /// Called when the worker's memory would grow beyond the limit of its agent type
+    pub fn agent_memory_limit_exceeded(&self) -> GolemSpecificWasmTrap {
+        let trap = GolemSpecificWasmTrap::WorkerExceededAgentMemoryLimit;
+        self.record_resource_limit_exceeded(&trap);
+        trap
+    }
+
+    fn record_resource_limit_exceeded(&self, trap: &GolemSpecificWasmTrap) {
+        let limit = match trap {
+            GolemSpecificWasmTrap::WorkerExceededAgentMemoryLimit => "max_memory",
+            GolemSpecificWasmTrap::WorkerExceededInvocationFuelLimit => "fuel_per_invocation",
+            GolemSpecificWasmTrap::WorkerExceededInvocationTimeLimit => "max_invocation_time",
+            GolemSpecificWasmTrap::WorkerExceededOplogSizeLimit => {
+                "max_oplog_entries_per_invocation"
+            }
+            GolemSpecificWasmTrap::WorkerExceededHttpRequestRateLimit => {
+                "max_outgoing_http_requests_per_minute"
+            }
+            GolemSpecificWasmTrap::WorkerOutOfMemory
+            | GolemSpecificWasmTrap::WorkerExceededMemoryLimit => return,
+        };
+        let agent_type = self
+            .state
+            .agent_id
+            .as_ref()
+            .map(|agent_id| agent_id.agent_type.as_str())
+            .unwrap_or_default();
+
+        warn!(
+            "Worker {} exceeded the {limit} limit of agent type {agent_type}",
+            self.owned_worker_id
+        );
+        record_agent_resource_limit_exceeded(agent_type, limit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpRequestRateWindow;
+    use std::time::{Duration, Instant};
+    use test_r::test;
+
+    #[test]
+    fn http_request_rate_window_limits_requests_per_minute() {
+        let start = Instant::now();
+        let mut window = HttpRequestRateWindow::default();
+
+        assert!(window.try_register(start, 2));
+        assert!(window.try_register(start + Duration::from_secs(10), 2));
+        assert!(!window.try_register(start + Duration::from_secs(20), 2));
+
+        // the first request leaves the window
+        assert!(window.try_register(start + Duration::from_secs(60), 2));
+        assert!(!window.try_register(start + Duration::from_secs(65), 2));
+
+        // both earlier accepted requests leave the window
+        assert!(window.try_register(start + Duration::from_secs(125), 2));
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
 use wasmtime::component::Resource;
 
 use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
@@ -71,11 +72,16 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         .await?;
 
         let result = if durability.is_live() {
-            let result = resolve_and_drain_addresses(self, network, name.clone()).await;
-            durability
-                .try_trigger_retry(self, &result)
-                .await
-                .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+            let result = if self.is_name_lookup_allowed(&name).await? {
+                let result = resolve_and_drain_addresses(self, network, name.clone()).await;
+                durability
+                    .try_trigger_retry(self, &result)
+                    .await
+                    .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+                result
+            } else {
+                Err(ErrorCode::AccessDenied.into())
+            };
 
             let serializable_result = match result {
                 Ok(addresses) => Ok(SerializableIpAddresses::from(addresses)),
@@ -84,7 +90,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             durability
                 .persist(
                     self,
-                    HostRequestSocketsResolveName { name },
+                    HostRequestSocketsResolveName { name: name.clone() },
                     HostResponseSocketsResolveName {
                         result: serializable_result,
                     },
@@ -96,6 +102,10 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
 
         match result.result {
             Ok(addresses) => {
+                self.record_resolved_host_name(
+                    &name,
+                    addresses.0.iter().cloned().map(IpAddr::from),
+                );
                 let addresses: Vec<IpAddress> = addresses.0.into_iter().map(|a| a.into()).collect();
                 let stream = ResolveAddressStream::Done(Ok(addresses.into_iter()));
                 Ok(self.table().push(stream)?)
@@ -12,16 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
+use std::net::IpAddr;
+
+use async_trait::async_trait;
+use bytes::Bytes;
 use wasmtime::component::Resource;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx, TcpConnectionState};
 use crate::workerctx::WorkerCtx;
+use golem_common::model::environment::NetworkEgressTarget;
+use golem_common::model::oplog::host_functions::{SocketsTcpFinishConnect, SocketsTcpStartConnect};
+use golem_common::model::oplog::types::{SerializableIpSocketAddress, SerializableSocketError};
+use golem_common::model::oplog::{
+    DurableFunctionType, HostRequestSocketsTcpConnection, HostResponseSocketsUnit,
+};
+use wasmtime_wasi::p2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::p2::bindings::sockets::tcp::{
     Duration, Host, HostTcpSocket, InputStream, IpAddressFamily, IpSocketAddress, Network,
     OutputStream, Pollable, ShutdownType, TcpSocket,
 };
 use wasmtime_wasi::p2::SocketError;
 use wasmtime_wasi::sockets::WasiSocketsView as _;
+use wasmtime_wasi::{StreamError, StreamResult};
 
 impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
     async fn start_bind(
@@ -46,15 +59,84 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         network: Resource<Network>,
         remote_address: IpSocketAddress,
     ) -> Result<(), SocketError> {
-        self.observe_function_call("sockets::tcp", "start_connect");
-        let mut view = self.as_wasi_view();
-        HostTcpSocket::start_connect(&mut view.sockets(), self_, network, remote_address).await
+        let handle = self_.rep();
+        let request = HostRequestSocketsTcpConnection {
+            remote_address: SerializableIpSocketAddress::from(remote_address),
+        };
+        let egress_target = NetworkEgressTarget::ip(
+            IpAddr::from(request.remote_address.address.clone()),
+            request.remote_address.port,
+        );
+
+        if !self.state.config.sockets.durable {
+            self.observe_function_call("sockets::tcp", "start_connect");
+            if !self.is_network_egress_allowed(egress_target).await? {
+                return Err(ErrorCode::AccessDenied.into());
+            }
+            let mut view = self.as_wasi_view();
+            return HostTcpSocket::start_connect(
+                &mut view.sockets(),
+                self_,
+                network,
+                remote_address,
+            )
+            .await;
+        }
+
+        let durability =
+            Durability::<SocketsTcpStartConnect>::new(self, DurableFunctionType::ReadRemote)
+                .await?;
+
+        // In replay mode no real connection is initiated, the socket stays in its initial state
+        // and all further operations on the connection are served from the oplog
+        let result = if durability.is_live() {
+            let result = if self.is_network_egress_allowed(egress_target).await? {
+                let mut view = self.as_wasi_view();
+                let result = HostTcpSocket::start_connect(
+                    &mut view.sockets(),
+                    self_,
+                    network,
+                    remote_address,
+                )
+                .await;
+                durability
+                    .try_trigger_retry(self, &result)
+                    .await
+                    .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+                result
+            } else {
+                Err(ErrorCode::AccessDenied.into())
+            };
+            durability
+                .persist(
+                    self,
+                    request.clone(),
+                    HostResponseSocketsUnit {
+                        result: result.map_err(SerializableSocketError::from),
+                    },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        if result.result.is_ok() {
+            self.state.tcp_connections.insert(
+                handle,
+                TcpConnectionState {
+                    request,
+                    replayed: !durability.is_live(),
+                },
+            );
+        }
+        result.result.map_err(SocketError::from)
     }
 
     fn finish_connect(
         &mut self,
         self_: Resource<TcpSocket>,
     ) -> Result<(Resource<InputStream>, Resource<OutputStream>), SocketError> {
+        // Only used if the linker does not override finish-connect with `finish_connect_durably`
         self.observe_function_call("sockets::tcp", "finish_connect");
         HostTcpSocket::finish_connect(&mut self.as_wasi_view().sockets(), self_)
     }
@@ -97,6 +179,11 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         self_: Resource<TcpSocket>,
     ) -> Result<IpSocketAddress, SocketError> {
         self.observe_function_call("sockets::tcp", "remote_address");
+        if let Some(connection) = self.state.tcp_connections.get(&self_.rep()) {
+            if connection.replayed {
+                return Ok(connection.request.remote_address.clone().into());
+            }
+        }
         HostTcpSocket::remote_address(&mut self.as_wasi_view().sockets(), self_)
     }
 
@@ -227,13 +314,177 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         shutdown_type: ShutdownType,
     ) -> Result<(), SocketError> {
         self.observe_function_call("sockets::tcp", "shutdown");
+        if is_replayed_connection(self, &self_) {
+            // There is no real connection to shut down
+            return Ok(());
+        }
         HostTcpSocket::shutdown(&mut self.as_wasi_view().sockets(), self_, shutdown_type)
     }
 
     fn drop(&mut self, rep: Resource<TcpSocket>) -> wasmtime::Result<()> {
         self.observe_function_call("sockets::tcp", "drop");
+        self.state.tcp_connections.remove(&rep.rep());
         HostTcpSocket::drop(&mut self.as_wasi_view().sockets(), rep)
     }
 }
 
+/// Durable version of `tcp-socket.finish-connect`.
+///
+/// The wasi-sockets binding of `finish-connect` is synchronous, so the linker overrides it with
+/// this function to be able to record the result of the connection attempt in the oplog. Sockets
+/// connected while durable sockets are disabled in the configuration are not recorded. When
+/// replaying a successful connection, the returned streams are not backed by a real network
+/// connection: reads are served from the oplog, writes are dropped, and once the agent is live
+/// again every operation on them fails, so the agent has to open a fresh connection.
+pub async fn finish_connect_durably<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    self_: Resource<TcpSocket>,
+) -> Result<(Resource<InputStream>, Resource<OutputStream>), SocketError> {
+    let handle = self_.rep();
+    let Some(connection) = ctx.state.tcp_connections.get(&handle).cloned() else {
+        // No recorded connection attempt, so this can only fail with a deterministic error
+        ctx.observe_function_call("sockets::tcp", "finish_connect");
+        return HostTcpSocket::finish_connect(&mut ctx.as_wasi_view().sockets(), self_);
+    };
+
+    let durability =
+        Durability::<SocketsTcpFinishConnect>::new(ctx, DurableFunctionType::ReadRemote).await?;
+
+    let (streams, result) = if durability.is_live() && !connection.replayed {
+        let result = HostTcpSocket::finish_connect(&mut ctx.as_wasi_view().sockets(), self_)
+            .map_err(SerializableSocketError::from);
+        let (streams, result) = match result {
+            Ok(streams) => (Some(streams), Ok(())),
+            Err(err) => (None, Err(err)),
+        };
+        if let Err(err) = &result {
+            // WouldBlock only means the connection is not established yet, it is recorded but not retried
+            if !is_would_block(err) {
+                durability
+                    .try_trigger_retry(ctx, &Err::<(), _>(SocketError::from(err.clone())))
+                    .await
+                    .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+            }
+        }
+        let result = durability
+            .persist(
+                ctx,
+                connection.request.clone(),
+                HostResponseSocketsUnit { result },
+            )
+            .await?;
+        (streams, result.result)
+    } else if durability.is_live() {
+        // The connection attempt was started during replay, but the result of finishing it was
+        // never recorded. There is no real connection to finish.
+        let result = durability
+            .persist(
+                ctx,
+                connection.request.clone(),
+                HostResponseSocketsUnit {
+                    result: Err(SerializableSocketError::from(SocketError::from(
+                        ErrorCode::ConnectionAborted,
+                    ))),
+                },
+            )
+            .await?;
+        (None, result.result)
+    } else {
+        let result = durability.replay(ctx).await?;
+        (None, result.result)
+    };
+
+    match result {
+        Ok(()) => {
+            let (input, output) = match streams {
+                Some(streams) => streams,
+                None => {
+                    let input: InputStream = Box::new(ReplayedTcpStream);
+                    let output: OutputStream = Box::new(ReplayedTcpStream);
+                    (ctx.table().push(input)?, ctx.table().push(output)?)
+                }
+            };
+            for stream_handle in [input.rep(), output.rep()] {
+                ctx.state
+                    .tcp_streams
+                    .insert(stream_handle, connection.clone());
+            }
+            Ok((input, output))
+        }
+        Err(err) => {
+            if !is_would_block(&err) {
+                ctx.state.tcp_connections.remove(&handle);
+            }
+            Err(err.into())
+        }
+    }
+}
+
+fn is_replayed_connection<Ctx: WorkerCtx>(
+    ctx: &DurableWorkerCtx<Ctx>,
+    socket: &Resource<TcpSocket>,
+) -> bool {
+    ctx.state
+        .tcp_connections
+        .get(&socket.rep())
+        .is_some_and(|connection| connection.replayed)
+}
+
+fn is_would_block(error: &SerializableSocketError) -> bool {
+    *error == SerializableSocketError::from(SocketError::from(ErrorCode::WouldBlock))
+}
+
+/// Stream of a tcp connection restored from the oplog, without a real network connection behind it.
+///
+/// Reads and writes are recorded in the oplog, so during replay they never reach this stream. In
+/// live mode they fail, indicating that the connection has to be re-established by the agent.
+/// Readiness checks and flushes always succeed so they behave the same in replay and live mode.
+pub struct ReplayedTcpStream;
+
+/// Upper limit of the write budget reported for tcp output streams
+pub const TCP_STREAM_WRITE_BUDGET: usize = 4096;
+
+impl ReplayedTcpStream {
+    fn error() -> StreamError {
+        StreamError::LastOperationFailed(anyhow::anyhow!(
+            "The connection was lost when the agent was recovered, a new connection must be opened"
+        ))
+    }
+}
+
+#[async_trait]
+impl wasmtime_wasi::Pollable for ReplayedTcpStream {
+    async fn ready(&mut self) {}
+}
+
+#[async_trait]
+impl wasmtime_wasi::InputStream for ReplayedTcpStream {
+    fn read(&mut self, _size: usize) -> StreamResult<Bytes> {
+        Err(Self::error())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl wasmtime_wasi::OutputStream for ReplayedTcpStream {
+    fn write(&mut self, _bytes: Bytes) -> StreamResult<()> {
+        Err(Self::error())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Ok(TCP_STREAM_WRITE_BUDGET)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
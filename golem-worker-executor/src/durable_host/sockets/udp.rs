@@ -12,10 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::IpAddr;
 use wasmtime::component::Resource;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
 use crate::workerctx::WorkerCtx;
+use golem_common::model::environment::NetworkEgressTarget;
+use golem_common::model::oplog::host_functions::{
+    SocketsUdpIncomingDatagramStreamReceive, SocketsUdpOutgoingDatagramStreamSend,
+};
+use golem_common::model::oplog::types::{
+    SerializableDatagram, SerializableIpSocketAddress, SerializableSocketError,
+};
+use golem_common::model::oplog::{
+    DurableFunctionType, HostRequestSocketsUdpReceive, HostRequestSocketsUdpSend,
+    HostResponseSocketsUdpDatagrams, HostResponseSocketsUdpSendCount,
+};
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use wasmtime_wasi::p2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::p2::bindings::sockets::udp::{
     Host, HostIncomingDatagramStream, HostOutgoingDatagramStream, HostUdpSocket, IncomingDatagram,
     IncomingDatagramStream, IpAddressFamily, IpSocketAddress, Network, OutgoingDatagram,
@@ -53,8 +67,23 @@ impl<Ctx: WorkerCtx> HostUdpSocket for DurableWorkerCtx<Ctx> {
         SocketError,
     > {
         self.observe_function_call("sockets::udp", "stream");
+        let serializable_remote_address = remote_address.map(SerializableIpSocketAddress::from);
         let mut view = self.as_wasi_view();
-        HostUdpSocket::stream(&mut view.sockets(), self_, remote_address).await
+        let (incoming, outgoing) =
+            HostUdpSocket::stream(&mut view.sockets(), self_, remote_address).await?;
+        match serializable_remote_address {
+            Some(remote_address) => {
+                self.state
+                    .udp_stream_remote_addresses
+                    .insert(outgoing.rep(), remote_address);
+            }
+            None => {
+                self.state
+                    .udp_stream_remote_addresses
+                    .remove(&outgoing.rep());
+            }
+        }
+        Ok((incoming, outgoing))
     }
 
     fn local_address(
@@ -137,6 +166,7 @@ impl<Ctx: WorkerCtx> HostIncomingDatagramStream for DurableWorkerCtx<Ctx> {
         self_: Resource<IncomingDatagramStream>,
         max_results: u64,
     ) -> Result<Vec<IncomingDatagram>, SocketError> {
+        // Only used if the linker does not override receive with `receive_durably`
         self.observe_function_call("sockets::udp", "receive");
         HostIncomingDatagramStream::receive(&mut self.as_wasi_view().sockets(), self_, max_results)
     }
@@ -166,9 +196,48 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
         self_: Resource<OutgoingDatagramStream>,
         datagrams: Vec<OutgoingDatagram>,
     ) -> Result<u64, SocketError> {
-        self.observe_function_call("sockets::udp", "send");
-        let mut view = self.as_wasi_view();
-        HostOutgoingDatagramStream::send(&mut view.sockets(), self_, datagrams).await
+        if !self.state.config.sockets.durable {
+            self.observe_function_call("sockets::udp", "send");
+            if !self.is_udp_send_allowed(&self_, &datagrams).await? {
+                return Err(ErrorCode::AccessDenied.into());
+            }
+            let mut view = self.as_wasi_view();
+            return HostOutgoingDatagramStream::send(&mut view.sockets(), self_, datagrams).await;
+        }
+
+        let datagram_count = datagrams.len() as u64;
+        let durability = Durability::<SocketsUdpOutgoingDatagramStreamSend>::new(
+            self,
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let result = if self.is_udp_send_allowed(&self_, &datagrams).await? {
+                let mut view = self.as_wasi_view();
+                let result =
+                    HostOutgoingDatagramStream::send(&mut view.sockets(), self_, datagrams).await;
+                durability
+                    .try_trigger_retry(self, &result)
+                    .await
+                    .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+                result
+            } else {
+                Err(ErrorCode::AccessDenied.into())
+            };
+            durability
+                .persist(
+                    self,
+                    HostRequestSocketsUdpSend { datagram_count },
+                    HostResponseSocketsUdpSendCount {
+                        result: result.map_err(SerializableSocketError::from),
+                    },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+        result.result.map_err(SocketError::from)
     }
 
     fn subscribe(
@@ -181,8 +250,101 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<OutgoingDatagramStream>) -> wasmtime::Result<()> {
         self.observe_function_call("sockets::udp", "drop");
+        self.state.udp_stream_remote_addresses.remove(&rep.rep());
         HostOutgoingDatagramStream::drop(&mut self.as_wasi_view().sockets(), rep)
     }
 }
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Checks the destinations of the datagrams against the network egress policy. Datagrams
+    /// without an explicit remote address are sent to the address the stream was connected to.
+    async fn is_udp_send_allowed(
+        &self,
+        stream: &Resource<OutgoingDatagramStream>,
+        datagrams: &[OutgoingDatagram],
+    ) -> Result<bool, WorkerExecutorError> {
+        for datagram in datagrams {
+            let remote_address = match datagram.remote_address {
+                Some(remote_address) => Some(SerializableIpSocketAddress::from(remote_address)),
+                None => self
+                    .state
+                    .udp_stream_remote_addresses
+                    .get(&stream.rep())
+                    .cloned(),
+            };
+            if let Some(remote_address) = remote_address {
+                let egress_target = NetworkEgressTarget::ip(
+                    IpAddr::from(remote_address.address),
+                    remote_address.port,
+                );
+                if !self.is_network_egress_allowed(egress_target).await? {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Durable version of `incoming-datagram-stream.receive`.
+///
+/// The wasi-sockets binding of `receive` is synchronous, so the linker overrides it with this
+/// function to be able to record the received datagrams in the oplog and return them on replay.
+/// Unless durable sockets are enabled in the configuration, it just forwards to the original.
+pub async fn receive_durably<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    self_: Resource<IncomingDatagramStream>,
+    max_results: u64,
+) -> Result<Vec<IncomingDatagram>, SocketError> {
+    if !ctx.state.config.sockets.durable {
+        ctx.observe_function_call("sockets::udp", "receive");
+        return HostIncomingDatagramStream::receive(
+            &mut ctx.as_wasi_view().sockets(),
+            self_,
+            max_results,
+        );
+    }
+
+    let durability = Durability::<SocketsUdpIncomingDatagramStreamReceive>::new(
+        ctx,
+        DurableFunctionType::ReadRemote,
+    )
+    .await?;
+
+    let result = if durability.is_live() {
+        let result = HostIncomingDatagramStream::receive(
+            &mut ctx.as_wasi_view().sockets(),
+            self_,
+            max_results,
+        );
+        durability
+            .try_trigger_retry(ctx, &result)
+            .await
+            .map_err(|e| SocketError::trap(wasmtime::Error::from_anyhow(e)))?;
+        durability
+            .persist(
+                ctx,
+                HostRequestSocketsUdpReceive { max_results },
+                HostResponseSocketsUdpDatagrams {
+                    result: result
+                        .map(|datagrams| {
+                            datagrams
+                                .into_iter()
+                                .map(SerializableDatagram::from)
+                                .collect()
+                        })
+                        .map_err(SerializableSocketError::from),
+                },
+            )
+            .await
+    } else {
+        durability.replay(ctx).await
+    }?;
+
+    result
+        .result
+        .map(|datagrams| datagrams.into_iter().map(IncomingDatagram::from).collect())
+        .map_err(SocketError::from)
+}
@@ -16,7 +16,9 @@ use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
 use crate::get_oplog_entry;
 use crate::preview2::golem::agent::host::{
     CancellationToken, FutureInvokeResult, HostCancellationToken, HostFutureInvokeResult,
-    HostWasmRpc, RpcError,
+    HostWasmRpc, MissedFirePolicy as WitMissedFirePolicy,
+    RecurringInvocation as WitRecurringInvocation, RecurringSchedule as WitRecurringSchedule,
+    RpcError,
 };
 use crate::services::oplog::{CommitLevel, OplogOps};
 use crate::services::rpc::{RpcDemand, RpcError as InternalRpcError};
@@ -31,18 +33,26 @@ use golem_common::model::agent::wit_naming::ToWitNaming;
 use golem_common::model::agent::UntypedDataValue;
 use golem_common::model::invocation_context::{AttributeValue, InvocationContextSpan, SpanId};
 use golem_common::model::oplog::host_functions::{
-    GolemRpcCancellationTokenCancel, GolemRpcFutureInvokeResultGet, GolemRpcWasmRpcInvoke,
-    GolemRpcWasmRpcInvokeAndAwaitResult, GolemRpcWasmRpcScheduleInvocation,
+    GolemRpcCancellationTokenCancel, GolemRpcFutureInvokeResultGet,
+    GolemRpcWasmRpcCancelRecurringInvocation, GolemRpcWasmRpcInvoke,
+    GolemRpcWasmRpcInvokeAndAwaitResult, GolemRpcWasmRpcListRecurringInvocations,
+    GolemRpcWasmRpcScheduleInvocation, GolemRpcWasmRpcScheduleRecurringInvocation,
 };
 use golem_common::model::oplog::types::{
     SerializableInvokeResult, SerializableScheduledInvocation,
 };
 use golem_common::model::oplog::{
     DurableFunctionType, HostPayloadPair, HostRequest, HostRequestGolemRpcInvoke,
-    HostRequestGolemRpcScheduledInvocation, HostRequestGolemRpcScheduledInvocationCancellation,
-    HostResponse, HostResponseGolemRpcInvokeAndAwait, HostResponseGolemRpcInvokeGet,
-    HostResponseGolemRpcScheduledInvocation, HostResponseGolemRpcUnit,
-    HostResponseGolemRpcUnitOrFailure, OplogEntry, PersistenceLevel,
+    HostRequestGolemRpcRecurringInvocation, HostRequestGolemRpcRecurringInvocationCancellation,
+    HostRequestGolemRpcRecurringInvocationList, HostRequestGolemRpcScheduledInvocation,
+    HostRequestGolemRpcScheduledInvocationCancellation, HostResponse,
+    HostResponseGolemRpcInvokeAndAwait, HostResponseGolemRpcInvokeGet,
+    HostResponseGolemRpcRecurringInvocation, HostResponseGolemRpcRecurringInvocationCanceled,
+    HostResponseGolemRpcRecurringInvocations, HostResponseGolemRpcScheduledInvocation,
+    HostResponseGolemRpcUnit, HostResponseGolemRpcUnitOrFailure, OplogEntry, PersistenceLevel,
+};
+use golem_common::model::recurring_invocation::{
+    MissedFirePolicy, RecurringInvocation, RecurringInvocationId, RecurringSchedule,
 };
 use golem_common::model::{
     AgentInvocation, IdempotencyKey, OplogIndex, OwnedWorkerId, ScheduledAction, WorkerId,
@@ -59,6 +69,8 @@ use wasmtime_wasi::runtime::AbortOnDropJoinHandle;
 
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 
+pub mod saga;
+
 impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
     async fn new(
         &mut self,
@@ -528,6 +540,157 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         Ok(resource)
     }
 
+    async fn schedule_recurring_invocation(
+        &mut self,
+        this: Resource<WasmRpcEntry>,
+        schedule: WitRecurringSchedule,
+        missed_fire_policy: WitMissedFirePolicy,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<Result<WitRecurringInvocation, String>> {
+        let durability = Durability::<GolemRpcWasmRpcScheduleRecurringInvocation>::new(
+            self,
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let remote_worker_id = self.remote_worker_id(&this)?;
+            let input_untyped: UntypedDataValue = input.into();
+            let schedule: RecurringSchedule = schedule.into();
+            let missed_fire_policy: MissedFirePolicy = missed_fire_policy.into();
+
+            let request = HostRequestGolemRpcRecurringInvocation {
+                remote_worker_id: remote_worker_id.worker_id(),
+                method_name: method_name.clone(),
+                input: input_untyped.clone(),
+                schedule: schedule.clone(),
+                missed_fire_policy,
+            };
+
+            let stack = self
+                .state
+                .invocation_context
+                .clone_as_inherited_stack(&self.state.current_span_id);
+
+            let invocation = AgentInvocation::AgentMethod {
+                idempotency_key: IdempotencyKey::fresh(),
+                method_name,
+                input: input_untyped,
+                invocation_context: stack,
+                principal: Principal::anonymous(),
+            };
+
+            let result = self
+                .state
+                .scheduler_service
+                .schedule_recurring(
+                    self.created_by(),
+                    &remote_worker_id,
+                    invocation,
+                    schedule,
+                    missed_fire_policy,
+                )
+                .await
+                .map_err(|err| err.to_string());
+
+            durability
+                .persist(
+                    self,
+                    request,
+                    HostResponseGolemRpcRecurringInvocation { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(result.result.map(|invocation| invocation.into()))
+    }
+
+    async fn list_recurring_invocations(
+        &mut self,
+        this: Resource<WasmRpcEntry>,
+    ) -> anyhow::Result<Vec<WitRecurringInvocation>> {
+        let durability = Durability::<GolemRpcWasmRpcListRecurringInvocations>::new(
+            self,
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let remote_worker_id = self.remote_worker_id(&this)?;
+
+            let result = self
+                .state
+                .scheduler_service
+                .list_recurring(&remote_worker_id)
+                .await
+                .map_err(|err| err.to_string());
+
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemRpcRecurringInvocationList {
+                        remote_worker_id: remote_worker_id.worker_id(),
+                    },
+                    HostResponseGolemRpcRecurringInvocations { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        let invocations = result
+            .result
+            .map_err(|err| anyhow::anyhow!("Failed to list recurring invocations: {err}"))?;
+        Ok(invocations
+            .into_iter()
+            .map(|invocation| invocation.into())
+            .collect())
+    }
+
+    async fn cancel_recurring_invocation(
+        &mut self,
+        this: Resource<WasmRpcEntry>,
+        id: golem_wasm::Uuid,
+    ) -> anyhow::Result<bool> {
+        let durability = Durability::<GolemRpcWasmRpcCancelRecurringInvocation>::new(
+            self,
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let remote_worker_id = self.remote_worker_id(&this)?;
+            let id = RecurringInvocationId(id.into());
+
+            let result = self
+                .state
+                .scheduler_service
+                .cancel_recurring(&remote_worker_id, id)
+                .await
+                .map_err(|err| err.to_string());
+
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemRpcRecurringInvocationCancellation {
+                        remote_worker_id: remote_worker_id.worker_id(),
+                        id,
+                    },
+                    HostResponseGolemRpcRecurringInvocationCanceled { result },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        result
+            .result
+            .map_err(|err| anyhow::anyhow!("Failed to cancel recurring invocation: {err}"))
+    }
+
     async fn drop(&mut self, rep: Resource<WasmRpcEntry>) -> anyhow::Result<()> {
         self.observe_function_call("golem::rpc::wasm-rpc", "drop");
 
@@ -541,6 +704,14 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    fn remote_worker_id(&mut self, this: &Resource<WasmRpcEntry>) -> anyhow::Result<OwnedWorkerId> {
+        let entry = self.table().get(this)?;
+        let payload = entry.payload.downcast_ref::<WasmRpcEntryPayload>().unwrap();
+        Ok(payload.remote_worker_id.clone())
+    }
+}
+
 impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
     async fn subscribe(
         &mut self,
@@ -744,6 +915,24 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                 }
             };
 
+            // When replaying a recorded oplog in a sandbox, the guest continues with the recorded
+            // result instead of the live one
+            let recorded_invoke_result = if self.state.snapshotting_mode.is_none() {
+                match self
+                    .recorded_host_call_response(&GolemRpcFutureInvokeResultGet::HOST_FUNCTION_NAME)
+                    .await
+                {
+                    Some(HostResponse::GolemRpcInvokeGet(recorded)) => Some(recorded.result),
+                    _ => None,
+                }
+            } else {
+                None
+            };
+            let (result, serializable_invoke_result) = match recorded_invoke_result {
+                Some(recorded) => (invoke_result_from_serializable(recorded.clone()), recorded),
+                None => (result, serializable_invoke_result),
+            };
+
             let for_retry = match &result {
                 Err(err) => Err(anyhow::anyhow!(err.to_string())),
                 Ok(Some(Err(err))) => Err(anyhow::anyhow!(err.to_string())),
@@ -845,20 +1034,13 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                 self.finish_span(&span_id).await?;
             }
 
-            match serialized_invoke_result {
-                SerializableInvokeResult::Pending => Ok(None),
-                SerializableInvokeResult::Completed(result) => match result {
-                    Ok(untyped) => {
-                        let data_value: golem_common::model::agent::bindings::golem::agent::common::DataValue = untyped.into();
-                        Ok(Some(Ok(data_value)))
-                    }
-                    Err(error) => {
-                        let rpc_error: InternalRpcError = error.into();
-                        let rpc_error: RpcError = rpc_error.into();
-                        Ok(Some(Err(rpc_error)))
-                    }
-                },
-                SerializableInvokeResult::Failed(error) => Err(anyhow::anyhow!(error)),
+            match invoke_result_from_serializable(serialized_invoke_result)? {
+                Some(Ok(untyped)) => {
+                    let data_value: golem_common::model::agent::bindings::golem::agent::common::DataValue = untyped.into();
+                    Ok(Some(Ok(data_value)))
+                }
+                Some(Err(error)) => Ok(Some(Err(error))),
+                None => Ok(None),
             }
         }
     }
@@ -870,6 +1052,21 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
     }
 }
 
+/// Creates the result of `future-invoke-result::get` from a persisted result
+fn invoke_result_from_serializable(
+    result: SerializableInvokeResult,
+) -> Result<Option<Result<UntypedDataValue, RpcError>>, anyhow::Error> {
+    match result {
+        SerializableInvokeResult::Pending => Ok(None),
+        SerializableInvokeResult::Completed(Ok(untyped)) => Ok(Some(Ok(untyped))),
+        SerializableInvokeResult::Completed(Err(error)) => {
+            let rpc_error: InternalRpcError = error.into();
+            Ok(Some(Err(rpc_error.into())))
+        }
+        SerializableInvokeResult::Failed(error) => Err(anyhow::anyhow!(error)),
+    }
+}
+
 impl<Ctx: WorkerCtx> HostCancellationToken for DurableWorkerCtx<Ctx> {
     async fn cancel(&mut self, this: Resource<CancellationToken>) -> anyhow::Result<()> {
         let entry = self.table().get(&this)?;
@@ -969,6 +1166,64 @@ pub struct WasmRpcEntryPayload {
     pub span_id: SpanId,
 }
 
+impl From<WitRecurringSchedule> for RecurringSchedule {
+    fn from(value: WitRecurringSchedule) -> Self {
+        match value {
+            WitRecurringSchedule::Cron(expression) => RecurringSchedule::cron(expression),
+            WitRecurringSchedule::Interval(interval_millis) => {
+                RecurringSchedule::interval(std::time::Duration::from_millis(interval_millis))
+            }
+        }
+    }
+}
+
+impl From<RecurringSchedule> for WitRecurringSchedule {
+    fn from(value: RecurringSchedule) -> Self {
+        match value {
+            RecurringSchedule::Cron(cron) => WitRecurringSchedule::Cron(cron.expression),
+            RecurringSchedule::Interval(interval) => {
+                WitRecurringSchedule::Interval(interval.interval_millis)
+            }
+        }
+    }
+}
+
+impl From<WitMissedFirePolicy> for MissedFirePolicy {
+    fn from(value: WitMissedFirePolicy) -> Self {
+        match value {
+            WitMissedFirePolicy::Skip => MissedFirePolicy::Skip,
+            WitMissedFirePolicy::RunOnce => MissedFirePolicy::RunOnce,
+            WitMissedFirePolicy::CatchUp => MissedFirePolicy::CatchUp,
+        }
+    }
+}
+
+impl From<MissedFirePolicy> for WitMissedFirePolicy {
+    fn from(value: MissedFirePolicy) -> Self {
+        match value {
+            MissedFirePolicy::Skip => WitMissedFirePolicy::Skip,
+            MissedFirePolicy::RunOnce => WitMissedFirePolicy::RunOnce,
+            MissedFirePolicy::CatchUp => WitMissedFirePolicy::CatchUp,
+        }
+    }
+}
+
+impl From<RecurringInvocation> for WitRecurringInvocation {
+    fn from(value: RecurringInvocation) -> Self {
+        let next_fire_at = value.next_fire_at.to_millis();
+        Self {
+            id: value.id.0.into(),
+            method_name: value.method_name,
+            schedule: value.schedule.into(),
+            missed_fire_policy: value.missed_fire_policy.into(),
+            next_fire_at: wasmtime_wasi::p2::bindings::clocks::wall_clock::Datetime {
+                seconds: next_fire_at / 1000,
+                nanoseconds: ((next_fire_at % 1000) * 1_000_000) as u32,
+            },
+        }
+    }
+}
+
 impl Debug for WasmRpcEntryPayload {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("WasmRpcEntryPayload")
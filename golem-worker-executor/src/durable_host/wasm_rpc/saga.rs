@@ -0,0 +1,237 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
+use crate::get_oplog_entry;
+use crate::preview2::golem::agent::host::{HostSaga, RpcError, Saga, WasmRpc};
+use crate::services::oplog::{CommitLevel, OplogOps};
+use crate::services::rpc::RpcError as InternalRpcError;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::agent::UntypedDataValue;
+use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::oplog::host_functions::GolemRpcSagaAbort;
+use golem_common::model::oplog::{
+    DurableFunctionType, HostRequestGolemRpcSagaAbort, HostResponseGolemRpcUnitOrFailure,
+    OplogEntry, SagaCompensation,
+};
+use golem_common::model::{OplogIndex, OwnedWorkerId};
+use tracing::{debug, error};
+use wasmtime::component::Resource;
+
+/// State of an open saga resource. The recorded steps are kept in order, so they can be
+/// compensated in reverse order when the saga gets aborted.
+pub struct SagaEntry {
+    begin_index: OplogIndex,
+    steps: Vec<(OplogIndex, SagaCompensation)>,
+}
+
+impl<Ctx: WorkerCtx> HostSaga for DurableWorkerCtx<Ctx> {
+    async fn new(&mut self) -> anyhow::Result<Resource<Saga>> {
+        self.observe_function_call("golem::rpc::saga", "new");
+
+        let begin_index = if self.state.is_live() {
+            let begin_index = self.state.oplog.add(OplogEntry::begin_saga()).await;
+            self.state.oplog.commit(CommitLevel::Always).await;
+            begin_index
+        } else {
+            let (begin_index, _) =
+                get_oplog_entry!(self.state.replay_state, OplogEntry::BeginSaga)?;
+            begin_index
+        };
+
+        Ok(self.table().push(SagaEntry {
+            begin_index,
+            steps: Vec::new(),
+        })?)
+    }
+
+    async fn record_step(
+        &mut self,
+        self_: Resource<Saga>,
+        target: Resource<WasmRpc>,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<()> {
+        self.observe_function_call("golem::rpc::saga", "record_step");
+
+        let begin_index = self.table().get(&self_)?.begin_index;
+        let target_worker_id = self.remote_worker_id(&target)?;
+
+        let (step_index, compensation) = if self.state.is_live() {
+            let input: UntypedDataValue = input.into();
+            let input = self
+                .state
+                .oplog
+                .upload_payload(&input)
+                .await
+                .map_err(|err| {
+                    anyhow::anyhow!("Failed to upload saga compensation input: {err}")
+                })?;
+            let compensation = SagaCompensation {
+                target_worker_id: target_worker_id.worker_id(),
+                method_name,
+                input,
+            };
+            let step_index = self
+                .state
+                .oplog
+                .add(OplogEntry::saga_step(begin_index, compensation.clone()))
+                .await;
+            self.state.oplog.commit(CommitLevel::Always).await;
+            (step_index, compensation)
+        } else {
+            let (step_index, entry) =
+                get_oplog_entry!(self.state.replay_state, OplogEntry::SagaStep)?;
+            match entry {
+                OplogEntry::SagaStep { compensation, .. } => (step_index, compensation),
+                _ => unreachable!(),
+            }
+        };
+
+        self.table()
+            .get_mut(&self_)?
+            .steps
+            .push((step_index, compensation));
+        Ok(())
+    }
+
+    async fn complete(&mut self, self_: Resource<Saga>) -> anyhow::Result<()> {
+        self.observe_function_call("golem::rpc::saga", "complete");
+
+        let entry = self.table().get_mut(&self_)?;
+        let begin_index = entry.begin_index;
+        entry.steps.clear();
+
+        if self.state.is_live() {
+            self.state
+                .oplog
+                .add(OplogEntry::end_saga(begin_index, false))
+                .await;
+            self.state.oplog.commit(CommitLevel::Always).await;
+        }
+        Ok(())
+    }
+
+    async fn abort(&mut self, self_: Resource<Saga>) -> anyhow::Result<Result<(), RpcError>> {
+        let begin_index = self.table().get(&self_)?.begin_index;
+
+        let durability =
+            Durability::<GolemRpcSagaAbort>::new(self, DurableFunctionType::WriteRemote).await?;
+
+        let result = if durability.is_live() {
+            let result = self.compensate_saga(&self_).await?;
+            durability.try_trigger_retry(self, &result).await?;
+            durability
+                .persist(
+                    self,
+                    HostRequestGolemRpcSagaAbort { begin_index },
+                    HostResponseGolemRpcUnitOrFailure {
+                        result: result.map_err(|err| err.into()),
+                    },
+                )
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        match result.result {
+            Ok(()) => {
+                self.table().get_mut(&self_)?.steps.clear();
+                Ok(Ok(()))
+            }
+            Err(err) => {
+                // When replaying a failed abort all the steps are kept, so a retried abort invokes
+                // the already performed compensations again, with the same idempotency keys
+                let rpc_error: InternalRpcError = err.into();
+                error!("Saga compensation failed: {rpc_error}");
+                Ok(Err(rpc_error.into()))
+            }
+        }
+    }
+
+    async fn drop(&mut self, rep: Resource<Saga>) -> anyhow::Result<()> {
+        self.observe_function_call("golem::rpc::saga", "drop");
+        let _ = self.table().delete(rep)?;
+        Ok(())
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Invokes the compensations of the saga's remaining steps in reverse order, recording each
+    /// performed compensation in the oplog. Stops at the first failing compensation.
+    async fn compensate_saga(
+        &mut self,
+        saga: &Resource<Saga>,
+    ) -> anyhow::Result<Result<(), InternalRpcError>> {
+        let mut env =
+            wasmtime_wasi::p2::bindings::cli::environment::Host::get_environment(self).await?;
+        crate::model::WorkerConfig::remove_dynamic_vars(&mut env);
+        let config_vars = self.state.config_vars.clone();
+        let worker_id = self.worker_id().clone();
+        let environment_id = self.owned_worker_id().environment_id;
+        let created_by = self.created_by();
+        let stack = InvocationContextStack::fresh();
+
+        let begin_index = self.table().get(saga)?.begin_index;
+
+        while let Some((step_index, compensation)) = self.table().get(saga)?.steps.last().cloned() {
+            let input = self
+                .state
+                .oplog
+                .download_payload(compensation.input)
+                .await
+                .map_err(|err| {
+                    anyhow::anyhow!("Failed to download saga compensation input: {err}")
+                })?;
+
+            debug!(
+                "Compensating saga step {step_index} by invoking {} on {}",
+                compensation.method_name, compensation.target_worker_id
+            );
+
+            let target = OwnedWorkerId::new(environment_id, &compensation.target_worker_id);
+            let result = self
+                .rpc()
+                .invoke_and_await(
+                    &target,
+                    Some(SagaCompensation::idempotency_key(&worker_id, step_index)),
+                    compensation.method_name,
+                    input,
+                    created_by,
+                    &worker_id,
+                    &env,
+                    config_vars.clone(),
+                    stack.clone(),
+                )
+                .await;
+
+            if let Err(err) = result {
+                return Ok(Err(err));
+            }
+
+            self.state
+                .oplog
+                .add(OplogEntry::saga_step_compensated(begin_index, step_index))
+                .await;
+            self.table().get_mut(saga)?.steps.pop();
+        }
+
+        self.state
+            .oplog
+            .add(OplogEntry::end_saga(begin_index, true))
+            .await;
+        self.state.oplog.commit(CommitLevel::Always).await;
+        Ok(Ok(()))
+    }
+}
@@ -17,17 +17,20 @@ mod invocation;
 use crate::grpc::invocation::{from_proto_invocation_context, CanStartWorker};
 use crate::model::event::InternalWorkerEvent;
 use crate::model::public_oplog::{
-    find_component_revision_at, get_public_oplog_chunk, search_public_oplog,
+    find_component_revision_at, get_invocation_queue, get_public_oplog_chunk, search_public_oplog,
 };
 use crate::model::{LastError, ReadFileResult};
+use crate::services::active_workers::ActiveWorkers;
+use crate::services::component::ComponentService;
 use crate::services::events::Event;
+use crate::services::oplog::OplogService;
 use crate::services::worker_activator::{
     DefaultWorkerActivator, LazyWorkerActivator, WorkerActivator,
 };
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
     All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService,
-    HasPromiseService, HasRunningWorkerEnumerationService, HasSchedulerService,
+    HasPromiseService, HasPubSubService, HasRunningWorkerEnumerationService, HasSchedulerService,
     HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService,
     UsesAllDeps,
 };
@@ -42,22 +45,36 @@ use golem_api_grpc::proto::golem::worker::{Cursor, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
-    CancelInvocationResponse, ConnectWorkerRequest, DeactivatePluginRequest,
-    DeactivatePluginResponse, DeleteWorkerRequest, ForkWorkerRequest, ForkWorkerResponse,
+    CancelInvocationResponse, CancelRecurringInvocationRequest, CancelRecurringInvocationResponse,
+    ConnectWorkerRequest, DeactivatePluginRequest, DeactivatePluginResponse, DeleteWorkerRequest,
+    FollowOplogRequest, FollowOplogResponse, ForkWorkerRequest, ForkWorkerResponse,
     GetFileContentsRequest, GetFileContentsResponse, GetFileSystemNodeRequest,
-    GetFileSystemNodeResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
+    GetFileSystemNodeResponse, GetInvocationQueueRequest, GetInvocationQueueResponse,
+    GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
     GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
-    InvokeAgentRequest, InvokeAgentResponse, RevertWorkerRequest, RevertWorkerResponse,
-    SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    InvokeAgentRequest, InvokeAgentResponse, ListRecurringInvocationsRequest,
+    ListRecurringInvocationsResponse, ListRecurringInvocationsSuccessResponse,
+    PublishTopicMessageRequest, PublishTopicMessageResponse, PurgeInvocationsRequest,
+    PurgeInvocationsResponse, ReorderInvocationRequest, ReorderInvocationResponse,
+    RevertWorkerRequest, RevertWorkerResponse, ScheduleRecurringInvocationRequest,
+    ScheduleRecurringInvocationResponse, SearchOplogRequest, SearchOplogResponse,
+    SetInvocationQueueLimitRequest, SetInvocationQueueLimitResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
 use golem_common::model::account::AccountId;
-use golem_common::model::agent::{AgentId, AgentMode, Principal, UntypedDataValue};
-use golem_common::model::component::{ComponentFilePath, ComponentId, PluginPriority};
+use golem_common::model::agent::{AgentId, AgentMode, AgentTypeName, Principal, UntypedDataValue};
+use golem_common::model::component::{
+    ComponentFilePath, ComponentId, ComponentRevision, PluginPriority,
+};
 use golem_common::model::environment::EnvironmentId;
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::invocation_queue::{InvocationPriority, InvocationQueue};
 use golem_common::model::oplog::{OplogIndex, UpdateDescription};
 use golem_common::model::protobuf::to_protobuf_resource_description;
+use golem_common::model::recurring_invocation::{
+    MissedFirePolicy, RecurringInvocation, RecurringInvocationId, RecurringSchedule,
+};
 use golem_common::model::worker::WorkerCreationLocalAgentConfigEntry;
 use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, AgentInvocationResult, IdempotencyKey, OwnedWorkerId,
@@ -72,12 +89,13 @@ use golem_service_base::grpc::{
 };
 use golem_service_base::model::auth::AuthCtx;
 use golem_service_base::model::GetFileSystemNodeResult;
-use std::cmp::min;
+use std::cmp::{max, min};
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio;
 use tokio::sync::broadcast::error::RecvError;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
@@ -325,7 +343,11 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
 
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let data = request.data;
+        let outcome = if request.failed {
+            common_model::PromiseOutcome::Failed(request.data)
+        } else {
+            common_model::PromiseOutcome::Completed(request.data)
+        };
 
         let promise_id: common_model::PromiseId = promise_id
             .clone()
@@ -334,7 +356,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
 
         let completed = self
             .promise_service()
-            .complete(promise_id, data, auth_ctx.account_id())
+            .complete(promise_id, outcome, auth_ctx.account_id())
             .await?;
 
         let success = golem::workerexecutor::v1::CompletePromiseSuccess { completed };
@@ -510,6 +532,266 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn schedule_recurring_invocation_internal(
+        &self,
+        request: ScheduleRecurringInvocationRequest,
+    ) -> Result<RecurringInvocation, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let account_id: AccountId = request
+            .component_owner_account_id
+            .ok_or(WorkerExecutorError::invalid_request("account_id not found"))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!("Invalid account id: {e}"))
+            })?;
+
+        let method_parameters: UntypedDataValue = request
+            .method_parameters
+            .clone()
+            .ok_or(WorkerExecutorError::invalid_request(
+                "method_parameters not found",
+            ))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!(
+                    "failed converting method_parameters: {e}"
+                ))
+            })?;
+
+        let schedule: RecurringSchedule = request
+            .schedule
+            .clone()
+            .ok_or(WorkerExecutorError::invalid_request("schedule not found"))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!("failed converting schedule: {e}"))
+            })?;
+
+        let missed_fire_policy: MissedFirePolicy = request.missed_fire_policy().into();
+
+        if self.worker_service().get(&owned_worker_id).await.is_none() {
+            return Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ));
+        }
+
+        let invocation = AgentInvocation::AgentMethod {
+            // Replaced by a key derived from the occurrence on each invocation
+            idempotency_key: IdempotencyKey::fresh(),
+            method_name: request.method_name.clone(),
+            input: method_parameters,
+            invocation_context: InvocationContextStack::fresh(),
+            principal: extract_principal(&request.principal),
+        };
+
+        self.scheduler_service()
+            .schedule_recurring(
+                account_id,
+                &owned_worker_id,
+                invocation,
+                schedule,
+                missed_fire_policy,
+            )
+            .await
+    }
+
+    async fn list_recurring_invocations_internal(
+        &self,
+        request: ListRecurringInvocationsRequest,
+    ) -> Result<Vec<RecurringInvocation>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        self.scheduler_service()
+            .list_recurring(&owned_worker_id)
+            .await
+    }
+
+    async fn cancel_recurring_invocation_internal(
+        &self,
+        request: CancelRecurringInvocationRequest,
+    ) -> Result<bool, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let id: RecurringInvocationId = request
+            .id
+            .ok_or(WorkerExecutorError::invalid_request("id not found"))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!(
+                    "Invalid recurring invocation id: {e}"
+                ))
+            })?;
+
+        self.scheduler_service()
+            .cancel_recurring(&owned_worker_id, id)
+            .await
+    }
+
+    async fn get_invocation_queue_internal(
+        &self,
+        request: GetInvocationQueueRequest,
+    ) -> Result<InvocationQueue, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        self.current_invocation_queue(&owned_worker_id).await
+    }
+
+    async fn reorder_invocation_internal(
+        &self,
+        request: ReorderInvocationRequest,
+    ) -> Result<InvocationQueue, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let priority = request
+            .priority
+            .is_some()
+            .then(|| InvocationPriority::from(request.priority()));
+
+        let idempotency_key = request
+            .idempotency_key
+            .clone()
+            .ok_or(WorkerExecutorError::invalid_request(
+                "idempotency_key not found",
+            ))?
+            .into();
+
+        let worker = self
+            .get_suspended_worker_for_queue(&owned_worker_id, &request.auth_ctx, &request.principal)
+            .await?;
+        worker
+            .reorder_invocation(idempotency_key, priority, request.position)
+            .await?;
+
+        self.current_invocation_queue(&owned_worker_id).await
+    }
+
+    async fn purge_invocations_internal(
+        &self,
+        request: PurgeInvocationsRequest,
+    ) -> Result<u64, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let worker = self
+            .get_suspended_worker_for_queue(&owned_worker_id, &request.auth_ctx, &request.principal)
+            .await?;
+        worker.purge_invocations(&request.method_name).await
+    }
+
+    async fn set_invocation_queue_limit_internal(
+        &self,
+        request: SetInvocationQueueLimitRequest,
+    ) -> Result<InvocationQueue, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let worker = self
+            .get_suspended_worker_for_queue(&owned_worker_id, &request.auth_ctx, &request.principal)
+            .await?;
+        worker.set_invocation_queue_limit(request.limit).await?;
+
+        self.current_invocation_queue(&owned_worker_id).await
+    }
+
+    async fn publish_topic_message_internal(
+        &self,
+        request: PublishTopicMessageRequest,
+    ) -> Result<u64, WorkerExecutorError> {
+        let environment_id: EnvironmentId = request
+            .environment_id
+            .ok_or(WorkerExecutorError::invalid_request(
+                "environment_id not found",
+            ))?
+            .try_into()
+            .map_err(WorkerExecutorError::invalid_request)?;
+
+        let message: UntypedDataValue = request
+            .message
+            .ok_or(WorkerExecutorError::invalid_request("message not found"))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!("failed converting message: {e}"))
+            })?;
+
+        self.pubsub_service()
+            .publish(environment_id, &request.topic, message)
+            .await
+    }
+
+    async fn get_suspended_worker_for_queue(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        auth_ctx: &Option<golem::auth::AuthCtx>,
+        principal: &Option<golem::component::Principal>,
+    ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError> {
+        let auth_ctx: AuthCtx = auth_ctx
+            .clone()
+            .ok_or(WorkerExecutorError::invalid_request("auth_ctx not found"))?
+            .try_into()
+            .map_err(|e| {
+                WorkerExecutorError::invalid_request(format!("failed converting auth_ctx: {e}"))
+            })?;
+
+        Worker::<Ctx>::get_latest_metadata(&self.services, owned_worker_id)
+            .await
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
+
+        Worker::get_or_create_suspended(
+            self,
+            auth_ctx.account_id(),
+            owned_worker_id,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+            &InvocationContextStack::fresh(),
+            extract_principal(principal),
+        )
+        .await
+    }
+
+    async fn current_invocation_queue(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<InvocationQueue, WorkerExecutorError> {
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, owned_worker_id)
+            .await
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
+
+        get_invocation_queue(
+            self.component_service(),
+            owned_worker_id,
+            &metadata.last_known_status,
+        )
+        .await
+        .map_err(WorkerExecutorError::runtime)
+    }
+
     async fn cancel_invocation_internal(
         &self,
         request: CancelInvocationRequest,
@@ -1048,6 +1330,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                     ));
                 }
 
+                if metadata.last_known_status.discarded_region.is_some() {
+                    return Err(WorkerExecutorError::invalid_request(
+                        "Automatic update is not possible because the oplog history has been discarded by the retention policy",
+                    ));
+                }
+
                 match &metadata.last_known_status.status {
                     WorkerStatus::Exited => {
                         warn!("Attempted updating worker which already exited")
@@ -1389,6 +1677,76 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         })
     }
 
+    async fn follow_oplog_internal(
+        &self,
+        request: FollowOplogRequest,
+    ) -> Result<<Self as WorkerExecutor>::FollowOplogStream, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.environment_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        Worker::<Ctx>::get_latest_metadata(self, &owned_worker_id)
+            .await
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
+
+        let agent_type_name =
+            AgentId::parse_agent_type_name(&owned_worker_id.worker_id.worker_name).ok();
+
+        let start = max(
+            OplogIndex::from_u64(request.from_oplog_index),
+            OplogIndex::INITIAL,
+        );
+        let current_component_revision =
+            find_component_revision_at(self.oplog_service(), &owned_worker_id, start).await?;
+
+        let follower = OplogFollower {
+            owned_worker_id,
+            agent_type_name,
+            component_service: self.component_service(),
+            oplog_service: self.oplog_service(),
+            active_workers: self.active_workers(),
+            next_oplog_index: start,
+            current_component_revision,
+            chunk_size: self.services.config().limits.max_oplog_query_pages_size,
+            commits: None,
+            started: false,
+        };
+
+        info!("Oplog follower connected");
+        record_new_grpc_api_active_stream();
+
+        Ok(Box::pin(futures::stream::unfold(
+            Some(follower),
+            |follower| async move {
+                let mut follower = follower?;
+                match follower.next_batch().await {
+                    Ok(success) => Some((
+                        Ok(FollowOplogResponse {
+                            result: Some(
+                                golem::workerexecutor::v1::follow_oplog_response::Result::Success(
+                                    success,
+                                ),
+                            ),
+                        }),
+                        Some(follower),
+                    )),
+                    Err(err) => Some((
+                        Ok(FollowOplogResponse {
+                            result: Some(
+                                golem::workerexecutor::v1::follow_oplog_response::Result::Failure(
+                                    err.into(),
+                                ),
+                            ),
+                        }),
+                        None,
+                    )),
+                }
+            },
+        )))
+    }
+
     async fn get_file_system_node_internal(
         &self,
         request: GetFileSystemNodeRequest,
@@ -1705,6 +2063,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
 
         let ik = idempotency_key.unwrap_or(IdempotencyKey::fresh());
         let invocation_context = from_proto_invocation_context(&request.context);
+        let priority: InvocationPriority = request.priority().into();
 
         let invocation = AgentInvocation::AgentMethod {
             idempotency_key: ik,
@@ -1717,7 +2076,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         match mode {
             golem_api_grpc::proto::golem::workerexecutor::v1::AgentInvocationMode::Await => {
                 let worker = self.get_or_create(&request).await?;
-                let invocation_output = worker.invoke_and_await(invocation).await?;
+                let invocation_output = worker.invoke_and_await(invocation, priority).await?;
                 Ok(Some(invocation_output))
             }
             golem_api_grpc::proto::golem::workerexecutor::v1::AgentInvocationMode::Schedule => {
@@ -1737,7 +2096,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                     }
                     None => {
                         let worker = self.get_or_create(&request).await?;
-                        worker.invoke(invocation).await?;
+                        worker.invoke(invocation, priority).await?;
                         Ok(None)
                     }
                 }
@@ -1844,6 +2203,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 .into_regions()
                 .map(|region| region.into())
                 .collect(),
+            discarded_region: latest_status.discarded_region.map(|region| region.into()),
         })
     }
 }
@@ -2370,6 +2730,45 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    type FollowOplogStream =
+        Pin<Box<dyn Stream<Item = Result<FollowOplogResponse, Status>> + Send + 'static>>;
+
+    async fn follow_oplog(
+        &self,
+        request: Request<FollowOplogRequest>,
+    ) -> ResponseResult<Self::FollowOplogStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "follow_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            from_oplog_index = request.from_oplog_index,
+        );
+
+        let result = self
+            .follow_oplog_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        let stream: Self::FollowOplogStream = match result {
+            Ok(stream) => record.succeed(stream),
+            Err(mut err) => {
+                let res = FollowOplogResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::follow_oplog_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                };
+
+                let err_stream: Self::FollowOplogStream =
+                    Box::pin(tokio_stream::iter(vec![Ok(res)]));
+
+                record.fail(err_stream, &mut err)
+            }
+        };
+        Ok(Response::new(stream))
+    }
+
     async fn fork_worker(
         &self,
         request: Request<ForkWorkerRequest>,
@@ -2483,6 +2882,306 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn schedule_recurring_invocation(
+        &self,
+        request: Request<ScheduleRecurringInvocationRequest>,
+    ) -> ResponseResult<ScheduleRecurringInvocationResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "schedule_recurring_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            method_name = request.method_name,
+        );
+
+        let result = self
+            .schedule_recurring_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(invocation) => record.succeed(Ok(Response::new(
+                ScheduleRecurringInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::schedule_recurring_invocation_response::Result::Success(
+                            invocation.into(),
+                        ),
+                    ),
+                },
+            ))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(ScheduleRecurringInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::schedule_recurring_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn list_recurring_invocations(
+        &self,
+        request: Request<ListRecurringInvocationsRequest>,
+    ) -> ResponseResult<ListRecurringInvocationsResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "list_recurring_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .list_recurring_invocations_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(invocations) => record.succeed(Ok(Response::new(
+                ListRecurringInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::list_recurring_invocations_response::Result::Success(
+                            ListRecurringInvocationsSuccessResponse {
+                                invocations: invocations.into_iter().map(|i| i.into()).collect(),
+                            },
+                        ),
+                    ),
+                },
+            ))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(ListRecurringInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::list_recurring_invocations_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn cancel_recurring_invocation(
+        &self,
+        request: Request<CancelRecurringInvocationRequest>,
+    ) -> ResponseResult<CancelRecurringInvocationResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "cancel_recurring_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .cancel_recurring_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(canceled) => record.succeed(Ok(Response::new(
+                CancelRecurringInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_recurring_invocation_response::Result::Success(
+                            canceled,
+                        ),
+                    ),
+                },
+            ))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(CancelRecurringInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_recurring_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn get_invocation_queue(
+        &self,
+        request: Request<GetInvocationQueueRequest>,
+    ) -> ResponseResult<GetInvocationQueueResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "get_invocation_queue",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_invocation_queue_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(GetInvocationQueueResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_invocation_queue_response::Result::Success(
+                        result.into(),
+                    ),
+                ),
+            }))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(GetInvocationQueueResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_invocation_queue_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn reorder_invocation(
+        &self,
+        request: Request<ReorderInvocationRequest>,
+    ) -> ResponseResult<ReorderInvocationResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "reorder_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let result = self
+            .reorder_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(ReorderInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::reorder_invocation_response::Result::Success(
+                        result.into(),
+                    ),
+                ),
+            }))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(ReorderInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::reorder_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn purge_invocations(
+        &self,
+        request: Request<PurgeInvocationsRequest>,
+    ) -> ResponseResult<PurgeInvocationsResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "purge_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            method_name = request.method_name,
+        );
+
+        let result = self
+            .purge_invocations_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(PurgeInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::purge_invocations_response::Result::Success(result),
+                ),
+            }))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(PurgeInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::purge_invocations_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn set_invocation_queue_limit(
+        &self,
+        request: Request<SetInvocationQueueLimitRequest>,
+    ) -> ResponseResult<SetInvocationQueueLimitResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "set_invocation_queue_limit",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .set_invocation_queue_limit_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(SetInvocationQueueLimitResponse {
+                result: Some(
+                    golem::workerexecutor::v1::set_invocation_queue_limit_response::Result::Success(result.into()),
+                ),
+            }))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(SetInvocationQueueLimitResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::set_invocation_queue_limit_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
+    async fn publish_topic_message(
+        &self,
+        request: Request<PublishTopicMessageRequest>,
+    ) -> ResponseResult<PublishTopicMessageResponse> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!("publish_topic_message", topic = request.topic,);
+
+        let result = self
+            .publish_topic_message_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(offset) => record.succeed(Ok(Response::new(PublishTopicMessageResponse {
+                result: Some(
+                    golem::workerexecutor::v1::publish_topic_message_response::Result::Success(
+                        offset,
+                    ),
+                ),
+            }))),
+            Err(mut err) => record.fail(
+                Ok(Response::new(PublishTopicMessageResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::publish_topic_message_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &mut err,
+            ),
+        }
+    }
+
     async fn get_file_system_node(
         &self,
         request: Request<GetFileSystemNodeRequest>,
@@ -2719,6 +3418,100 @@ impl Stream for WorkerEventStream {
     }
 }
 
+/// State of a `FollowOplog` stream.
+///
+/// The first batch is returned immediately, even if it is empty, so clients can tell that the
+/// stream has been established. After that, new entries are read whenever the worker reports an
+/// oplog commit. As the worker may be
+/// unloaded and reloaded while being followed, the oplog is also polled periodically, and the
+/// commit notifications are resubscribed to whenever the worker is found in memory again.
+struct OplogFollower<Ctx: WorkerCtx> {
+    owned_worker_id: OwnedWorkerId,
+    agent_type_name: Option<AgentTypeName>,
+    component_service: Arc<dyn ComponentService>,
+    oplog_service: Arc<dyn OplogService>,
+    active_workers: Arc<ActiveWorkers<Ctx>>,
+    next_oplog_index: OplogIndex,
+    current_component_revision: ComponentRevision,
+    chunk_size: usize,
+    commits: Option<tokio::sync::watch::Receiver<OplogIndex>>,
+    started: bool,
+}
+
+impl<Ctx: WorkerCtx> OplogFollower<Ctx> {
+    const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Waits until there are committed entries after the last returned one, and returns them
+    async fn next_batch(
+        &mut self,
+    ) -> Result<golem::workerexecutor::v1::FollowOplogSuccessResponse, WorkerExecutorError> {
+        loop {
+            // Subscribing before reading, so commits happening while reading are not missed
+            if self.commits.is_none() {
+                self.commits = self
+                    .active_workers
+                    .try_get(&self.owned_worker_id)
+                    .await
+                    .map(|worker| worker.oplog_commits());
+            }
+
+            let chunk = get_public_oplog_chunk(
+                self.component_service.clone(),
+                self.oplog_service.clone(),
+                &self.owned_worker_id,
+                self.agent_type_name.as_ref(),
+                self.current_component_revision,
+                self.next_oplog_index,
+                self.chunk_size,
+            )
+            .await
+            .map_err(WorkerExecutorError::unknown)?;
+
+            if !chunk.entries.is_empty() || !self.started {
+                self.started = true;
+                self.next_oplog_index = chunk.next_oplog_index;
+                self.current_component_revision = chunk.current_component_revision;
+
+                let first_index = u64::from(chunk.first_index_in_chunk);
+                let entries = chunk
+                    .entries
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, entry)| {
+                        entry.try_into().map(|entry: golem::worker::OplogEntry| {
+                            golem::worker::OplogEntryWithIndex {
+                                oplog_index: first_index + idx as u64,
+                                entry: Some(entry),
+                            }
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(WorkerExecutorError::unknown)?;
+
+                break Ok(golem::workerexecutor::v1::FollowOplogSuccessResponse {
+                    entries,
+                    last_index: chunk.last_index.into(),
+                });
+            }
+
+            match &mut self.commits {
+                Some(commits) => {
+                    tokio::select! {
+                        result = commits.changed() => {
+                            if result.is_err() {
+                                // The followed worker instance got dropped
+                                self.commits = None;
+                            }
+                        }
+                        _ = tokio::time::sleep(Self::POLL_INTERVAL) => {}
+                    }
+                }
+                None => tokio::time::sleep(Self::POLL_INTERVAL).await,
+            }
+        }
+    }
+}
+
 fn extract_principal(
     proto_principal: &Option<golem_api_grpc::proto::golem::component::Principal>,
 ) -> Principal {
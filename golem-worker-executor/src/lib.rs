@@ -30,10 +30,18 @@ test_r::enable!();
 
 use self::services::agent_deployments::{AgentDeploymentsService, GrpcAgentDeploymentService};
 use self::services::agent_webhooks::AgentWebhooksService;
-use self::services::golem_config::AgentDeploymentsServiceConfig;
+use self::services::golem_config::{
+    AgentDeploymentsServiceConfig, NetworkEgressPolicyServiceConfig,
+};
+use self::services::network_egress_policy::{
+    GrpcNetworkEgressPolicyService, NetworkEgressPolicyService,
+};
 use self::services::promise::LazyPromiseService;
 use crate::grpc::WorkerExecutorImpl;
 use crate::services::active_workers::ActiveWorkers;
+use crate::services::agent_lifecycle_events::{
+    AgentLifecycleEventService, AgentLifecycleEventServiceDefault,
+};
 use crate::services::agent_types::AgentTypesService;
 use crate::services::blob_store::{BlobStoreService, DefaultBlobStoreService};
 use crate::services::component::ComponentService;
@@ -49,9 +57,11 @@ use crate::services::oplog::{
     BlobOplogArchiveService, CompressedOplogArchiveService, MultiLayerOplogService,
     OplogArchiveService, OplogService, PrimaryOplogService,
 };
+use crate::services::oplog_retention::start_oplog_retention;
 use crate::services::promise::{DefaultPromiseService, DefaultPromiseWorkerAccess, PromiseService};
+use crate::services::pubsub::{PubSubService, PubSubServiceDefault};
 use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
-use crate::services::shard::{ShardService, ShardServiceDefault};
+use crate::services::shard::{ShardService, ShardServiceDefault, ShardServiceFaultInjecting};
 use crate::services::shard_manager::ShardManagerService;
 use crate::services::worker::{DefaultWorkerService, WorkerService};
 use crate::services::worker_activator::{LazyWorkerActivator, WorkerActivator};
@@ -64,10 +74,12 @@ use crate::services::{
     rdbms, shard_manager, All, HasActiveWorkers, HasComponentService, HasConfig, HasOplogService,
     HasWorkerActivator, HasWorkerService,
 };
+use crate::storage::indexed::fault_injection::FaultInjectingIndexedStorage;
 use crate::storage::indexed::multi_sqlite::MultiSqliteIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
 use crate::storage::indexed::sqlite::SqliteIndexedStorage;
 use crate::storage::indexed::IndexedStorage;
+use crate::storage::keyvalue::fault_injection::FaultInjectingKeyValueStorage;
 use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
 use crate::storage::keyvalue::multi_sqlite::MultiSqliteKeyValueStorage;
 use crate::storage::keyvalue::redis::RedisKeyValueStorage;
@@ -82,8 +94,10 @@ use golem_common::redis::RedisPool;
 use golem_service_base::clients::registry::{GrpcRegistryService, RegistryService};
 use golem_service_base::config::BlobStorageConfig;
 use golem_service_base::db::sqlite::SqlitePool;
+use golem_service_base::fault_injection::FaultInjector;
 use golem_service_base::grpc::server::GrpcServerTlsConfig;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
+use golem_service_base::storage::blob::fault_injection::FaultInjectingBlobStorage;
 use golem_service_base::storage::blob::s3::S3BlobStorage;
 use golem_service_base::storage::blob::sqlite::SqliteBlobStorage;
 use golem_service_base::storage::blob::BlobStorage;
@@ -153,6 +167,19 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         ))
     }
 
+    fn create_network_egress_policy_service(
+        &self,
+        config: &NetworkEgressPolicyServiceConfig,
+        registry_service: Arc<dyn RegistryService>,
+    ) -> Arc<dyn NetworkEgressPolicyService> {
+        Arc::new(GrpcNetworkEgressPolicyService::new(
+            registry_service,
+            config.cache_capacity,
+            config.cache_ttl,
+            config.cache_eviction_interval,
+        ))
+    }
+
     async fn run_grpc_server(
         &self,
         service_dependencies: All<Ctx>,
@@ -242,12 +269,15 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_activator: Arc<dyn WorkerActivator<Ctx>>,
         oplog_service: Arc<dyn OplogService>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn AgentLifecycleEventService>,
         worker_proxy: Arc<dyn WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         agent_type_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         registry_service: Arc<dyn RegistryService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         leak_sentinel: Arc<()>,
@@ -464,6 +494,12 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         }
     };
 
+    let fault_injector =
+        FaultInjector::configured(&golem_config.fault_injection).map_err(|err| anyhow!(err))?;
+    let key_value_storage = FaultInjectingKeyValueStorage::wrap(key_value_storage, &fault_injector);
+    let indexed_storage = FaultInjectingIndexedStorage::wrap(indexed_storage, &fault_injector);
+    let blob_storage = FaultInjectingBlobStorage::wrap(blob_storage, &fault_injector);
+
     let initial_files_service = Arc::new(InitialComponentFilesService::new(blob_storage.clone()));
 
     let file_loader = Arc::new(FileLoader::new(initial_files_service.clone())?);
@@ -489,6 +525,11 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         golem_config.agent_webhooks_service.hmac_key.0.clone(),
     ));
 
+    let network_egress_policy_service = bootstrap.create_network_egress_policy_service(
+        &golem_config.network_egress_policy_service,
+        registry_service.clone(),
+    );
+
     let agent_type_service = services::agent_types::configured(
         &golem_config.agent_types_service,
         component_service.clone(),
@@ -497,7 +538,8 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
 
     let golem_config = Arc::new(golem_config.clone());
 
-    let shard_service = Arc::new(ShardServiceDefault::new());
+    let shard_service =
+        ShardServiceFaultInjecting::wrap(Arc::new(ShardServiceDefault::new()), &fault_injector);
 
     let mut oplog_archives: Vec<Arc<dyn OplogArchiveService>> = Vec::new();
     for idx in 1..golem_config.oplog.indexed_storage_layers {
@@ -552,7 +594,8 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         active_workers.clone(),
     ));
 
-    let shard_manager_service = shard_manager::configured(&golem_config.shard_manager_service);
+    let shard_manager_service =
+        shard_manager::configured(&golem_config.shard_manager_service, &fault_injector);
 
     let config = bootstrap.create_wasmtime_config(&golem_config.engine);
     let engine = Arc::new(Engine::new(&config)?);
@@ -624,6 +667,30 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         shutdown_token.clone(),
     );
 
+    let pubsub_service = PubSubServiceDefault::new(
+        key_value_storage.clone(),
+        shard_service.clone(),
+        Arc::new(lazy_worker_activator.clone() as Arc<dyn WorkerActivator<Ctx>>),
+        worker_service.clone(),
+        golem_config.pubsub.clone(),
+        shutdown_token.clone(),
+    );
+
+    start_oplog_retention(
+        &active_workers,
+        worker_service.clone(),
+        lazy_worker_activator.clone(),
+        golem_config.oplog.retention.clone(),
+        shutdown_token.clone(),
+    );
+
+    let agent_lifecycle_event_service = AgentLifecycleEventServiceDefault::new(
+        key_value_storage.clone(),
+        registry_service.clone(),
+        golem_config.agent_lifecycle_events.clone(),
+        shutdown_token.clone(),
+    );
+
     let leak_sentinel = Arc::new(());
 
     let all = bootstrap
@@ -646,12 +713,15 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
             lazy_worker_activator.clone(),
             oplog_service,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_proxy,
             events,
             file_loader,
             oplog_processor_plugin,
             agent_type_service,
             agent_webhooks_service,
+            network_egress_policy_service,
             registry_service,
             shutdown_token,
             leak_sentinel,
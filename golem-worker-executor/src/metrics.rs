@@ -138,6 +138,8 @@ pub mod promises {
             "Number of scheduled promise completions"
         )
         .unwrap();
+        static ref PROMISES_EXPIRED_TOTAL: Counter =
+            register_counter!("promises_expired_total", "Number of expired promises").unwrap();
     }
 
     pub fn record_promise_created() {
@@ -147,6 +149,10 @@ pub mod promises {
     pub fn record_scheduled_promise_completed() {
         PROMISES_SCHEDULED_COMPLETE_TOTAL.inc();
     }
+
+    pub fn record_promise_expired() {
+        PROMISES_EXPIRED_TOTAL.inc();
+    }
 }
 
 pub mod sharding {
@@ -285,6 +291,15 @@ pub mod oplog {
             golem_common::metrics::DEFAULT_TIME_BUCKETS.to_vec()
         )
         .unwrap();
+        static ref OPLOG_RETENTION_DROPPED_ENTRIES_TOTAL: Counter = register_counter!(
+            "oplog_retention_dropped_entries_total",
+            "Number of oplog entries permanently discarded by the oplog retention policy"
+        )
+        .unwrap();
+    }
+
+    pub fn record_oplog_retention_dropped_entries(count: u64) {
+        OPLOG_RETENTION_DROPPED_ENTRIES_TOTAL.inc_by(count as f64);
     }
 
     pub fn record_oplog_call(api_name: &'static str) {
@@ -311,6 +326,12 @@ pub mod resources {
             register_counter!("fuel_borrow_total", "Total amount of fuel borrowed").unwrap();
         static ref FUEL_RETURN_TOTAL: Counter =
             register_counter!("fuel_return_total", "Total amount of fuel returned").unwrap();
+        static ref AGENT_RESOURCE_LIMIT_EXCEEDED_TOTAL: CounterVec = register_counter_vec!(
+            "agent_resource_limit_exceeded_total",
+            "Number of times agents exceeded a resource limit of their agent type",
+            &["agent_type", "limit"]
+        )
+        .unwrap();
     }
 
     pub fn record_fuel_borrow(amount: u64) {
@@ -320,4 +341,155 @@ pub mod resources {
     pub fn record_fuel_return(amount: u64) {
         FUEL_RETURN_TOTAL.inc_by(amount as f64);
     }
+
+    pub fn record_agent_resource_limit_exceeded(agent_type: &str, limit: &'static str) {
+        AGENT_RESOURCE_LIMIT_EXCEEDED_TOTAL
+            .with_label_values(&[agent_type, limit])
+            .inc();
+    }
+}
+
+pub mod usage {
+    use golem_service_base::model::{AgentUsage, AgentUsageKey};
+    use lazy_static::lazy_static;
+    use prometheus::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    const OTHER_LABEL: &str = "other";
+
+    lazy_static! {
+        static ref AGENT_USAGE_TOTAL: IntCounterVec = register_int_counter_vec!(
+            "agent_usage_total",
+            "Resource usage of agents per environment, component and agent type",
+            &["environment_id", "component_id", "agent_type", "kind"]
+        )
+        .unwrap();
+        static ref EXPORTED_LABEL_SETS: LabelSets = LabelSets::default();
+    }
+
+    /// The keys exported with their own labels so far
+    #[derive(Debug, Default)]
+    struct LabelSets {
+        exported: Mutex<HashSet<AgentUsageKey>>,
+    }
+
+    impl LabelSets {
+        /// Returns the environment, component and agent type labels of `key`. The first
+        /// `max_label_sets` distinct keys get their own labels, every further key gets the
+        /// "other" labels.
+        fn labels_of(&self, key: &AgentUsageKey, max_label_sets: usize) -> [String; 3] {
+            let has_own_labels = {
+                let mut exported = self.exported.lock().unwrap();
+                if exported.contains(key) {
+                    true
+                } else if exported.len() < max_label_sets {
+                    exported.insert(key.clone());
+                    true
+                } else {
+                    false
+                }
+            };
+
+            if has_own_labels {
+                [
+                    key.environment_id.to_string(),
+                    key.component_id.to_string(),
+                    key.agent_type.0.clone(),
+                ]
+            } else {
+                [
+                    OTHER_LABEL.to_string(),
+                    OTHER_LABEL.to_string(),
+                    OTHER_LABEL.to_string(),
+                ]
+            }
+        }
+    }
+
+    /// Exports agent usage with its own labels for the first `max_label_sets` distinct keys, and
+    /// under the "other" labels for every further key to keep the cardinality bounded.
+    pub fn record_agent_usage(key: &AgentUsageKey, usage: &AgentUsage, max_label_sets: usize) {
+        let [environment_id, component_id, agent_type] =
+            EXPORTED_LABEL_SETS.labels_of(key, max_label_sets);
+
+        for (kind, value) in [
+            ("invocations", usage.invocations),
+            ("fuel", usage.fuel),
+            ("invocation_time_millis", usage.invocation_time_millis),
+            ("oplog_bytes", usage.oplog_bytes),
+            ("outgoing_http_requests", usage.outgoing_http_requests),
+        ] {
+            if value > 0 {
+                AGENT_USAGE_TOTAL
+                    .with_label_values(&[&environment_id, &component_id, &agent_type, kind])
+                    .inc_by(value);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{LabelSets, OTHER_LABEL};
+        use golem_common::model::agent::AgentTypeName;
+        use golem_common::model::component::ComponentId;
+        use golem_common::model::environment::EnvironmentId;
+        use golem_service_base::model::AgentUsageKey;
+        use test_r::test;
+        use uuid::Uuid;
+
+        fn key(agent_type: &str) -> AgentUsageKey {
+            AgentUsageKey {
+                environment_id: EnvironmentId::new(),
+                component_id: ComponentId(Uuid::new_v4()),
+                agent_type: AgentTypeName(agent_type.to_string()),
+            }
+        }
+
+        fn other_labels() -> [String; 3] {
+            [
+                OTHER_LABEL.to_string(),
+                OTHER_LABEL.to_string(),
+                OTHER_LABEL.to_string(),
+            ]
+        }
+
+        #[test]
+        fn label_sets_beyond_the_cap_are_exported_as_other() {
+            let label_sets = LabelSets::default();
+            let first = key("first");
+            let second = key("second");
+            let third = key("third");
+
+            assert_eq!(
+                label_sets.labels_of(&first, 2),
+                [
+                    first.environment_id.to_string(),
+                    first.component_id.to_string(),
+                    "first".to_string()
+                ]
+            );
+            assert_eq!(label_sets.labels_of(&second, 2)[2], "second");
+            assert_eq!(label_sets.labels_of(&third, 2), other_labels());
+        }
+
+        #[test]
+        fn already_exported_label_sets_keep_their_labels_after_the_cap_is_reached() {
+            let label_sets = LabelSets::default();
+            let first = key("first");
+            let second = key("second");
+
+            assert_eq!(label_sets.labels_of(&first, 1)[2], "first");
+            assert_eq!(label_sets.labels_of(&second, 1), other_labels());
+            assert_eq!(label_sets.labels_of(&first, 1)[2], "first");
+            assert_eq!(label_sets.labels_of(&second, 1), other_labels());
+        }
+
+        #[test]
+        fn a_zero_cap_exports_everything_as_other() {
+            let label_sets = LabelSets::default();
+
+            assert_eq!(label_sets.labels_of(&key("any"), 0), other_labels());
+        }
+    }
 }
@@ -270,6 +270,36 @@ impl TrapType {
                             error: WorkerError::ExceededMemoryLimit,
                             retry_from,
                         },
+                        Some(GolemSpecificWasmTrap::WorkerExceededAgentMemoryLimit) => {
+                            TrapType::Error {
+                                error: WorkerError::ExceededAgentMemoryLimit,
+                                retry_from,
+                            }
+                        }
+                        Some(GolemSpecificWasmTrap::WorkerExceededInvocationFuelLimit) => {
+                            TrapType::Error {
+                                error: WorkerError::ExceededInvocationFuelLimit,
+                                retry_from,
+                            }
+                        }
+                        Some(GolemSpecificWasmTrap::WorkerExceededInvocationTimeLimit) => {
+                            TrapType::Error {
+                                error: WorkerError::ExceededInvocationTimeLimit,
+                                retry_from,
+                            }
+                        }
+                        Some(GolemSpecificWasmTrap::WorkerExceededOplogSizeLimit) => {
+                            TrapType::Error {
+                                error: WorkerError::ExceededOplogSizeLimit,
+                                retry_from,
+                            }
+                        }
+                        Some(GolemSpecificWasmTrap::WorkerExceededHttpRequestRateLimit) => {
+                            TrapType::Error {
+                                error: WorkerError::ExceededHttpRequestRateLimit,
+                                retry_from,
+                            }
+                        }
                         None => match error.root_cause().downcast_ref::<WorkerExecutorError>() {
                             Some(WorkerExecutorError::InvalidRequest { details }) => {
                                 TrapType::Error {
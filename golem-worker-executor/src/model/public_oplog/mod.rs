@@ -22,18 +22,20 @@ use golem_common::model::agent::{AgentId, AgentTypeName};
 use golem_common::model::agent::{DataValue, ElementValues};
 use golem_common::model::component::{ComponentRevision, InstalledPlugin};
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::invocation_queue::{truncate_preview, InvocationQueue, PendingInvocation};
 use golem_common::model::lucene::Query;
 use golem_common::model::oplog::public_oplog_entry::{
     ActivatePluginParams, AgentInvocationFinishedParams, AgentInvocationStartedParams,
-    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams,
+    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams, BeginSagaParams,
     CancelPendingInvocationParams, ChangePersistenceLevelParams, ChangeRetryPolicyParams,
     CommittedRemoteTransactionParams, CreateParams, CreateResourceParams, DeactivatePluginParams,
-    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, ErrorParams, ExitedParams,
-    FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams, InterruptedParams,
-    JumpParams, LogParams, NoOpParams, PendingAgentInvocationParams, PendingUpdateParams,
-    PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams, RestartParams,
-    RevertParams, RolledBackRemoteTransactionParams, SetSpanAttributeParams, SnapshotParams,
-    StartSpanParams, SuccessfulUpdateParams, SuspendParams,
+    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, EndSagaParams, ErrorParams,
+    ExitedParams, FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams,
+    InterruptedParams, JumpParams, LogParams, NoOpParams, PendingAgentInvocationParams,
+    PendingUpdateParams, PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams,
+    ReorderPendingInvocationParams, RestartParams, RevertParams, RolledBackRemoteTransactionParams,
+    SagaStepCompensatedParams, SagaStepParams, SetInvocationQueueLimitParams,
+    SetSpanAttributeParams, SnapshotParams, StartSpanParams, SuccessfulUpdateParams, SuspendParams,
 };
 use golem_common::model::oplog::types::encode_span_data;
 use golem_common::model::oplog::{
@@ -42,12 +44,13 @@ use golem_common::model::oplog::{
     HostRequestGolemRpcInvoke, HostRequestGolemRpcScheduledInvocation, HostResponse,
     JsonSnapshotData, LoadSnapshotParameters, ManualUpdateParameters, OplogEntry, OplogIndex,
     PluginInstallationDescription, ProcessOplogEntriesParameters, PublicAgentInvocation,
-    PublicAgentInvocationResult, PublicAttribute, PublicOplogEntry, PublicSnapshotData,
-    PublicUpdateDescription, RawSnapshotData, SaveSnapshotResultParameters,
-    SnapshotBasedUpdateParameters, UpdateDescription,
+    PublicAgentInvocationResult, PublicAttribute, PublicOplogEntry, PublicSagaCompensation,
+    PublicSnapshotData, PublicUpdateDescription, RawSnapshotData, SagaCompensation,
+    SaveSnapshotResultParameters, SnapshotBasedUpdateParameters, UpdateDescription,
 };
 use golem_common::model::{
     AgentInvocation, AgentInvocationPayload, AgentInvocationResult, Empty, OwnedWorkerId, WorkerId,
+    WorkerStatusRecord,
 };
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use golem_wasm::IntoValueAndType;
@@ -201,6 +204,57 @@ pub async fn find_component_revision_at(
     Ok(initial_component_revision)
 }
 
+/// Lists the pending invocations of a worker with short previews of their parameters
+pub async fn get_invocation_queue(
+    components: Arc<dyn ComponentService>,
+    owned_worker_id: &OwnedWorkerId,
+    status: &WorkerStatusRecord,
+) -> Result<InvocationQueue, String> {
+    let mut invocations = Vec::new();
+    for pending in &status.pending_invocations {
+        let Some(idempotency_key) = pending.invocation.idempotency_key().cloned() else {
+            continue;
+        };
+
+        let public_invocation = agent_invocation_to_public(
+            components.clone(),
+            owned_worker_id,
+            status.component_revision,
+            pending.invocation.clone(),
+        )
+        .await?;
+
+        let (method_name, preview) = match public_invocation {
+            PublicAgentInvocation::AgentInitialization(params) => (
+                "agent-initialization".to_string(),
+                params.constructor_parameters.to_string(),
+            ),
+            PublicAgentInvocation::AgentMethodInvocation(params) => {
+                (params.method_name, params.function_input.to_string())
+            }
+            PublicAgentInvocation::SaveSnapshot(_) => ("save-snapshot".to_string(), String::new()),
+            PublicAgentInvocation::LoadSnapshot(_) => ("load-snapshot".to_string(), String::new()),
+            PublicAgentInvocation::ProcessOplogEntries(_) => {
+                ("process-oplog-entries".to_string(), String::new())
+            }
+            PublicAgentInvocation::ManualUpdate(_) => ("manual-update".to_string(), String::new()),
+        };
+
+        invocations.push(PendingInvocation {
+            idempotency_key,
+            timestamp: pending.timestamp,
+            method_name,
+            priority: pending.priority,
+            preview: truncate_preview(preview),
+        });
+    }
+
+    Ok(InvocationQueue {
+        invocations,
+        limit: status.pending_invocation_limit,
+    })
+}
+
 #[async_trait]
 pub trait PublicOplogEntryOps: Sized {
     async fn from_oplog_entry(
@@ -748,10 +802,111 @@ impl PublicOplogEntryOps for PublicOplogEntry {
                     data: snapshot_data,
                 }))
             }
+            OplogEntry::ReorderPendingInvocation {
+                timestamp,
+                idempotency_key,
+                priority,
+                position,
+            } => Ok(PublicOplogEntry::ReorderPendingInvocation(
+                ReorderPendingInvocationParams {
+                    timestamp,
+                    idempotency_key,
+                    priority,
+                    position,
+                },
+            )),
+            OplogEntry::SetInvocationQueueLimit { timestamp, limit } => {
+                Ok(PublicOplogEntry::SetInvocationQueueLimit(
+                    SetInvocationQueueLimitParams { timestamp, limit },
+                ))
+            }
+            OplogEntry::BeginSaga { timestamp } => {
+                Ok(PublicOplogEntry::BeginSaga(BeginSagaParams { timestamp }))
+            }
+            OplogEntry::SagaStep {
+                timestamp,
+                begin_index,
+                compensation,
+            } => {
+                let compensation = saga_compensation_to_public(
+                    components,
+                    oplog_service,
+                    owned_worker_id,
+                    compensation,
+                )
+                .await?;
+                Ok(PublicOplogEntry::SagaStep(SagaStepParams {
+                    timestamp,
+                    begin_index,
+                    compensation,
+                }))
+            }
+            OplogEntry::SagaStepCompensated {
+                timestamp,
+                begin_index,
+                step_index,
+            } => Ok(PublicOplogEntry::SagaStepCompensated(
+                SagaStepCompensatedParams {
+                    timestamp,
+                    begin_index,
+                    step_index,
+                },
+            )),
+            OplogEntry::EndSaga {
+                timestamp,
+                begin_index,
+                compensated,
+            } => Ok(PublicOplogEntry::EndSaga(EndSagaParams {
+                timestamp,
+                begin_index,
+                compensated,
+            })),
         }
     }
 }
 
+async fn saga_compensation_to_public(
+    components: Arc<dyn ComponentService>,
+    oplog_service: Arc<dyn OplogService>,
+    owned_worker_id: &OwnedWorkerId,
+    compensation: SagaCompensation,
+) -> Result<PublicSagaCompensation, String> {
+    let input = oplog_service
+        .download_payload(owned_worker_id, compensation.input)
+        .await?;
+
+    // The compensated agent may belong to a different component than the coordinator
+    let method_schema = components
+        .get_metadata(compensation.target_worker_id.component_id, None)
+        .await
+        .ok()
+        .and_then(|component| {
+            resolve_agent_type_from_worker_name(
+                &component.metadata,
+                &compensation.target_worker_id.worker_name,
+            )
+        })
+        .and_then(|agent_type| {
+            agent_type
+                .methods
+                .into_iter()
+                .find(|m| m.name == compensation.method_name)
+        })
+        .map(|m| m.input_schema);
+
+    let input = match method_schema {
+        Some(schema) => DataValue::try_from_untyped(input, schema)
+            .unwrap_or_else(|_| DataValue::Tuple(ElementValues { elements: vec![] })),
+        None => DataValue::Tuple(ElementValues { elements: vec![] }),
+    };
+
+    Ok(PublicSagaCompensation {
+        target_worker_id: compensation.target_worker_id,
+        method_name: compensation.method_name,
+        input,
+    })
+}
+
 fn raw_snapshot_to_public(snapshot: RawSnapshotData) -> PublicSnapshotData {
     if snapshot.mime_type == "application/json" {
         match serde_json::from_slice(&snapshot.data) {
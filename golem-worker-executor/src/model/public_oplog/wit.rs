@@ -16,18 +16,20 @@ use crate::preview2::golem_api_1_x::oplog;
 use golem_common::model::environment::EnvironmentId;
 use golem_common::model::oplog::public_oplog_entry::{
     ActivatePluginParams, AgentInvocationFinishedParams, AgentInvocationStartedParams,
-    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams,
+    BeginAtomicRegionParams, BeginRemoteTransactionParams, BeginRemoteWriteParams, BeginSagaParams,
     CancelPendingInvocationParams, ChangePersistenceLevelParams, ChangeRetryPolicyParams,
     CommittedRemoteTransactionParams, CreateParams, CreateResourceParams, DeactivatePluginParams,
-    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, ErrorParams, ExitedParams,
-    FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams, InterruptedParams,
-    JumpParams, LogParams, ManualUpdateParameters, NoOpParams, PendingAgentInvocationParams,
-    PendingUpdateParams, PluginInstallationDescription, PreCommitRemoteTransactionParams,
-    PreRollbackRemoteTransactionParams, PublicAgentInvocation, PublicAgentInvocationResult,
-    PublicAttributeValue, PublicDurableFunctionType, PublicRetryConfig, PublicSpanData,
-    RestartParams, RevertParams, RolledBackRemoteTransactionParams, SetSpanAttributeParams,
-    SnapshotParams, StartSpanParams, StringAttributeValue, SuccessfulUpdateParams, SuspendParams,
-    WriteRemoteBatchedParameters, WriteRemoteTransactionParameters,
+    DropResourceParams, EndAtomicRegionParams, EndRemoteWriteParams, EndSagaParams, ErrorParams,
+    ExitedParams, FailedUpdateParams, FinishSpanParams, GrowMemoryParams, HostCallParams,
+    InterruptedParams, JumpParams, LogParams, ManualUpdateParameters, NoOpParams,
+    PendingAgentInvocationParams, PendingUpdateParams, PluginInstallationDescription,
+    PreCommitRemoteTransactionParams, PreRollbackRemoteTransactionParams, PublicAgentInvocation,
+    PublicAgentInvocationResult, PublicAttributeValue, PublicDurableFunctionType,
+    PublicRetryConfig, PublicSpanData, ReorderPendingInvocationParams, RestartParams, RevertParams,
+    RolledBackRemoteTransactionParams, SagaStepCompensatedParams, SagaStepParams,
+    SetInvocationQueueLimitParams, SetSpanAttributeParams, SnapshotParams, StartSpanParams,
+    StringAttributeValue, SuccessfulUpdateParams, SuspendParams, WriteRemoteBatchedParameters,
+    WriteRemoteTransactionParameters,
 };
 use golem_common::model::oplog::{
     AgentInvocationOutputParameters, FallibleResultParameters, JsonSnapshotData, PublicOplogEntry,
@@ -365,6 +367,23 @@ impl From<PublicOplogEntry> for oplog::PublicOplogEntry {
                     mime_type,
                 })
             }
+            // Not part of golem:api/oplog@1.5.0, presented as no-op entries
+            PublicOplogEntry::ReorderPendingInvocation(ReorderPendingInvocationParams {
+                timestamp,
+                ..
+            })
+            | PublicOplogEntry::SetInvocationQueueLimit(SetInvocationQueueLimitParams {
+                timestamp,
+                ..
+            })
+            | PublicOplogEntry::BeginSaga(BeginSagaParams { timestamp })
+            | PublicOplogEntry::SagaStep(SagaStepParams { timestamp, .. })
+            | PublicOplogEntry::SagaStepCompensated(SagaStepCompensatedParams {
+                timestamp, ..
+            })
+            | PublicOplogEntry::EndSaga(EndSagaParams { timestamp, .. }) => {
+                Self::NoOp(timestamp.into())
+            }
         }
     }
 }
@@ -1049,3 +1068,94 @@ impl TryFrom<oplog::OplogEntry> for golem_common::model::oplog::OplogEntry {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::preview2::golem_api_1_x::oplog;
+    use golem_common::model::agent::{DataValue, ElementValues};
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::invocation_queue::InvocationPriority;
+    use golem_common::model::oplog::public_oplog_entry::{
+        BeginSagaParams, EndSagaParams, ReorderPendingInvocationParams, SagaStepCompensatedParams,
+        SagaStepParams, SetInvocationQueueLimitParams,
+    };
+    use golem_common::model::oplog::{PublicOplogEntry, PublicSagaCompensation};
+    use golem_common::model::{IdempotencyKey, OplogIndex, Timestamp, WorkerId};
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn assert_no_op(entry: PublicOplogEntry, timestamp: Timestamp) {
+        let expected = oplog::Timestamp::from(timestamp).timestamp;
+        match oplog::PublicOplogEntry::from(entry) {
+            oplog::PublicOplogEntry::NoOp(ts) => {
+                assert_eq!(ts.timestamp.seconds, expected.seconds);
+                assert_eq!(ts.timestamp.nanoseconds, expected.nanoseconds);
+            }
+            _ => panic!("Expected a no-op entry"),
+        }
+    }
+
+    #[test]
+    fn invocation_queue_entries_are_presented_as_no_op() {
+        let timestamp = Timestamp::now_utc();
+
+        assert_no_op(
+            PublicOplogEntry::ReorderPendingInvocation(ReorderPendingInvocationParams {
+                timestamp,
+                idempotency_key: IdempotencyKey::fresh(),
+                priority: InvocationPriority::High,
+                position: Some(0),
+            }),
+            timestamp,
+        );
+        assert_no_op(
+            PublicOplogEntry::SetInvocationQueueLimit(SetInvocationQueueLimitParams {
+                timestamp,
+                limit: Some(10),
+            }),
+            timestamp,
+        );
+    }
+
+    #[test]
+    fn saga_entries_are_presented_as_no_op() {
+        let timestamp = Timestamp::now_utc();
+        let begin_index = OplogIndex::from_u64(10);
+
+        assert_no_op(
+            PublicOplogEntry::BeginSaga(BeginSagaParams { timestamp }),
+            timestamp,
+        );
+        assert_no_op(
+            PublicOplogEntry::SagaStep(SagaStepParams {
+                timestamp,
+                begin_index,
+                compensation: PublicSagaCompensation {
+                    target_worker_id: WorkerId {
+                        component_id: ComponentId(Uuid::new_v4()),
+                        worker_name: "inventory(\"warehouse-1\")".to_string(),
+                    },
+                    method_name: "release".to_string(),
+                    input: DataValue::Tuple(ElementValues { elements: vec![] }),
+                },
+            }),
+            timestamp,
+        );
+        assert_no_op(
+            PublicOplogEntry::SagaStepCompensated(SagaStepCompensatedParams {
+                timestamp,
+                begin_index,
+                step_index: OplogIndex::from_u64(11),
+            }),
+            timestamp,
+        );
+        assert_no_op(
+            PublicOplogEntry::EndSaga(EndSagaParams {
+                timestamp,
+                begin_index,
+                compensated: true,
+            }),
+            timestamp,
+        );
+    }
+}
@@ -44,6 +44,7 @@ wasmtime::component::bindgen!({
         "golem:agent/host.wasm-rpc": golem_wasm::WasmRpcEntry,
         "golem:agent/host.future-invoke-result": golem_wasm::FutureInvokeResultEntry,
         "golem:agent/host.cancellation-token": golem_wasm::CancellationTokenEntry,
+        "golem:agent/host.saga": super::durable_host::wasm_rpc::saga::SagaEntry,
         // shared wasi dependencies of golem:rpc/wasm-rpc and golem:api/golem
         "wasi:io/poll": wasmtime_wasi::p2::bindings::io::poll,
         "wasi:clocks/wall-clock": wasmtime_wasi::p2::bindings::clocks::wall_clock,
@@ -0,0 +1,181 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::golem_config::AgentLifecycleEventsConfig;
+use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use async_trait::async_trait;
+use golem_common::model::lifecycle_webhook::AgentLifecycleEvent;
+use golem_service_base::clients::registry::RegistryService;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, span, warn, Instrument, Level};
+
+/// Records the lifecycle events of the agents running on the executor, to be delivered to the
+/// lifecycle webhooks of their environments
+#[async_trait]
+pub trait AgentLifecycleEventService: Send + Sync {
+    /// Stores the event until it is forwarded to the registry service
+    async fn publish(&self, event: AgentLifecycleEvent);
+}
+
+/// Stores the published events in the key-value storage, so they survive restarts of the
+/// executor and outages of the registry service, and forwards them in batches to the registry
+/// service from a background loop. An event is only removed from the storage once the registry
+/// service accepted it; the registry service ignores events it already received.
+pub struct AgentLifecycleEventServiceDefault {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    registry_service: Arc<dyn RegistryService>,
+    config: AgentLifecycleEventsConfig,
+}
+
+impl AgentLifecycleEventServiceDefault {
+    const PENDING_EVENTS_KEY: &'static str = "agent-lifecycle-events:pending";
+
+    pub fn new(
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+        registry_service: Arc<dyn RegistryService>,
+        config: AgentLifecycleEventsConfig,
+        shutdown_token: CancellationToken,
+    ) -> Arc<Self> {
+        let svc = Arc::new(Self {
+            key_value_storage,
+            registry_service,
+            config,
+        });
+
+        if svc.config.enabled {
+            let svc_weak = Arc::downgrade(&svc);
+            let flush_interval = svc.config.flush_interval;
+            tokio::spawn(
+                async move {
+                    loop {
+                        let shutdown = tokio::select! {
+                            _ = shutdown_token.cancelled() => true,
+                            _ = tokio::time::sleep(flush_interval) => false,
+                        };
+                        let Some(svc) = svc_weak.upgrade() else {
+                            info!("Agent lifecycle event service dropped, stopping forwarding");
+                            break;
+                        };
+                        if let Err(err) = svc.forward_pending().await {
+                            error!("Failed to forward agent lifecycle events: {err}");
+                        }
+                        if shutdown {
+                            info!("Shutdown requested, stopping agent lifecycle event forwarding");
+                            break;
+                        }
+                    }
+                }
+                .instrument(span!(
+                    parent: None,
+                    Level::INFO,
+                    "Agent lifecycle event forwarding"
+                )),
+            );
+        }
+
+        svc
+    }
+
+    /// Forwards the stored events to the registry service in batches, oldest first, until all
+    /// of them are forwarded or sending a batch fails
+    async fn forward_pending(&self) -> Result<(), String> {
+        loop {
+            let stored = self
+                .key_value_storage
+                .get_sorted_set_page(
+                    "agent_lifecycle_events",
+                    "forward",
+                    "event",
+                    KeyValueStorageNamespace::AgentLifecycleEvents,
+                    Self::PENDING_EVENTS_KEY,
+                    f64::MIN,
+                    self.config.batch_size,
+                )
+                .await?;
+            if stored.is_empty() {
+                return Ok(());
+            }
+
+            let mut events = Vec::with_capacity(stored.len());
+            for (_, bytes) in &stored {
+                match serde_json::from_slice::<AgentLifecycleEvent>(bytes) {
+                    Ok(event) => events.push(event),
+                    Err(err) => warn!("Skipping invalid stored agent lifecycle event: {err}"),
+                }
+            }
+            let batch_size = stored.len();
+
+            if !events.is_empty() {
+                self.registry_service
+                    .publish_agent_lifecycle_events(events)
+                    .await
+                    .map_err(|err| {
+                        format!("failed to publish {batch_size} agent lifecycle events, retrying later: {err}")
+                    })?;
+            }
+
+            for (_, bytes) in stored {
+                self.key_value_storage
+                    .remove_from_sorted_set(
+                        "agent_lifecycle_events",
+                        "forward",
+                        "event",
+                        KeyValueStorageNamespace::AgentLifecycleEvents,
+                        Self::PENDING_EVENTS_KEY,
+                        &bytes,
+                    )
+                    .await?;
+            }
+
+            if (batch_size as u64) < self.config.batch_size {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AgentLifecycleEventService for AgentLifecycleEventServiceDefault {
+    async fn publish(&self, event: AgentLifecycleEvent) {
+        if !self.config.enabled {
+            return;
+        }
+
+        let result = match serde_json::to_vec(&event) {
+            Ok(bytes) => {
+                self.key_value_storage
+                    .add_to_sorted_set(
+                        "agent_lifecycle_events",
+                        "publish",
+                        "event",
+                        KeyValueStorageNamespace::AgentLifecycleEvents,
+                        Self::PENDING_EVENTS_KEY,
+                        event.timestamp.timestamp_millis() as f64,
+                        &bytes,
+                    )
+                    .await
+            }
+            Err(err) => Err(err.to_string()),
+        };
+
+        if let Err(err) = result {
+            error!(
+                event_id = %event.id,
+                worker_id = %event.worker_id,
+                "Failed to store agent lifecycle event: {err}"
+            );
+        }
+    }
+}
@@ -24,11 +24,13 @@ use golem_common::tracing::TracingConfig;
 use golem_common::{grpc_uri, SafeDisplay};
 use golem_service_base::clients::registry::GrpcRegistryServiceConfig;
 use golem_service_base::config::BlobStorageConfig;
+use golem_service_base::fault_injection::FaultInjectionConfig;
 use golem_service_base::grpc::client::GrpcClientConfig;
 use golem_service_base::grpc::server::GrpcServerTlsConfig;
 use golem_service_base::service::compiled_component::CompiledComponentServiceConfig;
 use http::Uri;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Write;
 use std::net::{Ipv4Addr, SocketAddrV4};
 use std::path::{Path, PathBuf};
@@ -48,18 +50,24 @@ pub struct GolemConfig {
     pub shard_manager_service: ShardManagerServiceConfig,
     pub oplog: OplogConfig,
     pub suspend: SuspendConfig,
+    pub sockets: SocketsConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
+    pub pubsub: PubSubConfig,
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub memory: MemoryConfig,
     pub rdbms: RdbmsConfig,
     pub resource_limits: ResourceLimitsConfig,
+    pub usage_metering: UsageMeteringConfig,
     pub component_cache: ComponentCacheConfig,
     pub agent_types_service: AgentTypesServiceConfig,
     pub agent_deployments_service: AgentDeploymentsServiceConfig,
     pub agent_webhooks_service: AgentWebhooksServiceConfig,
+    pub agent_lifecycle_events: AgentLifecycleEventsConfig,
+    pub network_egress_policy_service: NetworkEgressPolicyServiceConfig,
     pub registry_service: GrpcRegistryServiceConfig,
     pub engine: EngineConfig,
+    pub fault_injection: FaultInjectionConfig,
     pub grpc: GrpcApiConfig,
     pub http_address: String,
     pub http_port: u16,
@@ -116,6 +124,8 @@ impl SafeDisplay for GolemConfig {
         let _ = writeln!(&mut result, "{}", self.oplog.to_safe_string_indented());
         let _ = writeln!(&mut result, "suspend:");
         let _ = writeln!(&mut result, "{}", self.suspend.to_safe_string_indented());
+        let _ = writeln!(&mut result, "sockets:");
+        let _ = writeln!(&mut result, "{}", self.sockets.to_safe_string_indented());
         let _ = writeln!(&mut result, "active_workers:");
         let _ = writeln!(
             &mut result,
@@ -124,6 +134,8 @@ impl SafeDisplay for GolemConfig {
         );
         let _ = writeln!(&mut result, "scheduler:");
         let _ = writeln!(&mut result, "{}", self.scheduler.to_safe_string_indented());
+        let _ = writeln!(&mut result, "pubsub:");
+        let _ = writeln!(&mut result, "{}", self.pubsub.to_safe_string_indented());
         let _ = writeln!(&mut result, "public worker api:");
         let _ = writeln!(
             &mut result,
@@ -140,6 +152,12 @@ impl SafeDisplay for GolemConfig {
             "{}",
             self.resource_limits.to_safe_string_indented()
         );
+        let _ = writeln!(&mut result, "usage metering:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.usage_metering.to_safe_string_indented()
+        );
         let _ = writeln!(&mut result, "registry service:");
         let _ = writeln!(
             &mut result,
@@ -174,6 +192,20 @@ impl SafeDisplay for GolemConfig {
             self.agent_webhooks_service.to_safe_string_indented()
         );
 
+        let _ = writeln!(&mut result, "agent lifecycle events:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.agent_lifecycle_events.to_safe_string_indented()
+        );
+
+        let _ = writeln!(&mut result, "network egress policy service:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.network_egress_policy_service.to_safe_string_indented()
+        );
+
         let _ = writeln!(&mut result, "engine:");
         let _ = writeln!(&mut result, "{}", self.engine.to_safe_string_indented());
 
@@ -201,16 +233,21 @@ impl Default for GolemConfig {
             shard_manager_service: ShardManagerServiceConfig::default(),
             oplog: OplogConfig::default(),
             suspend: SuspendConfig::default(),
+            sockets: SocketsConfig::default(),
             scheduler: SchedulerConfig::default(),
+            pubsub: PubSubConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
             public_worker_api: WorkerServiceGrpcConfig::default(),
             memory: MemoryConfig::default(),
             rdbms: RdbmsConfig::default(),
             resource_limits: ResourceLimitsConfig::default(),
+            usage_metering: UsageMeteringConfig::default(),
             component_cache: ComponentCacheConfig::default(),
             agent_types_service: AgentTypesServiceConfig::default(),
             agent_deployments_service: AgentDeploymentsServiceConfig::default(),
             agent_webhooks_service: AgentWebhooksServiceConfig::default(),
+            agent_lifecycle_events: AgentLifecycleEventsConfig::default(),
+            network_egress_policy_service: NetworkEgressPolicyServiceConfig::default(),
             registry_service: GrpcRegistryServiceConfig {
                 client_config: GrpcClientConfig {
                     request_timeout: Some(Duration::from_secs(30)),
@@ -219,6 +256,7 @@ impl Default for GolemConfig {
                 ..GrpcRegistryServiceConfig::default()
             },
             engine: EngineConfig::default(),
+            fault_injection: FaultInjectionConfig::default(),
             grpc: GrpcApiConfig::default(),
             http_address: "0.0.0.0".to_string(),
             http_port: 8082,
@@ -463,6 +501,20 @@ impl SafeDisplay for SuspendConfig {
     }
 }
 
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SocketsConfig {
+    /// Records the traffic of TCP connections and UDP sockets in the oplog so agents using them
+    /// can be recovered. Every connection attempt, read, write and datagram batch adds an oplog
+    /// entry, so it is disabled by default.
+    pub durable: bool,
+}
+
+impl SafeDisplay for SocketsConfig {
+    fn to_safe_string(&self) -> String {
+        format!("durable: {}", self.durable)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ActiveWorkersConfig {
     pub drop_when_full: f64,
@@ -493,6 +545,41 @@ impl SafeDisplay for SchedulerConfig {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PubSubConfig {
+    /// How often the subscriptions of the agents owned by this executor are checked for
+    /// undelivered messages
+    #[serde(with = "humantime_serde")]
+    pub delivery_interval: Duration,
+    /// The maximum number of messages enqueued for a single subscriber in one delivery round
+    pub max_messages_per_delivery: usize,
+    /// Delivered messages are kept at least this long before they are deleted
+    #[serde(with = "humantime_serde")]
+    pub message_retention: Duration,
+}
+
+impl SafeDisplay for PubSubConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(
+            &mut result,
+            "delivery interval: {:?}",
+            self.delivery_interval
+        );
+        let _ = writeln!(
+            &mut result,
+            "max messages per delivery: {}",
+            self.max_messages_per_delivery
+        );
+        let _ = writeln!(
+            &mut result,
+            "message retention: {:?}",
+            self.message_retention
+        );
+        result
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OplogConfig {
     pub max_operations_before_commit: u64,
@@ -504,6 +591,7 @@ pub struct OplogConfig {
     #[serde(with = "humantime_serde")]
     pub archive_interval: Duration,
     pub default_snapshotting: SnapshotPolicy,
+    pub retention: OplogRetentionConfig,
 }
 
 impl SafeDisplay for OplogConfig {
@@ -538,6 +626,89 @@ impl SafeDisplay for OplogConfig {
             "{}",
             self.default_snapshotting.to_safe_string_indented()
         );
+        let _ = writeln!(&mut result, "retention:");
+        let _ = writeln!(&mut result, "{}", self.retention.to_safe_string_indented());
+        result
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OplogRetentionConfig {
+    /// How often the retention policies are applied to the agents of the assigned shards
+    #[serde(with = "humantime_serde")]
+    pub check_interval: Duration,
+    /// The retention policy used for agent types without an explicit policy
+    pub default_policy: OplogRetentionPolicy,
+    /// Retention policies by agent type name
+    pub agent_types: HashMap<String, OplogRetentionPolicy>,
+}
+
+impl OplogRetentionConfig {
+    pub fn policy_for(&self, agent_type: Option<&str>) -> &OplogRetentionPolicy {
+        agent_type
+            .and_then(|agent_type| self.agent_types.get(agent_type))
+            .unwrap_or(&self.default_policy)
+    }
+}
+
+impl SafeDisplay for OplogRetentionConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "check interval: {:?}", self.check_interval);
+        let _ = writeln!(&mut result, "default policy:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.default_policy.to_safe_string_indented()
+        );
+        for (agent_type, policy) in &self.agent_types {
+            let _ = writeln!(&mut result, "policy of {agent_type}:");
+            let _ = writeln!(&mut result, "{}", policy.to_safe_string_indented());
+        }
+        result
+    }
+}
+
+/// Defines which part of an agent's oplog history can be permanently discarded. Only entries
+/// preceding the agent's latest snapshot are ever discarded, as the agent is recovered from that
+/// snapshot; the initial entry of the oplog is always kept.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[derive(Default)]
+pub enum OplogRetentionPolicy {
+    /// The whole oplog history is kept
+    #[default]
+    KeepAll,
+    /// Keeps at least the given number of most recent entries
+    KeepLastEntries { count: u64 },
+    /// Keeps at least the entries created within the given duration
+    KeepLastDuration {
+        #[serde(with = "humantime_serde")]
+        duration: Duration,
+    },
+    /// Everything before the latest snapshot is discarded
+    DiscardBeforeSnapshot,
+}
+
+impl SafeDisplay for OplogRetentionPolicy {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        match self {
+            OplogRetentionPolicy::KeepAll => {
+                let _ = writeln!(&mut result, "keep all");
+            }
+            OplogRetentionPolicy::KeepLastEntries { count } => {
+                let _ = writeln!(&mut result, "keep last entries:");
+                let _ = writeln!(&mut result, "  count: {count}");
+            }
+            OplogRetentionPolicy::KeepLastDuration { duration } => {
+                let _ = writeln!(&mut result, "keep last duration:");
+                let _ = writeln!(&mut result, "  duration: {duration:?}");
+            }
+            OplogRetentionPolicy::DiscardBeforeSnapshot => {
+                let _ = writeln!(&mut result, "discard before snapshot");
+            }
+        }
         result
     }
 }
@@ -862,6 +1033,38 @@ impl SafeDisplay for ResourceLimitsGrpcConfig {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ResourceLimitsDisabledConfig {}
 
+/// Usage of agents is always reported to the registry service together with the fuel consumption,
+/// this configures the optional export of the same usage as Prometheus metrics.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UsageMeteringConfig {
+    pub prometheus_export: bool,
+    /// Maximum number of distinct (environment, component, agent type) label sets exported, usage of
+    /// any further agent type is exported with the labels set to "other"
+    pub prometheus_max_label_sets: usize,
+}
+
+impl Default for UsageMeteringConfig {
+    fn default() -> Self {
+        Self {
+            prometheus_export: false,
+            prometheus_max_label_sets: 1000,
+        }
+    }
+}
+
+impl SafeDisplay for UsageMeteringConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "prometheus export: {}", self.prometheus_export);
+        let _ = writeln!(
+            &mut result,
+            "prometheus max label sets: {}",
+            self.prometheus_max_label_sets
+        );
+        result
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComponentCacheConfig {
     pub max_capacity: usize,
@@ -966,6 +1169,39 @@ impl SafeDisplay for AgentDeploymentsServiceConfig {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NetworkEgressPolicyServiceConfig {
+    pub cache_capacity: usize,
+    #[serde(with = "humantime_serde")]
+    pub cache_ttl: Duration,
+    #[serde(with = "humantime_serde")]
+    pub cache_eviction_interval: Duration,
+}
+
+impl Default for NetworkEgressPolicyServiceConfig {
+    fn default() -> Self {
+        Self {
+            cache_capacity: 1000,
+            cache_ttl: Duration::from_mins(1),
+            cache_eviction_interval: Duration::from_mins(1),
+        }
+    }
+}
+
+impl SafeDisplay for NetworkEgressPolicyServiceConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "cache_capacity: {}", self.cache_capacity);
+        let _ = writeln!(&mut result, "cache_ttl: {:?}", self.cache_ttl);
+        let _ = writeln!(
+            &mut result,
+            "cache_eviction_interval: {:?}",
+            self.cache_eviction_interval
+        );
+        result
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AgentWebhooksServiceConfig {
     pub use_https_for_webhook_url: bool,
@@ -998,6 +1234,38 @@ impl SafeDisplay for AgentWebhooksServiceConfig {
     }
 }
 
+/// Lifecycle events of agents (creation, failures, updates, etc.) are stored in the key-value
+/// storage and forwarded in batches to the registry service, which delivers them to the lifecycle
+/// webhooks of the environment.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AgentLifecycleEventsConfig {
+    pub enabled: bool,
+    #[serde(with = "humantime_serde")]
+    pub flush_interval: Duration,
+    /// Maximum number of events sent to the registry service in one request
+    pub batch_size: u64,
+}
+
+impl Default for AgentLifecycleEventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            flush_interval: Duration::from_secs(1),
+            batch_size: 1000,
+        }
+    }
+}
+
+impl SafeDisplay for AgentLifecycleEventsConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(&mut result, "enabled: {}", self.enabled);
+        let _ = writeln!(&mut result, "flush interval: {:?}", self.flush_interval);
+        let _ = writeln!(&mut result, "batch size: {}", self.batch_size);
+        result
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
 #[derive(Default)]
@@ -1113,6 +1381,17 @@ impl Default for OplogConfig {
             entry_count_limit: 1024,
             archive_interval: Duration::from_secs(60 * 60 * 24), // 24 hours
             default_snapshotting: SnapshotPolicy::default(),
+            retention: OplogRetentionConfig::default(),
+        }
+    }
+}
+
+impl Default for OplogRetentionConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(60 * 60), // 1 hour
+            default_policy: OplogRetentionPolicy::default(),
+            agent_types: HashMap::new(),
         }
     }
 }
@@ -1142,6 +1421,16 @@ impl Default for SchedulerConfig {
     }
 }
 
+impl Default for PubSubConfig {
+    fn default() -> Self {
+        Self {
+            delivery_interval: Duration::from_secs(1),
+            max_messages_per_delivery: 100,
+            message_retention: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
 impl Default for KeyValueStorageConfig {
     fn default() -> Self {
         Self::default_redis()
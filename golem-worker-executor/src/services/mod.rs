@@ -14,6 +14,7 @@
 
 pub mod active_workers;
 pub mod agent_deployments;
+pub mod agent_lifecycle_events;
 pub mod agent_types;
 pub mod agent_webhooks;
 pub mod blob_store;
@@ -22,9 +23,13 @@ pub mod events;
 pub mod file_loader;
 pub mod golem_config;
 pub mod key_value;
+pub mod network_egress_policy;
 pub mod oplog;
+pub mod oplog_retention;
 pub mod promise;
+pub mod pubsub;
 pub mod rdbms;
+pub mod recorded_host_calls;
 pub mod resource_limits;
 pub mod rpc;
 pub mod scheduler;
@@ -140,6 +145,16 @@ pub trait HasSchedulerService {
     fn scheduler_service(&self) -> Arc<dyn scheduler::SchedulerService>;
 }
 
+pub trait HasPubSubService {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService>;
+}
+
+pub trait HasAgentLifecycleEventService {
+    fn agent_lifecycle_event_service(
+        &self,
+    ) -> Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>;
+}
+
 pub trait HasExtraDeps<Ctx: WorkerCtx> {
     fn extra_deps(&self) -> Ctx::ExtraDeps;
 }
@@ -180,6 +195,12 @@ pub trait HasResourceLimits {
     fn resource_limits(&self) -> Arc<dyn resource_limits::ResourceLimits>;
 }
 
+pub trait HasNetworkEgressPolicyService {
+    fn network_egress_policy_service(
+        &self,
+    ) -> Arc<dyn network_egress_policy::NetworkEgressPolicyService>;
+}
+
 pub trait HasShutdownToken {
     fn shutdown_token(&self) -> tokio_util::sync::CancellationToken;
 }
@@ -207,6 +228,8 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasOplogService
     + HasRpc
     + HasSchedulerService
+    + HasPubSubService
+    + HasAgentLifecycleEventService
     + HasWorkerActivator<Ctx>
     + HasWorkerProxy
     + HasEvents
@@ -215,6 +238,7 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasFileLoader
     + HasOplogProcessorPlugin
     + HasResourceLimits
+    + HasNetworkEgressPolicyService
     + HasShutdownToken
     + HasExtraDeps<Ctx>
     + HasLeakSentinel
@@ -242,6 +266,8 @@ impl<
             + HasOplogService
             + HasRpc
             + HasSchedulerService
+            + HasPubSubService
+            + HasAgentLifecycleEventService
             + HasWorkerActivator<Ctx>
             + HasWorkerProxy
             + HasEvents
@@ -250,6 +276,7 @@ impl<
             + HasFileLoader
             + HasOplogProcessorPlugin
             + HasResourceLimits
+            + HasNetworkEgressPolicyService
             + HasShutdownToken
             + HasExtraDeps<Ctx>
             + HasLeakSentinel
@@ -284,12 +311,15 @@ pub struct All<Ctx: WorkerCtx> {
     oplog_service: Arc<dyn oplog::OplogService>,
     rpc: Arc<dyn rpc::Rpc>,
     scheduler_service: Arc<dyn scheduler::SchedulerService>,
+    pubsub_service: Arc<dyn pubsub::PubSubService>,
+    agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
     worker_activator: Arc<dyn WorkerActivator<Ctx>>,
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy>,
     events: Arc<Events>,
     file_loader: Arc<FileLoader>,
     oplog_processor_plugin: Arc<dyn oplog::plugin::OplogProcessorPlugin>,
     resource_limits: Arc<dyn resource_limits::ResourceLimits>,
+    network_egress_policy_service: Arc<dyn network_egress_policy::NetworkEgressPolicyService>,
     shutdown_token: CancellationToken,
     extra_deps: Ctx::ExtraDeps,
     /// A no-op sentinel that participates in the `All` lifecycle.
@@ -321,6 +351,8 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             oplog_service: self.oplog_service.clone(),
             rpc: self.rpc.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            agent_lifecycle_event_service: self.agent_lifecycle_event_service.clone(),
             worker_activator: self.worker_activator.clone(),
             worker_proxy: self.worker_proxy.clone(),
             rdbms_service: self.rdbms_service.clone(),
@@ -328,6 +360,7 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             file_loader: self.file_loader.clone(),
             oplog_processor_plugin: self.oplog_processor_plugin.clone(),
             resource_limits: self.resource_limits.clone(),
+            network_egress_policy_service: self.network_egress_policy_service.clone(),
             shutdown_token: self.shutdown_token.clone(),
             extra_deps: self.extra_deps.clone(),
             leak_sentinel: self.leak_sentinel.clone(),
@@ -361,12 +394,15 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         oplog_service: Arc<dyn oplog::OplogService>,
         rpc: Arc<dyn rpc::Rpc>,
         scheduler_service: Arc<dyn scheduler::SchedulerService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
         worker_activator: Arc<dyn WorkerActivator<Ctx>>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn oplog::plugin::OplogProcessorPlugin>,
         resource_limits: Arc<dyn resource_limits::ResourceLimits>,
+        network_egress_policy_service: Arc<dyn network_egress_policy::NetworkEgressPolicyService>,
         shutdown_token: CancellationToken,
         extra_deps: Ctx::ExtraDeps,
         leak_sentinel: Arc<()>,
@@ -393,12 +429,15 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             oplog_service,
             rpc,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator,
             worker_proxy,
             events,
             file_loader,
             oplog_processor_plugin,
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             extra_deps,
             leak_sentinel,
@@ -435,12 +474,15 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.oplog_service(),
             this.rpc(),
             this.scheduler_service(),
+            this.pubsub_service(),
+            this.agent_lifecycle_event_service(),
             this.worker_activator(),
             this.worker_proxy(),
             this.events(),
             this.file_loader(),
             this.oplog_processor_plugin(),
             this.resource_limits(),
+            this.network_egress_policy_service(),
             this.shutdown_token(),
             this.extra_deps(),
             this.leak_sentinel(),
@@ -580,6 +622,20 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasSchedulerService for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasPubSubService for T {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.all().pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasAgentLifecycleEventService for T {
+    fn agent_lifecycle_event_service(
+        &self,
+    ) -> Arc<dyn agent_lifecycle_events::AgentLifecycleEventService> {
+        self.all().agent_lifecycle_event_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWorkerActivator<Ctx> for T {
     fn worker_activator(&self) -> Arc<dyn WorkerActivator<Ctx>> {
         self.all().worker_activator.clone()
@@ -622,6 +678,14 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasResourceLimits for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasNetworkEgressPolicyService for T {
+    fn network_egress_policy_service(
+        &self,
+    ) -> Arc<dyn network_egress_policy::NetworkEgressPolicyService> {
+        self.all().network_egress_policy_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasShutdownToken for T {
     fn shutdown_token(&self) -> CancellationToken {
         self.all().shutdown_token.clone()
@@ -0,0 +1,82 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::model::environment::{EnvironmentId, NetworkEgressPolicy};
+use golem_service_base::clients::registry::RegistryService;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[async_trait]
+pub trait NetworkEgressPolicyService: Send + Sync {
+    /// Get the network egress policy of the environment.
+    /// Environments without an explicit policy get the unrestricted default policy.
+    async fn get_network_egress_policy(
+        &self,
+        environment: EnvironmentId,
+    ) -> Result<Arc<NetworkEgressPolicy>, WorkerExecutorError>;
+}
+
+pub struct GrpcNetworkEgressPolicyService {
+    client: Arc<dyn RegistryService>,
+    cached_policies: Cache<EnvironmentId, (), Arc<NetworkEgressPolicy>, WorkerExecutorError>,
+}
+
+impl GrpcNetworkEgressPolicyService {
+    pub fn new(
+        registry_service: Arc<dyn RegistryService>,
+        cache_capacity: usize,
+        cache_ttl: Duration,
+        cache_eviction_interval: Duration,
+    ) -> Self {
+        Self {
+            client: registry_service,
+            cached_policies: Cache::new(
+                Some(cache_capacity),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::OlderThan {
+                    ttl: cache_ttl,
+                    period: cache_eviction_interval,
+                },
+                "grpc_network_egress_policy_service",
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl NetworkEgressPolicyService for GrpcNetworkEgressPolicyService {
+    async fn get_network_egress_policy(
+        &self,
+        environment: EnvironmentId,
+    ) -> Result<Arc<NetworkEgressPolicy>, WorkerExecutorError> {
+        self.cached_policies
+            .get_or_insert_simple(&environment, || {
+                Box::pin(async move {
+                    self.client
+                        .get_network_egress_policy(environment)
+                        .await
+                        .map(Arc::new)
+                        .map_err(|e| {
+                            WorkerExecutorError::runtime(format!(
+                                "Failed to get network egress policy: {e}"
+                            ))
+                        })
+                })
+            })
+            .await
+    }
+}
@@ -446,13 +446,14 @@ impl OplogArchive for BlobOplogArchive {
         result
     }
 
-    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) {
+    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) -> u64 {
         self.ensure_is_created().await;
 
         if chunk.is_empty() {
-            return;
+            return 0;
         }
 
+        let mut bytes = 0;
         for sub_chunk in chunk.chunks(BlobOplogArchiveService::MAX_CHUNK_SIZE) {
             let last = sub_chunk.last().unwrap();
             let oplog_index = last.0;
@@ -463,6 +464,7 @@ impl OplogArchive for BlobOplogArchive {
 
             let compressed_chunk = CompressedOplogChunk::compress(entries)
                 .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
+            bytes += compressed_chunk.compressed_data.len() as u64;
 
             let mut entries_map = self.entries.write().await;
 
@@ -487,6 +489,7 @@ impl OplogArchive for BlobOplogArchive {
 
             entries_map.insert(oplog_index, path);
         }
+        bytes
     }
 
     async fn current_oplog_index(&self) -> OplogIndex {
@@ -564,6 +567,78 @@ impl OplogArchive for BlobOplogArchive {
         drop_count as u64
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        self.ensure_is_created().await;
+
+        let mut entries = self.entries.write().await;
+
+        let mut idx_to_drop = entries
+            .range(first_dropped_id..=last_dropped_id)
+            .map(|(idx, _)| *idx)
+            .collect::<Vec<_>>();
+
+        // Chunks are stored by their last index; a chunk which starts before the range must be kept
+        if let Some(first_idx) = idx_to_drop.first().copied() {
+            let first_chunk: Option<CompressedOplogChunk> = self
+                .blob_storage
+                .with("blob_oplog", "drop_range")
+                .get(
+                    BlobStorageNamespace::CompressedOplog {
+                        environment_id: self.owned_worker_id.environment_id(),
+                        component_id: self.owned_worker_id.component_id(),
+                        level: self.level,
+                    },
+                    &self.oplog_index_to_path(first_idx),
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    panic!(
+                        "failed to read compressed oplog chunk for worker {} in blob storage: {err}",
+                        self.owned_worker_id.worker_id
+                    )
+                });
+            if let Some(first_chunk) = first_chunk {
+                if u64::from(first_idx) - first_chunk.count + 1 < u64::from(first_dropped_id) {
+                    idx_to_drop.remove(0);
+                }
+            }
+        }
+
+        if idx_to_drop.is_empty() {
+            return 0;
+        }
+
+        let to_drop = idx_to_drop
+            .iter()
+            .map(|idx| self.oplog_index_to_path(*idx))
+            .collect::<Vec<_>>();
+
+        self.blob_storage
+            .with("blob_oplog", "drop_range")
+            .delete_many(
+                BlobStorageNamespace::CompressedOplog {
+                    environment_id: self.owned_worker_id.environment_id(),
+                    component_id: self.owned_worker_id.component_id(),
+                    level: self.level,
+                },
+                &to_drop,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to drop compressed oplog chunks for worker {} in blob storage: {err}",
+                    self.owned_worker_id.worker_id
+                )
+            });
+
+        for idx in &idx_to_drop {
+            let _ = entries.remove(idx);
+        }
+        *self.cache.write().await = EvictingCacheMap::new();
+
+        idx_to_drop.len() as u64
+    }
+
     async fn length(&self) -> u64 {
         let entries = self.entries.read().await;
         entries.len() as u64
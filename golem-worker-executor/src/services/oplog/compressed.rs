@@ -231,7 +231,7 @@ impl CompressedOplogArchive {
     }
 }
 
-/// Currently only the background-transfer fiber calls `append` and `drop_prefix` on oplog archives,
+/// Currently only the background-transfer fiber calls `append`, `drop_prefix` and `drop_range` on oplog archives,
 /// so here it is not protected by a lock. If this changes, we need to add a lock here, similar
 /// to the `PrimaryOplog` implementation.
 #[async_trait]
@@ -285,12 +285,13 @@ impl OplogArchive for CompressedOplogArchive {
         result
     }
 
-    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) {
+    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) -> u64 {
         if chunk.is_empty() {
-            return;
+            return 0;
         }
 
         let worker_id = &self.worker_id;
+        let mut bytes = 0;
         let mut cache = self.cache.write().await;
 
         for (idx, entry) in &chunk {
@@ -305,6 +306,7 @@ impl OplogArchive for CompressedOplogArchive {
 
             let compressed_chunk = CompressedOplogChunk::compress(entries)
                 .unwrap_or_else(|err| panic!("failed to compress oplog chunk: {err}"));
+            bytes += compressed_chunk.compressed_data.len() as u64;
 
             self.indexed_storage
                 .with_entity("compressed_oplog", "append", "compressed_entry")
@@ -321,6 +323,7 @@ impl OplogArchive for CompressedOplogArchive {
                     )
                 });
         }
+        bytes
     }
 
     async fn current_oplog_index(&self) -> OplogIndex {
@@ -357,6 +360,40 @@ impl OplogArchive for CompressedOplogArchive {
         before - remaining
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        let worker_id = &self.worker_id;
+        let before = self.length().await;
+
+        // Chunks are stored by their last index; a chunk which starts before the range must be kept
+        let first_chunk = self.indexed_storage
+            .with_entity("compressed_oplog", "drop_range", "compressed_entry")
+            .closest::<CompressedOplogChunk>(IndexedStorageNamespace::CompressedOpLog { worker_id: self.worker_id.clone(), level: self.level }, &self.key, first_dropped_id.into())
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to read compressed oplog for worker {worker_id} in indexed storage: {err}")
+            });
+        let first_dropped_id = match first_chunk {
+            Some((last_idx_in_chunk, chunk))
+                if last_idx_in_chunk - chunk.count + 1 < u64::from(first_dropped_id) =>
+            {
+                OplogIndex::from_u64(last_idx_in_chunk + 1)
+            }
+            _ => first_dropped_id,
+        };
+
+        if first_dropped_id <= last_dropped_id {
+            self.indexed_storage.with("compressed_oplog", "drop_range")
+                .drop_range(IndexedStorageNamespace::CompressedOpLog { worker_id: self.worker_id.clone(), level: self.level }, &self.key, first_dropped_id.into(), last_dropped_id.into())
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to drop range from compressed oplog for worker {worker_id} in indexed storage: {err}")
+                });
+            *self.cache.write().await = EvictingCacheMap::new();
+        }
+
+        before - self.length().await
+    }
+
     async fn length(&self) -> u64 {
         self.indexed_storage
             .with("compressed_oplog", "length")
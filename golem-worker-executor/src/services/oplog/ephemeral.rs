@@ -42,6 +42,7 @@ struct EphemeralOplogState {
     max_operations_before_commit: u64,
     target: Arc<dyn OplogArchive + Send + Sync>,
     last_added_non_hint_entry: Option<OplogIndex>,
+    committed_bytes: u64,
 }
 
 impl EphemeralOplogState {
@@ -70,7 +71,7 @@ impl EphemeralOplogState {
             self.last_committed_idx = oplog_idx;
         }
 
-        self.target.append(pairs).await;
+        self.committed_bytes += self.target.append(pairs).await;
         result
     }
 }
@@ -95,6 +96,7 @@ impl EphemeralOplog {
                 max_operations_before_commit,
                 target,
                 last_added_non_hint_entry: None,
+                committed_bytes: 0,
             })),
             close_fn: Some(close),
         }
@@ -141,6 +143,11 @@ impl Oplog for EphemeralOplog {
         }
     }
 
+    async fn take_committed_bytes(&self) -> u64 {
+        let mut state = self.state.lock().await;
+        std::mem::take(&mut state.committed_bytes)
+    }
+
     async fn current_oplog_index(&self) -> OplogIndex {
         record_oplog_call("current_oplog_index");
         let state = self.state.lock().await;
@@ -194,9 +194,26 @@ pub trait Oplog: Any + Debug + Send + Sync {
     /// Returns the number of dropped entries.
     async fn drop_prefix(&self, last_dropped_id: OplogIndex) -> u64;
 
+    /// Permanently discards a range of entries (both ends inclusive) from every layer of the oplog,
+    /// keeping the entries before and after it.
+    ///
+    /// Used by the oplog retention policy to get rid of history that is no longer needed for
+    /// recovery. Oplogs that are not durable have nothing to discard.
+    ///
+    /// Returns the number of dropped entries.
+    async fn drop_range(&self, _first_dropped_id: OplogIndex, _last_dropped_id: OplogIndex) -> u64 {
+        0
+    }
+
     /// Commits the buffered entries to the oplog
     async fn commit(&self, level: CommitLevel) -> BTreeMap<OplogIndex, OplogEntry>;
 
+    /// Returns the number of bytes the committed entries took in the storage since the previous
+    /// call, and resets the counter
+    async fn take_committed_bytes(&self) -> u64 {
+        0
+    }
+
     /// Returns the current oplog index
     async fn current_oplog_index(&self) -> OplogIndex;
 
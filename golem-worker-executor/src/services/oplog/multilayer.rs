@@ -15,7 +15,7 @@
 use crate::model::ExecutionStatus;
 use crate::services::oplog::ephemeral::EphemeralOplog;
 use crate::services::oplog::multilayer::BackgroundTransferMessage::{
-    TransferFromLower, TransferFromPrimary,
+    DropRange, TransferFromLower, TransferFromPrimary,
 };
 use crate::services::oplog::{
     downcast_oplog, CommitLevel, OpenOplogs, Oplog, OplogConstructor, OplogService,
@@ -96,8 +96,8 @@ pub trait OplogArchive: Debug {
         self.read_range(OplogIndex::INITIAL, last_idx).await
     }
 
-    /// Append a new chunk of entries to the oplog
-    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>);
+    /// Append a new chunk of entries to the oplog, returning the number of bytes stored
+    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) -> u64;
 
     /// Gets the last appended chunk's last index
     async fn current_oplog_index(&self) -> OplogIndex;
@@ -107,6 +107,12 @@ pub trait OplogArchive: Debug {
     /// This should only be called _after_ `append` succeeded in the archive below this one
     async fn drop_prefix(&self, last_dropped_id: OplogIndex) -> u64;
 
+    /// Drop a range of entries (both ends inclusive) from the oplog archive, keeping the entries
+    /// before and after it. A stored chunk is only removed if it is entirely within the range.
+    ///
+    /// Returns the number of dropped entries.
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64;
+
     /// Gets the total number of entries in this oplog archive
     async fn length(&self) -> u64;
 
@@ -654,6 +660,24 @@ impl MultiLayerOplog {
                         done.send(()).unwrap()
                     }
                 }
+                DropRange {
+                    first_dropped_idx,
+                    last_dropped_idx,
+                    primary,
+                    done,
+                } => {
+                    info!("Dropping oplog entries between {first_dropped_idx} and {last_dropped_idx} from all oplog layers");
+
+                    let mut dropped_entries = primary
+                        .drop_range(first_dropped_idx, last_dropped_idx)
+                        .await;
+                    for layer in &lower {
+                        dropped_entries +=
+                            layer.drop_range(first_dropped_idx, last_dropped_idx).await;
+                    }
+
+                    let _ = done.send(dropped_entries);
+                }
             }
         }
     }
@@ -763,6 +787,23 @@ impl Oplog for MultiLayerOplog {
         dropped_entries
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        // Dropping through the background transfer fiber, so it cannot interleave with an
+        // ongoing transfer between the layers
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        self.transfer
+            .send(DropRange {
+                first_dropped_idx: first_dropped_id,
+                last_dropped_idx: last_dropped_id,
+                primary: self.primary.clone(),
+                done: done_tx,
+            })
+            .expect("Failed to enqueue dropping oplog entries");
+        done_rx
+            .await
+            .expect("Failed to wait for dropping oplog entries")
+    }
+
     async fn commit(&self, level: CommitLevel) -> BTreeMap<OplogIndex, OplogEntry> {
         let result = self.primary.commit(level).await;
 
@@ -781,6 +822,10 @@ impl Oplog for MultiLayerOplog {
         result
     }
 
+    async fn take_committed_bytes(&self) -> u64 {
+        self.primary.take_committed_bytes().await
+    }
+
     async fn current_oplog_index(&self) -> OplogIndex {
         self.primary.current_oplog_index().await
     }
@@ -884,6 +929,12 @@ enum BackgroundTransferMessage {
         keep_alive: Option<Arc<dyn Oplog>>,
         done: Option<Sender<()>>,
     },
+    DropRange {
+        first_dropped_idx: OplogIndex,
+        last_dropped_idx: OplogIndex,
+        primary: Arc<dyn Oplog>,
+        done: Sender<u64>,
+    },
 }
 
 #[async_trait]
@@ -943,10 +994,10 @@ impl OplogArchive for WrappedOplogArchive {
         self.archive.read(idx, n).await
     }
 
-    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) {
+    async fn append(&self, chunk: Vec<(OplogIndex, OplogEntry)>) -> u64 {
         if !chunk.is_empty() {
             let last_idx = chunk.last().unwrap().0;
-            self.archive.append(chunk).await;
+            let bytes = self.archive.append(chunk).await;
             let old_count = self.entry_count.fetch_add(1, Ordering::AcqRel); // Note: the whole chunk is stored as one entry, so incrementing only by one
             let count = old_count + 1;
             if count >= self.entry_count_limit {
@@ -960,6 +1011,9 @@ impl OplogArchive for WrappedOplogArchive {
                 // Resetting the counter, otherwise it would trigger additional transfers until the background process finishes
                 self.entry_count.store(0, Ordering::Release);
             }
+            bytes
+        } else {
+            0
         }
     }
 
@@ -976,6 +1030,18 @@ impl OplogArchive for WrappedOplogArchive {
         dropped_entries
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        let dropped_entries = self
+            .archive
+            .drop_range(first_dropped_id, last_dropped_id)
+            .await;
+        let new_length = self.archive.length().await;
+        let old_entry_count = self.entry_count.load(Ordering::Acquire);
+        let new_entry_count = min(new_length, old_entry_count);
+        self.entry_count.store(new_entry_count, Ordering::Release);
+        dropped_entries
+    }
+
     async fn length(&self) -> u64 {
         self.archive.length().await
     }
@@ -1023,7 +1089,7 @@ impl BackgroundTransfer for BackgroundTransferFromPrimary {
     }
 
     async fn append_target(&self, entries: Vec<(OplogIndex, OplogEntry)>) {
-        self.lower.first().append(entries).await
+        self.lower.first().append(entries).await;
     }
 
     async fn drop_source_prefix(&self, last_dropped_id: OplogIndex) {
@@ -1065,7 +1131,7 @@ impl BackgroundTransfer for BackgroundTransferBetweenLowers {
     }
 
     async fn append_target(&self, entries: Vec<(OplogIndex, OplogEntry)>) {
-        self.target_layer.append(entries).await
+        self.target_layer.append(entries).await;
     }
 
     async fn drop_source_prefix(&self, last_dropped_id: OplogIndex) {
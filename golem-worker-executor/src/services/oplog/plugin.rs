@@ -30,6 +30,7 @@ use golem_common::model::agent::Principal;
 use golem_common::model::component::{ComponentId, ComponentRevision, InstalledPlugin};
 use golem_common::model::environment::EnvironmentId;
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::invocation_queue::InvocationPriority;
 use golem_common::model::oplog::{
     OplogEntry, OplogIndex, PayloadId, PersistenceLevel, RawOplogPayload,
 };
@@ -222,18 +223,21 @@ impl<Ctx: WorkerCtx> OplogProcessorPlugin for PerExecutorOplogProcessorPlugin<Ct
 
         let account_id = worker_metadata.created_by;
         worker
-            .invoke(AgentInvocation::ProcessOplogEntries {
-                idempotency_key,
-                account_id,
-                config: running_plugin
-                    .configuration
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.clone()))
-                    .collect(),
-                metadata: worker_metadata.into(),
-                first_entry_index: initial_oplog_index,
-                entries,
-            })
+            .invoke(
+                AgentInvocation::ProcessOplogEntries {
+                    idempotency_key,
+                    account_id,
+                    config: running_plugin
+                        .configuration
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect(),
+                    metadata: worker_metadata.into(),
+                    first_entry_index: initial_oplog_index,
+                    entries,
+                },
+                InvocationPriority::Normal,
+            )
             .await?;
 
         Ok(())
@@ -604,6 +608,12 @@ impl Oplog for ForwardingOplog {
         self.inner.drop_prefix(last_dropped_id).await
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        self.inner
+            .drop_range(first_dropped_id, last_dropped_id)
+            .await
+    }
+
     async fn commit(&self, level: CommitLevel) -> BTreeMap<OplogIndex, OplogEntry> {
         let mut state = self.state.lock().await;
         let result = self.inner.commit(level).await;
@@ -614,6 +624,10 @@ impl Oplog for ForwardingOplog {
         result
     }
 
+    async fn take_committed_bytes(&self) -> u64 {
+        self.inner.take_committed_bytes().await
+    }
+
     async fn current_oplog_index(&self) -> OplogIndex {
         self.inner.current_oplog_index().await
     }
@@ -718,14 +732,17 @@ impl ForwardingOplogState {
                 )
                 .await?;
 
+            // only oplog processor plugins are receiving the entries, other installed plugins
+            // (component transformers, libraries) have already been applied to the component
             let plugins_to_send_to = component_metadata
                 .installed_plugins
                 .into_iter()
                 .filter(|p| {
-                    metadata
-                        .last_known_status
-                        .active_plugins
-                        .contains(&p.priority)
+                    p.oplog_processor_component_id.is_some()
+                        && metadata
+                            .last_known_status
+                            .active_plugins
+                            .contains(&p.priority)
                 });
 
             for plugin in plugins_to_send_to {
@@ -493,6 +493,7 @@ impl PrimaryOplog {
                 owned_worker_id,
                 last_added_non_hint_entry: None,
                 persistence_level: PersistenceLevel::Smart,
+                committed_bytes: 0,
             })),
             key,
             close: Some(close),
@@ -514,6 +515,7 @@ struct PrimaryOplogState {
     owned_worker_id: OwnedWorkerId,
     last_added_non_hint_entry: Option<OplogIndex>,
     persistence_level: PersistenceLevel,
+    committed_bytes: u64,
 }
 
 impl PrimaryOplogState {
@@ -528,7 +530,8 @@ impl PrimaryOplogState {
             last_idx = oplog_idx;
         }
         let pairs_ref: Vec<(u64, &OplogEntry)> = pairs.iter().map(|(id, e)| (*id, e)).collect();
-        self.indexed_storage
+        let appended_bytes = self
+            .indexed_storage
             .with_entity("oplog", "append", "entry")
             .append_many(
                 IndexedStorageNamespace::OpLog {
@@ -547,6 +550,7 @@ impl PrimaryOplogState {
         drop(pairs_ref);
 
         self.last_committed_idx = last_idx;
+        self.committed_bytes += appended_bytes;
         BTreeMap::from_iter(
             pairs
                 .into_iter()
@@ -704,6 +708,28 @@ impl PrimaryOplogState {
             });
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) {
+        record_oplog_call("drop_range");
+
+        self.indexed_storage
+            .with("oplog", "drop_range")
+            .drop_range(
+                IndexedStorageNamespace::OpLog {
+                    worker_id: self.owned_worker_id.worker_id(),
+                },
+                &self.key,
+                first_dropped_id.into(),
+                last_dropped_id.into(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to drop range for {} in indexed storage: {err}",
+                    self.key
+                )
+            });
+    }
+
     async fn length(&self) -> u64 {
         record_oplog_call("length");
 
@@ -773,11 +799,24 @@ impl Oplog for PrimaryOplog {
         before - remaining
     }
 
+    async fn drop_range(&self, first_dropped_id: OplogIndex, last_dropped_id: OplogIndex) -> u64 {
+        let state = self.state.lock().await;
+        let before = state.length().await;
+        state.drop_range(first_dropped_id, last_dropped_id).await;
+        let remaining = state.length().await;
+        before - remaining
+    }
+
     async fn commit(&self, level: CommitLevel) -> BTreeMap<OplogIndex, OplogEntry> {
         let mut state = self.state.lock().await;
         state.commit(level).await
     }
 
+    async fn take_committed_bytes(&self) -> u64 {
+        let mut state = self.state.lock().await;
+        std::mem::take(&mut state.committed_bytes)
+    }
+
     async fn current_oplog_index(&self) -> OplogIndex {
         let state = self.state.lock().await;
         state.last_oplog_idx
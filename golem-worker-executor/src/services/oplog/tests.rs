@@ -127,6 +127,47 @@ async fn open_add_and_read_back(_tracing: &Tracing) {
     );
 }
 
+#[test]
+async fn committed_bytes_are_reported_once(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let oplog_service =
+        PrimaryOplogService::new(indexed_storage, blob_storage, 100, 100, 100).await;
+    let account_id = AccountId::new();
+    let environment_id = EnvironmentId::new();
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(environment_id, &worker_id);
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            None,
+            WorkerMetadata::default(worker_id.clone(), account_id, environment_id),
+            default_last_known_status(),
+            default_execution_status(AgentMode::Durable),
+        )
+        .await;
+
+    let entry1 = OplogEntry::suspend().rounded();
+    let entry2 = OplogEntry::exited().rounded();
+    let expected_bytes = (golem_common::serialization::serialize(&entry1)
+        .unwrap()
+        .len()
+        + golem_common::serialization::serialize(&entry2)
+            .unwrap()
+            .len()) as u64;
+
+    oplog.add(entry1).await;
+    oplog.add(entry2).await;
+    check!(oplog.take_committed_bytes().await == 0);
+
+    oplog.commit(CommitLevel::Always).await;
+    check!(oplog.take_committed_bytes().await == expected_bytes);
+    check!(oplog.take_committed_bytes().await == 0);
+}
+
 #[test]
 async fn open_add_and_read_back_many(_tracing: &Tracing) {
     let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
@@ -944,6 +985,115 @@ async fn read_from_archive_impl(use_blob: bool) {
     assert_eq!(last10, original_last10);
 }
 
+#[test]
+async fn drop_range_from_archive(_tracing: &Tracing) {
+    drop_range_from_archive_impl(false).await;
+}
+
+#[test]
+async fn blob_drop_range_from_archive(_tracing: &Tracing) {
+    drop_range_from_archive_impl(true).await;
+}
+
+async fn drop_range_from_archive_impl(use_blob: bool) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService> = if use_blob {
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 1))
+    } else {
+        Arc::new(CompressedOplogArchiveService::new(
+            indexed_storage.clone(),
+            1,
+        ))
+    };
+    let tertiary_layer: Arc<dyn OplogArchiveService> = if use_blob {
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2))
+    } else {
+        Arc::new(CompressedOplogArchiveService::new(
+            indexed_storage.clone(),
+            2,
+        ))
+    };
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        10,
+        10,
+    ));
+    let account_id = AccountId::new();
+    let environment_id = EnvironmentId::new();
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(environment_id, &worker_id);
+
+    let oplog = oplog_service
+        .open(
+            &owned_worker_id,
+            None,
+            WorkerMetadata::default(worker_id.clone(), account_id, environment_id),
+            default_last_known_status(),
+            default_execution_status(AgentMode::Durable),
+        )
+        .await;
+
+    let timestamp = Timestamp::now_utc();
+    let entries: Vec<OplogEntry> = (0..100)
+        .map(|i| {
+            OplogEntry::Error {
+                timestamp,
+                error: WorkerError::Unknown(i.to_string()),
+                retry_from: OplogIndex::NONE,
+            }
+            .rounded()
+        })
+        .collect();
+
+    let initial_oplog_idx = oplog.current_oplog_index().await;
+
+    for entry in &entries {
+        oplog.add(entry.clone()).await;
+    }
+    oplog.commit(CommitLevel::Always).await;
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let archived_length_before = secondary_layer.open(&owned_worker_id).await.length().await
+        + tertiary_layer.open(&owned_worker_id).await.length().await;
+
+    // Keeping the first entry, dropping the next 60
+    let first_dropped_idx = initial_oplog_idx.next().next();
+    let last_dropped_idx = OplogIndex::from_u64(u64::from(initial_oplog_idx) + 61);
+    let dropped_entries = oplog.drop_range(first_dropped_idx, last_dropped_idx).await;
+
+    let archived_length_after = secondary_layer.open(&owned_worker_id).await.length().await
+        + tertiary_layer.open(&owned_worker_id).await.length().await;
+
+    check!(dropped_entries > 0);
+    check!(archived_length_after < archived_length_before);
+
+    let first = oplog_service
+        .read(&owned_worker_id, initial_oplog_idx.next(), 1)
+        .await;
+    check!(first.into_values().collect::<Vec<_>>() == entries[0..1].to_vec());
+
+    let retained = oplog_service
+        .read(&owned_worker_id, last_dropped_idx.next(), 39)
+        .await;
+    check!(retained.into_values().collect::<Vec<_>>() == entries[61..].to_vec());
+
+    let last10 = oplog
+        .read_many(oplog.current_oplog_index().await.subtract(10).next(), 10)
+        .await
+        .into_values()
+        .collect::<Vec<_>>();
+    check!(last10 == entries[90..].to_vec());
+}
+
 #[test]
 async fn read_initial_from_archive(_tracing: &Tracing) {
     crate::services::oplog::tests::read_initial_from_archive_impl(false).await;
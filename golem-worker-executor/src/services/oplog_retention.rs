@@ -0,0 +1,154 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::metrics::oplog::record_oplog_retention_dropped_entries;
+use crate::services::active_workers::ActiveWorkers;
+use crate::services::golem_config::{OplogRetentionConfig, OplogRetentionPolicy};
+use crate::services::worker::WorkerService;
+use crate::services::worker_activator::WorkerActivator;
+use crate::worker::Worker;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::agent::{AgentMode, Principal};
+use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::{OwnedWorkerId, WorkerId};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, span, warn, Instrument, Level};
+
+/// Starts a background loop periodically applying the configured oplog retention policies to
+/// the durable agents of the shards assigned to this executor. Besides the active agents, the
+/// loop also visits the agents which are not loaded in memory but have oplog history before their
+/// latest snapshot. Does nothing if every policy keeps the whole oplog history.
+pub fn start_oplog_retention<Ctx: WorkerCtx>(
+    active_workers: &Arc<ActiveWorkers<Ctx>>,
+    worker_service: Arc<dyn WorkerService>,
+    worker_activator: Arc<dyn WorkerActivator<Ctx>>,
+    config: OplogRetentionConfig,
+    shutdown_token: CancellationToken,
+) {
+    let has_any_policy = !matches!(config.default_policy, OplogRetentionPolicy::KeepAll)
+        || config
+            .agent_types
+            .values()
+            .any(|policy| !matches!(policy, OplogRetentionPolicy::KeepAll));
+    if !has_any_policy {
+        return;
+    }
+
+    let active_workers = Arc::downgrade(active_workers);
+    tokio::spawn(
+        async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown_token.cancelled() => {
+                        info!("Shutdown requested, stopping oplog retention loop");
+                        break;
+                    }
+                    _ = tokio::time::sleep(config.check_interval) => {}
+                }
+                let active_workers = match active_workers.upgrade() {
+                    Some(active_workers) => active_workers,
+                    None => break,
+                };
+
+                for (worker_id, worker) in active_workers.snapshot().await {
+                    apply_oplog_retention(&worker_id, &worker, &config).await;
+                }
+
+                for owned_worker_id in worker_service
+                    .get_oplog_retention_candidates_in_shards()
+                    .await
+                {
+                    if active_workers.try_get(&owned_worker_id).await.is_some() {
+                        continue;
+                    }
+                    match load_suspended(&worker_service, &worker_activator, &owned_worker_id).await
+                    {
+                        Some(worker) => {
+                            if !apply_oplog_retention(&owned_worker_id.worker_id, &worker, &config)
+                                .await
+                            {
+                                worker_service
+                                    .remove_oplog_retention_candidate(&owned_worker_id)
+                                    .await;
+                            }
+                        }
+                        None => {
+                            worker_service
+                                .remove_oplog_retention_candidate(&owned_worker_id)
+                                .await;
+                        }
+                    }
+                }
+            }
+        }
+        .instrument(span!(parent: None, Level::INFO, "Oplog retention loop")),
+    );
+}
+
+/// Applies the retention policy of the worker's agent type. Returns true if the worker may have
+/// more history to discard later.
+async fn apply_oplog_retention<Ctx: WorkerCtx>(
+    worker_id: &WorkerId,
+    worker: &Arc<Worker<Ctx>>,
+    config: &OplogRetentionConfig,
+) -> bool {
+    if worker.agent_mode() != AgentMode::Durable {
+        return false;
+    }
+    let agent_type = worker
+        .agent_id()
+        .map(|agent_id| agent_id.agent_type.as_str());
+    let policy = config.policy_for(agent_type);
+    if matches!(policy, OplogRetentionPolicy::KeepAll) {
+        return false;
+    }
+
+    let dropped_entries = worker.apply_oplog_retention(policy).await;
+    if dropped_entries > 0 {
+        debug!("Discarded {dropped_entries} oplog entries of {worker_id} by the retention policy");
+        record_oplog_retention_dropped_entries(dropped_entries);
+    }
+    worker.has_discardable_oplog_history().await
+}
+
+async fn load_suspended<Ctx: WorkerCtx>(
+    worker_service: &Arc<dyn WorkerService>,
+    worker_activator: &Arc<dyn WorkerActivator<Ctx>>,
+    owned_worker_id: &OwnedWorkerId,
+) -> Option<Arc<Worker<Ctx>>> {
+    let metadata = worker_service.get(owned_worker_id).await?;
+    match worker_activator
+        .get_or_create_suspended(
+            metadata.initial_worker_metadata.created_by,
+            owned_worker_id,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
+            &InvocationContextStack::fresh(),
+            Principal::anonymous(),
+        )
+        .await
+    {
+        Ok(worker) => Some(worker),
+        Err(err) => {
+            warn!(
+                "Failed to load {owned_worker_id} for applying the oplog retention policy: {err}"
+            );
+            None
+        }
+    }
+}
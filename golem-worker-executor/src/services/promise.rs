@@ -31,7 +31,7 @@ use golem_common::model::account::AccountId;
 use golem_common::model::agent::Principal;
 use golem_common::model::invocation_context::InvocationContextStack;
 use golem_common::model::oplog::OplogIndex;
-use golem_common::model::{OwnedWorkerId, PromiseId, WorkerId, WorkerStatus};
+use golem_common::model::{OwnedWorkerId, PromiseId, PromiseOutcome, WorkerId, WorkerStatus};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use std::collections::HashMap;
 #[cfg(test)]
@@ -50,7 +50,7 @@ pub struct PromiseHandle {
 #[derive(Debug)]
 pub struct PromiseHandleInner {
     notify: Notify,
-    state: Mutex<Option<Vec<u8>>>,
+    state: Mutex<Option<PromiseOutcome>>,
 }
 
 impl PromiseHandle {
@@ -78,14 +78,14 @@ impl PromiseHandle {
         self.inner.notify.notified().await;
     }
 
-    pub async fn get(&self) -> Option<Vec<u8>> {
+    pub async fn get(&self) -> Option<PromiseOutcome> {
         let state = self.inner.state.lock().await;
         state.clone()
     }
 
-    pub async fn complete(&self, data: Vec<u8>) {
+    pub async fn complete(&self, outcome: PromiseOutcome) {
         let mut state = self.inner.state.lock().await;
-        *state = Some(data);
+        *state = Some(outcome);
         self.inner.notify.notify_waiters();
     }
 }
@@ -98,12 +98,12 @@ pub trait PromiseService: Send + Sync {
 
     async fn poll(&self, promise_id: PromiseId) -> Result<PromiseHandle, WorkerExecutorError>;
 
-    /// Completes a promise with the given payload.
+    /// Completes a promise with the given outcome (successful completion, failure or expiry).
     /// If the promise was not completed before, it returns true. If the promise was completed before, it returns false.
     async fn complete(
         &self,
         promise_id: PromiseId,
-        data: Vec<u8>,
+        outcome: PromiseOutcome,
         completed_by: AccountId,
     ) -> Result<bool, WorkerExecutorError>;
 
@@ -144,13 +144,13 @@ impl PromiseService for LazyPromiseService {
     async fn complete(
         &self,
         promise_id: PromiseId,
-        data: Vec<u8>,
+        outcome: PromiseOutcome,
         completed_by: AccountId,
     ) -> Result<bool, WorkerExecutorError> {
         let lock = self.0.read().await;
         lock.as_ref()
             .unwrap()
-            .complete(promise_id, data, completed_by)
+            .complete(promise_id, outcome, completed_by)
             .await
     }
 
@@ -189,12 +189,12 @@ impl PromiseRegistry {
         })
     }
 
-    async fn complete(&mut self, id: &PromiseId, data: Vec<u8>) {
+    async fn complete(&mut self, id: &PromiseId, outcome: PromiseOutcome) {
         if let Some(weak) = self.handles.get(id) {
             if let Some(inner) = weak.upgrade() {
                 tokio::spawn(async move {
                     let mut state = inner.state.lock().await;
-                    *state = Some(data.clone());
+                    *state = Some(outcome);
                     inner.notify.notify_waiters();
                 });
             }
@@ -303,7 +303,7 @@ impl PromiseService for DefaultPromiseService {
         };
 
         // Check if already completed in Redis
-        if let Some(RedisPromiseState::Complete(data)) = self
+        if let Some(outcome) = self
             .key_value_storage
             .with_entity("promise", "poll", "promise")
             .get(
@@ -314,8 +314,9 @@ impl PromiseService for DefaultPromiseService {
             )
             .await
             .unwrap_or_else(|err| panic!("failed to get promise {promise_id} from Redis: {err}"))
+            .and_then(RedisPromiseState::into_outcome)
         {
-            handle.complete(data).await;
+            handle.complete(outcome).await;
         }
 
         Ok(handle)
@@ -324,7 +325,7 @@ impl PromiseService for DefaultPromiseService {
     async fn complete(
         &self,
         promise_id: PromiseId,
-        data: Vec<u8>,
+        outcome: PromiseOutcome,
         completed_by: AccountId,
     ) -> Result<bool, WorkerExecutorError> {
         let key = get_promise_result_redis_key(&promise_id);
@@ -341,15 +342,16 @@ impl PromiseService for DefaultPromiseService {
                     worker_id: promise_id.worker_id.clone(),
                 },
                 &key,
-                &RedisPromiseState::Complete(data.clone()),
+                &RedisPromiseState::from(outcome.clone()),
             )
             .await
             .unwrap_or_else(|err| panic!("failed to set promise {promise_id} in Redis: {err}"));
 
-        // Also wake any in-memory handle, ensuring that still running workers that wait on the pollable can continue
-        {
+        // Also wake any in-memory handle, ensuring that still running workers that wait on the pollable can continue.
+        // If the promise was already completed, the handle has to reflect the stored outcome instead of the new one.
+        if written {
             let mut reg = self.registry.lock().await;
-            reg.complete(&promise_id, data.clone()).await;
+            reg.complete(&promise_id, outcome).await;
         }
 
         // Wake up the worker that owns the promise, ensuring that it resumes its work.
@@ -504,11 +506,34 @@ fn get_promise_result_redis_key(promise_id: &PromiseId) -> String {
 pub enum RedisPromiseState {
     Pending,
     Complete(Vec<u8>),
+    Failed(Vec<u8>),
+    Expired,
+}
+
+impl RedisPromiseState {
+    fn into_outcome(self) -> Option<PromiseOutcome> {
+        match self {
+            RedisPromiseState::Pending => None,
+            RedisPromiseState::Complete(data) => Some(PromiseOutcome::Completed(data)),
+            RedisPromiseState::Failed(error) => Some(PromiseOutcome::Failed(error)),
+            RedisPromiseState::Expired => Some(PromiseOutcome::Expired),
+        }
+    }
+}
+
+impl From<PromiseOutcome> for RedisPromiseState {
+    fn from(value: PromiseOutcome) -> Self {
+        match value {
+            PromiseOutcome::Completed(data) => RedisPromiseState::Complete(data),
+            PromiseOutcome::Failed(error) => RedisPromiseState::Failed(error),
+            PromiseOutcome::Expired => RedisPromiseState::Expired,
+        }
+    }
 }
 
 #[cfg(test)]
 pub struct PromiseServiceMock {
-    completed: Arc<Mutex<HashSet<PromiseId>>>,
+    completed: Arc<Mutex<HashMap<PromiseId, PromiseOutcome>>>,
 }
 
 #[cfg(test)]
@@ -522,12 +547,16 @@ impl Default for PromiseServiceMock {
 impl PromiseServiceMock {
     pub fn new() -> Self {
         Self {
-            completed: Arc::new(Mutex::new(HashSet::new())),
+            completed: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
     pub async fn all_completed(&self) -> HashSet<PromiseId> {
-        self.completed.lock().await.clone()
+        self.completed.lock().await.keys().cloned().collect()
+    }
+
+    pub async fn outcome(&self, promise_id: &PromiseId) -> Option<PromiseOutcome> {
+        self.completed.lock().await.get(promise_id).cloned()
     }
 }
 
@@ -545,12 +574,107 @@ impl PromiseService for PromiseServiceMock {
     async fn complete(
         &self,
         promise_id: PromiseId,
-        _data: Vec<u8>,
+        outcome: PromiseOutcome,
         _completed_by: AccountId,
     ) -> Result<bool, WorkerExecutorError> {
-        self.completed.lock().await.insert(promise_id);
-        Ok(true)
+        let mut completed = self.completed.lock().await;
+        if completed.contains_key(&promise_id) {
+            Ok(false)
+        } else {
+            completed.insert(promise_id, outcome);
+            Ok(true)
+        }
     }
 
     async fn cleanup(&self) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::services::promise::{DefaultPromiseService, PromiseService, PromiseWorkerAccess};
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use async_trait::async_trait;
+    use golem_common::model::account::AccountId;
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{PromiseId, PromiseOutcome, WorkerId};
+    use golem_service_base::error::worker_executor::WorkerExecutorError;
+    use std::sync::Arc;
+    use test_r::test;
+    use uuid::Uuid;
+
+    struct NoopPromiseWorkerAccess;
+
+    #[async_trait]
+    impl PromiseWorkerAccess for NoopPromiseWorkerAccess {
+        async fn activate_worker_if_needed(
+            &self,
+            _promise_id: &PromiseId,
+            _completed_by: AccountId,
+        ) -> Result<(), WorkerExecutorError> {
+            Ok(())
+        }
+    }
+
+    async fn created_promise(service: &DefaultPromiseService) -> PromiseId {
+        let worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "promise-test".to_string(),
+        };
+        service.create(&worker_id, OplogIndex::from_u64(3)).await
+    }
+
+    #[test]
+    async fn failed_promise_keeps_its_outcome() {
+        let service = DefaultPromiseService::new(
+            Arc::new(InMemoryKeyValueStorage::new()),
+            Arc::new(NoopPromiseWorkerAccess),
+        );
+        let promise_id = created_promise(&service).await;
+
+        let pending = service.poll(promise_id.clone()).await.unwrap();
+        assert_eq!(pending.get().await, None);
+        drop(pending);
+
+        assert!(service
+            .complete(
+                promise_id.clone(),
+                PromiseOutcome::Failed(vec![1]),
+                AccountId::new()
+            )
+            .await
+            .unwrap());
+        assert!(!service
+            .complete(
+                promise_id.clone(),
+                PromiseOutcome::Completed(vec![2]),
+                AccountId::new()
+            )
+            .await
+            .unwrap());
+
+        let handle = service.poll(promise_id).await.unwrap();
+        assert_eq!(handle.get().await, Some(PromiseOutcome::Failed(vec![1])));
+    }
+
+    #[test]
+    async fn expired_promise_has_the_expired_outcome() {
+        let service = DefaultPromiseService::new(
+            Arc::new(InMemoryKeyValueStorage::new()),
+            Arc::new(NoopPromiseWorkerAccess),
+        );
+        let promise_id = created_promise(&service).await;
+
+        assert!(service
+            .complete(
+                promise_id.clone(),
+                PromiseOutcome::Expired,
+                AccountId::new()
+            )
+            .await
+            .unwrap());
+
+        let handle = service.poll(promise_id).await.unwrap();
+        assert_eq!(handle.get().await, Some(PromiseOutcome::Expired));
+    }
+}
@@ -0,0 +1,1126 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::golem_config::PubSubConfig;
+use crate::services::scheduler::SchedulerWorkerAccess;
+use crate::services::shard::ShardService;
+use crate::services::worker::WorkerService;
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use async_trait::async_trait;
+use desert_rust::BinaryCodec;
+use golem_common::model::account::AccountId;
+use golem_common::model::agent::{Principal, UntypedDataValue};
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::pubsub::validate_topic_name;
+use golem_common::model::{AgentInvocation, IdempotencyKey, OwnedWorkerId, Timestamp, WorkerId};
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, span, warn, Instrument, Level};
+use uuid::Uuid;
+
+/// Durable publish/subscribe topics scoped to environments.
+///
+/// Messages published to a topic are delivered to each subscribed agent by enqueueing an
+/// invocation of its handler method. Each subscription keeps its own delivery offset, which is
+/// only advanced after the invocation got enqueued, so messages are delivered at least once.
+#[async_trait]
+pub trait PubSubService: Send + Sync {
+    /// Publishes a message to a topic of an environment, returning its offset within the topic
+    async fn publish(
+        &self,
+        environment_id: EnvironmentId,
+        topic: &str,
+        message: UntypedDataValue,
+    ) -> Result<u64, WorkerExecutorError>;
+
+    /// Subscribes an agent to a topic of its environment. Only the messages published after
+    /// subscribing are delivered. Subscribing again replaces the handler method, keeping the
+    /// delivery offset.
+    async fn subscribe(
+        &self,
+        account_id: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+        handler_method: String,
+    ) -> Result<(), WorkerExecutorError>;
+
+    /// Unsubscribes an agent from a topic. Returns false if it was not subscribed to it.
+    async fn unsubscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+    ) -> Result<bool, WorkerExecutorError>;
+
+    /// Enqueues the undelivered messages of the subscriptions of the agents owned by this
+    /// executor, and deletes the messages which were delivered to all the subscribers. The
+    /// background loop calls this periodically and whenever a message gets published.
+    async fn deliver(&self) -> Result<(), String>;
+}
+
+/// A topic which has messages or subscribers, as stored in the key-value storage
+#[derive(Debug, Clone, PartialEq, BinaryCodec)]
+#[desert(evolution())]
+struct TopicKey {
+    environment_id: EnvironmentId,
+    topic: String,
+}
+
+/// A published message as stored in the key-value storage
+#[derive(Debug, Clone, PartialEq, BinaryCodec)]
+#[desert(evolution())]
+struct TopicMessageRecord {
+    offset: u64,
+    message: UntypedDataValue,
+    published_at: Timestamp,
+}
+
+/// The subscription of an agent to a topic, as stored in the key-value storage
+#[derive(Debug, Clone, PartialEq, BinaryCodec)]
+#[desert(evolution())]
+struct SubscriptionRecord {
+    account_id: AccountId,
+    owned_worker_id: OwnedWorkerId,
+    handler_method: String,
+    /// The offset of the first message not enqueued to the subscriber yet
+    next_offset: u64,
+}
+
+impl SubscriptionRecord {
+    /// The invocation delivering a message to the subscriber, with an idempotency key derived
+    /// from the subscription and the message offset, so redelivering the same message after a
+    /// failure does not lead to duplicate invocations
+    fn invocation_for(&self, topic: &TopicKey, message: TopicMessageRecord) -> AgentInvocation {
+        AgentInvocation::AgentMethod {
+            idempotency_key: IdempotencyKey::from_uuid(Uuid::new_v5(
+                &Uuid::NAMESPACE_OID,
+                format!(
+                    "pubsub:{}:{}:{}:{}",
+                    topic.environment_id,
+                    topic.topic,
+                    self.owned_worker_id.worker_id,
+                    message.offset
+                )
+                .as_bytes(),
+            )),
+            method_name: self.handler_method.clone(),
+            input: message.message,
+            invocation_context: InvocationContextStack::fresh(),
+            principal: Principal::anonymous(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PubSubServiceDefault {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    shard_service: Arc<dyn ShardService>,
+    worker_access: Arc<dyn SchedulerWorkerAccess + Send + Sync>,
+    worker_service: Arc<dyn WorkerService>,
+    config: PubSubConfig,
+    published: Arc<Notify>,
+    background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl PubSubServiceDefault {
+    pub fn new(
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+        shard_service: Arc<dyn ShardService>,
+        worker_access: Arc<dyn SchedulerWorkerAccess + Send + Sync>,
+        worker_service: Arc<dyn WorkerService>,
+        config: PubSubConfig,
+        shutdown_token: CancellationToken,
+    ) -> Arc<Self> {
+        let svc = Arc::new(Self {
+            key_value_storage,
+            shard_service,
+            worker_access,
+            worker_service,
+            config,
+            published: Arc::new(Notify::new()),
+            background_handle: Arc::new(Mutex::new(None)),
+        });
+        let background_handle = {
+            let svc_weak = Arc::downgrade(&svc);
+            let published = svc.published.clone();
+            let delivery_interval = svc.config.delivery_interval;
+            tokio::spawn(
+                async move {
+                    loop {
+                        tokio::select! {
+                            biased;
+                            _ = shutdown_token.cancelled() => {
+                                info!("Shutdown requested, stopping pub/sub delivery loop");
+                                break;
+                            }
+                            _ = tokio::time::sleep(delivery_interval) => {}
+                            _ = published.notified() => {}
+                        }
+                        let svc = match svc_weak.upgrade() {
+                            Some(s) => s,
+                            None => {
+                                info!("Pub/sub service dropped, stopping delivery loop");
+                                break;
+                            }
+                        };
+                        if svc.shard_service.is_ready() {
+                            let r = svc.deliver().await;
+                            if let Err(err) = r {
+                                error!(err, "Error in pub/sub delivery background task");
+                            }
+                        } else {
+                            warn!("Skipping pub/sub delivery, shard service is not ready")
+                        }
+                    }
+                }
+                .instrument(span!(parent: None, Level::INFO, "Pub/sub delivery loop")),
+            )
+        };
+        *svc.background_handle.lock().unwrap() = Some(background_handle);
+
+        svc
+    }
+
+    /// Delivers the pending messages of a topic to its subscribers owned by this executor, then
+    /// deletes the messages which are not needed anymore
+    async fn deliver_topic(&self, topic: &TopicKey) -> Result<(), String> {
+        let head = self.get_offset(&Self::head_key(topic)).await?;
+
+        let subscribers: Vec<WorkerId> = self
+            .key_value_storage
+            .with_entity("pubsub", "deliver", "subscriber")
+            .members_of_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscribers_key(topic),
+            )
+            .await?;
+
+        let mut min_offset = head;
+        // ! Do not exit early because of a failing subscriber, as it would block the others
+        for worker_id in subscribers {
+            let Some(subscription) = self.get_subscription(topic, &worker_id).await? else {
+                continue;
+            };
+
+            let next_offset = if self.shard_service.check_worker(&worker_id).is_ok() {
+                match self.deliver_to_subscriber(topic, subscription, head).await {
+                    Ok(next_offset) => next_offset,
+                    Err(err) => {
+                        error!(
+                            worker_id = worker_id.to_string(),
+                            topic = topic.topic,
+                            "Failed to deliver messages: {err}"
+                        );
+                        continue;
+                    }
+                }
+            } else {
+                Some(subscription.next_offset)
+            };
+
+            if let Some(next_offset) = next_offset {
+                min_offset = min_offset.min(next_offset);
+            }
+        }
+
+        self.delete_delivered(topic, min_offset).await
+    }
+
+    /// Enqueues the messages of the topic to a subscriber, starting from its next offset.
+    /// Returns the subscriber's new next offset, or `None` if the subscription got removed.
+    async fn deliver_to_subscriber(
+        &self,
+        topic: &TopicKey,
+        subscription: SubscriptionRecord,
+        head: u64,
+    ) -> Result<Option<u64>, String> {
+        let owned_worker_id = subscription.owned_worker_id.clone();
+
+        if subscription.next_offset >= head {
+            return Ok(Some(subscription.next_offset));
+        }
+
+        if self.worker_service.get(&owned_worker_id).await.is_none() {
+            // Delivering to a deleted agent would create it again
+            info!(
+                worker_id = owned_worker_id.to_string(),
+                topic = topic.topic,
+                "Removing the subscription of a deleted agent"
+            );
+            self.remove_subscription(topic, &owned_worker_id.worker_id)
+                .await?;
+            return Ok(None);
+        }
+
+        let last =
+            head.min(subscription.next_offset + self.config.max_messages_per_delivery as u64);
+        let offsets = (subscription.next_offset..last).collect::<Vec<_>>();
+        let messages: Vec<Option<TopicMessageRecord>> = self
+            .key_value_storage
+            .with_entity("pubsub", "deliver", "topic_message")
+            .get_many(
+                KeyValueStorageNamespace::PubSub,
+                offsets
+                    .iter()
+                    .map(|offset| Self::message_key(topic, *offset))
+                    .collect(),
+            )
+            .await?;
+
+        let mut next_offset = subscription.next_offset;
+        for (offset, message) in offsets.into_iter().zip(messages) {
+            // Messages can only be missing if they were already deleted, which happens
+            // when they are not needed by any of the subscribers
+            if let Some(message) = message {
+                debug!(
+                    worker_id = owned_worker_id.to_string(),
+                    topic = topic.topic,
+                    offset,
+                    "Delivering message"
+                );
+                let result = self
+                    .worker_access
+                    .enqueue_invocation(
+                        subscription.account_id,
+                        &owned_worker_id,
+                        subscription.invocation_for(topic, message),
+                    )
+                    .await;
+                if let Err(err) = result {
+                    // Retried in the next round, starting from this message
+                    error!(
+                        worker_id = owned_worker_id.to_string(),
+                        topic = topic.topic,
+                        offset,
+                        "Failed to enqueue message: {err}"
+                    );
+                    break;
+                }
+            }
+            next_offset = offset + 1;
+        }
+
+        if next_offset > subscription.next_offset {
+            // Re-reading the subscription, as it may have been changed or removed meanwhile
+            match self
+                .get_subscription(topic, &owned_worker_id.worker_id)
+                .await?
+            {
+                Some(mut current) => {
+                    current.next_offset = current.next_offset.max(next_offset);
+                    self.key_value_storage
+                        .with_entity("pubsub", "deliver", "subscription")
+                        .set(
+                            KeyValueStorageNamespace::PubSub,
+                            &Self::subscription_key(topic, &owned_worker_id.worker_id),
+                            &current,
+                        )
+                        .await?;
+                    Ok(Some(current.next_offset))
+                }
+                None => Ok(None),
+            }
+        } else {
+            Ok(Some(next_offset))
+        }
+    }
+
+    /// Deletes the messages below the given offset which are older than the retention period.
+    ///
+    /// Keeping the messages for the retention period makes sure a publisher which read an
+    /// outdated head offset cannot reuse the offset of a deleted message.
+    async fn delete_delivered(&self, topic: &TopicKey, min_offset: u64) -> Result<(), String> {
+        let tail = self.get_offset(&Self::tail_key(topic)).await?;
+        if tail >= min_offset {
+            return Ok(());
+        }
+
+        let retained_after = Timestamp::now_utc()
+            .to_millis()
+            .saturating_sub(self.config.message_retention.as_millis() as u64);
+
+        let last = min_offset.min(tail + self.config.max_messages_per_delivery as u64);
+        let offsets = (tail..last).collect::<Vec<_>>();
+        let messages: Vec<Option<TopicMessageRecord>> = self
+            .key_value_storage
+            .with_entity("pubsub", "delete_delivered", "topic_message")
+            .get_many(
+                KeyValueStorageNamespace::PubSub,
+                offsets
+                    .iter()
+                    .map(|offset| Self::message_key(topic, *offset))
+                    .collect(),
+            )
+            .await?;
+
+        let mut new_tail = tail;
+        let mut to_delete = Vec::new();
+        for (offset, message) in offsets.into_iter().zip(messages) {
+            match message {
+                Some(message) if message.published_at.to_millis() > retained_after => break,
+                Some(_) => to_delete.push(Self::message_key(topic, offset)),
+                None => {}
+            }
+            new_tail = offset + 1;
+        }
+
+        if new_tail > tail {
+            // The tail is moved first, so publishers never claim the offsets being deleted
+            self.advance_offset(&Self::tail_key(topic), new_tail)
+                .await?;
+            if !to_delete.is_empty() {
+                self.key_value_storage
+                    .with("pubsub", "delete_delivered")
+                    .del_many(KeyValueStorageNamespace::PubSub, to_delete)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_offset(&self, key: &str) -> Result<u64, String> {
+        Ok(self
+            .key_value_storage
+            .with_entity("pubsub", "get_offset", "offset")
+            .get(KeyValueStorageNamespace::PubSub, key)
+            .await?
+            .unwrap_or(0))
+    }
+
+    /// Moves the offset stored at the key forward to the given one, unless it is already beyond it
+    async fn advance_offset(&self, key: &str, offset: u64) -> Result<(), String> {
+        let storage = self
+            .key_value_storage
+            .with_entity("pubsub", "advance_offset", "offset");
+        loop {
+            let current: Option<u64> = storage.get(KeyValueStorageNamespace::PubSub, key).await?;
+            if current.is_some_and(|current| current >= offset) {
+                return Ok(());
+            }
+            if storage
+                .compare_and_set(
+                    KeyValueStorageNamespace::PubSub,
+                    key,
+                    current.as_ref(),
+                    &offset,
+                )
+                .await?
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn get_subscription(
+        &self,
+        topic: &TopicKey,
+        worker_id: &WorkerId,
+    ) -> Result<Option<SubscriptionRecord>, String> {
+        self.key_value_storage
+            .with_entity("pubsub", "get_subscription", "subscription")
+            .get(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscription_key(topic, worker_id),
+            )
+            .await
+    }
+
+    async fn remove_subscription(
+        &self,
+        topic: &TopicKey,
+        worker_id: &WorkerId,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with("pubsub", "remove_subscription")
+            .del(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscription_key(topic, worker_id),
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity("pubsub", "remove_subscription", "subscriber")
+            .remove_from_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscribers_key(topic),
+                worker_id,
+            )
+            .await
+    }
+
+    async fn register_topic(&self, topic: &TopicKey) -> Result<(), String> {
+        self.key_value_storage
+            .with_entity("pubsub", "register_topic", "topic")
+            .add_to_set(KeyValueStorageNamespace::PubSub, Self::TOPICS_KEY, topic)
+            .await
+    }
+
+    fn topic_key(
+        environment_id: EnvironmentId,
+        topic: &str,
+    ) -> Result<TopicKey, WorkerExecutorError> {
+        validate_topic_name(topic).map_err(WorkerExecutorError::invalid_request)?;
+        Ok(TopicKey {
+            environment_id,
+            topic: topic.to_string(),
+        })
+    }
+
+    const TOPICS_KEY: &'static str = "pubsub:topics";
+
+    fn head_key(topic: &TopicKey) -> String {
+        format!("pubsub:topic:{}:{}:head", topic.environment_id, topic.topic)
+    }
+
+    fn tail_key(topic: &TopicKey) -> String {
+        format!("pubsub:topic:{}:{}:tail", topic.environment_id, topic.topic)
+    }
+
+    fn message_key(topic: &TopicKey, offset: u64) -> String {
+        format!(
+            "pubsub:topic:{}:{}:message:{offset}",
+            topic.environment_id, topic.topic
+        )
+    }
+
+    fn subscribers_key(topic: &TopicKey) -> String {
+        format!(
+            "pubsub:topic:{}:{}:subscribers",
+            topic.environment_id, topic.topic
+        )
+    }
+
+    fn subscription_key(topic: &TopicKey, worker_id: &WorkerId) -> String {
+        format!(
+            "pubsub:topic:{}:{}:subscription:{worker_id}",
+            topic.environment_id, topic.topic
+        )
+    }
+}
+
+impl Drop for PubSubServiceDefault {
+    fn drop(&mut self) {
+        if let Some(handle) = self.background_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl PubSubService for PubSubServiceDefault {
+    async fn publish(
+        &self,
+        environment_id: EnvironmentId,
+        topic: &str,
+        message: UntypedDataValue,
+    ) -> Result<u64, WorkerExecutorError> {
+        let topic = Self::topic_key(environment_id, topic)?;
+        let to_runtime_error =
+            |err: String| WorkerExecutorError::runtime(format!("Failed to publish message: {err}"));
+
+        let head = self
+            .get_offset(&Self::head_key(&topic))
+            .await
+            .map_err(to_runtime_error)?;
+        let tail = self
+            .get_offset(&Self::tail_key(&topic))
+            .await
+            .map_err(to_runtime_error)?;
+
+        // The head offset is only a hint, the offset is claimed by atomically creating the
+        // message's key; concurrent publishers skip the offsets claimed by others
+        let mut record = TopicMessageRecord {
+            offset: head.max(tail),
+            message,
+            published_at: Timestamp::now_utc(),
+        };
+        while !self
+            .key_value_storage
+            .with_entity("pubsub", "publish", "topic_message")
+            .set_if_not_exists(
+                KeyValueStorageNamespace::PubSub,
+                &Self::message_key(&topic, record.offset),
+                &record,
+            )
+            .await
+            .map_err(to_runtime_error)?
+        {
+            record.offset += 1;
+        }
+
+        // Concurrent publishers may finish in any order, so the head is never moved backwards
+        self.advance_offset(&Self::head_key(&topic), record.offset + 1)
+            .await
+            .map_err(to_runtime_error)?;
+        self.register_topic(&topic)
+            .await
+            .map_err(to_runtime_error)?;
+
+        self.published.notify_one();
+        Ok(record.offset)
+    }
+
+    async fn subscribe(
+        &self,
+        account_id: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+        handler_method: String,
+    ) -> Result<(), WorkerExecutorError> {
+        let topic = Self::topic_key(owned_worker_id.environment_id, topic)?;
+        let to_runtime_error =
+            |err: String| WorkerExecutorError::runtime(format!("Failed to subscribe: {err}"));
+
+        let record = match self
+            .get_subscription(&topic, &owned_worker_id.worker_id)
+            .await
+            .map_err(to_runtime_error)?
+        {
+            Some(existing) => SubscriptionRecord {
+                handler_method,
+                ..existing
+            },
+            None => {
+                let head = self
+                    .get_offset(&Self::head_key(&topic))
+                    .await
+                    .map_err(to_runtime_error)?;
+                SubscriptionRecord {
+                    account_id,
+                    owned_worker_id: owned_worker_id.clone(),
+                    handler_method,
+                    next_offset: head,
+                }
+            }
+        };
+
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "subscription")
+            .set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscription_key(&topic, &owned_worker_id.worker_id),
+                &record,
+            )
+            .await
+            .map_err(to_runtime_error)?;
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "subscriber")
+            .add_to_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscribers_key(&topic),
+                &owned_worker_id.worker_id,
+            )
+            .await
+            .map_err(to_runtime_error)?;
+        self.register_topic(&topic)
+            .await
+            .map_err(to_runtime_error)?;
+
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+    ) -> Result<bool, WorkerExecutorError> {
+        let topic = Self::topic_key(owned_worker_id.environment_id, topic)?;
+        let to_runtime_error =
+            |err: String| WorkerExecutorError::runtime(format!("Failed to unsubscribe: {err}"));
+
+        let existed = self
+            .key_value_storage
+            .with("pubsub", "unsubscribe")
+            .exists(
+                KeyValueStorageNamespace::PubSub,
+                &Self::subscription_key(&topic, &owned_worker_id.worker_id),
+            )
+            .await
+            .map_err(to_runtime_error)?;
+        if existed {
+            self.remove_subscription(&topic, &owned_worker_id.worker_id)
+                .await
+                .map_err(to_runtime_error)?;
+        }
+        Ok(existed)
+    }
+
+    async fn deliver(&self) -> Result<(), String> {
+        let topics: Vec<TopicKey> = self
+            .key_value_storage
+            .with_entity("pubsub", "deliver", "topic")
+            .members_of_set(KeyValueStorageNamespace::PubSub, Self::TOPICS_KEY)
+            .await?;
+
+        for topic in topics {
+            if let Err(err) = self.deliver_topic(&topic).await {
+                error!(
+                    environment_id = topic.environment_id.to_string(),
+                    topic = topic.topic,
+                    "Failed to process topic: {err}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::golem_config::PubSubConfig;
+    use crate::services::oplog::Oplog;
+    use crate::services::pubsub::{PubSubService, PubSubServiceDefault, TopicKey};
+    use crate::services::scheduler::SchedulerWorkerAccess;
+    use crate::services::shard::{ShardService, ShardServiceDefault};
+    use crate::services::worker::{GetWorkerMetadataResult, IndexedWorker, WorkerService};
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use crate::storage::keyvalue::{KeyValueStorageLabelledApi, KeyValueStorageNamespace};
+    use async_trait::async_trait;
+    use golem_common::model::account::AccountId;
+    use golem_common::model::agent::{AgentMode, UntypedDataValue, UntypedElementValue};
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::environment::EnvironmentId;
+    use golem_common::model::regions::OplogRegion;
+    use golem_common::model::{
+        AgentInvocation, OwnedWorkerId, ShardId, Timestamp, WorkerId, WorkerMetadata,
+        WorkerStatusRecord,
+    };
+    use golem_service_base::error::worker_executor::WorkerExecutorError;
+    use golem_wasm::IntoValue;
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use test_r::test;
+    use tokio_util::sync::CancellationToken;
+    use uuid::Uuid;
+
+    #[derive(Default)]
+    struct SchedulerWorkerAccessRecorder {
+        invocations: Mutex<Vec<(OwnedWorkerId, AgentInvocation)>>,
+    }
+
+    impl SchedulerWorkerAccessRecorder {
+        /// The delivered (worker name, handler method, message) triples, in delivery order
+        fn deliveries(&self) -> Vec<(String, String, UntypedDataValue)> {
+            self.invocations
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(owned_worker_id, invocation)| match invocation {
+                    AgentInvocation::AgentMethod {
+                        method_name, input, ..
+                    } => (
+                        owned_worker_id.worker_id.worker_name.clone(),
+                        method_name.clone(),
+                        input.clone(),
+                    ),
+                    other => panic!("Unexpected invocation: {other:?}"),
+                })
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl SchedulerWorkerAccess for SchedulerWorkerAccessRecorder {
+        async fn activate_worker(&self, _created_by: AccountId, _owned_worker_id: &OwnedWorkerId) {}
+        async fn open_oplog(
+            &self,
+            _created_by: AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Arc<dyn Oplog>, WorkerExecutorError> {
+            unimplemented!()
+        }
+        async fn enqueue_invocation(
+            &self,
+            _created_by: AccountId,
+            owned_worker_id: &OwnedWorkerId,
+            invocation: AgentInvocation,
+        ) -> Result<(), WorkerExecutorError> {
+            self.invocations
+                .lock()
+                .unwrap()
+                .push((owned_worker_id.clone(), invocation));
+            Ok(())
+        }
+        async fn compensate_open_sagas(
+            &self,
+            _created_by: AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<bool, WorkerExecutorError> {
+            unimplemented!()
+        }
+    }
+
+    /// Only the given workers exist
+    struct WorkerServiceMock {
+        existing: HashSet<WorkerId>,
+    }
+
+    #[async_trait]
+    impl WorkerService for WorkerServiceMock {
+        async fn get(&self, owned_worker_id: &OwnedWorkerId) -> Option<GetWorkerMetadataResult> {
+            self.existing
+                .contains(&owned_worker_id.worker_id)
+                .then(|| GetWorkerMetadataResult {
+                    initial_worker_metadata: WorkerMetadata::default(
+                        owned_worker_id.worker_id.clone(),
+                        AccountId::new(),
+                        owned_worker_id.environment_id,
+                    ),
+                    last_known_status: None,
+                })
+        }
+
+        async fn get_running_workers_in_shards(&self) -> Vec<GetWorkerMetadataResult> {
+            unimplemented!()
+        }
+
+        async fn get_oplog_retention_candidates_in_shards(&self) -> Vec<OwnedWorkerId> {
+            unimplemented!()
+        }
+
+        async fn remove_oplog_retention_candidate(&self, _owned_worker_id: &OwnedWorkerId) {}
+
+        async fn set_discarded_oplog_region(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+            _region: &OplogRegion,
+        ) {
+            unimplemented!()
+        }
+
+        async fn remove(&self, _owned_worker_id: &OwnedWorkerId) {}
+
+        async fn remove_cached_status(&self, _owned_worker_id: &OwnedWorkerId) {}
+
+        async fn update_cached_status(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+            _status_value: &WorkerStatusRecord,
+            _agent_mode: AgentMode,
+        ) {
+        }
+
+        async fn add_to_index(
+            &self,
+            _worker_id: &WorkerId,
+            _created_at: Timestamp,
+            _status_value: Option<&WorkerStatusRecord>,
+        ) {
+        }
+
+        async fn get_index_page(
+            &self,
+            _component_id: &ComponentId,
+            _min_created_at: u64,
+            _count: u64,
+        ) -> (Option<u64>, Vec<IndexedWorker>) {
+            unimplemented!()
+        }
+
+        async fn is_index_complete(&self, _component_id: &ComponentId) -> bool {
+            false
+        }
+
+        async fn mark_index_complete(&self, _component_id: &ComponentId) {}
+    }
+
+    struct TestSetup {
+        environment_id: EnvironmentId,
+        kvs: Arc<InMemoryKeyValueStorage>,
+        worker_access: Arc<SchedulerWorkerAccessRecorder>,
+        svc: Arc<PubSubServiceDefault>,
+    }
+
+    impl TestSetup {
+        fn new(
+            environment_id: EnvironmentId,
+            existing: &[&OwnedWorkerId],
+            message_retention: Duration,
+        ) -> Self {
+            let kvs = Arc::new(InMemoryKeyValueStorage::new());
+            let shard_service = Arc::new(ShardServiceDefault::new());
+            shard_service.register(1, &HashSet::from_iter(vec![ShardId::new(0)]));
+            let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+            let worker_service = Arc::new(WorkerServiceMock {
+                existing: existing.iter().map(|w| w.worker_id.clone()).collect(),
+            });
+
+            // The background delivery loop is stopped, deliver() is called explicitly
+            let shutdown_token = CancellationToken::new();
+            shutdown_token.cancel();
+
+            let svc = PubSubServiceDefault::new(
+                kvs.clone(),
+                shard_service as Arc<dyn ShardService>,
+                worker_access.clone(),
+                worker_service,
+                PubSubConfig {
+                    message_retention,
+                    ..PubSubConfig::default()
+                },
+                shutdown_token,
+            );
+
+            Self {
+                environment_id,
+                kvs,
+                worker_access,
+                svc,
+            }
+        }
+
+        async fn message_exists(&self, topic: &str, offset: u64) -> bool {
+            let topic = TopicKey {
+                environment_id: self.environment_id,
+                topic: topic.to_string(),
+            };
+            self.kvs
+                .with("pubsub", "test")
+                .exists(
+                    KeyValueStorageNamespace::PubSub,
+                    &PubSubServiceDefault::message_key(&topic, offset),
+                )
+                .await
+                .unwrap()
+        }
+    }
+
+    fn agent(environment_id: EnvironmentId, name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            environment_id,
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: name.to_string(),
+            },
+        )
+    }
+
+    fn message(value: &str) -> UntypedDataValue {
+        UntypedDataValue::Tuple(vec![UntypedElementValue::ComponentModel(
+            value.to_string().into_value(),
+        )])
+    }
+
+    #[test]
+    pub async fn published_messages_are_delivered_to_all_subscribers() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let a2 = agent(environment_id, "agent-2");
+        let setup = TestSetup::new(environment_id, &[&a1, &a2], Duration::from_secs(3600));
+        let account_id = AccountId::new();
+
+        setup
+            .svc
+            .subscribe(account_id, &a1, "orders", "on-order".to_string())
+            .await
+            .unwrap();
+        setup
+            .svc
+            .subscribe(account_id, &a2, "orders", "handle-order".to_string())
+            .await
+            .unwrap();
+
+        let o1 = setup
+            .svc
+            .publish(environment_id, "orders", message("first"))
+            .await
+            .unwrap();
+        let o2 = setup
+            .svc
+            .publish(environment_id, "orders", message("second"))
+            .await
+            .unwrap();
+
+        setup.svc.deliver().await.unwrap();
+        // Already delivered messages are not delivered again
+        setup.svc.deliver().await.unwrap();
+
+        let mut deliveries = setup.worker_access.deliveries();
+        deliveries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!((o1, o2), (0, 1));
+        assert_eq!(
+            deliveries,
+            vec![
+                (
+                    "agent-1".to_string(),
+                    "on-order".to_string(),
+                    message("first")
+                ),
+                (
+                    "agent-1".to_string(),
+                    "on-order".to_string(),
+                    message("second")
+                ),
+                (
+                    "agent-2".to_string(),
+                    "handle-order".to_string(),
+                    message("first")
+                ),
+                (
+                    "agent-2".to_string(),
+                    "handle-order".to_string(),
+                    message("second")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    pub async fn only_messages_published_after_subscribing_are_delivered() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let setup = TestSetup::new(environment_id, &[&a1], Duration::from_secs(3600));
+
+        setup
+            .svc
+            .publish(environment_id, "orders", message("before"))
+            .await
+            .unwrap();
+        setup
+            .svc
+            .subscribe(AccountId::new(), &a1, "orders", "on-order".to_string())
+            .await
+            .unwrap();
+        setup
+            .svc
+            .publish(environment_id, "orders", message("after"))
+            .await
+            .unwrap();
+        setup
+            .svc
+            .publish(environment_id, "other", message("other"))
+            .await
+            .unwrap();
+
+        setup.svc.deliver().await.unwrap();
+
+        assert_eq!(
+            setup.worker_access.deliveries(),
+            vec![(
+                "agent-1".to_string(),
+                "on-order".to_string(),
+                message("after")
+            )]
+        );
+    }
+
+    #[test]
+    pub async fn unsubscribed_agents_receive_no_messages() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let setup = TestSetup::new(environment_id, &[&a1], Duration::from_secs(3600));
+
+        setup
+            .svc
+            .subscribe(AccountId::new(), &a1, "orders", "on-order".to_string())
+            .await
+            .unwrap();
+        let unsubscribed = setup.svc.unsubscribe(&a1, "orders").await.unwrap();
+        let unsubscribed_again = setup.svc.unsubscribe(&a1, "orders").await.unwrap();
+
+        setup
+            .svc
+            .publish(environment_id, "orders", message("first"))
+            .await
+            .unwrap();
+        setup.svc.deliver().await.unwrap();
+
+        assert!(unsubscribed);
+        assert!(!unsubscribed_again);
+        assert!(setup.worker_access.deliveries().is_empty());
+    }
+
+    #[test]
+    pub async fn subscriptions_of_deleted_agents_are_removed() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let setup = TestSetup::new(environment_id, &[], Duration::from_secs(3600));
+
+        setup
+            .svc
+            .subscribe(AccountId::new(), &a1, "orders", "on-order".to_string())
+            .await
+            .unwrap();
+        setup
+            .svc
+            .publish(environment_id, "orders", message("first"))
+            .await
+            .unwrap();
+        setup.svc.deliver().await.unwrap();
+
+        assert!(setup.worker_access.deliveries().is_empty());
+        assert!(!setup.svc.unsubscribe(&a1, "orders").await.unwrap());
+    }
+
+    #[test]
+    pub async fn delivered_messages_are_deleted_after_the_retention_period() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let setup = TestSetup::new(environment_id, &[&a1], Duration::ZERO);
+
+        setup
+            .svc
+            .subscribe(AccountId::new(), &a1, "orders", "on-order".to_string())
+            .await
+            .unwrap();
+        setup
+            .svc
+            .publish(environment_id, "orders", message("first"))
+            .await
+            .unwrap();
+
+        assert!(setup.message_exists("orders", 0).await);
+
+        setup.svc.deliver().await.unwrap();
+
+        assert!(!setup.message_exists("orders", 0).await);
+
+        // Offsets keep growing after deleting messages
+        let offset = setup
+            .svc
+            .publish(environment_id, "orders", message("second"))
+            .await
+            .unwrap();
+        setup.svc.deliver().await.unwrap();
+
+        assert_eq!(offset, 1);
+        assert_eq!(setup.worker_access.deliveries().len(), 2);
+    }
+
+    #[test]
+    pub async fn invalid_topic_names_are_rejected() {
+        let environment_id = EnvironmentId::new();
+        let a1 = agent(environment_id, "agent-1");
+        let setup = TestSetup::new(environment_id, &[&a1], Duration::from_secs(3600));
+
+        assert!(setup
+            .svc
+            .publish(environment_id, "", message("first"))
+            .await
+            .is_err());
+        assert!(setup
+            .svc
+            .subscribe(AccountId::new(), &a1, "orders/*", "on-order".to_string())
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use golem_common::model::oplog::host_functions::{
+    host_response_from_value_and_type, HostFunctionName,
+};
+use golem_common::model::oplog::{HostResponse, PublicOplogEntry, PublicOplogEntryWithIndex};
+use golem_common::model::WorkerId;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// A host call recorded in an oplog
+#[derive(Debug, Clone)]
+pub struct RecordedHostCall {
+    pub function_name: HostFunctionName,
+    pub response: HostResponse,
+}
+
+impl RecordedHostCall {
+    /// The host calls recorded in a public oplog, in the order they were performed
+    pub fn from_public_oplog(entries: &[PublicOplogEntryWithIndex]) -> anyhow::Result<Vec<Self>> {
+        entries
+            .iter()
+            .filter_map(|entry| match &entry.entry {
+                PublicOplogEntry::HostCall(params) => Some((entry.oplog_index, params)),
+                _ => None,
+            })
+            .map(|(oplog_index, params)| {
+                let response = host_response_from_value_and_type(
+                    &params.function_name,
+                    params.response.clone(),
+                )
+                .map_err(|err| {
+                    anyhow!("Invalid host call response at oplog index {oplog_index}: {err}")
+                })?;
+                Ok(Self {
+                    function_name: HostFunctionName::from(params.function_name.as_str()),
+                    response,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Recorded host call responses returned to workers in place of the live responses of their host
+/// calls, used for replaying recorded oplogs in a sandbox. Workers without recorded host calls
+/// always get the live responses.
+#[derive(Clone, Default)]
+pub struct RecordedHostCalls {
+    host_calls: Arc<scc::HashMap<WorkerId, VecDeque<RecordedHostCall>>>,
+}
+
+impl RecordedHostCalls {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the worker receive the responses of the given host calls, in order, in place of the
+    /// live responses of its host calls
+    pub async fn set(&self, worker_id: WorkerId, host_calls: Vec<RecordedHostCall>) {
+        self.host_calls
+            .upsert_async(worker_id, host_calls.into())
+            .await;
+    }
+
+    /// Takes the recorded response of the worker's next host call. Once the worker calls a
+    /// different host function than the recorded one, its replay diverged, and the rest of the
+    /// recorded host calls are dropped, so it continues with live responses.
+    pub async fn next_response(
+        &self,
+        worker_id: &WorkerId,
+        function_name: &HostFunctionName,
+    ) -> Option<HostResponse> {
+        let mut entry = self.host_calls.get_async(worker_id).await?;
+        let host_calls = entry.get_mut();
+        match host_calls.pop_front() {
+            Some(host_call) if &host_call.function_name == function_name => {
+                Some(host_call.response)
+            }
+            Some(_) => {
+                host_calls.clear();
+                None
+            }
+            None => None,
+        }
+    }
+}
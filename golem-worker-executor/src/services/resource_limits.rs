@@ -13,15 +13,17 @@
 // limitations under the License.
 
 use crate::metrics::resources::{record_fuel_borrow, record_fuel_return};
-use crate::services::golem_config::ResourceLimitsConfig;
+use crate::metrics::usage::record_agent_usage;
+use crate::services::golem_config::{ResourceLimitsConfig, UsageMeteringConfig};
 use async_trait::async_trait;
 use golem_common::model::account::AccountId;
 use golem_common::SafeDisplay;
 use golem_service_base::clients::registry::RegistryService;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::{AgentUsage, AgentUsageKey, AgentUsageUpdate};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::OnceCell;
 use tokio_util::sync::CancellationToken;
@@ -108,10 +110,15 @@ pub trait ResourceLimits: Send + Sync {
         &self,
         account_id: AccountId,
     ) -> Result<Arc<AtomicResourceEntry>, WorkerExecutorError>;
+
+    // Records resource usage of the agents of an agent type. The usage is reported to registry
+    // service together with the next batch of fuel updates
+    fn record_agent_usage(&self, key: AgentUsageKey, usage: AgentUsage);
 }
 
 pub fn configured(
     config: &ResourceLimitsConfig,
+    usage_metering: &UsageMeteringConfig,
     registry_service: Arc<dyn RegistryService>,
     shutdown_token: CancellationToken,
 ) -> Arc<dyn ResourceLimits> {
@@ -119,9 +126,44 @@ pub fn configured(
         ResourceLimitsConfig::Grpc(config) => ResourceLimitsGrpc::new(
             registry_service,
             config.batch_update_interval,
+            usage_metering.clone(),
             shutdown_token,
         ),
-        ResourceLimitsConfig::Disabled(_) => Arc::new(ResourceLimitsDisabled),
+        ResourceLimitsConfig::Disabled(_) => Arc::new(ResourceLimitsDisabled {
+            usage_metering: usage_metering.clone(),
+        }),
+    }
+}
+
+fn export_agent_usage(config: &UsageMeteringConfig, key: &AgentUsageKey, usage: &AgentUsage) {
+    if config.prometheus_export {
+        record_agent_usage(key, usage, config.prometheus_max_label_sets);
+    }
+}
+
+/// Agent usage recorded since the last batch update, aggregated per agent type
+#[derive(Debug, Default)]
+struct AgentUsageBuffer {
+    usage: Mutex<HashMap<AgentUsageKey, AgentUsage>>,
+}
+
+impl AgentUsageBuffer {
+    fn record(&self, key: AgentUsageKey, usage: &AgentUsage) {
+        self.usage
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .add(usage);
+    }
+
+    /// Takes the aggregated usage, leaving the buffer empty for the next batch update
+    fn take_updates(&self) -> Vec<AgentUsageUpdate> {
+        let usage = std::mem::take(&mut *self.usage.lock().unwrap());
+        usage
+            .into_iter()
+            .map(|(key, usage)| AgentUsageUpdate { key, usage })
+            .collect()
     }
 }
 
@@ -132,17 +174,22 @@ pub fn configured(
 pub struct ResourceLimitsGrpc {
     client: Arc<dyn RegistryService>,
     entries: scc::HashMap<AccountId, Arc<OnceCell<Arc<AtomicResourceEntry>>>>,
+    agent_usage: AgentUsageBuffer,
+    usage_metering: UsageMeteringConfig,
 }
 
 impl ResourceLimitsGrpc {
     pub fn new(
         registry_service: Arc<dyn RegistryService>,
         batch_update_interval: Duration,
+        usage_metering: UsageMeteringConfig,
         shutdown_token: CancellationToken,
     ) -> Arc<Self> {
         let svc = Self {
             client: registry_service,
             entries: scc::HashMap::new(),
+            agent_usage: AgentUsageBuffer::default(),
+            usage_metering,
         };
         let svc = Arc::new(svc);
         let svc_weak = Arc::downgrade(&svc);
@@ -168,8 +215,11 @@ impl ResourceLimitsGrpc {
                     };
 
                     let updates = svc_arc.take_fuel_updates().await;
-                    if !updates.is_empty() {
-                        svc_arc.send_batch_updates(updates.clone()).await
+                    let agent_usage_updates = svc_arc.agent_usage.take_updates();
+                    if !updates.is_empty() || !agent_usage_updates.is_empty() {
+                        svc_arc
+                            .send_batch_updates(updates, agent_usage_updates)
+                            .await
                     }
                 }
             }
@@ -257,10 +307,17 @@ impl ResourceLimitsGrpc {
         }
     }
 
-    async fn send_batch_updates(&self, updates: HashMap<AccountId, i64>) {
+    async fn send_batch_updates(
+        &self,
+        updates: HashMap<AccountId, i64>,
+        agent_usage_updates: Vec<AgentUsageUpdate>,
+    ) {
         tracing::debug!("Sending batch fuel updates");
 
-        let update_limits_result = self.client.batch_update_fuel_usage(updates.clone()).await;
+        let update_limits_result = self
+            .client
+            .batch_update_fuel_usage(updates.clone(), agent_usage_updates.clone())
+            .await;
 
         match update_limits_result {
             Ok(updated_limits) => {
@@ -272,6 +329,7 @@ impl ResourceLimitsGrpc {
             Err(err) => {
                 error!("Failed to send batched resource usage updates: {}", err);
                 error!("Lost fuel updates: {:?}", updates);
+                error!("Lost agent usage updates: {:?}", agent_usage_updates);
                 for account_id in updates.keys() {
                     self.reset_in_flight_delta(*account_id).await;
                 }
@@ -306,9 +364,16 @@ impl ResourceLimits for ResourceLimitsGrpc {
 
         Ok(entry.clone())
     }
+
+    fn record_agent_usage(&self, key: AgentUsageKey, usage: AgentUsage) {
+        export_agent_usage(&self.usage_metering, &key, &usage);
+        self.agent_usage.record(key, &usage);
+    }
 }
 
-pub struct ResourceLimitsDisabled;
+pub struct ResourceLimitsDisabled {
+    usage_metering: UsageMeteringConfig,
+}
 
 #[async_trait]
 impl ResourceLimits for ResourceLimitsDisabled {
@@ -318,4 +383,91 @@ impl ResourceLimits for ResourceLimitsDisabled {
     ) -> Result<Arc<AtomicResourceEntry>, WorkerExecutorError> {
         Ok(Arc::new(AtomicResourceEntry::new(u64::MAX, usize::MAX)))
     }
+
+    fn record_agent_usage(&self, key: AgentUsageKey, usage: AgentUsage) {
+        export_agent_usage(&self.usage_metering, &key, &usage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AgentUsageBuffer;
+    use golem_common::model::agent::AgentTypeName;
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::environment::EnvironmentId;
+    use golem_service_base::model::{AgentUsage, AgentUsageKey};
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn key(agent_type: &str) -> AgentUsageKey {
+        AgentUsageKey {
+            environment_id: EnvironmentId::new(),
+            component_id: ComponentId(Uuid::new_v4()),
+            agent_type: AgentTypeName(agent_type.to_string()),
+        }
+    }
+
+    fn invocation(fuel: u64, oplog_bytes: u64) -> AgentUsage {
+        AgentUsage {
+            invocations: 1,
+            fuel,
+            oplog_bytes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn usage_of_the_same_agent_type_is_aggregated() {
+        let buffer = AgentUsageBuffer::default();
+        let key = key("counter");
+
+        buffer.record(key.clone(), &invocation(100, 10));
+        buffer.record(key.clone(), &invocation(50, 5));
+
+        let updates = buffer.take_updates();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].key, key);
+        assert_eq!(
+            updates[0].usage,
+            AgentUsage {
+                invocations: 2,
+                fuel: 150,
+                oplog_bytes: 15,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn usage_of_different_agent_types_is_kept_apart() {
+        let buffer = AgentUsageBuffer::default();
+        let first = key("first");
+        let second = key("second");
+
+        buffer.record(first.clone(), &invocation(100, 0));
+        buffer.record(second.clone(), &invocation(7, 0));
+
+        let mut updates = buffer.take_updates();
+        updates.sort_by(|a, b| a.key.agent_type.0.cmp(&b.key.agent_type.0));
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].key, first);
+        assert_eq!(updates[0].usage.fuel, 100);
+        assert_eq!(updates[1].key, second);
+        assert_eq!(updates[1].usage.fuel, 7);
+    }
+
+    #[test]
+    fn taking_the_updates_flushes_the_buffer() {
+        let buffer = AgentUsageBuffer::default();
+        let key = key("counter");
+
+        buffer.record(key.clone(), &invocation(100, 0));
+        assert_eq!(buffer.take_updates().len(), 1);
+        assert!(buffer.take_updates().is_empty());
+
+        buffer.record(key.clone(), &invocation(30, 0));
+        let updates = buffer.take_updates();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].usage, invocation(30, 0));
+    }
 }
@@ -16,20 +16,22 @@ use super::agent_webhooks::AgentWebhooksService;
 use super::file_loader::FileLoader;
 use super::HasAgentWebhooksService;
 use crate::services::events::Events;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::plugin::OplogProcessorPlugin;
 use crate::services::resource_limits::ResourceLimits;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use crate::services::{
-    active_workers, agent_types, blob_store, component, golem_config, key_value, oplog, promise,
-    rdbms, scheduler, shard_manager, worker, worker_activator, worker_enumeration, worker_fork,
-    HasActiveWorkers, HasAgentTypesService, HasBlobStoreService, HasComponentService, HasConfig,
-    HasEvents, HasExtraDeps, HasFileLoader, HasKeyValueService, HasLeakSentinel,
-    HasOplogProcessorPlugin, HasOplogService, HasPromiseService, HasRdbmsService,
-    HasResourceLimits, HasRpc, HasRunningWorkerEnumerationService, HasSchedulerService,
-    HasShardManagerService, HasShardService, HasShutdownToken, HasWasmtimeEngine,
-    HasWorkerActivator, HasWorkerEnumerationService, HasWorkerForkService, HasWorkerProxy,
-    HasWorkerService,
+    active_workers, agent_lifecycle_events, agent_types, blob_store, component, golem_config,
+    key_value, oplog, promise, pubsub, rdbms, scheduler, shard_manager, worker, worker_activator,
+    worker_enumeration, worker_fork, HasActiveWorkers, HasAgentLifecycleEventService,
+    HasAgentTypesService, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
+    HasExtraDeps, HasFileLoader, HasKeyValueService, HasLeakSentinel,
+    HasNetworkEgressPolicyService, HasOplogProcessorPlugin, HasOplogService, HasPromiseService,
+    HasPubSubService, HasRdbmsService, HasResourceLimits, HasRpc,
+    HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
+    HasShardService, HasShutdownToken, HasWasmtimeEngine, HasWorkerActivator,
+    HasWorkerEnumerationService, HasWorkerForkService, HasWorkerProxy, HasWorkerService,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -39,6 +41,7 @@ use golem_common::model::agent::{
     AgentInvocationMode, AgentPrincipal, Principal, UntypedDataValue,
 };
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::invocation_queue::InvocationPriority;
 use golem_common::model::oplog::types::SerializableRpcError;
 use golem_common::model::{
     AgentInvocation, AgentInvocationResult, IdempotencyKey, OwnedWorkerId, WorkerId,
@@ -385,11 +388,14 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     rdbms_service: Arc<dyn rdbms::RdbmsService>,
     oplog_service: Arc<dyn oplog::OplogService>,
     scheduler_service: Arc<dyn scheduler::SchedulerService>,
+    pubsub_service: Arc<dyn pubsub::PubSubService>,
+    agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
     worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx>>,
     events: Arc<Events>,
     file_loader: Arc<FileLoader>,
     oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
     resource_limits: Arc<dyn ResourceLimits>,
+    network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
     shutdown_token: tokio_util::sync::CancellationToken,
     agent_types_service: Arc<dyn agent_types::AgentTypesService>,
     agent_webhooks_service: Arc<AgentWebhooksService>,
@@ -419,11 +425,14 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             rdbms_service: self.rdbms_service.clone(),
             oplog_service: self.oplog_service.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            agent_lifecycle_event_service: self.agent_lifecycle_event_service.clone(),
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
             file_loader: self.file_loader.clone(),
             oplog_processor_plugin: self.oplog_processor_plugin.clone(),
             resource_limits: self.resource_limits.clone(),
+            network_egress_policy_service: self.network_egress_policy_service.clone(),
             shutdown_token: self.shutdown_token.clone(),
             agent_types_service: self.agent_types_service.clone(),
             agent_webhooks_service: self.agent_webhooks_service.clone(),
@@ -527,6 +536,20 @@ impl<Ctx: WorkerCtx> HasSchedulerService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasAgentLifecycleEventService for DirectWorkerInvocationRpc<Ctx> {
+    fn agent_lifecycle_event_service(
+        &self,
+    ) -> Arc<dyn agent_lifecycle_events::AgentLifecycleEventService> {
+        self.agent_lifecycle_event_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasPubSubService for DirectWorkerInvocationRpc<Ctx> {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.pubsub_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasOplogService for DirectWorkerInvocationRpc<Ctx> {
     fn oplog_service(&self) -> Arc<dyn oplog::OplogService> {
         self.oplog_service.clone()
@@ -605,6 +628,12 @@ impl<Ctx: WorkerCtx> HasResourceLimits for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasNetworkEgressPolicyService for DirectWorkerInvocationRpc<Ctx> {
+    fn network_egress_policy_service(&self) -> Arc<dyn NetworkEgressPolicyService> {
+        self.network_egress_policy_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasShutdownToken for DirectWorkerInvocationRpc<Ctx> {
     fn shutdown_token(&self) -> tokio_util::sync::CancellationToken {
         self.shutdown_token.clone()
@@ -636,11 +665,14 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         rdbms_service: Arc<dyn rdbms::RdbmsService>,
         oplog_service: Arc<dyn oplog::OplogService>,
         scheduler_service: Arc<dyn scheduler::SchedulerService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx>>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         resource_limits: Arc<dyn ResourceLimits>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shutdown_token: tokio_util::sync::CancellationToken,
         agent_types_service: Arc<dyn agent_types::AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
@@ -667,11 +699,14 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             rdbms_service,
             oplog_service,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator,
             events,
             file_loader,
             oplog_processor_plugin,
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             agent_types_service,
             agent_webhooks_service,
@@ -773,7 +808,9 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 principal,
             };
 
-            let output = worker.invoke_and_await(invocation).await?;
+            let output = worker
+                .invoke_and_await(invocation, InvocationPriority::Normal)
+                .await?;
 
             match output.result {
                 AgentInvocationResult::AgentMethod { output } => Ok(output),
@@ -842,7 +879,10 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 principal,
             };
 
-            match worker.invoke(invocation).await? {
+            match worker
+                .invoke(invocation, InvocationPriority::Normal)
+                .await?
+            {
                 crate::worker::ResultOrSubscription::Finished(Err(err)) => Err(err.into()),
                 _ => Ok(()),
             }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::metrics::oplog::record_scheduled_archive;
-use crate::metrics::promises::record_scheduled_promise_completed;
+use crate::metrics::promises::{record_promise_expired, record_scheduled_promise_completed};
 use crate::services::oplog::{MultiLayerOplog, Oplog, OplogService};
 use crate::services::promise::PromiseService;
 use crate::services::shard::ShardService;
@@ -27,10 +27,18 @@ use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
 use chrono::{DateTime, TimeZone, Utc};
+use desert_rust::BinaryCodec;
 use golem_common::model::account::AccountId;
 use golem_common::model::agent::Principal;
 use golem_common::model::invocation_context::InvocationContextStack;
-use golem_common::model::{AgentInvocation, OwnedWorkerId, ScheduleId, ScheduledAction};
+use golem_common::model::invocation_queue::InvocationPriority;
+use golem_common::model::recurring_invocation::{
+    MissedFirePolicy, RecurringInvocation, RecurringInvocationId, RecurringSchedule,
+};
+use golem_common::model::{
+    AgentInvocation, IdempotencyKey, OwnedWorkerId, PromiseOutcome, ScheduleId, ScheduledAction,
+    Timestamp,
+};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use std::ops::{Add, Deref};
 use std::sync::{Arc, Mutex};
@@ -39,12 +47,90 @@ use tokio::task::JoinHandle;
 use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, span, warn, Instrument, Level};
+use uuid::Uuid;
 
 #[async_trait]
 pub trait SchedulerService: Send + Sync {
     async fn schedule(&self, time: DateTime<Utc>, action: ScheduledAction) -> ScheduleId;
 
     async fn cancel(&self, id: ScheduleId);
+
+    /// Creates a recurring invocation, enqueueing the given agent method invocation on every
+    /// occurrence of the schedule. The idempotency key of the invocation is replaced by a
+    /// new one for each occurrence.
+    async fn schedule_recurring(
+        &self,
+        account_id: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        invocation: AgentInvocation,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+    ) -> Result<RecurringInvocation, WorkerExecutorError>;
+
+    async fn list_recurring(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<RecurringInvocation>, WorkerExecutorError>;
+
+    /// Cancels a recurring invocation of the worker. Returns false if it did not exist.
+    async fn cancel_recurring(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: RecurringInvocationId,
+    ) -> Result<bool, WorkerExecutorError>;
+
+    /// Performs the scheduled actions that are due at the given time. The background loop calls
+    /// this with the current time; embedded executors use it to advance a virtual clock.
+    async fn process_due(&self, now: DateTime<Utc>) -> Result<(), String>;
+}
+
+/// A recurring invocation as stored in the key-value storage
+#[derive(Debug, Clone, PartialEq, BinaryCodec)]
+#[desert(evolution())]
+struct RecurringInvocationRecord {
+    account_id: AccountId,
+    owned_worker_id: OwnedWorkerId,
+    invocation: RecurringInvocation,
+    /// The invocation to enqueue on each occurrence, with the idempotency key replaced
+    template: AgentInvocation,
+}
+
+impl RecurringInvocationRecord {
+    fn anchor(&self) -> DateTime<Utc> {
+        to_date_time(self.invocation.created_at)
+    }
+
+    /// The invocation of a given occurrence, with an idempotency key derived from the occurrence,
+    /// so retrying to enqueue it does not lead to duplicate invocations
+    fn invocation_for(&self, occurrence: DateTime<Utc>) -> AgentInvocation {
+        match self.template.clone() {
+            AgentInvocation::AgentMethod {
+                method_name,
+                input,
+                invocation_context,
+                principal,
+                ..
+            } => AgentInvocation::AgentMethod {
+                idempotency_key: IdempotencyKey::from_uuid(Uuid::new_v5(
+                    &self.invocation.id.0,
+                    format!("occurrence-{}", occurrence.timestamp_millis()).as_bytes(),
+                )),
+                method_name,
+                input,
+                invocation_context,
+                principal,
+            },
+            other => other,
+        }
+    }
+}
+
+fn to_date_time(timestamp: Timestamp) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(timestamp.to_millis() as i64).unwrap_or_default()
+}
+
+fn to_timestamp(date_time: DateTime<Utc>) -> Timestamp {
+    Timestamp::from(date_time.timestamp_millis() as u64)
 }
 
 /// A lighter trait than `WorkerActivator` that only provides the required functionality
@@ -65,6 +151,13 @@ pub trait SchedulerWorkerAccess {
         owned_worker_id: &OwnedWorkerId,
         invocation: AgentInvocation,
     ) -> Result<(), WorkerExecutorError>;
+
+    // perform the compensations of the sagas left open by a failed worker, returns whether all of them were performed
+    async fn compensate_open_sagas(
+        &self,
+        created_by: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<bool, WorkerExecutorError>;
 }
 
 #[async_trait]
@@ -116,12 +209,36 @@ impl<Ctx: WorkerCtx> SchedulerWorkerAccess for Arc<dyn WorkerActivator<Ctx>> {
             )
             .await?;
 
-        worker.invoke(invocation).await?;
+        worker
+            .invoke(invocation, InvocationPriority::Normal)
+            .await?;
 
         Worker::start_if_needed(worker).await?;
 
         Ok(())
     }
+
+    async fn compensate_open_sagas(
+        &self,
+        created_by: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<bool, WorkerExecutorError> {
+        let worker = self
+            .get_or_create_suspended(
+                created_by,
+                owned_worker_id,
+                None,
+                None,
+                Vec::new(),
+                None,
+                None,
+                &InvocationContextStack::fresh(),
+                Principal::anonymous(),
+            )
+            .await?;
+
+        Ok(worker.compensate_open_sagas().await)
+    }
 }
 
 #[derive(Clone)]
@@ -254,7 +371,11 @@ impl SchedulerServiceDefault {
 
                     let result = self
                         .promise_service
-                        .complete(promise_id.clone(), vec![], account_id)
+                        .complete(
+                            promise_id.clone(),
+                            PromiseOutcome::Completed(vec![]),
+                            account_id,
+                        )
                         .await;
 
                     // TODO: We probably need more error handling here as not completing a promise that is expected to complete can lead to deadlocks.
@@ -360,6 +481,79 @@ impl SchedulerServiceDefault {
                         );
                     };
                 }
+                ScheduledAction::InvokeRecurring {
+                    owned_worker_id,
+                    id,
+                    scheduled_for,
+                } => {
+                    let result = self.fire_recurring(now, id, scheduled_for).await;
+
+                    if let Err(e) = result {
+                        error!(
+                            worker_id = owned_worker_id.to_string(),
+                            recurring_invocation_id = id.to_string(),
+                            "Failed to process recurring invocation: {e}"
+                        );
+                    }
+                }
+                ScheduledAction::ExpirePromise {
+                    account_id,
+                    promise_id,
+                    environment_id,
+                } => {
+                    // Expiring a promise which was already completed or failed is a no-op,
+                    // the owning worker is woken up by the promise service otherwise
+                    let result = self
+                        .promise_service
+                        .complete(promise_id.clone(), PromiseOutcome::Expired, account_id)
+                        .await;
+
+                    match result {
+                        Ok(true) => {
+                            record_promise_expired();
+                        }
+                        Ok(false) => {}
+                        Err(e) => {
+                            let owned_worker_id =
+                                OwnedWorkerId::new(environment_id, &promise_id.worker_id);
+                            error!(
+                                worker_id = owned_worker_id.to_string(),
+                                promise_id = promise_id.to_string(),
+                                "Failed to expire promise: {e}"
+                            );
+                        }
+                    }
+                }
+                ScheduledAction::CompensateSagas {
+                    account_id,
+                    owned_worker_id,
+                    attempt,
+                } => {
+                    let compensated = self
+                        .worker_access
+                        .compensate_open_sagas(account_id, &owned_worker_id)
+                        .await
+                        .unwrap_or_else(|e| {
+                            error!(
+                                worker_id = owned_worker_id.to_string(),
+                                "Failed to compensate open sagas: {e}"
+                            );
+                            false
+                        });
+
+                    if !compensated {
+                        // The next attempt is a different action, so it is not purged together with this one
+                        self.schedule(
+                            now.add(Self::saga_compensation_retry_delay(attempt)),
+                            ScheduledAction::CompensateSagas {
+                                account_id,
+                                owned_worker_id,
+                                attempt: attempt + 1,
+                            },
+                        )
+                        .await;
+                    }
+                }
             }
 
             // We are completely done with the action, purge it from the queue
@@ -372,6 +566,185 @@ impl SchedulerServiceDefault {
         Ok(())
     }
 
+    /// The delay before the next attempt of compensating the open sagas of a failed agent,
+    /// doubling with each attempt up to about an hour
+    fn saga_compensation_retry_delay(attempt: u32) -> Duration {
+        Duration::from_secs(1 << attempt.min(12))
+    }
+
+    /// Enqueues the invocations of the occurrences of a recurring invocation which are due,
+    /// according to its missed fire policy, and schedules its next occurrence
+    async fn fire_recurring(
+        &self,
+        now: DateTime<Utc>,
+        id: RecurringInvocationId,
+        scheduled_for: Timestamp,
+    ) -> Result<(), String> {
+        let Some(mut record) = self.get_recurring_record(id).await? else {
+            // The recurring invocation has been cancelled
+            return Ok(());
+        };
+        if record.invocation.next_fire_at != scheduled_for {
+            // Outdated action, the recurring invocation has already been rescheduled
+            return Ok(());
+        }
+
+        if self
+            .worker_service
+            .get(&record.owned_worker_id)
+            .await
+            .is_none()
+        {
+            // Enqueueing an invocation to a deleted agent would create it again
+            info!(
+                worker_id = record.owned_worker_id.to_string(),
+                recurring_invocation_id = id.to_string(),
+                "Removing the recurring invocation of a deleted agent"
+            );
+            self.remove_recurring_record(&record).await?;
+            return Ok(());
+        }
+
+        let schedule = record.invocation.schedule.clone();
+        let anchor = record.anchor();
+
+        let mut due = vec![to_date_time(scheduled_for)];
+        let mut truncated = false;
+        loop {
+            match schedule.next_occurrence(anchor, *due.last().unwrap())? {
+                Some(occurrence) if occurrence <= now => {
+                    if due.len() < Self::MAX_MISSED_OCCURRENCES {
+                        due.push(occurrence);
+                    } else {
+                        truncated = true;
+                        break;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let to_fire = match record.invocation.missed_fire_policy {
+            MissedFirePolicy::Skip if due.len() > 1 => {
+                info!(
+                    worker_id = record.owned_worker_id.to_string(),
+                    recurring_invocation_id = id.to_string(),
+                    "Skipping {} missed occurrences of recurring invocation",
+                    due.len()
+                );
+                vec![]
+            }
+            MissedFirePolicy::CatchUp if truncated => {
+                warn!(
+                    worker_id = record.owned_worker_id.to_string(),
+                    recurring_invocation_id = id.to_string(),
+                    "More than {} occurrences of recurring invocation were missed, catching up with the first {} and dropping the rest",
+                    Self::MAX_MISSED_OCCURRENCES,
+                    Self::MAX_MISSED_OCCURRENCES
+                );
+                due
+            }
+            MissedFirePolicy::Skip | MissedFirePolicy::CatchUp => due,
+            MissedFirePolicy::RunOnce => due.split_off(due.len() - 1),
+        };
+
+        for occurrence in to_fire {
+            let result = self
+                .worker_access
+                .enqueue_invocation(
+                    record.account_id,
+                    &record.owned_worker_id,
+                    record.invocation_for(occurrence),
+                )
+                .await;
+
+            if let Err(e) = result {
+                error!(
+                    worker_id = record.owned_worker_id.to_string(),
+                    recurring_invocation_id = id.to_string(),
+                    "Failed to invoke worker with recurring invocation: {e}"
+                );
+            }
+        }
+
+        let recurring_key = Self::recurring_invocation_key(&id);
+        if !self
+            .key_value_storage
+            .with("scheduler", "fire_recurring")
+            .exists(KeyValueStorageNamespace::Schedule, &recurring_key)
+            .await?
+        {
+            // Cancelled while the invocations were enqueued
+            return Ok(());
+        }
+
+        match schedule.next_occurrence(anchor, now)? {
+            Some(next) => {
+                record.invocation.next_fire_at = to_timestamp(next);
+                self.key_value_storage
+                    .with_entity("scheduler", "fire_recurring", "recurring_invocation")
+                    .set(KeyValueStorageNamespace::Schedule, &recurring_key, &record)
+                    .await?;
+                self.schedule(
+                    next,
+                    ScheduledAction::InvokeRecurring {
+                        owned_worker_id: record.owned_worker_id,
+                        id,
+                        scheduled_for: record.invocation.next_fire_at,
+                    },
+                )
+                .await;
+            }
+            None => {
+                info!(
+                    worker_id = record.owned_worker_id.to_string(),
+                    recurring_invocation_id = id.to_string(),
+                    "Recurring invocation has no more occurrences, removing it"
+                );
+                self.remove_recurring_record(&record).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_recurring_record(
+        &self,
+        id: RecurringInvocationId,
+    ) -> Result<Option<RecurringInvocationRecord>, String> {
+        self.key_value_storage
+            .with_entity("scheduler", "get_recurring", "recurring_invocation")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocation_key(&id),
+            )
+            .await
+    }
+
+    async fn remove_recurring_record(
+        &self,
+        record: &RecurringInvocationRecord,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with("scheduler", "remove_recurring")
+            .del(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocation_key(&record.invocation.id),
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity("scheduler", "remove_recurring", "recurring_invocation_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocations_of_worker_key(&record.owned_worker_id),
+                &record.invocation.id,
+            )
+            .await
+    }
+
+    /// Upper limit of the occurrences fired at once when catching up with missed occurrences
+    const MAX_MISSED_OCCURRENCES: usize = 100;
+
     const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
 
     fn split_time<Tz: TimeZone>(time: DateTime<Tz>) -> (i64, f64) {
@@ -388,6 +761,14 @@ impl SchedulerServiceDefault {
     fn schedule_key_from_timestamp(timestamp: i64) -> String {
         format!("worker:schedule:{timestamp}")
     }
+
+    fn recurring_invocation_key(id: &RecurringInvocationId) -> String {
+        format!("worker:recurring-invocation:{id}")
+    }
+
+    fn recurring_invocations_of_worker_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!("worker:recurring-invocations:{owned_worker_id}")
+    }
 }
 
 impl Drop for SchedulerServiceDefault {
@@ -440,36 +821,201 @@ impl SchedulerService for SchedulerServiceDefault {
                 )
             });
     }
+
+    async fn schedule_recurring(
+        &self,
+        account_id: AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        invocation: AgentInvocation,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+    ) -> Result<RecurringInvocation, WorkerExecutorError> {
+        schedule.validate().map_err(|err| {
+            WorkerExecutorError::invalid_request(format!("Invalid schedule: {err}"))
+        })?;
+
+        let method_name = match &invocation {
+            AgentInvocation::AgentMethod { method_name, .. } => method_name.clone(),
+            other => {
+                return Err(WorkerExecutorError::invalid_request(format!(
+                    "Only agent method invocations can recur, got {:?}",
+                    other.kind()
+                )))
+            }
+        };
+
+        let created_at = to_timestamp(Utc::now());
+        let anchor = to_date_time(created_at);
+        let next = schedule
+            .next_occurrence(anchor, anchor)
+            .map_err(|err| {
+                WorkerExecutorError::invalid_request(format!("Invalid schedule: {err}"))
+            })?
+            .ok_or_else(|| {
+                WorkerExecutorError::invalid_request("The schedule has no future occurrences")
+            })?;
+
+        let record = RecurringInvocationRecord {
+            account_id,
+            owned_worker_id: owned_worker_id.clone(),
+            invocation: RecurringInvocation {
+                id: RecurringInvocationId::new(),
+                worker_id: owned_worker_id.worker_id(),
+                method_name,
+                schedule,
+                missed_fire_policy,
+                next_fire_at: to_timestamp(next),
+                created_at,
+            },
+            template: invocation,
+        };
+        let id = record.invocation.id;
+
+        self.key_value_storage
+            .with_entity("scheduler", "schedule_recurring", "recurring_invocation")
+            .set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocation_key(&id),
+                &record,
+            )
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!("Failed to store recurring invocation: {err}"))
+            })?;
+        self.key_value_storage
+            .with_entity("scheduler", "schedule_recurring", "recurring_invocation_id")
+            .add_to_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocations_of_worker_key(owned_worker_id),
+                &id,
+            )
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!("Failed to store recurring invocation: {err}"))
+            })?;
+
+        self.schedule(
+            next,
+            ScheduledAction::InvokeRecurring {
+                owned_worker_id: owned_worker_id.clone(),
+                id,
+                scheduled_for: record.invocation.next_fire_at,
+            },
+        )
+        .await;
+
+        Ok(record.invocation)
+    }
+
+    async fn list_recurring(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<RecurringInvocation>, WorkerExecutorError> {
+        let ids: Vec<RecurringInvocationId> = self
+            .key_value_storage
+            .with_entity("scheduler", "list_recurring", "recurring_invocation_id")
+            .members_of_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::recurring_invocations_of_worker_key(owned_worker_id),
+            )
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!("Failed to get recurring invocations: {err}"))
+            })?;
+
+        let records: Vec<Option<RecurringInvocationRecord>> = self
+            .key_value_storage
+            .with_entity("scheduler", "list_recurring", "recurring_invocation")
+            .get_many(
+                KeyValueStorageNamespace::Schedule,
+                ids.iter().map(Self::recurring_invocation_key).collect(),
+            )
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!("Failed to get recurring invocations: {err}"))
+            })?;
+
+        let mut result = records
+            .into_iter()
+            .flatten()
+            .map(|record| record.invocation)
+            .collect::<Vec<_>>();
+        result.sort_by_key(|invocation| invocation.created_at);
+        Ok(result)
+    }
+
+    async fn cancel_recurring(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: RecurringInvocationId,
+    ) -> Result<bool, WorkerExecutorError> {
+        let record = self.get_recurring_record(id).await.map_err(|err| {
+            WorkerExecutorError::runtime(format!("Failed to get recurring invocation: {err}"))
+        })?;
+
+        match record {
+            Some(record) if &record.owned_worker_id == owned_worker_id => {
+                self.remove_recurring_record(&record).await.map_err(|err| {
+                    WorkerExecutorError::runtime(format!(
+                        "Failed to remove recurring invocation: {err}"
+                    ))
+                })?;
+
+                let next_fire_at = to_date_time(record.invocation.next_fire_at);
+                let (hours_since_epoch, _) = Self::split_time(next_fire_at);
+                self.cancel(ScheduleId {
+                    timestamp: hours_since_epoch,
+                    action: ScheduledAction::InvokeRecurring {
+                        owned_worker_id: record.owned_worker_id,
+                        id,
+                        scheduled_for: record.invocation.next_fire_at,
+                    },
+                })
+                .await;
+
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn process_due(&self, now: DateTime<Utc>) -> Result<(), String> {
+        self.process(now).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::services::oplog::{Oplog, OplogService, PrimaryOplogService};
-    use crate::services::promise::PromiseServiceMock;
+    use crate::services::promise::{PromiseService, PromiseServiceMock};
     use crate::services::scheduler::{
         SchedulerService, SchedulerServiceDefault, SchedulerWorkerAccess,
     };
     use crate::services::shard::{ShardService, ShardServiceDefault};
-    use crate::services::worker::{GetWorkerMetadataResult, WorkerService};
+    use crate::services::worker::{GetWorkerMetadataResult, IndexedWorker, WorkerService};
     use crate::storage::indexed::memory::InMemoryIndexedStorage;
     use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
     use async_trait::async_trait;
     use chrono::DateTime;
     use desert_rust::BinarySerializer;
     use golem_common::model::account::AccountId;
-    use golem_common::model::agent::AgentMode;
+    use golem_common::model::agent::{AgentMode, Principal, UntypedDataValue};
     use golem_common::model::component::ComponentId;
     use golem_common::model::environment::EnvironmentId;
+    use golem_common::model::invocation_context::InvocationContextStack;
     use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::recurring_invocation::{MissedFirePolicy, RecurringSchedule};
+    use golem_common::model::regions::OplogRegion;
     use golem_common::model::WorkerStatusRecord;
     use golem_common::model::{
-        AgentInvocation, OwnedWorkerId, PromiseId, ScheduledAction, ShardId, WorkerId,
+        AgentInvocation, IdempotencyKey, OwnedWorkerId, PromiseId, PromiseOutcome, ScheduledAction,
+        ShardId, Timestamp, WorkerId, WorkerMetadata,
     };
     use golem_service_base::error::worker_executor::WorkerExecutorError;
     use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
     use std::collections::{HashMap, HashSet};
     use std::str::FromStr;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use test_r::test;
     use tokio_util::sync::CancellationToken;
@@ -495,20 +1041,92 @@ mod tests {
         ) -> Result<(), WorkerExecutorError> {
             unimplemented!()
         }
+        async fn compensate_open_sagas(
+            &self,
+            _created_by: AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<bool, WorkerExecutorError> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct SchedulerWorkerAccessRecorder {
+        invocations: Mutex<Vec<(OwnedWorkerId, AgentInvocation)>>,
+    }
+
+    impl SchedulerWorkerAccessRecorder {
+        fn invocations(&self) -> Vec<(OwnedWorkerId, AgentInvocation)> {
+            self.invocations.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl SchedulerWorkerAccess for SchedulerWorkerAccessRecorder {
+        async fn activate_worker(&self, _created_by: AccountId, _owned_worker_id: &OwnedWorkerId) {}
+        async fn open_oplog(
+            &self,
+            _created_by: AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Arc<dyn Oplog>, WorkerExecutorError> {
+            unimplemented!()
+        }
+        async fn enqueue_invocation(
+            &self,
+            _created_by: AccountId,
+            owned_worker_id: &OwnedWorkerId,
+            invocation: AgentInvocation,
+        ) -> Result<(), WorkerExecutorError> {
+            self.invocations
+                .lock()
+                .unwrap()
+                .push((owned_worker_id.clone(), invocation));
+            Ok(())
+        }
+        async fn compensate_open_sagas(
+            &self,
+            _created_by: AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<bool, WorkerExecutorError> {
+            unimplemented!()
+        }
     }
 
-    struct WorkerServiceMock;
+    struct WorkerServiceMock {
+        agents_exist: bool,
+    }
 
     #[async_trait]
     impl WorkerService for WorkerServiceMock {
-        async fn get(&self, _owned_worker_id: &OwnedWorkerId) -> Option<GetWorkerMetadataResult> {
-            unimplemented!()
+        async fn get(&self, owned_worker_id: &OwnedWorkerId) -> Option<GetWorkerMetadataResult> {
+            self.agents_exist.then(|| GetWorkerMetadataResult {
+                initial_worker_metadata: WorkerMetadata::default(
+                    owned_worker_id.worker_id.clone(),
+                    AccountId::new(),
+                    owned_worker_id.environment_id,
+                ),
+                last_known_status: None,
+            })
         }
 
         async fn get_running_workers_in_shards(&self) -> Vec<GetWorkerMetadataResult> {
             unimplemented!()
         }
 
+        async fn get_oplog_retention_candidates_in_shards(&self) -> Vec<OwnedWorkerId> {
+            unimplemented!()
+        }
+
+        async fn remove_oplog_retention_candidate(&self, _owned_worker_id: &OwnedWorkerId) {}
+
+        async fn set_discarded_oplog_region(
+            &self,
+            _owned_worker_id: &OwnedWorkerId,
+            _region: &OplogRegion,
+        ) {
+            unimplemented!()
+        }
+
         async fn remove(&self, _owned_worker_id: &OwnedWorkerId) {}
 
         async fn remove_cached_status(&self, _owned_worker_id: &OwnedWorkerId) {}
@@ -520,6 +1138,29 @@ mod tests {
             _agent_mode: AgentMode,
         ) {
         }
+
+        async fn add_to_index(
+            &self,
+            _worker_id: &WorkerId,
+            _created_at: Timestamp,
+            _status_value: Option<&WorkerStatusRecord>,
+        ) {
+        }
+
+        async fn get_index_page(
+            &self,
+            _component_id: &ComponentId,
+            _min_created_at: u64,
+            _count: u64,
+        ) -> (Option<u64>, Vec<IndexedWorker>) {
+            unimplemented!()
+        }
+
+        async fn is_index_complete(&self, _component_id: &ComponentId) -> bool {
+            false
+        }
+
+        async fn mark_index_complete(&self, _component_id: &ComponentId) {}
     }
 
     fn serialized_bytes<T: BinarySerializer>(entry: &T) -> Vec<u8> {
@@ -556,7 +1197,7 @@ mod tests {
     }
 
     fn create_worker_service_mock() -> Arc<dyn WorkerService> {
-        Arc::new(WorkerServiceMock)
+        Arc::new(WorkerServiceMock { agents_exist: true })
     }
 
     #[test]
@@ -1240,4 +1881,354 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[test]
+    pub async fn expired_promises_are_completed_unless_already_completed() {
+        let worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "inst1".to_string(),
+        };
+        let environment_id = EnvironmentId::new();
+
+        let p1 = PromiseId {
+            worker_id: worker_id.clone(),
+            oplog_idx: OplogIndex::from_u64(10),
+        };
+        let p2 = PromiseId {
+            worker_id: worker_id.clone(),
+            oplog_idx: OplogIndex::from_u64(20),
+        };
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let promise_service = create_promise_service_mock();
+
+        let svc = SchedulerServiceDefault::new(
+            kvs,
+            create_shard_service_mock(),
+            promise_service.clone(),
+            create_worker_access_mock(),
+            create_oplog_service_mock().await,
+            create_worker_service_mock(),
+            Duration::from_secs(1000), // explicitly calling process for testing
+            CancellationToken::new(),
+        );
+
+        let account_id = AccountId::new();
+
+        promise_service
+            .complete(
+                p2.clone(),
+                PromiseOutcome::Completed(vec![1, 2, 3]),
+                account_id,
+            )
+            .await
+            .unwrap();
+
+        for promise_id in [&p1, &p2] {
+            svc.schedule(
+                DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+                ScheduledAction::ExpirePromise {
+                    account_id,
+                    environment_id,
+                    promise_id: promise_id.clone(),
+                },
+            )
+            .await;
+        }
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            promise_service.outcome(&p1).await,
+            Some(PromiseOutcome::Expired)
+        );
+        assert_eq!(
+            promise_service.outcome(&p2).await,
+            Some(PromiseOutcome::Completed(vec![1, 2, 3]))
+        );
+    }
+
+    fn recurring_test_invocation() -> AgentInvocation {
+        AgentInvocation::AgentMethod {
+            idempotency_key: IdempotencyKey::fresh(),
+            method_name: "tick".to_string(),
+            input: UntypedDataValue::Tuple(vec![]),
+            invocation_context: InvocationContextStack::fresh(),
+            principal: Principal::anonymous(),
+        }
+    }
+
+    fn recurring_test_worker() -> OwnedWorkerId {
+        OwnedWorkerId {
+            environment_id: EnvironmentId::new(),
+            worker_id: WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "recurring".to_string(),
+            },
+        }
+    }
+
+    async fn create_recurring_test_scheduler(
+        kvs: Arc<InMemoryKeyValueStorage>,
+        worker_access: Arc<SchedulerWorkerAccessRecorder>,
+    ) -> SchedulerServiceDefault {
+        create_recurring_test_scheduler_with_agents(kvs, worker_access, true).await
+    }
+
+    async fn create_recurring_test_scheduler_with_agents(
+        kvs: Arc<InMemoryKeyValueStorage>,
+        worker_access: Arc<SchedulerWorkerAccessRecorder>,
+        agents_exist: bool,
+    ) -> SchedulerServiceDefault {
+        SchedulerServiceDefault::new(
+            kvs,
+            create_shard_service_mock(),
+            create_promise_service_mock(),
+            worker_access,
+            create_oplog_service_mock().await,
+            Arc::new(WorkerServiceMock { agents_exist }),
+            Duration::from_secs(1000), // explicitly calling process for testing
+            CancellationToken::new(),
+        )
+    }
+
+    #[test]
+    pub async fn recurring_invocations_can_be_listed_and_cancelled() {
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+        let svc = create_recurring_test_scheduler(kvs.clone(), worker_access).await;
+
+        let account_id = AccountId::new();
+        let owned_worker_id = recurring_test_worker();
+
+        let r1 = svc
+            .schedule_recurring(
+                account_id,
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::interval(Duration::from_secs(60)),
+                MissedFirePolicy::RunOnce,
+            )
+            .await
+            .unwrap();
+        let r2 = svc
+            .schedule_recurring(
+                account_id,
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::cron("*/5 * * * *"),
+                MissedFirePolicy::Skip,
+            )
+            .await
+            .unwrap();
+
+        let listed = svc.list_recurring(&owned_worker_id).await.unwrap();
+        assert_eq!(listed.len(), 2);
+        assert!(listed.contains(&r1));
+        assert!(listed.contains(&r2));
+        assert_eq!(
+            r1.next_fire_at.to_millis() - r1.created_at.to_millis(),
+            60000
+        );
+
+        assert!(svc.cancel_recurring(&owned_worker_id, r1.id).await.unwrap());
+        assert!(!svc.cancel_recurring(&owned_worker_id, r1.id).await.unwrap());
+        assert!(!svc
+            .cancel_recurring(&recurring_test_worker(), r2.id)
+            .await
+            .unwrap());
+
+        let listed = svc.list_recurring(&owned_worker_id).await.unwrap();
+        assert_eq!(listed, vec![r2]);
+
+        let mut scheduled = 0;
+        kvs.sorted_sets()
+            .iter_async(|_key, entry| {
+                scheduled += entry.len();
+                true
+            })
+            .await;
+        assert_eq!(scheduled, 1);
+    }
+
+    #[test]
+    pub async fn recurring_invalid_schedules_are_rejected() {
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+        let svc = create_recurring_test_scheduler(kvs, worker_access).await;
+
+        let account_id = AccountId::new();
+        let owned_worker_id = recurring_test_worker();
+
+        let result = svc
+            .schedule_recurring(
+                account_id,
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::cron("61 * * * *"),
+                MissedFirePolicy::RunOnce,
+            )
+            .await;
+        assert!(result.is_err());
+
+        let result = svc
+            .schedule_recurring(
+                account_id,
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::interval(Duration::from_millis(10)),
+                MissedFirePolicy::RunOnce,
+            )
+            .await;
+        assert!(result.is_err());
+
+        assert!(svc
+            .list_recurring(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    async fn fire_missed_occurrences(policy: MissedFirePolicy) -> Vec<AgentInvocation> {
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+        let svc = create_recurring_test_scheduler(kvs, worker_access.clone()).await;
+
+        let owned_worker_id = recurring_test_worker();
+        let recurring = svc
+            .schedule_recurring(
+                AccountId::new(),
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::interval(Duration::from_secs(60)),
+                policy,
+            )
+            .await
+            .unwrap();
+
+        // Three occurrences are due
+        let now = Timestamp::from(recurring.created_at.to_millis() + 3 * 60000 + 30000);
+        svc.process(DateTime::from_timestamp_millis(now.to_millis() as i64).unwrap())
+            .await
+            .unwrap();
+
+        let listed = svc.list_recurring(&owned_worker_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(
+            listed[0].next_fire_at.to_millis(),
+            recurring.created_at.to_millis() + 4 * 60000
+        );
+
+        worker_access
+            .invocations()
+            .into_iter()
+            .map(|(worker_id, invocation)| {
+                assert_eq!(worker_id, owned_worker_id);
+                invocation
+            })
+            .collect()
+    }
+
+    #[test]
+    pub async fn recurring_missed_occurrences_skip() {
+        let invocations = fire_missed_occurrences(MissedFirePolicy::Skip).await;
+        assert!(invocations.is_empty());
+    }
+
+    #[test]
+    pub async fn recurring_missed_occurrences_run_once() {
+        let invocations = fire_missed_occurrences(MissedFirePolicy::RunOnce).await;
+        assert_eq!(invocations.len(), 1);
+    }
+
+    #[test]
+    pub async fn recurring_missed_occurrences_catch_up() {
+        let invocations = fire_missed_occurrences(MissedFirePolicy::CatchUp).await;
+        let idempotency_keys = invocations
+            .iter()
+            .map(|invocation| invocation.idempotency_key().cloned())
+            .collect::<HashSet<_>>();
+        assert_eq!(invocations.len(), 3);
+        assert_eq!(idempotency_keys.len(), 3);
+    }
+
+    #[test]
+    pub async fn recurring_missed_occurrences_catch_up_is_limited() {
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+        let svc = create_recurring_test_scheduler(kvs, worker_access.clone()).await;
+
+        let owned_worker_id = recurring_test_worker();
+        let recurring = svc
+            .schedule_recurring(
+                AccountId::new(),
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::interval(Duration::from_secs(60)),
+                MissedFirePolicy::CatchUp,
+            )
+            .await
+            .unwrap();
+
+        // 150 occurrences are due
+        let now = recurring.created_at.to_millis() + 150 * 60000 + 30000;
+        svc.process(DateTime::from_timestamp_millis(now as i64).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            worker_access.invocations().len(),
+            SchedulerServiceDefault::MAX_MISSED_OCCURRENCES
+        );
+        let listed = svc.list_recurring(&owned_worker_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(
+            listed[0].next_fire_at.to_millis(),
+            recurring.created_at.to_millis() + 151 * 60000
+        );
+    }
+
+    #[test]
+    pub async fn recurring_invocations_of_deleted_agents_are_removed() {
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+        let worker_access = Arc::new(SchedulerWorkerAccessRecorder::default());
+        let svc =
+            create_recurring_test_scheduler_with_agents(kvs.clone(), worker_access.clone(), false)
+                .await;
+
+        let owned_worker_id = recurring_test_worker();
+        let recurring = svc
+            .schedule_recurring(
+                AccountId::new(),
+                &owned_worker_id,
+                recurring_test_invocation(),
+                RecurringSchedule::interval(Duration::from_secs(60)),
+                MissedFirePolicy::RunOnce,
+            )
+            .await
+            .unwrap();
+
+        let now = recurring.created_at.to_millis() + 90000;
+        svc.process(DateTime::from_timestamp_millis(now as i64).unwrap())
+            .await
+            .unwrap();
+
+        assert!(worker_access.invocations().is_empty());
+        assert!(svc
+            .list_recurring(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let mut scheduled = 0;
+        kvs.sorted_sets()
+            .iter_async(|_key, entry| {
+                scheduled += entry.len();
+                true
+            })
+            .await;
+        assert_eq!(scheduled, 0);
+    }
 }
@@ -16,6 +16,7 @@ use crate::metrics::sharding::*;
 use crate::model::ShardAssignmentCheck;
 use golem_common::model::{ShardAssignment, ShardId, WorkerId};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::fault_injection::{Fault, FaultInjectionTarget, FaultInjector};
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::convert::identity;
@@ -149,8 +150,171 @@ impl ShardService for ShardServiceDefault {
     }
 }
 
+/// Injects faults into the shard assignment changes received from the shard manager. A partial
+/// write applies the change but still reports it as failed to the shard manager.
+pub struct ShardServiceFaultInjecting {
+    inner: Arc<dyn ShardService>,
+    injector: Arc<FaultInjector>,
+}
+
+impl ShardServiceFaultInjecting {
+    pub fn wrap(
+        inner: Arc<dyn ShardService>,
+        fault_injector: &Option<Arc<FaultInjector>>,
+    ) -> Arc<dyn ShardService> {
+        match fault_injector {
+            Some(injector) if injector.applies_to(FaultInjectionTarget::ShardManager) => {
+                Arc::new(Self {
+                    inner,
+                    injector: injector.clone(),
+                })
+            }
+            _ => inner,
+        }
+    }
+
+    fn with_fault(
+        &self,
+        api_name: &'static str,
+        f: impl FnOnce() -> Result<(), WorkerExecutorError>,
+    ) -> Result<(), WorkerExecutorError> {
+        let error = || {
+            WorkerExecutorError::unknown(FaultInjector::error_message(
+                FaultInjectionTarget::ShardManager,
+                api_name,
+            ))
+        };
+        match self.injector.next_fault_without_delay(true) {
+            Fault::None => f(),
+            Fault::Error => Err(error()),
+            Fault::PartialWrite => {
+                f()?;
+                Err(error())
+            }
+        }
+    }
+}
+
+impl ShardService for ShardServiceFaultInjecting {
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn assign_shards(&self, shard_ids: &HashSet<ShardId>) -> Result<(), WorkerExecutorError> {
+        self.with_fault("assign_shards", || self.inner.assign_shards(shard_ids))
+    }
+
+    fn check_worker(&self, worker_id: &WorkerId) -> Result<(), WorkerExecutorError> {
+        self.inner.check_worker(worker_id)
+    }
+
+    fn register(&self, number_of_shards: usize, shard_ids: &HashSet<ShardId>) {
+        self.inner.register(number_of_shards, shard_ids)
+    }
+
+    fn revoke_shards(&self, shard_ids: &HashSet<ShardId>) -> Result<(), WorkerExecutorError> {
+        self.with_fault("revoke_shards", || self.inner.revoke_shards(shard_ids))
+    }
+
+    fn current_assignment(&self) -> Result<ShardAssignment, WorkerExecutorError> {
+        self.inner.current_assignment()
+    }
+
+    fn try_get_current_assignment(&self) -> Option<ShardAssignment> {
+        self.inner.try_get_current_assignment()
+    }
+}
+
 fn sharding_not_ready_error() -> WorkerExecutorError {
     WorkerExecutorError::Unknown {
         details: "Sharding is not ready".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ShardService, ShardServiceDefault, ShardServiceFaultInjecting};
+    use golem_common::model::ShardId;
+    use golem_service_base::fault_injection::{FaultInjectionConfig, FaultInjector};
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use test_r::test;
+
+    fn fault_injecting(
+        error_probability: f64,
+        partial_write_probability: f64,
+    ) -> Arc<dyn ShardService> {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            error_probability,
+            partial_write_probability,
+            delay_probability: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let inner: Arc<dyn ShardService> = Arc::new(ShardServiceDefault::new());
+        inner.register(4, &HashSet::new());
+        ShardServiceFaultInjecting::wrap(inner, &Some(Arc::new(injector)))
+    }
+
+    fn shards(ids: &[i64]) -> HashSet<ShardId> {
+        ids.iter().map(|id| ShardId::new(*id)).collect()
+    }
+
+    #[test]
+    fn failed_assign_and_revoke_do_not_change_the_assignment() {
+        let service = fault_injecting(1.0, 0.0);
+
+        assert!(service.assign_shards(&shards(&[1, 2])).is_err());
+        assert!(service.current_assignment().unwrap().shard_ids.is_empty());
+
+        assert!(service.revoke_shards(&shards(&[1])).is_err());
+        assert!(service.current_assignment().unwrap().shard_ids.is_empty());
+    }
+
+    #[test]
+    fn partially_written_assign_and_revoke_change_the_assignment_but_fail() {
+        let service = fault_injecting(0.0, 1.0);
+
+        assert!(service.assign_shards(&shards(&[1, 2])).is_err());
+        assert_eq!(
+            service.current_assignment().unwrap().shard_ids,
+            shards(&[1, 2])
+        );
+
+        assert!(service.revoke_shards(&shards(&[1])).is_err());
+        assert_eq!(
+            service.current_assignment().unwrap().shard_ids,
+            shards(&[2])
+        );
+    }
+
+    #[test]
+    fn assign_and_revoke_pass_through_without_faults() {
+        let service = fault_injecting(0.0, 0.0);
+
+        service.assign_shards(&shards(&[1, 2, 3])).unwrap();
+        service.revoke_shards(&shards(&[2])).unwrap();
+        assert_eq!(
+            service.current_assignment().unwrap().shard_ids,
+            shards(&[1, 3])
+        );
+    }
+
+    #[test]
+    fn disabled_shard_manager_faults_keep_the_inner_service() {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            shard_manager: false,
+            error_probability: 1.0,
+            partial_write_probability: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let inner: Arc<dyn ShardService> = Arc::new(ShardServiceDefault::new());
+        inner.register(4, &HashSet::new());
+        let service = ShardServiceFaultInjecting::wrap(inner, &Some(Arc::new(injector)));
+
+        service.assign_shards(&shards(&[1])).unwrap();
+    }
+}
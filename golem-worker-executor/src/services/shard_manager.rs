@@ -19,6 +19,7 @@ use golem_api_grpc::proto::golem::shardmanager::v1::shard_manager_service_client
 use golem_common::model::{RetryConfig, ShardAssignment, ShardId};
 use golem_common::retries::with_retries;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::fault_injection::{Fault, FaultInjectionTarget, FaultInjector};
 use golem_service_base::grpc::client::GrpcClient;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -36,8 +37,11 @@ pub trait ShardManagerService: Send + Sync {
     ) -> Result<ShardAssignment, WorkerExecutorError>;
 }
 
-pub fn configured(config: &ShardManagerServiceConfig) -> Arc<dyn ShardManagerService> {
-    match config {
+pub fn configured(
+    config: &ShardManagerServiceConfig,
+    fault_injector: &Option<Arc<FaultInjector>>,
+) -> Arc<dyn ShardManagerService> {
+    let service: Arc<dyn ShardManagerService> = match config {
         ShardManagerServiceConfig::Grpc(config) => {
             tracing::info!("Using grpc shard manager");
             Arc::new(ShardManagerServiceGrpc::new(config))
@@ -46,6 +50,13 @@ pub fn configured(config: &ShardManagerServiceConfig) -> Arc<dyn ShardManagerSer
             tracing::info!("Using single shard shard manager");
             Arc::new(ShardManagerServiceSingleShard::new())
         }
+    };
+
+    match fault_injector {
+        Some(injector) if injector.applies_to(FaultInjectionTarget::ShardManager) => Arc::new(
+            ShardManagerServiceFaultInjecting::new(service, injector.clone()),
+        ),
+        _ => service,
     }
 }
 
@@ -160,3 +171,32 @@ impl ShardManagerService for ShardManagerServiceSingleShard {
         ))
     }
 }
+
+/// Shard manager client injecting the faults of a [`FaultInjector`], simulating a network
+/// partition between the executor and the shard manager
+pub struct ShardManagerServiceFaultInjecting {
+    inner: Arc<dyn ShardManagerService>,
+    injector: Arc<FaultInjector>,
+}
+
+impl ShardManagerServiceFaultInjecting {
+    pub fn new(inner: Arc<dyn ShardManagerService>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+}
+
+#[async_trait]
+impl ShardManagerService for ShardManagerServiceFaultInjecting {
+    async fn register(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<ShardAssignment, WorkerExecutorError> {
+        match self.injector.next_fault(false).await {
+            Fault::None => self.inner.register(host, port).await,
+            Fault::Error | Fault::PartialWrite => Err(WorkerExecutorError::unknown(
+                FaultInjector::error_message(FaultInjectionTarget::ShardManager, "register"),
+            )),
+        }
+    }
+}
@@ -23,10 +23,13 @@ use crate::storage::keyvalue::{
 };
 use crate::worker::status::calculate_last_known_status_for_existing_worker;
 use async_trait::async_trait;
+use desert_rust::BinaryCodec;
 use golem_common::model::agent::{AgentId, AgentMode};
+use golem_common::model::component::{ComponentId, ComponentRevision};
 use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::regions::OplogRegion;
 use golem_common::model::{
-    OwnedWorkerId, ShardId, WorkerId, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+    OwnedWorkerId, ShardId, Timestamp, WorkerId, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
 use std::sync::Arc;
 use tracing::debug;
@@ -39,6 +42,50 @@ pub struct GetWorkerMetadataResult {
     pub last_known_status: Option<WorkerStatusRecord>,
 }
 
+/// The indexed subset of a worker's last known status, used to evaluate filters during
+/// enumeration without reading the worker's oplog
+#[derive(Debug, Clone, PartialEq, BinaryCodec)]
+#[desert(evolution())]
+pub struct WorkerIndexEntry {
+    pub status: WorkerStatus,
+    pub component_revision: ComponentRevision,
+    pub last_invocation_at: Option<Timestamp>,
+}
+
+impl From<&WorkerStatusRecord> for WorkerIndexEntry {
+    fn from(value: &WorkerStatusRecord) -> Self {
+        Self {
+            status: value.status.clone(),
+            component_revision: value.component_revision,
+            last_invocation_at: value.last_invocation_at,
+        }
+    }
+}
+
+/// Returns true if the worker has oplog entries before its latest snapshot that have not been
+/// discarded by the oplog retention policy yet.
+pub fn has_discardable_oplog_history(status: &WorkerStatusRecord) -> bool {
+    match status.last_snapshot_index {
+        Some(snapshot_idx) => {
+            let first_retained = status
+                .discarded_region
+                .as_ref()
+                .map(|region| region.end.next())
+                .unwrap_or(OplogIndex::INITIAL.next());
+            snapshot_idx > first_retained
+        }
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexedWorker {
+    pub worker_id: WorkerId,
+    pub created_at: Timestamp,
+    // None if the worker is indexed but its status was not cached yet
+    pub entry: Option<WorkerIndexEntry>,
+}
+
 /// Service for persisting the current set of Golem workers represented by their metadata
 #[async_trait]
 pub trait WorkerService: Send + Sync {
@@ -46,6 +93,21 @@ pub trait WorkerService: Send + Sync {
 
     async fn get_running_workers_in_shards(&self) -> Vec<GetWorkerMetadataResult>;
 
+    /// Returns the workers of the assigned shards which have oplog history before their latest
+    /// snapshot that was not discarded yet, whether they are loaded or not.
+    async fn get_oplog_retention_candidates_in_shards(&self) -> Vec<OwnedWorkerId>;
+
+    async fn remove_oplog_retention_candidate(&self, owned_worker_id: &OwnedWorkerId);
+
+    /// Durably records the region of the worker's oplog that was discarded by the oplog retention
+    /// policy. Unlike the cached status, it is kept as long as the worker exists, so the worker's
+    /// status can always be recalculated around the missing oplog history.
+    async fn set_discarded_oplog_region(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        region: &OplogRegion,
+    );
+
     async fn remove(&self, owned_worker_id: &OwnedWorkerId);
 
     async fn remove_cached_status(&self, owned_worker_id: &OwnedWorkerId);
@@ -56,6 +118,31 @@ pub trait WorkerService: Send + Sync {
         status_value: &WorkerStatusRecord,
         agent_mode: AgentMode,
     );
+
+    /// Adds the worker to the enumeration index of its component. The given status is only indexed
+    /// if the worker has no indexed status yet.
+    async fn add_to_index(
+        &self,
+        worker_id: &WorkerId,
+        created_at: Timestamp,
+        status_value: Option<&WorkerStatusRecord>,
+    );
+
+    /// Returns a page of the enumeration index of a component, ordered by the workers' creation time,
+    /// starting with the workers created at `min_created_at` (in milliseconds). Workers created in
+    /// the same millisecond are never split between pages, so a page may contain more than `count`
+    /// workers. Also returns the `min_created_at` of the next page, or `None` if this was the last one.
+    async fn get_index_page(
+        &self,
+        component_id: &ComponentId,
+        min_created_at: u64,
+        count: u64,
+    ) -> (Option<u64>, Vec<IndexedWorker>);
+
+    /// Whether every worker of the component has been added to the enumeration index
+    async fn is_index_complete(&self, component_id: &ComponentId) -> bool;
+
+    async fn mark_index_complete(&self, component_id: &ComponentId);
 }
 
 #[derive(Clone)]
@@ -84,6 +171,29 @@ impl DefaultWorkerService {
         }
     }
 
+    /// The status to calculate the worker's status from when there is no usable cached status. If
+    /// part of the oplog was discarded, the calculation has to skip it, as it cannot be read anymore.
+    async fn initial_status(&self, owned_worker_id: &OwnedWorkerId) -> Option<WorkerStatusRecord> {
+        let discarded_region: Option<OplogRegion> = self
+            .key_value_storage
+            .with_entity("worker", "get", "discarded_oplog_region")
+            .get(
+                KeyValueStorageNamespace::Worker {
+                    worker_id: owned_worker_id.worker_id(),
+                },
+                &Self::discarded_oplog_region_key(&owned_worker_id.worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to get discarded oplog region for {owned_worker_id} from KV storage: {err}")
+            });
+
+        discarded_region.map(|region| WorkerStatusRecord {
+            discarded_region: Some(region),
+            ..WorkerStatusRecord::default()
+        })
+    }
+
     async fn enum_workers_at_key(&self, key: &str) -> Vec<GetWorkerMetadataResult> {
         record_worker_call("enum");
 
@@ -111,9 +221,29 @@ impl DefaultWorkerService {
         format!("worker:status:{}", worker_id.to_redis_key())
     }
 
+    fn discarded_oplog_region_key(worker_id: &WorkerId) -> String {
+        format!("worker:discarded_oplog_region:{}", worker_id.to_redis_key())
+    }
+
     fn running_in_shard_key(shard_id: &ShardId) -> String {
         format!("worker:running_in_shard:{shard_id}")
     }
+
+    fn oplog_retention_candidates_in_shard_key(shard_id: &ShardId) -> String {
+        format!("worker:oplog_retention_candidates_in_shard:{shard_id}")
+    }
+
+    fn index_key(component_id: &ComponentId) -> String {
+        format!("worker:index:{component_id}")
+    }
+
+    fn index_entry_key(worker_id: &WorkerId) -> String {
+        format!("worker:index_entry:{}", worker_id.to_redis_key())
+    }
+
+    fn index_complete_key(component_id: &ComponentId) -> String {
+        format!("worker:index_complete:{component_id}")
+    }
 }
 
 #[async_trait]
@@ -208,7 +338,7 @@ impl WorkerService for DefaultWorkerService {
                         let last_known_status = calculate_last_known_status_for_existing_worker(
                             self,
                             owned_worker_id,
-                            None,
+                            self.initial_status(owned_worker_id).await,
                         )
                         .await;
 
@@ -221,7 +351,7 @@ impl WorkerService for DefaultWorkerService {
 
                         Some(last_known_status)
                     }
-                    None => None,
+                    None => self.initial_status(owned_worker_id).await,
                 };
 
                 Some(GetWorkerMetadataResult {
@@ -246,12 +376,96 @@ impl WorkerService for DefaultWorkerService {
         result
     }
 
+    async fn get_oplog_retention_candidates_in_shards(&self) -> Vec<OwnedWorkerId> {
+        record_worker_call("get_oplog_retention_candidates");
+
+        let shard_assignment = self.shard_service.try_get_current_assignment();
+        let mut result = Vec::new();
+        if let Some(shard_assignment) = shard_assignment {
+            for shard_id in shard_assignment.shard_ids {
+                let mut candidates: Vec<OwnedWorkerId> = self
+                    .key_value_storage
+                    .with_entity("worker", "get_oplog_retention_candidates", "worker_id")
+                    .members_of_set(
+                        KeyValueStorageNamespace::RunningWorkers,
+                        &Self::oplog_retention_candidates_in_shard_key(&shard_id),
+                    )
+                    .await
+                    .unwrap_or_else(|err| {
+                        panic!("failed to get oplog retention candidates from KV storage: {err}")
+                    });
+                result.append(&mut candidates);
+            }
+        }
+        result
+    }
+
+    async fn remove_oplog_retention_candidate(&self, owned_worker_id: &OwnedWorkerId) {
+        record_worker_call("remove_oplog_retention_candidate");
+
+        let shard_assignment = self
+            .shard_service
+            .current_assignment()
+            .expect("sharding assignment is not ready");
+        let shard_id = ShardId::from_worker_id(
+            &owned_worker_id.worker_id,
+            shard_assignment.number_of_shards,
+        );
+
+        self.key_value_storage
+            .with_entity("worker", "remove_oplog_retention_candidate", "worker_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::RunningWorkers,
+                &Self::oplog_retention_candidates_in_shard_key(&shard_id),
+                owned_worker_id,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove oplog retention candidate from KV storage: {err}")
+            });
+    }
+
+    async fn set_discarded_oplog_region(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        region: &OplogRegion,
+    ) {
+        record_worker_call("set_discarded_oplog_region");
+
+        self.key_value_storage
+            .with_entity("worker", "set_discarded_oplog_region", "discarded_oplog_region")
+            .set(
+                KeyValueStorageNamespace::Worker {
+                    worker_id: owned_worker_id.worker_id(),
+                },
+                &Self::discarded_oplog_region_key(&owned_worker_id.worker_id),
+                region,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to set discarded oplog region for {owned_worker_id} in KV storage: {err}")
+            });
+    }
+
     async fn remove(&self, owned_worker_id: &OwnedWorkerId) {
         record_worker_call("remove");
 
         self.oplog_service.delete(owned_worker_id).await;
         self.remove_cached_status(owned_worker_id).await;
 
+        self.key_value_storage
+            .with("worker", "remove")
+            .del(
+                KeyValueStorageNamespace::Worker {
+                    worker_id: owned_worker_id.worker_id(),
+                },
+                &Self::discarded_oplog_region_key(&owned_worker_id.worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove discarded oplog region in KV storage: {err}")
+            });
+
         let shard_assignment = self
             .shard_service
             .current_assignment()
@@ -271,6 +485,41 @@ impl WorkerService for DefaultWorkerService {
                     "failed to remove worker from the set of running worker ids per shard in KV storage: {err}"
                 )
             });
+
+        self.key_value_storage
+            .with_entity("worker", "remove", "worker_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::RunningWorkers,
+                &Self::oplog_retention_candidates_in_shard_key(&shard_id),
+                owned_worker_id,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove oplog retention candidate from KV storage: {err}")
+            });
+
+        self.key_value_storage
+            .with_entity("worker", "remove", "worker_name")
+            .remove_from_sorted_set(
+                KeyValueStorageNamespace::WorkerIndex,
+                &Self::index_key(&owned_worker_id.worker_id.component_id),
+                &owned_worker_id.worker_id.worker_name,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove worker from the worker index in KV storage: {err}")
+            });
+
+        self.key_value_storage
+            .with("worker", "remove")
+            .del(
+                KeyValueStorageNamespace::WorkerIndex,
+                &Self::index_entry_key(&owned_worker_id.worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to remove worker index entry in KV storage: {err}")
+            });
     }
 
     async fn remove_cached_status(&self, owned_worker_id: &OwnedWorkerId) {
@@ -313,6 +562,18 @@ impl WorkerService for DefaultWorkerService {
                 .await
                 .unwrap_or_else(|err| panic!("failed to set worker status in KV storage: {err}"));
 
+            self.key_value_storage
+                .with_entity("worker", "update_status", "worker_index_entry")
+                .set(
+                    KeyValueStorageNamespace::WorkerIndex,
+                    &Self::index_entry_key(&owned_worker_id.worker_id),
+                    &WorkerIndexEntry::from(status_value),
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to set worker index entry in KV storage: {err}")
+                });
+
             let shard_assignment = self
                 .shard_service
                 .current_assignment()
@@ -350,7 +611,175 @@ impl WorkerService for DefaultWorkerService {
                         )
                     });
             }
+
+            if has_discardable_oplog_history(status_value) {
+                self.key_value_storage
+                    .with_entity("worker", "add_oplog_retention_candidate", "worker_id")
+                    .add_to_set(
+                        KeyValueStorageNamespace::RunningWorkers,
+                        &Self::oplog_retention_candidates_in_shard_key(&shard_id),
+                        owned_worker_id,
+                    )
+                    .await
+                    .unwrap_or_else(|err| {
+                        panic!("failed to add oplog retention candidate to KV storage: {err}")
+                    });
+            }
+        }
+    }
+
+    async fn add_to_index(
+        &self,
+        worker_id: &WorkerId,
+        created_at: Timestamp,
+        status_value: Option<&WorkerStatusRecord>,
+    ) {
+        record_worker_call("add_to_index");
+
+        if let Some(status_value) = status_value {
+            self.key_value_storage
+                .with_entity("worker", "add_to_index", "worker_index_entry")
+                .set_if_not_exists(
+                    KeyValueStorageNamespace::WorkerIndex,
+                    &Self::index_entry_key(worker_id),
+                    &WorkerIndexEntry::from(status_value),
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to set worker index entry in KV storage: {err}")
+                });
+        }
+
+        self.key_value_storage
+            .with_entity("worker", "add_to_index", "worker_name")
+            .add_to_sorted_set(
+                KeyValueStorageNamespace::WorkerIndex,
+                &Self::index_key(&worker_id.component_id),
+                created_at.to_millis() as f64,
+                &worker_id.worker_name,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to add worker to the worker index in KV storage: {err}")
+            });
+    }
+
+    async fn get_index_page(
+        &self,
+        component_id: &ComponentId,
+        min_created_at: u64,
+        count: u64,
+    ) -> (Option<u64>, Vec<IndexedWorker>) {
+        record_worker_call("get_index_page");
+
+        let index_key = Self::index_key(component_id);
+        let mut worker_names: Vec<(f64, String)> = self
+            .key_value_storage
+            .with_entity("worker", "get_index_page", "worker_name")
+            .get_sorted_set_page(
+                KeyValueStorageNamespace::WorkerIndex,
+                &index_key,
+                min_created_at as f64,
+                count,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to get page of the worker index from KV storage: {err}")
+            });
+
+        // The next page starts after the creation time of the last worker on this page, so the
+        // page is completed with every other worker created in that same millisecond. Workers
+        // deleted in the meantime can then never shift others out of the enumeration.
+        let next_min_created_at = match worker_names.last() {
+            Some((last_score, _)) if worker_names.len() as u64 >= count => {
+                let last_score = *last_score;
+                let mut same_millisecond: Vec<(f64, String)> = self
+                    .key_value_storage
+                    .with_entity("worker", "get_index_page", "worker_name")
+                    .query_sorted_set(
+                        KeyValueStorageNamespace::WorkerIndex,
+                        &index_key,
+                        last_score,
+                        last_score,
+                    )
+                    .await
+                    .unwrap_or_else(|err| {
+                        panic!("failed to get page of the worker index from KV storage: {err}")
+                    });
+                same_millisecond.sort_by(|a, b| a.1.cmp(&b.1));
+                worker_names.retain(|(score, _)| *score < last_score);
+                worker_names.extend(same_millisecond);
+                Some(last_score as u64 + 1)
+            }
+            _ => None,
+        };
+
+        let worker_ids = worker_names
+            .into_iter()
+            .map(|(score, worker_name)| {
+                (
+                    WorkerId {
+                        component_id: *component_id,
+                        worker_name,
+                    },
+                    Timestamp::from(score as u64),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if worker_ids.is_empty() {
+            return (next_min_created_at, Vec::new());
         }
+
+        let entries: Vec<Option<WorkerIndexEntry>> = self
+            .key_value_storage
+            .with_entity("worker", "get_index_page", "worker_index_entry")
+            .get_many(
+                KeyValueStorageNamespace::WorkerIndex,
+                worker_ids
+                    .iter()
+                    .map(|(worker_id, _)| Self::index_entry_key(worker_id))
+                    .collect(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to get worker index entries from KV storage: {err}")
+            });
+
+        let workers = worker_ids
+            .into_iter()
+            .zip(entries)
+            .map(|((worker_id, created_at), entry)| IndexedWorker {
+                worker_id,
+                created_at,
+                entry,
+            })
+            .collect();
+
+        (next_min_created_at, workers)
+    }
+
+    async fn is_index_complete(&self, component_id: &ComponentId) -> bool {
+        self.key_value_storage
+            .with("worker", "is_index_complete")
+            .exists(
+                KeyValueStorageNamespace::WorkerIndex,
+                &Self::index_complete_key(component_id),
+            )
+            .await
+            .unwrap_or_else(|err| panic!("failed to check worker index state in KV storage: {err}"))
+    }
+
+    async fn mark_index_complete(&self, component_id: &ComponentId) {
+        self.key_value_storage
+            .with_entity("worker", "mark_index_complete", "worker_index_state")
+            .set(
+                KeyValueStorageNamespace::WorkerIndex,
+                &Self::index_complete_key(component_id),
+                &true,
+            )
+            .await
+            .unwrap_or_else(|err| panic!("failed to set worker index state in KV storage: {err}"));
     }
 }
 
@@ -1,17 +1,20 @@
 use crate::services::active_workers::ActiveWorkers;
 use crate::services::golem_config::GolemConfig;
 use crate::services::oplog::OplogService;
-use crate::services::worker::WorkerService;
+use crate::services::worker::{IndexedWorker, WorkerIndexEntry, WorkerService};
 use crate::services::{HasConfig, HasOplogService, HasWorkerService};
 use crate::worker::status::calculate_last_known_status_for_existing_worker;
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
+use golem_common::model::account::AccountId;
 use golem_common::model::component::ComponentId;
 use golem_common::model::environment::EnvironmentId;
-use golem_common::model::{ScanCursor, WorkerFilter, WorkerMetadata, WorkerStatus};
+use golem_common::model::{
+    OwnedWorkerId, ScanCursor, WorkerFilter, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use std::sync::Arc;
-use tracing::{info, Instrument};
+use tracing::{info, warn, Instrument};
 
 #[async_trait]
 pub trait RunningWorkerEnumerationService: Send + Sync {
@@ -80,11 +83,19 @@ pub trait WorkerEnumerationService: Send + Sync {
     ) -> Result<(Option<ScanCursor>, Vec<WorkerMetadata>), WorkerExecutorError>;
 }
 
+/// Cursor layer marking cursors of the index based enumeration. Their `cursor` field is the minimum
+/// creation time (in milliseconds) of the workers on the next page instead of an oplog scan cursor,
+/// so deleting workers between pages does not affect which workers the next page returns.
+const INDEX_CURSOR_LAYER: usize = usize::MAX;
+
+const INDEX_BACKFILL_BATCH_SIZE: u64 = 1000;
+
 #[derive(Clone)]
 pub struct DefaultWorkerEnumerationService {
     worker_service: Arc<dyn WorkerService>,
     oplog_service: Arc<dyn OplogService>,
     golem_config: Arc<GolemConfig>,
+    index_backfills: Arc<scc::HashSet<ComponentId>>,
 }
 
 impl DefaultWorkerEnumerationService {
@@ -97,6 +108,7 @@ impl DefaultWorkerEnumerationService {
             worker_service,
             oplog_service,
             golem_config,
+            index_backfills: Arc::new(scc::HashSet::new()),
         }
     }
 
@@ -153,6 +165,220 @@ impl DefaultWorkerEnumerationService {
 
         Ok((new_cursor.into_option(), workers))
     }
+
+    /// Enumerates workers using the component's worker index. Filters are evaluated on the indexed
+    /// status, and the full metadata is only read for the matching workers.
+    async fn get_indexed(
+        &self,
+        environment_id: &EnvironmentId,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        cursor: ScanCursor,
+        count: u64,
+    ) -> Result<(Option<ScanCursor>, Vec<WorkerMetadata>), WorkerExecutorError> {
+        let mut workers: Vec<WorkerMetadata> = vec![];
+
+        let min_created_at = if cursor.layer == INDEX_CURSOR_LAYER {
+            cursor.cursor
+        } else {
+            0
+        };
+
+        let (next_min_created_at, page) = self
+            .worker_service
+            .get_index_page(component_id, min_created_at, count)
+            .instrument(tracing::info_span!("get_index_page"))
+            .await;
+
+        let new_cursor = next_min_created_at.map(|cursor| ScanCursor {
+            cursor,
+            layer: INDEX_CURSOR_LAYER,
+        });
+
+        for indexed_worker in page {
+            let matches_index = match &indexed_worker.entry {
+                Some(entry) => filter.as_ref().is_none_or(|f| {
+                    f.matches(&indexed_worker_metadata(
+                        environment_id,
+                        &indexed_worker,
+                        entry,
+                    ))
+                }),
+                None => true,
+            };
+
+            if matches_index {
+                let owned_worker_id =
+                    OwnedWorkerId::new(*environment_id, &indexed_worker.worker_id);
+                let worker_metadata = self
+                    .worker_service
+                    .get(&owned_worker_id)
+                    .instrument(tracing::info_span!("get_worker_metadata"))
+                    .await;
+
+                if let Some(worker_metadata) = worker_metadata {
+                    let metadata = WorkerMetadata {
+                        last_known_status: worker_metadata.last_known_status.unwrap_or_default(),
+                        ..worker_metadata.initial_worker_metadata
+                    };
+
+                    // Workers without an indexed status are filtered by their full metadata
+                    if indexed_worker.entry.is_some()
+                        || filter.as_ref().is_none_or(|f| f.matches(&metadata))
+                    {
+                        workers.push(metadata);
+                    }
+                }
+            }
+        }
+
+        Ok((new_cursor, workers))
+    }
+
+    /// Starts adding all existing workers of the component to its worker index in the background,
+    /// unless it is already in progress on this executor.
+    async fn start_index_backfill(
+        &self,
+        environment_id: &EnvironmentId,
+        component_id: &ComponentId,
+    ) {
+        if self
+            .index_backfills
+            .insert_async(*component_id)
+            .await
+            .is_err()
+        {
+            return;
+        }
+
+        let this = self.clone();
+        let environment_id = *environment_id;
+        let component_id = *component_id;
+        tokio::spawn(
+            async move {
+                match this.backfill_index(&environment_id, &component_id).await {
+                    Ok(()) => info!("Worker index of component {component_id} is complete"),
+                    Err(err) => {
+                        warn!("Failed to build the worker index of component {component_id}: {err}")
+                    }
+                }
+                this.index_backfills.remove_async(&component_id).await;
+            }
+            .in_current_span(),
+        );
+    }
+
+    async fn backfill_index(
+        &self,
+        environment_id: &EnvironmentId,
+        component_id: &ComponentId,
+    ) -> Result<(), WorkerExecutorError> {
+        let mut cursor = ScanCursor::default();
+        loop {
+            let (new_cursor, keys) = self
+                .oplog_service
+                .scan_for_component(
+                    environment_id,
+                    component_id,
+                    cursor,
+                    INDEX_BACKFILL_BATCH_SIZE,
+                )
+                .await?;
+
+            for owned_worker_id in keys {
+                if let Some(worker_metadata) = self.worker_service.get(&owned_worker_id).await {
+                    self.worker_service
+                        .add_to_index(
+                            &owned_worker_id.worker_id,
+                            worker_metadata.initial_worker_metadata.created_at,
+                            worker_metadata.last_known_status.as_ref(),
+                        )
+                        .await;
+                }
+            }
+
+            match new_cursor.into_option() {
+                Some(new_cursor) => cursor = new_cursor,
+                None => break,
+            }
+        }
+
+        self.worker_service.mark_index_complete(component_id).await;
+        Ok(())
+    }
+
+    /// Decides whether a page should be served from the worker index. Pagination started from the
+    /// index always continues there, new enumerations only use it if it contains every worker of
+    /// the component and the filter can be evaluated on the indexed status.
+    async fn use_index(
+        &self,
+        environment_id: &EnvironmentId,
+        component_id: &ComponentId,
+        filter: &Option<WorkerFilter>,
+        cursor: &ScanCursor,
+        precise: bool,
+    ) -> bool {
+        if cursor.layer == INDEX_CURSOR_LAYER {
+            true
+        } else if precise
+            || !cursor.is_finished()
+            || !filter.as_ref().is_none_or(is_index_compatible)
+        {
+            false
+        } else if self.worker_service.is_index_complete(component_id).await {
+            true
+        } else {
+            self.start_index_backfill(environment_id, component_id)
+                .await;
+            false
+        }
+    }
+}
+
+/// Whether the filter only refers to properties available in the worker index
+fn is_index_compatible(filter: &WorkerFilter) -> bool {
+    match filter {
+        WorkerFilter::Name(_)
+        | WorkerFilter::Status(_)
+        | WorkerFilter::Revision(_)
+        | WorkerFilter::CreatedAt(_)
+        | WorkerFilter::AgentType(_)
+        | WorkerFilter::AgentParameters(_)
+        | WorkerFilter::LastInvocationAt(_) => true,
+        WorkerFilter::And(filter) => filter.filters.iter().all(is_index_compatible),
+        WorkerFilter::Or(filter) => filter.filters.iter().all(is_index_compatible),
+        WorkerFilter::Not(filter) => is_index_compatible(&filter.filter),
+        WorkerFilter::Env(_)
+        | WorkerFilter::ConfigVars(_)
+        | WorkerFilter::PendingInvocationCount(_)
+        | WorkerFilter::RetryCount(_)
+        | WorkerFilter::LastError(_)
+        | WorkerFilter::MemorySize(_)
+        | WorkerFilter::ActivePlugin(_) => false,
+    }
+}
+
+/// Metadata of an indexed worker containing only the indexed properties, only to be used for
+/// evaluating index compatible filters
+fn indexed_worker_metadata(
+    environment_id: &EnvironmentId,
+    indexed_worker: &IndexedWorker,
+    entry: &WorkerIndexEntry,
+) -> WorkerMetadata {
+    WorkerMetadata {
+        created_at: indexed_worker.created_at,
+        last_known_status: WorkerStatusRecord {
+            status: entry.status.clone(),
+            component_revision: entry.component_revision,
+            last_invocation_at: entry.last_invocation_at,
+            ..WorkerStatusRecord::default()
+        },
+        ..WorkerMetadata::default(
+            indexed_worker.worker_id.clone(),
+            AccountId::SYSTEM,
+            *environment_id,
+        )
+    }
 }
 
 impl HasOplogService for DefaultWorkerEnumerationService {
@@ -196,14 +422,27 @@ impl WorkerEnumerationService for DefaultWorkerEnumerationService {
             precise = %precise,
             "Enumerating workers"
         );
+        let use_index = self
+            .use_index(environment_id, component_id, &filter, &cursor, precise)
+            .await;
+
         let mut new_cursor: Option<ScanCursor> = Some(cursor);
         let mut workers: Vec<WorkerMetadata> = vec![];
 
         while new_cursor.is_some() && (workers.len() as u64) < count {
             let new_count = count - (workers.len() as u64);
 
-            let (next_cursor, workers_page) = self
-                .get_internal(
+            let (next_cursor, workers_page) = if use_index {
+                self.get_indexed(
+                    environment_id,
+                    component_id,
+                    filter.clone(),
+                    new_cursor.unwrap_or_default(),
+                    new_count,
+                )
+                .await?
+            } else {
+                self.get_internal(
                     environment_id,
                     component_id,
                     filter.clone(),
@@ -211,7 +450,8 @@ impl WorkerEnumerationService for DefaultWorkerEnumerationService {
                     new_count,
                     precise,
                 )
-                .await?;
+                .await?
+            };
 
             workers.extend(workers_page);
 
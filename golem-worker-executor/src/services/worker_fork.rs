@@ -18,6 +18,7 @@ use super::HasAgentWebhooksService;
 use crate::metrics::workers::record_worker_call;
 use crate::model::ExecutionStatus;
 use crate::services::events::Events;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::plugin::OplogProcessorPlugin;
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
 use crate::services::resource_limits::ResourceLimits;
@@ -25,11 +26,12 @@ use crate::services::rpc::Rpc;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::WorkerProxy;
 use crate::services::{
-    active_workers, agent_types, blob_store, component, golem_config, key_value, oplog, promise,
-    scheduler, shard_manager, worker, worker_activator, worker_enumeration, HasActiveWorkers,
-    HasAgentTypesService, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
-    HasExtraDeps, HasFileLoader, HasKeyValueService, HasLeakSentinel, HasOplogProcessorPlugin,
-    HasOplogService, HasPromiseService, HasResourceLimits, HasRpc,
+    active_workers, agent_lifecycle_events, agent_types, blob_store, component, golem_config,
+    key_value, oplog, promise, pubsub, scheduler, shard_manager, worker, worker_activator,
+    worker_enumeration, HasActiveWorkers, HasAgentLifecycleEventService, HasAgentTypesService,
+    HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps, HasFileLoader,
+    HasKeyValueService, HasLeakSentinel, HasNetworkEgressPolicyService, HasOplogProcessorPlugin,
+    HasOplogService, HasPromiseService, HasPubSubService, HasResourceLimits, HasRpc,
     HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
     HasShardService, HasShutdownToken, HasWasmtimeEngine, HasWorkerActivator,
     HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
@@ -100,11 +102,14 @@ pub struct DefaultWorkerFork<Ctx: WorkerCtx> {
     pub rdbms_service: Arc<dyn rdbms::RdbmsService>,
     pub oplog_service: Arc<dyn oplog::OplogService>,
     pub scheduler_service: Arc<dyn scheduler::SchedulerService>,
+    pub pubsub_service: Arc<dyn pubsub::PubSubService>,
+    pub agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
     pub worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx>>,
     pub events: Arc<Events>,
     pub file_loader: Arc<FileLoader>,
     pub oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
     pub resource_limits: Arc<dyn ResourceLimits>,
+    pub network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
     pub shutdown_token: tokio_util::sync::CancellationToken,
     pub extra_deps: Ctx::ExtraDeps,
     pub leak_sentinel: Arc<()>,
@@ -210,6 +215,20 @@ impl<Ctx: WorkerCtx> HasSchedulerService for DefaultWorkerFork<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasAgentLifecycleEventService for DefaultWorkerFork<Ctx> {
+    fn agent_lifecycle_event_service(
+        &self,
+    ) -> Arc<dyn agent_lifecycle_events::AgentLifecycleEventService> {
+        self.agent_lifecycle_event_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasPubSubService for DefaultWorkerFork<Ctx> {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.pubsub_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasOplogService for DefaultWorkerFork<Ctx> {
     fn oplog_service(&self) -> Arc<dyn oplog::OplogService> {
         self.oplog_service.clone()
@@ -276,6 +295,12 @@ impl<Ctx: WorkerCtx> HasResourceLimits for DefaultWorkerFork<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasNetworkEgressPolicyService for DefaultWorkerFork<Ctx> {
+    fn network_egress_policy_service(&self) -> Arc<dyn NetworkEgressPolicyService> {
+        self.network_egress_policy_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasShutdownToken for DefaultWorkerFork<Ctx> {
     fn shutdown_token(&self) -> tokio_util::sync::CancellationToken {
         self.shutdown_token.clone()
@@ -312,11 +337,14 @@ impl<Ctx: WorkerCtx> Clone for DefaultWorkerFork<Ctx> {
             rdbms_service: self.rdbms_service.clone(),
             oplog_service: self.oplog_service.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            agent_lifecycle_event_service: self.agent_lifecycle_event_service.clone(),
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
             file_loader: self.file_loader.clone(),
             oplog_processor_plugin: self.oplog_processor_plugin.clone(),
             resource_limits: self.resource_limits.clone(),
+            network_egress_policy_service: self.network_egress_policy_service.clone(),
             shutdown_token: self.shutdown_token.clone(),
             extra_deps: self.extra_deps.clone(),
             leak_sentinel: self.leak_sentinel.clone(),
@@ -348,11 +376,14 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
         rdbms_service: Arc<dyn rdbms::RdbmsService>,
         oplog_service: Arc<dyn oplog::OplogService>,
         scheduler_service: Arc<dyn scheduler::SchedulerService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        agent_lifecycle_event_service: Arc<dyn agent_lifecycle_events::AgentLifecycleEventService>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator<Ctx>>,
         events: Arc<Events>,
         file_loader: Arc<FileLoader>,
         oplog_processor_plugin: Arc<dyn OplogProcessorPlugin>,
         resource_limits: Arc<dyn ResourceLimits>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         agent_types: Arc<dyn agent_types::AgentTypesService>,
         agent_webhooks: Arc<AgentWebhooksService>,
         shutdown_token: tokio_util::sync::CancellationToken,
@@ -381,11 +412,14 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
             rdbms_service,
             oplog_service,
             scheduler_service,
+            pubsub_service,
+            agent_lifecycle_event_service,
             worker_activator,
             events,
             file_loader,
             oplog_processor_plugin,
             resource_limits,
+            network_egress_policy_service,
             shutdown_token,
             extra_deps,
             leak_sentinel,
@@ -25,7 +25,7 @@ use golem_api_grpc::proto::golem::worker::v1::{
     LaunchNewWorkerResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
     RevertWorkerResponse, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
 };
-use golem_api_grpc::proto::golem::worker::{CompleteParameters, UpdateMode};
+use golem_api_grpc::proto::golem::worker::{CompleteParameters, InvocationPriority, UpdateMode};
 use golem_common::model::account::AccountId;
 use golem_common::model::agent::{AgentInvocationMode, Principal, UntypedDataValue};
 use golem_common::model::component::ComponentRevision;
@@ -107,10 +107,13 @@ pub trait WorkerProxy: Send + Sync {
         caller_account_id: AccountId,
     ) -> Result<(), WorkerProxyError>;
 
+    /// Completes a promise owned by a remote worker. If `failed` is true, the promise
+    /// is failed with `data` as the error payload.
     async fn complete_promise(
         &self,
         promise_id: PromiseId,
         data: Vec<u8>,
+        failed: bool,
         caller_account_id: AccountId,
     ) -> Result<bool, WorkerProxyError>;
 }
@@ -333,6 +336,7 @@ impl WorkerProxy for RemoteWorkerProxy {
                     }),
                     auth_ctx: Some(auth_ctx.clone().into()),
                     principal: Some(principal.clone().into()),
+                    priority: InvocationPriority::Normal as i32,
                 }))
             })
             .await?
@@ -514,6 +518,7 @@ impl WorkerProxy for RemoteWorkerProxy {
         &self,
         promise_id: PromiseId,
         data: Vec<u8>,
+        failed: bool,
         caller_account_id: AccountId,
     ) -> Result<bool, WorkerProxyError> {
         let auth_ctx = self.get_auth_ctx(caller_account_id);
@@ -526,6 +531,7 @@ impl WorkerProxy for RemoteWorkerProxy {
                     complete_parameters: Some(CompleteParameters {
                         oplog_idx: promise_id.oplog_idx.into(),
                         data: data.clone(),
+                        failed,
                     }),
                     auth_ctx: Some(auth_ctx.clone().into()),
                 }))
@@ -0,0 +1,466 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::storage::indexed::{
+    IndexedStorage, IndexedStorageMetaNamespace, IndexedStorageNamespace, ScanCursor,
+};
+use async_trait::async_trait;
+use golem_service_base::fault_injection::{Fault, FaultInjectionTarget, FaultInjector};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Indexed storage injecting the faults of a [`FaultInjector`] into the operations of an
+/// underlying storage
+#[derive(Debug)]
+pub struct FaultInjectingIndexedStorage {
+    inner: Arc<dyn IndexedStorage + Send + Sync>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultInjectingIndexedStorage {
+    pub fn new(inner: Arc<dyn IndexedStorage + Send + Sync>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    /// Wraps the storage if the fault injector is enabled for indexed storage
+    pub fn wrap(
+        inner: Arc<dyn IndexedStorage + Send + Sync>,
+        injector: &Option<Arc<FaultInjector>>,
+    ) -> Arc<dyn IndexedStorage + Send + Sync> {
+        match injector {
+            Some(injector) if injector.applies_to(FaultInjectionTarget::IndexedStorage) => {
+                Arc::new(Self::new(inner, injector.clone()))
+            }
+            _ => inner,
+        }
+    }
+
+    async fn with_read_fault<R>(
+        &self,
+        api_name: &'static str,
+        read: impl Future<Output = Result<R, String>>,
+    ) -> Result<R, String> {
+        match self.injector.next_fault(false).await {
+            Fault::None => read.await,
+            Fault::Error | Fault::PartialWrite => Err(Self::error(api_name)),
+        }
+    }
+
+    /// Performs a write, on partial write faults the given `partial` write is performed instead
+    /// of the full one before reporting the failure
+    async fn with_write_fault<R>(
+        &self,
+        api_name: &'static str,
+        write: impl Future<Output = Result<R, String>>,
+        partial: impl Future<Output = Result<R, String>>,
+    ) -> Result<R, String> {
+        match self.injector.next_fault(true).await {
+            Fault::None => write.await,
+            Fault::Error => Err(Self::error(api_name)),
+            Fault::PartialWrite => {
+                partial.await?;
+                Err(Self::error(api_name))
+            }
+        }
+    }
+
+    fn error(api_name: &'static str) -> String {
+        FaultInjector::error_message(FaultInjectionTarget::IndexedStorage, api_name)
+    }
+}
+
+#[async_trait]
+impl IndexedStorage for FaultInjectingIndexedStorage {
+    async fn number_of_replicas(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+    ) -> Result<u8, String> {
+        self.with_read_fault(api_name, self.inner.number_of_replicas(svc_name, api_name))
+            .await
+    }
+
+    async fn wait_for_replicas(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        replicas: u8,
+        timeout: Duration,
+    ) -> Result<u8, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .wait_for_replicas(svc_name, api_name, replicas, timeout),
+        )
+        .await
+    }
+
+    async fn exists(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+    ) -> Result<bool, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner.exists(svc_name, api_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn scan(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageMetaNamespace,
+        pattern: &str,
+        cursor: ScanCursor,
+        count: u64,
+    ) -> Result<(ScanCursor, Vec<String>), String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .scan(svc_name, api_name, namespace, pattern, cursor, count),
+        )
+        .await
+    }
+
+    async fn append(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        id: u64,
+        value: Vec<u8>,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.append(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                id,
+                value.clone(),
+            ),
+            self.inner
+                .append(svc_name, api_name, entity_name, namespace, key, id, value),
+        )
+        .await
+    }
+
+    async fn append_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        pairs: Vec<(u64, Vec<u8>)>,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.append_many(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                pairs.clone(),
+            ),
+            self.inner.append_many(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                pairs[..pairs.len() / 2].to_vec(),
+            ),
+        )
+        .await
+    }
+
+    async fn length(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+    ) -> Result<u64, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner.length(svc_name, api_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn delete(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner
+                .delete(svc_name, api_name, namespace.clone(), key),
+            self.inner.delete(svc_name, api_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn read(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        start_id: u64,
+        end_id: u64,
+    ) -> Result<Vec<(u64, Vec<u8>)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner.read(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                start_id,
+                end_id,
+            ),
+        )
+        .await
+    }
+
+    async fn first(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+    ) -> Result<Option<(u64, Vec<u8>)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .first(svc_name, api_name, entity_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn last(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+    ) -> Result<Option<(u64, Vec<u8>)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .last(svc_name, api_name, entity_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn closest(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        id: u64,
+    ) -> Result<Option<(u64, Vec<u8>)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .closest(svc_name, api_name, entity_name, namespace, key, id),
+        )
+        .await
+    }
+
+    async fn drop_prefix(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner
+                .drop_prefix(svc_name, api_name, namespace.clone(), key, last_dropped_id),
+            self.inner
+                .drop_prefix(svc_name, api_name, namespace, key, last_dropped_id),
+        )
+        .await
+    }
+
+    async fn drop_range(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.drop_range(
+                svc_name,
+                api_name,
+                namespace.clone(),
+                key,
+                first_dropped_id,
+                last_dropped_id,
+            ),
+            self.inner.drop_range(
+                svc_name,
+                api_name,
+                namespace,
+                key,
+                first_dropped_id,
+                last_dropped_id,
+            ),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::FaultInjectingIndexedStorage;
+    use crate::storage::indexed::memory::InMemoryIndexedStorage;
+    use crate::storage::indexed::{IndexedStorage, IndexedStorageNamespace};
+    use golem_common::model::component::ComponentId;
+    use golem_common::model::WorkerId;
+    use golem_service_base::fault_injection::{FaultInjectionConfig, FaultInjector};
+    use std::sync::Arc;
+
+    fn storages(
+        error_probability: f64,
+        partial_write_probability: f64,
+    ) -> (
+        Arc<dyn IndexedStorage + Send + Sync>,
+        Arc<dyn IndexedStorage + Send + Sync>,
+    ) {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            error_probability,
+            partial_write_probability,
+            delay_probability: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let inner: Arc<dyn IndexedStorage + Send + Sync> = Arc::new(InMemoryIndexedStorage::new());
+        let wrapped = FaultInjectingIndexedStorage::wrap(inner.clone(), &Some(Arc::new(injector)));
+        (inner, wrapped)
+    }
+
+    fn namespace() -> IndexedStorageNamespace {
+        IndexedStorageNamespace::OpLog {
+            worker_id: WorkerId {
+                component_id: ComponentId::new(),
+                worker_name: "worker".to_string(),
+            },
+        }
+    }
+
+    fn entries() -> Vec<(u64, Vec<u8>)> {
+        (1..=4).map(|id| (id, vec![id as u8])).collect()
+    }
+
+    async fn stored_ids(
+        storage: &Arc<dyn IndexedStorage + Send + Sync>,
+        namespace: &IndexedStorageNamespace,
+    ) -> Vec<u64> {
+        storage
+            .read("test", "test", "test", namespace.clone(), "key", 1, 4)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    #[test]
+    async fn failed_appends_store_nothing() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+        let namespace = namespace();
+
+        let result = wrapped
+            .append_many("test", "test", "test", namespace.clone(), "key", entries())
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(stored_ids(&inner, &namespace).await, Vec::<u64>::new());
+    }
+
+    #[test]
+    async fn partial_appends_store_a_prefix_and_fail() {
+        let (inner, wrapped) = storages(0.0, 1.0);
+        let namespace = namespace();
+
+        let result = wrapped
+            .append_many("test", "test", "test", namespace.clone(), "key", entries())
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(stored_ids(&inner, &namespace).await, vec![1, 2]);
+    }
+
+    #[test]
+    async fn reads_fail_on_injected_errors() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+        let namespace = namespace();
+        inner
+            .append_many("test", "test", "test", namespace.clone(), "key", entries())
+            .await
+            .unwrap();
+
+        let result = wrapped
+            .read("test", "test", "test", namespace, "key", 1, 4)
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn operations_pass_through_without_faults() {
+        let (inner, wrapped) = storages(0.0, 0.0);
+        let namespace = namespace();
+
+        wrapped
+            .append_many("test", "test", "test", namespace.clone(), "key", entries())
+            .await
+            .unwrap();
+
+        assert_eq!(stored_ids(&inner, &namespace).await, vec![1, 2, 3, 4]);
+        assert_eq!(stored_ids(&wrapped, &namespace).await, vec![1, 2, 3, 4]);
+    }
+}
@@ -325,6 +325,24 @@ impl IndexedStorage for InMemoryIndexedStorage {
             .await;
         Ok(())
     }
+
+    async fn drop_range(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        let composite_key = Self::composite_key(namespace, key);
+        self.data
+            .update_async(&composite_key, |_, entry| {
+                entry.retain(|k, _| *k < first_dropped_id || *k > last_dropped_id);
+            })
+            .await;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -808,4 +826,79 @@ mod tests {
 
         check!(result == vec![(3, 300), (4, 400)]);
     }
+
+    #[test]
+    async fn drop_range() {
+        let storage = super::InMemoryIndexedStorage::new();
+        let api = storage.with_entity("test", "test", "test");
+        let key = "key";
+
+        api.append(
+            IndexedStorageNamespace::OpLog {
+                worker_id: test_worker_id(),
+            },
+            key,
+            1,
+            &100,
+        )
+        .await
+        .unwrap();
+        api.append(
+            IndexedStorageNamespace::OpLog {
+                worker_id: test_worker_id(),
+            },
+            key,
+            2,
+            &200,
+        )
+        .await
+        .unwrap();
+        api.append(
+            IndexedStorageNamespace::OpLog {
+                worker_id: test_worker_id(),
+            },
+            key,
+            3,
+            &300,
+        )
+        .await
+        .unwrap();
+        api.append(
+            IndexedStorageNamespace::OpLog {
+                worker_id: test_worker_id(),
+            },
+            key,
+            4,
+            &400,
+        )
+        .await
+        .unwrap();
+
+        storage
+            .with("test", "test")
+            .drop_range(
+                IndexedStorageNamespace::OpLog {
+                    worker_id: test_worker_id(),
+                },
+                key,
+                2,
+                3,
+            )
+            .await
+            .unwrap();
+
+        let result = api
+            .read(
+                IndexedStorageNamespace::OpLog {
+                    worker_id: test_worker_id(),
+                },
+                key,
+                1,
+                4,
+            )
+            .await
+            .unwrap();
+
+        check!(result == vec![(1, 100), (4, 400)]);
+    }
 }
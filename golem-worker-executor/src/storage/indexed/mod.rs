@@ -20,6 +20,7 @@ use desert_rust::{BinaryDeserializer, BinarySerializer};
 use golem_common::model::WorkerId;
 use golem_common::serialization::{deserialize, serialize};
 
+pub mod fault_injection;
 pub mod memory;
 pub mod multi_sqlite;
 pub mod redis;
@@ -183,6 +184,18 @@ pub trait IndexedStorage: Debug + Sync {
         key: &str,
         last_dropped_id: u64,
     ) -> Result<(), String>;
+
+    /// Deletes all the entries in the index of the given key with an id between `first_dropped_id`
+    /// and `last_dropped_id` (both inclusive), keeping the entries before and after the range.
+    async fn drop_range(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String>;
 }
 
 pub trait IndexedStorageLabelledApi<T: IndexedStorage + ?Sized> {
@@ -307,6 +320,25 @@ impl<'a, S: ?Sized + IndexedStorage> LabelledIndexedStorage<'a, S> {
             )
             .await
     }
+
+    pub async fn drop_range(
+        &self,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        self.storage
+            .drop_range(
+                self.svc_name,
+                self.api_name,
+                namespace,
+                key,
+                first_dropped_id,
+                last_dropped_id,
+            )
+            .await
+    }
 }
 
 pub struct LabelledEntityIndexedStorage<'a, S: IndexedStorage + ?Sized> {
@@ -373,16 +405,20 @@ impl<'a, S: ?Sized + IndexedStorage> LabelledEntityIndexedStorage<'a, S> {
             .await
     }
 
-    /// Appends multiple entries to the given key with the given id, serializing the value first
+    /// Appends multiple entries to the given key with the given id, serializing the value first.
+    /// Returns the total size of the serialized entries.
     pub async fn append_many<V: BinarySerializer>(
         &self,
         namespace: IndexedStorageNamespace,
         key: &str,
         pairs: &[(u64, &V)],
-    ) -> Result<(), String> {
+    ) -> Result<u64, String> {
         let mut serialized_pairs = Vec::with_capacity(pairs.len());
+        let mut size = 0u64;
         for (id, value) in pairs {
-            serialized_pairs.push((*id, serialize(value)?));
+            let bytes = serialize(value)?;
+            size += bytes.len() as u64;
+            serialized_pairs.push((*id, bytes));
         }
         self.storage
             .append_many(
@@ -393,7 +429,8 @@ impl<'a, S: ?Sized + IndexedStorage> LabelledEntityIndexedStorage<'a, S> {
                 key,
                 serialized_pairs,
             )
-            .await
+            .await?;
+        Ok(size)
     }
 
     /// Reads a closed range of entries from the index of the given key, deserializing each entry
@@ -369,4 +369,26 @@ impl IndexedStorage for MultiSqliteIndexedStorage {
             .drop_prefix(svc_name, api_name, namespace, key, last_dropped_id)
             .await
     }
+
+    async fn drop_range(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        self.storage_by_namespace(&namespace)
+            .await?
+            .drop_range(
+                svc_name,
+                api_name,
+                namespace,
+                key,
+                first_dropped_id,
+                last_dropped_id,
+            )
+            .await
+    }
 }
@@ -353,4 +353,37 @@ impl IndexedStorage for RedisIndexedStorage {
             .map_err(|e| e.to_string())?;
         Ok(())
     }
+
+    async fn drop_range(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: IndexedStorageNamespace,
+        key: &str,
+        first_dropped_id: u64,
+        last_dropped_id: u64,
+    ) -> Result<(), String> {
+        let composite_key = Self::composite_key(namespace, key);
+        let items: Vec<HashMap<String, HashMap<String, Bytes>>> = self
+            .redis
+            .with(svc_name, api_name)
+            .xrange(&composite_key, first_dropped_id, last_dropped_id, None)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let ids = items
+            .into_iter()
+            .flat_map(|item| item.into_keys())
+            .collect::<Vec<_>>();
+
+        if !ids.is_empty() {
+            let _: u64 = self
+                .redis
+                .with(svc_name, api_name)
+                .xdel(&composite_key, ids)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
 }
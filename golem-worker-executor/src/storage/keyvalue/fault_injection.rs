@@ -0,0 +1,646 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_service_base::fault_injection::{Fault, FaultInjectionTarget, FaultInjector};
+use std::future::Future;
+use std::sync::Arc;
+
+/// Key-value storage injecting the faults of a [`FaultInjector`] into the operations of an
+/// underlying storage
+#[derive(Debug)]
+pub struct FaultInjectingKeyValueStorage {
+    inner: Arc<dyn KeyValueStorage + Send + Sync>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultInjectingKeyValueStorage {
+    pub fn new(
+        inner: Arc<dyn KeyValueStorage + Send + Sync>,
+        injector: Arc<FaultInjector>,
+    ) -> Self {
+        Self { inner, injector }
+    }
+
+    /// Wraps the storage if the fault injector is enabled for key-value storage
+    pub fn wrap(
+        inner: Arc<dyn KeyValueStorage + Send + Sync>,
+        injector: &Option<Arc<FaultInjector>>,
+    ) -> Arc<dyn KeyValueStorage + Send + Sync> {
+        match injector {
+            Some(injector) if injector.applies_to(FaultInjectionTarget::KeyValueStorage) => {
+                Arc::new(Self::new(inner, injector.clone()))
+            }
+            _ => inner,
+        }
+    }
+
+    async fn with_read_fault<R>(
+        &self,
+        api_name: &'static str,
+        read: impl Future<Output = Result<R, String>>,
+    ) -> Result<R, String> {
+        match self.injector.next_fault(false).await {
+            Fault::None => read.await,
+            Fault::Error | Fault::PartialWrite => Err(Self::error(api_name)),
+        }
+    }
+
+    /// Performs a write, on partial write faults the given `partial` write is performed instead
+    /// of the full one before reporting the failure
+    async fn with_write_fault<R>(
+        &self,
+        api_name: &'static str,
+        write: impl Future<Output = Result<R, String>>,
+        partial: impl Future<Output = Result<R, String>>,
+    ) -> Result<R, String> {
+        match self.injector.next_fault(true).await {
+            Fault::None => write.await,
+            Fault::Error => Err(Self::error(api_name)),
+            Fault::PartialWrite => {
+                partial.await?;
+                Err(Self::error(api_name))
+            }
+        }
+    }
+
+    fn error(api_name: &'static str) -> String {
+        FaultInjector::error_message(FaultInjectionTarget::KeyValueStorage, api_name)
+    }
+}
+
+#[async_trait]
+impl KeyValueStorage for FaultInjectingKeyValueStorage {
+    async fn set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                value,
+            ),
+            self.inner
+                .set(svc_name, api_name, entity_name, namespace, key, value),
+        )
+        .await
+    }
+
+    async fn set_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        pairs: &[(&str, &[u8])],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner
+                .set_many(svc_name, api_name, entity_name, namespace.clone(), pairs),
+            self.inner.set_many(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                &pairs[..pairs.len() / 2],
+            ),
+        )
+        .await
+    }
+
+    async fn set_if_not_exists(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.set_if_not_exists(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                value,
+            ),
+            self.inner
+                .set_if_not_exists(svc_name, api_name, entity_name, namespace, key, value),
+        )
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.compare_and_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                expected,
+                value,
+            ),
+            self.inner.compare_and_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                expected,
+                value,
+            ),
+        )
+        .await
+    }
+
+    async fn get(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Option<Bytes>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .get(svc_name, api_name, entity_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn get_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        keys: Vec<String>,
+    ) -> Result<Vec<Option<Bytes>>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .get_many(svc_name, api_name, entity_name, namespace, keys),
+        )
+        .await
+    }
+
+    async fn del(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.del(svc_name, api_name, namespace.clone(), key),
+            self.inner.del(svc_name, api_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn del_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        keys: Vec<String>,
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner
+                .del_many(svc_name, api_name, namespace.clone(), keys.clone()),
+            self.inner.del_many(
+                svc_name,
+                api_name,
+                namespace,
+                keys[..keys.len() / 2].to_vec(),
+            ),
+        )
+        .await
+    }
+
+    async fn exists(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<bool, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner.exists(svc_name, api_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn keys(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+    ) -> Result<Vec<String>, String> {
+        self.with_read_fault(api_name, self.inner.keys(svc_name, api_name, namespace))
+            .await
+    }
+
+    async fn add_to_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.add_to_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                value,
+            ),
+            self.inner
+                .add_to_set(svc_name, api_name, entity_name, namespace, key, value),
+        )
+        .await
+    }
+
+    async fn remove_from_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.remove_from_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                value,
+            ),
+            self.inner
+                .remove_from_set(svc_name, api_name, entity_name, namespace, key, value),
+        )
+        .await
+    }
+
+    async fn members_of_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Vec<Bytes>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .members_of_set(svc_name, api_name, entity_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn add_to_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        score: f64,
+        value: &[u8],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.add_to_sorted_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                score,
+                value,
+            ),
+            self.inner.add_to_sorted_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                score,
+                value,
+            ),
+        )
+        .await
+    }
+
+    async fn remove_from_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        self.with_write_fault(
+            api_name,
+            self.inner.remove_from_sorted_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace.clone(),
+                key,
+                value,
+            ),
+            self.inner.remove_from_sorted_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                value,
+            ),
+        )
+        .await
+    }
+
+    async fn get_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .get_sorted_set(svc_name, api_name, entity_name, namespace, key),
+        )
+        .await
+    }
+
+    async fn query_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner
+                .query_sorted_set(svc_name, api_name, entity_name, namespace, key, min, max),
+        )
+        .await
+    }
+
+    async fn get_sorted_set_page(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        self.with_read_fault(
+            api_name,
+            self.inner.get_sorted_set_page(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                min_score,
+                count,
+            ),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::FaultInjectingKeyValueStorage;
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+    use golem_service_base::fault_injection::{FaultInjectionConfig, FaultInjector};
+    use std::sync::Arc;
+
+    fn storages(
+        error_probability: f64,
+        partial_write_probability: f64,
+    ) -> (
+        Arc<dyn KeyValueStorage + Send + Sync>,
+        Arc<dyn KeyValueStorage + Send + Sync>,
+    ) {
+        let injector = FaultInjector::new(FaultInjectionConfig {
+            enabled: true,
+            error_probability,
+            partial_write_probability,
+            delay_probability: 0.0,
+            ..Default::default()
+        })
+        .unwrap();
+        let inner: Arc<dyn KeyValueStorage + Send + Sync> =
+            Arc::new(InMemoryKeyValueStorage::new());
+        let wrapped = FaultInjectingKeyValueStorage::wrap(inner.clone(), &Some(Arc::new(injector)));
+        (inner, wrapped)
+    }
+
+    async fn stored_keys(storage: &Arc<dyn KeyValueStorage + Send + Sync>) -> Vec<Option<String>> {
+        storage
+            .get_many(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                vec![
+                    "a".to_string(),
+                    "b".to_string(),
+                    "c".to_string(),
+                    "d".to_string(),
+                ],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|value| value.map(|value| String::from_utf8(value.to_vec()).unwrap()))
+            .collect()
+    }
+
+    const PAIRS: [(&str, &[u8]); 4] = [("a", b"1"), ("b", b"2"), ("c", b"3"), ("d", b"4")];
+
+    #[test]
+    async fn failed_writes_store_nothing() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+
+        let result = wrapped
+            .set_many(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                &PAIRS,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(stored_keys(&inner).await, vec![None, None, None, None]);
+    }
+
+    #[test]
+    async fn partial_batch_writes_store_a_prefix_and_fail() {
+        let (inner, wrapped) = storages(0.0, 1.0);
+
+        let result = wrapped
+            .set_many(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                &PAIRS,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            stored_keys(&inner).await,
+            vec![Some("1".to_string()), Some("2".to_string()), None, None]
+        );
+    }
+
+    #[test]
+    async fn partial_single_writes_are_stored_but_fail() {
+        let (inner, wrapped) = storages(0.0, 1.0);
+
+        let result = wrapped
+            .set(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                "a",
+                b"1",
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            stored_keys(&inner).await,
+            vec![Some("1".to_string()), None, None, None]
+        );
+    }
+
+    #[test]
+    async fn reads_fail_on_injected_errors() {
+        let (inner, wrapped) = storages(1.0, 0.0);
+        inner
+            .set(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                "a",
+                b"1",
+            )
+            .await
+            .unwrap();
+
+        let result = wrapped
+            .get(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                "a",
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    async fn operations_pass_through_without_faults() {
+        let (inner, wrapped) = storages(0.0, 0.0);
+
+        wrapped
+            .set_many(
+                "test",
+                "test",
+                "test",
+                KeyValueStorageNamespace::Schedule,
+                &PAIRS,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            stored_keys(&inner).await,
+            vec![
+                Some("1".to_string()),
+                Some("2".to_string()),
+                Some("3".to_string()),
+                Some("4".to_string())
+            ]
+        );
+        assert_eq!(stored_keys(&wrapped).await, stored_keys(&inner).await);
+    }
+}
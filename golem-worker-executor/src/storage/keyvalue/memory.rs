@@ -111,6 +111,33 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         }
     }
 
+    async fn compare_and_set(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        match self
+            .kvs
+            .entry_async(Self::composite_key(&namespace, key))
+            .await
+        {
+            Entry::Occupied(mut entry) if Some(entry.get().as_slice()) == expected => {
+                *entry.get_mut() = value.to_vec();
+                Ok(true)
+            }
+            Entry::Vacant(entry) if expected.is_none() => {
+                entry.insert_entry(value.to_vec());
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     async fn get(
         &self,
         _svc_name: &'static str,
@@ -353,4 +380,32 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
             .await
             .unwrap_or_default())
     }
+
+    async fn get_sorted_set_page(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        Ok(self
+            .sorted_sets
+            .read_async(&Self::composite_key(&namespace, key), |_, entry| {
+                let mut entries = entry
+                    .iter()
+                    .filter(|(score, _)| *score >= min_score)
+                    .collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap().then_with(|| a.1.cmp(&b.1)));
+                entries
+                    .into_iter()
+                    .take(count as usize)
+                    .map(|(score, value)| (*score, Bytes::from(value.clone())))
+                    .collect()
+            })
+            .await
+            .unwrap_or_default())
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod fault_injection;
 pub mod memory;
 pub mod multi_sqlite;
 pub mod redis;
@@ -56,6 +57,19 @@ pub trait KeyValueStorage: Debug {
         value: &[u8],
     ) -> Result<bool, String>;
 
+    /// Sets the value of the key only if its current value is `expected`, or if the key does not
+    /// exist in case `expected` is `None`. Returns whether the value has been set.
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String>;
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -174,6 +188,19 @@ pub trait KeyValueStorage: Debug {
         min: f64,
         max: f64,
     ) -> Result<Vec<(f64, Bytes)>, String>;
+
+    /// Returns at most `count` elements of a sorted set with a score of at least `min_score`, ordered
+    /// by their score. Elements with the same score are ordered by their value.
+    async fn get_sorted_set_page(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String>;
 }
 
 pub trait KeyValueStorageLabelledApi<T: KeyValueStorage + ?Sized> {
@@ -336,6 +363,28 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
             .await
     }
 
+    pub async fn compare_and_set<V: BinarySerializer>(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&V>,
+        value: &V,
+    ) -> Result<bool, String> {
+        let expected = expected.map(serialize).transpose()?;
+        let serialized = serialize(value)?;
+        self.storage
+            .compare_and_set(
+                self.svc_name,
+                self.api_name,
+                self.entity_name,
+                namespace,
+                key,
+                expected.as_deref(),
+                &serialized,
+            )
+            .await
+    }
+
     pub async fn set_many<V: BinarySerializer>(
         &self,
         namespace: KeyValueStorageNamespace,
@@ -619,6 +668,33 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
         }
         Ok(values)
     }
+
+    pub async fn get_sorted_set_page<V: BinaryDeserializer>(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, V)>, String> {
+        let maybe_bytes = self
+            .storage
+            .get_sorted_set_page(
+                self.svc_name,
+                self.api_name,
+                self.entity_name,
+                namespace,
+                key,
+                min_score,
+                count,
+            )
+            .await?;
+        let mut values = Vec::new();
+        for (score, bytes) in maybe_bytes {
+            let value: V = deserialize(&bytes)?;
+            values.push((score, value));
+        }
+        Ok(values)
+    }
 }
 
 /// Various namespaces for key-value storage
@@ -632,6 +708,9 @@ pub enum KeyValueStorageNamespace {
         worker_id: WorkerId,
     },
     Schedule,
+    WorkerIndex,
+    PubSub,
+    AgentLifecycleEvents,
     UserDefined {
         environment_id: EnvironmentId,
         bucket: String,
@@ -109,6 +109,11 @@ impl MultiSqliteKeyValueStorage {
                 format!("kv-worker-{}.db", self.worker_id_hash(worker_id).await)
             }
             KeyValueStorageNamespace::Schedule => "kv-schedule.db".to_string(),
+            KeyValueStorageNamespace::WorkerIndex => "kv-worker-index.db".to_string(),
+            KeyValueStorageNamespace::PubSub => "kv-pubsub.db".to_string(),
+            KeyValueStorageNamespace::AgentLifecycleEvents => {
+                "kv-agent-lifecycle-events.db".to_string()
+            }
             KeyValueStorageNamespace::UserDefined { .. } => "kv-user-defined.db".to_string(),
         }
     }
@@ -183,6 +188,30 @@ impl KeyValueStorage for MultiSqliteKeyValueStorage {
             .await
     }
 
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        self.storage_by_namespace(&namespace)
+            .await?
+            .compare_and_set(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                expected,
+                value,
+            )
+            .await
+    }
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -374,4 +403,28 @@ impl KeyValueStorage for MultiSqliteKeyValueStorage {
             .query_sorted_set(svc_name, api_name, entity_name, namespace, key, min, max)
             .await
     }
+
+    async fn get_sorted_set_page(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        self.storage_by_namespace(&namespace)
+            .await?
+            .get_sorted_set_page(
+                svc_name,
+                api_name,
+                entity_name,
+                namespace,
+                key,
+                min_score,
+                count,
+            )
+            .await
+    }
 }
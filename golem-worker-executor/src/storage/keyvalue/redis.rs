@@ -14,7 +14,7 @@
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use fred::types::SetOptions;
+use fred::types::{SetOptions, Value};
 use golem_common::metrics::redis::{record_redis_deserialized_size, record_redis_serialized_size};
 use golem_common::redis::RedisPool;
 use std::collections::HashMap;
@@ -31,12 +31,19 @@ impl RedisKeyValueStorage {
         Self { redis }
     }
 
+    const COMPARE_AND_SET_SCRIPT: &'static str = "if redis.call('GET', KEYS[1]) == ARGV[1] then redis.call('SET', KEYS[1], ARGV[2]) return 1 else return 0 end";
+
+    const HASH_COMPARE_AND_SET_SCRIPT: &'static str = "if redis.call('HGET', KEYS[1], ARGV[1]) == ARGV[2] then redis.call('HSET', KEYS[1], ARGV[1], ARGV[3]) return 1 else return 0 end";
+
     fn use_hash(namespace: &KeyValueStorageNamespace) -> Option<String> {
         match namespace {
             KeyValueStorageNamespace::Worker { .. } => None,
             KeyValueStorageNamespace::RunningWorkers => None,
             KeyValueStorageNamespace::Promise { .. } => Some("promises".to_string()),
             KeyValueStorageNamespace::Schedule => None,
+            KeyValueStorageNamespace::WorkerIndex => None,
+            KeyValueStorageNamespace::PubSub => None,
+            KeyValueStorageNamespace::AgentLifecycleEvents => None,
             KeyValueStorageNamespace::UserDefined {
                 environment_id,
                 bucket,
@@ -138,6 +145,49 @@ impl KeyValueStorage for RedisKeyValueStorage {
         }
     }
 
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        let Some(expected) = expected else {
+            return self
+                .set_if_not_exists(svc_name, api_name, entity_name, namespace, key, value)
+                .await;
+        };
+
+        record_redis_serialized_size(svc_name, entity_name, value.len());
+
+        let expected = Value::Bytes(Bytes::copy_from_slice(expected));
+        let value = Value::Bytes(Bytes::copy_from_slice(value));
+        let (script, keys, args) = match Self::use_hash(&namespace) {
+            Some(ns) => (
+                Self::HASH_COMPARE_AND_SET_SCRIPT,
+                vec![ns],
+                vec![Value::from(key), expected, value],
+            ),
+            None => (
+                Self::COMPARE_AND_SET_SCRIPT,
+                vec![key.to_string()],
+                vec![expected, value],
+            ),
+        };
+
+        let result: i64 = self
+            .redis
+            .with(svc_name, api_name)
+            .eval(script, keys, args)
+            .await
+            .map_err(|redis_err| redis_err.to_string())?;
+
+        Ok(result == 1)
+    }
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -464,4 +514,38 @@ impl KeyValueStorage for RedisKeyValueStorage {
             .map(|(data, score)| (score, data))
             .collect())
     }
+
+    async fn get_sorted_set_page(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let key = match Self::use_hash(&namespace) {
+            Some(ns) => format!("{ns}:{key}"),
+            None => key.to_string(),
+        };
+        let pairs: Vec<(Bytes, f64)> = self
+            .redis
+            .with(svc_name, api_name)
+            .zrangebyscore(&key, min_score, "+inf", true, Some((0, count as i64)))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        for (data, _score) in &pairs {
+            record_redis_deserialized_size(svc_name, entity_name, data.len());
+        }
+
+        Ok(pairs
+            .into_iter()
+            .map(|(data, score)| (score, data))
+            .collect())
+    }
 }
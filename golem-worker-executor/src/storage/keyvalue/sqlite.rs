@@ -96,6 +96,9 @@ impl SqliteKeyValueStorage {
             KeyValueStorageNamespace::Worker { .. } => "worker".to_string(),
             KeyValueStorageNamespace::Promise { .. } => "promise".to_string(),
             KeyValueStorageNamespace::Schedule => "schedule".to_string(),
+            KeyValueStorageNamespace::WorkerIndex => "worker-index".to_string(),
+            KeyValueStorageNamespace::PubSub => "pubsub".to_string(),
+            KeyValueStorageNamespace::AgentLifecycleEvents => "agent-lifecycle-events".to_string(),
             KeyValueStorageNamespace::UserDefined {
                 environment_id,
                 bucket,
@@ -193,6 +196,40 @@ impl KeyValueStorage for SqliteKeyValueStorage {
             .map_err(|err| err.to_safe_string())
     }
 
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: Option<&[u8]>,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        let query = match expected {
+            Some(expected) => sqlx::query(
+                "UPDATE kv_storage SET value = ? WHERE key = ? AND namespace = ? AND value = ?;",
+            )
+            .bind(value)
+            .bind(key)
+            .bind(Self::namespace(namespace))
+            .bind(expected),
+            None => sqlx::query(
+                "INSERT OR IGNORE INTO kv_storage (key, value, namespace) VALUES (?, ?, ?);",
+            )
+            .bind(key)
+            .bind(value)
+            .bind(Self::namespace(namespace)),
+        };
+
+        self.pool
+            .with_rw(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() == 1)
+            .map_err(|err| err.to_safe_string())
+    }
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -506,6 +543,35 @@ impl KeyValueStorage for SqliteKeyValueStorage {
             })
             .map_err(|err| err.to_safe_string())
     }
+
+    async fn get_sorted_set_page(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min_score: f64,
+        count: u64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        let query =
+            sqlx::query_as("SELECT value, score FROM sorted_set_storage WHERE key = ? AND namespace = ? AND score >= ? ORDER BY score ASC, value ASC LIMIT ?;")
+                .bind(key)
+                .bind(Self::namespace(namespace))
+                .bind(min_score)
+                .bind(count as i64);
+
+        self.pool
+            .with_ro(svc_name, api_name)
+            .fetch_all_as::<DBScoreValue, _>(query)
+            .await
+            .map(|vec| {
+                vec.into_iter()
+                    .map(|k| k.into_pair())
+                    .collect::<Vec<(f64, Bytes)>>()
+            })
+            .map_err(|err| err.to_safe_string())
+    }
 }
 
 #[derive(sqlx::FromRow, Debug)]
@@ -15,16 +15,61 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::durable_host::sockets::tcp::finish_connect_durably;
+use crate::durable_host::sockets::udp::receive_durably;
 use crate::durable_host::DurableWorkerCtx;
 use crate::workerctx::WorkerCtx;
-use wasmtime::component::{HasSelf, Linker};
+use wasmtime::component::{HasSelf, Linker, Resource};
 use wasmtime::Engine;
 use wasmtime_wasi::cli::{StdinStream, StdoutStream};
+use wasmtime_wasi::p2::bindings::sockets::network;
+use wasmtime_wasi::p2::bindings::sockets::tcp::TcpSocket;
+use wasmtime_wasi::p2::bindings::sockets::udp::IncomingDatagramStream;
 use wasmtime_wasi::{DirPerms, FilePerms, IoCtx, ResourceTable, WasiCtx, WasiCtxBuilder};
 
 pub mod helpers;
 pub mod logging;
 
+/// Replaces the synchronous socket functions whose results have to be recorded in the oplog
+/// with async versions, as persisting oplog entries requires awaiting.
+fn add_durable_socket_overrides<Ctx: WorkerCtx + Send + Sync>(
+    linker: &mut Linker<Ctx>,
+    get: fn(&mut Ctx) -> &mut DurableWorkerCtx<Ctx>,
+) -> wasmtime::Result<()> {
+    linker.allow_shadowing(true);
+
+    linker.instance("wasi:sockets/tcp@0.2.3")?.func_wrap_async(
+        "[method]tcp-socket.finish-connect",
+        move |mut store, (self_,): (Resource<TcpSocket>,)| {
+            Box::new(async move {
+                let ctx = get(store.data_mut());
+                let result = match finish_connect_durably(ctx, self_).await {
+                    Ok(streams) => Ok(streams),
+                    Err(err) => Err(network::Host::convert_error_code(ctx, err)?),
+                };
+                Ok((result,))
+            })
+        },
+    )?;
+
+    linker.instance("wasi:sockets/udp@0.2.3")?.func_wrap_async(
+        "[method]incoming-datagram-stream.receive",
+        move |mut store, (self_, max_results): (Resource<IncomingDatagramStream>, u64)| {
+            Box::new(async move {
+                let ctx = get(store.data_mut());
+                let result = match receive_durably(ctx, self_, max_results).await {
+                    Ok(datagrams) => Ok(datagrams),
+                    Err(err) => Err(network::Host::convert_error_code(ctx, err)?),
+                };
+                Ok((result,))
+            })
+        },
+    )?;
+
+    linker.allow_shadowing(false);
+    Ok(())
+}
+
 pub fn create_linker<Ctx: WorkerCtx + Send + Sync>(
     engine: &Engine,
     get: fn(&mut Ctx) -> &mut DurableWorkerCtx<Ctx>,
@@ -147,6 +192,7 @@ pub fn create_linker<Ctx: WorkerCtx + Send + Sync>(
         _,
         HasSelf<DurableWorkerCtx<Ctx>>,
     >(&mut linker, get)?;
+    add_durable_socket_overrides(&mut linker, get)?;
 
     wasmtime_wasi_http::bindings::http::outgoing_handler::add_to_linker::<
         _,
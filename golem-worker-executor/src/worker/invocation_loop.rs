@@ -40,12 +40,13 @@ use golem_common::model::{
 };
 use golem_common::retries::get_delay;
 use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
-use golem_service_base::model::GetFileSystemNodeResult;
+use golem_service_base::model::{AgentUsage, GetFileSystemNodeResult};
 
 use std::collections::VecDeque;
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::RwLock;
 use tracing::{debug, span, warn, Instrument, Level, Span};
@@ -284,7 +285,12 @@ impl<Ctx: WorkerCtx> InnerInvocationLoop<'_, Ctx> {
                             self.internal_invocation(message).await
                         } else {
                             // Queue is empty, use last_known_status for pending updates and invocations
-                            break self.drain_pending_from_status().await;
+                            match self.drain_pending_from_status().await {
+                                CommandOutcome::Continue if self.active.read().await.is_empty() => {
+                                    break CommandOutcome::Continue;
+                                }
+                                other => other,
+                            }
                         };
 
                         match result {
@@ -324,8 +330,15 @@ impl<Ctx: WorkerCtx> InnerInvocationLoop<'_, Ctx> {
 
     /// When the main queue becomes empty, process items from last_known_status:
     /// first pending_updates, then pending_invocations
+    ///
+    /// Stops early if new items appear in the main queue (for example read-only queries), so
+    /// they get served between the persisted invocations.
     async fn drain_pending_from_status(&mut self) -> CommandOutcome {
         loop {
+            if !self.active.read().await.is_empty() {
+                break CommandOutcome::Continue;
+            }
+
             let status = self.parent.last_known_status.read().await.clone();
 
             // First, try to process a pending update
@@ -471,6 +484,112 @@ impl<Ctx: WorkerCtx> Invocation<'_, Ctx> {
                 CommandOutcome::Continue
             }
             QueuedWorkerInvocation::SaveSnapshot => self.save_snapshot().await,
+            QueuedWorkerInvocation::ReadOnlyQuery { invocation, sender } => {
+                self.read_only_query(invocation, sender).await
+            }
+        }
+    }
+
+    /// Runs a read-only agent method against the current state of the instance
+    ///
+    /// Nothing gets written to the oplog. Durable side effects fail the method, and the SDKs reject
+    /// methods changing the agent's state, so a successful query leaves the instance as it was.
+    /// If the query did not succeed, the instance may have been left in an inconsistent state, so it
+    /// gets restarted and recovered from the oplog.
+    async fn read_only_query(
+        &mut self,
+        invocation: AgentInvocation,
+        sender: Sender<Result<AgentInvocationOutput, WorkerExecutorError>>,
+    ) -> CommandOutcome {
+        let span = span!(
+            Level::INFO,
+            "read_only_query",
+            worker_id = %self.owned_worker_id.worker_id,
+            agent_type = self.parent
+                .agent_id
+                .as_ref()
+                .map(|id| id.agent_type.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            function = invocation.display_name()
+        );
+
+        self.read_only_query_inner(invocation, sender)
+            .instrument(span)
+            .await
+    }
+
+    /// The inner implementation of `read_only_query` to be instrumented with a span.
+    async fn read_only_query_inner(
+        &mut self,
+        invocation: AgentInvocation,
+        sender: Sender<Result<AgentInvocationOutput, WorkerExecutorError>>,
+    ) -> CommandOutcome {
+        let component_metadata = self.store.data().component_metadata().metadata.clone();
+        let component_revision = self.store.data().component_metadata().revision;
+
+        let lowered = match lower_invocation(
+            invocation,
+            &component_metadata,
+            self.parent.agent_id.as_ref(),
+        ) {
+            Ok(lowered) => lowered,
+            Err(err) => {
+                let _ = sender.send(Err(err));
+                return CommandOutcome::Continue;
+            }
+        };
+
+        self.store.data_mut().begin_read_only_query();
+        let result = invoke_observed_and_traced(
+            lowered,
+            self.store,
+            self.instance,
+            &component_metadata,
+            InvocationMode::Replay,
+        )
+        .await;
+        self.store.data_mut().end_read_only_query();
+
+        match result {
+            Ok(InvokeResult::Succeeded {
+                result,
+                consumed_fuel,
+            }) => {
+                let _ = sender.send(Ok(AgentInvocationOutput {
+                    result,
+                    consumed_fuel: Some(consumed_fuel),
+                    component_revision: Some(component_revision),
+                }));
+                CommandOutcome::Continue
+            }
+            Ok(InvokeResult::Failed { error, .. }) => {
+                let stderr = self
+                    .store
+                    .data()
+                    .get_public_state()
+                    .event_service()
+                    .get_last_invocation_errors();
+                let _ = sender.send(Err(WorkerExecutorError::InvocationFailed { error, stderr }));
+                CommandOutcome::BreakInnerLoop(RetryDecision::Immediate)
+            }
+            Ok(InvokeResult::Exited { .. }) => {
+                let _ = sender.send(Err(WorkerExecutorError::runtime("Process exited")));
+                CommandOutcome::BreakInnerLoop(RetryDecision::Immediate)
+            }
+            Ok(InvokeResult::Interrupted { interrupt_kind, .. }) => {
+                let _ = sender.send(Err(interrupt_kind.into()));
+                match interrupt_kind {
+                    InterruptKind::Restart | InterruptKind::Jump => {
+                        CommandOutcome::BreakInnerLoop(RetryDecision::Immediate)
+                    }
+                    _ => CommandOutcome::BreakInnerLoop(RetryDecision::None),
+                }
+            }
+            Err(err) => {
+                // Failed before running the method, so the instance state was not affected
+                let _ = sender.send(Err(err));
+                CommandOutcome::Continue
+            }
         }
     }
 
@@ -550,9 +669,19 @@ impl<Ctx: WorkerCtx> Invocation<'_, Ctx> {
     ) -> CommandOutcome {
         let kind = invocation.kind();
         let display_name = invocation.display_name();
+        let started_at = Instant::now();
         let result = self
             .invoke_agent_with_context(invocation_context, idempotency_key, invocation)
             .await;
+        self.parent.record_usage(AgentUsage {
+            invocations: 1,
+            fuel: result
+                .as_ref()
+                .map(|result| result.consumed_fuel())
+                .unwrap_or_default(),
+            invocation_time_millis: started_at.elapsed().as_millis() as u64,
+            ..Default::default()
+        });
 
         match result {
             Ok(InvokeResult::Succeeded {
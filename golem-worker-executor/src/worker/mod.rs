@@ -18,22 +18,23 @@ mod invocation_loop;
 pub mod status;
 
 use self::status::{
-    calculate_last_known_status_for_existing_worker, update_status_with_new_entries,
+    agent_lifecycle_events, calculate_last_known_status_for_existing_worker,
+    update_status_with_new_entries,
 };
 use crate::durable_host::recover_stderr_logs;
 use crate::model::{ExecutionStatus, LookupResult, ReadFileResult, TrapType, WorkerConfig};
 use crate::services::events::{Event, EventsSubscription};
-use crate::services::golem_config::SnapshotPolicy;
+use crate::services::golem_config::{OplogRetentionPolicy, SnapshotPolicy};
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
-use crate::services::worker::GetWorkerMetadataResult;
+use crate::services::worker::{has_discardable_oplog_history, GetWorkerMetadataResult};
 use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
 use crate::services::{
-    All, HasActiveWorkers, HasAgentTypesService, HasAgentWebhooksService, HasAll,
-    HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps, HasFileLoader,
-    HasKeyValueService, HasOplog, HasOplogService, HasPromiseService, HasRdbmsService,
-    HasResourceLimits, HasRpc, HasSchedulerService, HasShardService, HasWasmtimeEngine,
-    HasWorkerEnumerationService, HasWorkerForkService, HasWorkerProxy, HasWorkerService,
-    UsesAllDeps,
+    All, HasActiveWorkers, HasAgentLifecycleEventService, HasAgentTypesService,
+    HasAgentWebhooksService, HasAll, HasBlobStoreService, HasComponentService, HasConfig,
+    HasEvents, HasExtraDeps, HasFileLoader, HasKeyValueService, HasNetworkEgressPolicyService,
+    HasOplog, HasOplogService, HasPromiseService, HasRdbmsService, HasResourceLimits, HasRpc,
+    HasSchedulerService, HasShardService, HasWasmtimeEngine, HasWorkerEnumerationService,
+    HasWorkerForkService, HasWorkerProxy, HasWorkerService, UsesAllDeps,
 };
 use crate::worker::invocation_loop::InvocationLoop;
 use crate::worker::status::calculate_last_known_status;
@@ -45,22 +46,30 @@ use golem_common::model::agent::{AgentId, AgentMode, Principal, Snapshotting, Sn
 use golem_common::model::component::ComponentRevision;
 use golem_common::model::component::{ComponentFilePath, PluginPriority};
 use golem_common::model::invocation_context::InvocationContextStack;
-use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
+use golem_common::model::invocation_queue::InvocationPriority;
+use golem_common::model::lifecycle_webhook::{
+    AgentLifecycleEvent, AgentLifecycleEventId, AgentLifecycleEventType,
+};
+use golem_common::model::oplog::{
+    OplogEntry, OplogIndex, SagaCompensation, TimestampedUpdateDescription, UpdateDescription,
+};
 use golem_common::model::regions::OplogRegion;
 use golem_common::model::worker::{RevertWorkerTarget, WorkerCreationLocalAgentConfigEntry};
 use golem_common::model::RetryConfig;
 use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, AgentInvocationResult, IdempotencyKey, OwnedWorkerId,
-    Timestamp, TimestampedAgentInvocation, WorkerId, WorkerMetadata, WorkerStatusRecord,
+    ScheduledAction, Timestamp, TimestampedAgentInvocation, WorkerId, WorkerMetadata, WorkerStatus,
+    WorkerStatusRecord,
 };
 use golem_common::one_shot::OneShotEvent;
 use golem_common::read_only_lock;
 use golem_service_base::error::worker_executor::{
     GolemSpecificWasmTrap, InterruptKind, WorkerExecutorError,
 };
-use golem_service_base::model::GetFileSystemNodeResult;
+use golem_service_base::model::{AgentUsage, AgentUsageKey, GetFileSystemNodeResult};
 
 use self::agent_config::parse_worker_creation_local_agent_config;
+use std::cmp::min;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -93,6 +102,8 @@ pub struct Worker<Ctx: WorkerCtx> {
 
     oplog: Arc<dyn Oplog>,
     worker_event_service: Arc<dyn WorkerEventService + Send + Sync>,
+    // Index of the last committed oplog entry, updated on every commit that added new entries
+    committed_oplog_index: tokio::sync::watch::Sender<OplogIndex>,
 
     deps: All<Ctx>,
 
@@ -106,6 +117,8 @@ pub struct Worker<Ctx: WorkerCtx> {
     // Note: std lock for wasmtime reasons
     execution_status: Arc<std::sync::RwLock<ExecutionStatus>>,
     update_state_lock: tokio::sync::Mutex<()>,
+    // Held while compensating open sagas, so concurrent attempts do not record the same compensations
+    saga_compensation_lock: tokio::sync::Mutex<()>,
     worker_estimate_coefficient: f64,
 
     // IMPORTANT: Every external operation must acquire the instance lock, even briefly, to confirm the worker isn’t deleting.
@@ -295,6 +308,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 deps.config().limits.event_broadcast_capacity,
                 deps.config().limits.event_history_size,
             )),
+            committed_oplog_index: tokio::sync::watch::Sender::new(last_oplog_idx),
             deps: All::from_other(deps),
             queue,
             external_invocation_spans,
@@ -307,6 +321,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             oom_retry_config: deps.config().memory.oom_retry_config.clone(),
             snapshot_policy,
             update_state_lock: Mutex::new(()),
+            saga_compensation_lock: Mutex::new(()),
             last_known_status_detached: AtomicBool::new(false),
             last_resume_request: Mutex::new(Timestamp::now_utc()),
             snapshot_recovery_disabled: AtomicBool::new(false),
@@ -334,9 +349,149 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         } else {
             warn!("Unexpected non-agentic worker"); // TODO: change this once oplog-processors are finalized
         };
+
+        // We might have stopped between the agent failing and scheduling the compensation of its open sagas
+        let has_uncompensated_sagas = {
+            let status = worker.last_known_status.read().await;
+            status.status == WorkerStatus::Failed && !status.open_sagas.is_empty()
+        };
+        if has_uncompensated_sagas {
+            worker.schedule_saga_compensation().await;
+        }
         Ok(worker)
     }
 
+    pub fn agent_id(&self) -> Option<&AgentId> {
+        self.agent_id.as_ref()
+    }
+
+    /// Records usage metered for the worker's agent type. Non-agent workers are not metered.
+    pub(crate) fn record_usage(&self, usage: AgentUsage) {
+        if let Some(agent_id) = &self.agent_id {
+            self.resource_limits().record_agent_usage(
+                AgentUsageKey {
+                    environment_id: self.owned_worker_id.environment_id(),
+                    component_id: self.owned_worker_id.component_id(),
+                    agent_type: agent_id.agent_type.clone(),
+                },
+                usage,
+            );
+        }
+    }
+
+    async fn publish_agent_lifecycle_event(
+        &self,
+        event_type: AgentLifecycleEventType,
+        component_revision: ComponentRevision,
+        error: Option<String>,
+    ) {
+        self.agent_lifecycle_event_service()
+            .publish(AgentLifecycleEvent {
+                id: AgentLifecycleEventId::new(),
+                environment_id: self.owned_worker_id.environment_id(),
+                worker_id: self.owned_worker_id.worker_id(),
+                agent_type: self
+                    .agent_id
+                    .as_ref()
+                    .map(|agent_id| agent_id.agent_type.clone()),
+                event_type,
+                component_revision,
+                timestamp: chrono::Utc::now(),
+                error,
+            })
+            .await;
+    }
+
+    /// Schedules the compensation of the sagas left open by the agent when it failed permanently.
+    /// The scheduled action is persisted, so the compensations are resumed even if the executor
+    /// stops before performing them.
+    async fn schedule_saga_compensation(&self) {
+        let schedule_id = self
+            .scheduler_service()
+            .schedule(
+                chrono::Utc::now(),
+                ScheduledAction::CompensateSagas {
+                    account_id: self.initial_worker_metadata.created_by,
+                    owned_worker_id: self.owned_worker_id.clone(),
+                    attempt: 0,
+                },
+            )
+            .await;
+        debug!("Scheduled the compensation of open sagas with id {schedule_id}");
+    }
+
+    /// Invokes the compensations of the sagas left open by the agent when it failed permanently,
+    /// and returns whether all of them were performed. Each saga's steps are compensated in reverse
+    /// order, and the performed compensations are recorded in the oplog, so they are not repeated
+    /// by a next attempt. The compensations use the same idempotency keys as an explicit abort,
+    /// so steps already compensated by the agent are not executed again either.
+    pub async fn compensate_open_sagas(&self) -> bool {
+        let _guard = self.saga_compensation_lock.lock().await;
+        let open_sagas = {
+            let status = self.last_known_status.read().await;
+            if status.status != WorkerStatus::Failed {
+                // The agent has been reverted or resumed since it failed
+                return true;
+            }
+            status.open_sagas.clone()
+        };
+
+        let worker_id = self.owned_worker_id.worker_id();
+        let mut all_compensated = true;
+        for (begin_index, steps) in open_sagas {
+            let mut saga_compensated = true;
+            for (step_index, compensation) in steps.into_iter().rev() {
+                let input = match self.oplog.download_payload(compensation.input).await {
+                    Ok(input) => input,
+                    Err(err) => {
+                        warn!("Failed to download input of saga step {step_index}: {err}");
+                        saga_compensated = false;
+                        break;
+                    }
+                };
+                let target = OwnedWorkerId::new(
+                    self.owned_worker_id.environment_id(),
+                    &compensation.target_worker_id,
+                );
+                let result = self
+                    .rpc()
+                    .invoke_and_await(
+                        &target,
+                        Some(SagaCompensation::idempotency_key(&worker_id, step_index)),
+                        compensation.method_name,
+                        input,
+                        self.initial_worker_metadata.created_by,
+                        &worker_id,
+                        &self.initial_worker_metadata.env,
+                        self.initial_worker_metadata.config_vars.clone(),
+                        InvocationContextStack::fresh(),
+                    )
+                    .await;
+                match result {
+                    Ok(_) => {
+                        self.add_and_commit_oplog(OplogEntry::saga_step_compensated(
+                            begin_index,
+                            step_index,
+                        ))
+                        .await;
+                    }
+                    Err(err) => {
+                        warn!("Failed to compensate saga step {step_index}: {err}");
+                        saga_compensated = false;
+                        break;
+                    }
+                }
+            }
+            if saga_compensated {
+                self.add_and_commit_oplog(OplogEntry::end_saga(begin_index, true))
+                    .await;
+            } else {
+                all_compensated = false;
+            }
+        }
+        all_compensated
+    }
+
     pub fn worker_id(&self) -> WorkerId {
         self.owned_worker_id.worker_id()
     }
@@ -458,6 +613,12 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         self.worker_event_service.clone()
     }
 
+    /// Subscribes to oplog commit notifications. The received value is the index of the last
+    /// committed oplog entry, and it changes every time a commit adds new entries to the oplog.
+    pub fn oplog_commits(&self) -> tokio::sync::watch::Receiver<OplogIndex> {
+        self.committed_oplog_index.subscribe()
+    }
+
     pub fn is_loading(&self) -> bool {
         matches!(
             *self.execution_status.read().unwrap(),
@@ -486,6 +647,22 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Returns true if part of the oplog has been permanently discarded by the oplog retention
+    /// policy, in which case the worker can only be recovered from its latest snapshot.
+    pub async fn has_discarded_oplog_history(&self) -> bool {
+        self.last_known_status
+            .read()
+            .await
+            .discarded_region
+            .is_some()
+    }
+
+    /// Returns true if the worker has oplog history before its latest snapshot that has not been
+    /// discarded yet.
+    pub async fn has_discardable_oplog_history(&self) -> bool {
+        has_discardable_oplog_history(&*self.last_known_status.read().await)
+    }
+
     // Outside of reverts and updates, this will return the same status as get_latest_worker_metadata.
     // This just has an additional assert built in for when decisions need to be sure that they are fully up to date on the oplog.
     // _NEVER_ call this from outside the invocation loop, as that is the only place that can reason about whether the status is detached or not.
@@ -570,6 +747,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     pub async fn invoke(
         &self,
         invocation: AgentInvocation,
+        priority: InvocationPriority,
     ) -> Result<ResultOrSubscription, WorkerExecutorError> {
         let idempotency_key = invocation
             .idempotency_key()
@@ -587,17 +765,25 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             LookupResult::Interrupted => Err(InterruptKind::Interrupt(Timestamp::now_utc()).into()),
             LookupResult::Pending => Ok(ResultOrSubscription::Pending(subscription)),
             LookupResult::New => {
-                self.enqueue_worker_invocation(invocation).await?;
+                self.enqueue_worker_invocation(invocation, priority).await?;
                 Ok(ResultOrSubscription::Pending(subscription))
             }
         }
     }
 
     /// Invokes the worker and awaits for a result.
+    ///
+    /// Read-only agent methods are not enqueued as pending invocations; they are served by
+    /// `query` instead.
     pub async fn invoke_and_await(
         &self,
         invocation: AgentInvocation,
+        priority: InvocationPriority,
     ) -> Result<AgentInvocationOutput, WorkerExecutorError> {
+        if self.is_read_only_query(&invocation).await? {
+            return self.query(invocation).await;
+        }
+
         let idempotency_key = invocation
             .idempotency_key()
             .ok_or_else(|| {
@@ -605,7 +791,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             })?
             .clone();
 
-        match self.invoke(invocation).await? {
+        match self.invoke(invocation, priority).await? {
             ResultOrSubscription::Finished(Ok(output)) => Ok(output),
             ResultOrSubscription::Finished(Err(err)) => Err(err),
             ResultOrSubscription::Pending(subscription) => {
@@ -635,6 +821,76 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Runs a read-only agent method against the current in-memory state of the worker.
+    ///
+    /// The query is not persisted in the oplog, and it does not wait for the persisted pending
+    /// invocations of the worker - it is processed by the invocation loop as soon as the current
+    /// invocation finishes. If the method attempts to perform a durable side effect, it fails and
+    /// the worker gets restarted to drop any in-memory changes it made.
+    pub async fn query(
+        &self,
+        invocation: AgentInvocation,
+    ) -> Result<AgentInvocationOutput, WorkerExecutorError> {
+        let instance_guard = self.lock_non_stopping_worker().await;
+
+        if instance_guard.is_deleting() {
+            return Err(WorkerExecutorError::invalid_request(
+                "Cannot query a deleting worker",
+            ));
+        };
+
+        if let Some(err) = instance_guard.startup_failure() {
+            return Err(err.clone());
+        }
+
+        let (sender, receiver) = oneshot::channel();
+
+        self.queue
+            .write()
+            .await
+            .push_back(QueuedWorkerInvocation::ReadOnlyQuery { invocation, sender });
+
+        if let WorkerInstance::Running(running) = &*instance_guard {
+            running.sender.send(WorkerCommand::Unblock).unwrap();
+        };
+
+        drop(instance_guard);
+
+        receiver.await.unwrap()
+    }
+
+    /// Checks whether the invocation targets an agent method marked as read-only
+    async fn is_read_only_query(
+        &self,
+        invocation: &AgentInvocation,
+    ) -> Result<bool, WorkerExecutorError> {
+        let (Some(agent_id), AgentInvocation::AgentMethod { method_name, .. }) =
+            (&self.agent_id, invocation)
+        else {
+            return Ok(false);
+        };
+
+        let component_revision = self.last_known_status.read().await.component_revision;
+        let component = self
+            .component_service()
+            .get_metadata(
+                self.owned_worker_id.component_id(),
+                Some(component_revision),
+            )
+            .await?;
+
+        Ok(component
+            .metadata
+            .find_agent_type_by_name(&agent_id.agent_type)
+            .and_then(|agent_type| {
+                agent_type
+                    .methods
+                    .into_iter()
+                    .find(|method| &method.name == method_name)
+            })
+            .is_some_and(|method| method.read_only))
+    }
+
     /// Enqueue attempting an update.
     ///
     /// The update itself is not performed by the invocation queue's processing loop,
@@ -656,6 +912,33 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .await
     }
 
+    /// Makes the worker attempt the given update the next time it gets recovered, without
+    /// recording it in the oplog.
+    ///
+    /// Used by the debugging executor to check an update against the worker's existing history
+    /// without affecting the worker itself.
+    pub async fn simulate_pending_update(&self, update_description: UpdateDescription) {
+        let oplog_index = self.oplog.current_oplog_index().await;
+        self.last_known_status
+            .write()
+            .await
+            .pending_updates
+            .push_front(TimestampedUpdateDescription {
+                timestamp: Timestamp::now_utc(),
+                oplog_index,
+                description: update_description,
+            });
+    }
+
+    /// Removes an update previously added by `simulate_pending_update`.
+    pub async fn drop_simulated_pending_update(&self) {
+        self.last_known_status
+            .write()
+            .await
+            .pending_updates
+            .pop_front();
+    }
+
     pub async fn pending_invocations(&self) -> Vec<TimestampedAgentInvocation> {
         self.last_known_status
             .read()
@@ -704,6 +987,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 .collect(),
         ]
         .concat();
+        let component_revision = self.last_known_status.read().await.component_revision;
         let mut map = self.invocation_results.write().await;
         for key in keys_to_fail {
             let stderr = self.worker_event_service.get_last_invocation_errors();
@@ -718,6 +1002,12 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             );
             let golem_error = trap_type.as_golem_error(&stderr);
             if let Some(golem_error) = golem_error {
+                self.publish_agent_lifecycle_event(
+                    AgentLifecycleEventType::InvocationFailed,
+                    component_revision,
+                    Some(golem_error.to_string()),
+                )
+                .await;
                 self.events().publish(Event::InvocationCompleted {
                     worker_id: self.owned_worker_id.worker_id(),
                     idempotency_key: key.clone(),
@@ -819,6 +1109,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     async fn enqueue_worker_invocation(
         &self,
         invocation: AgentInvocation,
+        priority: InvocationPriority,
     ) -> Result<(), WorkerExecutorError> {
         let instance_guard = self.lock_non_stopping_worker().await;
 
@@ -832,6 +1123,18 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             return Err(err.clone());
         }
 
+        {
+            let status = self.last_known_status.read().await;
+            if let Some(limit) = status.pending_invocation_limit {
+                if status.pending_invocations.len() as u64 >= limit {
+                    return Err(WorkerExecutorError::InvocationQueueFull {
+                        worker_id: self.owned_worker_id.worker_id(),
+                        limit,
+                    });
+                }
+            }
+        }
+
         let (idempotency_key, invocation_payload, invocation_context) =
             invocation.clone().into_parts();
         let payload = self
@@ -845,7 +1148,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             })?;
         let invocation_context_spans = invocation_context.to_oplog_data();
         let entry = OplogEntry::pending_agent_invocation(
-            idempotency_key,
+            idempotency_key.clone(),
             payload,
             invocation_context.trace_id,
             invocation_context.trace_states,
@@ -854,9 +1157,21 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         let timestamped_invocation = TimestampedAgentInvocation {
             timestamp: entry.timestamp(),
             invocation,
+            priority,
         };
-        self.add_and_commit_oplog_internal(&instance_guard, entry)
+        if priority == InvocationPriority::Normal {
+            self.add_and_commit_oplog_internal(&instance_guard, entry)
+                .await;
+        } else {
+            // Pending invocations are always enqueued with normal priority, the reorder entry
+            // moves them to their priority class
+            self.add_to_oplog(entry).await;
+            self.add_and_commit_oplog_internal(
+                &instance_guard,
+                OplogEntry::reorder_pending_invocation(idempotency_key, priority, None),
+            )
             .await;
+        }
 
         if let Some(idempotency_key) = timestamped_invocation.invocation.idempotency_key() {
             self.external_invocation_spans
@@ -1127,8 +1442,126 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         Ok(())
     }
 
+    /// Moves a pending invocation to a new place in the invocation queue.
+    ///
+    /// If `priority` is not specified, the invocation keeps its current priority.
+    pub async fn reorder_invocation(
+        &self,
+        idempotency_key: IdempotencyKey,
+        priority: Option<InvocationPriority>,
+        position: Option<u64>,
+    ) -> Result<(), WorkerExecutorError> {
+        let instance_guard = self.lock_non_stopping_worker().await;
+
+        if instance_guard.is_deleting() {
+            return Err(WorkerExecutorError::invalid_request(
+                "Cannot reorder invocations on a deleting worker",
+            ));
+        };
+
+        let current_priority = self
+            .pending_invocations()
+            .await
+            .into_iter()
+            .find(|pending| pending.invocation.has_idempotency_key(&idempotency_key))
+            .map(|pending| pending.priority)
+            .ok_or_else(|| {
+                WorkerExecutorError::invalid_request(format!(
+                    "No pending invocation with idempotency key {idempotency_key}"
+                ))
+            })?;
+
+        self.add_and_commit_oplog_internal(
+            &instance_guard,
+            OplogEntry::reorder_pending_invocation(
+                idempotency_key,
+                priority.unwrap_or(current_priority),
+                position,
+            ),
+        )
+        .await;
+
+        drop(instance_guard);
+        Ok(())
+    }
+
+    /// Cancels every pending invocation of the given agent method, returning the number of
+    /// canceled invocations.
+    pub async fn purge_invocations(&self, method_name: &str) -> Result<u64, WorkerExecutorError> {
+        let instance_guard = self.lock_non_stopping_worker().await;
+
+        if instance_guard.is_deleting() {
+            return Err(WorkerExecutorError::invalid_request(
+                "Cannot purge invocations on a deleting worker",
+            ));
+        };
+
+        let keys_to_purge = self
+            .pending_invocations()
+            .await
+            .into_iter()
+            .filter_map(|pending| match pending.invocation {
+                AgentInvocation::AgentMethod {
+                    idempotency_key,
+                    method_name: pending_method_name,
+                    ..
+                } if pending_method_name == method_name => Some(idempotency_key),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let purged = keys_to_purge.len() as u64;
+        if !keys_to_purge.is_empty() {
+            for idempotency_key in keys_to_purge {
+                self.add_to_oplog(OplogEntry::cancel_pending_invocation(idempotency_key))
+                    .await;
+            }
+            self.commit_oplog_and_update_state_internal(CommitLevel::Always)
+                .await;
+        }
+
+        drop(instance_guard);
+        Ok(purged)
+    }
+
+    /// Sets the maximum number of pending invocations. Invocations already in the queue are
+    /// kept even if they exceed the new limit.
+    pub async fn set_invocation_queue_limit(
+        &self,
+        limit: Option<u64>,
+    ) -> Result<(), WorkerExecutorError> {
+        let instance_guard = self.lock_non_stopping_worker().await;
+
+        if instance_guard.is_deleting() {
+            return Err(WorkerExecutorError::invalid_request(
+                "Cannot set the invocation queue limit of a deleting worker",
+            ));
+        };
+
+        self.add_and_commit_oplog_internal(
+            &instance_guard,
+            OplogEntry::set_invocation_queue_limit(limit),
+        )
+        .await;
+
+        drop(instance_guard);
+        Ok(())
+    }
+
+    pub async fn invocation_queue_limit(&self) -> Option<u64> {
+        self.last_known_status.read().await.pending_invocation_limit
+    }
+
     /// Starting from the end of the oplog, find the Nth AgentInvocationStarted entry's index.
     async fn find_nth_invocation_from_end(&self, n: usize) -> Option<OplogIndex> {
+        let first_retained = self
+            .last_known_status
+            .read()
+            .await
+            .discarded_region
+            .as_ref()
+            .map(|region| region.end.next())
+            .unwrap_or(OplogIndex::INITIAL);
         let mut current = self.oplog.current_oplog_index().await;
         let mut found = 0;
         loop {
@@ -1141,7 +1574,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 }
             }
 
-            if current == OplogIndex::INITIAL {
+            if current == first_retained {
                 return None;
             } else {
                 current = current.previous();
@@ -1181,6 +1614,14 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             Err(WorkerExecutorError::invalid_request(format!(
                 "Attempted to revert to a deleted region in oplog to index {last_oplog_index}"
             )))
+        } else if last_known_status
+            .discarded_region
+            .as_ref()
+            .is_some_and(|region| last_oplog_index <= region.end)
+        {
+            Err(WorkerExecutorError::invalid_request(format!(
+                "Attempted to revert to a discarded region in oplog to index {last_oplog_index}"
+            )))
         } else {
             let region = OplogRegion {
                 start: region_start,
@@ -1431,6 +1872,9 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     let _ = sender.send(Err(error.clone()));
                 }
                 QueuedWorkerInvocation::SaveSnapshot => {}
+                QueuedWorkerInvocation::ReadOnlyQuery { sender, .. } => {
+                    let _ = sender.send(Err(error.clone()));
+                }
             }
         }
 
@@ -1705,6 +2149,14 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         agent_mode,
                     )
                     .await;
+                let indexed_status = initial_status.read().await.clone();
+                this.worker_service()
+                    .add_to_index(
+                        &initial_worker_metadata.worker_id,
+                        initial_worker_metadata.created_at,
+                        (agent_mode != AgentMode::Ephemeral).then_some(&indexed_status),
+                    )
+                    .await;
 
                 Ok(GetOrCreateWorkerResult {
                     initial_worker_metadata,
@@ -1730,10 +2182,18 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         {
             debug!("Worker status was detached from oplog, reloading it from scratch");
 
-            // reload status from scratch
-            let worker_status =
-                calculate_last_known_status_for_existing_worker(self, &self.owned_worker_id, None)
-                    .await;
+            // reload status from scratch, skipping the discarded oplog history
+            let discarded_region = self.last_known_status.read().await.discarded_region.clone();
+            let initial_status = discarded_region.map(|region| WorkerStatusRecord {
+                discarded_region: Some(region),
+                ..WorkerStatusRecord::default()
+            });
+            let worker_status = calculate_last_known_status_for_existing_worker(
+                self,
+                &self.owned_worker_id,
+                initial_status,
+            )
+            .await;
 
             *self.last_known_status.write().await = worker_status.clone();
             self.worker_service()
@@ -1745,6 +2205,128 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         };
     }
 
+    /// Permanently discards the part of the oplog history that is no longer needed for recovering
+    /// the worker, according to the given retention policy. Only entries before the latest snapshot
+    /// are ever discarded, and the worker status is updated first so recovery starts from that
+    /// snapshot.
+    ///
+    /// Returns the number of dropped oplog entries.
+    pub async fn apply_oplog_retention(&self, policy: &OplogRetentionPolicy) -> u64 {
+        let update_state_lock_guard = self.update_state_lock.lock().await;
+
+        self.commit_and_update_state_inner(&update_state_lock_guard, CommitLevel::Always)
+            .await;
+        if self.last_known_status_detached.load(Ordering::Acquire) {
+            return 0;
+        }
+
+        let mut status = self.last_known_status.read().await.clone();
+        let Some(last_dropped_idx) = self.oplog_retention_point(policy, &status).await else {
+            return 0;
+        };
+        let first_dropped_idx = OplogIndex::INITIAL.next();
+
+        let discarded_region = OplogRegion {
+            start: first_dropped_idx,
+            end: last_dropped_idx,
+        };
+        // Recorded before dropping anything, so the status can be recalculated even if the cached
+        // status gets lost
+        self.worker_service()
+            .set_discarded_oplog_region(&self.owned_worker_id, &discarded_region)
+            .await;
+        status.discarded_region = Some(discarded_region);
+        status
+            .invocation_results
+            .retain(|_, oplog_idx| *oplog_idx > last_dropped_idx);
+        *self.last_known_status.write().await = status.clone();
+        self.worker_service()
+            .update_cached_status(&self.owned_worker_id, &status, self.agent_mode())
+            .await;
+
+        self.invocation_results
+            .write()
+            .await
+            .retain(|_, result| match result {
+                InvocationResult::Lazy { oplog_idx } => *oplog_idx > last_dropped_idx,
+                InvocationResult::Cached { .. } => true,
+            });
+
+        let dropped_entries = self
+            .oplog
+            .drop_range(first_dropped_idx, last_dropped_idx)
+            .await;
+
+        // ensure we hold mutex for the full duration
+        drop(update_state_lock_guard);
+        dropped_entries
+    }
+
+    /// Calculates the last oplog index that can be discarded according to the retention policy.
+    /// Returns None if nothing new can be discarded.
+    async fn oplog_retention_point(
+        &self,
+        policy: &OplogRetentionPolicy,
+        status: &WorkerStatusRecord,
+    ) -> Option<OplogIndex> {
+        // Entries belonging to work that is still in progress must be kept
+        if !status.pending_invocations.is_empty()
+            || !status.pending_updates.is_empty()
+            || status.current_idempotency_key.is_some()
+        {
+            return None;
+        }
+
+        let first_retained = status
+            .discarded_region
+            .as_ref()
+            .map(|region| region.end.next())
+            .unwrap_or(OplogIndex::INITIAL.next());
+        let last_discardable = status.last_snapshot_index?.previous();
+        if last_discardable < first_retained {
+            return None;
+        }
+
+        let point = match policy {
+            OplogRetentionPolicy::KeepAll => return None,
+            OplogRetentionPolicy::DiscardBeforeSnapshot => last_discardable,
+            OplogRetentionPolicy::KeepLastEntries { count } => {
+                let current = u64::from(status.oplog_idx);
+                if current <= *count {
+                    return None;
+                }
+                min(last_discardable, OplogIndex::from_u64(current - *count))
+            }
+            OplogRetentionPolicy::KeepLastDuration { duration } => {
+                let cutoff = Timestamp::now_utc()
+                    .to_millis()
+                    .saturating_sub(duration.as_millis() as u64);
+
+                // Finding the last entry older than the cutoff, assuming timestamps are increasing
+                let mut low = first_retained;
+                let mut high = last_discardable;
+                if self.oplog.read(low).await.timestamp().to_millis() >= cutoff {
+                    return None;
+                }
+                while low < high {
+                    let mid = OplogIndex::from_u64((u64::from(low) + u64::from(high)).div_ceil(2));
+                    if self.oplog.read(mid).await.timestamp().to_millis() < cutoff {
+                        low = mid;
+                    } else {
+                        high = mid.previous();
+                    }
+                }
+                low
+            }
+        };
+
+        if point >= first_retained {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
     // must be called within a held update_state_lock lock.
     async fn commit_and_update_state_inner(
         &self,
@@ -1753,8 +2335,23 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     ) -> bool {
         let new_entries = self.oplog.commit(commit_level).await;
 
+        if let Some(last_committed) = new_entries.keys().next_back() {
+            self.committed_oplog_index.send_replace(*last_committed);
+        }
+
+        let oplog_bytes = self.oplog.take_committed_bytes().await;
+        if self.agent_id.is_some() && oplog_bytes > 0 {
+            self.record_usage(AgentUsage {
+                oplog_bytes,
+                ..Default::default()
+            });
+        }
+
         if !self.last_known_status_detached.load(Ordering::Acquire) {
             let old_status = self.last_known_status.read().await.clone();
+            let created = new_entries
+                .values()
+                .any(|entry| matches!(entry, OplogEntry::Create { .. }));
 
             let updated_status = update_status_with_new_entries(
                 self,
@@ -1768,6 +2365,22 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             if let Some(updated_status) = updated_status {
                 if updated_status != old_status {
                     *self.last_known_status.write().await = updated_status.clone();
+                    for (event_type, error) in
+                        agent_lifecycle_events(&old_status, &updated_status, created)
+                    {
+                        self.publish_agent_lifecycle_event(
+                            event_type,
+                            updated_status.component_revision,
+                            error,
+                        )
+                        .await;
+                    }
+                    if updated_status.status == WorkerStatus::Failed
+                        && old_status.status != WorkerStatus::Failed
+                        && !updated_status.open_sagas.is_empty()
+                    {
+                        self.schedule_saga_compensation().await;
+                    }
                     // TODO: We should do this in the background on a timer instead of on every commit.
                     self.worker_service()
                         .update_cached_status(
@@ -2061,6 +2674,34 @@ impl RunningWorker {
                 .front()
                 .cloned();
 
+            if let (
+                Some(TimestampedUpdateDescription {
+                    description: UpdateDescription::Automatic { target_revision },
+                    ..
+                }),
+                Some(_),
+            ) = (
+                &pending_update,
+                &worker_metadata.last_known_status.discarded_region,
+            ) {
+                // Automatic updates replay the full oplog with the new revision, which is not
+                // possible once the history has been discarded by the oplog retention policy
+                warn!(
+                    "Rejecting automatic update to revision {target_revision} because the oplog history has been discarded"
+                );
+                parent
+                    .add_and_commit_oplog(OplogEntry::failed_update(
+                        *target_revision,
+                        Some(
+                            "Automatic update is not possible because the oplog history has been discarded by the retention policy"
+                                .to_string(),
+                        ),
+                    ))
+                    .await;
+
+                return Box::pin(Self::create_instance(parent)).await;
+            }
+
             let component_revision = pending_update.as_ref().map_or(
                 worker_metadata.last_known_status.component_revision,
                 |update| {
@@ -2124,6 +2765,12 @@ impl RunningWorker {
                     .component_revision_for_replay,
             );
 
+        // History discarded by the oplog retention policy can never be replayed
+        let mut skipped_regions = worker_metadata.last_known_status.skipped_regions;
+        if let Some(discarded_region) = worker_metadata.last_known_status.discarded_region {
+            skipped_regions.add(discarded_region);
+        }
+
         let context = Ctx::create(
             worker_metadata.created_by,
             OwnedWorkerId::new(worker_metadata.environment_id, &worker_metadata.worker_id),
@@ -2140,13 +2787,14 @@ impl RunningWorker {
             parent.oplog.clone(),
             Arc::downgrade(&parent),
             parent.scheduler_service(),
+            parent.pubsub_service(),
             parent.rpc(),
             parent.worker_proxy(),
             parent.component_service(),
             parent.extra_deps(),
             parent.config(),
             WorkerConfig::new(
-                worker_metadata.last_known_status.skipped_regions,
+                skipped_regions,
                 worker_metadata.last_known_status.total_linear_memory_size,
                 component_version_for_replay,
                 worker_metadata.created_by,
@@ -2166,6 +2814,7 @@ impl RunningWorker {
             parent.resource_limits(),
             parent.agent_types(),
             parent.agent_webhooks(),
+            parent.network_egress_policy_service(),
             parent.shard_service(),
             pending_update,
             None,
@@ -2187,6 +2836,10 @@ impl RunningWorker {
                 return Err(InterruptKind::Suspend(Timestamp::now_utc()).into());
             }
 
+            if let Some(trap) = data_mut.check_invocation_limits(current_level) {
+                return Err(trap.into());
+            }
+
             match data_mut.check_interrupt() {
                 Some(kind) => Err(kind.into()),
                 None => Ok(UpdateDeadline::Yield(1)),
@@ -2404,6 +3057,12 @@ pub enum QueuedWorkerInvocation {
     },
     // Triggers a periodic snapshot save for the worker
     SaveSnapshot,
+    // Runs a read-only agent method against the current in-memory state, without persisting it.
+    // These are processed between the persisted invocations of the worker.
+    ReadOnlyQuery {
+        invocation: AgentInvocation,
+        sender: oneshot::Sender<Result<AgentInvocationOutput, WorkerExecutorError>>,
+    },
 }
 
 pub enum ResultOrSubscription {
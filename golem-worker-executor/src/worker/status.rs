@@ -3,16 +3,20 @@ use async_recursion::async_recursion;
 use golem_common::base_model::OplogIndex;
 use golem_common::model::component::{ComponentRevision, PluginPriority};
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::invocation_queue::{
+    insert_by_priority, reorder_pending_invocation, InvocationPriority,
+};
+use golem_common::model::lifecycle_webhook::AgentLifecycleEventType;
 use golem_common::model::oplog::{
-    OplogEntry, OplogPayload, TimestampedUpdateDescription, UpdateDescription, WorkerError,
-    WorkerResourceId,
+    OplogEntry, OplogPayload, SagaCompensation, TimestampedUpdateDescription, UpdateDescription,
+    WorkerError, WorkerResourceId,
 };
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
 use golem_common::model::AgentInvocationPayload;
 use golem_common::model::{
     AgentInvocation, FailedUpdateRecord, IdempotencyKey, OwnedWorkerId, RetryConfig,
-    SuccessfulUpdateRecord, TimestampedAgentInvocation, WorkerResourceDescription, WorkerStatus,
-    WorkerStatusRecord,
+    SuccessfulUpdateRecord, Timestamp, TimestampedAgentInvocation, WorkerResourceDescription,
+    WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::serialization::deserialize;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
@@ -52,14 +56,35 @@ where
     } else if last_known.oplog_idx == last_oplog_index {
         Some(last_known)
     } else {
-        let new_entries: BTreeMap<OplogIndex, OplogEntry> = this
-            .oplog_service()
-            .read_range(
-                owned_worker_id,
-                last_known.oplog_idx.next(),
-                last_oplog_index,
-            )
-            .await;
+        let first_new_index = last_known.oplog_idx.next();
+        let discarded_region = last_known.discarded_region.clone();
+
+        let new_entries: BTreeMap<OplogIndex, OplogEntry> = match &discarded_region {
+            Some(discarded) if discarded.end >= first_new_index => {
+                // The discarded region no longer exists in the oplog, so only the entries around it
+                // are read
+                let mut new_entries = if first_new_index < discarded.start {
+                    this.oplog_service()
+                        .read_range(owned_worker_id, first_new_index, discarded.start.previous())
+                        .await
+                } else {
+                    BTreeMap::new()
+                };
+                if discarded.end < last_oplog_index {
+                    new_entries.extend(
+                        this.oplog_service()
+                            .read_range(owned_worker_id, discarded.end.next(), last_oplog_index)
+                            .await,
+                    );
+                }
+                new_entries
+            }
+            _ => {
+                this.oplog_service()
+                    .read_range(owned_worker_id, first_new_index, last_oplog_index)
+                    .await
+            }
+        };
 
         let final_status = update_status_with_new_entries(
             this,
@@ -73,7 +98,12 @@ where
         if let Some(final_status) = final_status {
             Some(final_status)
         } else {
-            calculate_last_known_status(this, owned_worker_id, None).await
+            // Discarded oplog history cannot be recomputed from the oplog, so it has to be kept
+            let initial = WorkerStatusRecord {
+                discarded_region,
+                ..WorkerStatusRecord::default()
+            };
+            calculate_last_known_status(this, owned_worker_id, Some(initial)).await
         }
     }
 }
@@ -189,6 +219,21 @@ pub async fn update_status_with_new_entries<T: HasOplogService + Sync>(
         &new_entries,
     );
 
+    let (last_invocation_at, last_error) = calculate_last_invocation_and_error(
+        last_known.last_invocation_at,
+        last_known.last_error,
+        &deleted_regions,
+        &new_entries,
+    );
+
+    let pending_invocation_limit = calculate_pending_invocation_limit(
+        last_known.pending_invocation_limit,
+        &deleted_regions,
+        &new_entries,
+    );
+
+    let open_sagas = calculate_open_sagas(last_known.open_sagas, &deleted_regions, &new_entries);
+
     let result = WorkerStatusRecord {
         oplog_idx: new_entries
             .keys()
@@ -213,6 +258,11 @@ pub async fn update_status_with_new_entries<T: HasOplogService + Sync>(
         component_revision_for_replay,
         current_retry_count,
         last_snapshot_index,
+        discarded_region: last_known.discarded_region,
+        last_invocation_at,
+        last_error,
+        pending_invocation_limit,
+        open_sagas,
     };
 
     Some(result)
@@ -355,6 +405,17 @@ fn calculate_latest_worker_status(
                 current_status = WorkerStatus::Running;
             }
             OplogEntry::Snapshot { .. } => {}
+            OplogEntry::ReorderPendingInvocation { .. } => {}
+            OplogEntry::SetInvocationQueueLimit { .. } => {}
+            OplogEntry::BeginSaga { .. } => {
+                current_status = WorkerStatus::Running;
+            }
+            OplogEntry::SagaStep { .. } => {
+                current_status = WorkerStatus::Running;
+            }
+            // Saga compensations may be recorded by the executor after the agent failed, so they do not change the status
+            OplogEntry::SagaStepCompensated { .. } => {}
+            OplogEntry::EndSaga { .. } => {}
             OplogEntry::Error { .. } => {
                 // .. handled separately
             }
@@ -532,10 +593,14 @@ async fn calculate_pending_invocations<T: HasOplogService + Sync>(
                         agent_payload,
                         invocation_context_stack,
                     );
-                    result.push(TimestampedAgentInvocation {
-                        timestamp: *timestamp,
-                        invocation,
-                    });
+                    insert_by_priority(
+                        &mut result,
+                        TimestampedAgentInvocation {
+                            timestamp: *timestamp,
+                            invocation,
+                            priority: InvocationPriority::Normal,
+                        },
+                    );
                 }
             }
             OplogEntry::AgentInvocationStarted {
@@ -582,6 +647,74 @@ async fn calculate_pending_invocations<T: HasOplogService + Sync>(
                     !invocation.invocation.has_idempotency_key(idempotency_key)
                 });
             }
+            OplogEntry::ReorderPendingInvocation {
+                idempotency_key,
+                priority,
+                position,
+                ..
+            } => {
+                reorder_pending_invocation(&mut result, idempotency_key, *priority, *position);
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn calculate_pending_invocation_limit(
+    initial: Option<u64>,
+    deleted_regions: &DeletedRegions,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> Option<u64> {
+    let mut result = initial;
+    for (idx, entry) in entries {
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+
+        if let OplogEntry::SetInvocationQueueLimit { limit, .. } = entry {
+            result = *limit;
+        }
+    }
+    result
+}
+
+fn calculate_open_sagas(
+    initial: BTreeMap<OplogIndex, BTreeMap<OplogIndex, SagaCompensation>>,
+    deleted_regions: &DeletedRegions,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> BTreeMap<OplogIndex, BTreeMap<OplogIndex, SagaCompensation>> {
+    let mut result = initial;
+    for (idx, entry) in entries {
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+
+        match entry {
+            OplogEntry::BeginSaga { .. } => {
+                result.insert(*idx, BTreeMap::new());
+            }
+            OplogEntry::SagaStep {
+                begin_index,
+                compensation,
+                ..
+            } => {
+                if let Some(steps) = result.get_mut(begin_index) {
+                    steps.insert(*idx, compensation.clone());
+                }
+            }
+            OplogEntry::SagaStepCompensated {
+                begin_index,
+                step_index,
+                ..
+            } => {
+                if let Some(steps) = result.get_mut(begin_index) {
+                    steps.remove(step_index);
+                }
+            }
+            OplogEntry::EndSaga { begin_index, .. } => {
+                result.remove(begin_index);
+            }
             _ => {}
         }
     }
@@ -856,6 +989,40 @@ fn calculate_last_snapshot_index(
     result
 }
 
+fn calculate_last_invocation_and_error(
+    last_invocation_at: Option<Timestamp>,
+    last_error: Option<String>,
+    deleted_regions: &DeletedRegions,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> (Option<Timestamp>, Option<String>) {
+    let mut last_invocation_at = last_invocation_at;
+    let mut last_error = last_error;
+
+    for (idx, entry) in entries {
+        // Skipping entries in deleted regions (by revert)
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+
+        match entry {
+            OplogEntry::AgentInvocationStarted { timestamp, .. } => {
+                last_invocation_at = Some(*timestamp);
+                last_error = None;
+            }
+            OplogEntry::AgentInvocationFinished { .. } => {
+                // Errors are never reported across invocation boundaries, similarly to retry counts
+                last_error = None;
+            }
+            OplogEntry::Error { error, .. } => {
+                last_error = Some(error.message().to_string());
+            }
+            _ => {}
+        }
+    }
+
+    (last_invocation_at, last_error)
+}
+
 fn is_worker_error_retriable(
     retry_config: &RetryConfig,
     error: &WorkerError,
@@ -868,9 +1035,61 @@ fn is_worker_error_retriable(
         WorkerError::OutOfMemory => true,
         WorkerError::ExceededMemoryLimit => false,
         WorkerError::AgentError(_) => false,
+        WorkerError::ExceededAgentMemoryLimit => false,
+        WorkerError::ExceededInvocationFuelLimit => false,
+        WorkerError::ExceededInvocationTimeLimit => false,
+        WorkerError::ExceededOplogSizeLimit => false,
+        WorkerError::ExceededHttpRequestRateLimit => retry_count < retry_config.max_attempts,
     }
 }
 
+/// Derives the lifecycle events caused by a status change, together with the error message
+/// belonging to the event if any. `created` indicates that the new entries contained the initial
+/// `Create` entry of the agent.
+pub fn agent_lifecycle_events(
+    old_status: &WorkerStatusRecord,
+    new_status: &WorkerStatusRecord,
+    created: bool,
+) -> Vec<(AgentLifecycleEventType, Option<String>)> {
+    let mut events = Vec::new();
+
+    if created {
+        events.push((AgentLifecycleEventType::Created, None));
+    }
+
+    for _ in new_status
+        .successful_updates
+        .iter()
+        .skip(old_status.successful_updates.len())
+    {
+        events.push((AgentLifecycleEventType::Updated, None));
+    }
+    for update in new_status
+        .failed_updates
+        .iter()
+        .skip(old_status.failed_updates.len())
+    {
+        events.push((
+            AgentLifecycleEventType::UpdateFailed,
+            update.details.clone(),
+        ));
+    }
+
+    if new_status.status != old_status.status {
+        match new_status.status {
+            WorkerStatus::Failed => events.push((
+                AgentLifecycleEventType::Failed,
+                new_status.last_error.clone(),
+            )),
+            WorkerStatus::Exited => events.push((AgentLifecycleEventType::Exited, None)),
+            WorkerStatus::Interrupted => events.push((AgentLifecycleEventType::Interrupted, None)),
+            _ => {}
+        }
+    }
+
+    events
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::ExecutionStatus;
@@ -878,8 +1097,10 @@ mod test {
     use crate::services::oplog::{Oplog, OplogService};
     use crate::services::{HasConfig, HasOplogService};
     use crate::worker::status::{
-        calculate_last_known_status, calculate_last_known_status_for_existing_worker,
+        agent_lifecycle_events, calculate_last_known_status,
+        calculate_last_known_status_for_existing_worker,
     };
+    use assert2::check;
     use async_trait::async_trait;
     use golem_common::base_model::OplogIndex;
     use golem_common::model::account::AccountId;
@@ -887,10 +1108,15 @@ mod test {
     use golem_common::model::component::{ComponentId, ComponentRevision, PluginPriority};
     use golem_common::model::environment::EnvironmentId;
     use golem_common::model::invocation_context::{InvocationContextStack, TraceId};
+    use golem_common::model::invocation_queue::{
+        insert_by_priority, reorder_pending_invocation, InvocationPriority,
+    };
+    use golem_common::model::lifecycle_webhook::AgentLifecycleEventType;
     use golem_common::model::oplog::host_functions::HostFunctionName;
     use golem_common::model::oplog::{
         DurableFunctionType, HostRequest, HostRequestNoInput, HostResponse, OplogEntry,
-        OplogPayload, PayloadId, RawOplogPayload, TimestampedUpdateDescription, UpdateDescription,
+        OplogPayload, PayloadId, RawOplogPayload, SagaCompensation, TimestampedUpdateDescription,
+        UpdateDescription,
     };
     use golem_common::model::regions::{DeletedRegions, OplogRegion};
     use golem_common::model::{
@@ -1410,6 +1636,76 @@ mod test {
         run_test_case(test_case).await;
     }
 
+    #[test]
+    async fn invocation_queue_management() {
+        let k1 = IdempotencyKey::fresh();
+        let k2 = IdempotencyKey::fresh();
+        let k3 = IdempotencyKey::fresh();
+
+        let method_invocation = |idempotency_key: &IdempotencyKey| AgentInvocation::AgentMethod {
+            idempotency_key: idempotency_key.clone(),
+            method_name: "a".to_string(),
+            input: UntypedDataValue::Tuple(vec![]),
+            invocation_context: InvocationContextStack::fresh(),
+            principal: Principal::anonymous(),
+        };
+
+        let test_case = TestCase::builder(0)
+            .set_invocation_queue_limit(Some(2))
+            .pending_invocation(method_invocation(&k1))
+            .pending_invocation(method_invocation(&k2))
+            .reorder_pending_invocation(k2.clone(), InvocationPriority::High, None)
+            .reorder_pending_invocation(k1.clone(), InvocationPriority::Low, None)
+            .pending_invocation(method_invocation(&k3))
+            .reorder_pending_invocation(k3, InvocationPriority::Low, Some(0))
+            .set_invocation_queue_limit(None)
+            .cancel_pending_invocation(k2)
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
+    #[test]
+    async fn saga_tracking() {
+        let k1 = IdempotencyKey::fresh();
+        let compensation = |method_name: &str| SagaCompensation {
+            target_worker_id: WorkerId {
+                component_id: ComponentId::new(),
+                worker_name: "remote-agent".to_string(),
+            },
+            method_name: method_name.to_string(),
+            input: OplogPayload::Inline(Box::new(UntypedDataValue::Tuple(vec![]))),
+        };
+
+        let builder = TestCase::builder(0).agent_invocation_started("a", vec![], k1.clone());
+        let completed_saga = builder.next_index();
+        let builder = builder
+            .begin_saga()
+            .saga_step(completed_saga, compensation("undo-a"))
+            .end_saga(completed_saga, false);
+        let aborted_saga = builder.next_index();
+        let builder = builder
+            .begin_saga()
+            .saga_step(aborted_saga, compensation("undo-b"));
+        let compensated_step = builder.next_index();
+        let builder = builder
+            .saga_step(aborted_saga, compensation("undo-c"))
+            .saga_step_compensated(aborted_saga, compensated_step);
+        let open_saga = builder.next_index();
+        let test_case = builder
+            .begin_saga()
+            .saga_step(open_saga, compensation("undo-d"))
+            .end_saga(aborted_saga, true)
+            .agent_invocation_finished(
+                AgentInvocationResult::AgentInitialization,
+                k1,
+                ComponentRevision::INITIAL,
+            )
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
     #[test]
     async fn snapshot_tracking() {
         let k1 = IdempotencyKey::fresh();
@@ -1451,6 +1747,117 @@ mod test {
         run_test_case(test_case).await;
     }
 
+    #[test]
+    async fn status_after_discarding_history() {
+        let k1 = IdempotencyKey::fresh();
+        let k2 = IdempotencyKey::fresh();
+
+        let test_case = TestCase::builder(0)
+            .agent_invocation_started("a", vec![], k1.clone())
+            .grow_memory(10)
+            .agent_invocation_finished(
+                AgentInvocationResult::AgentInitialization,
+                k1,
+                ComponentRevision::INITIAL,
+            )
+            .snapshot()
+            .agent_invocation_started("b", vec![], k2.clone())
+            .grow_memory(100)
+            .agent_invocation_finished(
+                AgentInvocationResult::AgentInitialization,
+                k2,
+                ComponentRevision::INITIAL,
+            )
+            .build()
+            .with_discarded_region(OplogRegion {
+                start: OplogIndex::from_u64(2),
+                end: OplogIndex::from_u64(4),
+            });
+
+        let last = test_case.entries.len() - 1;
+        let expected = test_case.expected_status_with_discarded_region(last);
+
+        // Continuing from the status recorded when the retention policy was applied
+        let at_retention = test_case.expected_status_with_discarded_region(4);
+        let final_status = calculate_last_known_status_for_existing_worker(
+            &test_case,
+            &test_case.owned_worker_id,
+            Some(at_retention),
+        )
+        .await;
+        assert_eq!(final_status, expected);
+
+        // Continuing from a status recorded in the middle of the discarded region only reads the
+        // entries after it
+        let in_discarded_region = test_case.expected_status_with_discarded_region(2);
+        let final_status = calculate_last_known_status_for_existing_worker(
+            &test_case,
+            &test_case.owned_worker_id,
+            Some(in_discarded_region),
+        )
+        .await;
+        assert_eq!(final_status, expected);
+    }
+
+    #[test]
+    fn agent_lifecycle_events_of_status_changes() {
+        let old_status = WorkerStatusRecord {
+            status: WorkerStatus::Running,
+            ..Default::default()
+        };
+
+        check!(agent_lifecycle_events(&old_status, &old_status, false).is_empty());
+
+        let created = agent_lifecycle_events(&WorkerStatusRecord::default(), &old_status, true);
+        check!(created == vec![(AgentLifecycleEventType::Created, None)]);
+
+        let failed = WorkerStatusRecord {
+            status: WorkerStatus::Failed,
+            last_error: Some("error".to_string()),
+            failed_updates: vec![FailedUpdateRecord {
+                timestamp: Timestamp::now_utc(),
+                target_revision: ComponentRevision::INITIAL,
+                details: Some("update error".to_string()),
+            }],
+            ..Default::default()
+        };
+        let events = agent_lifecycle_events(&old_status, &failed, false);
+        check!(
+            events
+                == vec![
+                    (
+                        AgentLifecycleEventType::UpdateFailed,
+                        Some("update error".to_string())
+                    ),
+                    (AgentLifecycleEventType::Failed, Some("error".to_string())),
+                ]
+        );
+
+        // the status is not changing, only the already reported update failure is kept
+        let still_failed = WorkerStatusRecord {
+            oplog_idx: OplogIndex::from_u64(10),
+            ..failed.clone()
+        };
+        check!(agent_lifecycle_events(&failed, &still_failed, false).is_empty());
+
+        let updated_and_exited = WorkerStatusRecord {
+            status: WorkerStatus::Exited,
+            successful_updates: vec![SuccessfulUpdateRecord {
+                timestamp: Timestamp::now_utc(),
+                target_revision: ComponentRevision::INITIAL,
+            }],
+            ..Default::default()
+        };
+        let events = agent_lifecycle_events(&old_status, &updated_and_exited, false);
+        check!(
+            events
+                == vec![
+                    (AgentLifecycleEventType::Updated, None),
+                    (AgentLifecycleEventType::Exited, None),
+                ]
+        );
+    }
+
     #[test]
     async fn non_existing_oplog() {
         let environment_id = EnvironmentId::new();
@@ -1464,6 +1871,7 @@ mod test {
         let test_case = TestCase {
             owned_worker_id: owned_worker_id.clone(),
             entries: vec![],
+            discarded_region: None,
         };
 
         let result = calculate_last_known_status(&test_case, &owned_worker_id, None).await;
@@ -1543,9 +1951,10 @@ mod test {
                 ),
                 principal: Principal::anonymous(),
             };
+            let timestamp = Timestamp::now_utc().rounded();
             self.add(
                 OplogEntry::AgentInvocationStarted {
-                    timestamp: Timestamp::now_utc(),
+                    timestamp,
                     idempotency_key: idempotency_key.clone(),
                     payload: OplogPayload::Inline(Box::new(payload)),
                     trace_id: TraceId::generate(),
@@ -1554,6 +1963,7 @@ mod test {
                 },
                 move |mut status| {
                     status.current_idempotency_key = Some(idempotency_key);
+                    status.last_invocation_at = Some(timestamp);
                     status.status = WorkerStatus::Running;
                     if !status.pending_invocations.is_empty() {
                         status.pending_invocations.pop();
@@ -1683,6 +2093,8 @@ mod test {
                 status.invocation_results = old_status.invocation_results;
                 status.component_revision_for_replay = old_status.component_revision_for_replay;
                 status.last_snapshot_index = old_status.last_snapshot_index;
+                status.last_invocation_at = old_status.last_invocation_at;
+                status.last_error = old_status.last_error;
 
                 status
             })
@@ -1700,10 +2112,14 @@ mod test {
             )
             .rounded();
             self.add(entry.clone(), move |mut status| {
-                status.pending_invocations.push(TimestampedAgentInvocation {
-                    timestamp: entry.timestamp(),
-                    invocation,
-                });
+                insert_by_priority(
+                    &mut status.pending_invocations,
+                    TimestampedAgentInvocation {
+                        timestamp: entry.timestamp(),
+                        invocation,
+                        priority: InvocationPriority::Normal,
+                    },
+                );
                 status
             })
         }
@@ -1718,6 +2134,91 @@ mod test {
             })
         }
 
+        pub fn reorder_pending_invocation(
+            self,
+            idempotency_key: IdempotencyKey,
+            priority: InvocationPriority,
+            position: Option<u64>,
+        ) -> Self {
+            let entry =
+                OplogEntry::reorder_pending_invocation(idempotency_key.clone(), priority, position)
+                    .rounded();
+            self.add(entry.clone(), move |mut status| {
+                reorder_pending_invocation(
+                    &mut status.pending_invocations,
+                    &idempotency_key,
+                    priority,
+                    position,
+                );
+                status
+            })
+        }
+
+        pub fn set_invocation_queue_limit(self, limit: Option<u64>) -> Self {
+            let entry = OplogEntry::set_invocation_queue_limit(limit).rounded();
+            self.add(entry.clone(), move |mut status| {
+                status.pending_invocation_limit = limit;
+                status
+            })
+        }
+
+        pub fn next_index(&self) -> OplogIndex {
+            self.previous_status_record.oplog_idx.next()
+        }
+
+        pub fn begin_saga(self) -> Self {
+            let begin_index = self.next_index();
+            self.add(OplogEntry::begin_saga(), move |mut status| {
+                status.open_sagas.insert(begin_index, BTreeMap::new());
+                status.status = WorkerStatus::Running;
+                status
+            })
+        }
+
+        pub fn saga_step(self, begin_index: OplogIndex, compensation: SagaCompensation) -> Self {
+            let step_index = self.next_index();
+            self.add(
+                OplogEntry::saga_step(begin_index, compensation.clone()),
+                move |mut status| {
+                    status
+                        .open_sagas
+                        .get_mut(&begin_index)
+                        .unwrap()
+                        .insert(step_index, compensation);
+                    status.status = WorkerStatus::Running;
+                    status
+                },
+            )
+        }
+
+        pub fn saga_step_compensated(
+            self,
+            begin_index: OplogIndex,
+            step_index: OplogIndex,
+        ) -> Self {
+            self.add(
+                OplogEntry::saga_step_compensated(begin_index, step_index),
+                move |mut status| {
+                    status
+                        .open_sagas
+                        .get_mut(&begin_index)
+                        .unwrap()
+                        .remove(&step_index);
+                    status
+                },
+            )
+        }
+
+        pub fn end_saga(self, begin_index: OplogIndex, compensated: bool) -> Self {
+            self.add(
+                OplogEntry::end_saga(begin_index, compensated),
+                move |mut status| {
+                    status.open_sagas.remove(&begin_index);
+                    status
+                },
+            )
+        }
+
         pub fn pending_update(
             self,
             update_description: &UpdateDescription,
@@ -1841,6 +2342,7 @@ mod test {
                     .into_iter()
                     .map(|entry| entry.rounded())
                     .collect(),
+                discarded_region: None,
             }
         }
     }
@@ -1864,6 +2366,8 @@ mod test {
     struct TestCase {
         owned_worker_id: OwnedWorkerId,
         entries: Vec<TestEntry>,
+        // Entries in this region are no longer readable, as if they were dropped by the oplog retention policy
+        discarded_region: Option<OplogRegion>,
     }
 
     impl TestCase {
@@ -1883,6 +2387,25 @@ mod test {
                 initial_component_version.try_into().unwrap(),
             )
         }
+
+        pub fn with_discarded_region(self, region: OplogRegion) -> Self {
+            TestCase {
+                discarded_region: Some(region),
+                ..self
+            }
+        }
+
+        /// The expected status of the given entry after the discarded region has been dropped
+        pub fn expected_status_with_discarded_region(&self, idx: usize) -> WorkerStatusRecord {
+            let mut status = self.entries[idx].expected_status.clone();
+            if let Some(region) = &self.discarded_region {
+                status.discarded_region = Some(region.clone());
+                status
+                    .invocation_results
+                    .retain(|_, oplog_idx| *oplog_idx > region.end);
+            }
+            status
+        }
     }
 
     impl HasOplogService for TestCase {
@@ -1932,6 +2455,13 @@ mod test {
             let mut result = BTreeMap::new();
             let idx_u64: u64 = idx.into();
             for i in idx_u64..(idx_u64 + n) {
+                if self
+                    .discarded_region
+                    .as_ref()
+                    .is_some_and(|region| region.contains(OplogIndex::from_u64(i)))
+                {
+                    continue;
+                }
                 if let Some(entry) = self.entries.get((i - 1) as usize) {
                     result.insert(OplogIndex::from_u64(i), entry.oplog_entry.clone());
                 }
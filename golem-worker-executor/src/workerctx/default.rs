@@ -18,7 +18,8 @@ use crate::metrics::wasm::record_allocated_memory;
 use crate::model::{ExecutionStatus, LastError, ReadFileResult, TrapType, WorkerConfig};
 use crate::preview2::golem::agent::host::{
     CancellationToken, FutureInvokeResult, Host as AgentHost, HostCancellationToken,
-    HostFutureInvokeResult, HostWasmRpc, RpcError, WasmRpc,
+    HostFutureInvokeResult, HostSaga, HostWasmRpc, MissedFirePolicy, RecurringInvocation,
+    RecurringSchedule, RpcError, Saga, WasmRpc,
 };
 use crate::services::active_workers::ActiveWorkers;
 use crate::services::agent_types::AgentTypesService;
@@ -28,9 +29,12 @@ use crate::services::component::ComponentService;
 use crate::services::file_loader::FileLoader;
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::{Oplog, OplogService};
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
+use crate::services::recorded_host_calls::RecordedHostCalls;
 use crate::services::resource_limits::{AtomicResourceEntry, ResourceLimits};
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
@@ -39,7 +43,7 @@ use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
 use crate::services::worker_fork::WorkerForkService;
 use crate::services::worker_proxy::WorkerProxy;
-use crate::services::{worker_enumeration, HasAll, NoAdditionalDeps};
+use crate::services::{worker_enumeration, HasAll};
 use crate::worker::{RetryDecision, Worker};
 use crate::workerctx::{
     ExternalOperations, FileSystemReading, FuelManagement, InvocationContextManagement,
@@ -54,7 +58,8 @@ use golem_common::model::component::{ComponentFilePath, ComponentRevision, Plugi
 use golem_common::model::invocation_context::{
     self, AttributeValue, InvocationContextStack, SpanId,
 };
-use golem_common::model::oplog::TimestampedUpdateDescription;
+use golem_common::model::oplog::host_functions::HostFunctionName;
+use golem_common::model::oplog::{HostResponse, TimestampedUpdateDescription};
 use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, IdempotencyKey, OwnedWorkerId, WorkerId,
     WorkerStatusRecord,
@@ -143,6 +148,11 @@ impl FuelManagement for Context {
 
         consumed
     }
+
+    fn check_invocation_limits(&self, current_level: u64) -> Option<GolemSpecificWasmTrap> {
+        let consumed = self.last_fuel_level.saturating_sub(current_level);
+        self.durable_ctx.check_invocation_limits(consumed)
+    }
 }
 
 #[async_trait]
@@ -225,6 +235,14 @@ impl InvocationHooks for Context {
     async fn get_current_retry_point(&self) -> OplogIndex {
         self.durable_ctx.get_current_retry_point().await
     }
+
+    fn begin_read_only_query(&mut self) {
+        self.durable_ctx.begin_read_only_query()
+    }
+
+    fn end_read_only_query(&mut self) {
+        self.durable_ctx.end_read_only_query()
+    }
 }
 
 #[async_trait]
@@ -245,6 +263,14 @@ impl ResourceLimiterAsync for Context {
             Err(GolemSpecificWasmTrap::WorkerExceededMemoryLimit)?;
         };
 
+        if self
+            .durable_ctx
+            .agent_max_memory()
+            .is_some_and(|agent_limit| desired > agent_limit)
+        {
+            Err(self.durable_ctx.agent_memory_limit_exceeded())?;
+        }
+
         let current_known = self.durable_ctx.total_linear_memory_size();
         let delta = (desired as u64).saturating_sub(current_known);
 
@@ -276,7 +302,7 @@ impl ResourceLimiterAsync for Context {
 
 #[async_trait]
 impl ExternalOperations<Context> for Context {
-    type ExtraDeps = NoAdditionalDeps;
+    type ExtraDeps = RecordedHostCalls;
 
     async fn get_last_error_and_retry_count<T: HasAll<Context> + Send + Sync>(
         this: &T,
@@ -312,6 +338,14 @@ impl ExternalOperations<Context> for Context {
     ) -> Result<(), Error> {
         DurableWorkerCtx::<Context>::on_shard_assignment_changed(this).await
     }
+
+    async fn recorded_host_call_response(
+        extra_deps: &RecordedHostCalls,
+        worker_id: &WorkerId,
+        function_name: &HostFunctionName,
+    ) -> Option<HostResponse> {
+        extra_deps.next_response(worker_id, function_name).await
+    }
 }
 
 #[async_trait]
@@ -451,6 +485,36 @@ impl HostWasmRpc for Context {
             .await
     }
 
+    async fn schedule_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<Result<RecurringInvocation, String>> {
+        self.durable_ctx
+            .schedule_recurring_invocation(self_, schedule, missed_fire_policy, method_name, input)
+            .await
+    }
+
+    async fn list_recurring_invocations(
+        &mut self,
+        self_: Resource<WasmRpc>,
+    ) -> anyhow::Result<Vec<RecurringInvocation>> {
+        self.durable_ctx.list_recurring_invocations(self_).await
+    }
+
+    async fn cancel_recurring_invocation(
+        &mut self,
+        self_: Resource<WasmRpc>,
+        id: golem_wasm::Uuid,
+    ) -> anyhow::Result<bool> {
+        self.durable_ctx
+            .cancel_recurring_invocation(self_, id)
+            .await
+    }
+
     async fn drop(&mut self, rep: Resource<WasmRpc>) -> anyhow::Result<()> {
         HostWasmRpc::drop(&mut self.durable_ctx, rep).await
     }
@@ -490,6 +554,34 @@ impl HostCancellationToken for Context {
     }
 }
 
+impl HostSaga for Context {
+    async fn new(&mut self) -> anyhow::Result<Resource<Saga>> {
+        HostSaga::new(&mut self.durable_ctx).await
+    }
+
+    async fn record_step(
+        &mut self,
+        self_: Resource<Saga>,
+        target: Resource<WasmRpc>,
+        method_name: String,
+        input: golem_common::model::agent::bindings::golem::agent::common::DataValue,
+    ) -> anyhow::Result<()> {
+        HostSaga::record_step(&mut self.durable_ctx, self_, target, method_name, input).await
+    }
+
+    async fn complete(&mut self, self_: Resource<Saga>) -> anyhow::Result<()> {
+        HostSaga::complete(&mut self.durable_ctx, self_).await
+    }
+
+    async fn abort(&mut self, self_: Resource<Saga>) -> anyhow::Result<Result<(), RpcError>> {
+        HostSaga::abort(&mut self.durable_ctx, self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<Saga>) -> anyhow::Result<()> {
+        HostSaga::drop(&mut self.durable_ctx, rep).await
+    }
+}
+
 impl AgentHost for Context {
     async fn get_all_agent_types(
         &mut self,
@@ -641,6 +733,7 @@ impl WorkerCtx for Context {
         oplog: Arc<dyn Oplog>,
         invocation_queue: Weak<Worker<Self>>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
@@ -653,6 +746,7 @@ impl WorkerCtx for Context {
         resource_limits: Arc<dyn ResourceLimits>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shard_service: Arc<dyn ShardService>,
         pending_update: Option<TimestampedUpdateDescription>,
         original_phantom_id: Option<Uuid>,
@@ -671,6 +765,7 @@ impl WorkerCtx for Context {
             oplog,
             invocation_queue,
             scheduler_service,
+            pubsub_service,
             rpc,
             worker_proxy,
             component_service,
@@ -681,6 +776,7 @@ impl WorkerCtx for Context {
             worker_fork,
             agent_types_service,
             agent_webhooks_service,
+            network_egress_policy_service,
             shard_service,
             pending_update,
             original_phantom_id,
@@ -23,8 +23,10 @@ use crate::services::component::ComponentService;
 use crate::services::file_loader::FileLoader;
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::network_egress_policy::NetworkEgressPolicyService;
 use crate::services::oplog::{Oplog, OplogService};
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
 use crate::services::resource_limits::ResourceLimits;
 use crate::services::rpc::Rpc;
@@ -43,12 +45,15 @@ use golem_common::model::component::{ComponentFilePath, ComponentRevision, Plugi
 use golem_common::model::invocation_context::{
     AttributeValue, InvocationContextSpan, InvocationContextStack, SpanId,
 };
-use golem_common::model::oplog::TimestampedUpdateDescription;
+use golem_common::model::oplog::host_functions::HostFunctionName;
+use golem_common::model::oplog::{HostResponse, TimestampedUpdateDescription};
 use golem_common::model::{
     AgentInvocation, AgentInvocationOutput, IdempotencyKey, OplogIndex, OwnedWorkerId, WorkerId,
     WorkerStatusRecord,
 };
-use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
+use golem_service_base::error::worker_executor::{
+    GolemSpecificWasmTrap, InterruptKind, WorkerExecutorError,
+};
 use golem_service_base::model::component::Component;
 use golem_service_base::model::GetFileSystemNodeResult;
 use golem_wasm::wasmtime::ResourceStore;
@@ -101,6 +106,7 @@ pub trait WorkerCtx:
     /// - `active_workers`: The service for managing active workers
     /// - `oplog_service`: The service for reading and writing the oplog
     /// - `scheduler_service`: The scheduler implementation responsible for waking up suspended workers
+    /// - `pubsub_service`: The service for publishing to and subscribing to topics
     /// - `recovery_management`: The service for deciding if a worker should be recovered
     /// - `rpc`: The RPC implementation used for worker to worker communication
     /// - `worker_proxy`: Access to the worker proxy above the worker executor cluster
@@ -126,6 +132,7 @@ pub trait WorkerCtx:
         oplog: Arc<dyn Oplog>,
         invocation_queue: Weak<Worker<Self>>,
         scheduler_service: Arc<dyn SchedulerService>,
+        pubsub_service: Arc<dyn PubSubService>,
         rpc: Arc<dyn Rpc>,
         worker_proxy: Arc<dyn WorkerProxy>,
         component_service: Arc<dyn ComponentService>,
@@ -138,6 +145,7 @@ pub trait WorkerCtx:
         resource_limits: Arc<dyn ResourceLimits>,
         agent_types_service: Arc<dyn AgentTypesService>,
         agent_webhooks_service: Arc<AgentWebhooksService>,
+        network_egress_policy_service: Arc<dyn NetworkEgressPolicyService>,
         shard_service: Arc<dyn ShardService>,
         pending_update: Option<TimestampedUpdateDescription>,
         original_phantom_id: Option<Uuid>,
@@ -206,6 +214,10 @@ pub trait FuelManagement {
 
     /// Returns the amount of fuel consumed since the last call to return_fuel.
     fn return_fuel(&mut self, current_level: u64) -> u64;
+
+    /// Checks the fuel and time limits of the running invocation, as declared for the agent type
+    /// of the worker. Returns the trap to fail the invocation with if any of them got exceeded.
+    fn check_invocation_limits(&self, current_level: u64) -> Option<GolemSpecificWasmTrap>;
 }
 
 /// The invocation management interface of a worker context is responsible for connecting
@@ -287,6 +299,13 @@ pub trait InvocationHooks {
     /// Gets the retry point that should be associated with a current error. Errors are grouped
     /// by this information. The current oplog index is a good default.
     async fn get_current_retry_point(&self) -> OplogIndex;
+
+    /// Marks the beginning of a read-only query. Nothing is persisted while the query runs, and
+    /// durable functions with side effects are rejected.
+    fn begin_read_only_query(&mut self);
+
+    /// Marks the end of a read-only query, restoring normal persistence
+    fn end_read_only_query(&mut self);
 }
 
 #[async_trait]
@@ -358,6 +377,17 @@ pub trait ExternalOperations<Ctx: WorkerCtx> {
     async fn on_shard_assignment_changed<T: HasAll<Ctx> + Send + Sync + 'static>(
         this: &T,
     ) -> Result<(), anyhow::Error>;
+
+    /// Gets a recorded response which is returned to the worker, and persisted, in place of the
+    /// live response of its next host function call. Used for replaying a recorded oplog in a
+    /// sandbox; by default the live responses are always used.
+    async fn recorded_host_call_response(
+        _extra_deps: &Self::ExtraDeps,
+        _worker_id: &WorkerId,
+        _function_name: &HostFunctionName,
+    ) -> Option<HostResponse> {
+        None
+    }
 }
 
 /// A required interface to be implemented by the worker context's public state.
@@ -40,7 +40,7 @@ use golem_worker_executor_test_utils::{
 };
 use pretty_assertions::assert_eq;
 use redis::Commands;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use std::io::Write;
 
@@ -603,6 +603,65 @@ async fn promise(
     Ok(())
 }
 
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn failed_promise_traps_legacy_get(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+    #[tagged_as("host_api_tests")] host_api_tests: &PrecompiledComponent,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, host_api_tests)
+        .store()
+        .await?;
+
+    let agent_id = agent_id!("golem-host-api", "failed-promise-1");
+    let worker_id = executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    let promise_id_value = executor
+        .invoke_and_await_agent(&component, &agent_id, "create_promise", data_value!())
+        .await?
+        .into_return_value()
+        .ok_or_else(|| anyhow!("expected return value"))?;
+
+    let promise_data = DataValue::Tuple(ElementValues {
+        elements: vec![ElementValue::ComponentModel(ComponentModelElementValue {
+            value: ValueAndType::new(promise_id_value.clone(), PromiseId::get_type()),
+        })],
+    });
+
+    let promise_id = PromiseId {
+        worker_id: worker_id.clone(),
+        oplog_idx: extract_oplog_idx_from_promise_id(&promise_id_value),
+    };
+    executor.fail_promise(&promise_id, vec![13]).await?;
+
+    // Completing an already failed promise does not change its outcome
+    executor.complete_promise(&promise_id, vec![42]).await?;
+
+    let poll = executor
+        .invoke_and_await_agent(&component, &agent_id, "poll_promise", promise_data)
+        .await;
+
+    executor.check_oplog_is_queryable(&worker_id).await?;
+
+    let Err(error) = poll else {
+        panic!("expected poll_promise to fail for a failed promise, got {poll:?}");
+    };
+    assert!(
+        error.to_string().contains("was failed"),
+        "unexpected error: {error}"
+    );
+    Ok(())
+}
+
 fn extract_oplog_idx_from_promise_id(promise_id_value: &Value) -> OplogIndex {
     let Value::Record(fields) = promise_id_value else {
         panic!("Expected a record for PromiseId");
@@ -1357,6 +1416,67 @@ async fn get_workers(
     Ok(())
 }
 
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn get_workers_from_index_while_deleting_between_pages(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+    #[tagged_as("agent_counters")] agent_counters: &PrecompiledComponent,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, agent_counters)
+        .store()
+        .await?;
+
+    let workers_count = 10;
+    let page_size = 3;
+    let mut worker_names = HashSet::new();
+
+    for i in 0..workers_count {
+        let agent_id = agent_id!("counter", format!("index-worker-{i}"));
+        let worker_id = executor.start_agent(&component.id, agent_id).await?;
+        worker_names.insert(worker_id.worker_name);
+    }
+
+    // The first imprecise enumeration starts building the worker index in the background
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let (mut cursor, mut page) = loop {
+        let (cursor, page) = executor
+            .get_workers_metadata(&component.id, None, ScanCursor::default(), page_size, false)
+            .await?;
+        if cursor.as_ref().is_some_and(|c| c.layer == usize::MAX) {
+            break (cursor, page);
+        }
+        assert!(Instant::now() < deadline, "worker index was not built");
+        sleep(Duration::from_millis(200)).await;
+    };
+
+    let mut enumerated = HashSet::new();
+    loop {
+        for worker in page {
+            enumerated.insert(worker.worker_id.worker_name.clone());
+            executor.delete_worker(&worker.worker_id).await?;
+        }
+
+        match cursor {
+            Some(next_cursor) => {
+                (cursor, page) = executor
+                    .get_workers_metadata(&component.id, None, next_cursor, page_size, false)
+                    .await?;
+            }
+            None => break,
+        }
+    }
+
+    assert_eq!(enumerated, worker_names);
+    Ok(())
+}
+
 #[test]
 #[tracing::instrument]
 #[timeout("4m")]
@@ -23,13 +23,16 @@ use golem_common::model::oplog::{OplogIndex, PublicOplogEntry, PublicSnapshotDat
 use golem_common::{agent_id, data_value};
 use golem_test_framework::dsl::TestDsl;
 use golem_wasm::Value;
-use golem_worker_executor::services::golem_config::SnapshotPolicy;
+use golem_worker_executor::services::golem_config::{
+    OplogRetentionConfig, OplogRetentionPolicy, SnapshotPolicy,
+};
 use golem_worker_executor_test_utils::{
-    start, start_with_snapshot_policy, LastUniqueId, PrecompiledComponent, TestContext,
-    WorkerExecutorTestDependencies,
+    start, start_with_oplog_retention, start_with_snapshot_policy, LastUniqueId,
+    PrecompiledComponent, TestContext, WorkerExecutorTestDependencies,
 };
 use http::StatusCode;
 use pretty_assertions::assert_eq;
+use redis::Commands;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -529,6 +532,229 @@ async fn snapshot_based_recovery(
     Ok(())
 }
 
+fn discard_before_snapshot_retention() -> OplogRetentionConfig {
+    OplogRetentionConfig {
+        check_interval: Duration::from_secs(1),
+        default_policy: OplogRetentionPolicy::DiscardBeforeSnapshot,
+        ..Default::default()
+    }
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_retention_discards_history_before_snapshot(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("agent_counters")] agent_counters: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_oplog_retention(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+        discard_before_snapshot_retention(),
+    )
+    .await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, agent_counters)
+        .store()
+        .await?;
+    let agent_id = agent_id!("snapshot-counter", "retention");
+    let worker_id = executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    for _ in 0..5 {
+        executor
+            .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+            .await?;
+    }
+    let oplog_before = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let oplog_after = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?;
+    let metadata = executor.get_worker_metadata(&worker_id).await?;
+
+    drop(executor);
+    let executor = start_with_oplog_retention(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+        discard_before_snapshot_retention(),
+    )
+    .await?;
+
+    let result_after_restart = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?;
+    let was_recovered = executor
+        .invoke_and_await_agent(
+            &component,
+            &agent_id,
+            "was_recovered_from_snapshot",
+            data_value!(),
+        )
+        .await?;
+
+    drop(executor);
+
+    assert!(
+        oplog_after.len() < oplog_before.len(),
+        "Expected the oplog to shrink from {} entries, got {}",
+        oplog_before.len(),
+        oplog_after.len()
+    );
+    assert!(matches!(&oplog_after[0].entry, PublicOplogEntry::Create(_)));
+    assert!(matches!(
+        &oplog_after[1].entry,
+        PublicOplogEntry::Snapshot(_)
+    ));
+    assert!(metadata.discarded_region.is_some());
+    assert_eq!(
+        result_after_restart.into_return_value(),
+        Some(Value::U32(6))
+    );
+    assert_eq!(was_recovered.into_return_value(), Some(Value::Bool(true)));
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_retention_survives_losing_the_cached_status(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("agent_counters")] agent_counters: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_oplog_retention(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+        discard_before_snapshot_retention(),
+    )
+    .await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, agent_counters)
+        .store()
+        .await?;
+    let agent_id = agent_id!("snapshot-counter", "lost-status");
+    let worker_id = executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    for _ in 0..5 {
+        executor
+            .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+            .await?;
+    }
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    let discarded_region = executor
+        .get_worker_metadata(&worker_id)
+        .await?
+        .discarded_region;
+
+    drop(executor);
+
+    // Deleting the cached status, so it has to be recalculated from the oplog
+    let mut redis = deps.redis.get_connection(0);
+    let _: () = redis
+        .del(format!(
+            "{}worker:status:{}",
+            context.redis_prefix(),
+            worker_id.to_redis_key()
+        ))
+        .unwrap();
+
+    let executor = start_with_oplog_retention(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+        discard_before_snapshot_retention(),
+    )
+    .await?;
+
+    let recalculated_region = executor
+        .get_worker_metadata(&worker_id)
+        .await?
+        .discarded_region;
+    let result = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?;
+
+    drop(executor);
+
+    assert!(discarded_region.is_some());
+    assert_eq!(recalculated_region, discarded_region);
+    assert_eq!(result.into_return_value(), Some(Value::U32(6)));
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+async fn oplog_retention_applies_to_agents_not_loaded_in_memory(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("agent_counters")] agent_counters: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start_with_snapshot_policy(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+    )
+    .await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, agent_counters)
+        .store()
+        .await?;
+    let agent_id = agent_id!("snapshot-counter", "idle-retention");
+    let worker_id = executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    for _ in 0..5 {
+        executor
+            .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+            .await?;
+    }
+    let oplog_before = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?;
+
+    // After the restart the idle agent is not loaded until it gets invoked again
+    drop(executor);
+    let executor = start_with_oplog_retention(
+        deps,
+        &context,
+        SnapshotPolicy::EveryNInvocation { count: 1 },
+        discard_before_snapshot_retention(),
+    )
+    .await?;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    let oplog_after = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?;
+    let result = executor
+        .invoke_and_await_agent(&component, &agent_id, "get", data_value!())
+        .await?;
+
+    drop(executor);
+
+    assert!(
+        oplog_after.len() < oplog_before.len(),
+        "Expected the oplog to shrink from {} entries, got {}",
+        oplog_before.len(),
+        oplog_after.len()
+    );
+    assert_eq!(result.into_return_value(), Some(Value::U32(5)));
+    Ok(())
+}
+
 #[test]
 #[tracing::instrument]
 async fn snapshot_based_recovery_preserves_state_across_multiple_restarts(
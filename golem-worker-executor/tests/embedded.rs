@@ -0,0 +1,253 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Tracing;
+use anyhow::anyhow;
+use golem_common::model::agent::{
+    ComponentModelElementValue, DataValue, ElementValue, ElementValues, UntypedDataValue,
+};
+use golem_common::model::oplog::{
+    OplogEntry, OplogPayload, PublicOplogEntry, SagaCompensation, WorkerError,
+};
+use golem_common::model::{OplogIndex, PromiseId, WorkerId};
+use golem_common::{agent_id, data_value};
+use golem_wasm::{IntoValue, Value, ValueAndType};
+use golem_worker_executor_embedded::EmbeddedExecutor;
+use pretty_assertions::assert_eq;
+use std::path::Path;
+use std::time::Duration;
+use test_r::{inherit_test_dep, test, timeout};
+
+inherit_test_dep!(Tracing);
+
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn embedded_executor_survives_simulated_crash(_tracing: &Tracing) -> anyhow::Result<()> {
+    let executor = EmbeddedExecutor::start().await?;
+
+    let component = executor
+        .add_component(Path::new(
+            "../test-components/it_agent_counters_release.wasm",
+        ))
+        .await?;
+    let agent_id = agent_id!("counter", "embedded-1");
+    let worker_id = WorkerId::from_agent_id(component.id, &agent_id).map_err(anyhow::Error::msg)?;
+
+    executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?;
+    executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?;
+
+    executor.simulated_crash(&worker_id).await?;
+
+    let result = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?
+        .into_return_value();
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await?;
+
+    assert_eq!(result, Some(Value::U32(3)));
+    assert!(!oplog.is_empty());
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn advancing_the_clock_performs_due_scheduled_invocations(
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let executor = EmbeddedExecutor::start().await?;
+
+    let component = executor
+        .add_component(Path::new(
+            "../test-components/it_agent_counters_release.wasm",
+        ))
+        .await?;
+    let agent_id = agent_id!("counter", "embedded-clock-1");
+
+    let result1 = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?
+        .into_return_value();
+
+    let scheduled_at = executor.now().await + chrono::Duration::hours(1);
+    executor
+        .schedule_agent_invocation(
+            &component,
+            &agent_id,
+            "increment",
+            data_value!(),
+            scheduled_at,
+        )
+        .await?;
+
+    let result2 = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?
+        .into_return_value();
+
+    executor
+        .advance_clock(Duration::from_secs(2 * 60 * 60))
+        .await?;
+
+    let result3 = executor
+        .invoke_and_await_agent(&component, &agent_id, "increment", data_value!())
+        .await?
+        .into_return_value();
+
+    assert_eq!(result1, Some(Value::U32(1)));
+    assert_eq!(result2, Some(Value::U32(2)));
+    assert_eq!(result3, Some(Value::U32(4)));
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn completed_promise_is_visible_to_the_agent(_tracing: &Tracing) -> anyhow::Result<()> {
+    let executor = EmbeddedExecutor::start().await?;
+
+    let component = executor
+        .add_component(Path::new(
+            "../test-components/golem_it_host_api_tests_release.wasm",
+        ))
+        .await?;
+    let agent_id = agent_id!("golem-host-api", "embedded-promise-1");
+    let worker_id = WorkerId::from_agent_id(component.id, &agent_id).map_err(anyhow::Error::msg)?;
+
+    let promise_id_value = executor
+        .invoke_and_await_agent(&component, &agent_id, "create_promise", data_value!())
+        .await?
+        .into_return_value()
+        .ok_or_else(|| anyhow!("expected return value"))?;
+    let Value::Record(fields) = &promise_id_value else {
+        return Err(anyhow!("Expected a record for PromiseId"));
+    };
+    let Value::U64(oplog_idx) = fields[1] else {
+        return Err(anyhow!("Expected u64 oplog-idx field"));
+    };
+    let promise_id = PromiseId {
+        worker_id,
+        oplog_idx: OplogIndex::from_u64(oplog_idx),
+    };
+
+    executor.complete_promise(&promise_id, vec![42]).await?;
+
+    let promise_data = DataValue::Tuple(ElementValues {
+        elements: vec![ElementValue::ComponentModel(ComponentModelElementValue {
+            value: ValueAndType::new(promise_id_value, PromiseId::get_type()),
+        })],
+    });
+    let poll = executor
+        .invoke_and_await_agent(&component, &agent_id, "poll_promise", promise_data)
+        .await?
+        .into_return_value();
+
+    assert_eq!(
+        poll,
+        Some(Value::Option(Some(Box::new(Value::List(vec![Value::U8(
+            42
+        )])))))
+    );
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+#[timeout("4m")]
+async fn open_sagas_of_failed_agent_are_compensated_once(_tracing: &Tracing) -> anyhow::Result<()> {
+    let executor = EmbeddedExecutor::start().await?;
+
+    let component = executor
+        .add_component(Path::new(
+            "../test-components/it_agent_counters_release.wasm",
+        ))
+        .await?;
+
+    let target_id = agent_id!("counter", "saga-target");
+    let target_worker_id =
+        WorkerId::from_agent_id(component.id, &target_id).map_err(anyhow::Error::msg)?;
+    executor
+        .invoke_and_await_agent(&component, &target_id, "increment", data_value!())
+        .await?;
+
+    let coordinator_id = agent_id!("counter", "saga-coordinator");
+    let coordinator_worker_id =
+        WorkerId::from_agent_id(component.id, &coordinator_id).map_err(anyhow::Error::msg)?;
+    executor
+        .invoke_and_await_agent(&component, &coordinator_id, "increment", data_value!())
+        .await?;
+
+    // The coordinator performs a saga step compensated by incrementing the target, then fails
+    let begin_index = executor
+        .append_to_oplog(&coordinator_worker_id, OplogEntry::begin_saga())
+        .await?;
+    let step_index = executor
+        .append_to_oplog(
+            &coordinator_worker_id,
+            OplogEntry::saga_step(
+                begin_index,
+                SagaCompensation {
+                    target_worker_id: target_worker_id.clone(),
+                    method_name: "increment".to_string(),
+                    input: OplogPayload::Inline(Box::new(UntypedDataValue::Tuple(vec![]))),
+                },
+            ),
+        )
+        .await?;
+    executor
+        .append_to_oplog(
+            &coordinator_worker_id,
+            OplogEntry::error(
+                WorkerError::AgentError("saga failed".to_string()),
+                step_index,
+            ),
+        )
+        .await?;
+
+    // Processing the scheduled compensation more than once must not repeat it
+    executor.advance_clock(Duration::from_secs(1)).await?;
+    executor.advance_clock(Duration::from_secs(60)).await?;
+
+    let result = executor
+        .invoke_and_await_agent(&component, &target_id, "increment", data_value!())
+        .await?
+        .into_return_value();
+    let coordinator_oplog = executor
+        .get_oplog(&coordinator_worker_id, OplogIndex::INITIAL)
+        .await?;
+    let compensated_steps = coordinator_oplog
+        .iter()
+        .filter_map(|entry| match &entry.entry {
+            PublicOplogEntry::SagaStepCompensated(params) => Some(params.step_index),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let ended_sagas = coordinator_oplog
+        .iter()
+        .filter_map(|entry| match &entry.entry {
+            PublicOplogEntry::EndSaga(params) => Some((params.begin_index, params.compensated)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, Some(Value::U32(3)));
+    assert_eq!(compensated_steps, vec![step_index]);
+    assert_eq!(ended_sagas, vec![(begin_index, true)]);
+    Ok(())
+}
@@ -356,6 +356,74 @@ async fn set_if_not_exists(
     assert_eq!(result3, Some(value1.into()));
 }
 
+#[test]
+#[tracing::instrument]
+async fn compare_and_set(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let key = "key";
+    let value1 = "value".as_bytes();
+    let value2 = "value2".as_bytes();
+    let value3 = "value3".as_bytes();
+
+    let result1 = kvs
+        .compare_and_set(
+            "test",
+            "api",
+            "entity",
+            ns.clone(),
+            key,
+            Some(value1),
+            value2,
+        )
+        .await
+        .unwrap();
+    let result2 = kvs
+        .compare_and_set("test", "api", "entity", ns.clone(), key, None, value1)
+        .await
+        .unwrap();
+    let result3 = kvs
+        .compare_and_set("test", "api", "entity", ns.clone(), key, None, value2)
+        .await
+        .unwrap();
+    let result4 = kvs
+        .compare_and_set(
+            "test",
+            "api",
+            "entity",
+            ns.clone(),
+            key,
+            Some(value2),
+            value3,
+        )
+        .await
+        .unwrap();
+    let result5 = kvs
+        .compare_and_set(
+            "test",
+            "api",
+            "entity",
+            ns.clone(),
+            key,
+            Some(value1),
+            value2,
+        )
+        .await
+        .unwrap();
+    let result6 = kvs.get("test", "api", "entity", ns, key).await.unwrap();
+    assert!(!result1);
+    assert!(result2);
+    assert!(!result3);
+    assert!(!result4);
+    assert!(result5);
+    assert_eq!(result6, Some(value2.into()));
+}
+
 #[test]
 #[tracing::instrument]
 async fn del(
@@ -870,3 +938,62 @@ async fn query_sorted_set(
     assert_eq!(result4, vec![(2.0, value2.into()), (3.0, value3.into())]);
     assert_eq!(result5, vec![(4.0, value4.into())]);
 }
+
+#[test]
+#[tracing::instrument]
+async fn get_sorted_set_page(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let set1 = "set1";
+
+    let value1 = "value1".as_bytes();
+    let value2 = "value2".as_bytes();
+    let value3 = "value3".as_bytes();
+    let value4 = "value4".as_bytes();
+
+    let result1 = kvs
+        .get_sorted_set_page("test", "api", "entity", ns.clone(), set1, 0.0, 2)
+        .await
+        .unwrap();
+
+    kvs.add_to_sorted_set("test", "api", "entity", ns.clone(), set1, 3.0, value4)
+        .await
+        .unwrap();
+    kvs.add_to_sorted_set("test", "api", "entity", ns.clone(), set1, 1.0, value2)
+        .await
+        .unwrap();
+    kvs.add_to_sorted_set("test", "api", "entity", ns.clone(), set1, 1.0, value1)
+        .await
+        .unwrap();
+    kvs.add_to_sorted_set("test", "api", "entity", ns.clone(), set1, 2.0, value3)
+        .await
+        .unwrap();
+
+    let result2 = kvs
+        .get_sorted_set_page("test", "api", "entity", ns.clone(), set1, 0.0, 2)
+        .await
+        .unwrap();
+    let result3 = kvs
+        .get_sorted_set_page("test", "api", "entity", ns.clone(), set1, 1.5, 2)
+        .await
+        .unwrap();
+    let result4 = kvs
+        .get_sorted_set_page("test", "api", "entity", ns.clone(), set1, 3.0, 10)
+        .await
+        .unwrap();
+    let result5 = kvs
+        .get_sorted_set_page("test", "api", "entity", ns.clone(), set1, 4.0, 10)
+        .await
+        .unwrap();
+
+    assert_eq!(result1, Vec::<(f64, bytes::Bytes)>::new());
+    assert_eq!(result2, vec![(1.0, value1.into()), (1.0, value2.into())]);
+    assert_eq!(result3, vec![(2.0, value3.into()), (3.0, value4.into())]);
+    assert_eq!(result4, vec![(3.0, value4.into())]);
+    assert_eq!(result5, Vec::<(f64, bytes::Bytes)>::new());
+}
@@ -27,6 +27,7 @@ pub mod api;
 pub mod blobstore;
 pub mod compatibility;
 pub mod durability;
+pub mod embedded;
 pub mod hot_update;
 pub mod http;
 pub mod indexed_storage;
@@ -35,6 +36,7 @@ pub mod keyvalue;
 pub mod observability;
 pub mod rdbms;
 pub mod rdbms_service;
+pub mod resource_limits;
 pub mod revert;
 pub mod rpc;
 pub mod scalability;
@@ -49,10 +51,12 @@ tag_suite!(keyvalue, group1);
 tag_suite!(http, group1);
 tag_suite!(rdbms, group1);
 tag_suite!(agent, group1);
+tag_suite!(embedded, group1);
 
 tag_suite!(hot_update, group2);
 tag_suite!(transactions, group2);
 tag_suite!(observability, group2);
+tag_suite!(resource_limits, group2);
 
 tag_suite!(durability, group3);
 tag_suite!(rpc, group3);
@@ -169,6 +173,12 @@ test_component!(
     "scalability_large_dynamic_memory_release",
     "scalability:large-dynamic-memory"
 );
+test_component!(
+    benchmark_agent_rust,
+    "benchmark_agent_rust",
+    "benchmark_agent_rust_release",
+    "benchmark:agent-rust"
+);
 test_component!(
     large_initial_memory,
     "large_initial_memory",
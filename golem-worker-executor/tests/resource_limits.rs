@@ -0,0 +1,186 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Tracing;
+use golem_common::model::agent::{AgentId, AgentTypeName, DataValue};
+use golem_common::model::component::AgentResourceLimits;
+use golem_common::{agent_id, data_value};
+use golem_test_framework::dsl::TestDsl;
+use golem_worker_executor_test_utils::{
+    start, LastUniqueId, PrecompiledComponent, TestContext, WorkerExecutorTestDependencies,
+};
+use test_r::{inherit_test_dep, test, timeout};
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(
+    #[tagged_as("benchmark_agent_rust")]
+    PrecompiledComponent
+);
+inherit_test_dep!(
+    #[tagged_as("large_dynamic_memory")]
+    PrecompiledComponent
+);
+inherit_test_dep!(Tracing);
+
+fn no_limits(agent_type: &str) -> AgentResourceLimits {
+    AgentResourceLimits {
+        agent: AgentTypeName(agent_type.to_string()),
+        max_memory_bytes: None,
+        fuel_per_invocation: None,
+        max_invocation_time_millis: None,
+        max_oplog_entries_per_invocation: None,
+        max_outgoing_http_requests_per_minute: None,
+    }
+}
+
+/// Invokes `method_name` on a fresh agent of a component stored with `limits`, and returns
+/// the error the invocation failed with
+async fn invoke_with_limits(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    precompiled: &PrecompiledComponent,
+    limits: AgentResourceLimits,
+    agent_id: AgentId,
+    method_name: &str,
+    params: DataValue,
+) -> anyhow::Result<String> {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await?;
+
+    let component = executor
+        .component_dep(&context.default_environment_id, precompiled)
+        .with_agent_resource_limits(vec![limits])
+        .store()
+        .await?;
+    executor
+        .start_agent(&component.id, agent_id.clone())
+        .await?;
+
+    let result = executor
+        .invoke_and_await_agent(&component, &agent_id, method_name, params)
+        .await;
+
+    Ok(result
+        .expect_err("Expected the invocation to fail")
+        .to_string())
+}
+
+#[test]
+#[timeout("4m")]
+#[tracing::instrument]
+async fn exceeding_the_agent_memory_limit_fails_the_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("large_dynamic_memory")] large_dynamic_memory: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let error = invoke_with_limits(
+        last_unique_id,
+        deps,
+        large_dynamic_memory,
+        AgentResourceLimits {
+            max_memory_bytes: Some(64 * 1024 * 1024),
+            ..no_limits("large-dynamic-memory-agent")
+        },
+        agent_id!("large-dynamic-memory-agent", "limited"),
+        "run",
+        data_value!(),
+    )
+    .await?;
+
+    assert!(error.contains("ExceededAgentMemoryLimit"), "{error}");
+    Ok(())
+}
+
+#[test]
+#[timeout("4m")]
+#[tracing::instrument]
+async fn exceeding_the_fuel_limit_per_invocation_fails_the_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("benchmark_agent_rust")] benchmark_agent_rust: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let error = invoke_with_limits(
+        last_unique_id,
+        deps,
+        benchmark_agent_rust,
+        AgentResourceLimits {
+            fuel_per_invocation: Some(1_000_000),
+            ..no_limits("rust-benchmark-agent")
+        },
+        agent_id!("rust-benchmark-agent", "fuel-limited"),
+        "cpu_intensive",
+        data_value!(1000f64),
+    )
+    .await?;
+
+    assert!(error.contains("ExceededInvocationFuelLimit"), "{error}");
+    Ok(())
+}
+
+#[test]
+#[timeout("4m")]
+#[tracing::instrument]
+async fn exceeding_the_invocation_time_limit_fails_the_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("benchmark_agent_rust")] benchmark_agent_rust: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let error = invoke_with_limits(
+        last_unique_id,
+        deps,
+        benchmark_agent_rust,
+        AgentResourceLimits {
+            max_invocation_time_millis: Some(100),
+            ..no_limits("rust-benchmark-agent")
+        },
+        agent_id!("rust-benchmark-agent", "time-limited"),
+        "cpu_intensive",
+        data_value!(1000f64),
+    )
+    .await?;
+
+    assert!(error.contains("ExceededInvocationTimeLimit"), "{error}");
+    Ok(())
+}
+
+#[test]
+#[timeout("4m")]
+#[tracing::instrument]
+async fn exceeding_the_oplog_entries_per_invocation_limit_fails_the_invocation(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    #[tagged_as("benchmark_agent_rust")] benchmark_agent_rust: &PrecompiledComponent,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let error = invoke_with_limits(
+        last_unique_id,
+        deps,
+        benchmark_agent_rust,
+        AgentResourceLimits {
+            max_oplog_entries_per_invocation: Some(100),
+            ..no_limits("rust-benchmark-agent")
+        },
+        agent_id!("rust-benchmark-agent", "oplog-limited"),
+        "oplog_heavy",
+        data_value!(100_000u32, true, false),
+    )
+    .await?;
+
+    assert!(error.contains("ExceededOplogSizeLimit"), "{error}");
+    Ok(())
+}
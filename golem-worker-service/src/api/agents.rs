@@ -6,6 +6,7 @@ use golem_common::model::IdempotencyKey;
 use golem_common::model::agent::{AgentTypeName, UntypedJsonDataValue};
 use golem_common::model::application::ApplicationName;
 use golem_common::model::environment::EnvironmentName;
+use golem_common::model::invocation_queue::InvocationPriority;
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::GolemSecurityScheme;
@@ -100,6 +101,7 @@ pub struct AgentInvocationRequest {
     pub idempotency_key: Option<IdempotencyKey>,
     pub deployment_revision: Option<i64>,
     pub owner_account_email: Option<String>,
+    pub priority: Option<InvocationPriority>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -15,8 +15,10 @@
 use crate::custom_api::error::RequestHandlerError;
 use crate::custom_api::route_resolver::RouteResolverError;
 use crate::service::auth::AuthServiceError;
+use crate::service::bulk_operation::BulkOperationError;
 use crate::service::component::ComponentServiceError;
 use crate::service::limit::LimitServiceError;
+use crate::service::update_check::UpdateCheckError;
 use crate::service::worker::{CallWorkerExecutorError, WorkerServiceError};
 use golem_common::SafeDisplay;
 use golem_common::metrics::api::ApiErrorDetails;
@@ -185,6 +187,30 @@ impl From<WorkerServiceError> for ApiEndpointError {
     }
 }
 
+impl From<BulkOperationError> for ApiEndpointError {
+    fn from(error: BulkOperationError) -> Self {
+        match error {
+            BulkOperationError::NotFound(_) => Self::not_found(error),
+            BulkOperationError::AlreadyFinished(_) => Self::conflict(error),
+            BulkOperationError::Component(inner) => inner.into(),
+            BulkOperationError::AuthError(inner) => inner.into(),
+            BulkOperationError::InternalError(_) => Self::internal(error),
+        }
+    }
+}
+
+impl From<UpdateCheckError> for ApiEndpointError {
+    fn from(error: UpdateCheckError) -> Self {
+        match error {
+            UpdateCheckError::TargetRevisionNotFound(_) => Self::bad_request(error),
+            UpdateCheckError::ReplayFailed(_) => Self::internal(error),
+            UpdateCheckError::Worker(inner) => inner.into(),
+            UpdateCheckError::Component(inner) => inner.into(),
+            UpdateCheckError::AuthError(inner) => inner.into(),
+        }
+    }
+}
+
 impl From<ComponentServiceError> for ApiEndpointError {
     fn from(error: ComponentServiceError) -> Self {
         match error {
@@ -216,6 +242,7 @@ impl From<WorkerExecutorError> for ApiEndpointError {
     fn from(error: WorkerExecutorError) -> Self {
         match error {
             WorkerExecutorError::WorkerNotFound { .. } => Self::not_found(error),
+            WorkerExecutorError::InvocationQueueFull { .. } => Self::limit_exceeded(error),
             WorkerExecutorError::InvocationFailed { error, stderr } => {
                 Self::InternalError(Json(ErrorBodyWithOptionalWorkerError {
                     error: "Invocation Failed".to_string(),
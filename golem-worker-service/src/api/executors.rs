@@ -0,0 +1,66 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::common::ApiEndpointError;
+use crate::service::auth::AuthService;
+use crate::service::worker::WorkerService;
+use golem_common::model::executor::ExecutorsSummary;
+use golem_common::recorded_http_api_request;
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::model::auth::{AuthCtx, GolemSecurityScheme};
+use poem_openapi::OpenApi;
+use poem_openapi::payload::Json;
+use std::sync::Arc;
+use tracing::Instrument;
+
+type Result<T> = std::result::Result<T, ApiEndpointError>;
+
+pub struct ExecutorsApi {
+    worker_service: Arc<WorkerService>,
+    auth_service: Arc<dyn AuthService>,
+}
+
+#[OpenApi(prefix_path = "/v1/executors", tag = ApiTags::Worker)]
+impl ExecutorsApi {
+    pub fn new(worker_service: Arc<WorkerService>, auth_service: Arc<dyn AuthService>) -> Self {
+        Self {
+            worker_service,
+            auth_service,
+        }
+    }
+
+    /// Get the worker executors of the cluster
+    ///
+    /// Lists the worker executors with assigned shards, as seen by the shard manager's routing table.
+    /// Only available for administrators.
+    #[oai(path = "/", method = "get", operation_id = "get_executors")]
+    async fn get_executors(&self, token: GolemSecurityScheme) -> Result<Json<ExecutorsSummary>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let record = recorded_http_api_request!("get_executors",);
+
+        let response = self
+            .get_executors_internal(auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_executors_internal(&self, auth: AuthCtx) -> Result<Json<ExecutorsSummary>> {
+        let executors = self.worker_service.get_executors(auth).await?;
+
+        Ok(Json(executors))
+    }
+}
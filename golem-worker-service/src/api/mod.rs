@@ -14,15 +14,25 @@
 
 pub mod agents;
 pub mod common;
+mod executors;
+mod topics;
 mod worker;
 
 use crate::api::agents::AgentsApi;
+use crate::api::executors::ExecutorsApi;
+use crate::api::topics::TopicsApi;
 use crate::api::worker::WorkerApi;
 use crate::bootstrap::Services;
 use golem_service_base::api::HealthcheckApi;
 use poem_openapi::OpenApiService;
 
-pub type Apis = (HealthcheckApi, WorkerApi, AgentsApi);
+pub type Apis = (
+    HealthcheckApi,
+    WorkerApi,
+    AgentsApi,
+    TopicsApi,
+    ExecutorsApi,
+);
 
 pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
     OpenApiService::new(
@@ -31,12 +41,22 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
             WorkerApi::new(
                 services.component_service.clone(),
                 services.worker_service.clone(),
+                services.bulk_operation_service.clone(),
+                services.update_check_service.clone(),
                 services.auth_service.clone(),
             ),
             AgentsApi::new(
                 services.worker_service.clone(),
                 services.auth_service.clone(),
             ),
+            TopicsApi::new(
+                services.worker_service.clone(),
+                services.auth_service.clone(),
+            ),
+            ExecutorsApi::new(
+                services.worker_service.clone(),
+                services.auth_service.clone(),
+            ),
         ),
         "Golem API",
         "1.0",
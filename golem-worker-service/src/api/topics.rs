@@ -0,0 +1,91 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::api::common::ApiEndpointError;
+use crate::service::auth::AuthService;
+use crate::service::worker::WorkerService;
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::pubsub::{TopicMessagePublication, TopicMessagePublished};
+use golem_common::recorded_http_api_request;
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::model::auth::{AuthCtx, GolemSecurityScheme};
+use poem_openapi::OpenApi;
+use poem_openapi::param::Path;
+use poem_openapi::payload::Json;
+use std::sync::Arc;
+use tracing::Instrument;
+
+type Result<T> = std::result::Result<T, ApiEndpointError>;
+
+pub struct TopicsApi {
+    worker_service: Arc<WorkerService>,
+    auth_service: Arc<dyn AuthService>,
+}
+
+#[OpenApi(prefix_path = "/v1/envs", tag = ApiTags::Agent)]
+impl TopicsApi {
+    pub fn new(worker_service: Arc<WorkerService>, auth_service: Arc<dyn AuthService>) -> Self {
+        Self {
+            worker_service,
+            auth_service,
+        }
+    }
+
+    /// Publish a message to a topic
+    ///
+    /// The message is delivered to every agent of the environment subscribed to the topic, as an
+    /// invocation of the agent's handler method. Returns the offset of the message within the topic.
+    #[oai(
+        path = "/:environment_id/topics/:topic/messages",
+        method = "post",
+        operation_id = "publish_topic_message"
+    )]
+    async fn publish_topic_message(
+        &self,
+        environment_id: Path<EnvironmentId>,
+        topic: Path<String>,
+        request: Json<TopicMessagePublication>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<TopicMessagePublished>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let record = recorded_http_api_request!(
+            "publish_topic_message",
+            environment_id = environment_id.0.to_string(),
+            topic = topic.0.clone(),
+        );
+
+        let response = self
+            .publish_topic_message_internal(environment_id.0, topic.0, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn publish_topic_message_internal(
+        &self,
+        environment_id: EnvironmentId,
+        topic: String,
+        request: TopicMessagePublication,
+        auth: AuthCtx,
+    ) -> Result<Json<TopicMessagePublished>> {
+        let published = self
+            .worker_service
+            .publish_topic_message(environment_id, topic, request, auth)
+            .await?;
+
+        Ok(Json(published))
+    }
+}
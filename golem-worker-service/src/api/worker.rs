@@ -15,17 +15,33 @@
 use super::common::ApiEndpointError;
 use crate::model;
 use crate::service::auth::AuthService;
+use crate::service::bulk_operation::BulkOperationService;
 use crate::service::component::ComponentService;
+use crate::service::update_check::UpdateCheckService;
 use crate::service::worker::ConnectWorkerStream;
 use crate::service::worker::{WorkerService, proxy_worker_connection};
 use futures::StreamExt;
 use futures::TryStreamExt;
 use golem_common::model::auth::TokenSecret;
+use golem_common::model::bulk_operation::{
+    BulkOperation, BulkOperationId, BulkOperationList, BulkOperationRequest,
+};
 use golem_common::model::component::{ComponentFilePath, ComponentId, PluginPriority};
 use golem_common::model::error::{ErrorBody, ErrorsBody};
+use golem_common::model::invocation_queue::{
+    InvocationQueue, InvocationQueueLimit, InvocationQueuePurge, InvocationQueuePurgeResult,
+    InvocationQueueReorder,
+};
 use golem_common::model::oplog::OplogCursor;
 use golem_common::model::oplog::OplogIndex;
-use golem_common::model::worker::{RevertWorkerTarget, WorkerCreationRequest, WorkerMetadataDto};
+use golem_common::model::recurring_invocation::{
+    RecurringInvocation, RecurringInvocationCreation, RecurringInvocationId,
+    RecurringInvocationList,
+};
+use golem_common::model::worker::{
+    RevertWorkerTarget, UpdateCheckRequest, UpdateCheckResponse, WorkerCreationRequest,
+    WorkerMetadataDto,
+};
 use golem_common::model::{IdempotencyKey, ScanCursor, WorkerFilter, WorkerId};
 use golem_common::{SafeDisplay, recorded_http_api_request};
 use golem_service_base::api_tags::ApiTags;
@@ -52,6 +68,8 @@ type Result<T> = std::result::Result<T, ApiEndpointError>;
 pub struct WorkerApi {
     component_service: Arc<dyn ComponentService>,
     worker_service: Arc<WorkerService>,
+    bulk_operation_service: Arc<BulkOperationService>,
+    update_check_service: Arc<UpdateCheckService>,
     auth_service: Arc<dyn AuthService>,
 }
 
@@ -60,11 +78,15 @@ impl WorkerApi {
     pub fn new(
         component_service: Arc<dyn ComponentService>,
         worker_service: Arc<WorkerService>,
+        bulk_operation_service: Arc<BulkOperationService>,
+        update_check_service: Arc<UpdateCheckService>,
         auth_service: Arc<dyn AuthService>,
     ) -> Self {
         Self {
             component_service,
             worker_service,
+            bulk_operation_service,
+            update_check_service,
             auth_service,
         }
     }
@@ -187,6 +209,7 @@ impl WorkerApi {
     /// Completes a promise with a given custom array of bytes.
     /// The promise must be previously created from within the worker, and it's identifier (a combination of a worker identifier and an oplogIdx ) must be sent out to an external caller so it can use this endpoint to mark the promise completed.
     /// The data field is sent back to the worker, and it has no predefined meaning.
+    /// If the failed field is true, the promise is failed (rejected) instead, and the data field is sent back to the worker as the error payload.
     #[oai(
         path = "/:component_id/workers/:worker_name/complete",
         method = "post",
@@ -222,11 +245,15 @@ impl WorkerApi {
         params: CompleteParameters,
         auth: AuthCtx,
     ) -> Result<Json<bool>> {
-        let CompleteParameters { oplog_idx, data } = params;
+        let CompleteParameters {
+            oplog_idx,
+            data,
+            failed,
+        } = params;
 
         let response = self
             .worker_service
-            .complete_promise(&worker_id, oplog_idx, data, auth)
+            .complete_promise(&worker_id, oplog_idx, data, failed.unwrap_or(false), auth)
             .await?;
 
         Ok(Json(response))
@@ -348,6 +375,17 @@ impl WorkerApi {
     /// | status      | FilterComparator       | Status of worker               | `status = Running`               |
     /// | env.\[key\] | StringFilterComparator | Environment variable of worker | `env.var1 = value`               |
     /// | createdAt   | FilterComparator       | Creation time of worker        | `createdAt > 2024-04-01T12:10:00Z` |
+    /// | agent_type  | StringFilterComparator | Agent type name                | `agent_type = shopping-cart`     |
+    /// | agent_parameters | StringFilterComparator | Constructor parameters, as they appear in the agent ID | `agent_parameters like user-1` |
+    /// | last_invocation_at | FilterComparator | Start time of the last invocation, or the creation time if never invoked | `last_invocation_at < now-30days` |
+    /// | pending_invocation_count | FilterComparator | Number of pending invocations | `pending_invocation_count > 100` |
+    /// | retry_count | FilterComparator       | Retry count of the current invocation | `retry_count >= 3`        |
+    /// | last_error  | StringFilterComparator | Message of the last error of the current invocation | `last_error like timeout` |
+    /// | memory_size | FilterComparator       | Total linear memory size in bytes | `memory_size > 104857600`     |
+    /// | active_plugin | `==`, `!=`           | Priority of an activated plugin | `active_plugin == 1`            |
+    ///
+    /// Timestamps can be given in ISO 8601 format, or relative to the current time as `now-<duration>`,
+    /// for example `now-30days`.
     ///
     ///
     /// ### Comparators
@@ -454,6 +492,15 @@ impl WorkerApi {
     /// | Status    | FilterComparator       | Status of worker               | `{ "type": "Status", "comparator": "Equal", "value": "Running" }`                             |
     /// | Env       | StringFilterComparator | Environment variable of worker | `{ "type": "Env", "name": "var1", "comparator": "Equal", "value": "value" }`                  |
     /// | CreatedAt | FilterComparator       | Creation time of worker        | `{ "type": "CreatedAt", "comparator": "Greater", "value": "2024-04-01T12:10:00Z" }`           |
+    /// | ConfigVars | StringFilterComparator | Configuration variable of worker | `{ "type": "ConfigVars", "name": "var1", "comparator": "Equal", "value": "value" }` |
+    /// | AgentType | StringFilterComparator | Agent type name                | `{ "type": "AgentType", "comparator": "Equal", "value": "shopping-cart" }`                    |
+    /// | AgentParameters | StringFilterComparator | Constructor parameters, as they appear in the agent ID | `{ "type": "AgentParameters", "comparator": "Like", "value": "user-1" }` |
+    /// | LastInvocationAt | FilterComparator | Start time of the last invocation, or the creation time if never invoked | `{ "type": "LastInvocationAt", "comparator": "Less", "value": "2024-04-01T12:10:00Z" }` |
+    /// | PendingInvocationCount | FilterComparator | Number of pending invocations | `{ "type": "PendingInvocationCount", "comparator": "Greater", "value": 100 }` |
+    /// | RetryCount | FilterComparator      | Retry count of the current invocation | `{ "type": "RetryCount", "comparator": "GreaterEqual", "value": 3 }`                  |
+    /// | LastError | StringFilterComparator | Message of the last error of the current invocation | `{ "type": "LastError", "comparator": "Like", "value": "timeout" }` |
+    /// | MemorySize | FilterComparator      | Total linear memory size in bytes | `{ "type": "MemorySize", "comparator": "Greater", "value": 104857600 }`                   |
+    /// | ActivePlugin |                     | Priority of an activated plugin | `{ "type": "ActivePlugin", "pluginPriority": 1 }`                                           |
     /// | And       |                        | And filter combinator          | `{ "type": "And", "filters": [ ... ] }`                                                       |
     /// | Or        |                        | Or filter combinator           | `{ "type": "Or", "filters": [ ... ] }`                                                        |
     /// | Not       |                        | Negates the specified filter   | `{ "type": "Not", "filter": { "type": "Version", "comparator": "GreaterEqual", "value": 0 } }`|
@@ -668,6 +715,68 @@ impl WorkerApi {
         Ok(Json(response))
     }
 
+    /// Follow the oplog of a worker
+    ///
+    /// Streams the oplog entries starting from the given index (or from the beginning), and keeps
+    /// streaming the new entries as they get committed. The response body is in JSON Lines format,
+    /// each line containing one oplog entry together with its index.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/oplog/follow",
+        method = "get",
+        operation_id = "follow_oplog"
+    )]
+    async fn follow_oplog(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        from: Query<Option<u64>>,
+        token: GolemSecurityScheme,
+    ) -> Result<Binary<Body>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!("follow_oplog", worker_id = worker_id.to_string());
+
+        let response = self
+            .follow_oplog_internal(worker_id, from.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn follow_oplog_internal(
+        &self,
+        worker_id: WorkerId,
+        from: Option<u64>,
+        auth: AuthCtx,
+    ) -> Result<Binary<Body>> {
+        let batches = self
+            .worker_service
+            .follow_oplog(
+                &worker_id,
+                from.map(OplogIndex::from_u64)
+                    .unwrap_or(OplogIndex::INITIAL),
+                auth,
+            )
+            .await?;
+
+        let lines = batches.map(|batch| {
+            let entries = batch.map_err(|e| std::io::Error::other(e.to_string()))?;
+            let mut lines = Vec::new();
+            for entry in entries {
+                serde_json::to_writer(&mut lines, &entry)?;
+                lines.push(b'\n');
+            }
+            Ok::<_, std::io::Error>(lines)
+        });
+
+        Ok(Binary(Body::from_bytes_stream(lines)))
+    }
+
     /// List files in a worker
     #[oai(
         path = "/:component_id/workers/:worker_name/files/:file_name",
@@ -1028,6 +1137,562 @@ impl WorkerApi {
         Ok(Json(CancelInvocationResponse { canceled }))
     }
 
+    /// Schedule a recurring invocation
+    ///
+    /// Creates an invocation of the given agent method repeating on a cron or fixed interval schedule,
+    /// until it gets cancelled. The missed fire policy determines what happens with occurrences which
+    /// passed while the schedule could not be processed.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/recurring-invocations",
+        method = "post",
+        operation_id = "schedule_recurring_invocation"
+    )]
+    async fn schedule_recurring_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<RecurringInvocationCreation>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<RecurringInvocation>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "schedule_recurring_invocation",
+            worker_id = worker_id.to_string(),
+            method_name = request.method_name.clone(),
+        );
+
+        let response = self
+            .schedule_recurring_invocation_internal(worker_id, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn schedule_recurring_invocation_internal(
+        &self,
+        worker_id: WorkerId,
+        request: RecurringInvocationCreation,
+        auth: AuthCtx,
+    ) -> Result<Json<RecurringInvocation>> {
+        let invocation = self
+            .worker_service
+            .schedule_recurring_invocation(&worker_id, request, auth)
+            .await?;
+
+        Ok(Json(invocation))
+    }
+
+    /// List the recurring invocations of a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/recurring-invocations",
+        method = "get",
+        operation_id = "list_recurring_invocations"
+    )]
+    async fn list_recurring_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<RecurringInvocationList>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "list_recurring_invocations",
+            worker_id = worker_id.to_string(),
+        );
+
+        let response = self
+            .list_recurring_invocations_internal(worker_id, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn list_recurring_invocations_internal(
+        &self,
+        worker_id: WorkerId,
+        auth: AuthCtx,
+    ) -> Result<Json<RecurringInvocationList>> {
+        let invocations = self
+            .worker_service
+            .list_recurring_invocations(&worker_id, auth)
+            .await?;
+
+        Ok(Json(RecurringInvocationList { invocations }))
+    }
+
+    /// Cancel a recurring invocation
+    ///
+    /// Already enqueued occurrences of the recurring invocation are not affected.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/recurring-invocations/:id",
+        method = "delete",
+        operation_id = "cancel_recurring_invocation"
+    )]
+    async fn cancel_recurring_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        id: Path<RecurringInvocationId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<CancelRecurringInvocationResponse>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "cancel_recurring_invocation",
+            worker_id = worker_id.to_string(),
+            recurring_invocation_id = id.0.to_string(),
+        );
+
+        let response = self
+            .cancel_recurring_invocation_internal(worker_id, id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn cancel_recurring_invocation_internal(
+        &self,
+        worker_id: WorkerId,
+        id: RecurringInvocationId,
+        auth: AuthCtx,
+    ) -> Result<Json<CancelRecurringInvocationResponse>> {
+        let canceled = self
+            .worker_service
+            .cancel_recurring_invocation(&worker_id, id, auth)
+            .await?;
+
+        Ok(Json(CancelRecurringInvocationResponse { canceled }))
+    }
+
+    /// Get the invocation queue of a worker
+    ///
+    /// Lists the pending invocations of the worker in the order they are going to be processed,
+    /// with a short preview of their parameters.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocation-queue",
+        method = "get",
+        operation_id = "get_invocation_queue"
+    )]
+    async fn get_invocation_queue(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationQueue>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record =
+            recorded_http_api_request!("get_invocation_queue", worker_id = worker_id.to_string(),);
+
+        let response = self
+            .get_invocation_queue_internal(worker_id, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_invocation_queue_internal(
+        &self,
+        worker_id: WorkerId,
+        auth: AuthCtx,
+    ) -> Result<Json<InvocationQueue>> {
+        let queue = self
+            .worker_service
+            .get_invocation_queue(&worker_id, auth)
+            .await?;
+
+        Ok(Json(queue))
+    }
+
+    /// Reorder a pending invocation
+    ///
+    /// Changes the priority of a pending invocation, or moves it to a given position in the
+    /// invocation queue. Returns the updated queue.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocation-queue/reorder",
+        method = "post",
+        operation_id = "reorder_invocation"
+    )]
+    async fn reorder_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<InvocationQueueReorder>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationQueue>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "reorder_invocation",
+            worker_id = worker_id.to_string(),
+            idempotency_key = request.idempotency_key.to_string(),
+        );
+
+        let response = self
+            .reorder_invocation_internal(worker_id, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn reorder_invocation_internal(
+        &self,
+        worker_id: WorkerId,
+        request: InvocationQueueReorder,
+        auth: AuthCtx,
+    ) -> Result<Json<InvocationQueue>> {
+        let queue = self
+            .worker_service
+            .reorder_invocation(&worker_id, request, auth)
+            .await?;
+
+        Ok(Json(queue))
+    }
+
+    /// Purge pending invocations of a method
+    ///
+    /// Cancels every pending invocation of the given agent method.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocation-queue/purge",
+        method = "post",
+        operation_id = "purge_invocations"
+    )]
+    async fn purge_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<InvocationQueuePurge>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationQueuePurgeResult>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "purge_invocations",
+            worker_id = worker_id.to_string(),
+            method_name = request.method_name.clone(),
+        );
+
+        let response = self
+            .purge_invocations_internal(worker_id, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn purge_invocations_internal(
+        &self,
+        worker_id: WorkerId,
+        request: InvocationQueuePurge,
+        auth: AuthCtx,
+    ) -> Result<Json<InvocationQueuePurgeResult>> {
+        let purged = self
+            .worker_service
+            .purge_invocations(&worker_id, request.method_name, auth)
+            .await?;
+
+        Ok(Json(InvocationQueuePurgeResult { purged }))
+    }
+
+    /// Set the invocation queue limit of a worker
+    ///
+    /// New invocations are rejected while the number of pending invocations reaches the limit.
+    /// Returns the updated queue.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocation-queue/limit",
+        method = "put",
+        operation_id = "set_invocation_queue_limit"
+    )]
+    async fn set_invocation_queue_limit(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<InvocationQueueLimit>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationQueue>> {
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let worker_id = self
+            .normalize_worker_id(component_id.0, worker_name.as_str())
+            .await?;
+
+        let record = recorded_http_api_request!(
+            "set_invocation_queue_limit",
+            worker_id = worker_id.to_string(),
+        );
+
+        let response = self
+            .set_invocation_queue_limit_internal(worker_id, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn set_invocation_queue_limit_internal(
+        &self,
+        worker_id: WorkerId,
+        request: InvocationQueueLimit,
+        auth: AuthCtx,
+    ) -> Result<Json<InvocationQueue>> {
+        let queue = self
+            .worker_service
+            .set_invocation_queue_limit(&worker_id, request.limit, auth)
+            .await?;
+
+        Ok(Json(queue))
+    }
+
+    /// Start a bulk operation on workers
+    ///
+    /// Applies an operation (interrupt, resume, update, delete, activate or deactivate a plugin, revert)
+    /// to every worker of the component matching the given filter. The operation runs in the background
+    /// with bounded concurrency, its progress can be observed using the returned bulk operation id.
+    #[oai(
+        path = "/:component_id/bulk-operations",
+        method = "post",
+        operation_id = "start_bulk_operation"
+    )]
+    async fn start_bulk_operation(
+        &self,
+        component_id: Path<ComponentId>,
+        request: Json<BulkOperationRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperation>> {
+        let record = recorded_http_api_request!(
+            "start_bulk_operation",
+            component_id = component_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .start_bulk_operation_internal(component_id.0, request.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn start_bulk_operation_internal(
+        &self,
+        component_id: ComponentId,
+        request: BulkOperationRequest,
+        auth: AuthCtx,
+    ) -> Result<Json<BulkOperation>> {
+        let operation = self
+            .bulk_operation_service
+            .start(component_id, request, auth)
+            .await?;
+
+        Ok(Json(operation))
+    }
+
+    /// List the bulk operations of a component
+    ///
+    /// Returns the running and recently finished bulk operations, most recent first.
+    #[oai(
+        path = "/:component_id/bulk-operations",
+        method = "get",
+        operation_id = "list_bulk_operations"
+    )]
+    async fn list_bulk_operations(
+        &self,
+        component_id: Path<ComponentId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperationList>> {
+        let record = recorded_http_api_request!(
+            "list_bulk_operations",
+            component_id = component_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .list_bulk_operations_internal(component_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn list_bulk_operations_internal(
+        &self,
+        component_id: ComponentId,
+        auth: AuthCtx,
+    ) -> Result<Json<BulkOperationList>> {
+        let operations = self.bulk_operation_service.list(component_id, auth).await?;
+
+        Ok(Json(BulkOperationList { operations }))
+    }
+
+    /// Get the progress of a bulk operation
+    #[oai(
+        path = "/:component_id/bulk-operations/:operation_id",
+        method = "get",
+        operation_id = "get_bulk_operation"
+    )]
+    async fn get_bulk_operation(
+        &self,
+        component_id: Path<ComponentId>,
+        operation_id: Path<BulkOperationId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperation>> {
+        let record = recorded_http_api_request!(
+            "get_bulk_operation",
+            component_id = component_id.0.to_string(),
+            operation_id = operation_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .get_bulk_operation_internal(component_id.0, operation_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_bulk_operation_internal(
+        &self,
+        component_id: ComponentId,
+        operation_id: BulkOperationId,
+        auth: AuthCtx,
+    ) -> Result<Json<BulkOperation>> {
+        let operation = self
+            .bulk_operation_service
+            .get(component_id, operation_id, auth)
+            .await?;
+
+        Ok(Json(operation))
+    }
+
+    /// Cancel a bulk operation
+    ///
+    /// Requests the cancellation of a running bulk operation. Workers already being processed are not
+    /// affected, the operation stops before processing the next batch of workers.
+    #[oai(
+        path = "/:component_id/bulk-operations/:operation_id/cancel",
+        method = "post",
+        operation_id = "cancel_bulk_operation"
+    )]
+    async fn cancel_bulk_operation(
+        &self,
+        component_id: Path<ComponentId>,
+        operation_id: Path<BulkOperationId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperation>> {
+        let record = recorded_http_api_request!(
+            "cancel_bulk_operation",
+            component_id = component_id.0.to_string(),
+            operation_id = operation_id.0.to_string()
+        );
+
+        let auth = self.auth_service.authenticate_token(token.secret()).await?;
+
+        let response = self
+            .cancel_bulk_operation_internal(component_id.0, operation_id.0, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn cancel_bulk_operation_internal(
+        &self,
+        component_id: ComponentId,
+        operation_id: BulkOperationId,
+        auth: AuthCtx,
+    ) -> Result<Json<BulkOperation>> {
+        let operation = self
+            .bulk_operation_service
+            .cancel(component_id, operation_id, auth)
+            .await?;
+
+        Ok(Json(operation))
+    }
+
+    /// Check whether workers can be automatically updated to a component revision
+    ///
+    /// Replays the oplogs of the workers matching the given filter against the target revision
+    /// in isolated debugging sessions, without affecting the workers, and reports the ones whose
+    /// replay diverges together with the oplog index of the divergence. Workers already using the
+    /// target revision are not checked.
+    #[oai(
+        path = "/:component_id/update-check",
+        method = "post",
+        operation_id = "check_workers_update"
+    )]
+    async fn check_workers_update(
+        &self,
+        component_id: Path<ComponentId>,
+        request: Json<UpdateCheckRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<UpdateCheckResponse>> {
+        let record = recorded_http_api_request!(
+            "check_workers_update",
+            component_id = component_id.0.to_string(),
+            target_revision = request.0.target_revision.to_string()
+        );
+
+        let token = token.secret();
+        let auth = self.auth_service.authenticate_token(token.clone()).await?;
+
+        let response = self
+            .check_workers_update_internal(component_id.0, request.0, token, auth)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn check_workers_update_internal(
+        &self,
+        component_id: ComponentId,
+        request: UpdateCheckRequest,
+        token: TokenSecret,
+        auth: AuthCtx,
+    ) -> Result<Json<UpdateCheckResponse>> {
+        let response = self
+            .update_check_service
+            .check(component_id, request, token, auth)
+            .await?;
+
+        Ok(Json(response))
+    }
+
     /// Connect to a worker using a websocket and stream events
     #[oai(
         path = "/:component_id/workers/:worker_name/connect",
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::config::{SessionStoreConfig, WorkerServiceConfig};
+use crate::config::{BulkOperationStorageConfig, SessionStoreConfig, WorkerServiceConfig};
 use crate::custom_api::api_definition_lookup::{
     HttpApiDefinitionsLookup, RegistryServiceApiDefinitionsLookup,
 };
@@ -25,8 +25,12 @@ use crate::custom_api::route_resolver::RouteResolver;
 use crate::custom_api::webhoooks::WebhookCallbackHandler;
 use crate::mcp::{McpCapabilityLookup, RegistryServiceMcpCapabilityLookup};
 use crate::service::auth::{AuthService, RemoteAuthService};
+use crate::service::bulk_operation::{
+    BulkOperationService, BulkOperationStore, RedisBulkOperationStore, SqliteBulkOperationStore,
+};
 use crate::service::component::{ComponentService, RemoteComponentService};
 use crate::service::limit::{LimitService, RemoteLimitService};
+use crate::service::update_check::{DebuggingServiceAgentUpdateReplayer, UpdateCheckService};
 use crate::service::worker::{WorkerClient, WorkerExecutorWorkerClient, WorkerService};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_common::redis::RedisPool;
@@ -43,6 +47,8 @@ pub struct Services {
     pub limit_service: Arc<dyn LimitService>,
     pub component_service: Arc<dyn ComponentService>,
     pub worker_service: Arc<WorkerService>,
+    pub bulk_operation_service: Arc<BulkOperationService>,
+    pub update_check_service: Arc<UpdateCheckService>,
     pub request_handler: Arc<RequestHandler>,
     pub mcp_capability_lookup: Arc<dyn McpCapabilityLookup + Sync + Send + 'static>,
 }
@@ -138,6 +144,42 @@ impl Services {
             }
         };
 
+        let bulk_operation_store: Arc<dyn BulkOperationStore> =
+            match &config.bulk_operations.storage {
+                BulkOperationStorageConfig::Redis(redis_config) => {
+                    let redis = RedisPool::configured(redis_config).await?;
+                    Arc::new(RedisBulkOperationStore::new(
+                        redis,
+                        config.bulk_operations.retention,
+                    ))
+                }
+                BulkOperationStorageConfig::Sqlite(sqlite_config) => {
+                    let pool = SqlitePool::configured(sqlite_config).await?;
+                    Arc::new(
+                        SqliteBulkOperationStore::new(pool, config.bulk_operations.retention)
+                            .await?,
+                    )
+                }
+            };
+
+        let bulk_operation_service = Arc::new(BulkOperationService::new(
+            bulk_operation_store,
+            worker_service.clone(),
+            component_service.clone(),
+            auth_service.clone(),
+            config.bulk_operations.clone(),
+        ));
+
+        let update_check_service = Arc::new(UpdateCheckService::new(
+            worker_service.clone(),
+            component_service.clone(),
+            auth_service.clone(),
+            Arc::new(DebuggingServiceAgentUpdateReplayer::new(
+                &config.update_check,
+            )?),
+            config.update_check.clone(),
+        ));
+
         let oidc_handler = Arc::new(OidcHandler::new(
             session_store.clone(),
             identity_provider.clone(),
@@ -160,6 +202,8 @@ impl Services {
             limit_service,
             component_service,
             worker_service,
+            bulk_operation_service,
+            update_check_service,
             request_handler,
             mcp_capability_lookup,
         })
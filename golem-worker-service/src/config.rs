@@ -46,6 +46,8 @@ pub struct WorkerServiceConfig {
     pub component_service: ComponentServiceConfig,
     pub auth_service: AuthServiceConfig,
     pub webhook_callback_handler: WebhookCallbackHandlerConfig,
+    pub bulk_operations: BulkOperationsConfig,
+    pub update_check: UpdateCheckConfig,
 }
 
 impl WorkerServiceConfig {
@@ -124,6 +126,20 @@ impl SafeDisplay for WorkerServiceConfig {
             self.webhook_callback_handler.to_safe_string_indented()
         );
 
+        let _ = writeln!(&mut result, "bulk operations:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.bulk_operations.to_safe_string_indented()
+        );
+
+        let _ = writeln!(&mut result, "update check:");
+        let _ = writeln!(
+            &mut result,
+            "{}",
+            self.update_check.to_safe_string_indented()
+        );
+
         result
     }
 }
@@ -147,6 +163,8 @@ impl Default for WorkerServiceConfig {
             component_service: ComponentServiceConfig::default(),
             auth_service: AuthServiceConfig::default(),
             webhook_callback_handler: WebhookCallbackHandlerConfig::default(),
+            bulk_operations: BulkOperationsConfig::default(),
+            update_check: UpdateCheckConfig::default(),
         }
     }
 }
@@ -300,6 +318,143 @@ impl Default for RouteResolverConfig {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BulkOperationsConfig {
+    /// Number of agents processed concurrently by a bulk operation if not specified in the request
+    pub default_concurrency: u32,
+    /// Upper limit for the concurrency requested for a bulk operation
+    pub max_concurrency: u32,
+    /// Maximum number of individual failures recorded for a bulk operation
+    pub max_recorded_failures: usize,
+    /// How long the state of a bulk operation is kept after it was last updated
+    #[serde(with = "humantime_serde")]
+    pub retention: Duration,
+    /// How long a running bulk operation is considered alive without a heartbeat from the
+    /// worker service instance running it. Operations with an expired lease are marked as failed.
+    #[serde(with = "humantime_serde")]
+    pub lease: Duration,
+    /// How often the worker service looks for bulk operations left running by stopped instances
+    #[serde(with = "humantime_serde")]
+    pub orphan_check_interval: Duration,
+    pub storage: BulkOperationStorageConfig,
+}
+
+impl SafeDisplay for BulkOperationsConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(
+            &mut result,
+            "default_concurrency: {}",
+            self.default_concurrency
+        );
+        let _ = writeln!(&mut result, "max_concurrency: {}", self.max_concurrency);
+        let _ = writeln!(
+            &mut result,
+            "max_recorded_failures: {}",
+            self.max_recorded_failures
+        );
+        let _ = writeln!(&mut result, "retention: {:?}", self.retention);
+        let _ = writeln!(&mut result, "lease: {:?}", self.lease);
+        let _ = writeln!(
+            &mut result,
+            "orphan_check_interval: {:?}",
+            self.orphan_check_interval
+        );
+        let _ = writeln!(&mut result, "storage:");
+        let _ = writeln!(&mut result, "{}", self.storage.to_safe_string_indented());
+        result
+    }
+}
+
+impl Default for BulkOperationsConfig {
+    fn default() -> Self {
+        Self {
+            default_concurrency: 8,
+            max_concurrency: 64,
+            max_recorded_failures: 100,
+            retention: Duration::from_hours(7 * 24),
+            lease: Duration::from_mins(1),
+            orphan_check_interval: Duration::from_mins(1),
+            storage: BulkOperationStorageConfig::Redis(RedisConfig::default()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum BulkOperationStorageConfig {
+    Redis(RedisConfig),
+    Sqlite(DbSqliteConfig),
+}
+
+impl SafeDisplay for BulkOperationStorageConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        match self {
+            BulkOperationStorageConfig::Redis(redis) => {
+                let _ = writeln!(&mut result, "redis:");
+                let _ = writeln!(&mut result, "{}", redis.to_safe_string_indented());
+            }
+            BulkOperationStorageConfig::Sqlite(sqlite) => {
+                let _ = writeln!(&mut result, "sqlite:");
+                let _ = writeln!(&mut result, "{}", sqlite.to_safe_string_indented());
+            }
+        }
+        result
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UpdateCheckConfig {
+    /// Host of the debugging service replaying the checked agents
+    pub debugging_service_host: String,
+    /// HTTP port of the debugging service replaying the checked agents
+    pub debugging_service_port: u16,
+    /// Number of agents replayed concurrently by an update check if not specified in the request
+    pub default_concurrency: u32,
+    /// Upper limit for the concurrency requested for an update check
+    pub max_concurrency: u32,
+    /// Maximum time allowed for replaying a single agent
+    #[serde(with = "humantime_serde")]
+    pub replay_timeout: Duration,
+}
+
+impl SafeDisplay for UpdateCheckConfig {
+    fn to_safe_string(&self) -> String {
+        let mut result = String::new();
+        let _ = writeln!(
+            &mut result,
+            "debugging_service_host: {}",
+            self.debugging_service_host
+        );
+        let _ = writeln!(
+            &mut result,
+            "debugging_service_port: {}",
+            self.debugging_service_port
+        );
+        let _ = writeln!(
+            &mut result,
+            "default_concurrency: {}",
+            self.default_concurrency
+        );
+        let _ = writeln!(&mut result, "max_concurrency: {}", self.max_concurrency);
+        let _ = writeln!(&mut result, "replay_timeout: {:?}", self.replay_timeout);
+        result
+    }
+}
+
+impl Default for UpdateCheckConfig {
+    fn default() -> Self {
+        Self {
+            debugging_service_host: "localhost".to_string(),
+            debugging_service_port: 8082,
+            default_concurrency: 4,
+            max_concurrency: 16,
+            replay_timeout: Duration::from_mins(5),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ComponentServiceConfig {
     pub component_cache_max_capacity: usize,
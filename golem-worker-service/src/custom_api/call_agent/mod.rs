@@ -87,6 +87,7 @@ impl CallAgentHandler {
                 behaviour.method_name.clone(),
                 proto_method_parameters,
                 golem_api_grpc::proto::golem::workerexecutor::v1::AgentInvocationMode::Await as i32,
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Normal as i32,
                 None,
                 Some(IdempotencyKey::fresh()),
                 invocation_context,
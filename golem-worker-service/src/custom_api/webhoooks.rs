@@ -69,6 +69,20 @@ impl WebhookCallbackHandler {
 
         let promise_id = webhook_id.into_promise_id(behaviour.component_id);
 
+        // callers can reject the promise by passing `failed=true`, the body becomes the error payload
+        let failed = match request.query_params().get("failed").map(|qp| qp.as_slice()) {
+            None | Some([]) => false,
+            Some([single]) => {
+                single
+                    .parse::<bool>()
+                    .map_err(|_| RequestHandlerError::ValueParsingFailed {
+                        value: single.clone(),
+                        expected: "bool",
+                    })?
+            }
+            Some(_) => return Err(RequestHandlerError::TooManyValues { expected: "failed" }),
+        };
+
         let body = request
             .parse_request_body(&RequestBodySchema::UnrestrictedBinary)
             .await?;
@@ -83,12 +97,15 @@ impl WebhookCallbackHandler {
 
         let auth_ctx = AuthCtx::impersonated_user(resolved_route.route.account_id);
 
-        tracing::debug!("Completing promise due to webhook_callback: {promise_id}");
+        tracing::debug!(
+            "Completing promise due to webhook_callback: {promise_id} (failed: {failed})"
+        );
         self.worker_service
             .complete_promise(
                 &promise_id.worker_id,
                 promise_id.oplog_idx.as_u64(),
                 body_binary,
+                failed,
                 auth_ctx,
             )
             .await?;
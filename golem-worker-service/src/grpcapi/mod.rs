@@ -226,6 +226,9 @@ pub fn error_to_status(error: WorkerError) -> Status {
                 worker_execution_error::Error::InvocationFailed(_) => {
                     "Invocation Failed".to_string()
                 }
+                worker_execution_error::Error::InvocationQueueFull(err) => {
+                    format!("Invocation Queue Full: Limit = {}", err.limit)
+                }
             };
             Status::internal(message)
         }
@@ -14,15 +14,17 @@
 
 use super::error::WorkerTraceErrorKind;
 use super::{bad_request_error, validate_protobuf_worker_id};
-use crate::service::worker::WorkerService;
+use crate::service::worker::{OplogEntryBatchStream, WorkerService, WorkerServiceError};
+use futures::{Stream, StreamExt};
 use golem_api_grpc::proto::golem::common::Empty;
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerService as GrpcWorkerService;
 use golem_api_grpc::proto::golem::worker::v1::{
-    CompletePromiseRequest, CompletePromiseResponse, ForkWorkerRequest, ForkWorkerResponse,
-    InvokeAgentRequest, InvokeAgentResponse, InvokeAgentSuccess, LaunchNewWorkerRequest,
-    LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse, ResumeWorkerRequest,
-    ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, UpdateWorkerRequest,
-    UpdateWorkerResponse, WorkerError as GrpcWorkerError, complete_promise_response,
+    CompletePromiseRequest, CompletePromiseResponse, FollowOplogRequest, FollowOplogResponse,
+    FollowOplogSuccessResponse, ForkWorkerRequest, ForkWorkerResponse, InvokeAgentRequest,
+    InvokeAgentResponse, InvokeAgentSuccess, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    WorkerError as GrpcWorkerError, complete_promise_response, follow_oplog_response,
     fork_worker_response, invoke_agent_response, launch_new_worker_response,
     resume_worker_response, revert_worker_response, update_worker_response,
 };
@@ -34,6 +36,7 @@ use golem_common::model::worker::WorkerUpdateMode;
 use golem_common::recorded_grpc_api_request;
 use golem_service_base::grpc::{proto_component_id_string, proto_worker_id_string};
 use golem_service_base::model::auth::AuthCtx;
+use std::pin::Pin;
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
 use tracing::Instrument;
@@ -240,6 +243,58 @@ impl GrpcWorkerService for WorkerGrpcApi {
             result: Some(response),
         }))
     }
+
+    type FollowOplogStream =
+        Pin<Box<dyn Stream<Item = Result<FollowOplogResponse, Status>> + Send + 'static>>;
+
+    async fn follow_oplog(
+        &self,
+        request: Request<FollowOplogRequest>,
+    ) -> Result<Response<Self::FollowOplogStream>, Status> {
+        let (_, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "follow_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            from_oplog_index = request.from_oplog_index,
+        );
+
+        let stream: Self::FollowOplogStream = match self
+            .follow_oplog(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(batches) => {
+                let stream: Self::FollowOplogStream =
+                    Box::pin(batches.map(|batch| {
+                        let result = batch.map_err(GrpcWorkerError::from).and_then(|entries| {
+                            entries
+                                .into_iter()
+                                .map(|entry| entry.try_into())
+                                .collect::<Result<Vec<_>, _>>()
+                                .map_err(|err: String| WorkerServiceError::Internal(err).into())
+                        });
+                        Ok(FollowOplogResponse {
+                            result: Some(match result {
+                                Ok(entries) => follow_oplog_response::Result::Success(
+                                    FollowOplogSuccessResponse { entries },
+                                ),
+                                Err(error) => follow_oplog_response::Result::Error(error),
+                            }),
+                        })
+                    }));
+                record.succeed(stream)
+            }
+            Err(error) => {
+                let stream: Self::FollowOplogStream =
+                    Box::pin(futures::stream::iter(Some(Ok(FollowOplogResponse {
+                        result: Some(follow_oplog_response::Result::Error(error.clone())),
+                    }))));
+                record.fail(stream, &mut WorkerTraceErrorKind(&error))
+            }
+        };
+
+        Ok(Response::new(stream))
+    }
 }
 
 impl WorkerGrpcApi {
@@ -308,7 +363,13 @@ impl WorkerGrpcApi {
 
         let result = self
             .worker_service
-            .complete_promise(&worker_id, parameters.oplog_idx, parameters.data, auth)
+            .complete_promise(
+                &worker_id,
+                parameters.oplog_idx,
+                parameters.data,
+                parameters.failed,
+                auth,
+            )
             .await?;
 
         Ok(result)
@@ -395,6 +456,30 @@ impl WorkerGrpcApi {
         Ok(())
     }
 
+    async fn follow_oplog(
+        &self,
+        request: FollowOplogRequest,
+    ) -> Result<OplogEntryBatchStream, GrpcWorkerError> {
+        let auth: AuthCtx = request
+            .auth_ctx
+            .ok_or(bad_request_error("auth_ctx not found"))?
+            .try_into()
+            .map_err(|e| bad_request_error(format!("failed converting auth_ctx: {e}")))?;
+
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let batches = self
+            .worker_service
+            .follow_oplog(
+                &worker_id,
+                OplogIndex::from_u64(request.from_oplog_index),
+                auth,
+            )
+            .await?;
+
+        Ok(batches)
+    }
+
     async fn invoke_agent(
         &self,
         request: InvokeAgentRequest,
@@ -422,6 +507,7 @@ impl WorkerGrpcApi {
                 request.method_name,
                 method_parameters,
                 request.mode,
+                request.priority,
                 request.schedule_at,
                 request.idempotency_key.map(|k| k.into()),
                 request.context,
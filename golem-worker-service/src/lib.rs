@@ -90,6 +90,9 @@ impl WorkerService {
             .start_api_gateway_server(join_set, tracer.clone())
             .await?;
         let mcp_port = self.start_mcp_server(join_set, tracer).await?;
+        self.services
+            .bulk_operation_service
+            .start_orphan_recovery(join_set);
 
         info!(
             "Started worker service on ports: http: {}, grpc: {}, gateway: {}, mcp: {}",
@@ -115,6 +118,9 @@ impl WorkerService {
             .start_api_gateway_server(join_set, tracer.clone())
             .await?;
         let mcp_port = self.start_mcp_server(join_set, tracer).await?;
+        self.services
+            .bulk_operation_service
+            .start_orphan_recovery(join_set);
         let api_endpoint = api::make_open_api_service(&self.services).boxed();
 
         Ok(TrafficReadyEndpoints {
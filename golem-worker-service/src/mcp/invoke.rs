@@ -85,6 +85,7 @@ pub async fn agent_invoke(
             mcp_tool.raw_method.name.clone(),
             proto_method_parameters,
             golem_api_grpc::proto::golem::workerexecutor::v1::AgentInvocationMode::Await as i32,
+            golem_api_grpc::proto::golem::worker::InvocationPriority::Normal as i32,
             None,
             None,
             None,
@@ -0,0 +1,60 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod service;
+mod store;
+
+pub use service::*;
+pub use store::*;
+
+use crate::service::auth::AuthServiceError;
+use crate::service::component::ComponentServiceError;
+use golem_common::model::bulk_operation::BulkOperationId;
+use golem_common::redis::RedisError;
+use golem_common::{SafeDisplay, error_forwarding};
+use golem_service_base::repo::RepoError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BulkOperationError {
+    #[error("Bulk operation not found: {0}")]
+    NotFound(BulkOperationId),
+    #[error("Bulk operation {0} is already finished")]
+    AlreadyFinished(BulkOperationId),
+    #[error(transparent)]
+    Component(#[from] ComponentServiceError),
+    #[error(transparent)]
+    AuthError(#[from] AuthServiceError),
+    #[error(transparent)]
+    InternalError(#[from] anyhow::Error),
+}
+
+impl SafeDisplay for BulkOperationError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            Self::NotFound(_) => self.to_string(),
+            Self::AlreadyFinished(_) => self.to_string(),
+            Self::Component(inner) => inner.to_safe_string(),
+            Self::AuthError(inner) => inner.to_safe_string(),
+            Self::InternalError(_) => "Internal error".to_string(),
+        }
+    }
+}
+
+error_forwarding!(BulkOperationError, RepoError);
+
+impl From<RedisError> for BulkOperationError {
+    fn from(value: RedisError) -> Self {
+        Self::InternalError(anyhow::Error::from(value).context("RedisError"))
+    }
+}
@@ -0,0 +1,436 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{BulkOperationError, BulkOperationStore};
+use crate::config::BulkOperationsConfig;
+use crate::service::auth::AuthService;
+use crate::service::component::ComponentService;
+use crate::service::worker::{WorkerResult, WorkerService};
+use futures::StreamExt;
+use golem_common::SafeDisplay;
+use golem_common::model::bulk_operation::{
+    BulkOperation, BulkOperationFailure, BulkOperationId, BulkOperationKind, BulkOperationRequest,
+    BulkOperationStatus,
+};
+use golem_common::model::component::ComponentId;
+use golem_common::model::{ScanCursor, Timestamp, WorkerId};
+use golem_service_base::model::auth::{AuthCtx, EnvironmentAction};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{Instrument, error, info, warn};
+
+/// Number of agents fetched from the shards at once while a bulk operation is running
+const BULK_OPERATION_PAGE_SIZE: u64 = 100;
+
+/// Runs operations on all agents of a component matching a filter in the background.
+///
+/// The operation is executed by the worker service instance that accepted the request, while its
+/// progress is persisted in the [`BulkOperationStore`] so it can be observed and cancelled through
+/// any instance. The running instance keeps renewing its lease on the operation; operations whose
+/// lease expires because the running instance stopped are marked as failed by
+/// [`BulkOperationService::fail_orphaned_operations`], as the agents they already processed
+/// cannot be determined reliably.
+pub struct BulkOperationService {
+    store: Arc<dyn BulkOperationStore>,
+    worker_service: Arc<WorkerService>,
+    component_service: Arc<dyn ComponentService>,
+    auth_service: Arc<dyn AuthService>,
+    config: BulkOperationsConfig,
+}
+
+impl BulkOperationService {
+    pub fn new(
+        store: Arc<dyn BulkOperationStore>,
+        worker_service: Arc<WorkerService>,
+        component_service: Arc<dyn ComponentService>,
+        auth_service: Arc<dyn AuthService>,
+        config: BulkOperationsConfig,
+    ) -> Self {
+        Self {
+            store,
+            worker_service,
+            component_service,
+            auth_service,
+            config,
+        }
+    }
+
+    pub async fn start(
+        &self,
+        component_id: ComponentId,
+        request: BulkOperationRequest,
+        auth_ctx: AuthCtx,
+    ) -> Result<BulkOperation, BulkOperationError> {
+        let action = match &request.operation {
+            BulkOperationKind::Delete(_) => EnvironmentAction::DeleteWorker,
+            _ => EnvironmentAction::UpdateWorker,
+        };
+        self.authorize(component_id, action, &auth_ctx).await?;
+
+        let concurrency = request
+            .concurrency
+            .unwrap_or(self.config.default_concurrency)
+            .clamp(1, self.config.max_concurrency.max(1)) as usize;
+
+        let now = Timestamp::now_utc();
+        let operation = BulkOperation {
+            id: BulkOperationId::new(),
+            component_id,
+            created_by: auth_ctx.account_id(),
+            filter: request.filter,
+            operation: request.operation,
+            status: BulkOperationStatus::Running,
+            created_at: now,
+            updated_at: now,
+            matched: 0,
+            succeeded: 0,
+            failed: 0,
+            failures: Vec::new(),
+            cancel_requested: false,
+            error: None,
+        };
+        self.store.create(&operation, self.config.lease).await?;
+
+        info!(
+            bulk_operation_id = %operation.id,
+            component_id = %component_id,
+            "Starting bulk operation"
+        );
+
+        let job = BulkOperationJob {
+            store: self.store.clone(),
+            worker_service: self.worker_service.clone(),
+            max_recorded_failures: self.config.max_recorded_failures,
+            concurrency,
+            lease: self.config.lease,
+            auth_ctx,
+        };
+        tokio::spawn(job.run(operation.clone()).in_current_span());
+
+        Ok(operation)
+    }
+
+    pub async fn get(
+        &self,
+        component_id: ComponentId,
+        id: BulkOperationId,
+        auth_ctx: AuthCtx,
+    ) -> Result<BulkOperation, BulkOperationError> {
+        self.authorize(component_id, EnvironmentAction::ViewWorker, &auth_ctx)
+            .await?;
+
+        self.get_for_component(component_id, id).await
+    }
+
+    pub async fn list(
+        &self,
+        component_id: ComponentId,
+        auth_ctx: AuthCtx,
+    ) -> Result<Vec<BulkOperation>, BulkOperationError> {
+        self.authorize(component_id, EnvironmentAction::ViewWorker, &auth_ctx)
+            .await?;
+
+        self.store.list(&component_id).await
+    }
+
+    /// Requests the cancellation of a running bulk operation. Agents that are already being
+    /// processed are not affected, the operation stops before processing the next batch.
+    pub async fn cancel(
+        &self,
+        component_id: ComponentId,
+        id: BulkOperationId,
+        auth_ctx: AuthCtx,
+    ) -> Result<BulkOperation, BulkOperationError> {
+        self.authorize(component_id, EnvironmentAction::UpdateWorker, &auth_ctx)
+            .await?;
+
+        let operation = self.get_for_component(component_id, id).await?;
+        if operation.status.is_finished() {
+            return Err(BulkOperationError::AlreadyFinished(id));
+        }
+
+        // Only the cancellation flag is written, the progress is owned by the running instance
+        let operation = self
+            .store
+            .request_cancel(&id)
+            .await?
+            .ok_or(BulkOperationError::NotFound(id))?;
+        if operation.status.is_finished() && !operation.cancel_requested {
+            return Err(BulkOperationError::AlreadyFinished(id));
+        }
+
+        Ok(operation)
+    }
+
+    /// Marks the operations left running by stopped worker service instances as failed
+    pub async fn fail_orphaned_operations(&self) -> Result<(), BulkOperationError> {
+        for mut operation in self.store.list_orphaned().await? {
+            operation.status = BulkOperationStatus::Failed;
+            operation.error = Some(
+                "The worker service instance running the bulk operation stopped before finishing it"
+                    .to_string(),
+            );
+            operation.updated_at = Timestamp::now_utc();
+
+            if self.store.finish(&operation).await? {
+                warn!(
+                    bulk_operation_id = %operation.id,
+                    "Marked orphaned bulk operation as failed"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Periodically fails orphaned operations, starting immediately
+    pub fn start_orphan_recovery(self: &Arc<Self>, join_set: &mut JoinSet<anyhow::Result<()>>) {
+        let service = self.clone();
+        join_set.spawn(
+            async move {
+                let mut interval = tokio::time::interval(
+                    service
+                        .config
+                        .orphan_check_interval
+                        .max(Duration::from_secs(1)),
+                );
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = service.fail_orphaned_operations().await {
+                        warn!("Failed to recover orphaned bulk operations: {err}");
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
+
+    async fn get_for_component(
+        &self,
+        component_id: ComponentId,
+        id: BulkOperationId,
+    ) -> Result<BulkOperation, BulkOperationError> {
+        self.store
+            .get(&id)
+            .await?
+            .filter(|operation| operation.component_id == component_id)
+            .ok_or(BulkOperationError::NotFound(id))
+    }
+
+    async fn authorize(
+        &self,
+        component_id: ComponentId,
+        action: EnvironmentAction,
+        auth_ctx: &AuthCtx,
+    ) -> Result<(), BulkOperationError> {
+        let component = self
+            .component_service
+            .get_latest_by_id(component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(component.environment_id, action, auth_ctx)
+            .await?;
+
+        Ok(())
+    }
+}
+
+struct BulkOperationJob {
+    store: Arc<dyn BulkOperationStore>,
+    worker_service: Arc<WorkerService>,
+    max_recorded_failures: usize,
+    concurrency: usize,
+    lease: Duration,
+    auth_ctx: AuthCtx,
+}
+
+impl BulkOperationJob {
+    async fn run(self, operation: BulkOperation) {
+        let lease_renewal = tokio::spawn(
+            Self::renew_lease(self.store.clone(), operation.id, self.lease).in_current_span(),
+        );
+        let operation = self.process(operation).await;
+        lease_renewal.abort();
+
+        info!(
+            bulk_operation_id = %operation.id,
+            status = %operation.status,
+            succeeded = operation.succeeded,
+            failed = operation.failed,
+            "Bulk operation finished"
+        );
+    }
+
+    async fn renew_lease(store: Arc<dyn BulkOperationStore>, id: BulkOperationId, lease: Duration) {
+        let mut interval = tokio::time::interval((lease / 3).max(Duration::from_secs(1)));
+        loop {
+            interval.tick().await;
+            if let Err(err) = store.renew_lease(&id, lease).await {
+                warn!(bulk_operation_id = %id, "Failed to renew bulk operation lease: {err}");
+            }
+        }
+    }
+
+    async fn process(&self, mut operation: BulkOperation) -> BulkOperation {
+        let mut cursor = ScanCursor::default();
+
+        loop {
+            let page = self
+                .worker_service
+                .find_metadata(
+                    operation.component_id,
+                    operation.filter.clone(),
+                    cursor,
+                    BULK_OPERATION_PAGE_SIZE,
+                    false,
+                    self.auth_ctx.clone(),
+                )
+                .await;
+
+            let next_cursor = match page {
+                Ok((next_cursor, workers)) => {
+                    operation.matched += workers.len() as u64;
+
+                    let job = self;
+                    let kind = &operation.operation;
+                    let results = futures::stream::iter(workers)
+                        .map(|worker| async move {
+                            let result = job.apply(kind, &worker.worker_id).await;
+                            (worker.worker_id, result)
+                        })
+                        .buffer_unordered(self.concurrency)
+                        .collect::<Vec<_>>()
+                        .await;
+
+                    for (worker_id, result) in results {
+                        match result {
+                            Ok(()) => operation.succeeded += 1,
+                            Err(err) => {
+                                operation.failed += 1;
+                                if operation.failures.len() < self.max_recorded_failures {
+                                    operation.failures.push(BulkOperationFailure {
+                                        worker_id,
+                                        error: err.to_safe_string(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+
+                    next_cursor
+                }
+                Err(err) => {
+                    error!(
+                        bulk_operation_id = %operation.id,
+                        "Failed to enumerate agents for bulk operation: {err}"
+                    );
+                    operation.status = BulkOperationStatus::Failed;
+                    operation.error = Some(err.to_safe_string());
+                    None
+                }
+            };
+
+            // The cancellation flag may have been set through any worker service instance
+            match self.store.get(&operation.id).await {
+                Ok(Some(stored)) => operation.cancel_requested |= stored.cancel_requested,
+                Ok(None) => {}
+                Err(err) => warn!(
+                    bulk_operation_id = %operation.id,
+                    "Failed to check cancellation of bulk operation: {err}"
+                ),
+            }
+
+            if !operation.status.is_finished() {
+                if operation.cancel_requested {
+                    operation.status = BulkOperationStatus::Cancelled;
+                } else if next_cursor.is_none() {
+                    operation.status = BulkOperationStatus::Completed;
+                }
+            }
+
+            operation.updated_at = Timestamp::now_utc();
+            let stored = if operation.status.is_finished() {
+                self.store.finish(&operation).await
+            } else {
+                self.store.update_progress(&operation).await
+            };
+            match stored {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Another instance considered this operation orphaned and finished it
+                    warn!(
+                        bulk_operation_id = %operation.id,
+                        "Bulk operation was finished by another instance, stopping"
+                    );
+                    break;
+                }
+                Err(err) => warn!(
+                    bulk_operation_id = %operation.id,
+                    "Failed to persist bulk operation progress: {err}"
+                ),
+            }
+
+            match next_cursor {
+                Some(next_cursor) if !operation.status.is_finished() => cursor = next_cursor,
+                _ => break,
+            }
+        }
+
+        operation
+    }
+
+    async fn apply(&self, operation: &BulkOperationKind, worker_id: &WorkerId) -> WorkerResult<()> {
+        let auth_ctx = self.auth_ctx.clone();
+        match operation {
+            BulkOperationKind::Interrupt(params) => {
+                self.worker_service
+                    .interrupt(worker_id, params.recover_immediately, auth_ctx)
+                    .await
+            }
+            BulkOperationKind::Resume(params) => {
+                self.worker_service
+                    .resume(worker_id, params.force, auth_ctx)
+                    .await
+            }
+            BulkOperationKind::Update(params) => {
+                self.worker_service
+                    .update(
+                        worker_id,
+                        params.mode,
+                        params.target_revision,
+                        params.disable_wakeup,
+                        auth_ctx,
+                    )
+                    .await
+            }
+            BulkOperationKind::Delete(_) => self.worker_service.delete(worker_id, auth_ctx).await,
+            BulkOperationKind::ActivatePlugin(params) => {
+                self.worker_service
+                    .activate_plugin(worker_id, params.plugin_priority, auth_ctx)
+                    .await
+            }
+            BulkOperationKind::DeactivatePlugin(params) => {
+                self.worker_service
+                    .deactivate_plugin(worker_id, params.plugin_priority, auth_ctx)
+                    .await
+            }
+            BulkOperationKind::Revert(params) => {
+                self.worker_service
+                    .revert_worker(worker_id, params.target.clone(), auth_ctx)
+                    .await
+            }
+        }
+    }
+}
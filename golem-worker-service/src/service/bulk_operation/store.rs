@@ -0,0 +1,746 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::BulkOperationError;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use fred::types::Expiration;
+use golem_common::model::bulk_operation::{BulkOperation, BulkOperationId};
+use golem_common::model::component::ComponentId;
+use golem_common::redis::RedisPool;
+use golem_service_base::db::sqlite::SqlitePool;
+use sqlx::Row;
+use sqlx::sqlite::SqliteRow;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Persists the state of bulk operations so they can be polled and cancelled from any
+/// worker service instance, independently of the one running the operation.
+///
+/// The progress of an operation is only written by the instance running it, while the
+/// cancellation flag is stored separately, so a cancellation request can never be overwritten by
+/// a progress update. Finishing an operation is conditional: only the first of the running
+/// instance and the orphan recovery of another instance can finish it.
+#[async_trait]
+pub trait BulkOperationStore: Send + Sync {
+    /// Stores a newly started operation, leased to the calling instance for the given duration
+    async fn create(
+        &self,
+        operation: &BulkOperation,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError>;
+
+    /// Stores the progress of a running operation. Returns `false` without storing anything if
+    /// the operation has already been finished.
+    async fn update_progress(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError>;
+
+    /// Stores the final state of an operation. Returns `false` without storing anything if the
+    /// operation has already been finished.
+    async fn finish(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError>;
+
+    /// Extends the lease of the instance running the operation
+    async fn renew_lease(
+        &self,
+        id: &BulkOperationId,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError>;
+
+    /// Sets the cancellation flag of an operation and returns its current state
+    async fn request_cancel(
+        &self,
+        id: &BulkOperationId,
+    ) -> Result<Option<BulkOperation>, BulkOperationError>;
+
+    async fn get(&self, id: &BulkOperationId) -> Result<Option<BulkOperation>, BulkOperationError>;
+
+    async fn list(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<Vec<BulkOperation>, BulkOperationError>;
+
+    /// Lists the unfinished operations whose lease has expired
+    async fn list_orphaned(&self) -> Result<Vec<BulkOperation>, BulkOperationError>;
+}
+
+fn serialize(operation: &BulkOperation) -> Result<String, BulkOperationError> {
+    Ok(serde_json::to_string(operation)
+        .map_err(|e| anyhow!("BulkOperation serialization error: {e}"))?)
+}
+
+fn deserialize(value: &[u8]) -> Result<BulkOperation, BulkOperationError> {
+    Ok(serde_json::from_slice(value)
+        .map_err(|e| anyhow!("BulkOperation deserialization error: {e}"))?)
+}
+
+/// Applies the separately stored cancellation flag to the stored progress of an operation
+fn with_cancel_flag(mut operation: BulkOperation, cancel_requested: bool) -> BulkOperation {
+    if cancel_requested && !operation.status.is_finished() {
+        operation.cancel_requested = true;
+    }
+    operation
+}
+
+fn duration_secs(duration: Duration) -> Result<i64, BulkOperationError> {
+    Ok(duration
+        .as_secs()
+        .max(1)
+        .try_into()
+        .map_err(|e| anyhow!("Invalid bulk operation duration: {e}"))?)
+}
+
+/// Stores each operation in a hash with separate fields for the progress written by the running
+/// instance, the cancellation flag and the marker claimed by whoever finishes the operation.
+pub struct RedisBulkOperationStore {
+    redis: RedisPool,
+    retention: Duration,
+}
+
+impl RedisBulkOperationStore {
+    const VALUE_FIELD: &'static str = "value";
+    const CANCEL_REQUESTED_FIELD: &'static str = "cancel_requested";
+    const FINISHED_FIELD: &'static str = "finished";
+    const RUNNING_KEY: &'static str = "bulk_operations:running";
+
+    pub fn new(redis: RedisPool, retention: Duration) -> Self {
+        Self { redis, retention }
+    }
+
+    fn redis_key_for_operation(id: &BulkOperationId) -> String {
+        format!("bulk_operation:{id}")
+    }
+
+    fn redis_key_for_lease(id: &BulkOperationId) -> String {
+        format!("bulk_operation_lease:{id}")
+    }
+
+    fn redis_key_for_component(component_id: &ComponentId) -> String {
+        format!("bulk_operations:{component_id}")
+    }
+
+    async fn write_value(&self, operation: &BulkOperation) -> Result<(), BulkOperationError> {
+        let key = Self::redis_key_for_operation(&operation.id);
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "write_value")
+            .hset(&key, (Self::VALUE_FIELD, serialize(operation)?))
+            .await?;
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "expire")
+            .expire(key, duration_secs(self.retention)?)
+            .await?;
+        Ok(())
+    }
+
+    async fn is_finished(&self, id: &BulkOperationId) -> Result<bool, BulkOperationError> {
+        Ok(self
+            .redis
+            .with("bulk_operation_store", "is_finished")
+            .hexists(Self::redis_key_for_operation(id), Self::FINISHED_FIELD)
+            .await?)
+    }
+}
+
+#[async_trait]
+impl BulkOperationStore for RedisBulkOperationStore {
+    async fn create(
+        &self,
+        operation: &BulkOperation,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError> {
+        self.write_value(operation).await?;
+        self.renew_lease(&operation.id, lease).await?;
+
+        let retention_secs = duration_secs(self.retention)?;
+        let component_key = Self::redis_key_for_component(&operation.component_id);
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "save_index")
+            .sadd(&component_key, operation.id.to_string())
+            .await?;
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "expire_index")
+            .expire(component_key, retention_secs)
+            .await?;
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "save_running")
+            .sadd(Self::RUNNING_KEY, operation.id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_progress(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError> {
+        if self.is_finished(&operation.id).await? {
+            return Ok(false);
+        }
+        self.write_value(operation).await?;
+        Ok(true)
+    }
+
+    async fn finish(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError> {
+        let claimed: bool = self
+            .redis
+            .with("bulk_operation_store", "claim_finish")
+            .hsetnx(
+                Self::redis_key_for_operation(&operation.id),
+                Self::FINISHED_FIELD,
+                operation.status.to_string(),
+            )
+            .await?;
+        if !claimed {
+            return Ok(false);
+        }
+
+        self.write_value(operation).await?;
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "remove_running")
+            .srem(Self::RUNNING_KEY, operation.id.to_string())
+            .await?;
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "release_lease")
+            .del(Self::redis_key_for_lease(&operation.id))
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn renew_lease(
+        &self,
+        id: &BulkOperationId,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError> {
+        let _: () = self
+            .redis
+            .with("bulk_operation_store", "renew_lease")
+            .set(
+                Self::redis_key_for_lease(id),
+                "1",
+                Some(Expiration::EX(duration_secs(lease)?)),
+                None,
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn request_cancel(
+        &self,
+        id: &BulkOperationId,
+    ) -> Result<Option<BulkOperation>, BulkOperationError> {
+        if self.get(id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let _: i64 = self
+            .redis
+            .with("bulk_operation_store", "request_cancel")
+            .hset(
+                Self::redis_key_for_operation(id),
+                (Self::CANCEL_REQUESTED_FIELD, "1"),
+            )
+            .await?;
+
+        self.get(id).await
+    }
+
+    async fn get(&self, id: &BulkOperationId) -> Result<Option<BulkOperation>, BulkOperationError> {
+        let (value, cancel_requested): (Option<Bytes>, Option<String>) = self
+            .redis
+            .with("bulk_operation_store", "get")
+            .hmget(
+                Self::redis_key_for_operation(id),
+                vec![Self::VALUE_FIELD, Self::CANCEL_REQUESTED_FIELD],
+            )
+            .await?;
+
+        value
+            .map(|bytes| {
+                Ok(with_cancel_flag(
+                    deserialize(&bytes)?,
+                    cancel_requested.is_some(),
+                ))
+            })
+            .transpose()
+    }
+
+    async fn list(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<Vec<BulkOperation>, BulkOperationError> {
+        let component_key = Self::redis_key_for_component(component_id);
+        let ids: Vec<String> = self
+            .redis
+            .with("bulk_operation_store", "list_index")
+            .smembers(&component_key)
+            .await?;
+
+        let mut result = Vec::with_capacity(ids.len());
+        for id in ids {
+            let operation = match BulkOperationId::from_str(&id) {
+                Ok(operation_id) => self.get(&operation_id).await?,
+                Err(_) => None,
+            };
+
+            match operation {
+                Some(operation) => result.push(operation),
+                None => {
+                    // The operation expired, drop it from the index as well
+                    let _: i64 = self
+                        .redis
+                        .with("bulk_operation_store", "remove_expired")
+                        .srem(&component_key, id)
+                        .await?;
+                }
+            }
+        }
+
+        result.sort_by_key(|operation| std::cmp::Reverse(operation.created_at));
+        Ok(result)
+    }
+
+    async fn list_orphaned(&self) -> Result<Vec<BulkOperation>, BulkOperationError> {
+        let ids: Vec<String> = self
+            .redis
+            .with("bulk_operation_store", "list_running")
+            .smembers(Self::RUNNING_KEY)
+            .await?;
+
+        let mut result = Vec::new();
+        for id in ids {
+            let operation = match BulkOperationId::from_str(&id) {
+                Ok(operation_id) => self.get(&operation_id).await?,
+                Err(_) => None,
+            };
+
+            match operation {
+                Some(operation) if !operation.status.is_finished() => {
+                    let leased: bool = self
+                        .redis
+                        .with("bulk_operation_store", "check_lease")
+                        .exists(Self::redis_key_for_lease(&operation.id))
+                        .await?;
+                    if !leased {
+                        result.push(operation);
+                    }
+                }
+                _ => {
+                    let _: i64 = self
+                        .redis
+                        .with("bulk_operation_store", "remove_running")
+                        .srem(Self::RUNNING_KEY, id)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub struct SqliteBulkOperationStore {
+    pool: SqlitePool,
+    retention: Duration,
+}
+
+impl SqliteBulkOperationStore {
+    pub async fn new(pool: SqlitePool, retention: Duration) -> anyhow::Result<Self> {
+        Self::init(&pool).await?;
+        Ok(Self { pool, retention })
+    }
+
+    async fn init(pool: &SqlitePool) -> anyhow::Result<()> {
+        pool.with_rw("bulk_operation_store", "init")
+            .execute(sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS bulk_operation (
+                    id TEXT PRIMARY KEY,
+                    component_id TEXT NOT NULL,
+                    value BLOB NOT NULL,
+                    cancel_requested INTEGER NOT NULL DEFAULT 0,
+                    finished INTEGER NOT NULL DEFAULT 0,
+                    lease_until INTEGER NOT NULL,
+                    updated_at INTEGER NOT NULL
+                );
+
+                CREATE INDEX IF NOT EXISTS bulk_operation_component_id_idx
+                    ON bulk_operation (component_id);
+                "#,
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    fn expired_before(&self) -> i64 {
+        chrono::Utc::now().timestamp() - self.retention.as_secs() as i64
+    }
+
+    fn lease_until(lease: Duration) -> Result<i64, BulkOperationError> {
+        Ok(chrono::Utc::now().timestamp() + duration_secs(lease)?)
+    }
+
+    fn operation_from_row(row: &SqliteRow) -> Result<BulkOperation, BulkOperationError> {
+        let bytes: Vec<u8> = row.get(0);
+        let cancel_requested: bool = row.get(1);
+        Ok(with_cancel_flag(deserialize(&bytes)?, cancel_requested))
+    }
+}
+
+#[async_trait]
+impl BulkOperationStore for SqliteBulkOperationStore {
+    async fn create(
+        &self,
+        operation: &BulkOperation,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError> {
+        let serialized = serialize(operation)?;
+
+        self.pool
+            .with_rw("bulk_operation_store", "create")
+            .execute(
+                sqlx::query(
+                    "INSERT INTO bulk_operation (id, component_id, value, lease_until, updated_at) VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(operation.id.to_string())
+                .bind(operation.component_id.to_string())
+                .bind(serialized.into_bytes())
+                .bind(Self::lease_until(lease)?)
+                .bind(chrono::Utc::now().timestamp()),
+            )
+            .await?;
+
+        // Expired operations are removed lazily, operations are not created often
+        self.pool
+            .with_rw("bulk_operation_store", "cleanup_expired")
+            .execute(
+                sqlx::query("DELETE FROM bulk_operation WHERE updated_at < ?")
+                    .bind(self.expired_before()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_progress(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError> {
+        let serialized = serialize(operation)?;
+
+        let result = self
+            .pool
+            .with_rw("bulk_operation_store", "update_progress")
+            .execute(
+                sqlx::query(
+                    "UPDATE bulk_operation SET value = ?, updated_at = ? WHERE id = ? AND finished = 0",
+                )
+                .bind(serialized.into_bytes())
+                .bind(chrono::Utc::now().timestamp())
+                .bind(operation.id.to_string()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn finish(&self, operation: &BulkOperation) -> Result<bool, BulkOperationError> {
+        let serialized = serialize(operation)?;
+
+        let result = self
+            .pool
+            .with_rw("bulk_operation_store", "finish")
+            .execute(
+                sqlx::query(
+                    "UPDATE bulk_operation SET value = ?, finished = 1, updated_at = ? WHERE id = ? AND finished = 0",
+                )
+                .bind(serialized.into_bytes())
+                .bind(chrono::Utc::now().timestamp())
+                .bind(operation.id.to_string()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn renew_lease(
+        &self,
+        id: &BulkOperationId,
+        lease: Duration,
+    ) -> Result<(), BulkOperationError> {
+        self.pool
+            .with_rw("bulk_operation_store", "renew_lease")
+            .execute(
+                sqlx::query(
+                    "UPDATE bulk_operation SET lease_until = ? WHERE id = ? AND finished = 0",
+                )
+                .bind(Self::lease_until(lease)?)
+                .bind(id.to_string()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn request_cancel(
+        &self,
+        id: &BulkOperationId,
+    ) -> Result<Option<BulkOperation>, BulkOperationError> {
+        self.pool
+            .with_rw("bulk_operation_store", "request_cancel")
+            .execute(
+                sqlx::query("UPDATE bulk_operation SET cancel_requested = 1 WHERE id = ?")
+                    .bind(id.to_string()),
+            )
+            .await?;
+
+        self.get(id).await
+    }
+
+    async fn get(&self, id: &BulkOperationId) -> Result<Option<BulkOperation>, BulkOperationError> {
+        let row = self
+            .pool
+            .with_ro("bulk_operation_store", "get")
+            .fetch_optional(
+                sqlx::query(
+                    "SELECT value, cancel_requested FROM bulk_operation WHERE id = ? AND updated_at >= ?",
+                )
+                .bind(id.to_string())
+                .bind(self.expired_before()),
+            )
+            .await?;
+
+        row.map(|row| Self::operation_from_row(&row)).transpose()
+    }
+
+    async fn list(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<Vec<BulkOperation>, BulkOperationError> {
+        let rows = self
+            .pool
+            .with_ro("bulk_operation_store", "list")
+            .fetch_all(
+                sqlx::query(
+                    "SELECT value, cancel_requested FROM bulk_operation WHERE component_id = ? AND updated_at >= ?",
+                )
+                .bind(component_id.to_string())
+                .bind(self.expired_before()),
+            )
+            .await?;
+
+        let mut result = rows
+            .iter()
+            .map(Self::operation_from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        result.sort_by_key(|operation| std::cmp::Reverse(operation.created_at));
+        Ok(result)
+    }
+
+    async fn list_orphaned(&self) -> Result<Vec<BulkOperation>, BulkOperationError> {
+        let rows = self
+            .pool
+            .with_ro("bulk_operation_store", "list_orphaned")
+            .fetch_all(
+                sqlx::query(
+                    "SELECT value, cancel_requested FROM bulk_operation WHERE finished = 0 AND lease_until < ?",
+                )
+                .bind(chrono::Utc::now().timestamp()),
+            )
+            .await?;
+
+        rows.iter().map(Self::operation_from_row).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BulkOperationStore, SqliteBulkOperationStore, deserialize, serialize};
+    use golem_common::config::DbSqliteConfig;
+    use golem_common::model::account::AccountId;
+    use golem_common::model::bulk_operation::{
+        BulkDelete, BulkOperation, BulkOperationFailure, BulkOperationId, BulkOperationKind,
+        BulkOperationStatus, BulkUpdate,
+    };
+    use golem_common::model::component::{ComponentId, ComponentRevision};
+    use golem_common::model::worker::WorkerUpdateMode;
+    use golem_common::model::{Timestamp, WorkerFilter, WorkerId};
+    use golem_service_base::db::sqlite::SqlitePool;
+    use std::time::Duration;
+    use tempfile::TempDir;
+    use test_r::test;
+
+    fn running_operation(component_id: ComponentId) -> BulkOperation {
+        let now = Timestamp::now_utc().rounded();
+        BulkOperation {
+            id: BulkOperationId::new(),
+            component_id,
+            created_by: AccountId::new(),
+            filter: None,
+            operation: BulkOperationKind::Delete(BulkDelete {}),
+            status: BulkOperationStatus::Running,
+            created_at: now,
+            updated_at: now,
+            matched: 0,
+            succeeded: 0,
+            failed: 0,
+            failures: Vec::new(),
+            cancel_requested: false,
+            error: None,
+        }
+    }
+
+    async fn sqlite_store(dir: &TempDir) -> SqliteBulkOperationStore {
+        let pool = SqlitePool::configured(&DbSqliteConfig {
+            database: dir
+                .path()
+                .join("bulk-operations.db")
+                .to_string_lossy()
+                .to_string(),
+            max_connections: 1,
+            foreign_keys: false,
+        })
+        .await
+        .unwrap();
+        SqliteBulkOperationStore::new(pool, Duration::from_hours(1))
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn bulk_operation_roundtrip() {
+        let component_id = ComponentId::new();
+        let now = Timestamp::now_utc().rounded();
+        let operation = BulkOperation {
+            id: BulkOperationId::new(),
+            component_id,
+            created_by: AccountId::new(),
+            filter: Some(WorkerFilter::from(vec!["revision < 7".to_string()]).unwrap()),
+            operation: BulkOperationKind::Update(BulkUpdate {
+                mode: WorkerUpdateMode::Automatic,
+                target_revision: ComponentRevision::new(7).unwrap(),
+                disable_wakeup: false,
+            }),
+            status: BulkOperationStatus::Running,
+            created_at: now,
+            updated_at: now,
+            matched: 2,
+            succeeded: 1,
+            failed: 1,
+            failures: vec![BulkOperationFailure {
+                worker_id: WorkerId {
+                    component_id,
+                    worker_name: "agent-1".to_string(),
+                },
+                error: "Worker not found".to_string(),
+            }],
+            cancel_requested: false,
+            error: None,
+        };
+
+        let serialized = serialize(&operation).unwrap();
+        let deserialized = deserialize(serialized.as_bytes()).unwrap();
+
+        assert_eq!(operation, deserialized);
+    }
+
+    #[test]
+    async fn cancellation_is_not_overwritten_by_progress() {
+        let dir = TempDir::new().unwrap();
+        let store = sqlite_store(&dir).await;
+        let mut operation = running_operation(ComponentId::new());
+        store
+            .create(&operation, Duration::from_mins(1))
+            .await
+            .unwrap();
+
+        let cancelled = store.request_cancel(&operation.id).await.unwrap().unwrap();
+        assert!(cancelled.cancel_requested);
+
+        // The running instance has not seen the cancellation yet when storing its progress
+        operation.matched = 10;
+        operation.succeeded = 10;
+        assert!(store.update_progress(&operation).await.unwrap());
+
+        let stored = store.get(&operation.id).await.unwrap().unwrap();
+        assert_eq!(stored.succeeded, 10);
+        assert!(stored.cancel_requested);
+    }
+
+    #[test]
+    async fn finished_operations_are_not_reset() {
+        let dir = TempDir::new().unwrap();
+        let store = sqlite_store(&dir).await;
+        let mut operation = running_operation(ComponentId::new());
+        store
+            .create(&operation, Duration::from_mins(1))
+            .await
+            .unwrap();
+
+        operation.status = BulkOperationStatus::Completed;
+        assert!(store.finish(&operation).await.unwrap());
+
+        let cancelled = store.request_cancel(&operation.id).await.unwrap().unwrap();
+        assert_eq!(cancelled.status, BulkOperationStatus::Completed);
+        assert!(!cancelled.cancel_requested);
+
+        let mut stale = operation.clone();
+        stale.status = BulkOperationStatus::Running;
+        assert!(!store.update_progress(&stale).await.unwrap());
+        stale.status = BulkOperationStatus::Failed;
+        assert!(!store.finish(&stale).await.unwrap());
+
+        let stored = store.get(&operation.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, BulkOperationStatus::Completed);
+    }
+
+    #[test]
+    async fn operations_with_expired_lease_are_orphaned() {
+        let dir = TempDir::new().unwrap();
+        let store = sqlite_store(&dir).await;
+        let component_id = ComponentId::new();
+
+        let leased = running_operation(component_id);
+        store.create(&leased, Duration::from_mins(1)).await.unwrap();
+
+        let orphaned = running_operation(component_id);
+        store
+            .create(&orphaned, Duration::from_secs(1))
+            .await
+            .unwrap();
+
+        let mut finished = running_operation(component_id);
+        store
+            .create(&finished, Duration::from_secs(1))
+            .await
+            .unwrap();
+        finished.status = BulkOperationStatus::Completed;
+        store.finish(&finished).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(2100)).await;
+        store
+            .renew_lease(&leased.id, Duration::from_mins(1))
+            .await
+            .unwrap();
+
+        let result = store.list_orphaned().await.unwrap();
+        assert_eq!(
+            result.iter().map(|op| op.id).collect::<Vec<_>>(),
+            vec![orphaned.id]
+        );
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 pub mod auth;
+pub mod bulk_operation;
 pub mod component;
 pub mod limit;
+pub mod update_check;
 pub mod worker;
@@ -0,0 +1,498 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::UpdateCheckConfig;
+use crate::service::auth::{AuthService, AuthServiceError};
+use crate::service::component::{ComponentService, ComponentServiceError};
+use crate::service::worker::{WorkerService, WorkerServiceError};
+use async_trait::async_trait;
+use futures::StreamExt;
+use golem_common::SafeDisplay;
+use golem_common::model::ScanCursor;
+use golem_common::model::WorkerId;
+use golem_common::model::auth::TokenSecret;
+use golem_common::model::component::{ComponentId, ComponentRevision};
+use golem_common::model::worker::{
+    AgentUpdateCheckRequest, AgentUpdateCheckResult, UpdateCheckRequest, UpdateCheckResponse,
+    WorkerMetadataDto,
+};
+use golem_service_base::model::auth::{AuthCtx, EnvironmentAction};
+use std::future::Future;
+use std::sync::Arc;
+use tracing::info;
+
+/// Number of agents fetched from the shards at once while selecting the agents to check
+const UPDATE_CHECK_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCheckError {
+    #[error("Target revision {0} does not exist")]
+    TargetRevisionNotFound(ComponentRevision),
+    #[error("Failed to replay agent: {0}")]
+    ReplayFailed(String),
+    #[error(transparent)]
+    Worker(#[from] WorkerServiceError),
+    #[error(transparent)]
+    Component(#[from] ComponentServiceError),
+    #[error(transparent)]
+    AuthError(#[from] AuthServiceError),
+}
+
+impl SafeDisplay for UpdateCheckError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            Self::TargetRevisionNotFound(_) => self.to_string(),
+            Self::ReplayFailed(_) => self.to_string(),
+            Self::Worker(inner) => inner.to_safe_string(),
+            Self::Component(inner) => inner.to_safe_string(),
+            Self::AuthError(inner) => inner.to_safe_string(),
+        }
+    }
+}
+
+/// Replays a single agent against a target component revision in isolation
+#[async_trait]
+pub trait AgentUpdateReplayer: Send + Sync {
+    async fn replay(
+        &self,
+        request: &AgentUpdateCheckRequest,
+        token: &TokenSecret,
+    ) -> Result<AgentUpdateCheckResult, UpdateCheckError>;
+}
+
+/// Replays agents using the debugging service, which recovers them in dedicated debug sessions
+/// on top of a read-only view of their oplogs, so the checked agents are not affected.
+pub struct DebuggingServiceAgentUpdateReplayer {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl DebuggingServiceAgentUpdateReplayer {
+    pub fn new(config: &UpdateCheckConfig) -> Result<Self, anyhow::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(config.replay_timeout)
+            .build()?;
+        Ok(Self {
+            client,
+            url: format!(
+                "http://{}:{}/v1/debugger/update-check",
+                config.debugging_service_host, config.debugging_service_port
+            ),
+        })
+    }
+}
+
+#[async_trait]
+impl AgentUpdateReplayer for DebuggingServiceAgentUpdateReplayer {
+    async fn replay(
+        &self,
+        request: &AgentUpdateCheckRequest,
+        token: &TokenSecret,
+    ) -> Result<AgentUpdateCheckResult, UpdateCheckError> {
+        let response = self
+            .client
+            .post(&self.url)
+            .bearer_auth(token.secret())
+            .json(request)
+            .send()
+            .await
+            .map_err(|err| {
+                UpdateCheckError::ReplayFailed(format!("debugging service is not reachable: {err}"))
+            })?;
+
+        let status = response.status();
+        if status.is_success() {
+            response.json().await.map_err(|err| {
+                UpdateCheckError::ReplayFailed(format!(
+                    "unexpected response from the debugging service: {err}"
+                ))
+            })
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(UpdateCheckError::ReplayFailed(format!(
+                "debugging service responded with {status}: {body}"
+            )))
+        }
+    }
+}
+
+/// Checks whether agents of a component can be automatically updated to a component revision.
+///
+/// The agents to check are selected by this service, and each of them is replayed against the
+/// target revision by an [`AgentUpdateReplayer`], with bounded concurrency.
+pub struct UpdateCheckService {
+    worker_service: Arc<WorkerService>,
+    component_service: Arc<dyn ComponentService>,
+    auth_service: Arc<dyn AuthService>,
+    replayer: Arc<dyn AgentUpdateReplayer>,
+    config: UpdateCheckConfig,
+}
+
+impl UpdateCheckService {
+    pub fn new(
+        worker_service: Arc<WorkerService>,
+        component_service: Arc<dyn ComponentService>,
+        auth_service: Arc<dyn AuthService>,
+        replayer: Arc<dyn AgentUpdateReplayer>,
+        config: UpdateCheckConfig,
+    ) -> Self {
+        Self {
+            worker_service,
+            component_service,
+            auth_service,
+            replayer,
+            config,
+        }
+    }
+
+    pub async fn check(
+        &self,
+        component_id: ComponentId,
+        request: UpdateCheckRequest,
+        token: TokenSecret,
+        auth_ctx: AuthCtx,
+    ) -> Result<UpdateCheckResponse, UpdateCheckError> {
+        let component = self
+            .component_service
+            .get_latest_by_id(component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::ViewWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.component_service
+            .get_revision(component_id, request.target_revision)
+            .await
+            .map_err(|err| match err {
+                ComponentServiceError::ComponentNotFound => {
+                    UpdateCheckError::TargetRevisionNotFound(request.target_revision)
+                }
+                err => err.into(),
+            })?;
+
+        let candidates = self.candidates(component_id, &request, auth_ctx).await?;
+
+        let concurrency = request
+            .concurrency
+            .unwrap_or(self.config.default_concurrency)
+            .clamp(1, self.config.max_concurrency.max(1)) as usize;
+
+        info!(
+            component_id = %component_id,
+            target_revision = %request.target_revision,
+            "Checking update of {} agents",
+            candidates.len()
+        );
+
+        let target_revision = request.target_revision;
+        let replayer = &self.replayer;
+        let token = &token;
+        let results = check_candidates(
+            candidates,
+            target_revision,
+            concurrency,
+            |worker_id| async move {
+                replayer
+                    .replay(
+                        &AgentUpdateCheckRequest {
+                            worker_id,
+                            target_revision,
+                        },
+                        token,
+                    )
+                    .await
+            },
+        )
+        .await;
+
+        Ok(UpdateCheckResponse {
+            target_revision,
+            results,
+        })
+    }
+
+    // Selects at most `sample_size` agents matching the filter which are not yet running the
+    // target revision
+    async fn candidates(
+        &self,
+        component_id: ComponentId,
+        request: &UpdateCheckRequest,
+        auth_ctx: AuthCtx,
+    ) -> Result<Vec<UpdateCheckCandidate>, UpdateCheckError> {
+        let limit = request.sample_size.unwrap_or(u64::MAX);
+
+        let mut result = Vec::new();
+        let mut cursor = Some(ScanCursor::default());
+        while let Some(current_cursor) = cursor {
+            if result.len() as u64 >= limit {
+                break;
+            }
+
+            let (next_cursor, workers) = self
+                .worker_service
+                .find_metadata(
+                    component_id,
+                    request.filter.clone(),
+                    current_cursor,
+                    UPDATE_CHECK_PAGE_SIZE,
+                    true,
+                    auth_ctx.clone(),
+                )
+                .await?;
+
+            result.extend(
+                workers
+                    .iter()
+                    .filter(|worker| worker.component_revision != request.target_revision)
+                    .map(UpdateCheckCandidate::from),
+            );
+            cursor = next_cursor;
+        }
+        result.truncate(limit.try_into().unwrap_or(usize::MAX));
+
+        Ok(result)
+    }
+}
+
+/// An agent selected for an update check
+#[derive(Debug, Clone, PartialEq)]
+struct UpdateCheckCandidate {
+    worker_id: WorkerId,
+    current_revision: ComponentRevision,
+    history_discarded: bool,
+}
+
+impl From<&WorkerMetadataDto> for UpdateCheckCandidate {
+    fn from(value: &WorkerMetadataDto) -> Self {
+        Self {
+            worker_id: value.worker_id.clone(),
+            current_revision: value.component_revision,
+            history_discarded: value.discarded_region.is_some(),
+        }
+    }
+}
+
+/// Replays the candidates with at most `concurrency` replays running at the same time.
+///
+/// Agents whose oplog history was discarded by the retention policy are reported as incompatible
+/// without replaying them, as automatic updates are rejected for them. Failing to replay an agent
+/// is reported in its result instead of failing the whole check.
+async fn check_candidates<F, Fut>(
+    candidates: Vec<UpdateCheckCandidate>,
+    target_revision: ComponentRevision,
+    concurrency: usize,
+    replay: F,
+) -> Vec<AgentUpdateCheckResult>
+where
+    F: Fn(WorkerId) -> Fut,
+    Fut: Future<Output = Result<AgentUpdateCheckResult, UpdateCheckError>>,
+{
+    let replay = &replay;
+    let mut results = futures::stream::iter(candidates)
+        .map(|candidate| async move {
+            let incompatible = |details: String| AgentUpdateCheckResult {
+                worker_id: candidate.worker_id.clone(),
+                current_revision: candidate.current_revision,
+                compatible: false,
+                divergence_index: None,
+                details: Some(details),
+            };
+
+            if candidate.history_discarded {
+                return incompatible(format!(
+                    "The oplog history of the agent has been discarded, it cannot be updated to revision {target_revision} automatically"
+                ));
+            }
+
+            match replay(candidate.worker_id.clone()).await {
+                Ok(result) => result,
+                Err(err) => incompatible(err.to_safe_string()),
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+    results.sort_by(|a, b| a.worker_id.worker_name.cmp(&b.worker_id.worker_name));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateCheckCandidate, UpdateCheckError, check_candidates};
+    use assert2::assert;
+    use golem_common::model::WorkerId;
+    use golem_common::model::component::{ComponentId, ComponentRevision};
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::worker::AgentUpdateCheckResult;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use test_r::test;
+
+    fn candidate(
+        component_id: ComponentId,
+        name: &str,
+        history_discarded: bool,
+    ) -> UpdateCheckCandidate {
+        UpdateCheckCandidate {
+            worker_id: WorkerId {
+                component_id,
+                worker_name: name.to_string(),
+            },
+            current_revision: ComponentRevision::new(1).unwrap(),
+            history_discarded,
+        }
+    }
+
+    #[test]
+    async fn replay_results_and_failures_are_reported_per_agent() {
+        let component_id = ComponentId::new();
+        let target_revision = ComponentRevision::new(2).unwrap();
+
+        let results = check_candidates(
+            vec![
+                candidate(component_id, "c", false),
+                candidate(component_id, "a", false),
+                candidate(component_id, "b", false),
+            ],
+            target_revision,
+            2,
+            |worker_id| async move {
+                match worker_id.worker_name.as_str() {
+                    "a" => Ok(AgentUpdateCheckResult {
+                        worker_id,
+                        current_revision: ComponentRevision::new(1).unwrap(),
+                        compatible: true,
+                        divergence_index: None,
+                        details: None,
+                    }),
+                    "b" => Ok(AgentUpdateCheckResult {
+                        worker_id,
+                        current_revision: ComponentRevision::new(1).unwrap(),
+                        compatible: false,
+                        divergence_index: Some(OplogIndex::from_u64(5)),
+                        details: Some("diverged".to_string()),
+                    }),
+                    _ => Err(UpdateCheckError::ReplayFailed("unavailable".to_string())),
+                }
+            },
+        )
+        .await;
+
+        let summary = results
+            .iter()
+            .map(|result| {
+                (
+                    result.worker_id.worker_name.clone(),
+                    result.compatible,
+                    result.divergence_index,
+                    result.details.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            summary
+                == vec![
+                    ("a".to_string(), true, None, None),
+                    (
+                        "b".to_string(),
+                        false,
+                        Some(OplogIndex::from_u64(5)),
+                        Some("diverged".to_string())
+                    ),
+                    (
+                        "c".to_string(),
+                        false,
+                        None,
+                        Some("Failed to replay agent: unavailable".to_string())
+                    ),
+                ]
+        );
+    }
+
+    #[test]
+    async fn agents_with_discarded_history_are_not_replayed() {
+        let component_id = ComponentId::new();
+        let replayed = Arc::new(AtomicUsize::new(0));
+
+        let results = check_candidates(
+            vec![candidate(component_id, "a", true)],
+            ComponentRevision::new(2).unwrap(),
+            1,
+            |worker_id| {
+                let replayed = replayed.clone();
+                async move {
+                    replayed.fetch_add(1, Ordering::SeqCst);
+                    Ok(AgentUpdateCheckResult {
+                        worker_id,
+                        current_revision: ComponentRevision::new(1).unwrap(),
+                        compatible: true,
+                        divergence_index: None,
+                        details: None,
+                    })
+                }
+            },
+        )
+        .await;
+
+        assert!(replayed.load(Ordering::SeqCst) == 0);
+        assert!(results.len() == 1);
+        assert!(!results[0].compatible);
+    }
+
+    #[test]
+    async fn replays_are_concurrent_up_to_the_limit() {
+        let component_id = ComponentId::new();
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let candidates = (0..10)
+            .map(|idx| candidate(component_id, &format!("agent-{idx}"), false))
+            .collect();
+
+        let results = check_candidates(
+            candidates,
+            ComponentRevision::new(2).unwrap(),
+            3,
+            |worker_id| {
+                let running = running.clone();
+                let max_running = max_running.clone();
+                async move {
+                    let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(AgentUpdateCheckResult {
+                        worker_id,
+                        current_revision: ComponentRevision::new(1).unwrap(),
+                        compatible: true,
+                        divergence_index: None,
+                        details: None,
+                    })
+                }
+            },
+        )
+        .await;
+
+        assert!(results.len() == 10);
+        assert!(max_running.load(Ordering::SeqCst) == 3);
+    }
+}
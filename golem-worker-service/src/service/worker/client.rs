@@ -25,9 +25,13 @@ use golem_api_grpc::proto::golem::worker::{InvocationContext, LogEvent};
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ActivatePluginRequest, CancelInvocationRequest, CompletePromiseRequest, ConnectWorkerRequest,
-    CreateWorkerRequest, DeactivatePluginRequest, ForkWorkerRequest, InterruptWorkerRequest,
-    ResumeWorkerRequest, RevertWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    ActivatePluginRequest, CancelInvocationRequest, CancelRecurringInvocationRequest,
+    CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest, DeactivatePluginRequest,
+    FollowOplogRequest, FollowOplogResponse, ForkWorkerRequest, GetInvocationQueueRequest,
+    InterruptWorkerRequest, ListRecurringInvocationsRequest, PublishTopicMessageRequest,
+    PurgeInvocationsRequest, ReorderInvocationRequest, ResumeWorkerRequest, RevertWorkerRequest,
+    ScheduleRecurringInvocationRequest, SearchOplogResponse, SetInvocationQueueLimitRequest,
+    UpdateWorkerRequest,
 };
 use golem_common::model::RetryConfig;
 use golem_common::model::account::AccountId;
@@ -36,14 +40,19 @@ use golem_common::model::component::{
     ComponentFilePath, ComponentId, ComponentRevision, PluginPriority,
 };
 use golem_common::model::environment::EnvironmentId;
+use golem_common::model::invocation_queue::{InvocationQueue, InvocationQueueReorder};
 use golem_common::model::oplog::{OplogCursor, PublicOplogEntry};
 use golem_common::model::oplog::{OplogIndex, PublicOplogEntryWithIndex};
+use golem_common::model::recurring_invocation::{
+    MissedFirePolicy, RecurringInvocation, RecurringInvocationId, RecurringSchedule,
+};
 use golem_common::model::worker::WorkerCreationLocalAgentConfigEntry;
 use golem_common::model::worker::WorkerUpdateMode;
 use golem_common::model::worker::{RevertWorkerTarget, WorkerMetadataDto};
 use golem_common::model::{AgentInvocationOutput, AgentInvocationResult};
 use golem_common::model::{
-    FilterComparator, IdempotencyKey, PromiseId, ScanCursor, WorkerFilter, WorkerId, WorkerStatus,
+    FilterComparator, IdempotencyKey, PromiseId, RoutingTable, ScanCursor, WorkerFilter, WorkerId,
+    WorkerStatus,
 };
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use golem_service_base::grpc::client::MultiTargetGrpcClient;
@@ -57,6 +66,10 @@ use tonic::Code;
 use tonic::transport::Channel;
 use tonic_tracing_opentelemetry::middleware::client::OtelGrpcService;
 
+/// Stream of oplog entry batches, as they get committed to a followed worker's oplog
+pub type OplogEntryBatchStream =
+    Pin<Box<dyn Stream<Item = WorkerResult<Vec<PublicOplogEntryWithIndex>>> + Send + 'static>>;
+
 #[async_trait]
 pub trait WorkerClient: Send + Sync {
     async fn create(
@@ -93,6 +106,7 @@ pub trait WorkerClient: Send + Sync {
         worker_id: &WorkerId,
         oplog_id: u64,
         data: Vec<u8>,
+        failed: bool,
         environment_id: EnvironmentId,
         auth_ctx: AuthCtx,
     ) -> WorkerResult<bool>;
@@ -161,6 +175,14 @@ pub trait WorkerClient: Send + Sync {
         auth_ctx: AuthCtx,
     ) -> Result<GetOplogResponse, WorkerServiceError>;
 
+    async fn follow_oplog(
+        &self,
+        worker_id: &WorkerId,
+        from_oplog_index: OplogIndex,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<OplogEntryBatchStream>;
+
     async fn get_file_system_node(
         &self,
         worker_id: &WorkerId,
@@ -221,12 +243,72 @@ pub trait WorkerClient: Send + Sync {
         auth_ctx: AuthCtx,
     ) -> WorkerResult<bool>;
 
+    async fn schedule_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        method_name: String,
+        method_parameters: golem_api_grpc::proto::golem::component::UntypedDataValue,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+        environment_id: EnvironmentId,
+        account_id: AccountId,
+        auth_ctx: AuthCtx,
+        principal: golem_api_grpc::proto::golem::component::Principal,
+    ) -> WorkerResult<RecurringInvocation>;
+
+    async fn list_recurring_invocations(
+        &self,
+        worker_id: &WorkerId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<Vec<RecurringInvocation>>;
+
+    async fn cancel_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        id: RecurringInvocationId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<bool>;
+
+    async fn get_invocation_queue(
+        &self,
+        worker_id: &WorkerId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue>;
+
+    async fn reorder_invocation(
+        &self,
+        worker_id: &WorkerId,
+        reorder: InvocationQueueReorder,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue>;
+
+    async fn purge_invocations(
+        &self,
+        worker_id: &WorkerId,
+        method_name: String,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<u64>;
+
+    async fn set_invocation_queue_limit(
+        &self,
+        worker_id: &WorkerId,
+        limit: Option<u64>,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue>;
+
     async fn invoke_agent(
         &self,
         worker_id: &WorkerId,
         method_name: String,
         method_parameters: golem_api_grpc::proto::golem::component::UntypedDataValue,
         mode: i32,
+        priority: i32,
         schedule_at: Option<::prost_types::Timestamp>,
         idempotency_key: Option<IdempotencyKey>,
         invocation_context: Option<InvocationContext>,
@@ -235,6 +317,15 @@ pub trait WorkerClient: Send + Sync {
         auth_ctx: AuthCtx,
         principal: golem_api_grpc::proto::golem::component::Principal,
     ) -> WorkerResult<AgentInvocationOutput>;
+
+    async fn publish_topic_message(
+        &self,
+        topic: String,
+        message: golem_api_grpc::proto::golem::component::UntypedDataValue,
+        environment_id: EnvironmentId,
+    ) -> WorkerResult<u64>;
+
+    async fn get_routing_table(&self) -> WorkerResult<RoutingTable>;
 }
 
 #[derive(Clone)]
@@ -535,6 +626,7 @@ impl WorkerClient for WorkerExecutorWorkerClient {
         worker_id: &WorkerId,
         oplog_id: u64,
         data: Vec<u8>,
+        failed: bool,
         environment_id: EnvironmentId,
         auth_ctx: AuthCtx,
     ) -> WorkerResult<bool> {
@@ -556,7 +648,8 @@ impl WorkerClient for WorkerExecutorWorkerClient {
                                 promise_id: Some(promise_id.into()),
                                 data,
                                 environment_id: Some(environment_id.into()),
-                                auth_ctx: Some(auth_ctx.clone().into())
+                                auth_ctx: Some(auth_ctx.clone().into()),
+                                failed,
                             })
                     )
                 },
@@ -911,6 +1004,70 @@ impl WorkerClient for WorkerExecutorWorkerClient {
             .await
     }
 
+    async fn follow_oplog(
+        &self,
+        worker_id: &WorkerId,
+        from_oplog_index: OplogIndex,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<OplogEntryBatchStream> {
+        let worker_id = worker_id.clone();
+        let stream = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "follow_oplog",
+                move |worker_executor_client| {
+                    Box::pin(worker_executor_client.follow_oplog(FollowOplogRequest {
+                        worker_id: Some(worker_id.clone().into()),
+                        from_oplog_index: from_oplog_index.into(),
+                        environment_id: Some(environment_id.into()),
+                        auth_ctx: Some(auth_ctx.clone().into()),
+                    }))
+                },
+                |response| Ok(WorkerStream::new(response.into_inner())),
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+
+        fn to_batch(
+            item: Result<FollowOplogResponse, tonic::Status>,
+        ) -> WorkerResult<Vec<PublicOplogEntryWithIndex>> {
+            let response =
+                item.map_err(|_| WorkerServiceError::Internal("Stream error".to_string()))?;
+            match response.result {
+                Some(workerexecutor::v1::follow_oplog_response::Result::Success(success)) => {
+                    success
+                        .entries
+                        .into_iter()
+                        .map(|entry| entry.try_into())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| {
+                            WorkerServiceError::Internal(format!(
+                                "Unexpected oplog entries in response: {err}"
+                            ))
+                        })
+                }
+                Some(workerexecutor::v1::follow_oplog_response::Result::Failure(err)) => {
+                    let converted = WorkerExecutorError::try_from(err).map_err(|err| {
+                        WorkerServiceError::Internal(format!("Failed converting errors {err}"))
+                    })?;
+                    Err(converted.into())
+                }
+                None => Err(WorkerServiceError::Internal("Empty response".to_string())),
+            }
+        }
+
+        // The executor always sends the first batch immediately, so failures of setting up
+        // the stream can be reported as errors of the call itself
+        let (first, stream) = stream.into_future().await;
+        let first =
+            to_batch(first.ok_or(WorkerServiceError::Internal("Empty stream".to_string()))?)?;
+
+        Ok(Box::pin(
+            futures::stream::iter(Some(Ok(first))).chain(stream.map(to_batch)),
+        ))
+    }
+
     async fn get_file_system_node(
         &self,
         worker_id: &WorkerId,
@@ -1266,12 +1423,306 @@ impl WorkerClient for WorkerExecutorWorkerClient {
         Ok(canceled)
     }
 
+    async fn schedule_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        method_name: String,
+        method_parameters: golem_api_grpc::proto::golem::component::UntypedDataValue,
+        schedule: RecurringSchedule,
+        missed_fire_policy: MissedFirePolicy,
+        environment_id: EnvironmentId,
+        account_id: AccountId,
+        auth_ctx: AuthCtx,
+        principal: golem_api_grpc::proto::golem::component::Principal,
+    ) -> WorkerResult<RecurringInvocation> {
+        let worker_id = worker_id.clone();
+        let invocation = self.call_worker_executor(
+            worker_id.clone(),
+            "schedule_recurring_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.schedule_recurring_invocation(
+                    ScheduleRecurringInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        method_name: method_name.clone(),
+                        method_parameters: Some(method_parameters.clone()),
+                        schedule: Some(schedule.clone().into()),
+                        missed_fire_policy: golem_api_grpc::proto::golem::worker::MissedFirePolicy::from(missed_fire_policy) as i32,
+                        component_owner_account_id: Some(account_id.into()),
+                        environment_id: Some(environment_id.into()),
+                        auth_ctx: Some(auth_ctx.clone().into()),
+                        principal: Some(principal.clone()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ScheduleRecurringInvocationResponse {
+                    result: Some(workerexecutor::v1::schedule_recurring_invocation_response::Result::Success(invocation)),
+                } => invocation
+                    .try_into()
+                    .map_err(|_| "Failed to convert recurring invocation".into()),
+                workerexecutor::v1::ScheduleRecurringInvocationResponse {
+                    result: Some(workerexecutor::v1::schedule_recurring_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ScheduleRecurringInvocationResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(invocation)
+    }
+
+    async fn list_recurring_invocations(
+        &self,
+        worker_id: &WorkerId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<Vec<RecurringInvocation>> {
+        let worker_id = worker_id.clone();
+        let invocations = self.call_worker_executor(
+            worker_id.clone(),
+            "list_recurring_invocations",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.list_recurring_invocations(
+                    ListRecurringInvocationsRequest {
+                        worker_id: Some(worker_id.into()),
+                        environment_id: Some(environment_id.into()),
+                        auth_ctx: Some(auth_ctx.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ListRecurringInvocationsResponse {
+                    result: Some(workerexecutor::v1::list_recurring_invocations_response::Result::Success(success)),
+                } => success
+                    .invocations
+                    .into_iter()
+                    .map(|invocation| {
+                        invocation
+                            .try_into()
+                            .map_err(|_| "Failed to convert recurring invocation".into())
+                    })
+                    .collect::<Result<Vec<_>, _>>(),
+                workerexecutor::v1::ListRecurringInvocationsResponse {
+                    result: Some(workerexecutor::v1::list_recurring_invocations_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ListRecurringInvocationsResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(invocations)
+    }
+
+    async fn cancel_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        id: RecurringInvocationId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<bool> {
+        let worker_id = worker_id.clone();
+        let canceled = self.call_worker_executor(
+            worker_id.clone(),
+            "cancel_recurring_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.cancel_recurring_invocation(
+                    CancelRecurringInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        id: Some(id.into()),
+                        environment_id: Some(environment_id.into()),
+                        auth_ctx: Some(auth_ctx.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::CancelRecurringInvocationResponse {
+                    result: Some(workerexecutor::v1::cancel_recurring_invocation_response::Result::Success(canceled)),
+                } => Ok(canceled),
+                workerexecutor::v1::CancelRecurringInvocationResponse {
+                    result: Some(workerexecutor::v1::cancel_recurring_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::CancelRecurringInvocationResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(canceled)
+    }
+
+    async fn get_invocation_queue(
+        &self,
+        worker_id: &WorkerId,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let worker_id = worker_id.clone();
+        let result = self
+            .call_worker_executor(
+                worker_id.clone(),
+                "get_invocation_queue",
+                move |worker_executor_client| {
+                    let worker_id = worker_id.clone();
+                    Box::pin(worker_executor_client.get_invocation_queue(
+                        GetInvocationQueueRequest {
+                            worker_id: Some(worker_id.into()),
+                            environment_id: Some(environment_id.into()),
+                            auth_ctx: Some(auth_ctx.clone().into()),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::GetInvocationQueueResponse {
+                        result:
+                            Some(workerexecutor::v1::get_invocation_queue_response::Result::Success(
+                                result,
+                            )),
+                    } => result
+                        .try_into()
+                        .map_err(|_| "Failed to convert invocation queue".into()),
+                    workerexecutor::v1::GetInvocationQueueResponse {
+                        result:
+                            Some(workerexecutor::v1::get_invocation_queue_response::Result::Failure(
+                                err,
+                            )),
+                    } => Err(err.into()),
+                    workerexecutor::v1::GetInvocationQueueResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+        Ok(result)
+    }
+
+    async fn reorder_invocation(
+        &self,
+        worker_id: &WorkerId,
+        reorder: InvocationQueueReorder,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let worker_id = worker_id.clone();
+        let result = self.call_worker_executor(
+            worker_id.clone(),
+            "reorder_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let reorder = reorder.clone();
+                Box::pin(worker_executor_client.reorder_invocation(ReorderInvocationRequest {
+                    worker_id: Some(worker_id.into()),
+                    idempotency_key: Some(reorder.idempotency_key.into()),
+                    priority: reorder.priority.map(|priority| {
+                        golem_api_grpc::proto::golem::worker::InvocationPriority::from(priority)
+                            as i32
+                    }),
+                    position: reorder.position,
+                    principal: None,
+                    environment_id: Some(environment_id.into()),
+                    auth_ctx: Some(auth_ctx.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ReorderInvocationResponse {
+                    result: Some(workerexecutor::v1::reorder_invocation_response::Result::Success(result)),
+                } => result
+                    .try_into()
+                    .map_err(|_| "Failed to convert invocation queue".into()),
+                workerexecutor::v1::ReorderInvocationResponse {
+                    result: Some(workerexecutor::v1::reorder_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ReorderInvocationResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(result)
+    }
+
+    async fn purge_invocations(
+        &self,
+        worker_id: &WorkerId,
+        method_name: String,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<u64> {
+        let worker_id = worker_id.clone();
+        let result = self.call_worker_executor(
+            worker_id.clone(),
+            "purge_invocations",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.purge_invocations(PurgeInvocationsRequest {
+                    worker_id: Some(worker_id.into()),
+                    method_name: method_name.clone(),
+                    principal: None,
+                    environment_id: Some(environment_id.into()),
+                    auth_ctx: Some(auth_ctx.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::PurgeInvocationsResponse {
+                    result: Some(workerexecutor::v1::purge_invocations_response::Result::Success(result)),
+                } => Ok(result),
+                workerexecutor::v1::PurgeInvocationsResponse {
+                    result: Some(workerexecutor::v1::purge_invocations_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::PurgeInvocationsResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(result)
+    }
+
+    async fn set_invocation_queue_limit(
+        &self,
+        worker_id: &WorkerId,
+        limit: Option<u64>,
+        environment_id: EnvironmentId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let worker_id = worker_id.clone();
+        let result = self.call_worker_executor(
+            worker_id.clone(),
+            "set_invocation_queue_limit",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.set_invocation_queue_limit(SetInvocationQueueLimitRequest {
+                    worker_id: Some(worker_id.into()),
+                    limit,
+                    principal: None,
+                    environment_id: Some(environment_id.into()),
+                    auth_ctx: Some(auth_ctx.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::SetInvocationQueueLimitResponse {
+                    result: Some(workerexecutor::v1::set_invocation_queue_limit_response::Result::Success(result)),
+                } => result
+                    .try_into()
+                    .map_err(|_| "Failed to convert invocation queue".into()),
+                workerexecutor::v1::SetInvocationQueueLimitResponse {
+                    result: Some(workerexecutor::v1::set_invocation_queue_limit_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::SetInvocationQueueLimitResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(result)
+    }
+
     async fn invoke_agent(
         &self,
         worker_id: &WorkerId,
         method_name: String,
         method_parameters: golem_api_grpc::proto::golem::component::UntypedDataValue,
         mode: i32,
+        priority: i32,
         schedule_at: Option<::prost_types::Timestamp>,
         idempotency_key: Option<IdempotencyKey>,
         invocation_context: Option<InvocationContext>,
@@ -1296,6 +1747,7 @@ impl WorkerClient for WorkerExecutorWorkerClient {
                             mode,
                             schedule_at,
                             idempotency_key: idempotency_key.clone().map(|k| k.into()),
+                            priority,
                             component_owner_account_id: Some(account_id.into()),
                             environment_id: Some(environment_id.into()),
                             auth_ctx: Some(auth_ctx.clone().into()),
@@ -1344,6 +1796,60 @@ impl WorkerClient for WorkerExecutorWorkerClient {
 
         Ok(result)
     }
+
+    async fn publish_topic_message(
+        &self,
+        topic: String,
+        message: golem_api_grpc::proto::golem::component::UntypedDataValue,
+        environment_id: EnvironmentId,
+    ) -> WorkerResult<u64> {
+        let result = self
+            .call_worker_executor(
+                RandomExecutor,
+                "publish_topic_message",
+                move |worker_executor_client| {
+                    Box::pin(worker_executor_client.publish_topic_message(
+                        PublishTopicMessageRequest {
+                            environment_id: Some(environment_id.into()),
+                            topic: topic.clone(),
+                            message: Some(message.clone()),
+                        },
+                    ))
+                },
+                |response| match response.into_inner() {
+                    workerexecutor::v1::PublishTopicMessageResponse {
+                        result:
+                            Some(workerexecutor::v1::publish_topic_message_response::Result::Success(
+                                offset,
+                            )),
+                    } => Ok(offset),
+                    workerexecutor::v1::PublishTopicMessageResponse {
+                        result:
+                            Some(workerexecutor::v1::publish_topic_message_response::Result::Failure(
+                                err,
+                            )),
+                    } => Err(err.into()),
+                    workerexecutor::v1::PublishTopicMessageResponse { .. } => {
+                        Err("Empty response".into())
+                    }
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn get_routing_table(&self) -> WorkerResult<RoutingTable> {
+        self.routing_table_service
+            .get_routing_table()
+            .await
+            .map_err(|error| {
+                WorkerServiceError::InternalCallError(
+                    CallWorkerExecutorError::FailedToGetRoutingTable(error),
+                )
+            })
+    }
 }
 
 fn is_filter_with_running_status(filter: &WorkerFilter) -> bool {
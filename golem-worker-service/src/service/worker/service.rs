@@ -13,9 +13,9 @@
 // limitations under the License.
 
 use super::WorkerResult;
-use super::{ConnectWorkerStream, WorkerClient, WorkerServiceError};
+use super::{ConnectWorkerStream, OplogEntryBatchStream, WorkerClient, WorkerServiceError};
 use crate::api::agents::{AgentInvocationMode, AgentInvocationRequest, AgentInvocationResult};
-use crate::service::auth::AuthService;
+use crate::service::auth::{AuthService, AuthServiceError};
 use crate::service::component::ComponentService;
 use crate::service::limit::LimitService;
 use bytes::Bytes;
@@ -29,14 +29,21 @@ use golem_common::model::component::{
     ComponentFilePath, ComponentId, ComponentRevision, PluginPriority,
 };
 use golem_common::model::deployment::DeploymentRevision;
+use golem_common::model::environment::EnvironmentId;
+use golem_common::model::executor::ExecutorsSummary;
+use golem_common::model::invocation_queue::{InvocationQueue, InvocationQueueReorder};
 use golem_common::model::oplog::OplogCursor;
 use golem_common::model::oplog::OplogIndex;
+use golem_common::model::pubsub::{TopicMessagePublication, TopicMessagePublished};
+use golem_common::model::recurring_invocation::{
+    RecurringInvocation, RecurringInvocationCreation, RecurringInvocationId,
+};
 use golem_common::model::worker::WorkerCreationLocalAgentConfigEntry;
 use golem_common::model::worker::WorkerUpdateMode;
 use golem_common::model::worker::{RevertWorkerTarget, WorkerMetadataDto};
 use golem_common::model::{IdempotencyKey, ScanCursor, WorkerFilter, WorkerId};
 use golem_service_base::clients::registry::RegistryService;
-use golem_service_base::model::auth::{AuthCtx, EnvironmentAction};
+use golem_service_base::model::auth::{AuthCtx, EnvironmentAction, GlobalAction};
 use golem_service_base::model::component::Component;
 use golem_service_base::model::{ComponentFileSystemNode, GetOplogResponse};
 use std::collections::BTreeMap;
@@ -228,6 +235,7 @@ impl WorkerService {
         worker_id: &WorkerId,
         oplog_id: u64,
         data: Vec<u8>,
+        failed: bool,
         auth_ctx: AuthCtx,
     ) -> WorkerResult<bool> {
         let component = self
@@ -249,6 +257,7 @@ impl WorkerService {
                 worker_id,
                 oplog_id,
                 data,
+                failed,
                 component.environment_id,
                 auth_ctx,
             )
@@ -485,6 +494,35 @@ impl WorkerService {
         Ok(result)
     }
 
+    pub async fn follow_oplog(
+        &self,
+        worker_id: &WorkerId,
+        from_oplog_index: OplogIndex,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<OplogEntryBatchStream> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::ViewWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .follow_oplog(
+                worker_id,
+                from_oplog_index,
+                component.environment_id,
+                auth_ctx,
+            )
+            .await
+    }
+
     pub async fn get_file_system_node(
         &self,
         worker_id: &WorkerId,
@@ -708,12 +746,272 @@ impl WorkerService {
         Ok(canceled)
     }
 
+    /// Creates a recurring invocation of an agent method. The agent type is determined by the
+    /// worker's name, and the JSON method parameters are type checked against the method's schema.
+    pub async fn schedule_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        creation: RecurringInvocationCreation,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<RecurringInvocation> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        let environment_auth_details = self
+            .auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        let agent_type_name = AgentId::parse_agent_type_name(&worker_id.worker_name)
+            .map_err(|err| WorkerServiceError::TypeChecker(format!("Invalid agent id: {err}")))?;
+
+        let agent_type = component
+            .metadata
+            .find_agent_type_by_wrapper_name(&agent_type_name)
+            .map_err(|err| {
+                WorkerServiceError::Internal(format!(
+                    "Cannot get agent type {agent_type_name} from component metadata: {err}"
+                ))
+            })?
+            .ok_or_else(|| {
+                WorkerServiceError::Internal(format!(
+                    "Agent type {agent_type_name} not found in component metadata"
+                ))
+            })?;
+
+        let method = agent_type
+            .methods
+            .iter()
+            .find(|m| m.name == creation.method_name)
+            .ok_or_else(|| {
+                WorkerServiceError::TypeChecker(format!(
+                    "Agent method {} not found in agent type {agent_type_name}",
+                    creation.method_name
+                ))
+            })?;
+
+        let method_parameters: DataValue = DataValue::try_from_untyped_json(
+            creation.method_parameters,
+            method.input_schema.clone(),
+        )
+        .map_err(|err| {
+            WorkerServiceError::TypeChecker(format!("Agent method parameters type error: {err}"))
+        })?;
+
+        let principal: golem_api_grpc::proto::golem::component::Principal =
+            Principal::GolemUser(GolemUserPrincipal {
+                account_id: auth_ctx.account_id(),
+            })
+            .into();
+
+        self.worker_client
+            .schedule_recurring_invocation(
+                worker_id,
+                creation.method_name,
+                UntypedDataValue::from(method_parameters).into(),
+                creation.schedule,
+                creation.missed_fire_policy.unwrap_or_default(),
+                component.environment_id,
+                environment_auth_details.account_id_owning_environment,
+                auth_ctx,
+                principal,
+            )
+            .await
+    }
+
+    pub async fn list_recurring_invocations(
+        &self,
+        worker_id: &WorkerId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<Vec<RecurringInvocation>> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::ViewWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .list_recurring_invocations(worker_id, component.environment_id, auth_ctx)
+            .await
+    }
+
+    pub async fn cancel_recurring_invocation(
+        &self,
+        worker_id: &WorkerId,
+        id: RecurringInvocationId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<bool> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .cancel_recurring_invocation(worker_id, id, component.environment_id, auth_ctx)
+            .await
+    }
+
+    pub async fn get_invocation_queue(
+        &self,
+        worker_id: &WorkerId,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::ViewWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .get_invocation_queue(worker_id, component.environment_id, auth_ctx)
+            .await
+    }
+
+    pub async fn reorder_invocation(
+        &self,
+        worker_id: &WorkerId,
+        reorder: InvocationQueueReorder,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .reorder_invocation(worker_id, reorder, component.environment_id, auth_ctx)
+            .await
+    }
+
+    pub async fn purge_invocations(
+        &self,
+        worker_id: &WorkerId,
+        method_name: String,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<u64> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .purge_invocations(worker_id, method_name, component.environment_id, auth_ctx)
+            .await
+    }
+
+    pub async fn set_invocation_queue_limit(
+        &self,
+        worker_id: &WorkerId,
+        limit: Option<u64>,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<InvocationQueue> {
+        let component = self
+            .component_service
+            .get_latest_by_id(worker_id.component_id)
+            .await?;
+
+        self.auth_service
+            .authorize_environment_actions(
+                component.environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        self.worker_client
+            .set_invocation_queue_limit(worker_id, limit, component.environment_id, auth_ctx)
+            .await
+    }
+
+    /// Publishes a message to a topic of an environment, to be delivered to the subscribed agents
+    pub async fn publish_topic_message(
+        &self,
+        environment_id: EnvironmentId,
+        topic: String,
+        publication: TopicMessagePublication,
+        auth_ctx: AuthCtx,
+    ) -> WorkerResult<TopicMessagePublished> {
+        self.auth_service
+            .authorize_environment_actions(
+                environment_id,
+                EnvironmentAction::UpdateWorker,
+                &auth_ctx,
+            )
+            .await?;
+
+        let offset = self
+            .worker_client
+            .publish_topic_message(
+                topic,
+                UntypedDataValue::from(publication.message).into(),
+                environment_id,
+            )
+            .await?;
+
+        Ok(TopicMessagePublished { offset })
+    }
+
+    pub async fn get_executors(&self, auth_ctx: AuthCtx) -> WorkerResult<ExecutorsSummary> {
+        auth_ctx
+            .authorize_global_action(GlobalAction::ViewExecutors)
+            .map_err(AuthServiceError::from)?;
+
+        let routing_table = self.worker_client.get_routing_table().await?;
+
+        Ok(ExecutorsSummary::from(&routing_table))
+    }
+
     pub async fn invoke_agent(
         &self,
         worker_id: &WorkerId,
         method_name: String,
         method_parameters: golem_api_grpc::proto::golem::component::UntypedDataValue,
         mode: i32,
+        priority: i32,
         schedule_at: Option<::prost_types::Timestamp>,
         idempotency_key: Option<IdempotencyKey>,
         invocation_context: Option<InvocationContext>,
@@ -740,6 +1038,7 @@ impl WorkerService {
                 method_name,
                 method_parameters,
                 mode,
+                priority,
                 schedule_at,
                 idempotency_key,
                 invocation_context,
@@ -861,6 +1160,10 @@ impl WorkerService {
             }
         };
 
+        let proto_priority = golem_api_grpc::proto::golem::worker::InvocationPriority::from(
+            request.priority.unwrap_or_default(),
+        ) as i32;
+
         let proto_schedule_at = request.schedule_at.map(|dt| ::prost_types::Timestamp {
             seconds: dt.timestamp(),
             nanos: dt.timestamp_subsec_nanos() as i32,
@@ -881,6 +1184,7 @@ impl WorkerService {
                 request.method_name,
                 proto_method_parameters,
                 proto_mode,
+                proto_priority,
                 proto_schedule_at,
                 request.idempotency_key,
                 None,
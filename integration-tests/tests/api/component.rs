@@ -191,6 +191,7 @@ async fn component_update_with_wrong_revision_is_rejected(
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: Vec::new(),
             },
@@ -315,6 +316,7 @@ async fn create_component_with_plugins_and_update_installations(
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: vec![PluginInstallationAction::Update(PluginInstallationUpdate {
                     environment_plugin_grant_id: installed_plugin.environment_plugin_grant_id,
@@ -344,6 +346,7 @@ async fn create_component_with_plugins_and_update_installations(
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: vec![PluginInstallationAction::Uninstall(PluginUninstallation {
                     environment_plugin_grant_id: installed_plugin.environment_plugin_grant_id,
@@ -411,6 +414,7 @@ async fn update_component_with_plugin(deps: &EnvBasedTestDependencies) -> anyhow
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: vec![PluginInstallationAction::Install(PluginInstallation {
                     environment_plugin_grant_id: oplog_plugin_grant.id,
@@ -455,6 +459,7 @@ async fn create_component_with_ifs_files(deps: &EnvBasedTestDependencies) -> any
                 env: BTreeMap::new(),
                 config_vars: BTreeMap::new(),
                 local_agent_config: Vec::new(),
+                agent_resource_limits: Vec::new(),
                 agent_types: Vec::new(),
                 plugins: Vec::new(),
             },
@@ -555,6 +560,7 @@ async fn list_agent_types(deps: &EnvBasedTestDependencies) -> anyhow::Result<()>
                 }],
             }),
             http_endpoint: Vec::new(),
+            read_only: false,
         }],
         dependencies: vec![],
         mode: AgentMode::Durable,
@@ -572,6 +578,7 @@ async fn list_agent_types(deps: &EnvBasedTestDependencies) -> anyhow::Result<()>
                 env: BTreeMap::new(),
                 config_vars: BTreeMap::new(),
                 local_agent_config: Vec::new(),
+                agent_resource_limits: Vec::new(),
                 agent_types: vec![agent_type.clone()],
                 plugins: Vec::new(),
             },
@@ -682,6 +689,7 @@ async fn create_component_with_duplicate_plugin_priorities_fails(
                 env: BTreeMap::new(),
                 config_vars: BTreeMap::new(),
                 local_agent_config: Vec::new(),
+                agent_resource_limits: Vec::new(),
                 agent_types: Vec::new(),
                 plugins: vec![
                     PluginInstallation {
@@ -761,6 +769,7 @@ async fn create_component_with_duplicate_plugin_grant_ids_fails(
                 env: BTreeMap::new(),
                 config_vars: BTreeMap::new(),
                 local_agent_config: Vec::new(),
+                agent_resource_limits: Vec::new(),
                 agent_types: Vec::new(),
                 plugins: vec![
                     PluginInstallation {
@@ -872,6 +881,7 @@ async fn update_component_with_duplicate_plugin_priorities_fails(
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: vec![
                     PluginInstallationAction::Install(PluginInstallation {
@@ -953,6 +963,7 @@ async fn update_component_with_duplicate_plugin_grant_ids_fails(
                 env: None,
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: vec![
                     PluginInstallationAction::Install(PluginInstallation {
@@ -27,7 +27,7 @@ use golem_common::model::http_api_deployment::{
     HttpApiDeploymentAgentOptions, HttpApiDeploymentCreation,
 };
 use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
-use golem_test_framework::dsl::{TestDsl, TestDslExtended};
+use golem_test_framework::dsl::{EnvironmentOptions, TestDsl, TestDslExtended};
 use pretty_assertions::{assert_eq, assert_ne};
 use std::collections::BTreeMap;
 use test_r::{inherit_test_dep, test};
@@ -122,6 +122,83 @@ async fn fail_with_409_on_hash_mismatch(deps: &EnvBasedTestDependencies) -> anyh
     Ok(())
 }
 
+#[test]
+#[tracing::instrument]
+async fn reject_deployment_with_incompatible_agent_types(
+    deps: &EnvBasedTestDependencies,
+) -> anyhow::Result<()> {
+    let user = deps.user().await?.with_auto_deploy(false);
+    let client = deps.registry_service().client(&user.token).await;
+    let (_, env) = user
+        .app_and_env_custom(&EnvironmentOptions {
+            compatibility_check: true,
+            version_check: false,
+            security_overrides: false,
+        })
+        .await?;
+
+    let component = user
+        .component(&env.id, "it_agent_counters_release")
+        .name("it:agent-counters")
+        .store()
+        .await?;
+
+    let deployment_1 = user.deploy_environment(env.id).await?;
+
+    // The new binary does not define any of the previously deployed agent types
+    user.update_component_with(
+        &component.id,
+        component.revision,
+        Some("it_agent_update_v1_release"),
+        vec![],
+        vec![],
+        None,
+        None,
+        None,
+    )
+    .await?;
+
+    let plan = client.get_environment_deployment_plan(&env.id.0).await?;
+
+    let result = client
+        .deploy_environment(
+            &env.id.0,
+            &DeploymentCreation {
+                current_revision: Some(deployment_1.current_revision),
+                expected_deployment_hash: plan.deployment_hash,
+                version: DeploymentVersion("0.0.2".to_string()),
+            },
+        )
+        .await;
+
+    let Err(golem_client::Error::Item(RegistryServiceDeployEnvironmentError::Error400(body))) =
+        result
+    else {
+        panic!("expected the deployment to be rejected, got {result:?}");
+    };
+    assert!(
+        body.errors
+            .iter()
+            .any(|error| error.contains("agent type removed")),
+        "unexpected errors: {:?}",
+        body.errors
+    );
+
+    // The previous deployment stays current
+    {
+        let fetched_environment = client.get_environment(&env.id.0).await?;
+        let Some(current_deployment) = fetched_environment.current_deployment else {
+            panic!("expected current_deployment to be Some");
+        };
+        assert_eq!(
+            current_deployment.deployment_revision,
+            deployment_1.revision
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 #[tracing::instrument]
 async fn get_component_version_from_previous_deployment(
@@ -163,6 +240,7 @@ async fn get_component_version_from_previous_deployment(
                 )])),
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: Vec::new(),
             },
@@ -413,6 +491,7 @@ async fn filter_deployments_by_version(deps: &EnvBasedTestDependencies) -> anyho
                 )])),
                 config_vars: None,
                 local_agent_config: None,
+                agent_resource_limits: None,
                 agent_types: None,
                 plugin_updates: Vec::new(),
             },
@@ -246,6 +246,7 @@ async fn cannot_create_two_environments_with_same_name(
                     compatibility_check: None,
                     version_check: None,
                     security_overrides: None,
+                    network_egress_policy: None,
                 },
             )
             .await;
@@ -292,6 +293,7 @@ async fn cannot_create_two_environments_with_same_name(
                 compatibility_check: None,
                 version_check: None,
                 security_overrides: None,
+                network_egress_policy: None,
             },
         )
         .await?;
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use axum::routing::post;
+use axum::Router;
+use base64::Engine;
 use golem_client::api::{
     RegistryServiceClient, RegistryServiceCreateEnvironmentPluginGrantError,
     RegistryServiceDeleteEnvironmentPluginGrantError, RegistryServiceGetComponentError,
@@ -20,14 +23,25 @@ use golem_client::api::{
 };
 use golem_common::model::auth::EnvironmentRole;
 use golem_common::model::base64::Base64;
-use golem_common::model::environment_plugin_grant::EnvironmentPluginGrantCreation;
+use golem_common::model::component::{
+    ComponentRevision, ComponentUpdate, PluginInstallation, PluginInstallationAction,
+    PluginPriority,
+};
+use golem_common::model::environment_plugin_grant::{
+    EnvironmentPluginGrantCreation, EnvironmentPluginGrantId,
+};
 use golem_common::model::plugin_registration::{
-    OplogProcessorPluginSpec, PluginRegistrationCreation, PluginSpecDto,
+    ComponentTransformerPluginSpec, OplogProcessorPluginSpec, PluginRegistrationCreation,
+    PluginSpecDto,
 };
 use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
 use golem_test_framework::dsl::{TestDsl, TestDslExtended};
-use pretty_assertions::assert_eq;
+use pretty_assertions::{assert_eq, assert_ne};
+use serde_json::json;
+use std::collections::BTreeMap;
 use test_r::{inherit_test_dep, test};
+use tokio::task::JoinHandle;
+use tracing::Instrument;
 
 inherit_test_dep!(EnvBasedTestDependencies);
 
@@ -776,3 +790,187 @@ async fn revoked_user_cannot_fetch_grant(deps: &EnvBasedTestDependencies) -> any
 
     Ok(())
 }
+
+#[test]
+#[tracing::instrument]
+async fn deleting_grant_removes_component_transformation(
+    deps: &EnvBasedTestDependencies,
+) -> anyhow::Result<()> {
+    let user = deps.user().await?;
+    let client = user.registry_service_client().await;
+    let (_, env) = user.app_and_env().await?;
+
+    let (transform_url, transformer) = start_component_transformer(deps).await?;
+
+    let plugin = client
+        .create_plugin(
+            &user.account_id.0,
+            &component_transformer_plugin_creation(transform_url),
+        )
+        .await?;
+    let plugin_grant = client
+        .create_environment_plugin_grant(
+            &env.id.0,
+            &EnvironmentPluginGrantCreation {
+                plugin_registration_id: plugin.id,
+            },
+        )
+        .await?;
+
+    let component = user
+        .component(&env.id, "it_agent_counters_release")
+        .store()
+        .await?;
+    let transformed = client
+        .update_component(
+            &component.id.0,
+            &install_plugin_update(component.revision, plugin_grant.id),
+            None::<Vec<u8>>,
+            None::<Vec<u8>>,
+        )
+        .await?;
+    assert_eq!(transformed.transformations.len(), 1);
+    assert_eq!(
+        transformed.transformations[0].environment_plugin_grant_id,
+        plugin_grant.id
+    );
+
+    client
+        .delete_environment_plugin_grant(&plugin_grant.id.0)
+        .await?;
+
+    let latest = client.get_component(&component.id.0).await?;
+    transformer.abort();
+
+    assert_ne!(latest.revision, transformed.revision);
+    assert!(latest.transformations.is_empty());
+    assert_eq!(latest.installed_plugins.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+async fn unregistering_plugin_removes_component_transformation_in_granted_envs(
+    deps: &EnvBasedTestDependencies,
+) -> anyhow::Result<()> {
+    let plugin_owner = deps.user().await?;
+    let plugin_owner_client = plugin_owner.registry_service_client().await;
+
+    let user = deps.user().await?;
+    let client = user.registry_service_client().await;
+    let (_, env) = user.app_and_env().await?;
+    let environment_share = user
+        .share_environment(&env.id, &plugin_owner.account_id, &[EnvironmentRole::Admin])
+        .await?;
+
+    let (transform_url, transformer) = start_component_transformer(deps).await?;
+
+    let plugin = plugin_owner_client
+        .create_plugin(
+            &plugin_owner.account_id.0,
+            &component_transformer_plugin_creation(transform_url),
+        )
+        .await?;
+    let plugin_grant = plugin_owner_client
+        .create_environment_plugin_grant(
+            &env.id.0,
+            &EnvironmentPluginGrantCreation {
+                plugin_registration_id: plugin.id,
+            },
+        )
+        .await?;
+
+    let component = user
+        .component(&env.id, "it_agent_counters_release")
+        .store()
+        .await?;
+    let transformed = client
+        .update_component(
+            &component.id.0,
+            &install_plugin_update(component.revision, plugin_grant.id),
+            None::<Vec<u8>>,
+            None::<Vec<u8>>,
+        )
+        .await?;
+    assert_eq!(transformed.transformations.len(), 1);
+
+    // the plugin owner losing access to the environment does not prevent the cleanup
+    client
+        .delete_environment_share(&environment_share.id.0, environment_share.revision.into())
+        .await?;
+
+    plugin_owner_client.delete_plugin(&plugin.id.0).await?;
+
+    let latest = client.get_component(&component.id.0).await?;
+    transformer.abort();
+
+    assert_ne!(latest.revision, transformed.revision);
+    assert!(latest.transformations.is_empty());
+
+    Ok(())
+}
+
+/// Starts a component transformer endpoint which responds with the unmodified counter component
+async fn start_component_transformer(
+    deps: &EnvBasedTestDependencies,
+) -> anyhow::Result<(String, JoinHandle<()>)> {
+    let wasm = tokio::fs::read(
+        deps.component_directory()
+            .join("it_agent_counters_release.wasm"),
+    )
+    .await?;
+    let response = json!({ "data": base64::engine::general_purpose::STANDARD.encode(wasm) });
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let port = listener.local_addr()?.port();
+
+    let handle = tokio::spawn(
+        async move {
+            let route = Router::new().route(
+                "/transform",
+                post(move || async move { axum::Json(response) }),
+            );
+            axum::serve(listener, route).await.unwrap();
+        }
+        .in_current_span(),
+    );
+
+    Ok((format!("http://127.0.0.1:{port}/transform"), handle))
+}
+
+fn component_transformer_plugin_creation(transform_url: String) -> PluginRegistrationCreation {
+    PluginRegistrationCreation {
+        name: "test-component-transformer".to_string(),
+        version: "1.0.0".to_string(),
+        description: "description".to_string(),
+        icon: Base64(Vec::new()),
+        homepage: "https://golem.cloud".to_string(),
+        spec: PluginSpecDto::ComponentTransformer(ComponentTransformerPluginSpec {
+            transform_url: Some(transform_url),
+            component_id: None,
+            component_revision: None,
+        }),
+    }
+}
+
+fn install_plugin_update(
+    current_revision: ComponentRevision,
+    environment_plugin_grant_id: EnvironmentPluginGrantId,
+) -> ComponentUpdate {
+    ComponentUpdate {
+        current_revision,
+        removed_files: Vec::new(),
+        new_file_options: BTreeMap::new(),
+        env: None,
+        config_vars: None,
+        local_agent_config: None,
+        agent_resource_limits: None,
+        agent_types: None,
+        plugin_updates: vec![PluginInstallationAction::Install(PluginInstallation {
+            environment_plugin_grant_id,
+            priority: PluginPriority(0),
+            parameters: BTreeMap::new(),
+        })],
+    }
+}
@@ -196,6 +196,7 @@ async fn invoke_agent_in_shared_environment(deps: &EnvBasedTestDependencies) ->
                 method_parameters: data_value!(5u64).into(),
                 mode: golem_client::model::AgentInvocationMode::Await,
                 schedule_at: None,
+                priority: None,
                 idempotency_key: None,
                 deployment_revision: None,
                 owner_account_email: Some(owner.account_email.0.clone()),
@@ -218,6 +219,7 @@ async fn invoke_agent_in_shared_environment(deps: &EnvBasedTestDependencies) ->
                 method_parameters: data_value!().into(),
                 mode: golem_client::model::AgentInvocationMode::Await,
                 schedule_at: None,
+                priority: None,
                 idempotency_key: None,
                 deployment_revision: None,
                 owner_account_email: Some(owner.account_email.0.clone()),
@@ -294,6 +296,7 @@ async fn invoke_agent_in_shared_environment_fails_without_share(
                 method_parameters: data_value!().into(),
                 mode: golem_client::model::AgentInvocationMode::Await,
                 schedule_at: None,
+                priority: None,
                 idempotency_key: None,
                 deployment_revision: None,
                 owner_account_email: Some(owner.account_email.0.clone()),
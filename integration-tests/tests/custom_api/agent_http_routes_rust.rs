@@ -799,6 +799,39 @@ async fn cors_get_wildcard_origin(agent: &HttpTestContext) -> anyhow::Result<()>
 #[test]
 #[tracing::instrument]
 async fn webhook_callback(agent: &HttpTestContext) -> anyhow::Result<()> {
+    let response = call_webhook_agent(agent, "test-agent", false).await?;
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body, json!({ "payload-length": 5 }));
+
+    Ok(())
+}
+
+#[test]
+#[tracing::instrument]
+async fn webhook_callback_with_failed_flag(agent: &HttpTestContext) -> anyhow::Result<()> {
+    // The webhook fails the promise instead of completing it, so the agent's legacy
+    // promise await traps instead of returning the body as a successful result
+    let response = call_webhook_agent(agent, "test-agent-failed", true).await?;
+
+    assert!(
+        response.status().is_server_error(),
+        "unexpected status: {}",
+        response.status()
+    );
+
+    Ok(())
+}
+
+/// Lets the webhook agent create a webhook and calls it back from a test server with the
+/// string "hello" as the body, optionally with the `failed=true` query parameter
+async fn call_webhook_agent(
+    agent: &HttpTestContext,
+    agent_name: &str,
+    failed: bool,
+) -> anyhow::Result<reqwest::Response> {
     use axum::{body::Bytes, routing::post, Router};
     use reqwest::Client;
     use std::sync::Arc;
@@ -831,6 +864,9 @@ async fn webhook_callback(agent: &HttpTestContext) -> anyhow::Result<()> {
                     let mut url: Url = webhook_url_str.parse().unwrap();
                     url.set_host(Some(&agent_host)).unwrap();
                     url.set_port(Some(agent_port)).unwrap();
+                    if failed {
+                        url.query_pairs_mut().append_pair("failed", "true");
+                    }
 
                     let client = Client::new();
                     let payload = serde_json::to_vec(&"hello").unwrap();
@@ -856,7 +892,7 @@ async fn webhook_callback(agent: &HttpTestContext) -> anyhow::Result<()> {
         .post(
             agent
                 .base_url
-                .join("/webhook-agents/test-agent/set-test-server-url")?,
+                .join(&format!("/webhook-agents/{agent_name}/set-test-server-url"))?,
         )
         .json(&serde_json::json!({ "test-server-url": test_server_url }))
         .send()
@@ -868,17 +904,12 @@ async fn webhook_callback(agent: &HttpTestContext) -> anyhow::Result<()> {
         .post(
             agent
                 .base_url
-                .join("/webhook-agents/test-agent/test-webhook")?,
+                .join(&format!("/webhook-agents/{agent_name}/test-webhook"))?,
         )
         .send()
         .await?;
 
-    assert_eq!(response.status(), reqwest::StatusCode::OK);
-
-    let body: serde_json::Value = response.json().await?;
-    assert_eq!(body, json!({ "payload-length": 5 }));
-
     http_server.abort();
 
-    Ok(())
+    Ok(response)
 }
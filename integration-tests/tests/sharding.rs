@@ -26,6 +26,7 @@ mod tests {
         EnvBasedTestDependencies, EnvBasedTestDependenciesConfig, TestDependencies,
     };
     use golem_test_framework::dsl::{TestDsl, TestDslExtended};
+    use golem_wasm::Value;
 
     use golem_common::model::agent::AgentId;
     use rand::prelude::*;
@@ -239,6 +240,77 @@ mod tests {
         chaos.await.unwrap();
     }
 
+    #[test]
+    #[timeout(240000)]
+    #[flaky(5)]
+    async fn invocation_interrupted_by_executor_crash_is_performed_exactly_once(
+        deps: &EnvBasedTestDependencies,
+        _tracing: &Tracing,
+    ) {
+        deps.reset(16).await;
+        let (component, agent_ids) = deps.create_component_and_start_workers(1).await;
+        let agent_id = &agent_ids[0];
+        let admin = deps.admin().await;
+
+        let before = admin
+            .invoke_and_await_agent(&component, agent_id, "increment", data_value!())
+            .await
+            .unwrap();
+        let during = admin
+            .invoke_and_await_agent_with_executor_crash(
+                &component,
+                agent_id,
+                "increment",
+                data_value!(),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap();
+        let after = admin
+            .invoke_and_await_agent(&component, agent_id, "increment", data_value!())
+            .await
+            .unwrap();
+
+        assert_eq!(before.into_return_value(), Some(Value::U32(1)));
+        assert_eq!(during.into_return_value(), Some(Value::U32(2)));
+        assert_eq!(after.into_return_value(), Some(Value::U32(3)));
+    }
+
+    #[test]
+    #[timeout(240000)]
+    #[flaky(5)]
+    async fn remote_call_interrupted_by_executor_crash_is_performed_exactly_once(
+        deps: &EnvBasedTestDependencies,
+        _tracing: &Tracing,
+    ) {
+        deps.reset(16).await;
+        let (component, agent_ids) = deps.create_component_and_start_workers(1).await;
+        let admin = deps.admin().await;
+
+        let result = admin
+            .invoke_and_await_agent_with_executor_crash(
+                &component,
+                &agent_ids[0],
+                "increment_through_rpc",
+                data_value!(),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap();
+        let inner = admin
+            .invoke_and_await_agent(
+                &component,
+                &agent_id!("counter", "sharding-test-1-inner"),
+                "increment",
+                data_value!(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.into_return_value(), Some(Value::U32(1)));
+        assert_eq!(inner.into_return_value(), Some(Value::U32(2)));
+    }
+
     async fn coordinated_scenario(
         deps: &EnvBasedTestDependencies,
         number_of_shard: usize,
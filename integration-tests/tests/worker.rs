@@ -1476,6 +1476,68 @@ async fn agent_promise_await(
     Ok(())
 }
 
+#[test]
+#[tracing::instrument]
+async fn agent_promise_await_failed(
+    deps: &EnvBasedTestDependencies,
+    _tracing: &Tracing,
+) -> anyhow::Result<()> {
+    let user = deps.user().await?;
+    let (_, env) = user.app_and_env().await?;
+
+    let component = user
+        .component(&env.id, "golem_it_agent_promise")
+        .name("golem-it:agent-promise")
+        .store()
+        .await?;
+
+    let promise_agent_id = agent_id!("promise-agent", "failed");
+    let worker = user
+        .start_agent(&component.id, promise_agent_id.clone())
+        .await?;
+
+    let result = user
+        .invoke_and_await_agent(&component, &promise_agent_id, "getPromise", data_value!())
+        .await?;
+
+    let promise_id_vat = result
+        .into_return_value_and_type()
+        .ok_or_else(|| anyhow!("expected return value"))?;
+    let promise_id =
+        PromiseId::from_value(promise_id_vat.value.clone()).map_err(|e| anyhow!("{e}"))?;
+
+    let task = {
+        let executor_clone = user.clone();
+        let agent_id_clone = promise_agent_id.clone();
+        let component_clone = component.clone();
+        tokio::spawn(
+            async move {
+                executor_clone
+                    .invoke_and_await_agent(
+                        &component_clone,
+                        &agent_id_clone,
+                        "awaitPromise",
+                        data_value!(promise_id_vat),
+                    )
+                    .await
+            }
+            .in_current_span(),
+        )
+    };
+
+    user.wait_for_status(&worker, WorkerStatus::Suspended, Duration::from_secs(10))
+        .await?;
+
+    // Fails the promise through the REST API's `failed` flag
+    user.fail_promise(&promise_id, b"rejected".to_vec()).await?;
+
+    // The agent awaits the promise with the legacy `get`, which traps for failed promises
+    let result = task.await?;
+    assert!(result.is_err(), "expected a failure, got {result:?}");
+
+    Ok(())
+}
+
 #[test]
 #[tracing::instrument]
 async fn stream_high_volume_log_output(deps: &EnvBasedTestDependencies) -> anyhow::Result<()> {
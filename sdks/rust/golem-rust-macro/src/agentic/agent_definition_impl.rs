@@ -27,7 +27,7 @@ use crate::agentic::{
     endpoint_on_static_method_error, generic_type_in_agent_method_error,
     generic_type_in_agent_return_type_error, generic_type_in_constructor_error, get_remote_client,
     invalid_static_method_in_agent_error, multiple_constructor_methods_error,
-    no_constructor_method_error,
+    mutable_read_only_method_error, no_constructor_method_error,
 };
 use proc_macro::TokenStream;
 use quote::quote;
@@ -222,6 +222,12 @@ fn get_agent_type_with_remote_client(
 
             let method_prompt_hint = extract_prompt_hint(&trait_fn.attrs).unwrap_or_default();
 
+            let method_read_only = is_read_only(&trait_fn.attrs);
+
+            if method_read_only && has_mutable_receiver(&trait_fn.sig) {
+                return Some(mutable_read_only_method_error(trait_fn.sig.ident.span()));
+            }
+
             let endpoint_details_tokens = parsed_endpoint_details.iter().map(|parsed| {
                 let method = &parsed.http_method;
                 let path = &parsed.path_suffix;
@@ -378,6 +384,7 @@ fn get_agent_type_with_remote_client(
                     input_schema: #input_schema,
                     output_schema: #output_schema,
                     http_endpoint: #endpoint_details,
+                    read_only: #method_read_only,
                 }
             })
         } else {
@@ -580,6 +587,15 @@ fn extract_prompt_hint(attrs: &[syn::Attribute]) -> Option<String> {
     extract_meta(attrs, "prompt")
 }
 
+fn is_read_only(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("read_only"))
+}
+
+fn has_mutable_receiver(sig: &syn::Signature) -> bool {
+    sig.receiver()
+        .is_some_and(|receiver| receiver.mutability.is_some() || receiver.reference.is_none())
+}
+
 fn extract_meta(attrs: &[syn::Attribute], key: &str) -> Option<String> {
     for attr in attrs {
         if attr.path().is_ident(key) {
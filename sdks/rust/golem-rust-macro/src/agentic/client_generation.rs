@@ -183,12 +183,14 @@ fn get_remote_agent_methods_info(
             let trigger_name = format_ident!("trigger_{}", method_name);
             let schedule_name = format_ident!("schedule_{}", method_name);
             let schedule_cancelable_name = format_ident!("schedule_cancelable_{}", method_name);
+            let compensation_name = format_ident!("{}_compensation", method_name);
 
             agent_method_names.extend(vec![
                 method_name.to_string(),
                 trigger_name.to_string(),
                 schedule_name.to_string(),
                 schedule_cancelable_name.to_string(),
+                compensation_name.to_string(),
             ]);
 
             Some(generate_method_code(
@@ -196,6 +198,7 @@ fn get_remote_agent_methods_info(
                 &trigger_name,
                 &schedule_name,
                 &schedule_cancelable_name,
+                &compensation_name,
                 &input_defs,
                 &input_idents,
                 &method.sig,
@@ -318,6 +321,7 @@ fn generate_method_code(
     trigger_name: &syn::Ident,
     schedule_name: &syn::Ident,
     schedule_cancelable_name: &syn::Ident,
+    compensation_name: &syn::Ident,
     input_defs: &[&syn::FnArg],
     input_idents: &[syn::Ident],
     sig: &syn::Signature,
@@ -387,6 +391,12 @@ fn generate_method_code(
                 &input
             )
         }
+
+        pub fn #compensation_name(#(#input_defs),*) -> golem_rust::agentic::Compensation<'_> {
+            #encode_input
+
+            golem_rust::agentic::Compensation::new(&self.wasm_rpc, #remote_token, input)
+        }
     }
 }
 
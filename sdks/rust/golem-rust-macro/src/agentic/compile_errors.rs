@@ -75,6 +75,13 @@ pub fn endpoint_on_constructor_method_error(span: Span) -> proc_macro2::TokenStr
     ).to_compile_error()
 }
 
+pub fn mutable_read_only_method_error(span: Span) -> proc_macro2::TokenStream {
+    syn::Error::new(
+        span,
+        "#[read_only] methods must not change the agent's state. Please ensure the method takes &self instead of &mut self as the first parameter."
+    ).to_compile_error()
+}
+
 pub fn invalid_static_method_in_agent_error(
     span: Span,
     method_name: &str,
@@ -164,17 +164,28 @@ impl_component_model_config_leaf![
     NonZeroU32,
     NonZeroU64,
     uuid::Uuid,
+    crate::bindings::golem::api::host::AgentActivePluginFilter,
     crate::bindings::golem::api::host::AgentAllFilter,
     crate::bindings::golem::api::host::AgentAnyFilter,
     crate::bindings::golem::api::host::AgentConfigVarsFilter,
     crate::bindings::golem::api::host::AgentCreatedAtFilter,
     crate::bindings::golem::api::host::AgentEnvFilter,
+    crate::bindings::golem::api::host::AgentLastErrorFilter,
+    crate::bindings::golem::api::host::AgentLastInvocationAtFilter,
+    crate::bindings::golem::api::host::AgentMemorySizeFilter,
     crate::bindings::golem::api::host::AgentMetadata,
     crate::bindings::golem::api::host::AgentNameFilter,
+    crate::bindings::golem::api::host::AgentParametersFilter,
+    crate::bindings::golem::api::host::AgentPendingInvocationCountFilter,
     crate::bindings::golem::api::host::AgentPropertyFilter,
+    crate::bindings::golem::api::host::AgentRetryCountFilter,
     crate::bindings::golem::api::host::AgentStatus,
     crate::bindings::golem::api::host::AgentStatusFilter,
+    crate::bindings::golem::api::host::AgentTypeFilter,
     crate::bindings::golem::api::host::AgentVersionFilter,
+    crate::bindings::golem::api::host::ExtendedAgentAllFilter,
+    crate::bindings::golem::api::host::ExtendedAgentAnyFilter,
+    crate::bindings::golem::api::host::ExtendedAgentPropertyFilter,
     crate::bindings::golem::api::host::FilterComparator,
     crate::bindings::golem::api::host::PromiseId,
     crate::bindings::golem::api::host::StringFilterComparator,
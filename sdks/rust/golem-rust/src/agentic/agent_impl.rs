@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use crate::agentic::{agent_registry, get_principal, get_resolved_agent, register_principal};
+use crate::golem_agentic::exports::golem::agent::read_only_methods::{
+    Guest as ReadOnlyMethodsGuest, ReadOnlyMethod,
+};
 use crate::golem_agentic::golem::agent::common::Principal;
 use crate::golem_agentic::golem::agent::host::parse_agent_id;
 use crate::load_snapshot::exports::golem::api::load_snapshot::Guest as LoadSnapshotGuest;
@@ -98,6 +101,12 @@ impl Guest for Component {
     }
 }
 
+impl ReadOnlyMethodsGuest for Component {
+    fn discover_read_only_methods() -> Vec<ReadOnlyMethod> {
+        agent_registry::get_all_read_only_methods()
+    }
+}
+
 impl LoadSnapshotGuest for Component {
     // https://github.com/golemcloud/golem/issues/2374#issuecomment-3618565370
     #[allow(clippy::await_holding_refcell_ref)]
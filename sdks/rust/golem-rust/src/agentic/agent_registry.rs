@@ -17,6 +17,7 @@ use crate::agentic::{EnrichedElementSchema, ExtendedDataSchema, Principal};
 use crate::{
     agentic::{agent_initiator::AgentInitiator, ResolvedAgent},
     golem_agentic::exports::golem::agent::guest::AgentType,
+    golem_agentic::golem::agent::common::ReadOnlyMethod,
 };
 use golem_wasm::golem_core_1_5_x::types::parse_uuid;
 use golem_wasm::{AgentId, ComponentId};
@@ -72,6 +73,27 @@ pub fn get_all_agent_types() -> Vec<AgentType> {
         .collect()
 }
 
+pub fn get_all_read_only_methods() -> Vec<ReadOnlyMethod> {
+    let state = get_state();
+
+    state
+        .agent_types
+        .borrow()
+        .agent_types
+        .values()
+        .flat_map(|agent_type| {
+            agent_type
+                .methods
+                .iter()
+                .filter(|method| method.read_only)
+                .map(|method| ReadOnlyMethod {
+                    agent_type: agent_type.type_name.clone(),
+                    method_name: method.name.clone(),
+                })
+        })
+        .collect()
+}
+
 pub fn get_enriched_agent_type_by_name(
     agent_type_name: &AgentTypeName,
 ) -> Option<ExtendedAgentType> {
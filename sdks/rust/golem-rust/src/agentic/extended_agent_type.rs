@@ -76,6 +76,7 @@ pub struct EnrichedAgentMethod {
     pub prompt_hint: Option<String>,
     pub input_schema: ExtendedDataSchema,
     pub output_schema: ExtendedDataSchema,
+    pub read_only: bool,
 }
 
 impl EnrichedAgentMethod {
@@ -654,6 +654,7 @@ mod tests {
             input_schema,
             output_schema: ExtendedDataSchema::Tuple(vec![]),
             http_endpoint: endpoints,
+            read_only: false,
         }
     }
 
@@ -26,6 +26,7 @@ pub use golem_wasm::agentic::unstructured_text::*;
 pub use http::*;
 pub use multimodal::*;
 pub use resolved_agent::*;
+pub use saga::*;
 pub use schema::*;
 pub use webhook::*;
 pub use wit_utils::*;
@@ -42,7 +43,9 @@ mod extended_agent_type;
 mod http;
 mod multimodal;
 mod principal_serde;
+pub mod pubsub;
 mod resolved_agent;
+mod saga;
 mod schema;
 pub mod snapshot_auto;
 mod unstructured_binary;
@@ -0,0 +1,40 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::agentic::Schema;
+use crate::bindings::golem::api::pubsub;
+
+/// Publishes a message to a topic of the agent's environment, and returns the offset of the
+/// message within the topic.
+///
+/// The message is delivered to every agent subscribed to the topic as an invocation of its
+/// handler method, which is expected to have a single parameter of the message's type.
+/// Delivery is at-least-once, so handlers should be idempotent.
+pub fn publish<T: Schema>(topic: &str, message: T) -> Result<u64, String> {
+    let message = message.to_data_value()?;
+    Ok(pubsub::publish(topic, &message))
+}
+
+/// Subscribes the current agent to a topic, delivering the messages published after subscribing
+/// as invocations of the given handler method of the agent.
+///
+/// Subscribing again to the same topic changes the handler method without losing messages.
+pub fn subscribe(topic: &str, handler_method: &str) {
+    pubsub::subscribe(topic, handler_method);
+}
+
+/// Unsubscribes the current agent from a topic. Returns false if it was not subscribed to it.
+pub fn unsubscribe(topic: &str) -> bool {
+    pubsub::unsubscribe(topic)
+}
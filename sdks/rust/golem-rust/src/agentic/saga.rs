@@ -0,0 +1,109 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::golem_agentic::golem::agent::common::DataValue;
+use crate::golem_agentic::golem::agent::host::{RpcError, Saga as SagaResource, WasmRpc};
+use std::future::Future;
+
+/// A method call on a remote agent compensating a step of a saga.
+///
+/// The generated agent clients have a `<method>_compensation` function for each agent method,
+/// constructing the compensation with the same parameters as the method itself.
+pub struct Compensation<'a> {
+    target: &'a WasmRpc,
+    method_name: String,
+    input: DataValue,
+}
+
+impl<'a> Compensation<'a> {
+    pub fn new(target: &'a WasmRpc, method_name: impl Into<String>, input: DataValue) -> Self {
+        Self {
+            target,
+            method_name: method_name.into(),
+            input,
+        }
+    }
+}
+
+/// A saga coordinating calls to other agents.
+///
+/// Each successfully performed step is recorded in the agent's oplog together with its
+/// compensation. Calling `abort` invokes the compensations of all the recorded steps in reverse
+/// order. If the agent fails permanently while the saga is open, the compensations are invoked
+/// by Golem automatically. Steps performed before a crash are not lost, as the saga log is
+/// restored when the agent recovers.
+pub struct Saga {
+    resource: SagaResource,
+}
+
+impl Saga {
+    /// Begins a new saga
+    pub fn new() -> Self {
+        Self {
+            resource: SagaResource::new(),
+        }
+    }
+
+    /// Performs a step of the saga, and records its compensation once it completed
+    pub async fn step<T>(
+        &mut self,
+        action: impl Future<Output = T>,
+        compensation: Compensation<'_>,
+    ) -> T {
+        let result = action.await;
+        self.record(compensation);
+        result
+    }
+
+    /// Performs a fallible step of the saga, recording its compensation only if it succeeded
+    pub async fn try_step<T, E>(
+        &mut self,
+        action: impl Future<Output = Result<T, E>>,
+        compensation: Compensation<'_>,
+    ) -> Result<T, E> {
+        let result = action.await;
+        if result.is_ok() {
+            self.record(compensation);
+        }
+        result
+    }
+
+    /// Records the compensation of a step which was performed outside of `step` or `try_step`
+    pub fn record(&mut self, compensation: Compensation<'_>) {
+        self.resource.record_step(
+            compensation.target,
+            &compensation.method_name,
+            &compensation.input,
+        );
+    }
+
+    /// Completes the saga, discarding the recorded compensations
+    pub fn complete(self) {
+        self.resource.complete();
+    }
+
+    /// Aborts the saga, invoking the compensations of the recorded steps in reverse order.
+    ///
+    /// If a compensation fails, the error is returned and the saga remains open. Calling `abort`
+    /// again retries the remaining compensations.
+    pub fn abort(&self) -> Result<(), RpcError> {
+        self.resource.abort()
+    }
+}
+
+impl Default for Saga {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -54,6 +54,7 @@ pub mod load_snapshot {
             "golem:api/host@1.5.0": crate::bindings::golem::api::host,
             "golem:api/oplog@1.5.0": crate::bindings::golem::api::oplog,
             "golem:api/context@1.5.0": crate::bindings::golem::api::context,
+            "golem:api/pubsub@1.5.0": crate::bindings::golem::api::pubsub,
             "golem:durability/durability@1.5.0": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@1.5.0": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@1.5.0": crate::bindings::golem::rdbms::postgres,
@@ -90,6 +91,7 @@ pub mod save_snapshot {
             "golem:api/host@1.5.0": crate::bindings::golem::api::host,
             "golem:api/oplog@1.5.0": crate::bindings::golem::api::oplog,
             "golem:api/context@1.5.0": crate::bindings::golem::api::context,
+            "golem:api/pubsub@1.5.0": crate::bindings::golem::api::pubsub,
             "golem:durability/durability@1.5.0": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@1.5.0": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@1.5.0": crate::bindings::golem::rdbms::postgres,
@@ -127,6 +129,7 @@ pub mod golem_agentic {
             "golem:api/host@1.5.0": crate::bindings::golem::api::host,
             "golem:api/oplog@1.5.0": crate::bindings::golem::api::oplog,
             "golem:api/context@1.5.0": crate::bindings::golem::api::context,
+            "golem:api/pubsub@1.5.0": crate::bindings::golem::api::pubsub,
             "golem:durability/durability@1.5.0": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@1.5.0": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@1.5.0": crate::bindings::golem::rdbms::postgres,
@@ -175,6 +178,7 @@ pub mod oplog_processor {
             "golem:api/host@1.5.0": crate::bindings::golem::api::host,
             "golem:api/oplog@1.5.0": crate::bindings::golem::api::oplog,
             "golem:api/context@1.5.0": crate::bindings::golem::api::context,
+            "golem:api/pubsub@1.5.0": crate::bindings::golem::api::pubsub,
             "golem:durability/durability@1.5.0": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@1.5.0": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@1.5.0": crate::bindings::golem::rdbms::postgres,
@@ -213,9 +217,10 @@ use bindings::golem::api::host::*;
 pub use golem_wasm;
 
 pub use bindings::golem::api::host::{
-    complete_promise, create_promise, fork, get_promise, oplog_commit,
+    complete_promise, create_promise, create_promise_with_timeout, fail_promise, fork, get_promise,
+    oplog_commit,
 };
-pub use bindings::golem::api::host::{ForkResult, PersistenceLevel, PromiseId};
+pub use bindings::golem::api::host::{ForkResult, PersistenceLevel, PromiseId, PromiseOutcome};
 
 pub use transaction::*;
 
@@ -227,6 +232,8 @@ pub use golem_rust_macro::*;
 ///
 /// Use `await_promise` for an async version of this function, allowing to interleave
 /// awaiting of the promise with other operations.
+///
+/// Traps if the promise is failed or expires, use `blocking_await_promise_outcome` to handle those.
 pub fn blocking_await_promise(promise_id: &PromiseId) -> Vec<u8> {
     let promise = get_promise(promise_id);
     let pollable = promise.subscribe();
@@ -238,6 +245,8 @@ pub fn blocking_await_promise(promise_id: &PromiseId) -> Vec<u8> {
 ///
 /// If only promises or timeouts are awaited simultaneously, the agent is going to be
 /// suspended until any of them completes.
+///
+/// Traps if the promise is failed or expires, use `await_promise_outcome` to handle those.
 pub async fn await_promise(promise_id: &PromiseId) -> Vec<u8> {
     let promise = get_promise(promise_id);
     let pollable = promise.subscribe();
@@ -245,6 +254,30 @@ pub async fn await_promise(promise_id: &PromiseId) -> Vec<u8> {
     promise.get().unwrap()
 }
 
+/// Awaits the outcome of a promise blocking the execution of the agent. The agent is going to be
+/// suspended until the promise is completed, failed or expired.
+///
+/// Use `await_promise_outcome` for an async version of this function, allowing to interleave
+/// awaiting of the promise with other operations.
+pub fn blocking_await_promise_outcome(promise_id: &PromiseId) -> PromiseOutcome {
+    let promise = get_promise(promise_id);
+    let pollable = promise.subscribe();
+    pollable.block();
+    promise.get_outcome().unwrap()
+}
+
+/// Awaits the outcome of a promise, which is either a successful completion, a failure or
+/// an expiry.
+///
+/// If only promises or timeouts are awaited simultaneously, the agent is going to be
+/// suspended until any of them completes.
+pub async fn await_promise_outcome(promise_id: &PromiseId) -> PromiseOutcome {
+    let promise = get_promise(promise_id);
+    let pollable = promise.subscribe();
+    wstd::io::AsyncPollable::new(pollable).wait_for().await;
+    promise.get_outcome().unwrap()
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct RetryPolicy {
     pub max_attempts: u32,
@@ -14,9 +14,13 @@
 
 use super::type_builder::TypeNodeBuilder;
 use crate::bindings::golem::api::host::{
-    AgentAllFilter, AgentAnyFilter, AgentConfigVarsFilter, AgentCreatedAtFilter, AgentEnvFilter,
-    AgentMetadata, AgentNameFilter, AgentPropertyFilter, AgentStatus, AgentStatusFilter,
-    AgentVersionFilter, EnvironmentId, FilterComparator, StringFilterComparator, UpdateMode,
+    AgentActivePluginFilter, AgentAllFilter, AgentAnyFilter, AgentConfigVarsFilter,
+    AgentCreatedAtFilter, AgentEnvFilter, AgentLastErrorFilter, AgentLastInvocationAtFilter,
+    AgentMemorySizeFilter, AgentMetadata, AgentNameFilter, AgentParametersFilter,
+    AgentPendingInvocationCountFilter, AgentPropertyFilter, AgentRetryCountFilter, AgentStatus,
+    AgentStatusFilter, AgentTypeFilter, AgentVersionFilter, EnvironmentId, ExtendedAgentAllFilter,
+    ExtendedAgentAnyFilter, ExtendedAgentPropertyFilter, FilterComparator, StringFilterComparator,
+    UpdateMode,
 };
 use crate::value_and_type::{FromValueAndType, IntoValue};
 use golem_wasm::{AgentId, NodeBuilder, Uuid, WitValueExtractor};
@@ -472,6 +476,311 @@ impl FromValueAndType for AgentConfigVarsFilter {
     }
 }
 
+// AgentTypeFilter
+
+impl IntoValue for AgentTypeFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentTypeFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <StringFilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <String>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentTypeFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <StringFilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <String>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentTypeFilter { comparator, value })
+    }
+}
+
+// AgentParametersFilter
+
+impl IntoValue for AgentParametersFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentParametersFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <StringFilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <String>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentParametersFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <StringFilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <String>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentParametersFilter { comparator, value })
+    }
+}
+
+// AgentLastInvocationAtFilter
+
+impl IntoValue for AgentLastInvocationAtFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentLastInvocationAtFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <FilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <u64>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentLastInvocationAtFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <FilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <u64>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentLastInvocationAtFilter { comparator, value })
+    }
+}
+
+// AgentPendingInvocationCountFilter
+
+impl IntoValue for AgentPendingInvocationCountFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentPendingInvocationCountFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <FilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <u64>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentPendingInvocationCountFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <FilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <u64>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentPendingInvocationCountFilter { comparator, value })
+    }
+}
+
+// AgentRetryCountFilter
+
+impl IntoValue for AgentRetryCountFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentRetryCountFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <FilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <u32>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentRetryCountFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <FilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <u32>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentRetryCountFilter { comparator, value })
+    }
+}
+
+// AgentLastErrorFilter
+
+impl IntoValue for AgentLastErrorFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentLastErrorFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <StringFilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <String>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentLastErrorFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <StringFilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <String>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentLastErrorFilter { comparator, value })
+    }
+}
+
+// AgentMemorySizeFilter
+
+impl IntoValue for AgentMemorySizeFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.comparator.add_to_builder(builder.item());
+        let builder = self.value.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentMemorySizeFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <FilterComparator>::add_to_type_builder(builder.field("comparator"));
+        let builder = <u64>::add_to_type_builder(builder.field("value"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentMemorySizeFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let comparator = <FilterComparator>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing comparator field".to_string())?,
+        )?;
+        let value = <u64>::from_extractor(
+            &extractor
+                .field(1usize)
+                .ok_or_else(|| "Missing value field".to_string())?,
+        )?;
+        Ok(AgentMemorySizeFilter { comparator, value })
+    }
+}
+
+// AgentActivePluginFilter
+
+impl IntoValue for AgentActivePluginFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.plugin_priority.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("AgentActivePluginFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <i32>::add_to_type_builder(builder.field("plugin-priority"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for AgentActivePluginFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let plugin_priority = <i32>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing plugin-priority field".to_string())?,
+        )?;
+        Ok(AgentActivePluginFilter { plugin_priority })
+    }
+}
+
 // AgentPropertyFilter
 
 impl IntoValue for AgentPropertyFilter {
@@ -573,6 +882,146 @@ impl FromValueAndType for AgentPropertyFilter {
     }
 }
 
+// ExtendedAgentPropertyFilter
+
+impl IntoValue for ExtendedAgentPropertyFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        match self {
+            ExtendedAgentPropertyFilter::Base(inner) => {
+                let builder = builder.variant(0u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::AgentType(inner) => {
+                let builder = builder.variant(1u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::AgentParameters(inner) => {
+                let builder = builder.variant(2u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::LastInvocationAt(inner) => {
+                let builder = builder.variant(3u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::PendingInvocationCount(inner) => {
+                let builder = builder.variant(4u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::RetryCount(inner) => {
+                let builder = builder.variant(5u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::LastError(inner) => {
+                let builder = builder.variant(6u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::MemorySize(inner) => {
+                let builder = builder.variant(7u32);
+                inner.add_to_builder(builder).finish()
+            }
+            ExtendedAgentPropertyFilter::ActivePlugin(inner) => {
+                let builder = builder.variant(8u32);
+                inner.add_to_builder(builder).finish()
+            }
+        }
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let mut builder = builder.variant(
+            Some("ExtendedAgentPropertyFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        builder = <AgentPropertyFilter>::add_to_type_builder(builder.case("base"));
+        builder = <AgentTypeFilter>::add_to_type_builder(builder.case("agent-type"));
+        builder = <AgentParametersFilter>::add_to_type_builder(builder.case("agent-parameters"));
+        builder =
+            <AgentLastInvocationAtFilter>::add_to_type_builder(builder.case("last-invocation-at"));
+        builder = <AgentPendingInvocationCountFilter>::add_to_type_builder(
+            builder.case("pending-invocation-count"),
+        );
+        builder = <AgentRetryCountFilter>::add_to_type_builder(builder.case("retry-count"));
+        builder = <AgentLastErrorFilter>::add_to_type_builder(builder.case("last-error"));
+        builder = <AgentMemorySizeFilter>::add_to_type_builder(builder.case("memory-size"));
+        builder = <AgentActivePluginFilter>::add_to_type_builder(builder.case("active-plugin"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for ExtendedAgentPropertyFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let (idx, inner) = extractor
+            .variant()
+            .ok_or_else(|| "Expected ExtendedAgentPropertyFilter to be a variant".to_string())?;
+        match idx {
+            0 => {
+                let value = <AgentPropertyFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::Base body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::Base(value))
+            }
+            1 => {
+                let value = <AgentTypeFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::AgentType body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::AgentType(value))
+            }
+            2 => {
+                let value = <AgentParametersFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::AgentParameters body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::AgentParameters(value))
+            }
+            3 => {
+                let value =
+                    <AgentLastInvocationAtFilter>::from_extractor(&inner.ok_or_else(|| {
+                        "Missing ExtendedAgentPropertyFilter::LastInvocationAt body".to_string()
+                    })?)?;
+                Ok(ExtendedAgentPropertyFilter::LastInvocationAt(value))
+            }
+            4 => {
+                let value = <AgentPendingInvocationCountFilter>::from_extractor(
+                    &inner.ok_or_else(|| {
+                        "Missing ExtendedAgentPropertyFilter::PendingInvocationCount body"
+                            .to_string()
+                    })?,
+                )?;
+                Ok(ExtendedAgentPropertyFilter::PendingInvocationCount(value))
+            }
+            5 => {
+                let value = <AgentRetryCountFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::RetryCount body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::RetryCount(value))
+            }
+            6 => {
+                let value = <AgentLastErrorFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::LastError body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::LastError(value))
+            }
+            7 => {
+                let value = <AgentMemorySizeFilter>::from_extractor(&inner.ok_or_else(|| {
+                    "Missing ExtendedAgentPropertyFilter::MemorySize body".to_string()
+                })?)?;
+                Ok(ExtendedAgentPropertyFilter::MemorySize(value))
+            }
+            8 => {
+                let value =
+                    <AgentActivePluginFilter>::from_extractor(&inner.ok_or_else(|| {
+                        "Missing ExtendedAgentPropertyFilter::ActivePlugin body".to_string()
+                    })?)?;
+                Ok(ExtendedAgentPropertyFilter::ActivePlugin(value))
+            }
+            _ => Err(format!(
+                "Invalid ExtendedAgentPropertyFilter variant index: {}",
+                idx
+            )),
+        }
+    }
+}
+
 // AgentAllFilter
 
 impl IntoValue for AgentAllFilter {
@@ -637,6 +1086,71 @@ impl FromValueAndType for AgentAnyFilter {
     }
 }
 
+// ExtendedAgentAllFilter
+
+impl IntoValue for ExtendedAgentAllFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.filters.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("ExtendedAgentAllFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder =
+            <Vec<ExtendedAgentPropertyFilter>>::add_to_type_builder(builder.field("filters"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for ExtendedAgentAllFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let filters = <Vec<ExtendedAgentPropertyFilter>>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing filters field".to_string())?,
+        )?;
+        Ok(ExtendedAgentAllFilter { filters })
+    }
+}
+
+// ExtendedAgentAnyFilter
+
+impl IntoValue for ExtendedAgentAnyFilter {
+    fn add_to_builder<T: NodeBuilder>(self, builder: T) -> T::Result {
+        let builder = builder.record();
+        let builder = self.filters.add_to_builder(builder.item());
+        builder.finish()
+    }
+
+    fn add_to_type_builder<T: TypeNodeBuilder>(builder: T) -> T::Result {
+        let builder = builder.record(
+            Some("ExtendedAgentAnyFilter".to_string()),
+            Some("golem".to_string()),
+        );
+        let builder = <Vec<ExtendedAgentAllFilter>>::add_to_type_builder(builder.field("filters"));
+        builder.finish()
+    }
+}
+
+impl FromValueAndType for ExtendedAgentAnyFilter {
+    fn from_extractor<'a, 'b>(
+        extractor: &'a impl WitValueExtractor<'a, 'b>,
+    ) -> Result<Self, String> {
+        let filters = <Vec<ExtendedAgentAllFilter>>::from_extractor(
+            &extractor
+                .field(0usize)
+                .ok_or_else(|| "Missing filters field".to_string())?,
+        )?;
+        Ok(ExtendedAgentAnyFilter { filters })
+    }
+}
+
 // EnvironmentId
 
 impl IntoValue for EnvironmentId {
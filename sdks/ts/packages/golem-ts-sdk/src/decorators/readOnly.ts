@@ -0,0 +1,48 @@
+// Copyright 2024-2026 Golem Cloud
+//
+// Licensed under the Golem Source License v1.1 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+import { TypeMetadata } from '@golemcloud/golem-ts-types-core';
+import { AgentMethodRegistry } from '../internal/registry/agentMethodRegistry';
+
+/*
+ * Marks a method of an agent as **read-only**
+ *
+ * Read-only methods are served against the agent's current in-memory state, without waiting
+ * for the agent's pending invocations and without being recorded in the agent's oplog.
+ * They must not perform any durable side effects (such as outgoing HTTP requests, RPC calls or
+ * writes to the file system) - doing so fails the call. They must not change the agent's state
+ * either: assigning, adding or removing a field of the agent fails the call.
+ *
+ * ```ts
+ * @agent()
+ * class CounterAgent {
+ *   @readOnly()
+ *   getValue(): number { ... }
+ * }
+ * ```
+ */
+export function readOnly() {
+  return function (target: Object, propertyKey: string | symbol, descriptor?: PropertyDescriptor) {
+    const className = target.constructor.name;
+
+    const classMetadata = TypeMetadata.get(className);
+    if (!classMetadata) {
+      throw new Error(
+        `Class metadata not found for agent ${className}. Ensure metadata is generated.`,
+      );
+    }
+
+    AgentMethodRegistry.setReadOnly(className, String(propertyKey));
+  };
+}
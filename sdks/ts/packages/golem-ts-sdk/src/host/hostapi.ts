@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-import { PromiseId, getPromise, Uuid } from 'golem:api/host@1.5.0';
+import { PromiseId, PromiseOutcome, getPromise, Uuid } from 'golem:api/host@1.5.0';
 import { parseUuid } from 'golem:core/types@1.5.0';
 import { AgentId } from '../agentId';
 import * as wasiEnv from 'wasi:cli/environment@0.2.3';
@@ -26,6 +26,15 @@ export async function awaitPromise(promiseId: PromiseId): Promise<Uint8Array> {
   return promise.get()!;
 }
 
+/**
+ * Awaits the outcome of a promise, which is either a successful completion, a failure or an expiry
+ */
+export async function awaitPromiseOutcome(promiseId: PromiseId): Promise<PromiseOutcome> {
+  const promise = getPromise(promiseId);
+  await promise.subscribe().promise();
+  return promise.getOutcome()!;
+}
+
 /**
  *  Generates a new random Golem Uuid
  */
@@ -14,10 +14,11 @@
 
 import type * as bindings from 'agent-guest';
 import { ResolvedAgent } from './internal/resolvedAgent';
-import { AgentType, Principal, DataValue } from 'golem:agent/common@1.5.0';
+import { AgentType, Principal, DataValue, ReadOnlyMethod } from 'golem:agent/common@1.5.0';
 import { createCustomError, isAgentError } from './internal/agentError';
 import { AgentTypeRegistry } from './internal/registry/agentTypeRegistry';
 import { AgentInitiatorRegistry } from './internal/registry/agentInitiatorRegistry';
+import { AgentMethodRegistry } from './internal/registry/agentMethodRegistry';
 import { getRawSelfAgentId } from './host/hostapi';
 import { AgentInitiator } from './internal/agentInitiator';
 import { TypeInfoInternal } from './internal/typeInfoInternal';
@@ -29,6 +30,7 @@ export { AgentId } from './agentId';
 export { description } from './decorators/description';
 export { agent, AgentDecoratorOptions, SnapshottingOption } from './decorators/agent';
 export { prompt } from './decorators/prompt';
+export { readOnly } from './decorators/readOnly';
 export { endpoint, EndpointDecoratorOptions } from './decorators/httpEndpoint';
 
 export * from './agentClassName';
@@ -112,6 +114,15 @@ async function discoverAgentTypes(): Promise<bindings.guest.AgentType[]> {
   }
 }
 
+async function discoverReadOnlyMethods(): Promise<ReadOnlyMethod[]> {
+  return AgentTypeRegistry.getRegisteredEntries().flatMap(([agentClassName, agentType]) => {
+    const methodMetadata = AgentMethodRegistry.get(agentClassName.value);
+    return agentType.methods
+      .filter((method) => methodMetadata?.get(method.name)?.readOnly ?? false)
+      .map((method) => ({ agentType: agentType.typeName, methodName: method.name }));
+  });
+}
+
 async function getDefinition(): Promise<AgentType> {
   if (!resolvedAgent) {
     throw new Error('Failed to get agent definition: agent is not initialized');
@@ -225,6 +236,10 @@ export const guest: typeof bindings.guest = {
   getDefinition,
 };
 
+export const readOnlyMethods: typeof bindings.readOnlyMethods = {
+  discoverReadOnlyMethods,
+};
+
 export const saveSnapshot: typeof bindings.saveSnapshot = {
   save,
 };
@@ -20,6 +20,7 @@ export interface AgentMethodMetadata {
   description?: string;
   returnType?: TypeInfoInternal;
   httpEndpoint?: HttpEndpointDetails[];
+  readOnly?: boolean;
 }
 
 /**
@@ -76,6 +77,12 @@ class AgentMethodRegistryImpl {
     classMeta.get(method)!.httpEndpoint!.push(endpoint);
   }
 
+  setReadOnly(agentClassName: string, method: string): void {
+    this.ensureMeta(agentClassName, method);
+    const classMeta = this.registry.get(agentClassName)!;
+    classMeta.get(method)!.readOnly = true;
+  }
+
   debugDump(): void {
     console.log(JSON.stringify(this.registry));
   }
@@ -42,6 +42,13 @@ class AgentTypeRegistryImpl {
     return this.cachedAgents;
   }
 
+  getRegisteredEntries(): [AgentClassName, AgentType][] {
+    return Array.from(this.registry.entries()).map(([nameValue, agentType]) => [
+      this.classNameCache.get(nameValue)!,
+      agentType,
+    ]);
+  }
+
   get(agentClassName: AgentClassName): AgentType | undefined {
     return this.registry.get(agentClassName.value);
   }
@@ -127,8 +127,19 @@ export class ResolvedAgent {
       };
     }
 
+    const stateBefore = methodInfo.readOnly ? ownPropertyValues(this.agentInstance) : undefined;
+
     const methodResult = await methodInfo.method.apply(this.agentInstance, deserializedArgs.val);
 
+    if (stateBefore && hasChangedOwnProperties(stateBefore, this.agentInstance)) {
+      return {
+        tag: 'err',
+        val: createCustomError(
+          `Read-only method ${methodName} in agent ${this.agentClassName.value} changed the agent's state`,
+        ),
+      };
+    }
+
     // Converting the result from the method back to data-value
     const dataValueEither = serializeToDataValue(methodResult, methodInfo.returnType);
 
@@ -243,6 +254,7 @@ export class ResolvedAgent {
         paramTypes,
         returnType,
         method: agentMethod,
+        readOnly: method?.readOnly ?? false,
       };
       this.cachedMethodInfo.set(methodName, methodInfo);
 
@@ -258,4 +270,31 @@ type CachedMethodInfo = {
   paramTypes: ParameterDetail[];
   returnType: TypeInfoInternal;
   method: any;
+  readOnly: boolean;
 };
+
+// Fields of the agent that are only caches, and may be filled by read-only methods
+const CACHE_FIELDS: ReadonlySet<string | symbol> = new Set(['cachedAgentType']);
+
+function ownPropertyValues(agent: BaseAgent): Map<string | symbol, unknown> {
+  return new Map(
+    Reflect.ownKeys(agent)
+      .filter((key) => !CACHE_FIELDS.has(key))
+      .map((key) => [key, Reflect.get(agent, key)]),
+  );
+}
+
+// Only detects fields of the agent being assigned, added or removed - changes made inside
+// the objects they refer to are not visible here
+function hasChangedOwnProperties(before: Map<string | symbol, unknown>, agent: BaseAgent): boolean {
+  const after = ownPropertyValues(agent);
+  if (after.size !== before.size) {
+    return true;
+  }
+  for (const [key, value] of after) {
+    if (!before.has(key) || !Object.is(before.get(key), value)) {
+      return true;
+    }
+  }
+  return false;
+}
@@ -53,4 +53,17 @@ declare module 'agent-guest' {
     export type Principal = golemAgent150Common.Principal;
     export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };
   }
+  /**
+   * Optional export of agent components.
+   * Read-only methods are served against the agent's current state without being persisted,
+   * and fail if they perform a durable side effect. Components not exporting this interface
+   * have no read-only methods.
+   */
+  export namespace readOnlyMethods {
+    /**
+     * Gets the read-only methods of the agent types defined by this component
+     */
+    export function discoverReadOnlyMethods(): Promise<ReadOnlyMethod[]>;
+    export type ReadOnlyMethod = golemAgent150Common.ReadOnlyMethod;
+  }
 }
@@ -110,6 +110,13 @@ declare module 'golem:agent/common@1.5.0' {
     inputSchema: DataSchema;
     outputSchema: DataSchema;
   };
+  /**
+   * A read-only method of an agent type, listed by the optional `read-only-methods` export
+   */
+  export type ReadOnlyMethod = {
+    agentType: string;
+    methodName: string;
+  };
   export type OidcPrincipal = {
     sub: string;
     issuer: string;
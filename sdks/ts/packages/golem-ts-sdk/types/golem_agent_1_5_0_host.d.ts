@@ -26,6 +26,7 @@ declare module 'golem:agent/host@1.5.0' {
   /**
    * Creates a webhook that can be used to integrate with webhook driven apis.
    * When the created url is called with a post request, the provided promise-id is completed with the body of the post request.
+   * If the url is called with the `failed=true` query parameter, the promise is failed instead, with the body of the post request as the error payload.
    * Note the following behaviours:
    * * Only agents whoose agent types are _currently_ deployed via an http api are allowed to create a webhook. Calling this function while the agent
    *    is not deployed via an http api will trap.
@@ -74,6 +75,19 @@ declare module 'golem:agent/host@1.5.0' {
      * Schedule invocation for later. Call cancel on the returned resource to cancel the invocation before the scheduled time.
      */
     scheduleCancelableInvocation(scheduledTime: Datetime, methodName: string, input: DataValue): CancellationToken;
+    /**
+     * Schedule an invocation repeating on the given schedule, until it gets cancelled
+     * @throws string
+     */
+    scheduleRecurringInvocation(schedule: RecurringSchedule, missedFirePolicy: MissedFirePolicy, methodName: string, input: DataValue): RecurringInvocation;
+    /**
+     * Lists the recurring invocations of the target agent
+     */
+    listRecurringInvocations(): RecurringInvocation[];
+    /**
+     * Cancels a recurring invocation of the target agent. Returns false if it did not exist.
+     */
+    cancelRecurringInvocation(id: Uuid): boolean;
   }
   export class FutureInvokeResult {
     /**
@@ -91,6 +105,27 @@ declare module 'golem:agent/host@1.5.0' {
      */
     cancel(): void;
   }
+  export class Saga {
+    /**
+     * Begins a new saga
+     */
+    constructor();
+    /**
+     * Records a successfully performed step of the saga, together with the method to be invoked
+     * on the target agent to compensate it
+     */
+    recordStep(target: WasmRpc, methodName: string, input: DataValue): void;
+    /**
+     * Completes the saga successfully; the recorded compensations are discarded
+     */
+    complete(): void;
+    /**
+     * Aborts the saga by invoking the compensations of all the recorded steps in reverse order.
+     * If a compensation fails, the error is returned and calling `abort` again retries the remaining ones.
+     * @throws RpcError
+     */
+    abort(): void;
+  }
   export type ComponentId = golemCore150Types.ComponentId;
   export type Uuid = golemCore150Types.Uuid;
   export type PromiseId = golemCore150Types.PromiseId;
@@ -131,5 +166,35 @@ declare module 'golem:agent/host@1.5.0' {
     tag: 'remote-agent-error'
     val: AgentError
   };
+  /**
+   * Defines when a recurring invocation fires
+   */
+  export type RecurringSchedule = 
+  /** Cron expression with five fields (minute, hour, day of month, month, day of week), evaluated in UTC */
+  {
+    tag: 'cron'
+    val: string
+  } |
+  /** Fixed time between two invocations, in milliseconds */
+  {
+    tag: 'interval'
+    val: bigint
+  };
+  /**
+   * Defines what happens with the occurrences of a recurring invocation which passed while the schedule
+   * could not be processed
+   */
+  export type MissedFirePolicy = "skip" | "run-once" | "catch-up";
+  /**
+   * A method invocation scheduled to repeat on an agent
+   */
+  export type RecurringInvocation = {
+    id: Uuid;
+    methodName: string;
+    schedule: RecurringSchedule;
+    missedFirePolicy: MissedFirePolicy;
+    /** The next time the invocation is going to be enqueued */
+    nextFireAt: Datetime;
+  };
   export type Result<T, E> = { tag: 'ok', val: T } | { tag: 'err', val: E };
 }
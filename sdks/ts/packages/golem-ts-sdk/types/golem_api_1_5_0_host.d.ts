@@ -10,6 +10,11 @@ declare module 'golem:api/host@1.5.0' {
    * Create a new promise
    */
   export function createPromise(): PromiseId;
+  /**
+   * Create a new promise which expires if it is not completed or failed within the given duration.
+   * An expired promise becomes ready with the `expired` outcome.
+   */
+  export function createPromiseWithTimeout(timeout: Duration): PromiseId;
   /**
    * Gets a handle to the result of the promise. Can only be called in the same agent that orignally created the promise.
    */
@@ -19,6 +24,11 @@ declare module 'golem:api/host@1.5.0' {
    * if the promise was already completed. The payload is passed to the agent that is awaiting the promise.
    */
   export function completePromise(promiseId: PromiseId, data: Uint8Array): boolean;
+  /**
+   * Fails the given promise with the given error payload. Returns true if the promise was failed, false
+   * if the promise was already completed. The error payload is passed to the agent that is awaiting the promise.
+   */
+  export function failPromise(promiseId: PromiseId, error: Uint8Array): boolean;
   /**
    * Returns the current position in the persistent op log
    */
@@ -137,6 +147,11 @@ declare module 'golem:api/host@1.5.0' {
      * metadata will be not guaranteed to be up-to-date.
      */
     constructor(componentId: ComponentId, filter: AgentAnyFilter | undefined, precise: boolean);
+    /**
+     * Creates an agent enumeration request in the same way as the constructor, with a filter that can
+     * also use the extended filter conditions.
+     */
+    static withExtendedFilter(componentId: ComponentId, filter: ExtendedAgentAnyFilter | undefined, precise: boolean): GetAgents;
     /**
      * Retrieves the next batch of agent metadata.
      */
@@ -149,8 +164,13 @@ declare module 'golem:api/host@1.5.0' {
     subscribe(): Pollable;
     /**
      * Poll the result of the promise, returning none if it is not yet ready.
+     * Traps if the promise was failed or expired; use `get-outcome` to observe those outcomes.
      */
     get(): Uint8Array | undefined;
+    /**
+     * Poll the outcome of the promise, returning none if it is not yet ready.
+     */
+    getOutcome(): PromiseOutcome | undefined;
   }
   export type Duration = wasiClocks023MonotonicClock.Duration;
   export type ComponentId = golemCore150Types.ComponentId;
@@ -258,6 +278,63 @@ declare module 'golem:api/host@1.5.0' {
     comparator: StringFilterComparator;
     value: string;
   };
+  /**
+   * Describes a filter condition on the agent type name when enumerating agents
+   */
+  export type AgentTypeFilter = {
+    comparator: StringFilterComparator;
+    value: string;
+  };
+  /**
+   * Describes a filter condition on the agent's constructor parameters, as they appear in the agent ID,
+   * when enumerating agents
+   */
+  export type AgentParametersFilter = {
+    comparator: StringFilterComparator;
+    value: string;
+  };
+  /**
+   * Describes a filter condition on the start time of the agent's last invocation when enumerating agents.
+   * Agents that were never invoked are matched by their creation time.
+   */
+  export type AgentLastInvocationAtFilter = {
+    comparator: FilterComparator;
+    value: bigint;
+  };
+  /**
+   * Describes a filter condition on the number of the agent's pending invocations when enumerating agents
+   */
+  export type AgentPendingInvocationCountFilter = {
+    comparator: FilterComparator;
+    value: bigint;
+  };
+  /**
+   * Describes a filter condition on the retry count of the agent's current invocation when enumerating agents
+   */
+  export type AgentRetryCountFilter = {
+    comparator: FilterComparator;
+    value: number;
+  };
+  /**
+   * Describes a filter condition on the message of the agent's last error when enumerating agents
+   */
+  export type AgentLastErrorFilter = {
+    comparator: StringFilterComparator;
+    value: string;
+  };
+  /**
+   * Describes a filter condition on the agent's total linear memory size when enumerating agents
+   */
+  export type AgentMemorySizeFilter = {
+    comparator: FilterComparator;
+    value: bigint;
+  };
+  /**
+   * Describes a filter condition matching agents having the plugin with the given priority activated
+   */
+  export type AgentActivePluginFilter = {
+    pluginPriority: number;
+  };
   /**
    * Describes one filter condition for enumerating agents
    */
@@ -298,6 +375,59 @@ declare module 'golem:api/host@1.5.0' {
   export type AgentAnyFilter = {
     filters: AgentAllFilter[];
   };
+  /**
+   * Describes one filter condition for enumerating agents with `get-agents.with-extended-filter`,
+   * extending `agent-property-filter` with the conditions added after it was published
+   */
+  export type ExtendedAgentPropertyFilter = 
+  {
+    tag: 'base'
+    val: AgentPropertyFilter
+  } |
+  {
+    tag: 'agent-type'
+    val: AgentTypeFilter
+  } |
+  {
+    tag: 'agent-parameters'
+    val: AgentParametersFilter
+  } |
+  {
+    tag: 'last-invocation-at'
+    val: AgentLastInvocationAtFilter
+  } |
+  {
+    tag: 'pending-invocation-count'
+    val: AgentPendingInvocationCountFilter
+  } |
+  {
+    tag: 'retry-count'
+    val: AgentRetryCountFilter
+  } |
+  {
+    tag: 'last-error'
+    val: AgentLastErrorFilter
+  } |
+  {
+    tag: 'memory-size'
+    val: AgentMemorySizeFilter
+  } |
+  {
+    tag: 'active-plugin'
+    val: AgentActivePluginFilter
+  };
+  /**
+   * Combines multiple extended filter conditions with an `AND` relationship for enumerating agents
+   */
+  export type ExtendedAgentAllFilter = {
+    filters: ExtendedAgentPropertyFilter[];
+  };
+  /**
+   * Combines multiple groups of extended filter conditions with an `OR` relationship for enumerating agents
+   */
+  export type ExtendedAgentAnyFilter = {
+    filters: ExtendedAgentAllFilter[];
+  };
   /**
    * Metadata about an agent
    */
@@ -355,6 +485,24 @@ declare module 'golem:api/host@1.5.0' {
     tag: 'forked'
     val: ForkDetails
   };
+  /**
+   * The final outcome of a promise
+   */
+  export type PromiseOutcome = 
+  /** The promise was completed successfully with the given payload */
+  {
+    tag: 'completed'
+    val: Uint8Array
+  } |
+  /** The promise was failed (rejected) with the given error payload */
+  {
+    tag: 'failed'
+    val: Uint8Array
+  } |
+  /** The promise was not completed or failed before its expiry */
+  {
+    tag: 'expired'
+  };
   /**
    * Snapshot payload
    */
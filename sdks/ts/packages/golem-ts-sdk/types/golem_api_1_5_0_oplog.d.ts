@@ -754,7 +754,8 @@ declare module 'golem:api/oplog@1.5.0' {
   } |
   /**
    * Marker entry added when get-oplog-index is called from the agent, to make the jumping behavior
-   * more predictable.
+   * more predictable. Oplog entries introduced after this version of the interface are also
+   * presented as no-op entries.
    */
   {
     tag: 'no-op'
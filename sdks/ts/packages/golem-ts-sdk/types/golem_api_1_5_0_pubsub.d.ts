@@ -0,0 +1,27 @@
+/**
+ * Durable publish/subscribe topics shared by the agents of an environment
+ */
+declare module 'golem:api/pubsub@1.5.0' {
+  import * as golemCore150Types from 'golem:core/types@1.5.0';
+  /**
+   * Publishes a message to a topic of the agent's environment, and returns the offset of the message
+   * within the topic.
+   * Every agent subscribed to the topic receives the message as an invocation of its handler method, with
+   * the message as the method's parameters. Messages are delivered at least once, in the order of their offsets.
+   * Topic names are non-empty and consist of ASCII letters, digits, and the `-`, `_`, `.` and `:`
+   * characters. Traps if the topic name is invalid.
+   */
+  export function publish(topic: string, message: DataValue): bigint;
+  /**
+   * Subscribes the current agent to a topic of its environment, delivering the messages published to the topic
+   * as invocations of the given handler method.
+   * Only the messages published after subscribing are delivered. Subscribing again to the same topic changes
+   * the handler method without losing any messages.
+   */
+  export function subscribe(topic: string, handlerMethod: string): void;
+  /**
+   * Unsubscribes the current agent from a topic. Returns false if the agent was not subscribed to it.
+   */
+  export function unsubscribe(topic: string): boolean;
+  export type DataValue = golemCore150Types.DataValue;
+}